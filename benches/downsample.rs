@@ -0,0 +1,36 @@
+//! Chart downsampling benchmarks: LTTB and the downsample pyramid on a long
+//! synthetic channel.
+//!
+//! ```text
+//! cargo bench --bench downsample
+//! ```
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ultralog::downsample::{lttb, DownsamplePyramid};
+
+/// Samples in the synthetic channel
+const SAMPLES: usize = 1_000_000;
+
+/// Points drawn for a full-width chart
+const CHART_POINTS: usize = 2_000;
+
+fn downsample_benches(c: &mut Criterion) {
+    // 50 Hz RPM-like signal: slow sweeps with some high-frequency noise
+    let times: Vec<f64> = (0..SAMPLES).map(|i| i as f64 * 0.02).collect();
+    let values: Vec<f64> = times
+        .iter()
+        .map(|t| 4000.0 + 3000.0 * (t * 0.05).sin() + 50.0 * (t * 37.0).sin())
+        .collect();
+
+    let mut group = c.benchmark_group("downsample");
+    group.bench_function("lttb_1m_to_2k", |b| {
+        b.iter(|| lttb(&times, &values, CHART_POINTS))
+    });
+    group.bench_function("pyramid_1m", |b| {
+        b.iter_with_large_drop(|| DownsamplePyramid::build(&times, &values, CHART_POINTS))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, downsample_benches);
+criterion_main!(benches);
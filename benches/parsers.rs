@@ -0,0 +1,154 @@
+//! Parser benchmarks on synthetic logs: Haltech and ECUMaster CSV, and
+//! Speeduino/rusEFI MLG (eager and lazy).
+//!
+//! ```text
+//! cargo bench --bench parsers
+//! cargo bench --bench parsers -- haltech
+//! ```
+
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use ultralog::parsers::{EcuMaster, Haltech, Speeduino};
+
+/// Records in each synthetic log
+const ROWS: usize = 1_000_000;
+
+/// Channel definitions (name, Haltech type) of the synthetic CSV logs
+const CHANNELS: &[(&str, &str)] = &[
+    ("RPM", "EngineSpeed"),
+    ("Manifold Pressure", "Pressure"),
+    ("Throttle Position", "Percentage"),
+    ("Coolant Temperature", "Temperature"),
+    ("Wideband Sensor 1", "AFR"),
+    ("Ignition Angle", "Angle"),
+    ("Battery Voltage", "BatteryVoltage"),
+    ("Vehicle Speed", "Speed"),
+    ("Injector Duty Cycle", "Percentage"),
+    ("Knock Level 1", "Decibel"),
+];
+
+/// Raw value of a channel at a record, varying enough to defeat any caching
+fn raw_value(row: usize, channel: usize) -> usize {
+    (row * (channel + 7)) % 9000
+}
+
+/// A Haltech CSV with `rows` records at 50 Hz
+fn haltech_log(rows: usize) -> String {
+    let mut log = String::from("%DataLog%\nDataLogVersion : 1.1\nSoftware : Haltech NSP\n");
+    for (id, (name, kind)) in CHANNELS.iter().enumerate() {
+        let _ = write!(log, "Channel : {name}\nID : {id}\nType : {kind}\n");
+    }
+    log.push_str("Log : 20250718 02:15:46\n");
+
+    for row in 0..rows {
+        let ms = row * 20;
+        let _ = write!(
+            log,
+            "{:02}:{:02}:{:02}.{:03}",
+            ms / 3_600_000 % 24,
+            ms / 60_000 % 60,
+            ms / 1000 % 60,
+            ms % 1000
+        );
+        for channel in 0..CHANNELS.len() {
+            let _ = write!(log, ",{}", raw_value(row, channel));
+        }
+        log.push('\n');
+    }
+    log
+}
+
+/// An ECUMaster CSV with `rows` records at 50 Hz and decimal values
+fn ecumaster_log(rows: usize) -> String {
+    let mut log = String::from("TIME");
+    for (name, _) in CHANNELS {
+        let _ = write!(log, ";engine/{}", name.to_lowercase().replace(' ', "_"));
+    }
+    log.push('\n');
+
+    for row in 0..rows {
+        let _ = write!(log, "{:.3}", row as f64 * 0.02);
+        for channel in 0..CHANNELS.len() {
+            let _ = write!(log, ";{:.1}", raw_value(row, channel) as f64 / 10.0);
+        }
+        log.push('\n');
+    }
+    log
+}
+
+/// A v1 MLG log with `rows` records of U16 fields at 100 Hz
+fn mlg_log(rows: usize) -> Vec<u8> {
+    const FIELD_LENGTH: usize = 55;
+    let data_begin = 22 + CHANNELS.len() * FIELD_LENGTH;
+
+    let mut data = b"MLVLG\0".to_vec();
+    data.extend(1i16.to_be_bytes()); // format version
+    data.extend(0i32.to_be_bytes()); // timestamp
+    data.extend(0u16.to_be_bytes()); // info_data_start
+    data.extend((data_begin as u32).to_be_bytes());
+    data.extend(0i16.to_be_bytes()); // record_length
+    data.extend((CHANNELS.len() as u16).to_be_bytes());
+
+    for (name, _) in CHANNELS {
+        data.push(2); // U16
+        let mut name_bytes = name.as_bytes().to_vec();
+        name_bytes.resize(34, 0);
+        data.extend(name_bytes);
+        data.extend([0u8; 10]); // units
+        data.push(0); // display style
+        data.extend(1.0f32.to_be_bytes()); // scale
+        data.extend(0.0f32.to_be_bytes()); // transform
+        data.push(0); // digits
+    }
+
+    for row in 0..rows {
+        data.extend([0, row as u8]);
+        data.extend(((row * 10) as u16).to_be_bytes()); // wraps like real logs
+        for channel in 0..CHANNELS.len() {
+            data.extend((raw_value(row, channel) as u16).to_be_bytes());
+        }
+        data.push(0); // CRC
+    }
+    data
+}
+
+fn parse_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    // Every iteration parses a whole 1M-record log
+    group.sample_size(10);
+
+    let haltech = haltech_log(ROWS);
+    group.throughput(Throughput::Bytes(haltech.len() as u64));
+    group.bench_function("haltech_1m", |b| {
+        b.iter_with_large_drop(|| Haltech.parse_reader(haltech.as_bytes(), &|_| {}).unwrap())
+    });
+    drop(haltech);
+
+    let ecumaster = ecumaster_log(ROWS);
+    group.throughput(Throughput::Bytes(ecumaster.len() as u64));
+    group.bench_function("ecumaster_1m", |b| {
+        b.iter_with_large_drop(|| {
+            EcuMaster
+                .parse_reader(ecumaster.as_bytes(), &|_| {})
+                .unwrap()
+        })
+    });
+    drop(ecumaster);
+
+    // Shared so the lazy parser can own a handle without copying the log
+    let mlg: Arc<[u8]> = mlg_log(ROWS).into();
+    group.throughput(Throughput::Bytes(mlg.len() as u64));
+    group.bench_function("mlg_1m", |b| {
+        b.iter_with_large_drop(|| Speeduino::parse_binary(&mlg).unwrap())
+    });
+    group.bench_function("mlg_1m_lazy_open", |b| {
+        b.iter_with_large_drop(|| Speeduino::parse_binary_lazy(Arc::clone(&mlg)).unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, parse_benches);
+criterion_main!(benches);
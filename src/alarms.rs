@@ -0,0 +1,348 @@
+//! Threshold alarm rules engine.
+//!
+//! Users define rules such as "Coolant Temp > 110" or
+//! "Oil Pressure < 100 while RPM > 2000". Each rule is evaluated against a
+//! log's channels and produces a list of violations (contiguous time ranges
+//! where every condition held), which are shaded on the chart and listed in
+//! the alarms window.
+
+use serde::{Deserialize, Serialize};
+
+/// Comparison operator for a rule condition
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Comparison {
+    #[default]
+    Greater,
+    GreaterOrEqual,
+    Less,
+    LessOrEqual,
+}
+
+impl Comparison {
+    /// All operators, in the order shown in the editor
+    pub const ALL: [Comparison; 4] = [
+        Comparison::Greater,
+        Comparison::GreaterOrEqual,
+        Comparison::Less,
+        Comparison::LessOrEqual,
+    ];
+
+    /// Get the display symbol for this operator
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => "≥",
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "≤",
+        }
+    }
+
+    /// Check whether `value` satisfies this comparison against `threshold`
+    pub fn matches(&self, value: f64, threshold: f64) -> bool {
+        match self {
+            Comparison::Greater => value > threshold,
+            Comparison::GreaterOrEqual => value >= threshold,
+            Comparison::Less => value < threshold,
+            Comparison::LessOrEqual => value <= threshold,
+        }
+    }
+}
+
+/// A single "channel <op> threshold" test
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Condition {
+    /// Channel name (matched against normalized or original names, case-insensitive)
+    pub channel: String,
+    /// Comparison operator
+    pub comparison: Comparison,
+    /// Threshold in display units
+    pub threshold: f64,
+}
+
+impl Condition {
+    /// Human-readable form, e.g. "Coolant Temp > 110"
+    pub fn describe(&self) -> String {
+        format!(
+            "{} {} {}",
+            self.channel,
+            self.comparison.symbol(),
+            self.threshold
+        )
+    }
+}
+
+/// A user-defined alarm rule
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AlarmRule {
+    /// Display name for the rule
+    pub name: String,
+    /// Disabled rules are kept but not evaluated
+    pub enabled: bool,
+    /// Primary condition being monitored
+    pub condition: Condition,
+    /// Additional conditions that must also hold ("while RPM > 2000")
+    pub while_conditions: Vec<Condition>,
+}
+
+impl Default for AlarmRule {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            enabled: true,
+            condition: Condition::default(),
+            while_conditions: Vec::new(),
+        }
+    }
+}
+
+impl AlarmRule {
+    /// Human-readable form of the full rule
+    pub fn describe(&self) -> String {
+        let mut text = self.condition.describe();
+        for (i, cond) in self.while_conditions.iter().enumerate() {
+            text.push_str(if i == 0 { " while " } else { " and " });
+            text.push_str(&cond.describe());
+        }
+        text
+    }
+
+    /// Evaluate the rule against a log.
+    ///
+    /// `resolve` maps a channel name to its values (in display units), one per
+    /// entry in `times`. Returns an error naming the first channel that
+    /// couldn't be found.
+    pub fn evaluate<F>(&self, times: &[f64], resolve: F) -> Result<Vec<Violation>, String>
+    where
+        F: Fn(&str) -> Option<Vec<f64>>,
+    {
+        let conditions: Vec<&Condition> = std::iter::once(&self.condition)
+            .chain(self.while_conditions.iter())
+            .collect();
+
+        let mut series: Vec<Vec<f64>> = Vec::with_capacity(conditions.len());
+        for cond in &conditions {
+            let values = resolve(&cond.channel)
+                .ok_or_else(|| format!("Channel \"{}\" not found", cond.channel))?;
+            series.push(values);
+        }
+
+        let len = series
+            .iter()
+            .map(|s| s.len())
+            .min()
+            .unwrap_or(0)
+            .min(times.len());
+
+        let mut violations = Vec::new();
+        let mut current: Option<Violation> = None;
+
+        for i in 0..len {
+            let active = conditions
+                .iter()
+                .zip(series.iter())
+                .all(|(cond, values)| cond.comparison.matches(values[i], cond.threshold));
+
+            let value = series[0][i];
+            match (&mut current, active) {
+                (Some(v), true) => {
+                    v.end_time = times[i];
+                    v.end_record = i;
+                    if self.is_worse(value, v.peak_value) {
+                        v.peak_value = value;
+                    }
+                }
+                (None, true) => {
+                    current = Some(Violation {
+                        start_time: times[i],
+                        end_time: times[i],
+                        start_record: i,
+                        end_record: i,
+                        peak_value: value,
+                    });
+                }
+                (Some(_), false) => violations.extend(current.take()),
+                (None, false) => {}
+            }
+        }
+        violations.extend(current);
+
+        Ok(violations)
+    }
+
+    /// Whether `value` is further past the threshold than `peak`
+    fn is_worse(&self, value: f64, peak: f64) -> bool {
+        match self.condition.comparison {
+            Comparison::Greater | Comparison::GreaterOrEqual => value > peak,
+            Comparison::Less | Comparison::LessOrEqual => value < peak,
+        }
+    }
+}
+
+/// A contiguous range of records where a rule was violated
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    /// Time of the first violating record (seconds)
+    pub start_time: f64,
+    /// Time of the last violating record (seconds)
+    pub end_time: f64,
+    /// Index of the first violating record
+    pub start_record: usize,
+    /// Index of the last violating record
+    pub end_record: usize,
+    /// Most extreme value of the primary channel during the violation
+    pub peak_value: f64,
+}
+
+impl Violation {
+    /// Duration of the violation in seconds
+    pub fn duration(&self) -> f64 {
+        self.end_time - self.start_time
+    }
+}
+
+/// Index of the violation after (or before) `time`, wrapping around at the
+/// ends. With no time, the first (or last) violation.
+pub fn adjacent_violation(
+    violations: &[Violation],
+    time: Option<f64>,
+    forward: bool,
+) -> Option<usize> {
+    if violations.is_empty() {
+        return None;
+    }
+    let last = violations.len() - 1;
+    let index = match (time, forward) {
+        (Some(time), true) => violations
+            .iter()
+            .position(|v| v.start_time > time + f64::EPSILON)
+            .unwrap_or(0),
+        (Some(time), false) => violations
+            .iter()
+            .rposition(|v| v.start_time < time - f64::EPSILON)
+            .unwrap_or(last),
+        (None, true) => 0,
+        (None, false) => last,
+    };
+    Some(index)
+}
+
+/// Evaluation results for one rule against one file
+#[derive(Clone, Debug)]
+pub struct RuleResult {
+    /// Index of the rule in the rule list
+    pub rule_index: usize,
+    /// Violations found, or an error if a channel was missing
+    pub violations: Result<Vec<Violation>, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolver(name: &str) -> Option<Vec<f64>> {
+        match name {
+            "Coolant Temp" => Some(vec![100.0, 112.0, 115.0, 105.0, 111.0]),
+            "RPM" => Some(vec![1000.0, 3000.0, 1500.0, 4000.0, 4000.0]),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_comparison() {
+        assert!(Comparison::Greater.matches(2.0, 1.0));
+        assert!(!Comparison::Greater.matches(1.0, 1.0));
+        assert!(Comparison::GreaterOrEqual.matches(1.0, 1.0));
+        assert!(Comparison::Less.matches(0.5, 1.0));
+        assert!(Comparison::LessOrEqual.matches(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_single_condition() {
+        let times = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let rule = AlarmRule {
+            name: "Hot".to_string(),
+            condition: Condition {
+                channel: "Coolant Temp".to_string(),
+                comparison: Comparison::Greater,
+                threshold: 110.0,
+            },
+            ..Default::default()
+        };
+
+        let violations = rule.evaluate(&times, resolver).unwrap();
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].start_record, 1);
+        assert_eq!(violations[0].end_record, 2);
+        assert_eq!(violations[0].peak_value, 115.0);
+        assert_eq!(violations[0].duration(), 1.0);
+        assert_eq!(violations[1].start_time, 4.0);
+    }
+
+    #[test]
+    fn test_while_condition() {
+        let times = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let rule = AlarmRule {
+            condition: Condition {
+                channel: "Coolant Temp".to_string(),
+                comparison: Comparison::Greater,
+                threshold: 110.0,
+            },
+            while_conditions: vec![Condition {
+                channel: "RPM".to_string(),
+                comparison: Comparison::Greater,
+                threshold: 2000.0,
+            }],
+            ..Default::default()
+        };
+
+        let violations = rule.evaluate(&times, resolver).unwrap();
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            (violations[0].start_record, violations[0].end_record),
+            (1, 1)
+        );
+        assert_eq!(
+            (violations[1].start_record, violations[1].end_record),
+            (4, 4)
+        );
+        assert_eq!(
+            rule.describe(),
+            "Coolant Temp > 110 while RPM > 2000".to_string()
+        );
+    }
+
+    #[test]
+    fn test_adjacent_violation() {
+        let violation = |start_time: f64| Violation {
+            start_time,
+            end_time: start_time + 0.5,
+            start_record: 0,
+            end_record: 0,
+            peak_value: 0.0,
+        };
+        let violations = [violation(1.0), violation(3.0), violation(5.0)];
+
+        assert_eq!(adjacent_violation(&violations, None, true), Some(0));
+        assert_eq!(adjacent_violation(&violations, None, false), Some(2));
+        assert_eq!(adjacent_violation(&violations, Some(1.0), true), Some(1));
+        assert_eq!(adjacent_violation(&violations, Some(3.0), false), Some(0));
+        assert_eq!(adjacent_violation(&violations, Some(4.0), false), Some(1));
+        // Past either end wraps around
+        assert_eq!(adjacent_violation(&violations, Some(5.0), true), Some(0));
+        assert_eq!(adjacent_violation(&violations, Some(0.5), false), Some(2));
+        assert_eq!(adjacent_violation(&[], Some(1.0), true), None);
+    }
+
+    #[test]
+    fn test_missing_channel() {
+        let rule = AlarmRule {
+            condition: Condition {
+                channel: "Oil Pressure".to_string(),
+                comparison: Comparison::Less,
+                threshold: 100.0,
+            },
+            ..Default::default()
+        };
+        assert!(rule.evaluate(&[0.0], resolver).is_err());
+    }
+}
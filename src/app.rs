@@ -5,20 +5,55 @@
 
 use eframe::egui;
 use memmap2::Mmap;
-use std::collections::HashMap;
+use notify::Watcher;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs::{self, File};
-use std::path::PathBuf;
+use std::io::{BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
 use std::thread;
 
-use crate::parsers::{EcuMaster, EcuType, Haltech, Parseable, Speeduino};
+use crate::alarms::{AlarmRule, Condition, RuleResult};
+use crate::crash;
+use crate::decimate;
+use crate::derived;
+use crate::downsample::{lru_evictions, DomainDownsample, DownsamplePyramid, ViewDownsample};
+use crate::dyno::DynoConfig;
+use crate::egt::EgtSpreadConfig;
+use crate::fuel_trim::FuelTrimConfig;
+use crate::i18n::Language;
+use crate::idle::IdleConfig;
+use crate::live::{LiveConfig, LiveMessage, LiveSession, OutputLayout};
+use crate::log_cache::LogCache;
+use crate::misfire::MisfireConfig;
+use crate::normalize::normalize_channel_name_with_custom;
+use crate::parsers::speeduino::{SpeeduinoChannel, SpeeduinoMeta};
+use crate::parsers::types::{group_thousands, Meta};
+use crate::parsers::{
+    Channel, Cobb, CsvProfile, EcuMaster, EcuType, GenericCsv, Haltech, HpTuners, Log, LogTail,
+    MegaSquirt, Parseable, ProfileCsv, Speeduino,
+};
+use crate::pulls::{Pull, PullConfig};
+use crate::report::ReportConfig;
+use crate::session::{Session, SessionTab, SESSION_EXTENSION};
+use crate::shifts::ShiftConfig;
 use crate::state::{
-    ActiveTool, CacheKey, LoadResult, LoadedFile, LoadingState, ScatterPlotConfig,
-    ScatterPlotState, SelectedChannel, Tab, ToastType, CHART_COLORS, COLORBLIND_COLORS,
-    MAX_CHANNELS,
+    ActiveTool, CacheKey, ChannelStyle, ComparedPull, DragScan, EgtSpreadScan, FindMatches,
+    FuelTrimSummary, IdleScan, LoadError, LoadMessage, LoadProgress, LoadResult, LoadedFile,
+    LoadingState, MisfireScan, PendingReload, PersistedSettings, ScatterPlotConfig,
+    ScatterPlotState, SelectedChannel, SettingsCategory, ShiftScan, SplitLayout, Tab, Theme,
+    ToastType, TriageState, UnsupportedFile, CHART_COLORS, COLORBLIND_COLORS,
+    DEFAULT_CACHE_BUDGET_MB, DEFAULT_MAX_CHANNELS, DEFAULT_MMAP_THRESHOLD_MB, MAX_CHANNELS_LIMIT,
+    MAX_CHART_POINTS, MAX_CHART_POINTS_LIMIT, MAX_LINE_WIDTH_SCALE, MAX_PARALLEL_LOADS,
+    MAX_UI_SCALE, MIN_CHART_POINTS, MIN_LINE_WIDTH_SCALE, MIN_UI_SCALE, SETTINGS_KEY,
+    TRIAGE_PREVIEW_BYTES,
+};
+use crate::units::{BoostUnit, UnitPreferences};
+use crate::updater::{
+    DownloadMessage, DownloadResult, UpdateChannel, UpdateCheckResult, UpdateInfo, UpdateState,
 };
-use crate::units::UnitPreferences;
-use crate::updater::{DownloadResult, UpdateCheckResult, UpdateState};
 
 // ============================================================================
 // Main Application State
@@ -32,16 +67,48 @@ pub struct UltraLogApp {
     pub(crate) selected_file: Option<usize>,
     /// Toast messages for user feedback (message, time, type)
     pub(crate) toast_message: Option<(String, std::time::Instant, ToastType)>,
-    /// Track dropped files to prevent duplicates
-    last_drop_time: Option<std::time::Instant>,
-    /// Channel for receiving loaded files from background thread
-    load_receiver: Option<Receiver<LoadResult>>,
+    /// Sender cloned into each background load worker
+    load_sender: Sender<LoadMessage>,
+    /// Progress and results from background load workers
+    load_receiver: Receiver<LoadMessage>,
+    /// Identifier for the next background load
+    next_load_id: u64,
+    /// Files waiting for a free load slot (e.g. from a drop or session)
+    pub(crate) load_queue: VecDeque<PathBuf>,
+    /// Session tabs waiting for their file to finish loading
+    pending_session_tabs: Vec<SessionTab>,
+    /// File path of the session's active tab, focused once restoring finishes
+    pending_session_active: Option<PathBuf>,
+    /// Tabs of files being reloaded from disk, restored once they load again
+    pending_reloads: Vec<PendingReload>,
     /// Current loading state
     pub(crate) loading_state: LoadingState,
-    /// Cache for downsampled chart data
-    pub(crate) downsample_cache: HashMap<CacheKey, Vec<[f64; 2]>>,
+    /// Unsupported file currently shown in the triage dialog
+    pub(crate) triage: Option<TriageState>,
+    /// Cache of per-channel downsample pyramids for chart rendering
+    pub(crate) downsample_cache: HashMap<CacheKey, DownsamplePyramid>,
+    /// Cache of per-channel downsamples of the visible chart range
+    pub(crate) view_downsample_cache: HashMap<CacheKey, ViewDownsample>,
+    /// Cache of per-channel downsamples against an X-axis channel
+    pub(crate) domain_view_cache: HashMap<CacheKey, DomainDownsample>,
     /// Cache for channel min/max values (avoids O(n) scans)
     pub(crate) minmax_cache: HashMap<CacheKey, (f64, f64)>,
+    /// Frame each channel's chart caches were last drawn in
+    cache_last_used: HashMap<CacheKey, u64>,
+    /// Frame counter for `cache_last_used`
+    cache_frame: u64,
+    /// Memory the chart's downsample caches may use (MB)
+    pub(crate) cache_budget_mb: u64,
+    /// Cache of whether each channel holds no data (all zero or NaN)
+    pub(crate) empty_channel_cache: HashMap<CacheKey, bool>,
+    /// Visible time range of the chart from the last frame (for annotations)
+    pub(crate) chart_view_range: Option<(f64, f64)>,
+    /// Watches followed files for writes, created when the first file is followed
+    follow_watcher: Option<notify::RecommendedWatcher>,
+    /// Paths currently registered with `follow_watcher`
+    watched_paths: HashSet<PathBuf>,
+    /// Set by the watcher when a followed file changed on disk
+    follow_changed: Arc<AtomicBool>,
     /// Current cursor position in seconds (timeline feature)
     pub(crate) cursor_time: Option<f64>,
     /// Total time range across all loaded files (min, max)
@@ -63,14 +130,30 @@ pub struct UltraLogApp {
     // === Accessibility ===
     /// When true, use colorblind-friendly color palette
     pub(crate) color_blind_mode: bool,
+    /// When true, text and widget outlines use stronger contrast
+    pub(crate) high_contrast: bool,
+    /// Multiplier applied to every chart line's width
+    pub(crate) line_width_scale: f32,
+    /// Language of menus and dialogs
+    pub(crate) language: Language,
+    /// When true, times are shown as the time of day the log was recorded,
+    /// for logs that record it
+    pub(crate) wall_clock_time: bool,
     /// When true, normalize field names to standard names
     pub(crate) field_normalization: bool,
+    /// When true, normalized names in the channel list and legend are
+    /// followed by the ECU's own name
+    pub(crate) show_original_names: bool,
+    /// When true, hide channels without data from the channel list
+    pub(crate) hide_empty_channels: bool,
     // === Chart View State ===
     /// Initial view window in seconds (shown before user interacts with chart)
     pub(crate) initial_view_seconds: f64,
     // === Unit Preferences ===
     /// User preferences for display units
     pub(crate) unit_preferences: UnitPreferences,
+    /// User-corrected units for ECUMaster channels (channel path -> unit)
+    pub(crate) unit_overrides: HashMap<String, String>,
     // === Custom Field Normalization ===
     /// Custom user-defined field name mappings (source name -> normalized name)
     pub(crate) custom_normalizations: HashMap<String, String>,
@@ -84,6 +167,119 @@ pub struct UltraLogApp {
     pub(crate) norm_editor_custom_source: String,
     /// Input field for new normalized name in "Create New Mapping" section
     pub(crate) norm_editor_custom_target: String,
+    /// User-defined threshold alarm rules
+    pub(crate) alarm_rules: Vec<AlarmRule>,
+    /// Whether to show the annotations window
+    pub(crate) show_annotations_window: bool,
+    /// Whether to show the alarm rules window
+    pub(crate) show_alarms_window: bool,
+    /// Whether to show the find value window
+    pub(crate) show_find_value_window: bool,
+    /// Condition searched for by the find value window
+    pub(crate) find_condition: Condition,
+    /// Matches for the find value condition in the active file, if searched
+    pub(crate) find_matches: Option<FindMatches>,
+    /// Vehicle parameters for the virtual dyno
+    pub(crate) dyno_config: DynoConfig,
+    /// WOT pull detection thresholds
+    pub(crate) pull_config: PullConfig,
+    /// Whether to show the WOT pulls window
+    pub(crate) show_pulls_window: bool,
+    /// Whether to show the boost control window
+    pub(crate) show_boost_window: bool,
+    /// Fuel trim summary threshold and cell sizes
+    pub(crate) fuel_trim_config: FuelTrimConfig,
+    /// Whether to show the fuel trim window
+    pub(crate) show_fuel_trim_window: bool,
+    /// Fuel trim summary of the active file, rebuilt when its inputs change
+    pub(crate) fuel_trim_summary: Option<FuelTrimSummary>,
+    /// Misfire detection thresholds
+    pub(crate) misfire_config: MisfireConfig,
+    /// Whether to show the misfire detector window
+    pub(crate) show_misfire_window: bool,
+    /// Candidate misfires in the active file, found again when its inputs change
+    pub(crate) misfire_scan: Option<MisfireScan>,
+    /// EGT imbalance thresholds
+    pub(crate) egt_spread_config: EgtSpreadConfig,
+    /// Whether to show the EGT spread window
+    pub(crate) show_egt_spread_window: bool,
+    /// EGT imbalances in the active file, found again when their inputs change
+    pub(crate) egt_spread_scan: Option<EgtSpreadScan>,
+    /// Idle detection thresholds
+    pub(crate) idle_config: IdleConfig,
+    /// Whether to show the idle quality window
+    pub(crate) show_idle_window: bool,
+    /// Idle analysis of the active file, redone when its inputs change
+    pub(crate) idle_scan: Option<IdleScan>,
+    /// Whether to show the drag runs window
+    pub(crate) show_drag_window: bool,
+    /// Drag runs in the active file, found again when the file changes
+    pub(crate) drag_scan: Option<DragScan>,
+    /// Shift detection thresholds
+    pub(crate) shift_config: ShiftConfig,
+    /// Whether to show the shift points window
+    pub(crate) show_shifts_window: bool,
+    /// Whether detected shifts are marked on the chart
+    pub(crate) show_shift_markers: bool,
+    /// Shifts in the active file, found again when their inputs change
+    pub(crate) shift_scan: Option<ShiftScan>,
+    /// Analyses included in generated reports
+    pub(crate) report_config: ReportConfig,
+    /// Whether to show the Generate Report window
+    pub(crate) show_report_window: bool,
+    /// Title of the next generated report (empty for one from the file name)
+    pub(crate) report_title: String,
+    /// Whether to show the Merge Logs window
+    pub(crate) show_merge_window: bool,
+    /// File indices chosen in the Merge Logs window: the time base and the
+    /// file whose channels are added
+    pub(crate) merge_selection: (usize, usize),
+    /// Cached WOT pulls per file index
+    pub(crate) pull_results: HashMap<usize, Vec<Pull>>,
+    /// Pulls overlaid in the RPM-aligned comparison plot
+    pub(crate) compared_pulls: Vec<ComparedPull>,
+    /// Channel compared across pulls (original or normalized name)
+    pub(crate) pull_compare_channel: String,
+    /// Live data connection settings
+    pub(crate) live_config: LiveConfig,
+    /// Maximum number of channels selected per tab
+    pub(crate) max_channels: usize,
+    /// Running live data acquisition, if connected
+    pub(crate) live_session: Option<LiveSession>,
+    /// Path of the loaded file receiving live samples
+    pub(crate) live_path: Option<PathBuf>,
+    /// Whether to show the live data window
+    pub(crate) show_live_window: bool,
+    /// File shown in the file info window, if open
+    pub(crate) file_info_index: Option<usize>,
+    /// Whether to show the preferences window
+    pub(crate) show_settings_window: bool,
+    pub(crate) show_log_console: bool,
+    /// Least severe level shown in the log console
+    pub(crate) log_console_level: tracing::Level,
+    /// Reports left by earlier crashes, offered one at a time on launch
+    pub(crate) crash_reports: Vec<PathBuf>,
+    /// Failed loads awaiting the error dialog, oldest first
+    pub(crate) load_errors: Vec<(PathBuf, LoadError)>,
+    /// Page shown in the preferences window
+    pub(crate) settings_category: SettingsCategory,
+    /// UI scale relative to the monitor's scale factor (1.0 = 100%)
+    pub(crate) ui_scale: f32,
+    /// Color theme
+    pub(crate) theme: Theme,
+    /// Points drawn per channel across the chart's width
+    pub(crate) max_chart_points: usize,
+    /// Size above which MLG files are memory-mapped (MB)
+    pub(crate) mmap_threshold_mb: u64,
+    /// Whether logs recorded faster than `decimate_rate_hz` are decimated
+    /// when they are opened
+    pub(crate) decimate_on_import: bool,
+    /// Rate dense logs are decimated to (Hz)
+    pub(crate) decimate_rate_hz: f64,
+    /// Cached alarm evaluation results per file index
+    pub(crate) alarm_results: HashMap<usize, Vec<RuleResult>>,
+    /// Unit preferences the cached alarm results were evaluated with
+    pub(crate) alarm_results_units: UnitPreferences,
     // === Tool/View Selection ===
     /// Currently active tool/view
     pub(crate) active_tool: ActiveTool,
@@ -92,32 +288,70 @@ pub struct UltraLogApp {
     pub(crate) tabs: Vec<Tab>,
     /// Index of the currently active tab
     pub(crate) active_tab: Option<usize>,
+    /// Id of the tab shown next to the active tab in split view
+    pub(crate) split_tab: Option<u64>,
+    /// Arrangement of the split view's panes
+    pub(crate) split_layout: SplitLayout,
+    /// When true, moving either split pane's cursor moves the other's
+    pub(crate) link_split_cursors: bool,
+    /// When true, moving the cursor in one tab moves it in every tab
+    pub(crate) link_all_cursors: bool,
+    /// Each tab's cursor time when cursors were last linked, by tab id
+    linked_cursors_seen: Vec<(u64, Option<f64>)>,
     // === Auto-Update ===
     /// Current state of the update checker
     pub(crate) update_state: UpdateState,
     /// Receiver for update check results from background thread
     update_check_receiver: Option<Receiver<UpdateCheckResult>>,
     /// Receiver for download results from background thread
-    update_download_receiver: Option<Receiver<DownloadResult>>,
+    update_download_receiver: Option<Receiver<DownloadMessage>>,
+    /// Set to stop the update download in progress
+    update_download_cancel: Arc<AtomicBool>,
     /// Whether to show the update available dialog
     pub(crate) show_update_dialog: bool,
+    /// Whether the user agreed to download an update that has no checksum
+    pub(crate) allow_unverified_update: bool,
     /// User preference: check for updates on startup
     pub(crate) auto_check_updates: bool,
+    /// Versions the user chose to skip; startup checks don't offer them
+    pub(crate) skipped_versions: Vec<String>,
+    /// Proxy URL for update checks and downloads (empty: from the environment)
+    pub(crate) update_proxy: String,
+    /// Whether prereleases are offered as updates
+    pub(crate) update_channel: UpdateChannel,
+    /// Outcome of the last update check, shown in the Help menu
+    pub(crate) update_status: Option<String>,
+    /// Cache parsed text logs on disk so they reopen quickly
+    pub(crate) cache_parsed_logs: bool,
     /// Whether the startup check has been performed
     startup_check_done: bool,
 }
 
 impl Default for UltraLogApp {
     fn default() -> Self {
+        let (load_sender, load_receiver) = channel();
         Self {
             files: Vec::new(),
             selected_file: None,
             toast_message: None,
-            last_drop_time: None,
-            load_receiver: None,
+            load_sender,
+            load_receiver,
+            next_load_id: 0,
+            load_queue: VecDeque::new(),
+            pending_session_tabs: Vec::new(),
+            pending_session_active: None,
+            pending_reloads: Vec::new(),
             loading_state: LoadingState::Idle,
+            triage: None,
             downsample_cache: HashMap::new(),
+            view_downsample_cache: HashMap::new(),
+            domain_view_cache: HashMap::new(),
             minmax_cache: HashMap::new(),
+            empty_channel_cache: HashMap::new(),
+            chart_view_range: None,
+            follow_watcher: None,
+            watched_paths: HashSet::new(),
+            follow_changed: Arc::new(AtomicBool::new(false)),
             cursor_time: None,
             time_range: None,
             cursor_record: None,
@@ -127,31 +361,110 @@ impl Default for UltraLogApp {
             last_frame_time: None,
             playback_speed: 1.0,
             color_blind_mode: false,
+            high_contrast: false,
+            line_width_scale: 1.0,
+            language: Language::default(),
+            wall_clock_time: false,
             field_normalization: true, // Enabled by default for better readability
+            show_original_names: false,
+            hide_empty_channels: false,
             initial_view_seconds: 60.0, // Start with 60 second view
             unit_preferences: UnitPreferences::default(),
+            unit_overrides: HashMap::new(),
             custom_normalizations: HashMap::new(),
             show_normalization_editor: false,
             norm_editor_extend_source: String::new(),
             norm_editor_selected_target: None,
             norm_editor_custom_source: String::new(),
             norm_editor_custom_target: String::new(),
+            alarm_rules: Vec::new(),
+            show_alarms_window: false,
+            show_find_value_window: false,
+            find_condition: Condition::default(),
+            find_matches: None,
+            show_annotations_window: false,
+            dyno_config: DynoConfig::default(),
+            pull_config: PullConfig::default(),
+            show_pulls_window: false,
+            show_boost_window: false,
+            fuel_trim_config: FuelTrimConfig::default(),
+            show_fuel_trim_window: false,
+            fuel_trim_summary: None,
+            misfire_config: MisfireConfig::default(),
+            show_misfire_window: false,
+            misfire_scan: None,
+            egt_spread_config: EgtSpreadConfig::default(),
+            show_egt_spread_window: false,
+            egt_spread_scan: None,
+            idle_config: IdleConfig::default(),
+            show_idle_window: false,
+            idle_scan: None,
+            show_drag_window: false,
+            drag_scan: None,
+            shift_config: ShiftConfig::default(),
+            show_shifts_window: false,
+            show_shift_markers: false,
+            shift_scan: None,
+            report_config: ReportConfig::default(),
+            show_report_window: false,
+            report_title: String::new(),
+            show_merge_window: false,
+            merge_selection: (0, 1),
+            pull_results: HashMap::new(),
+            compared_pulls: Vec::new(),
+            pull_compare_channel: "MAP".to_string(),
+            live_config: LiveConfig::default(),
+            max_channels: DEFAULT_MAX_CHANNELS,
+            live_session: None,
+            live_path: None,
+            show_live_window: false,
+            file_info_index: None,
+            show_settings_window: false,
+            show_log_console: false,
+            log_console_level: tracing::Level::INFO,
+            crash_reports: Vec::new(),
+            load_errors: Vec::new(),
+            settings_category: SettingsCategory::default(),
+            ui_scale: 1.0,
+            theme: Theme::default(),
+            max_chart_points: MAX_CHART_POINTS,
+            mmap_threshold_mb: DEFAULT_MMAP_THRESHOLD_MB,
+            decimate_on_import: false,
+            decimate_rate_hz: decimate::DEFAULT_RATE_HZ,
+            cache_last_used: HashMap::new(),
+            cache_frame: 0,
+            cache_budget_mb: DEFAULT_CACHE_BUDGET_MB,
+            alarm_results: HashMap::new(),
+            alarm_results_units: UnitPreferences::default(),
             active_tool: ActiveTool::default(),
             tabs: Vec::new(),
             active_tab: None,
+            split_tab: None,
+            split_layout: SplitLayout::default(),
+            link_split_cursors: true,
+            link_all_cursors: false,
+            linked_cursors_seen: Vec::new(),
             update_state: UpdateState::default(),
             update_check_receiver: None,
             update_download_receiver: None,
+            update_download_cancel: Arc::new(AtomicBool::new(false)),
             show_update_dialog: false,
+            allow_unverified_update: false,
             auto_check_updates: true, // Enabled by default
+            skipped_versions: Vec::new(),
+            update_proxy: String::new(),
+            update_channel: UpdateChannel::default(),
+            update_status: None,
+            cache_parsed_logs: true,
             startup_check_done: false,
         }
     }
 }
 
 impl UltraLogApp {
-    /// Create a new UltraLogApp instance with custom fonts
-    pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+    /// Create a new UltraLogApp instance with custom fonts, opening any log
+    /// or session files passed on the command line
+    pub fn new(cc: &eframe::CreationContext<'_>, startup_files: Vec<PathBuf>) -> Self {
         // Load custom Outfit font
         let mut fonts = egui::FontDefinitions::default();
 
@@ -184,13 +497,171 @@ impl UltraLogApp {
         // Apply fonts
         cc.egui_ctx.set_fonts(fonts);
 
-        Self::default()
+        let mut app = Self::default();
+
+        // Restore persisted settings
+        if let Some(settings) = cc
+            .storage
+            .and_then(|storage| eframe::get_value::<PersistedSettings>(storage, SETTINGS_KEY))
+        {
+            app.alarm_rules = settings.alarm_rules;
+            app.dyno_config = settings.dyno_config;
+            app.pull_config = settings.pull_config;
+            app.fuel_trim_config = settings.fuel_trim_config;
+            app.misfire_config = settings.misfire_config;
+            app.egt_spread_config = settings.egt_spread_config;
+            app.idle_config = settings.idle_config;
+            app.shift_config = settings.shift_config;
+            app.show_shift_markers = settings.show_shift_markers;
+            app.report_config = settings.report_config;
+            app.live_config = settings.live_config;
+            app.max_channels = settings.max_channels.clamp(1, MAX_CHANNELS_LIMIT);
+            app.custom_normalizations = settings.custom_normalizations;
+            app.unit_overrides = settings.unit_overrides;
+            app.cache_parsed_logs = settings.cache_parsed_logs;
+            app.ui_scale = settings.ui_scale.clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+            app.auto_check_updates = settings.auto_check_updates;
+            app.skipped_versions = settings.skipped_versions;
+            app.update_proxy = settings.update_proxy;
+            app.update_channel = settings.update_channel;
+            app.initial_view_seconds = settings.initial_view_seconds.max(1.0);
+            app.color_blind_mode = settings.color_blind_mode;
+            app.high_contrast = settings.high_contrast;
+            app.line_width_scale = settings
+                .line_width_scale
+                .clamp(MIN_LINE_WIDTH_SCALE, MAX_LINE_WIDTH_SCALE);
+            app.language = settings.language;
+            app.wall_clock_time = settings.wall_clock_time;
+            app.unit_preferences = settings.unit_preferences;
+            app.theme = settings.theme;
+            app.max_chart_points = settings
+                .max_chart_points
+                .clamp(MIN_CHART_POINTS, MAX_CHART_POINTS_LIMIT);
+            app.mmap_threshold_mb = settings.mmap_threshold_mb;
+            app.decimate_on_import = settings.decimate_on_import;
+            app.decimate_rate_hz = settings.decimate_rate_hz;
+            app.cache_budget_mb = settings.cache_budget_mb;
+        }
+        // egui multiplies this with each monitor's own scale factor
+        cc.egui_ctx.set_zoom_factor(app.ui_scale);
+
+        app.crash_reports = crash::pending_reports();
+        app.open_paths(startup_files);
+
+        app
+    }
+
+    /// Open files given on the command line (or by the OS file association)
+    /// or dropped on the window: sessions are restored, everything else is
+    /// queued as a log
+    fn open_paths(&mut self, paths: Vec<PathBuf>) {
+        let (sessions, logs): (Vec<PathBuf>, Vec<PathBuf>) = paths.into_iter().partition(|p| {
+            p.extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case(SESSION_EXTENSION))
+        });
+
+        if let Some(session) = sessions.first() {
+            self.open_session(session);
+        }
+        self.queue_files(logs);
     }
 
     // ========================================================================
     // Color and Unit Helpers
     // ========================================================================
 
+    /// Unit preferences for the active tab: its overrides if it has any,
+    /// otherwise the global defaults
+    pub fn active_unit_preferences(&self) -> &UnitPreferences {
+        self.active_tab
+            .and_then(|idx| self.tabs.get(idx))
+            .and_then(|tab| tab.unit_overrides.as_ref())
+            .unwrap_or(&self.unit_preferences)
+    }
+
+    /// Unit preferences edited by the Units menu: the active tab's overrides
+    /// if it has any, otherwise the global defaults
+    pub fn active_unit_preferences_mut(&mut self) -> &mut UnitPreferences {
+        let overrides = self
+            .active_tab
+            .and_then(|idx| self.tabs.get_mut(idx))
+            .and_then(|tab| tab.unit_overrides.as_mut());
+        match overrides {
+            Some(units) => units,
+            None => &mut self.unit_preferences,
+        }
+    }
+
+    /// Start or stop overriding the global unit preferences in the active tab.
+    /// New overrides start from the global defaults.
+    pub fn set_tab_unit_override(&mut self, enabled: bool) {
+        let global = self.unit_preferences.clone();
+        if let Some(tab) = self.active_tab.and_then(|idx| self.tabs.get_mut(idx)) {
+            tab.unit_overrides = enabled.then_some(global);
+        }
+    }
+
+    /// Color of a selected channel: its own color if the user picked one,
+    /// otherwise its palette color
+    pub fn selected_channel_color(&self, selected: &SelectedChannel) -> [u8; 3] {
+        selected
+            .style
+            .color
+            .unwrap_or_else(|| self.get_channel_color(selected.color_index))
+    }
+
+    /// Set the color and line style of one of the active tab's selected channels
+    pub fn set_channel_style(&mut self, index: usize, style: ChannelStyle) {
+        let Some(tab_idx) = self.active_tab else {
+            return;
+        };
+        if let Some(selected) = self.tabs[tab_idx].selected_channels.get_mut(index) {
+            selected.style = style;
+        }
+    }
+
+    /// Set the unit of an ECUMaster channel, or restore the inferred unit with
+    /// `None`. Overrides are keyed by channel path, so they apply to every log
+    /// recording that channel.
+    pub fn set_unit_override(&mut self, path: &str, unit: Option<String>) {
+        match unit.map(|u| u.trim().to_string()) {
+            Some(unit) if !unit.is_empty() => {
+                self.unit_overrides.insert(path.to_string(), unit);
+            }
+            _ => {
+                self.unit_overrides.remove(path);
+            }
+        }
+
+        // Derived channels depend on input units (e.g. pulse width in us or ms)
+        for file in &mut self.files {
+            file.log.apply_unit_overrides(&self.unit_overrides);
+            derived::add_derived_channels(&mut file.log);
+        }
+        self.downsample_cache.clear();
+        self.view_downsample_cache.clear();
+        self.domain_view_cache.clear();
+        self.minmax_cache.clear();
+        self.empty_channel_cache.clear();
+
+        // Selected channels hold copies of their channel definitions
+        let files = &self.files;
+        for tab in &mut self.tabs {
+            tab.selected_channels.retain_mut(|selected| {
+                let channel = files
+                    .get(selected.file_index)
+                    .and_then(|f| f.log.channels.get(selected.channel_index));
+                match channel {
+                    Some(channel) => {
+                        selected.channel = channel.clone();
+                        true
+                    }
+                    None => false,
+                }
+            });
+        }
+    }
+
     /// Get color for a channel based on color blind mode setting
     pub fn get_channel_color(&self, color_index: usize) -> [u8; 3] {
         let palette = if self.color_blind_mode {
@@ -207,41 +678,237 @@ impl UltraLogApp {
 
     /// Start loading a file in the background
     pub fn start_loading_file(&mut self, path: PathBuf) {
+        let cache = self
+            .cache_parsed_logs
+            .then(LogCache::default_location)
+            .flatten();
+        let mmap_threshold = self.mmap_threshold_mb * 1024 * 1024;
+        self.spawn_load(path, move |path, progress| {
+            Self::load_file_sync(path, cache.as_ref(), mmap_threshold, progress)
+        });
+    }
+
+    /// Delete every cached parsed log
+    pub fn clear_log_cache(&mut self) {
+        let result = LogCache::default_location().map_or(Ok(()), |cache| cache.clear());
+        match result {
+            Ok(()) => self.show_toast_success("Log cache cleared"),
+            Err(e) => self.show_toast_error(&format!("Failed to clear log cache: {}", e)),
+        }
+    }
+
+    /// Queue several files to be loaded in parallel, each in its own tab.
+    /// Files that are already loaded, loading, or queued are skipped.
+    pub fn queue_files(&mut self, paths: Vec<PathBuf>) {
+        for path in paths {
+            let already_known = self.files.iter().any(|f| f.path == path)
+                || self.loading_state.loads().iter().any(|l| l.path == path)
+                || self.load_queue.contains(&path);
+            if !already_known {
+                self.load_queue.push_back(path);
+            }
+        }
+        self.start_next_queued_load();
+    }
+
+    /// Start queued loads until all parallel load slots are busy
+    fn start_next_queued_load(&mut self) {
+        while self.loading_state.loads().len() < MAX_PARALLEL_LOADS {
+            let Some(next) = self.load_queue.pop_front() else {
+                break;
+            };
+            self.start_loading_file(next);
+        }
+    }
+
+    /// Start loading a file in the background with the generic CSV parser,
+    /// using the delimiter and column layout chosen in the triage dialog
+    pub fn start_loading_generic_csv(&mut self, path: PathBuf, parser: GenericCsv) {
+        self.spawn_load(path, move |path, progress| {
+            Self::load_generic_csv_sync(path, parser, progress)
+        });
+    }
+
+    /// Run a loader for a path on the background thread pool. The loader
+    /// receives a callback for reporting bytes processed.
+    fn spawn_load<F>(&mut self, path: PathBuf, loader: F)
+    where
+        F: FnOnce(PathBuf, &dyn Fn(u64)) -> LoadResult + Send + 'static,
+    {
         // Check for duplicate
         if self.files.iter().any(|f| f.path == path) {
             self.show_toast_warning("File already loaded");
             return;
         }
+        if self.loading_state.loads().iter().any(|l| l.path == path) {
+            self.show_toast_warning("File is already loading");
+            return;
+        }
 
         let filename = path
             .file_name()
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_else(|| "Unknown".to_string());
 
-        self.loading_state = LoadingState::Loading(filename.clone());
+        let id = self.next_load_id;
+        self.next_load_id += 1;
+        let bytes_total = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+
+        let progress = LoadProgress {
+            id,
+            path: path.clone(),
+            name: filename,
+            bytes_done: 0,
+            bytes_total,
+        };
+        match &mut self.loading_state {
+            LoadingState::LoadingMany(loads) => loads.push(progress),
+            LoadingState::Idle => self.loading_state = LoadingState::LoadingMany(vec![progress]),
+        }
 
-        let (sender, receiver): (Sender<LoadResult>, Receiver<LoadResult>) = channel();
-        self.load_receiver = Some(receiver);
+        self.update_crash_context();
 
-        // Spawn background thread for loading
-        thread::spawn(move || {
-            let result = Self::load_file_sync(path);
-            let _ = sender.send(result);
+        let sender = self.load_sender.clone();
+        rayon::spawn(move || {
+            let report = |bytes_done: u64| {
+                let _ = sender.send(LoadMessage::Progress {
+                    id,
+                    bytes_done,
+                    bytes_total,
+                });
+            };
+            let result = loader(path, &report);
+            let _ = sender.send(LoadMessage::Done { id, result });
         });
     }
 
+    /// Read a whole file in chunks, reporting bytes read after each chunk
+    fn read_with_progress(path: &Path, progress: &dyn Fn(u64)) -> std::io::Result<Vec<u8>> {
+        const CHUNK_SIZE: usize = 1024 * 1024;
+
+        let mut file = File::open(path)?;
+        let size = file.metadata().map(|m| m.len() as usize).unwrap_or(0);
+        let mut data = Vec::with_capacity(size);
+        let mut chunk = vec![0u8; CHUNK_SIZE];
+        loop {
+            let read = file.read(&mut chunk)?;
+            if read == 0 {
+                break;
+            }
+            data.extend_from_slice(&chunk[..read]);
+            progress(data.len() as u64);
+        }
+        Ok(data)
+    }
+
+    /// Synchronously load a file with the generic CSV parser (runs in background thread)
+    fn load_generic_csv_sync(
+        path: PathBuf,
+        parser: GenericCsv,
+        progress: &dyn Fn(u64),
+    ) -> LoadResult {
+        let binary_data = match Self::read_with_progress(&path, progress) {
+            Ok(d) => d,
+            Err(e) => return LoadResult::Error(format!("Failed to read file: {}", e).into()),
+        };
+
+        let contents = String::from_utf8_lossy(&binary_data);
+        let log = match parser.parse(&contents) {
+            Ok(l) => l,
+            Err(e) => {
+                return LoadResult::Error(LoadError::parse(
+                    EcuType::Generic,
+                    "Failed to parse CSV file",
+                    e.as_ref(),
+                ))
+            }
+        };
+
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        LoadResult::Success(Box::new(LoadedFile {
+            path,
+            name,
+            ecu_type: EcuType::Generic,
+            log,
+            tail: Some(LogTail::new(binary_data.len() as u64)),
+            following: false,
+            time_offset: 0.0,
+            runs: Vec::new(),
+            run: 0,
+        }))
+    }
+
+    /// Load a file on the calling thread without the GUI (used by
+    /// `ultralog convert`). Files no parser recognises fall back to the
+    /// generic CSV parser with a guessed delimiter.
+    pub fn load_file_blocking(path: PathBuf) -> Result<LoadedFile, String> {
+        let mmap_threshold = DEFAULT_MMAP_THRESHOLD_MB * 1024 * 1024;
+        let result = match Self::load_file_sync(path, None, mmap_threshold, &|_| {}) {
+            LoadResult::Unsupported(file) => {
+                let parser = GenericCsv {
+                    delimiter: GenericCsv::guess_delimiter(&String::from_utf8_lossy(&file.preview)),
+                    ..Default::default()
+                };
+                Self::load_generic_csv_sync(file.path, parser, &|_| {})
+            }
+            result => result,
+        };
+
+        match result {
+            LoadResult::Success(file) => Ok(*file),
+            LoadResult::Error(e) => Err(e.to_string()),
+            LoadResult::Unsupported(file) => Err(file.reason),
+        }
+    }
+
     /// Synchronously load a file (runs in background thread)
-    /// Uses memory-mapped files for large files (>10MB) for better performance.
-    fn load_file_sync(path: PathBuf) -> LoadResult {
-        // Use memory mapping for large files (>10MB) to reduce memory pressure
-        const MMAP_THRESHOLD: u64 = 10 * 1024 * 1024;
+    /// Text logs are parsed as a stream; binary logs larger than
+    /// `mmap_threshold` bytes use memory-mapped files for better performance.
+    /// With a cache, text logs parsed before are read back from it and new
+    /// ones are added to it.
+    fn load_file_sync(
+        path: PathBuf,
+        cache: Option<&LogCache>,
+        mmap_threshold: u64,
+        progress: &dyn Fn(u64),
+    ) -> LoadResult {
+        // Remember how far the parsers read so follow mode can continue from there
+        let consumed = std::cell::Cell::new(0u64);
+        let progress = &|bytes: u64| {
+            consumed.set(bytes);
+            progress(bytes);
+        };
+
+        // Bytes read up front for format detection
+        const DETECT_BYTES: u64 = 256 * 1024;
 
         // Get file metadata to check size
         let file_size = match fs::metadata(&path) {
             Ok(meta) => meta.len(),
-            Err(e) => return LoadResult::Error(format!("Failed to read file metadata: {}", e)),
+            Err(e) => {
+                return LoadResult::Error(format!("Failed to read file metadata: {}", e).into())
+            }
         };
 
+        if let Some(cached) = cache.and_then(|cache| cache.load(&path)) {
+            progress(file_size);
+            return LoadResult::Success(Box::new(LoadedFile {
+                name: Self::file_display_name(&path),
+                tail: Some(LogTail::new(cached.consumed)),
+                path,
+                ecu_type: cached.ecu_type,
+                log: cached.log,
+                following: false,
+                time_offset: 0.0,
+                runs: Vec::new(),
+                run: 0,
+            }));
+        }
+
         // Check for Link .llg format - proprietary format (check by extension since header varies)
         if let Some(ext) = path.extension() {
             if ext.to_string_lossy().to_lowercase() == "llg" {
@@ -251,201 +918,621 @@ impl UltraLogApp {
                     1. Open the .llg file in PCLink or G4+ software\n\
                     2. Go to File → Export → CSV\n\
                     3. Load the exported .csv file in UltraLog"
-                        .to_string(),
+                        .to_string()
+                        .into(),
+                );
+            }
+            // Holley EFI datalogs - undocumented binary format
+            if matches!(ext.to_string_lossy().to_lowercase().as_str(), "dl" | "dlz") {
+                return LoadResult::Error(
+                    "This is a Holley EFI datalog which uses a proprietary format.\n\n\
+                    To use this log in UltraLog, please export it as CSV from Holley's software:\n\
+                    1. Open the datalog in Holley EFI V1/V2/V3 software\n\
+                    2. Go to File → Export → CSV\n\
+                    3. Load the exported .csv file in UltraLog"
+                        .to_string()
+                        .into(),
                 );
             }
         }
 
-        // Load file data - use mmap for large files, regular read for small files
-        let (log, ecu_type) = if file_size > MMAP_THRESHOLD {
-            // Use memory-mapped file for large files
-            match Self::load_with_mmap(&path) {
-                Ok(result) => result,
-                Err(e) => return e,
+        // Read the start of the file to detect its format
+        let mut head = Vec::new();
+        if let Err(e) = File::open(&path).and_then(|f| f.take(DETECT_BYTES).read_to_end(&mut head))
+        {
+            return LoadResult::Error(format!("Failed to read file: {}", e).into());
+        }
+        if let Some(error) = Self::proprietary_format_error(&head) {
+            return error;
+        }
+
+        let loaded = if Speeduino::detect(&head) {
+            // Binary MLG - use mmap for large files, regular read for small files
+            if file_size > mmap_threshold {
+                Self::load_with_mmap(&path)
+            } else {
+                Self::load_with_read(&path, progress)
             }
+        } else if MegaSquirt::detect(&head) {
+            Self::load_frd(&path, progress)
         } else {
-            // Use regular file read for small files
-            match Self::load_with_read(&path) {
-                Ok(result) => result,
-                Err(e) => return e,
-            }
+            // Text formats are parsed line-by-line straight from disk
+            Self::load_text_streaming(&path, &head, progress)
+        };
+        let (log, ecu_type) = match loaded {
+            Ok(result) => result,
+            Err(e) => return e,
         };
 
-        let name = path
-            .file_name()
-            .map(|n| n.to_string_lossy().to_string())
-            .unwrap_or_else(|| "Unknown".to_string());
+        if let Some(cache) = cache {
+            if let Err(e) = cache.store(&path, &log, consumed.get()) {
+                tracing::warn!("Failed to cache parsed log {}: {}", path.display(), e);
+            }
+        }
+
+        let name = Self::file_display_name(&path);
+        let tail = log.supports_append().then(|| LogTail::new(consumed.get()));
 
         LoadResult::Success(Box::new(LoadedFile {
             path,
             name,
             ecu_type,
             log,
+            tail,
+            following: false,
+            time_offset: 0.0,
+            runs: Vec::new(),
+            run: 0,
         }))
     }
 
+    /// File name shown in the sidebar and tab bar
+    fn file_display_name(path: &Path) -> String {
+        path.file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Unknown".to_string())
+    }
+
     /// Load file using memory-mapped I/O for better performance with large files
     fn load_with_mmap(path: &PathBuf) -> Result<(crate::parsers::Log, EcuType), LoadResult> {
         let file = match File::open(path) {
             Ok(f) => f,
-            Err(e) => return Err(LoadResult::Error(format!("Failed to open file: {}", e))),
+            Err(e) => {
+                return Err(LoadResult::Error(
+                    format!("Failed to open file: {}", e).into(),
+                ))
+            }
         };
 
         // SAFETY: The file is opened read-only and we don't modify it.
-        // The mapping is dropped after parsing completes.
+        // The mapping is kept alive by the log so channels can be decoded on demand.
         let mmap = match unsafe { Mmap::map(&file) } {
             Ok(m) => m,
             Err(e) => {
-                return Err(LoadResult::Error(format!(
-                    "Failed to memory-map file: {}",
-                    e
-                )))
+                return Err(LoadResult::Error(
+                    format!("Failed to memory-map file: {}", e).into(),
+                ))
             }
         };
 
-        Self::parse_binary_data(&mmap, path)
+        // Large logs only index their records up front; each channel is
+        // decoded the first time it is viewed
+        match Speeduino::parse_binary_lazy(mmap) {
+            Ok(l) => Ok((l, EcuType::Speeduino)),
+            Err(e) => Err(LoadResult::Error(LoadError::parse(
+                EcuType::Speeduino,
+                "Failed to parse Speeduino/rusEFI MLG file",
+                e.as_ref(),
+            ))),
+        }
     }
 
     /// Load file using regular file read (for smaller files)
-    fn load_with_read(path: &PathBuf) -> Result<(crate::parsers::Log, EcuType), LoadResult> {
-        let binary_data = match fs::read(path) {
+    fn load_with_read(
+        path: &Path,
+        progress: &dyn Fn(u64),
+    ) -> Result<(crate::parsers::Log, EcuType), LoadResult> {
+        let binary_data = match Self::read_with_progress(path, progress) {
             Ok(d) => d,
-            Err(e) => return Err(LoadResult::Error(format!("Failed to read file: {}", e))),
+            Err(e) => {
+                return Err(LoadResult::Error(
+                    format!("Failed to read file: {}", e).into(),
+                ))
+            }
         };
 
-        Self::parse_binary_data(&binary_data, path)
+        Self::parse_mlg_data(&binary_data)
     }
 
-    /// Parse binary data and detect file format
-    fn parse_binary_data(
-        binary_data: &[u8],
-        path: &PathBuf,
-    ) -> Result<(crate::parsers::Log, EcuType), LoadResult> {
+    /// Detect proprietary formats from the start of a file and explain how to
+    /// export them to a supported format
+    fn proprietary_format_error(head: &[u8]) -> Option<LoadResult> {
         // Check for Haltech HEPS format (.hlgzip) - proprietary compressed format
-        if binary_data.len() >= 4 && &binary_data[0..4] == b"HEPS" {
-            return Err(LoadResult::Error(
+        if head.len() >= 4 && &head[0..4] == b"HEPS" {
+            return Some(LoadResult::Error(
                 "This is a Haltech .hlgzip file which uses proprietary compression.\n\n\
                 To use this log in UltraLog, please export it as CSV from Haltech's ESP or NSP software:\n\
                 1. Open the .hlgzip file in Haltech ESP/NSP\n\
                 2. Go to File → Export → CSV\n\
                 3. Load the exported .csv file in UltraLog"
-                    .to_string(),
+                    .to_string()
+                    .into(),
             ));
         }
 
         // Check for AEM .daq format - proprietary format (starts with "EMERALD")
-        if binary_data.len() >= 7 && &binary_data[0..7] == b"EMERALD" {
-            return Err(LoadResult::Error(
+        if head.len() >= 7 && &head[0..7] == b"EMERALD" {
+            return Some(LoadResult::Error(
                 "This is an AEM .daq file which uses a proprietary format.\n\n\
                 To use this log in UltraLog, please export it as CSV from AEM's software:\n\
                 1. Open the .daq file in AEMdata or AEM Pro\n\
                 2. Go to File → Export → CSV\n\
                 3. Load the exported .csv file in UltraLog"
-                    .to_string(),
+                    .to_string()
+                    .into(),
             ));
         }
 
-        // Auto-detect file format and parse
-        if Speeduino::detect(binary_data) {
-            // Speeduino/rusEFI MLG format detected (binary)
-            match Speeduino::parse_binary(binary_data) {
-                Ok(l) => Ok((l, EcuType::Speeduino)),
-                Err(e) => Err(LoadResult::Error(format!(
-                    "Failed to parse Speeduino/rusEFI MLG file: {}",
-                    e
-                ))),
+        None
+    }
+
+    /// Parse a Speeduino/rusEFI MLG binary log
+    fn parse_mlg_data(binary_data: &[u8]) -> Result<(crate::parsers::Log, EcuType), LoadResult> {
+        match Speeduino::parse_binary(binary_data) {
+            Ok(l) => Ok((l, EcuType::Speeduino)),
+            Err(e) => Err(LoadResult::Error(LoadError::parse(
+                EcuType::Speeduino,
+                "Failed to parse Speeduino/rusEFI MLG file",
+                e.as_ref(),
+            ))),
+        }
+    }
+
+    /// Load a MegaSquirt FRD log, decoding its records with the ECU's INI
+    /// file found next to the log
+    fn load_frd(
+        path: &Path,
+        progress: &dyn Fn(u64),
+    ) -> Result<(crate::parsers::Log, EcuType), LoadResult> {
+        let Some(ini_path) = MegaSquirt::find_ini(path) else {
+            return Err(LoadResult::Error(
+                "This is a MegaSquirt .frd log, which holds raw data blocks without channel names.\n\n\
+                To read it, UltraLog needs the ECU's TunerStudio INI file:\n\
+                1. Copy the INI for the firmware that recorded the log into the log's folder\n\
+                2. Name it like the log (e.g. datalog.ini for datalog.frd), or keep it the only INI there\n\
+                3. Load the .frd file again, or export it as .msl from MegaLogViewer instead"
+                    .to_string()
+                    .into(),
+            ));
+        };
+        let layout = match fs::read(&ini_path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| OutputLayout::from_ini(&String::from_utf8_lossy(&bytes)))
+        {
+            Ok(layout) => layout,
+            Err(e) => {
+                return Err(LoadResult::Error(
+                    format!("Failed to read INI {}: {}", ini_path.display(), e).into(),
+                ))
             }
-        } else {
-            // Try parsing as text-based formats
-            // For mmap, we use from_utf8 which doesn't copy the data
-            let contents = match std::str::from_utf8(binary_data) {
-                Ok(c) => c,
-                Err(_) => {
-                    // Fall back to lossy conversion for files with encoding issues
-                    return Self::parse_text_lossy(binary_data, path);
-                }
-            };
+        };
+        let binary_data = match Self::read_with_progress(path, progress) {
+            Ok(d) => d,
+            Err(e) => {
+                return Err(LoadResult::Error(
+                    format!("Failed to read file: {}", e).into(),
+                ))
+            }
+        };
 
-            Self::parse_text_content(contents)
+        match MegaSquirt::parse_frd(&binary_data, &layout) {
+            Ok(l) => Ok((l, EcuType::MegaSquirt)),
+            Err(e) => Err(LoadResult::Error(LoadError::parse(
+                EcuType::MegaSquirt,
+                "Failed to parse MegaSquirt FRD file",
+                e.as_ref(),
+            ))),
         }
     }
 
-    /// Parse text content after UTF-8 validation
-    fn parse_text_content(contents: &str) -> Result<(crate::parsers::Log, EcuType), LoadResult> {
-        if EcuMaster::detect(contents) {
+    /// Detect a text log format from the start of the file, then parse the
+    /// whole file line-by-line from disk with progress reporting
+    fn load_text_streaming(
+        path: &Path,
+        head: &[u8],
+        progress: &dyn Fn(u64),
+    ) -> Result<(crate::parsers::Log, EcuType), LoadResult> {
+        const READ_BUFFER_BYTES: usize = 1024 * 1024;
+
+        let file = match File::open(path) {
+            Ok(f) => f,
+            Err(e) => {
+                return Err(LoadResult::Error(
+                    format!("Failed to open file: {}", e).into(),
+                ))
+            }
+        };
+        let reader = BufReader::with_capacity(READ_BUFFER_BYTES, file);
+
+        let head_text = String::from_utf8_lossy(head);
+        if EcuMaster::detect(&head_text) {
             // ECUMaster format detected
-            let parser = EcuMaster;
-            match parser.parse(contents) {
+            match EcuMaster.parse_reader(reader, progress) {
                 Ok(l) => Ok((l, EcuType::EcuMaster)),
-                Err(e) => Err(LoadResult::Error(format!(
-                    "Failed to parse ECUMaster file: {}",
-                    e
+                Err(e) => Err(LoadResult::Error(LoadError::parse(
+                    EcuType::EcuMaster,
+                    "Failed to parse ECUMaster file",
+                    e.as_ref(),
+                ))),
+            }
+        } else if Cobb::detect(&head_text) {
+            match Cobb.parse_reader(reader, progress) {
+                Ok(l) => Ok((l, EcuType::Cobb)),
+                Err(e) => Err(LoadResult::Error(LoadError::parse(
+                    EcuType::Cobb,
+                    "Failed to parse Cobb Accessport file",
+                    e.as_ref(),
+                ))),
+            }
+        } else if HpTuners::detect(&head_text) {
+            match HpTuners.parse_reader(reader, progress) {
+                Ok(l) => Ok((l, EcuType::HpTuners)),
+                Err(e) => Err(LoadResult::Error(LoadError::parse(
+                    EcuType::HpTuners,
+                    "Failed to parse HP Tuners file",
+                    e.as_ref(),
+                ))),
+            }
+        } else if let Some(profile) = CsvProfile::detect(&head_text) {
+            match ProfileCsv(profile).parse_reader(reader, progress) {
+                Ok(l) => Ok((l, profile.ecu_type)),
+                Err(e) => Err(LoadResult::Error(LoadError::parse(
+                    profile.ecu_type,
+                    &format!("Failed to parse {} file", profile.name),
+                    e.as_ref(),
                 ))),
             }
         } else {
-            // Default to Haltech format
-            let parser = Haltech;
-            match parser.parse(contents) {
-                Ok(l) => Ok((l, EcuType::Haltech)),
-                Err(e) => Err(LoadResult::Error(format!("Failed to parse file: {}", e))),
+            // Default to Haltech format; if that finds nothing, no parser recognised the file
+            match Haltech.parse_reader(reader, progress) {
+                Ok(l) if !l.channels.is_empty() && l.record_count() > 0 => {
+                    Ok((l, EcuType::Haltech))
+                }
+                Ok(_) => Err(Self::unsupported_file(
+                    path,
+                    head,
+                    "No supported log format was detected".to_string(),
+                )),
+                Err(e) => Err(Self::unsupported_file(
+                    path,
+                    head,
+                    format!("Failed to parse file: {}", e),
+                )),
             }
         }
     }
 
-    /// Parse text with lossy UTF-8 conversion for files with encoding issues
-    fn parse_text_lossy(
-        binary_data: &[u8],
-        _path: &PathBuf,
-    ) -> Result<(crate::parsers::Log, EcuType), LoadResult> {
-        let contents = String::from_utf8_lossy(binary_data);
-        Self::parse_text_content(&contents)
+    /// Build the result for a file no parser recognised, keeping a preview for triage
+    fn unsupported_file(path: &Path, binary_data: &[u8], reason: String) -> LoadResult {
+        let preview_len = binary_data.len().min(TRIAGE_PREVIEW_BYTES);
+        LoadResult::Unsupported(Box::new(UnsupportedFile {
+            path: path.to_path_buf(),
+            reason,
+            preview: binary_data[..preview_len].to_vec(),
+        }))
     }
 
-    /// Check for completed background loads
+    /// Apply progress updates and results from background loads
     fn check_loading_complete(&mut self) {
-        if let Some(receiver) = &self.load_receiver {
-            if let Ok(result) = receiver.try_recv() {
-                match result {
-                    LoadResult::Success(file) => {
-                        let file_index = self.files.len();
-                        let file_name = file.name.clone();
-
-                        // Compute time range for this file
-                        let times = file.log.get_times_as_f64();
-                        let file_time_range =
-                            if let (Some(&first), Some(&last)) = (times.first(), times.last()) {
-                                Some((first, last))
-                            } else {
-                                None
-                            };
-
-                        self.files.push(*file);
-                        self.selected_file = Some(file_index);
-                        self.update_time_range();
-
-                        // Create a new tab for this file with its time range
-                        let mut tab = Tab::new(file_index, file_name);
-                        tab.time_range = file_time_range;
-                        // Initialize cursor to start of file
-                        if let Some((min_time, _)) = file_time_range {
-                            tab.cursor_time = Some(min_time);
-                            tab.cursor_record = Some(0);
+        while let Ok(message) = self.load_receiver.try_recv() {
+            match message {
+                LoadMessage::Progress {
+                    id,
+                    bytes_done,
+                    bytes_total,
+                } => {
+                    if let LoadingState::LoadingMany(loads) = &mut self.loading_state {
+                        if let Some(load) = loads.iter_mut().find(|l| l.id == id) {
+                            load.bytes_done = bytes_done;
+                            load.bytes_total = bytes_total;
                         }
-                        self.tabs.push(tab);
-                        self.active_tab = Some(self.tabs.len() - 1);
-
-                        self.show_toast_success("File loaded successfully");
                     }
-                    LoadResult::Error(e) => {
-                        self.show_toast_error(&format!("Error: {}", e));
+                }
+                LoadMessage::Done { id, result } => {
+                    let mut path = None;
+                    if let LoadingState::LoadingMany(loads) = &mut self.loading_state {
+                        path = loads.iter().find(|l| l.id == id).map(|l| l.path.clone());
+                        loads.retain(|l| l.id != id);
+                        if loads.is_empty() {
+                            self.loading_state = LoadingState::Idle;
+                        }
                     }
+                    self.handle_load_result(result, path);
+                    self.update_crash_context();
+
+                    // Continue with any queued files
+                    self.start_next_queued_load();
                 }
-                self.load_receiver = None;
-                self.loading_state = LoadingState::Idle;
             }
         }
     }
 
+    /// Add a finished load as a new tab, or report why it failed
+    pub(crate) fn handle_load_result(&mut self, result: LoadResult, path: Option<PathBuf>) {
+        match result {
+            LoadResult::Success(mut file) => {
+                let original_records = file.log.record_count();
+                let mut runs = std::mem::take(&mut file.log).split_runs();
+                let mut decimated = false;
+                for log in &mut runs {
+                    decimated |=
+                        self.decimate_on_import && decimate::decimate(log, self.decimate_rate_hz);
+                    log.apply_unit_overrides(&self.unit_overrides);
+                    derived::add_derived_channels(log);
+                }
+                let records: usize = runs.iter().map(Log::record_count).sum();
+                let run_count = runs.len();
+                if let Some(first) = runs.first_mut() {
+                    file.log = std::mem::take(first);
+                }
+                if run_count > 1 {
+                    file.runs = runs;
+                    // A multi-log export is a finished download, not a log being written
+                    file.tail = None;
+                }
+                if decimated {
+                    // Appended records would arrive at the full rate, so a
+                    // decimated log can't be followed
+                    file.tail = None;
+                }
+                let file_index = self.files.len();
+                self.files.push(*file);
+                self.update_time_range();
+                let tab_idx = self.open_file_tab(file_index);
+
+                let summary = self.files[file_index].log.skipped_summary();
+                let path = self.files[file_index].path.clone();
+                if self.apply_pending_reload(tab_idx) {
+                    self.show_toast_success("File reloaded");
+                } else if self.apply_pending_session_tab(tab_idx) {
+                    // Further session tabs over the same file get tabs of their own
+                    while self.pending_session_tabs.iter().any(|t| t.path == path) {
+                        let tab_idx = self.open_file_tab(file_index);
+                        self.apply_pending_session_tab(tab_idx);
+                    }
+                } else {
+                    self.show_toast_success("File loaded successfully");
+                }
+                if run_count > 1 {
+                    self.show_toast(&format!(
+                        "The file holds {} logging sessions; pick one in the Files panel",
+                        run_count
+                    ));
+                }
+                if decimated {
+                    self.show_toast(&format!(
+                        "Decimated to {:.0} Hz: kept {} of {} records",
+                        self.decimate_rate_hz,
+                        group_thousands(records),
+                        group_thousands(original_records)
+                    ));
+                }
+                // Damaged records outrank the usual confirmation
+                if let Some(summary) = summary {
+                    self.show_toast_warning(&format!("{} (damaged)", summary));
+                }
+            }
+            LoadResult::Error(e) => {
+                self.load_errors.push((path.unwrap_or_default(), e));
+            }
+            LoadResult::Unsupported(file) => {
+                self.open_triage(*file);
+            }
+        }
+    }
+
+    // ========================================================================
+    // Session Save/Load
+    // ========================================================================
+
+    /// Build a session from the currently open tabs
+    pub fn current_session(&self) -> Session {
+        let tabs = self
+            .tabs
+            .iter()
+            .filter_map(|tab| {
+                let file = self.files.get(tab.file_index)?;
+                Some(SessionTab {
+                    path: file.path.clone(),
+                    channels: tab
+                        .selected_channels
+                        .iter()
+                        .map(|c| c.channel.name())
+                        .collect(),
+                    annotations: tab.annotations.clone(),
+                    time_offset: file.time_offset,
+                    run: file.run,
+                })
+            })
+            .collect();
+
+        Session {
+            tabs,
+            active_tab: self.active_tab,
+            ..Default::default()
+        }
+    }
+
+    /// Save the current session to a file
+    pub fn save_session(&mut self, path: &Path) {
+        match self.current_session().save(path) {
+            Ok(()) => self.show_toast_success("Session saved"),
+            Err(e) => self.show_toast_error(&format!("Failed to save session: {}", e)),
+        }
+    }
+
+    /// Open a session file, loading its log files and restoring each tab
+    pub fn open_session(&mut self, path: &Path) {
+        let session = match Session::load(path) {
+            Ok(session) => session,
+            Err(e) => {
+                self.show_toast_error(&format!("Failed to open session: {}", e));
+                return;
+            }
+        };
+
+        self.pending_session_active = session
+            .active_tab
+            .and_then(|idx| session.tabs.get(idx))
+            .map(|tab| tab.path.clone());
+
+        let mut restored: Vec<PathBuf> = Vec::new();
+        for session_tab in session.tabs {
+            if let Some(file_index) = self.files.iter().position(|f| f.path == session_tab.path) {
+                // Already loaded - restore into its tab directly, or a new
+                // tab for a file the session shows more than once
+                if restored.contains(&session_tab.path) {
+                    self.open_file_tab(file_index);
+                } else {
+                    self.switch_to_file_tab(file_index);
+                    restored.push(session_tab.path.clone());
+                }
+                self.pending_session_tabs.push(session_tab);
+                if let Some(tab_idx) = self.active_tab {
+                    self.apply_pending_session_tab(tab_idx);
+                }
+            } else if self
+                .pending_session_tabs
+                .iter()
+                .any(|t| t.path == session_tab.path)
+            {
+                // Already queued; restored into another tab once loaded
+                self.pending_session_tabs.push(session_tab);
+            } else {
+                self.load_queue.push_back(session_tab.path.clone());
+                self.pending_session_tabs.push(session_tab);
+            }
+        }
+
+        self.start_next_queued_load();
+    }
+
+    /// Restore channels and annotations for a tab whose file matches a pending
+    /// session entry. Returns true if a session entry was applied.
+    fn apply_pending_session_tab(&mut self, tab_idx: usize) -> bool {
+        let file_index = self.tabs[tab_idx].file_index;
+        let path = self.files[file_index].path.clone();
+        let Some(pos) = self
+            .pending_session_tabs
+            .iter()
+            .position(|t| t.path == path)
+        else {
+            return false;
+        };
+        let session_tab = self.pending_session_tabs.remove(pos);
+
+        self.select_run(file_index, session_tab.run);
+        // Annotations were saved with the offset applied
+        self.set_time_offset(file_index, session_tab.time_offset);
+        self.tabs[tab_idx].annotations = session_tab.annotations;
+
+        let previous_active = self.active_tab;
+        self.active_tab = Some(tab_idx);
+        for name in &session_tab.channels {
+            let channel_index = self.files[file_index]
+                .log
+                .channels
+                .iter()
+                .position(|c| c.name() == *name);
+            let already_selected = self.tabs[tab_idx]
+                .selected_channels
+                .iter()
+                .any(|c| Some(c.channel_index) == channel_index);
+            if let (Some(channel_index), false) = (channel_index, already_selected) {
+                self.add_channel(file_index, channel_index);
+            }
+        }
+        self.active_tab = previous_active;
+
+        if self.pending_session_tabs.is_empty() {
+            if let Some(active_path) = self.pending_session_active.take() {
+                if let Some(file_index) = self.files.iter().position(|f| f.path == active_path) {
+                    self.switch_to_file_tab(file_index);
+                }
+            }
+            self.show_toast_success("Session restored");
+        }
+        true
+    }
+
+    /// Replace the fresh tab of a reloaded file with the tabs it had before
+    /// reloading. Returns false if the tab's file isn't being reloaded.
+    fn apply_pending_reload(&mut self, tab_idx: usize) -> bool {
+        let file_index = self.tabs[tab_idx].file_index;
+        let Some(pos) = self
+            .pending_reloads
+            .iter()
+            .position(|r| r.path == self.files[file_index].path)
+        else {
+            return false;
+        };
+        let reload = self.pending_reloads.remove(pos);
+        let fresh = self.tabs.remove(tab_idx);
+        self.select_run(file_index, reload.run);
+        // The old tabs already hold times with the offset applied
+        self.shift_file_times(file_index, reload.time_offset);
+        self.update_time_range();
+        let log = &self.files[file_index].log;
+        let time_range = match (log.times.first(), log.times.last()) {
+            (Some(&first), Some(&last)) => Some((first, last)),
+            _ => None,
+        };
+        let find_channel = |name: &str| log.channels.iter().position(|c| c.name() == name);
+
+        for (position, mut tab, x_axis_channel) in reload.tabs {
+            tab.file_index = file_index;
+            // Channels that no longer exist in the file are dropped
+            tab.selected_channels = std::mem::take(&mut tab.selected_channels)
+                .into_iter()
+                .filter_map(|selected| {
+                    let channel_index = find_channel(&selected.channel.name())?;
+                    Some(SelectedChannel {
+                        file_index,
+                        channel_index,
+                        channel: log.channels[channel_index].clone(),
+                        ..selected
+                    })
+                })
+                .collect();
+            tab.x_axis_channel = x_axis_channel.as_deref().and_then(find_channel);
+            tab.time_range = time_range;
+            match (tab.cursor_time, tab.time_range) {
+                (Some(time), Some((min_time, max_time))) => {
+                    let time = time.clamp(min_time, max_time);
+                    tab.cursor_time = Some(time);
+                    tab.cursor_record = log.record_at_time(time);
+                }
+                _ => {
+                    tab.cursor_time = fresh.cursor_time;
+                    tab.cursor_record = fresh.cursor_record;
+                }
+            }
+            // Channel indices of the other tools may have moved
+            tab.scatter_plot_state = fresh.scatter_plot_state.clone();
+            tab.track_map_state = fresh.track_map_state.clone();
+            tab.dyno_state = fresh.dyno_state.clone();
+            // The tab keeps its id, so the chart keeps its zoom
+            self.tabs.insert(position.min(self.tabs.len()), tab);
+        }
+
+        self.active_tab = reload
+            .active_tab_id
+            .and_then(|id| self.tabs.iter().position(|t| t.id == id))
+            .or_else(|| (!self.tabs.is_empty()).then_some(0));
+        self.selected_file = self.active_tab.map(|idx| self.tabs[idx].file_index);
+        true
+    }
+
     // ========================================================================
     // Time Range and Cursor
     // ========================================================================
@@ -477,34 +1564,10 @@ impl UltraLogApp {
         }
     }
 
-    /// Find the record index closest to the given time
+    /// Find the record of the active tab's file closest to the given time
     pub fn find_record_at_time(&self, time: f64) -> Option<usize> {
-        // Use the first file with data for record indexing
-        if let Some(file) = self.files.first() {
-            let times = file.log.get_times_as_f64();
-            if times.is_empty() {
-                return None;
-            }
-            // Binary search for closest time
-            let mut low = 0;
-            let mut high = times.len() - 1;
-            while low < high {
-                let mid = (low + high) / 2;
-                if times[mid] < time {
-                    low = mid + 1;
-                } else {
-                    high = mid;
-                }
-            }
-            // Check if low or low-1 is closer
-            if low > 0 && (times[low] - time).abs() > (times[low - 1] - time).abs() {
-                Some(low - 1)
-            } else {
-                Some(low)
-            }
-        } else {
-            None
-        }
+        let file_index = self.active_tab.map_or(0, |idx| self.tabs[idx].file_index);
+        self.files.get(file_index)?.log.record_at_time(time)
     }
 
     /// Get value at a specific record index for a channel
@@ -514,13 +1577,51 @@ impl UltraLogApp {
         channel_index: usize,
         record: usize,
     ) -> Option<f64> {
-        if file_index < self.files.len() {
-            let file = &self.files[file_index];
-            if record < file.log.data.len() && channel_index < file.log.data[record].len() {
-                return Some(file.log.data[record][channel_index].as_f64());
+        self.files
+            .get(file_index)?
+            .log
+            .value_at(channel_index, record)
+    }
+
+    /// Convert a channel's value at a record with the active unit preferences.
+    /// Manifold pressure is shown as boost/vacuum when enabled, relative to the
+    /// log's barometric pressure channel if it has one.
+    pub fn convert_channel_value(
+        &self,
+        file_index: usize,
+        channel_index: usize,
+        record: usize,
+        value: f64,
+    ) -> (f64, String) {
+        let units = self.active_unit_preferences();
+        let Some(file) = self.files.get(file_index) else {
+            return (value, String::new());
+        };
+        let Some(channel) = file.log.channels.get(channel_index) else {
+            return (value, String::new());
+        };
+        let source_unit = channel.unit();
+
+        if units.boost != BoostUnit::Absolute
+            && source_unit == "kPa"
+            && normalize_channel_name_with_custom(
+                &channel.name(),
+                Some(&self.custom_normalizations),
+            ) == "MAP"
+        {
+            let baro = file
+                .log
+                .channels
+                .iter()
+                .position(|c| c.unit() == "kPa" && c.name().to_lowercase().contains("baro"))
+                .and_then(|idx| file.log.value_at(idx, record));
+            if let Some((converted, unit)) = units.boost.convert_from_map(value, baro) {
+                return (converted, unit.to_string());
             }
         }
-        None
+
+        let (converted, unit) = units.convert_value(value, source_unit);
+        (converted, unit.to_string())
     }
 
     /// Get min and max values for a channel across all records (cached)
@@ -538,36 +1639,572 @@ impl UltraLogApp {
             file_index,
             channel_index,
         };
-        if let Some(&cached) = self.minmax_cache.get(&cache_key) {
-            return Some(cached);
+        if let Some(&cached) = self.minmax_cache.get(&cache_key) {
+            return Some(cached);
+        }
+
+        // Compute min/max
+        let file = &self.files[file_index];
+        let data = file.log.get_channel_data(channel_index);
+
+        if data.is_empty() {
+            return None;
+        }
+
+        let (min_val, max_val) = data.iter().fold((f64::MAX, f64::MIN), |(min, max), &v| {
+            (min.min(v), max.max(v))
+        });
+
+        // Cache the result
+        self.minmax_cache.insert(cache_key, (min_val, max_val));
+        Some((min_val, max_val))
+    }
+
+    /// Whether a channel holds no data, i.e. is all zero or NaN (cached)
+    pub fn is_channel_empty(&mut self, file_index: usize, channel_index: usize) -> bool {
+        let Some(file) = self.files.get(file_index) else {
+            return false;
+        };
+        *self
+            .empty_channel_cache
+            .entry(CacheKey {
+                file_index,
+                channel_index,
+            })
+            .or_insert_with(|| file.log.is_channel_empty(channel_index))
+    }
+
+    // ========================================================================
+    // File and Channel Management
+    // ========================================================================
+
+    /// Start or stop watching a file for newly written records
+    pub fn set_following(&mut self, file_index: usize, following: bool) {
+        if let Some(file) = self.files.get_mut(file_index) {
+            file.following = following && file.tail.is_some();
+        }
+    }
+
+    /// Read records appended to followed files once the watcher reports a
+    /// change, then invalidate that file's caches and scroll its chart to the
+    /// newest data
+    fn poll_followed_files(&mut self, ctx: &egui::Context) {
+        self.sync_follow_watches(ctx);
+        if !self.follow_changed.swap(false, Ordering::Relaxed) {
+            return;
+        }
+
+        for file_index in 0..self.files.len() {
+            let file = &mut self.files[file_index];
+            let Some(tail) = file.tail.as_mut().filter(|_| file.following) else {
+                continue;
+            };
+
+            let new_bytes = match Self::read_appended(&file.path, tail.offset) {
+                Ok(Some(bytes)) => bytes,
+                Ok(None) => continue,
+                Err(message) => {
+                    file.following = false;
+                    let message = format!("Stopped following {}: {}", file.name, message);
+                    self.show_toast_with_type(&message, ToastType::Warning);
+                    continue;
+                }
+            };
+
+            let lines = tail.push(&new_bytes);
+            let before = file.log.record_count();
+            if file.log.append_lines(&lines) > 0 {
+                let offset = file.time_offset;
+                for time in &mut file.log.times[before..] {
+                    *time += offset;
+                }
+                self.records_appended(file_index);
+            }
+        }
+    }
+
+    /// Register followed files with the file watcher and drop files no longer
+    /// followed. The watcher wakes the UI whenever a watched file is written.
+    fn sync_follow_watches(&mut self, ctx: &egui::Context) {
+        let followed: HashSet<PathBuf> = self
+            .files
+            .iter()
+            .filter(|f| f.following)
+            .map(|f| f.path.clone())
+            .collect();
+        if followed == self.watched_paths {
+            return;
+        }
+
+        if self.follow_watcher.is_none() {
+            let changed = Arc::clone(&self.follow_changed);
+            let ctx = ctx.clone();
+            let watcher = notify::recommended_watcher(
+                move |event: notify::Result<notify::Event>| match event {
+                    Ok(event) if event.kind.is_modify() || event.kind.is_remove() => {
+                        changed.store(true, Ordering::Relaxed);
+                        ctx.request_repaint();
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("File watcher error: {}", e),
+                },
+            );
+            match watcher {
+                Ok(watcher) => self.follow_watcher = Some(watcher),
+                Err(e) => {
+                    for file in &mut self.files {
+                        file.following = false;
+                    }
+                    self.show_toast_error(&format!("Failed to watch files: {}", e));
+                    return;
+                }
+            }
+        }
+        let Some(watcher) = self.follow_watcher.as_mut() else {
+            return;
+        };
+
+        for path in self.watched_paths.difference(&followed) {
+            if let Err(e) = watcher.unwatch(path) {
+                tracing::debug!("Failed to unwatch {}: {}", path.display(), e);
+            }
+        }
+        let mut failed = Vec::new();
+        for path in followed.difference(&self.watched_paths) {
+            if let Err(e) = watcher.watch(path, notify::RecursiveMode::NonRecursive) {
+                failed.push((path.clone(), e));
+            }
+        }
+        self.watched_paths = followed;
+
+        for (path, e) in failed {
+            self.watched_paths.remove(&path);
+            for file in self.files.iter_mut().filter(|f| f.path == path) {
+                file.following = false;
+            }
+            let message = format!("Stopped following {}: {}", path.display(), e);
+            self.show_toast_with_type(&message, ToastType::Warning);
+        }
+
+        // Pick up anything written before the watch started
+        self.follow_changed.store(true, Ordering::Relaxed);
+    }
+
+    /// Change the chart's point budget, dropping downsamples made for the
+    /// old one
+    pub fn set_max_chart_points(&mut self, points: usize) {
+        if points == self.max_chart_points {
+            return;
+        }
+        self.max_chart_points = points;
+        self.downsample_cache.clear();
+        self.view_downsample_cache.clear();
+        self.domain_view_cache.clear();
+    }
+
+    /// Mark a channel's chart caches as drawn this frame
+    pub(crate) fn touch_chart_cache(&mut self, key: &CacheKey) {
+        self.cache_last_used.insert(key.clone(), self.cache_frame);
+    }
+
+    /// Memory held by the chart caches of one file, or of every file if
+    /// `file_index` is None (bytes)
+    pub(crate) fn chart_cache_bytes(&self, file_index: Option<usize>) -> usize {
+        let included = |key: &CacheKey| file_index.is_none_or(|idx| key.file_index == idx);
+        self.downsample_cache
+            .iter()
+            .filter(|(key, _)| included(key))
+            .map(|(_, pyramid)| pyramid.memory_bytes())
+            .chain(
+                self.view_downsample_cache
+                    .iter()
+                    .filter(|(key, _)| included(key))
+                    .map(|(_, view)| view.memory_bytes()),
+            )
+            .chain(
+                self.domain_view_cache
+                    .iter()
+                    .filter(|(key, _)| included(key))
+                    .map(|(_, view)| view.memory_bytes()),
+            )
+            .sum()
+    }
+
+    /// Estimated memory used by a loaded file: its data plus its chart
+    /// caches (bytes)
+    pub(crate) fn file_memory_bytes(&self, file_index: usize) -> usize {
+        self.files.get(file_index).map_or(0, |file| {
+            file.log.memory_bytes() + file.runs.iter().map(Log::memory_bytes).sum::<usize>()
+        }) + self.chart_cache_bytes(Some(file_index))
+    }
+
+    /// Drop the chart caches of the channels drawn least recently while the
+    /// caches use more than the budget, then start the next frame. Channels
+    /// drawn this frame are kept.
+    fn evict_chart_caches(&mut self) {
+        let now = self.cache_frame;
+        self.cache_frame += 1;
+
+        let budget = (self.cache_budget_mb * 1024 * 1024) as usize;
+        if self.chart_cache_bytes(None) <= budget {
+            return;
+        }
+
+        let mut bytes: HashMap<CacheKey, usize> = HashMap::new();
+        for (key, pyramid) in &self.downsample_cache {
+            *bytes.entry(key.clone()).or_default() += pyramid.memory_bytes();
+        }
+        for (key, view) in &self.view_downsample_cache {
+            *bytes.entry(key.clone()).or_default() += view.memory_bytes();
+        }
+        for (key, view) in &self.domain_view_cache {
+            *bytes.entry(key.clone()).or_default() += view.memory_bytes();
+        }
+        let entries: Vec<(CacheKey, u64, usize)> = bytes
+            .into_iter()
+            .map(|(key, bytes)| {
+                let used = self.cache_last_used.get(&key).copied().unwrap_or(0);
+                (key, used, bytes)
+            })
+            .collect();
+
+        for key in lru_evictions(&entries, budget, now) {
+            self.downsample_cache.remove(&key);
+            self.view_downsample_cache.remove(&key);
+            self.domain_view_cache.remove(&key);
+            self.minmax_cache.remove(&key);
+            self.cache_last_used.remove(&key);
+        }
+    }
+
+    /// Refresh state after records were appended to a loaded file: drop
+    /// caches that no longer cover the log, extend its tabs' time ranges and
+    /// keep the newest data in view
+    pub(crate) fn records_appended(&mut self, file_index: usize) {
+        self.drop_file_caches(file_index);
+
+        let times = self.files[file_index].log.get_times_as_f64();
+        let (Some(&first), Some(&last)) = (times.first(), times.last()) else {
+            return;
+        };
+        for tab in self.tabs.iter_mut().filter(|t| t.file_index == file_index) {
+            tab.time_range = Some((first, last));
+        }
+        self.update_time_range();
+
+        if self.selected_file == Some(file_index) && !self.is_playing {
+            self.set_jump_to_time(Some(last));
+        }
+    }
+
+    /// Drop chart caches and cached analysis results of one file
+    fn drop_file_caches(&mut self, file_index: usize) {
+        self.downsample_cache
+            .retain(|k, _| k.file_index != file_index);
+        self.view_downsample_cache
+            .retain(|k, _| k.file_index != file_index);
+        self.domain_view_cache
+            .retain(|k, _| k.file_index != file_index);
+        self.minmax_cache.retain(|k, _| k.file_index != file_index);
+        self.empty_channel_cache
+            .retain(|k, _| k.file_index != file_index);
+        self.alarm_results.remove(&file_index);
+        self.pull_results.remove(&file_index);
+    }
+
+    /// Move a file's records and markers `delta` seconds later. Tabs are
+    /// left alone; see [`Self::set_time_offset`].
+    fn shift_file_times(&mut self, file_index: usize, delta: f64) {
+        if delta == 0.0 {
+            return;
+        }
+        let file = &mut self.files[file_index];
+        file.time_offset += delta;
+        for time in &mut file.log.times {
+            *time += delta;
+        }
+        for marker in &mut file.log.markers {
+            marker.time += delta;
+        }
+
+        self.drop_file_caches(file_index);
+        self.clear_time_analyses();
+    }
+
+    /// Drop analysis results that hold times, so they are found again
+    fn clear_time_analyses(&mut self) {
+        self.find_matches = None;
+        self.fuel_trim_summary = None;
+        self.misfire_scan = None;
+        self.egt_spread_scan = None;
+        self.idle_scan = None;
+        self.drag_scan = None;
+        self.shift_scan = None;
+    }
+
+    /// Set the number of seconds added to a file's recorded times, e.g. to
+    /// line up logs started at different moments. Its tabs' cursors,
+    /// annotations, loop points and zoom move with the records.
+    pub fn set_time_offset(&mut self, file_index: usize, offset: f64) {
+        let Some(file) = self.files.get(file_index) else {
+            return;
+        };
+        let delta = offset - file.time_offset;
+        if delta == 0.0 || !delta.is_finite() {
+            return;
+        }
+        self.shift_file_times(file_index, delta);
+
+        let times = self.files[file_index].log.get_times_as_f64();
+        let time_range = match (times.first(), times.last()) {
+            (Some(&first), Some(&last)) => Some((first, last)),
+            _ => None,
+        };
+        let shift = |time: &mut Option<f64>| {
+            if let Some(time) = time {
+                *time += delta;
+            }
+        };
+        for tab in self.tabs.iter_mut().filter(|t| t.file_index == file_index) {
+            tab.time_range = time_range;
+            shift(&mut tab.cursor_time);
+            shift(&mut tab.loop_start);
+            shift(&mut tab.loop_end);
+            for annotation in &mut tab.annotations {
+                annotation.start += delta;
+                annotation.end += delta;
+            }
+            tab.zoom_to_range = tab
+                .zoom_to_range
+                .or(tab.view_range)
+                .map(|(start, end)| (start + delta, end + delta));
+        }
+        self.update_time_range();
+    }
+
+    /// Names of the logging sessions of a file holding several, in order
+    pub fn run_labels(&self, file_index: usize) -> Vec<String> {
+        let Some(file) = self.files.get(file_index) else {
+            return Vec::new();
+        };
+        (0..file.runs.len())
+            .map(|run| {
+                let log = if run == file.run {
+                    &file.log
+                } else {
+                    &file.runs[run]
+                };
+                let label = match &log.meta {
+                    Meta::Haltech(meta) => meta.run_label(),
+                    _ => String::new(),
+                };
+                let duration = Self::format_time(log.duration());
+                if label.is_empty() {
+                    format!("{}. {}", run + 1, duration)
+                } else {
+                    format!("{}. {} ({})", run + 1, label, duration)
+                }
+            })
+            .collect()
+    }
+
+    /// Show another logging session of a file holding several. The file
+    /// keeps its time offset and its tabs their channels (matched by name)
+    /// and annotations; cursors and loop points start over on the new run.
+    pub fn select_run(&mut self, file_index: usize, run: usize) {
+        let Some(file) = self.files.get(file_index) else {
+            return;
+        };
+        if run == file.run || run >= file.runs.len() {
+            return;
+        }
+        let offset = file.time_offset;
+        self.shift_file_times(file_index, -offset);
+        let file = &mut self.files[file_index];
+        let shown = std::mem::take(&mut file.runs[run]);
+        file.runs[file.run] = std::mem::replace(&mut file.log, shown);
+        file.run = run;
+        self.drop_file_caches(file_index);
+        self.clear_time_analyses();
+        self.shift_file_times(file_index, offset);
+
+        let log = &self.files[file_index].log;
+        let time_range = match (log.times.first(), log.times.last()) {
+            (Some(&first), Some(&last)) => Some((first, last)),
+            _ => None,
+        };
+        let find_channel = |name: &str| log.channels.iter().position(|c| c.name() == name);
+        for tab in self.tabs.iter_mut().filter(|t| t.file_index == file_index) {
+            tab.selected_channels.retain_mut(|selected| {
+                let Some(channel_index) = find_channel(&selected.channel.name()) else {
+                    return false;
+                };
+                selected.channel_index = channel_index;
+                selected.channel = log.channels[channel_index].clone();
+                true
+            });
+            tab.time_range = time_range;
+            tab.cursor_time = time_range.map(|(first, _)| first);
+            tab.cursor_record = time_range.map(|_| 0);
+            tab.loop_start = None;
+            tab.loop_end = None;
+            tab.zoom_to_range = time_range;
+        }
+        self.update_time_range();
+    }
+
+    /// Read bytes written to a file after `offset`, or `None` if it hasn't grown
+    fn read_appended(path: &Path, offset: u64) -> Result<Option<Vec<u8>>, String> {
+        let len = fs::metadata(path).map_err(|e| e.to_string())?.len();
+        if len < offset {
+            return Err("the file was truncated".to_string());
+        }
+        if len == offset {
+            return Ok(None);
+        }
+
+        let mut file = File::open(path).map_err(|e| e.to_string())?;
+        file.seek(SeekFrom::Start(offset))
+            .map_err(|e| e.to_string())?;
+        let mut bytes = Vec::with_capacity((len - offset) as usize);
+        file.take(len - offset)
+            .read_to_end(&mut bytes)
+            .map_err(|e| e.to_string())?;
+        Ok(Some(bytes))
+    }
+
+    // ========================================================================
+    // Live Data
+    // ========================================================================
+
+    /// Connect to the ECU described by the live config and open a tab that
+    /// receives its samples
+    pub fn connect_live(&mut self) {
+        self.disconnect_live();
+
+        let Some(ini_path) = self.live_config.ini_path.clone() else {
+            self.show_toast_error("Select the ECU's TunerStudio INI file first");
+            return;
+        };
+        let layout = match fs::read(&ini_path)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| OutputLayout::from_ini(&String::from_utf8_lossy(&bytes)))
+        {
+            Ok(layout) => layout,
+            Err(e) => {
+                self.show_toast_error(&format!("Failed to read INI: {}", e));
+                return;
+            }
+        };
+
+        let target = self.live_config.target();
+        let channels: Vec<Channel> = layout
+            .channels
+            .iter()
+            .map(|c| {
+                Channel::Speeduino(SpeeduinoChannel {
+                    name: c.name.clone(),
+                    unit: c.unit.clone(),
+                    scale: c.scale as f32,
+                    transform: c.translate as f32,
+                    field_type: 0,
+                    category: String::new(),
+                })
+            })
+            .collect();
+        let path = PathBuf::from(format!("live://{}", target));
+        let file = LoadedFile {
+            path: path.clone(),
+            name: format!("Live: {}", target),
+            ecu_type: EcuType::Speeduino,
+            log: Log {
+                meta: Meta::Speeduino(SpeeduinoMeta {
+                    version: "Live".to_string(),
+                    capture_date: String::new(),
+                }),
+                data: vec![Vec::new(); channels.len()],
+                channels,
+                times: Vec::new(),
+                lazy_data: None,
+                skipped_records: 0,
+                markers: Vec::new(),
+            },
+            tail: None,
+            following: false,
+            time_offset: 0.0,
+            runs: Vec::new(),
+            run: 0,
+        };
+
+        self.handle_load_result(LoadResult::Success(Box::new(file)), None);
+        self.live_session = Some(LiveSession::start(self.live_config.clone(), layout));
+        self.live_path = Some(path);
+        self.show_toast_success(&format!("Connecting to {}", target));
+    }
+
+    /// Stop the live data session, keeping the samples already received
+    pub fn disconnect_live(&mut self) {
+        if let Some(session) = self.live_session.take() {
+            session.stop();
+        }
+        self.live_path = None;
+    }
+
+    /// Append samples received from the live session to its file
+    fn poll_live_session(&mut self, ctx: &egui::Context) {
+        let Some(session) = &self.live_session else {
+            return;
+        };
+        ctx.request_repaint_after(std::time::Duration::from_millis(
+            self.live_config.poll_interval_ms,
+        ));
+
+        let messages: Vec<LiveMessage> = session.receiver.try_iter().collect();
+        if messages.is_empty() {
+            return;
         }
 
-        // Compute min/max
-        let file = &self.files[file_index];
-        let data = file.log.get_channel_data(channel_index);
+        // The live tab may have been closed, which ends the session
+        let Some(file_index) = self
+            .live_path
+            .as_ref()
+            .and_then(|path| self.files.iter().position(|f| &f.path == path))
+        else {
+            self.disconnect_live();
+            return;
+        };
 
-        if data.is_empty() {
-            return None;
+        let mut appended = false;
+        for message in messages {
+            match message {
+                LiveMessage::Sample { time, values } => {
+                    let file = &mut self.files[file_index];
+                    let log = &mut file.log;
+                    log.times.push(time + file.time_offset);
+                    for (column, value) in log.data.iter_mut().zip(values) {
+                        column.push(value);
+                    }
+                    appended = true;
+                }
+                LiveMessage::Error(e) => {
+                    self.disconnect_live();
+                    self.show_toast_error(&format!("Live data stopped: {}", e));
+                    break;
+                }
+            }
         }
 
-        let (min_val, max_val) = data.iter().fold((f64::MAX, f64::MIN), |(min, max), &v| {
-            (min.min(v), max.max(v))
-        });
-
-        // Cache the result
-        self.minmax_cache.insert(cache_key, (min_val, max_val));
-        Some((min_val, max_val))
+        if appended {
+            derived::add_derived_channels(&mut self.files[file_index].log);
+            self.records_appended(file_index);
+        }
     }
 
-    // ========================================================================
-    // File and Channel Management
-    // ========================================================================
-
     /// Remove a loaded file
     pub fn remove_file(&mut self, index: usize) {
         if index < self.files.len() {
-            // Find and close the tab for this file
-            if let Some(tab_idx) = self.tabs.iter().position(|t| t.file_index == index) {
+            // Close every tab showing this file
+            while let Some(tab_idx) = self.tabs.iter().position(|t| t.file_index == index) {
                 self.close_tab(tab_idx);
             }
 
@@ -591,6 +2228,8 @@ impl UltraLogApp {
                 }
             }
             self.downsample_cache = new_cache;
+            self.view_downsample_cache.clear();
+            self.domain_view_cache.clear();
 
             // Clear minmax cache entries for this file and update indices
             let mut new_minmax_cache = HashMap::new();
@@ -610,6 +2249,45 @@ impl UltraLogApp {
                 }
             }
             self.minmax_cache = new_minmax_cache;
+            self.cache_last_used.clear();
+
+            // Same for the empty channel cache
+            self.empty_channel_cache = self
+                .empty_channel_cache
+                .drain()
+                .filter(|(key, _)| key.file_index != index)
+                .map(|(key, value)| {
+                    let file_index = if key.file_index > index {
+                        key.file_index - 1
+                    } else {
+                        key.file_index
+                    };
+                    (
+                        CacheKey {
+                            file_index,
+                            channel_index: key.channel_index,
+                        },
+                        value,
+                    )
+                })
+                .collect();
+
+            // Alarm results and pulls are keyed by file index, re-evaluate lazily
+            self.alarm_results.clear();
+            self.pull_results.clear();
+            self.find_matches = None;
+            self.fuel_trim_summary = None;
+            self.misfire_scan = None;
+            self.egt_spread_scan = None;
+            self.idle_scan = None;
+            self.drag_scan = None;
+            self.shift_scan = None;
+            self.compared_pulls.retain(|p| p.file_index != index);
+            for compared in &mut self.compared_pulls {
+                if compared.file_index > index {
+                    compared.file_index -= 1;
+                }
+            }
 
             // Update file indices for remaining tabs and their channels
             for tab in &mut self.tabs {
@@ -626,6 +2304,12 @@ impl UltraLogApp {
 
             self.files.remove(index);
 
+            self.file_info_index = match self.file_info_index {
+                Some(shown) if shown == index => None,
+                Some(shown) if shown > index => Some(shown - 1),
+                shown => shown,
+            };
+
             // Update selected file
             if let Some(selected) = self.selected_file {
                 if selected == index {
@@ -637,6 +2321,145 @@ impl UltraLogApp {
 
             // Update time range after file removal
             self.update_time_range();
+            self.update_crash_context();
+        }
+    }
+
+    /// Tell the crash reporter which files are open and loading
+    fn update_crash_context(&self) {
+        crash::set_file_context(crash::FileContext {
+            open: self.files.iter().map(|f| f.name.clone()).collect(),
+            loading: self
+                .loading_state
+                .loads()
+                .iter()
+                .map(|l| l.name.clone())
+                .collect(),
+        });
+    }
+
+    /// Remove every loaded file except one
+    pub fn close_other_files(&mut self, keep: usize) {
+        for index in (0..self.files.len()).rev() {
+            if index != keep {
+                self.remove_file(index);
+            }
+        }
+    }
+
+    /// Whether a loaded file is backed by a file on disk (not a live stream)
+    pub fn is_file_on_disk(&self, index: usize) -> bool {
+        self.files
+            .get(index)
+            .is_some_and(|f| self.live_path.as_ref() != Some(&f.path) && f.path.is_file())
+    }
+
+    /// Close a file and load it again from disk, with the parser it was
+    /// loaded with. Its tabs keep their places, selected channels (matched
+    /// by name), cursor positions and zoom.
+    pub fn reload_file(&mut self, index: usize) {
+        if !self.is_file_on_disk(index) {
+            return;
+        }
+        let file = &self.files[index];
+        let path = file.path.clone();
+        let generic_parser = match &file.log.meta {
+            Meta::Generic(meta) => Some(meta.parser),
+            _ => None,
+        };
+
+        let tabs: Vec<(usize, Tab, Option<String>)> = self
+            .tabs
+            .iter()
+            .enumerate()
+            .filter(|(_, tab)| tab.file_index == index)
+            .map(|(position, tab)| {
+                let x_axis_channel = tab
+                    .x_axis_channel
+                    .and_then(|channel| file.log.channels.get(channel))
+                    .map(|c| c.name());
+                (position, tab.clone(), x_axis_channel)
+            })
+            .collect();
+        self.pending_reloads.retain(|r| r.path != path);
+        if !tabs.is_empty() {
+            self.pending_reloads.push(PendingReload {
+                path: path.clone(),
+                tabs,
+                active_tab_id: self.active_tab.map(|idx| self.tabs[idx].id),
+                time_offset: file.time_offset,
+                run: file.run,
+            });
+        }
+
+        self.remove_file(index);
+        match generic_parser {
+            Some(parser) => self.start_loading_generic_csv(path, parser),
+            None => self.start_loading_file(path),
+        }
+    }
+
+    /// Time range a trimmed log covers: the active tab's A/B loop region if
+    /// both points are set, otherwise the visible part of the chart
+    pub fn trim_range(&self) -> Option<(f64, f64)> {
+        self.get_loop_region().or(self.chart_view_range)
+    }
+
+    /// Open the records of the active file within [`Self::trim_range`] as a
+    /// new log in its own tab, with times starting at zero
+    pub fn trim_to_selection(&mut self) {
+        let (Some(file_index), Some((start, end))) = (
+            self.active_tab.map(|idx| self.tabs[idx].file_index),
+            self.trim_range(),
+        ) else {
+            return;
+        };
+        let source = &self.files[file_index];
+        let log = source.log.slice(start, end);
+        if log.record_count() == 0 {
+            self.show_toast_warning("No records in the selected range");
+            return;
+        }
+
+        let range = format!("{}-{}", Self::format_time(start), Self::format_time(end));
+        let file = LoadedFile {
+            path: PathBuf::from(format!("{} [{}]", source.path.display(), range)),
+            name: format!("{} [{}]", source.name, range),
+            ecu_type: source.ecu_type,
+            log,
+            tail: None,
+            following: false,
+            time_offset: 0.0,
+            runs: Vec::new(),
+            run: 0,
+        };
+        self.handle_load_result(LoadResult::Success(Box::new(file)), None);
+    }
+
+    /// Show a loaded file in the system file manager
+    pub fn reveal_file(&mut self, index: usize) {
+        if !self.is_file_on_disk(index) {
+            return;
+        }
+        let path = &self.files[index].path;
+
+        #[cfg(target_os = "windows")]
+        let result = std::process::Command::new("explorer")
+            .arg(format!("/select,{}", path.display()))
+            .spawn()
+            .map(|_| ());
+        #[cfg(target_os = "macos")]
+        let result = std::process::Command::new("open")
+            .arg("-R")
+            .arg(path)
+            .spawn()
+            .map(|_| ());
+        // Other file managers can't select a file, so open its folder
+        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+        let result = open::that(path.parent().unwrap_or(Path::new(".")));
+
+        if let Err(e) = result {
+            self.show_toast_error(&format!("Failed to open file manager: {}", e));
         }
     }
 
@@ -655,8 +2478,12 @@ impl UltraLogApp {
             return;
         }
 
-        if tab.selected_channels.len() >= MAX_CHANNELS {
-            self.show_toast_warning("Maximum 10 channels reached");
+        if tab.selected_channels.len() >= self.max_channels {
+            let message = format!(
+                "Maximum {} channels reached (change the limit in the View menu)",
+                self.max_channels
+            );
+            self.show_toast_warning(&message);
             return;
         }
 
@@ -680,15 +2507,15 @@ impl UltraLogApp {
             .map(|c| c.color_index)
             .collect();
 
-        let color_index = (0..CHART_COLORS.len())
-            .find(|i| !used_colors.contains(i))
-            .unwrap_or(0);
+        // Beyond the palette size, colors repeat (see get_channel_color)
+        let color_index = (0..).find(|i| !used_colors.contains(i)).unwrap_or(0);
 
         self.tabs[tab_idx].selected_channels.push(SelectedChannel {
             file_index,
             channel_index,
             channel,
             color_index,
+            style: ChannelStyle::default(),
         });
     }
 
@@ -703,6 +2530,20 @@ impl UltraLogApp {
         }
     }
 
+    /// Move a selected channel of the active tab to a new position, which
+    /// also sets its place in the legend
+    pub fn move_channel(&mut self, from: usize, to: usize) {
+        let Some(tab_idx) = self.active_tab else {
+            return;
+        };
+
+        let channels = &mut self.tabs[tab_idx].selected_channels;
+        if from < channels.len() && to < channels.len() && from != to {
+            let channel = channels.remove(from);
+            channels.insert(to, channel);
+        }
+    }
+
     /// Get the selected channels for the active tab
     pub fn get_selected_channels(&self) -> &[SelectedChannel] {
         if let Some(tab_idx) = self.active_tab {
@@ -730,17 +2571,46 @@ impl UltraLogApp {
 
     /// Switch to a tab for the given file, creating one if it doesn't exist
     pub fn switch_to_file_tab(&mut self, file_index: usize) {
-        // Check if a tab already exists for this file
-        if let Some(tab_idx) = self.tabs.iter().position(|t| t.file_index == file_index) {
+        let active_file = self.active_tab.map(|idx| self.tabs[idx].file_index);
+        if active_file == Some(file_index) {
+            // Already showing this file, maybe one of several tabs over it
+            self.selected_file = Some(file_index);
+        } else if let Some(tab_idx) = self.tabs.iter().position(|t| t.file_index == file_index) {
             self.active_tab = Some(tab_idx);
             self.selected_file = Some(file_index);
         } else {
-            // Create a new tab for this file
-            let file_name = self.files[file_index].name.clone();
-            let tab = Tab::new(file_index, file_name);
-            self.tabs.push(tab);
-            self.active_tab = Some(self.tabs.len() - 1);
-            self.selected_file = Some(file_index);
+            self.open_file_tab(file_index);
+        }
+    }
+
+    /// Open and activate a new tab over a loaded file, with the cursor at
+    /// its start. Returns the new tab's index.
+    pub fn open_file_tab(&mut self, file_index: usize) -> usize {
+        let mut tab = Tab::new(file_index, self.new_tab_name(file_index));
+        let times = self.files[file_index].log.get_times_as_f64();
+        if let (Some(&first), Some(&last)) = (times.first(), times.last()) {
+            tab.time_range = Some((first, last));
+            tab.cursor_time = Some(first);
+            tab.cursor_record = Some(0);
+        }
+        self.tabs.push(tab);
+        self.active_tab = Some(self.tabs.len() - 1);
+        self.selected_file = Some(file_index);
+        self.tabs.len() - 1
+    }
+
+    /// Name for another tab over a file: the file name, numbered when the
+    /// file already has tabs
+    fn new_tab_name(&self, file_index: usize) -> String {
+        let name = &self.files[file_index].name;
+        match self
+            .tabs
+            .iter()
+            .filter(|t| t.file_index == file_index)
+            .count()
+        {
+            0 => name.clone(),
+            open => format!("{} ({})", name, open + 1),
         }
     }
 
@@ -769,6 +2639,180 @@ impl UltraLogApp {
         }
     }
 
+    /// Close every tab except one
+    pub fn close_other_tabs(&mut self, keep: usize) {
+        if keep >= self.tabs.len() {
+            return;
+        }
+        let tab = self.tabs.swap_remove(keep);
+        self.tabs = vec![tab];
+        self.active_tab = Some(0);
+        self.selected_file = Some(self.tabs[0].file_index);
+    }
+
+    /// Open a copy of a tab, with the same channels and view state, right
+    /// after it
+    pub fn duplicate_tab(&mut self, tab_index: usize) {
+        let Some(tab) = self.tabs.get(tab_index) else {
+            return;
+        };
+        let mut tab = tab.duplicate();
+        tab.name = self.new_tab_name(tab.file_index);
+        self.tabs.insert(tab_index + 1, tab);
+        self.active_tab = Some(tab_index + 1);
+        self.selected_file = Some(self.tabs[tab_index + 1].file_index);
+    }
+
+    /// Move a tab to another position, keeping the same tab active
+    pub fn move_tab(&mut self, from: usize, to: usize) {
+        if from >= self.tabs.len() || to >= self.tabs.len() || from == to {
+            return;
+        }
+        let tab = self.tabs.remove(from);
+        self.tabs.insert(to, tab);
+        self.active_tab = self.active_tab.map(|active| {
+            if active == from {
+                to
+            } else if from < active && active <= to {
+                active - 1
+            } else if to <= active && active < from {
+                active + 1
+            } else {
+                active
+            }
+        });
+    }
+
+    /// Activate the next tab, or the previous one, wrapping around
+    pub fn cycle_tab(&mut self, forward: bool) {
+        let count = self.tabs.len();
+        let Some(active) = self.active_tab.filter(|_| count > 1) else {
+            return;
+        };
+        let next = if forward {
+            (active + 1) % count
+        } else {
+            (active + count - 1) % count
+        };
+        self.activate_tab(next);
+    }
+
+    /// Make a tab the active one. Activating the split view's other pane
+    /// swaps the panes rather than leaving the split.
+    pub fn activate_tab(&mut self, index: usize) {
+        if index >= self.tabs.len() {
+            return;
+        }
+        if let Some(active) = self.active_tab {
+            if self.split_tab == Some(self.tabs[index].id) {
+                self.split_tab = Some(self.tabs[active].id);
+            }
+        }
+        self.active_tab = Some(index);
+        self.selected_file = Some(self.tabs[index].file_index);
+    }
+
+    /// Index of the tab shown next to the active tab, if split view is open
+    pub fn split_tab_index(&self) -> Option<usize> {
+        let id = self.split_tab?;
+        let index = self.tabs.iter().position(|t| t.id == id)?;
+        (self.active_tab != Some(index)).then_some(index)
+    }
+
+    /// Show a tab next to the active tab
+    pub fn open_split_view(&mut self, tab_index: usize) {
+        if self.active_tab.is_none_or(|active| active == tab_index) {
+            return;
+        }
+        let Some(tab) = self.tabs.get(tab_index) else {
+            return;
+        };
+        self.split_tab = Some(tab.id);
+    }
+
+    /// Open split view with the tab after the active one, or a copy of the
+    /// active tab when it is the only one; close it if already open
+    pub fn toggle_split_view(&mut self) {
+        if self.split_tab_index().is_some() {
+            self.split_tab = None;
+            return;
+        }
+        let Some(active) = self.active_tab else {
+            return;
+        };
+        if self.tabs.len() == 1 {
+            self.duplicate_tab(active);
+            self.active_tab = Some(active);
+            self.open_split_view(active + 1);
+        } else {
+            self.open_split_view((active + 1) % self.tabs.len());
+        }
+    }
+
+    /// Move a tab's cursor to a time, clamped to its log
+    fn set_tab_cursor(&mut self, tab_index: usize, time: f64) {
+        let tab = &self.tabs[tab_index];
+        let time = match tab.time_range {
+            Some((min, max)) => time.clamp(min, max),
+            None => time,
+        };
+        let record = self.files[tab.file_index].log.record_at_time(time);
+        let tab = &mut self.tabs[tab_index];
+        tab.cursor_time = Some(time);
+        tab.cursor_record = record;
+    }
+
+    /// Tabs whose cursors move together: every tab when linked across tabs,
+    /// otherwise the two split panes when those are linked
+    fn linked_tabs(&self) -> Vec<usize> {
+        if self.link_all_cursors {
+            return (0..self.tabs.len()).collect();
+        }
+        match (self.active_tab, self.split_tab_index()) {
+            (Some(active), Some(other)) if self.link_split_cursors => vec![active, other],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Carry a cursor move in one linked tab over to the other linked tabs,
+    /// at the same time from the start of each log
+    fn sync_linked_cursors(&mut self, ctx: &egui::Context) {
+        let linked = self.linked_tabs();
+        let moved = |tab: &Tab| {
+            self.linked_cursors_seen
+                .iter()
+                .any(|(id, time)| *id == tab.id && *time != tab.cursor_time)
+        };
+        // The active tab wins if several moved, e.g. during playback
+        let source = self
+            .active_tab
+            .into_iter()
+            .chain(linked.iter().copied())
+            .find(|i| linked.contains(i) && moved(&self.tabs[*i]));
+
+        if let Some(source) = source {
+            let tab = &self.tabs[source];
+            if let (Some(time), Some((start, _))) = (tab.cursor_time, tab.time_range) {
+                let elapsed = time - start;
+                for &i in linked.iter().filter(|&&i| i != source) {
+                    if let Some((start, _)) = self.tabs[i].time_range {
+                        self.set_tab_cursor(i, start + elapsed);
+                        // Bring the moved cursor into view when the tab is next drawn
+                        let tab = &mut self.tabs[i];
+                        if let (Some(time), Some((min, max))) = (tab.cursor_time, tab.view_range) {
+                            if time < min || time > max {
+                                tab.jump_to_time = Some(time);
+                            }
+                        }
+                    }
+                }
+                ctx.request_repaint();
+            }
+        }
+
+        self.linked_cursors_seen = self.tabs.iter().map(|t| (t.id, t.cursor_time)).collect();
+    }
+
     /// Get the cursor time for the active tab
     pub fn get_cursor_time(&self) -> Option<f64> {
         self.active_tab.and_then(|idx| self.tabs[idx].cursor_time)
@@ -793,6 +2837,28 @@ impl UltraLogApp {
         }
     }
 
+    /// Time of day (seconds since midnight) of `time` in the active tab's
+    /// log, if wall-clock time is shown and the log records when it was made
+    pub fn wall_clock_at(&self, time: f64) -> Option<f64> {
+        if !self.wall_clock_time {
+            return None;
+        }
+        let file = self
+            .files
+            .get(self.tabs.get(self.active_tab?)?.file_index)?;
+        let start = file.log.wall_clock_start()?;
+        Some(start + time - file.log.times.first()?)
+    }
+
+    /// A time in the active tab's log as shown to the user: the time of day
+    /// if wall-clock time is shown, otherwise the time from the start
+    pub fn display_time(&self, time: f64) -> String {
+        match self.wall_clock_at(time) {
+            Some(clock) => Self::format_clock_time(clock),
+            None => Self::format_time(time),
+        }
+    }
+
     /// Get the time range for the active tab
     pub fn get_time_range(&self) -> Option<(f64, f64)> {
         self.active_tab.and_then(|idx| self.tabs[idx].time_range)
@@ -831,6 +2897,60 @@ impl UltraLogApp {
         }
     }
 
+    /// X-axis channel of the active tab's chart as (file index, channel
+    /// index), or `None` when plotting against time
+    pub fn get_x_axis_channel(&self) -> Option<(usize, usize)> {
+        let tab = &self.tabs[self.active_tab?];
+        let channel = tab.x_axis_channel?;
+        let file = self.files.get(tab.file_index)?;
+        (channel < file.log.channels.len()).then_some((tab.file_index, channel))
+    }
+
+    /// Set the X-axis channel of the active tab's chart (`None` for time)
+    pub fn set_x_axis_channel(&mut self, channel: Option<usize>) {
+        if let Some(tab_idx) = self.active_tab {
+            self.tabs[tab_idx].x_axis_channel = channel;
+        }
+    }
+
+    /// Get the pending zoom-to-range request for the active tab
+    pub fn get_zoom_to_range(&self) -> Option<(f64, f64)> {
+        self.active_tab.and_then(|idx| self.tabs[idx].zoom_to_range)
+    }
+
+    /// Set a zoom-to-range request for the active tab (chart will show exactly this range)
+    pub fn set_zoom_to_range(&mut self, range: Option<(f64, f64)>) {
+        if let Some(tab_idx) = self.active_tab {
+            self.tabs[tab_idx].zoom_to_range = range;
+        }
+    }
+
+    /// Get the playback loop region for the active tab
+    pub fn get_loop_region(&self) -> Option<(f64, f64)> {
+        self.active_tab.and_then(|idx| self.tabs[idx].loop_region())
+    }
+
+    /// Set the active tab's loop start (A) or end (B) at the cursor
+    pub fn set_loop_bound_at_cursor(&mut self, start: bool) {
+        let Some(tab_idx) = self.active_tab else {
+            return;
+        };
+        let tab = &mut self.tabs[tab_idx];
+        if start {
+            tab.loop_start = tab.cursor_time;
+        } else {
+            tab.loop_end = tab.cursor_time;
+        }
+    }
+
+    /// Remove the active tab's loop region
+    pub fn clear_loop_region(&mut self) {
+        if let Some(tab_idx) = self.active_tab {
+            self.tabs[tab_idx].loop_start = None;
+            self.tabs[tab_idx].loop_end = None;
+        }
+    }
+
     /// Clear the jump-to-time request for the active tab
     pub fn clear_jump_to_time(&mut self) {
         if let Some(tab_idx) = self.active_tab {
@@ -883,7 +3003,7 @@ impl UltraLogApp {
         // Don't start if already checking or downloading
         if matches!(
             self.update_state,
-            UpdateState::Checking | UpdateState::Downloading
+            UpdateState::Checking | UpdateState::Downloading { .. }
         ) {
             return;
         }
@@ -892,37 +3012,76 @@ impl UltraLogApp {
 
         let (sender, receiver) = channel();
         self.update_check_receiver = Some(receiver);
+        let proxy = self.update_proxy.clone();
+        let channel = self.update_channel;
 
         thread::spawn(move || {
-            let result = crate::updater::check_for_updates();
+            let result = crate::updater::check_for_updates(&proxy, channel);
             let _ = sender.send(result);
         });
     }
 
     /// Start downloading update in background
-    pub fn start_update_download(&mut self, url: String) {
-        self.update_state = UpdateState::Downloading;
+    pub fn start_update_download(&mut self, info: &UpdateInfo) {
+        let url = info.download_url.clone();
+        let sha256 = info.sha256.clone();
+        let allow_unverified = self.allow_unverified_update;
+        self.update_state = UpdateState::Downloading {
+            downloaded: 0,
+            total: None,
+        };
 
         let (sender, receiver) = channel();
         self.update_download_receiver = Some(receiver);
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.update_download_cancel = cancel.clone();
+        let proxy = self.update_proxy.clone();
 
         thread::spawn(move || {
-            let result = crate::updater::download_update(&url);
-            let _ = sender.send(result);
+            let progress = |downloaded, total| {
+                let _ = sender.send(DownloadMessage::Progress { downloaded, total });
+            };
+            let result = crate::updater::download_update(
+                &url,
+                sha256.as_deref(),
+                allow_unverified,
+                &proxy,
+                &progress,
+                &cancel,
+            );
+            let _ = sender.send(DownloadMessage::Done(result));
         });
     }
 
+    /// Stop the update download in progress
+    pub fn cancel_update_download(&mut self) {
+        self.update_download_cancel.store(true, Ordering::Relaxed);
+    }
+
     /// Check for completed update operations
     fn check_update_complete(&mut self) {
         // Check for update check completion
         if let Some(receiver) = &self.update_check_receiver {
             if let Ok(result) = receiver.try_recv() {
                 match result {
+                    // Skipped versions are only offered when asked for
+                    UpdateCheckResult::UpdateAvailable(info)
+                        if !self.startup_check_done
+                            && self.skipped_versions.contains(&info.new_version) =>
+                    {
+                        tracing::info!("Skipping update to {}", info.new_version);
+                        self.update_status = Some(format!("Version {} skipped", info.new_version));
+                        self.update_state = UpdateState::Idle;
+                    }
                     UpdateCheckResult::UpdateAvailable(info) => {
+                        self.update_status =
+                            Some(format!("Version {} available", info.new_version));
                         self.update_state = UpdateState::UpdateAvailable(info);
+                        self.allow_unverified_update = false;
                         self.show_update_dialog = true;
                     }
                     UpdateCheckResult::UpToDate => {
+                        self.update_status = Some("Up to date".to_string());
                         self.update_state = UpdateState::Idle;
                         // Only show toast for manual checks (not startup)
                         if self.startup_check_done {
@@ -930,10 +3089,14 @@ impl UltraLogApp {
                         }
                     }
                     UpdateCheckResult::Error(e) => {
-                        self.update_state = UpdateState::Error(e.clone());
-                        // Only show error toast for manual checks
+                        tracing::info!("Update check failed: {}", e);
+                        self.update_status = Some(format!("Check failed: {}", e));
+                        // A failed startup check stays quiet; offline is normal
                         if self.startup_check_done {
+                            self.update_state = UpdateState::Error(e.clone());
                             self.show_toast_error(&format!("Update check failed: {}", e));
+                        } else {
+                            self.update_state = UpdateState::Idle;
                         }
                     }
                 }
@@ -942,21 +3105,35 @@ impl UltraLogApp {
             }
         }
 
-        // Check for download completion
-        if let Some(receiver) = &self.update_download_receiver {
-            if let Ok(result) = receiver.try_recv() {
-                match result {
-                    DownloadResult::Success(path) => {
-                        self.update_state = UpdateState::ReadyToInstall(path);
-                        self.show_toast_success("Update downloaded successfully");
-                    }
-                    DownloadResult::Error(e) => {
-                        self.update_state = UpdateState::Error(e.clone());
-                        self.show_toast_error(&format!("Download failed: {}", e));
-                    }
+        // Check for download progress and completion
+        while let Some(message) = self
+            .update_download_receiver
+            .as_ref()
+            .and_then(|receiver| receiver.try_recv().ok())
+        {
+            let result = match message {
+                DownloadMessage::Progress { downloaded, total } => {
+                    self.update_state = UpdateState::Downloading { downloaded, total };
+                    continue;
+                }
+                DownloadMessage::Done(result) => result,
+            };
+            match result {
+                DownloadResult::Success { path, verified } => {
+                    self.update_state = UpdateState::ReadyToInstall { path, verified };
+                    self.show_toast_success("Update downloaded successfully");
+                }
+                DownloadResult::Cancelled => {
+                    self.update_state = UpdateState::Idle;
+                    self.show_update_dialog = false;
+                    self.show_toast("Update download cancelled");
+                }
+                DownloadResult::Error(e) => {
+                    self.update_state = UpdateState::Error(e.clone());
+                    self.show_toast_error(&format!("Download failed: {}", e));
                 }
-                self.update_download_receiver = None;
             }
+            self.update_download_receiver = None;
         }
     }
 
@@ -1002,18 +3179,6 @@ impl UltraLogApp {
 
     /// Handle file drops
     fn handle_dropped_files(&mut self, ctx: &egui::Context) {
-        // Don't accept drops while loading
-        if matches!(self.loading_state, LoadingState::Loading(_)) {
-            return;
-        }
-
-        // Debounce file drops (5 second window)
-        if let Some(last_drop) = self.last_drop_time {
-            if last_drop.elapsed().as_secs() < 5 {
-                return;
-            }
-        }
-
         let dropped_files: Vec<PathBuf> = ctx.input(|i| {
             i.raw
                 .dropped_files
@@ -1022,14 +3187,22 @@ impl UltraLogApp {
                 .collect()
         });
 
-        if !dropped_files.is_empty() {
-            self.last_drop_time = Some(std::time::Instant::now());
-
-            // Only load first file for now (could queue multiple)
-            if let Some(path) = dropped_files.into_iter().next() {
-                self.start_loading_file(path);
-            }
+        if dropped_files.is_empty() {
+            return;
+        }
+        let (accepted, rejected): (Vec<PathBuf>, Vec<PathBuf>) = dropped_files
+            .into_iter()
+            .partition(|path| crate::ui::drop_overlay::accepts_drop(path));
+        if !rejected.is_empty() {
+            let names: Vec<String> = rejected
+                .iter()
+                .map(|p| Self::file_display_name(p))
+                .collect();
+            self.show_toast_warning(&format!("Unsupported file type: {}", names.join(", ")));
         }
+
+        // Drops during a load are queued behind it
+        self.open_paths(accepted);
     }
 
     // ========================================================================
@@ -1038,6 +3211,32 @@ impl UltraLogApp {
 
     /// Handle keyboard shortcuts
     fn handle_keyboard_shortcuts(&mut self, ctx: &egui::Context) {
+        // Ctrl+Tab / Ctrl+Shift+Tab cycle tabs, even from a text field.
+        // Shift is checked first since Ctrl+Tab also matches with it held.
+        let backward = ctx.input_mut(|i| {
+            i.consume_key(
+                egui::Modifiers::CTRL | egui::Modifiers::SHIFT,
+                egui::Key::Tab,
+            )
+        });
+        let forward =
+            !backward && ctx.input_mut(|i| i.consume_key(egui::Modifiers::CTRL, egui::Key::Tab));
+        if backward || forward {
+            self.cycle_tab(forward);
+        }
+
+        // Ctrl+F opens the find value window, F3 / Shift+F3 step through its matches
+        if ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::F)) {
+            self.show_find_value_window = true;
+        }
+        if self.show_find_value_window {
+            if ctx.input_mut(|i| i.consume_key(egui::Modifiers::SHIFT, egui::Key::F3)) {
+                self.step_find_match(false);
+            } else if ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::F3)) {
+                self.step_find_match(true);
+            }
+        }
+
         // Only handle shortcuts when we have data loaded
         if self.files.is_empty() || self.get_selected_channels().is_empty() {
             return;
@@ -1048,6 +3247,22 @@ impl UltraLogApp {
             return;
         }
 
+        // Ctrl+Shift+C copies the selected channels' values at the cursor.
+        // The platform turns Ctrl+C into a copy event rather than a key press.
+        if ctx
+            .input(|i| i.modifiers.shift && i.events.iter().any(|e| matches!(e, egui::Event::Copy)))
+        {
+            self.copy_cursor_values(ctx);
+        }
+
+        // A / B set the loop region's bounds at the cursor
+        if ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::A)) {
+            self.set_loop_bound_at_cursor(true);
+        }
+        if ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, egui::Key::B)) {
+            self.set_loop_bound_at_cursor(false);
+        }
+
         // Spacebar to toggle play/pause
         ctx.input(|i| {
             if i.key_pressed(egui::Key::Space) {
@@ -1087,20 +3302,33 @@ impl eframe::App for UltraLogApp {
         // Handle file drops
         self.handle_dropped_files(ctx);
 
+        // Append records written to followed files
+        self.poll_followed_files(ctx);
+
+        // Append samples streamed from a live ECU connection
+        self.poll_live_session(ctx);
+
         // Update playback (advances cursor if playing)
         self.update_playback(ctx);
 
         // Handle keyboard shortcuts
         self.handle_keyboard_shortcuts(ctx);
 
-        // Apply dark theme
-        ctx.set_visuals(egui::Visuals::dark());
+        // Apply the color theme
+        ctx.set_visuals(self.theme.visuals(self.high_contrast));
+
+        // Follow zooming with Ctrl +/-, within the UI scale setting's range
+        let zoom = ctx.zoom_factor().clamp(MIN_UI_SCALE, MAX_UI_SCALE);
+        if zoom != ctx.zoom_factor() {
+            ctx.set_zoom_factor(zoom);
+        }
+        self.ui_scale = zoom;
 
         // Request repaint while loading or updating (for spinner animation)
-        if matches!(self.loading_state, LoadingState::Loading(_))
+        if self.loading_state.is_loading()
             || matches!(
                 self.update_state,
-                UpdateState::Checking | UpdateState::Downloading
+                UpdateState::Checking | UpdateState::Downloading { .. }
             )
         {
             ctx.request_repaint();
@@ -1108,10 +3336,31 @@ impl eframe::App for UltraLogApp {
 
         // Toast notifications
         self.render_toast(ctx);
+        self.render_drop_overlay(ctx);
 
         // Modal windows
         self.render_normalization_editor(ctx);
         self.render_update_dialog(ctx);
+        self.render_triage_dialog(ctx);
+        self.render_alarms_window(ctx);
+        self.render_find_value_window(ctx);
+        self.render_pulls_window(ctx);
+        self.render_boost_window(ctx);
+        self.render_fuel_trim_window(ctx);
+        self.render_misfire_window(ctx);
+        self.render_egt_spread_window(ctx);
+        self.render_idle_window(ctx);
+        self.render_drag_window(ctx);
+        self.render_shifts_window(ctx);
+        self.render_report_window(ctx);
+        self.render_merge_window(ctx);
+        self.render_live_window(ctx);
+        self.render_annotations_window(ctx);
+        self.render_file_info_window(ctx);
+        self.render_settings_window(ctx);
+        self.render_log_console(ctx);
+        self.render_error_dialog(ctx);
+        self.render_crash_dialog(ctx);
 
         // Menu bar at top with padding
         let menu_frame = egui::Frame::NONE.inner_margin(egui::Margin {
@@ -1129,7 +3378,7 @@ impl eframe::App for UltraLogApp {
 
         // Tool switcher panel (pill tabs)
         let tool_switcher_frame = egui::Frame::NONE
-            .fill(egui::Color32::from_rgb(35, 35, 35))
+            .fill(self.theme.recessed_fill())
             .inner_margin(egui::Margin {
                 left: 10,
                 right: 10,
@@ -1144,7 +3393,7 @@ impl eframe::App for UltraLogApp {
             });
 
         // Panel background color (matches drop zone card)
-        let panel_bg = egui::Color32::from_rgb(45, 45, 45);
+        let panel_bg = self.theme.panel_fill();
         let panel_frame = egui::Frame::NONE
             .fill(panel_bg)
             .inner_margin(egui::Margin::symmetric(10, 10));
@@ -1153,13 +3402,16 @@ impl eframe::App for UltraLogApp {
         egui::SidePanel::left("files_panel")
             .default_width(200.0)
             .resizable(true)
-            .frame(panel_frame.clone())
+            .frame(panel_frame)
             .show(ctx, |ui| {
                 self.render_sidebar(ui);
             });
 
-        // Right panel for channel selection (only in Log Viewer mode)
-        if self.active_tool == ActiveTool::LogViewer {
+        // Right panel for channel selection (Log Viewer and Data Table)
+        if matches!(
+            self.active_tool,
+            ActiveTool::LogViewer | ActiveTool::DataTable
+        ) {
             egui::SidePanel::right("channels_panel")
                 .default_width(300.0)
                 .min_width(200.0)
@@ -1168,20 +3420,25 @@ impl eframe::App for UltraLogApp {
                 .show(ctx, |ui| {
                     self.render_channel_selection(ui);
                 });
+        }
 
-            // Bottom panel for timeline scrubber (only in Log Viewer mode)
-            if self.get_time_range().is_some() && !self.get_selected_channels().is_empty() {
-                egui::TopBottomPanel::bottom("timeline_panel")
-                    .resizable(false)
-                    .min_height(60.0)
-                    .show(ctx, |ui| {
-                        ui.add_space(5.0);
-                        self.render_record_indicator(ui);
-                        ui.separator();
-                        self.render_timeline_scrubber(ui);
-                        ui.add_space(5.0);
-                    });
-            }
+        // Bottom panel for timeline scrubber (Log Viewer with channels, or Track Map)
+        let show_timeline = match self.active_tool {
+            ActiveTool::LogViewer => !self.get_selected_channels().is_empty(),
+            ActiveTool::TrackMap | ActiveTool::Dyno | ActiveTool::DataTable => true,
+            ActiveTool::ScatterPlot => false,
+        };
+        if show_timeline && self.get_time_range().is_some() {
+            egui::TopBottomPanel::bottom("timeline_panel")
+                .resizable(false)
+                .min_height(60.0)
+                .show(ctx, |ui| {
+                    ui.add_space(5.0);
+                    self.render_record_indicator(ui);
+                    ui.separator();
+                    self.render_timeline_scrubber(ui);
+                    ui.add_space(5.0);
+                });
         }
 
         // Main content area - render based on active tool
@@ -1191,21 +3448,86 @@ impl eframe::App for UltraLogApp {
                     // Tab bar at top (Chrome-style tabs for log files)
                     self.render_tab_bar(ui);
 
-                    // Selected channels below tabs
-                    ui.add_space(10.0);
-                    self.render_selected_channels(ui);
-
-                    ui.add_space(10.0);
-                    ui.separator();
+                    if self.files.is_empty() {
+                        self.render_welcome(ui);
+                    } else if self.split_tab_index().is_some() {
+                        // Two tabs side by side or stacked
+                        ui.add_space(6.0);
+                        self.render_split_view(ui);
+                    } else {
+                        // Selected channels below tabs
+                        ui.add_space(10.0);
+                        self.render_selected_channels(ui);
+
+                        ui.add_space(10.0);
+                        ui.separator();
 
-                    // Chart takes remaining space
-                    self.render_chart(ui);
+                        // Chart takes remaining space
+                        self.render_chart(ui);
+                    }
                 }
                 ActiveTool::ScatterPlot => {
                     ui.add_space(10.0);
                     self.render_scatter_plot_view(ui);
                 }
+                ActiveTool::TrackMap => {
+                    ui.add_space(10.0);
+                    self.render_track_map_view(ui);
+                }
+                ActiveTool::Dyno => {
+                    ui.add_space(10.0);
+                    self.render_dyno_view(ui);
+                }
+                ActiveTool::DataTable => {
+                    ui.add_space(10.0);
+                    self.render_data_table_view(ui);
+                }
             }
         });
+
+        // Keep tabs with linked cursors at the same moment
+        self.sync_linked_cursors(ctx);
+
+        // Keep chart caches within their memory budget
+        self.evict_chart_caches();
+    }
+
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        let settings = PersistedSettings {
+            alarm_rules: self.alarm_rules.clone(),
+            dyno_config: self.dyno_config.clone(),
+            pull_config: self.pull_config.clone(),
+            fuel_trim_config: self.fuel_trim_config.clone(),
+            misfire_config: self.misfire_config.clone(),
+            egt_spread_config: self.egt_spread_config.clone(),
+            idle_config: self.idle_config.clone(),
+            shift_config: self.shift_config.clone(),
+            show_shift_markers: self.show_shift_markers,
+            report_config: self.report_config.clone(),
+            live_config: self.live_config.clone(),
+            max_channels: self.max_channels,
+            custom_normalizations: self.custom_normalizations.clone(),
+            unit_overrides: self.unit_overrides.clone(),
+            cache_parsed_logs: self.cache_parsed_logs,
+            ui_scale: self.ui_scale,
+            auto_check_updates: self.auto_check_updates,
+            skipped_versions: self.skipped_versions.clone(),
+            update_proxy: self.update_proxy.clone(),
+            update_channel: self.update_channel,
+            initial_view_seconds: self.initial_view_seconds,
+            color_blind_mode: self.color_blind_mode,
+            high_contrast: self.high_contrast,
+            line_width_scale: self.line_width_scale,
+            language: self.language,
+            wall_clock_time: self.wall_clock_time,
+            unit_preferences: self.unit_preferences.clone(),
+            theme: self.theme,
+            max_chart_points: self.max_chart_points,
+            mmap_threshold_mb: self.mmap_threshold_mb,
+            decimate_on_import: self.decimate_on_import,
+            decimate_rate_hz: self.decimate_rate_hz,
+            cache_budget_mb: self.cache_budget_mb,
+        };
+        eframe::set_value(storage, SETTINGS_KEY, &settings);
     }
 }
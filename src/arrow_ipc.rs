@@ -0,0 +1,535 @@
+//! Apache Arrow IPC file export (Feather v2).
+//!
+//! Writes a log as a single record batch: a `time` column followed by one
+//! Float64 column per channel, with each channel's unit and original name in
+//! the field metadata. The files open directly in pandas
+//! (`pd.read_feather`), Polars (`pl.read_ipc`) and DuckDB.
+//!
+//! The format is small enough to emit by hand, so this module includes a
+//! minimal FlatBuffers builder for the schema and record batch metadata
+//! rather than pulling in the `arrow` crate. Its output is pinned by a
+//! golden file in `exampleLogs/arrow/` that the tests read back with the
+//! Arrow project's own reader (`arrow-ipc`, a dev-dependency only).
+
+use std::io::{self, Write};
+
+use crate::parsers::Log;
+
+/// Leading and trailing magic of an Arrow IPC file
+const MAGIC: &[u8] = b"ARROW1";
+
+/// Marks the start of an encapsulated IPC message
+const CONTINUATION: u32 = 0xFFFF_FFFF;
+
+/// Arrow metadata version V5
+const METADATA_VERSION: i16 = 4;
+
+/// `MessageHeader` union tags
+const HEADER_SCHEMA: u8 = 1;
+const HEADER_RECORD_BATCH: u8 = 3;
+
+/// `Type` union tag for `FloatingPoint`, and its DOUBLE precision
+const TYPE_FLOATING_POINT: u8 = 3;
+const PRECISION_DOUBLE: i16 = 2;
+
+/// Field definition for the exported schema
+struct ArrowField<'a> {
+    name: &'a str,
+    metadata: Vec<(&'static str, String)>,
+}
+
+/// Write the log as an Arrow IPC file. `names` are the output column names
+/// for each channel (normalized or original).
+pub fn write_arrow<W: Write>(log: &Log, names: &[String], out: &mut W) -> io::Result<()> {
+    let mut fields = vec![ArrowField {
+        name: "time",
+        metadata: vec![("unit", "s".to_string())],
+    }];
+    for (name, channel) in names.iter().zip(&log.channels) {
+        fields.push(ArrowField {
+            name,
+            metadata: vec![
+                ("unit", channel.unit().to_string()),
+                ("original_name", channel.name()),
+            ],
+        });
+    }
+
+    let rows = log.record_count();
+    let mut columns: Vec<&[f64]> = vec![&log.times];
+    columns.extend((0..log.channels.len()).map(|idx| log.get_channel_data(idx)));
+
+    let mut writer = IpcWriter { out, position: 0 };
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[0, 0])?;
+
+    let schema = build_schema_message(&fields);
+    writer.write_message(&schema, &[])?;
+
+    // Body: one empty validity buffer and one values buffer per column.
+    // Missing values stay NaN rather than becoming nulls.
+    let mut body = Vec::with_capacity(columns.len() * rows * 8);
+    let mut buffers = Vec::with_capacity(columns.len() * 2);
+    for column in &columns {
+        buffers.push((body.len() as i64, 0i64));
+        let start = body.len();
+        for record in 0..rows {
+            let value = column.get(record).copied().unwrap_or(f64::NAN);
+            body.extend_from_slice(&value.to_le_bytes());
+        }
+        buffers.push((start as i64, (body.len() - start) as i64));
+    }
+    let batch = build_record_batch_message(rows as i64, columns.len(), &buffers, body.len());
+    let batch_block = writer.write_message(&batch, &body)?;
+
+    // End-of-stream marker, then the footer pointing at the batch
+    writer.write_all(&CONTINUATION.to_le_bytes())?;
+    writer.write_all(&0u32.to_le_bytes())?;
+
+    let footer = build_footer(&fields, batch_block);
+    writer.write_all(&footer)?;
+    writer.write_all(&(footer.len() as u32).to_le_bytes())?;
+    writer.write_all(MAGIC)?;
+    Ok(())
+}
+
+/// Location of a message within the file, as recorded in the footer
+#[derive(Clone, Copy)]
+struct Block {
+    offset: i64,
+    metadata_length: i32,
+    body_length: i64,
+}
+
+/// Tracks the write position so the footer can reference messages
+struct IpcWriter<'a, W: Write> {
+    out: &'a mut W,
+    position: u64,
+}
+
+impl<W: Write> IpcWriter<'_, W> {
+    fn write_all(&mut self, bytes: &[u8]) -> io::Result<()> {
+        self.out.write_all(bytes)?;
+        self.position += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Write an encapsulated message: continuation marker, metadata length,
+    /// FlatBuffer metadata padded to 8 bytes, then the body
+    fn write_message(&mut self, metadata: &[u8], body: &[u8]) -> io::Result<Block> {
+        let offset = self.position as i64;
+        let padding = (8 - (8 + metadata.len()) % 8) % 8;
+        let metadata_length = (metadata.len() + padding) as u32;
+
+        self.write_all(&CONTINUATION.to_le_bytes())?;
+        self.write_all(&metadata_length.to_le_bytes())?;
+        self.write_all(metadata)?;
+        self.write_all(&vec![0; padding])?;
+
+        let body_padding = (8 - body.len() % 8) % 8;
+        self.write_all(body)?;
+        self.write_all(&vec![0; body_padding])?;
+
+        Ok(Block {
+            offset,
+            metadata_length: 8 + metadata_length as i32,
+            body_length: (body.len() + body_padding) as i64,
+        })
+    }
+}
+
+/// Add the `Schema` table for `fields` to the builder
+fn add_schema(fb: &mut FlatBuilder, fields: &[ArrowField]) -> usize {
+    let field_offsets: Vec<usize> = fields
+        .iter()
+        .map(|field| {
+            let name = fb.create_string(field.name);
+            let metadata: Vec<usize> = field
+                .metadata
+                .iter()
+                .map(|(key, value)| {
+                    let key = fb.create_string(key);
+                    let value = fb.create_string(value);
+                    fb.start_table();
+                    fb.add_offset(0, key);
+                    fb.add_offset(1, value);
+                    fb.end_table()
+                })
+                .collect();
+            let metadata = fb.create_offset_vector(&metadata);
+            let children = fb.create_offset_vector(&[]);
+
+            fb.start_table();
+            fb.add_i16(0, PRECISION_DOUBLE);
+            let float_type = fb.end_table();
+
+            fb.start_table();
+            fb.add_offset(0, name);
+            fb.add_u8(1, 1); // nullable
+            fb.add_u8(2, TYPE_FLOATING_POINT);
+            fb.add_offset(3, float_type);
+            fb.add_offset(5, children);
+            fb.add_offset(6, metadata);
+            fb.end_table()
+        })
+        .collect();
+    let fields = fb.create_offset_vector(&field_offsets);
+
+    fb.start_table();
+    fb.add_i16(0, 0); // little-endian
+    fb.add_offset(1, fields);
+    fb.end_table()
+}
+
+fn build_schema_message(fields: &[ArrowField]) -> Vec<u8> {
+    let mut fb = FlatBuilder::default();
+    let schema = add_schema(&mut fb, fields);
+
+    fb.start_table();
+    fb.add_i16(0, METADATA_VERSION);
+    fb.add_u8(1, HEADER_SCHEMA);
+    fb.add_offset(2, schema);
+    fb.add_i64(3, 0);
+    let message = fb.end_table();
+    fb.finish(message)
+}
+
+fn build_record_batch_message(
+    rows: i64,
+    column_count: usize,
+    buffers: &[(i64, i64)],
+    body_length: usize,
+) -> Vec<u8> {
+    let mut fb = FlatBuilder::default();
+
+    // FieldNode { length, null_count } per column
+    let nodes: Vec<u8> = (0..column_count)
+        .flat_map(|_| [rows.to_le_bytes(), 0i64.to_le_bytes()].concat())
+        .collect();
+    let nodes = fb.create_struct_vector(&nodes, column_count, 8);
+    // Buffer { offset, length }
+    let buffer_bytes: Vec<u8> = buffers
+        .iter()
+        .flat_map(|(offset, length)| [offset.to_le_bytes(), length.to_le_bytes()].concat())
+        .collect();
+    let buffers = fb.create_struct_vector(&buffer_bytes, buffers.len(), 8);
+
+    fb.start_table();
+    fb.add_i64(0, rows);
+    fb.add_offset(1, nodes);
+    fb.add_offset(2, buffers);
+    let batch = fb.end_table();
+
+    fb.start_table();
+    fb.add_i16(0, METADATA_VERSION);
+    fb.add_u8(1, HEADER_RECORD_BATCH);
+    fb.add_offset(2, batch);
+    fb.add_i64(3, (body_length + (8 - body_length % 8) % 8) as i64);
+    let message = fb.end_table();
+    fb.finish(message)
+}
+
+fn build_footer(fields: &[ArrowField], batch: Block) -> Vec<u8> {
+    let mut fb = FlatBuilder::default();
+    let schema = add_schema(&mut fb, fields);
+
+    // Block { offset: i64, metaDataLength: i32, (padding), bodyLength: i64 }
+    let mut block = Vec::with_capacity(24);
+    block.extend_from_slice(&batch.offset.to_le_bytes());
+    block.extend_from_slice(&batch.metadata_length.to_le_bytes());
+    block.extend_from_slice(&[0; 4]);
+    block.extend_from_slice(&batch.body_length.to_le_bytes());
+    let record_batches = fb.create_struct_vector(&block, 1, 8);
+    let dictionaries = fb.create_struct_vector(&[], 0, 8);
+
+    fb.start_table();
+    fb.add_i16(0, METADATA_VERSION);
+    fb.add_offset(1, schema);
+    fb.add_offset(2, dictionaries);
+    fb.add_offset(3, record_batches);
+    let footer = fb.end_table();
+    fb.finish(footer)
+}
+
+/// Minimal back-to-front FlatBuffers builder.
+///
+/// Bytes are stored reversed so prepending is a push; offsets returned by
+/// the builder count bytes from the end of the finished buffer, as in the
+/// reference implementation.
+#[derive(Default)]
+struct FlatBuilder {
+    reversed: Vec<u8>,
+    min_align: usize,
+    /// (field slot, offset) for the table being built
+    fields: Vec<(u16, usize)>,
+    table_start: usize,
+}
+
+impl FlatBuilder {
+    fn offset(&self) -> usize {
+        self.reversed.len()
+    }
+
+    fn prepend(&mut self, bytes: &[u8]) {
+        self.reversed.extend(bytes.iter().rev());
+    }
+
+    /// Pad so that after writing `additional` bytes the position is aligned to `size`
+    fn prep(&mut self, size: usize, additional: usize) {
+        self.min_align = self.min_align.max(size);
+        let padding = (size - (self.offset() + additional) % size) % size;
+        self.reversed.extend(std::iter::repeat_n(0, padding));
+    }
+
+    fn prepend_uoffset(&mut self, target: usize) {
+        self.prep(4, 0);
+        let relative = (self.offset() + 4 - target) as u32;
+        self.prepend(&relative.to_le_bytes());
+    }
+
+    fn create_string(&mut self, text: &str) -> usize {
+        self.prep(4, text.len() + 1);
+        self.prepend(&[0]);
+        self.prepend(text.as_bytes());
+        self.prepend(&(text.len() as u32).to_le_bytes());
+        self.offset()
+    }
+
+    fn create_offset_vector(&mut self, offsets: &[usize]) -> usize {
+        self.prep(4, offsets.len() * 4);
+        for &target in offsets.iter().rev() {
+            self.prepend_uoffset(target);
+        }
+        self.prepend(&(offsets.len() as u32).to_le_bytes());
+        self.offset()
+    }
+
+    fn create_struct_vector(&mut self, bytes: &[u8], count: usize, align: usize) -> usize {
+        self.prep(4, bytes.len());
+        self.prep(align, bytes.len());
+        self.prepend(bytes);
+        self.prepend(&(count as u32).to_le_bytes());
+        self.offset()
+    }
+
+    fn start_table(&mut self) {
+        self.fields.clear();
+        self.table_start = self.offset();
+    }
+
+    fn add_scalar(&mut self, slot: u16, bytes: &[u8]) {
+        self.prep(bytes.len(), 0);
+        self.prepend(bytes);
+        self.fields.push((slot, self.offset()));
+    }
+
+    fn add_u8(&mut self, slot: u16, value: u8) {
+        self.add_scalar(slot, &[value]);
+    }
+
+    fn add_i16(&mut self, slot: u16, value: i16) {
+        self.add_scalar(slot, &value.to_le_bytes());
+    }
+
+    fn add_i64(&mut self, slot: u16, value: i64) {
+        self.add_scalar(slot, &value.to_le_bytes());
+    }
+
+    fn add_offset(&mut self, slot: u16, target: usize) {
+        self.prepend_uoffset(target);
+        self.fields.push((slot, self.offset()));
+    }
+
+    /// Write the table's vtable and return the table's offset
+    fn end_table(&mut self) -> usize {
+        // Placeholder for the soffset to the vtable
+        self.prep(4, 0);
+        self.prepend(&[0; 4]);
+        let table = self.offset();
+
+        let slot_count = self
+            .fields
+            .iter()
+            .map(|(slot, _)| slot + 1)
+            .max()
+            .unwrap_or(0);
+        let mut entries = vec![0u16; slot_count as usize];
+        for &(slot, offset) in &self.fields {
+            entries[slot as usize] = (table - offset) as u16;
+        }
+        for entry in entries.iter().rev() {
+            self.prepend(&entry.to_le_bytes());
+        }
+        self.prepend(&((table - self.table_start) as u16).to_le_bytes());
+        self.prepend(&(4 + 2 * slot_count).to_le_bytes());
+        let vtable = self.offset();
+
+        // vtable position = table position - soffset
+        let soffset = (vtable - table) as i32;
+        for (k, byte) in soffset.to_le_bytes().iter().enumerate() {
+            self.reversed[table - 1 - k] = *byte;
+        }
+        self.fields.clear();
+        table
+    }
+
+    /// Prepend the root offset and return the finished buffer
+    fn finish(mut self, root: usize) -> Vec<u8> {
+        let align = self.min_align.max(4);
+        self.prep(align, 4);
+        self.prepend_uoffset(root);
+        self.reversed.reverse();
+        self.reversed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::{GenericCsv, Parseable};
+
+    fn u32_at(buf: &[u8], pos: usize) -> usize {
+        u32::from_le_bytes(buf[pos..pos + 4].try_into().unwrap()) as usize
+    }
+
+    /// Position of a table field, or `None` if it's absent
+    fn field(buf: &[u8], table: usize, slot: usize) -> Option<usize> {
+        let soffset = i32::from_le_bytes(buf[table..table + 4].try_into().unwrap());
+        let vtable = (table as i64 - soffset as i64) as usize;
+        let vtable_size = u16::from_le_bytes([buf[vtable], buf[vtable + 1]]) as usize;
+        let entry = 4 + slot * 2;
+        if entry >= vtable_size {
+            return None;
+        }
+        let offset = u16::from_le_bytes([buf[vtable + entry], buf[vtable + entry + 1]]) as usize;
+        (offset != 0).then_some(table + offset)
+    }
+
+    fn deref(buf: &[u8], pos: usize) -> usize {
+        pos + u32_at(buf, pos)
+    }
+
+    fn string_at(buf: &[u8], pos: usize) -> String {
+        let len = u32_at(buf, pos);
+        String::from_utf8(buf[pos + 4..pos + 4 + len].to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_flatbuffer_tables() {
+        let mut fb = FlatBuilder::default();
+        let name = fb.create_string("rpm");
+        fb.start_table();
+        fb.add_offset(0, name);
+        fb.add_i16(2, 7);
+        let table = fb.end_table();
+        let buf = fb.finish(table);
+
+        let root = deref(&buf, 0);
+        assert_eq!(
+            string_at(&buf, deref(&buf, field(&buf, root, 0).unwrap())),
+            "rpm"
+        );
+        assert_eq!(field(&buf, root, 1), None);
+        let value = field(&buf, root, 2).unwrap();
+        assert_eq!(i16::from_le_bytes([buf[value], buf[value + 1]]), 7);
+    }
+
+    #[test]
+    fn test_write_arrow_file() {
+        let log = GenericCsv::default()
+            .parse("Time,RPM (rpm),TPS\n0,1000,5\n0.5,1500,10\n")
+            .unwrap();
+        let names = vec!["RPM".to_string(), "TPS".to_string()];
+        let mut out = Vec::new();
+        write_arrow(&log, &names, &mut out).unwrap();
+
+        assert_eq!(&out[..6], MAGIC);
+        assert_eq!(&out[out.len() - 6..], MAGIC);
+
+        // Footer -> schema -> field names
+        let footer_len = u32_at(&out, out.len() - 10);
+        let footer_start = out.len() - 10 - footer_len;
+        let footer = &out[footer_start..out.len() - 10];
+        let root = deref(footer, 0);
+        let schema = deref(footer, field(footer, root, 1).unwrap());
+        let fields = deref(footer, field(footer, schema, 1).unwrap());
+        assert_eq!(u32_at(footer, fields), 3);
+        let field_names: Vec<String> = (0..3)
+            .map(|i| {
+                let table = deref(footer, fields + 4 + i * 4);
+                string_at(footer, deref(footer, field(footer, table, 0).unwrap()))
+            })
+            .collect();
+        assert_eq!(field_names, vec!["time", "RPM", "TPS"]);
+
+        // Footer block -> record batch message -> second column values
+        let blocks = deref(footer, field(footer, root, 3).unwrap());
+        assert_eq!(u32_at(footer, blocks), 1);
+        let block = blocks + 4;
+        let offset = i64::from_le_bytes(footer[block..block + 8].try_into().unwrap()) as usize;
+        let meta_len = u32_at(footer, block + 8);
+        assert_eq!(u32_at(&out, offset), CONTINUATION as usize);
+        assert_eq!(offset % 8, 0);
+
+        let body = offset + meta_len;
+        let rpm = body + 2 * 8; // after the time column's two values
+        let value = f64::from_le_bytes(out[rpm + 8..rpm + 16].try_into().unwrap());
+        assert_eq!(value, 1500.0);
+    }
+
+    /// Fixture written by `write_arrow` from [`golden_log`] and read back
+    /// with the Arrow project's own Rust reader
+    const GOLDEN_FILE: &[u8] = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/exampleLogs/arrow/golden.arrow"
+    ));
+
+    fn golden_log() -> (Log, Vec<String>) {
+        let log = GenericCsv::default()
+            .parse("Time,RPM (rpm),TPS (%)\n0,1000,5\n0.5,1500,10\n1,2250,12.5\n")
+            .unwrap();
+        (log, vec!["Engine Speed".to_string(), "TPS".to_string()])
+    }
+
+    #[test]
+    fn test_write_arrow_matches_golden_file() {
+        let (log, names) = golden_log();
+        let mut out = Vec::new();
+        write_arrow(&log, &names, &mut out).unwrap();
+        assert!(
+            out == GOLDEN_FILE,
+            "write_arrow output differs from exampleLogs/arrow/golden.arrow"
+        );
+    }
+
+    #[test]
+    fn test_golden_file_reads_with_arrow_rs() {
+        use arrow_array::cast::AsArray;
+        use arrow_array::types::Float64Type;
+
+        let reader =
+            arrow_ipc::reader::FileReader::try_new(std::io::Cursor::new(GOLDEN_FILE), None)
+                .unwrap();
+        let schema = reader.schema();
+        let names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, vec!["time", "Engine Speed", "TPS"]);
+        let rpm = schema.field(1);
+        assert_eq!(rpm.data_type(), &arrow_schema::DataType::Float64);
+        assert_eq!(rpm.metadata()["unit"], "rpm");
+        assert_eq!(rpm.metadata()["original_name"], "RPM");
+
+        let batches: Vec<_> = reader.map(|batch| batch.unwrap()).collect();
+        assert_eq!(batches.len(), 1);
+        let column = |idx: usize| -> Vec<f64> {
+            batches[0]
+                .column(idx)
+                .as_primitive::<Float64Type>()
+                .values()
+                .to_vec()
+        };
+        assert_eq!(column(0), vec![0.0, 0.5, 1.0]);
+        assert_eq!(column(1), vec![1000.0, 1500.0, 2250.0]);
+        assert_eq!(column(2), vec![5.0, 10.0, 12.5]);
+    }
+}
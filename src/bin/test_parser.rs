@@ -2,7 +2,9 @@ use std::env;
 use std::fs;
 
 // Import from the library
-use ultralog::parsers::{EcuMaster, EcuType, Haltech, Parseable};
+use ultralog::parsers::{
+    Cobb, CsvProfile, EcuMaster, EcuType, Haltech, HpTuners, Parseable, ProfileCsv,
+};
 
 fn main() {
     // Get file path from command line or use default
@@ -29,6 +31,36 @@ fn main() {
                 std::process::exit(1);
             }
         }
+    } else if Cobb::detect(&contents) {
+        println!("\nDetected: Cobb Accessport format");
+        println!("Parsing Cobb log...");
+        match Cobb.parse(&contents) {
+            Ok(log) => (EcuType::Cobb, log),
+            Err(e) => {
+                eprintln!("Parse error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else if HpTuners::detect(&contents) {
+        println!("\nDetected: HP Tuners format");
+        println!("Parsing HP Tuners log...");
+        match HpTuners.parse(&contents) {
+            Ok(log) => (EcuType::HpTuners, log),
+            Err(e) => {
+                eprintln!("Parse error: {}", e);
+                std::process::exit(1);
+            }
+        }
+    } else if let Some(profile) = CsvProfile::detect(&contents) {
+        println!("\nDetected: {} format", profile.name);
+        println!("Parsing {} log...", profile.name);
+        match ProfileCsv(profile).parse(&contents) {
+            Ok(log) => (profile.ecu_type, log),
+            Err(e) => {
+                eprintln!("Parse error: {}", e);
+                std::process::exit(1);
+            }
+        }
     } else {
         println!("\nDetected: Haltech format");
         println!("Parsing Haltech log...");
@@ -45,7 +77,7 @@ fn main() {
     println!("\n=== Parse Results ===");
     println!("ECU Type: {}", ecu_type.name());
     println!("Channels: {}", log.channels.len());
-    println!("Data points: {}", log.data.len());
+    println!("Data points: {}", log.record_count());
     println!(
         "Time range: {:.3} to {:.3} seconds",
         log.times.first().unwrap_or(&0.0),
@@ -88,13 +120,14 @@ fn main() {
     println!("  Time      | {}", header.join(" | "));
     println!("  ----------+-{}", ["-------------"; 8].join("-+-"));
 
-    for (time, row) in log.times.iter().zip(log.data.iter()).take(5) {
-        let values: Vec<String> = row
+    for (record, time) in log.times.iter().enumerate().take(5) {
+        let values: Vec<String> = log
+            .channels
             .iter()
-            .zip(log.channels.iter())
+            .enumerate()
             .take(8)
-            .map(|(v, c)| {
-                let val = v.as_f64();
+            .filter_map(|(i, c)| Some((log.value_at(i, record)?, c)))
+            .map(|(val, c)| {
                 let unit = c.unit();
                 if unit.is_empty() {
                     format!("{:>12.2}", val)
@@ -108,7 +141,7 @@ fn main() {
 
     // Show some key channels with values
     println!("\n=== Key Channel Values (first data point) ===");
-    if let Some(first_row) = log.data.first() {
+    if log.record_count() > 0 {
         for (i, channel) in log.channels.iter().enumerate() {
             let name = channel.name().to_lowercase();
             // Show specific interesting channels
@@ -128,9 +161,9 @@ fn main() {
                 || name.contains("speed")
                 || name.contains("temp")
             {
-                if let Some(value) = first_row.get(i) {
+                if let Some(value) = log.value_at(i, 0) {
                     let unit = channel.unit();
-                    println!("  {}: {:.2} {}", channel.name(), value.as_f64(), unit);
+                    println!("  {}: {:.2} {}", channel.name(), value, unit);
                 }
             }
         }
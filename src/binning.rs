@@ -0,0 +1,418 @@
+//! 2D binning of channel data for scatter plot surfaces and cell statistics.
+//!
+//! Samples are assigned to the nearest bin center on each axis, matching how
+//! ECU tables look up values at their breakpoints.
+
+/// Bin centers along one axis, in ascending order
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxisBins {
+    centers: Vec<f64>,
+}
+
+impl AxisBins {
+    /// Evenly spaced bins covering `min..=max`
+    pub fn uniform(min: f64, max: f64, count: usize) -> Self {
+        let count = count.max(1);
+        if count == 1 || (max - min).abs() < f64::EPSILON {
+            return Self {
+                centers: vec![min; 1],
+            };
+        }
+        let step = (max - min) / (count - 1) as f64;
+        Self {
+            centers: (0..count).map(|i| min + i as f64 * step).collect(),
+        }
+    }
+
+    /// Bins centered on explicit breakpoints (sorted and de-duplicated)
+    pub fn from_breakpoints(breakpoints: &[f64]) -> Option<Self> {
+        let mut centers: Vec<f64> = breakpoints
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .collect();
+        centers.sort_by(|a, b| a.total_cmp(b));
+        centers.dedup();
+        if centers.is_empty() {
+            None
+        } else {
+            Some(Self { centers })
+        }
+    }
+
+    /// Bin center values
+    pub fn centers(&self) -> &[f64] {
+        &self.centers
+    }
+
+    /// Number of bins
+    pub fn len(&self) -> usize {
+        self.centers.len()
+    }
+
+    /// Whether there are no bins
+    pub fn is_empty(&self) -> bool {
+        self.centers.is_empty()
+    }
+
+    /// Index of the nearest bin center for a value
+    pub fn index_of(&self, value: f64) -> Option<usize> {
+        if !value.is_finite() || self.centers.is_empty() {
+            return None;
+        }
+        let pos = self.centers.partition_point(|&c| c < value);
+        if pos == 0 {
+            return Some(0);
+        }
+        if pos == self.centers.len() {
+            return Some(pos - 1);
+        }
+        let below = value - self.centers[pos - 1];
+        let above = self.centers[pos] - value;
+        Some(if below <= above { pos - 1 } else { pos })
+    }
+}
+
+/// Parse a user-entered list of breakpoints separated by commas, semicolons
+/// or whitespace. An empty list means automatic binning.
+pub fn parse_breakpoints(text: &str) -> Result<Vec<f64>, String> {
+    text.split(|c: char| c == ',' || c == ';' || c.is_whitespace())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            part.parse::<f64>()
+                .ok()
+                .filter(|v| v.is_finite())
+                .ok_or_else(|| format!("Invalid breakpoint: {}", part))
+        })
+        .collect()
+}
+
+/// Accumulated statistics for a single cell
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CellStats {
+    /// Number of samples in the cell
+    pub count: u32,
+    /// Sum of Z values
+    pub sum: f64,
+    /// Minimum Z value
+    pub min: f64,
+    /// Maximum Z value
+    pub max: f64,
+}
+
+impl Default for CellStats {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            sum: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl CellStats {
+    /// Add a Z sample to the cell
+    pub fn add(&mut self, z: f64) {
+        self.count += 1;
+        self.sum += z;
+        self.min = self.min.min(z);
+        self.max = self.max.max(z);
+    }
+
+    /// Average Z value, if the cell has samples
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum / self.count as f64)
+    }
+}
+
+/// Extracts one statistic from a cell, `None` for empty cells
+type CellStat = fn(&CellStats) -> Option<f64>;
+
+/// A grid of cell statistics over X/Y bins
+#[derive(Clone, Debug)]
+pub struct BinnedGrid {
+    /// X axis bins
+    pub x: AxisBins,
+    /// Y axis bins
+    pub y: AxisBins,
+    /// Cells in row-major order (`y * x.len() + x`)
+    cells: Vec<CellStats>,
+}
+
+impl BinnedGrid {
+    /// Bin samples into the grid. When `z` is `None`, cell statistics track
+    /// hit counts only (Z is recorded as 0).
+    pub fn build(x: AxisBins, y: AxisBins, xs: &[f64], ys: &[f64], zs: Option<&[f64]>) -> Self {
+        let mut cells = vec![CellStats::default(); x.len() * y.len()];
+        let n = xs
+            .len()
+            .min(ys.len())
+            .min(zs.map_or(usize::MAX, |z| z.len()));
+
+        for i in 0..n {
+            let (Some(xi), Some(yi)) = (x.index_of(xs[i]), y.index_of(ys[i])) else {
+                continue;
+            };
+            let z = zs.map_or(0.0, |z| z[i]);
+            if !z.is_finite() {
+                continue;
+            }
+            cells[yi * x.len() + xi].add(z);
+        }
+
+        Self { x, y, cells }
+    }
+
+    /// Statistics for the cell at (x, y) bin indices
+    pub fn cell(&self, x: usize, y: usize) -> &CellStats {
+        &self.cells[y * self.x.len() + x]
+    }
+
+    /// Highest hit count across all cells
+    pub fn max_count(&self) -> u32 {
+        self.cells.iter().map(|c| c.count).max().unwrap_or(0)
+    }
+
+    /// Range of cell averages (min, max), if any cell has samples
+    pub fn mean_range(&self) -> Option<(f64, f64)> {
+        self.cells
+            .iter()
+            .filter_map(|c| c.mean())
+            .fold(None, |acc, m| match acc {
+                None => Some((m, m)),
+                Some((lo, hi)) => Some((lo.min(m), hi.max(m))),
+            })
+    }
+
+    /// Cell averages with empty cells filled from neighbouring cells.
+    ///
+    /// Empty cells take the average of their filled 8-neighbours, repeated
+    /// until the grid is complete, giving a continuous surface. Returns
+    /// `None` entries only when the grid has no samples at all.
+    pub fn interpolated_means(&self) -> Vec<Option<f64>> {
+        let (nx, ny) = (self.x.len(), self.y.len());
+        let mut values: Vec<Option<f64>> = self.cells.iter().map(|c| c.mean()).collect();
+
+        if values.iter().all(|v| v.is_none()) {
+            return values;
+        }
+
+        while values.iter().any(|v| v.is_none()) {
+            let snapshot = values.clone();
+            for yi in 0..ny {
+                for xi in 0..nx {
+                    if snapshot[yi * nx + xi].is_some() {
+                        continue;
+                    }
+                    let mut sum = 0.0;
+                    let mut count = 0;
+                    for dy in -1i64..=1 {
+                        for dx in -1i64..=1 {
+                            let (nx_i, ny_i) = (xi as i64 + dx, yi as i64 + dy);
+                            if nx_i < 0 || ny_i < 0 || nx_i >= nx as i64 || ny_i >= ny as i64 {
+                                continue;
+                            }
+                            if let Some(v) = snapshot[ny_i as usize * nx + nx_i as usize] {
+                                sum += v;
+                                count += 1;
+                            }
+                        }
+                    }
+                    if count > 0 {
+                        values[yi * nx + xi] = Some(sum / count as f64);
+                    }
+                }
+            }
+        }
+
+        values
+    }
+
+    /// Write the cell statistics as CSV tables ready to paste into a tuning
+    /// table: one block each for average, min, and max of `z_name` (when Z was
+    /// binned) followed by hit counts. Rows are Y bins, columns are X bins,
+    /// and empty cells are left blank.
+    pub fn to_csv(&self, x_name: &str, y_name: &str, z_name: Option<&str>) -> String {
+        let mut out = String::new();
+
+        if let Some(z_name) = z_name {
+            let stats: [(&str, CellStat); 3] = [
+                ("Average", |c| c.mean()),
+                ("Min", |c| (c.count > 0).then_some(c.min)),
+                ("Max", |c| (c.count > 0).then_some(c.max)),
+            ];
+            for (label, stat) in stats {
+                self.write_csv_table(
+                    &mut out,
+                    &format!("{} {}", label, z_name),
+                    x_name,
+                    y_name,
+                    |c| stat(c).map(format_csv_number).unwrap_or_default(),
+                );
+                out.push('\n');
+            }
+        }
+
+        self.write_csv_table(&mut out, "Hits", x_name, y_name, |c| {
+            if c.count > 0 {
+                c.count.to_string()
+            } else {
+                String::new()
+            }
+        });
+
+        out
+    }
+
+    /// Append one titled table of per-cell values to `out`
+    fn write_csv_table(
+        &self,
+        out: &mut String,
+        title: &str,
+        x_name: &str,
+        y_name: &str,
+        value: impl Fn(&CellStats) -> String,
+    ) {
+        out.push_str(&csv_field(title));
+        out.push('\n');
+
+        out.push_str(&csv_field(&format!("{} \\ {}", y_name, x_name)));
+        for &x in self.x.centers() {
+            out.push(',');
+            out.push_str(&format_csv_number(x));
+        }
+        out.push('\n');
+
+        for (yi, &y) in self.y.centers().iter().enumerate() {
+            out.push_str(&format_csv_number(y));
+            for xi in 0..self.x.len() {
+                out.push(',');
+                out.push_str(&value(self.cell(xi, yi)));
+            }
+            out.push('\n');
+        }
+    }
+}
+
+/// Format a number compactly (up to 4 decimals, trailing zeros trimmed)
+fn format_csv_number(value: f64) -> String {
+    let text = format!("{:.4}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// Quote a CSV field if it contains a delimiter, quote, or newline
+pub(crate) fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uniform_bins() {
+        let axis = AxisBins::uniform(0.0, 100.0, 5);
+        assert_eq!(axis.centers(), &[0.0, 25.0, 50.0, 75.0, 100.0]);
+        assert_eq!(axis.index_of(-10.0), Some(0));
+        assert_eq!(axis.index_of(12.0), Some(0));
+        assert_eq!(axis.index_of(13.0), Some(1));
+        assert_eq!(axis.index_of(1000.0), Some(4));
+        assert_eq!(axis.index_of(f64::NAN), None);
+    }
+
+    #[test]
+    fn test_breakpoints_sorted() {
+        let axis = AxisBins::from_breakpoints(&[3000.0, 1000.0, 2000.0, 2000.0]).unwrap();
+        assert_eq!(axis.centers(), &[1000.0, 2000.0, 3000.0]);
+        assert_eq!(axis.index_of(2600.0), Some(2));
+        assert!(AxisBins::from_breakpoints(&[]).is_none());
+    }
+
+    #[test]
+    fn test_parse_breakpoints() {
+        assert_eq!(
+            parse_breakpoints("500, 1000;1500 2000").unwrap(),
+            vec![500.0, 1000.0, 1500.0, 2000.0]
+        );
+        assert_eq!(parse_breakpoints("  ").unwrap(), Vec::<f64>::new());
+        assert!(parse_breakpoints("500, abc").is_err());
+    }
+
+    #[test]
+    fn test_grid_stats() {
+        let grid = BinnedGrid::build(
+            AxisBins::uniform(0.0, 1.0, 2),
+            AxisBins::uniform(0.0, 1.0, 2),
+            &[0.0, 0.1, 1.0],
+            &[0.0, 0.0, 1.0],
+            Some(&[10.0, 20.0, 5.0]),
+        );
+        let cell = grid.cell(0, 0);
+        assert_eq!(cell.count, 2);
+        assert_eq!(cell.mean(), Some(15.0));
+        assert_eq!((cell.min, cell.max), (10.0, 20.0));
+        assert_eq!(grid.cell(1, 1).count, 1);
+        assert_eq!(grid.cell(1, 0).mean(), None);
+        assert_eq!(grid.max_count(), 2);
+        assert_eq!(grid.mean_range(), Some((5.0, 15.0)));
+    }
+
+    #[test]
+    fn test_interpolated_means_fill_gaps() {
+        let grid = BinnedGrid::build(
+            AxisBins::uniform(0.0, 2.0, 3),
+            AxisBins::uniform(0.0, 0.0, 1),
+            &[0.0, 2.0],
+            &[0.0, 0.0],
+            Some(&[10.0, 20.0]),
+        );
+        let values = grid.interpolated_means();
+        assert_eq!(values, vec![Some(10.0), Some(15.0), Some(20.0)]);
+    }
+
+    #[test]
+    fn test_csv_tables() {
+        let grid = BinnedGrid::build(
+            AxisBins::uniform(1000.0, 2000.0, 2),
+            AxisBins::uniform(0.0, 100.0, 2),
+            &[1000.0, 1000.0, 2000.0],
+            &[0.0, 0.0, 100.0],
+            Some(&[12.5, 13.5, 11.0]),
+        );
+        let csv = grid.to_csv("RPM", "MAP, kPa", Some("AFR"));
+        let blocks: Vec<&str> = csv.split("\n\n").collect();
+        assert_eq!(blocks.len(), 4);
+        assert_eq!(
+            blocks[0],
+            "Average AFR\n\"MAP, kPa \\ RPM\",1000,2000\n0,13,\n100,,11"
+        );
+        assert!(blocks[1].ends_with("0,12.5,\n100,,11"));
+        assert!(blocks[2].ends_with("0,13.5,\n100,,11"));
+        assert_eq!(
+            blocks[3],
+            "Hits\n\"MAP, kPa \\ RPM\",1000,2000\n0,2,\n100,,1\n"
+        );
+    }
+
+    #[test]
+    fn test_csv_hits_only() {
+        let grid = BinnedGrid::build(
+            AxisBins::uniform(0.0, 1.0, 2),
+            AxisBins::uniform(0.0, 0.0, 1),
+            &[0.0, 1.0, 1.0],
+            &[0.0, 0.0, 0.0],
+            None,
+        );
+        assert_eq!(grid.to_csv("X", "Y", None), "Hits\nY \\ X,0,1\n0,1,2\n");
+    }
+}
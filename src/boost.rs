@@ -0,0 +1,143 @@
+//! Boost control diagnostics.
+//!
+//! Compares manifold pressure against the boost controller's target over a
+//! WOT pull: how far boost overshoots the target, how long it takes to
+//! settle within [`SETTLED_BAND`] of it, and the wastegate duty the
+//! controller holds once it has.
+
+use std::collections::BTreeMap;
+
+/// Error around the target that counts as settled, as a fraction of the target
+pub const SETTLED_BAND: f64 = 0.05;
+
+/// How the boost controller tracked its target over one pull
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoostReport {
+    /// Highest target over the pull
+    pub peak_target: f64,
+    /// Most actual pressure exceeded the target by (0 if it never did)
+    pub overshoot: f64,
+    /// Time of the largest overshoot (seconds), if boost went over target
+    pub overshoot_time: Option<f64>,
+    /// Seconds from the start of the pull until pressure stays within the
+    /// settled band to its end, if it does
+    pub settling_time: Option<f64>,
+    /// Mean of actual minus target once settled
+    pub steady_error: Option<f64>,
+    /// Mean wastegate duty once settled
+    pub steady_duty: Option<f64>,
+    /// Gear the pull was mostly in
+    pub gear: Option<i64>,
+}
+
+/// Analyze one pull. All slices hold the pull's records only, with target
+/// and actual pressure in the same units. Returns `None` if the pull has no
+/// finite target and pressure values.
+pub fn analyze(
+    times: &[f64],
+    target: &[f64],
+    actual: &[f64],
+    duty: Option<&[f64]>,
+    gear: Option<&[f64]>,
+) -> Option<BoostReport> {
+    let len = times.len().min(target.len()).min(actual.len());
+    let valid: Vec<usize> = (0..len)
+        .filter(|&i| target[i].is_finite() && actual[i].is_finite())
+        .collect();
+    let (&first, &last) = (valid.first()?, valid.last()?);
+
+    let peak_target = valid.iter().map(|&i| target[i]).fold(f64::MIN, f64::max);
+
+    let (overshoot, overshoot_time) = valid
+        .iter()
+        .map(|&i| (actual[i] - target[i], i))
+        .filter(|(over, _)| *over > 0.0)
+        .fold((0.0, None), |(best, at), (over, i)| {
+            if over > best {
+                (over, Some(times[i]))
+            } else {
+                (best, at)
+            }
+        });
+
+    // Settled from the record after the last one outside the band
+    let in_band = |i: usize| (actual[i] - target[i]).abs() <= SETTLED_BAND * target[i].abs();
+    let settled_from = match valid.iter().rev().find(|&&i| !in_band(i)) {
+        None => Some(first),
+        Some(&i) if i < last => valid.iter().copied().find(|&j| j > i),
+        Some(_) => None,
+    };
+    let settled: Vec<usize> = settled_from
+        .map(|from| valid.iter().copied().filter(|&i| i >= from).collect())
+        .unwrap_or_default();
+
+    let mean = |values: &[f64]| -> Option<f64> {
+        let finite: Vec<f64> = settled
+            .iter()
+            .filter_map(|&i| values.get(i).copied())
+            .filter(|v| v.is_finite())
+            .collect();
+        (!finite.is_empty()).then(|| finite.iter().sum::<f64>() / finite.len() as f64)
+    };
+    let errors: Vec<f64> = (0..len).map(|i| actual[i] - target[i]).collect();
+
+    Some(BoostReport {
+        peak_target,
+        overshoot,
+        overshoot_time,
+        settling_time: settled_from.map(|from| times[from] - times[first]),
+        steady_error: mean(&errors),
+        steady_duty: duty.and_then(mean),
+        gear: gear.and_then(most_common_gear),
+    })
+}
+
+/// The gear seen in the most records, ignoring neutral
+fn most_common_gear(gear: &[f64]) -> Option<i64> {
+    let mut counts: BTreeMap<i64, usize> = BTreeMap::new();
+    for g in gear.iter().filter(|g| g.is_finite() && **g >= 1.0) {
+        *counts.entry(g.round() as i64).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .map(|(gear, _)| gear)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overshoot_and_settling() {
+        let times: Vec<f64> = (0..10).map(f64::from).collect();
+        let target = [200.0; 10];
+        let actual = [
+            100.0, 150.0, 190.0, 215.0, 205.0, 198.0, 201.0, 200.0, 199.0, 200.0,
+        ];
+        let duty = [90.0, 90.0, 80.0, 40.0, 50.0, 60.0, 60.0, 60.0, 60.0, 60.0];
+        let gear = [3.0; 10];
+
+        let report = analyze(&times, &target, &actual, Some(&duty), Some(&gear)).unwrap();
+        assert_eq!(report.peak_target, 200.0);
+        assert_eq!(report.overshoot, 15.0);
+        assert_eq!(report.overshoot_time, Some(3.0));
+        assert_eq!(report.settling_time, Some(4.0));
+        assert!((report.steady_duty.unwrap() - 350.0 / 6.0).abs() < 1e-9);
+        assert!((report.steady_error.unwrap() - 0.5).abs() < 1e-9);
+        assert_eq!(report.gear, Some(3));
+    }
+
+    #[test]
+    fn test_never_settles() {
+        let times = [0.0, 1.0, 2.0];
+        let report = analyze(&times, &[200.0; 3], &[120.0, 150.0, 170.0], None, None).unwrap();
+        assert_eq!(report.overshoot, 0.0);
+        assert_eq!(report.overshoot_time, None);
+        assert_eq!(report.settling_time, None);
+        assert_eq!(report.steady_duty, None);
+        assert_eq!(report.gear, None);
+
+        assert!(analyze(&times, &[f64::NAN; 3], &[1.0; 3], None, None).is_none());
+    }
+}
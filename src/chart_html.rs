@@ -0,0 +1,242 @@
+//! Interactive HTML export of a chart view.
+//!
+//! Writes a single self-contained page: the series are embedded as JSON and
+//! drawn on a canvas by a small inline script, so the file opens in any
+//! browser without UltraLog or a network connection. Like the app's chart,
+//! each series is scaled to its own range; hovering shows the real values,
+//! the mouse wheel zooms, dragging pans, double-clicking resets the view and
+//! clicking a legend entry hides or shows its series.
+
+use serde::Serialize;
+
+/// Most points embedded per series; longer series are reduced with LTTB
+pub const MAX_POINTS_PER_SERIES: usize = 5000;
+
+/// One channel of the exported chart
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct HtmlSeries {
+    pub name: String,
+    /// Display unit, empty if the channel has none
+    pub unit: String,
+    pub color: [u8; 3],
+    /// (time in seconds, value in display units)
+    pub points: Vec<[f64; 2]>,
+}
+
+#[derive(Serialize)]
+struct ChartData<'a> {
+    title: &'a str,
+    subtitle: &'a str,
+    series: &'a [HtmlSeries],
+}
+
+/// The drawing script. It reads the `DATA` object defined before it.
+const SCRIPT: &str = r#"
+const canvas = document.getElementById('chart');
+const ctx = canvas.getContext('2d');
+const tooltip = document.getElementById('tooltip');
+const legend = document.getElementById('legend');
+const pad = { left: 16, right: 16, top: 12, bottom: 28 };
+
+const series = DATA.series.map(s => {
+  const values = s.points.map(p => p[1]).filter(Number.isFinite);
+  let min = Math.min(...values), max = Math.max(...values);
+  if (!Number.isFinite(min)) { min = 0; max = 1; }
+  if (max - min < 1e-9) { min -= 0.5; max += 0.5; }
+  return { ...s, min, max, visible: true };
+});
+const allTimes = series.flatMap(s => s.points.length ? [s.points[0][0], s.points[s.points.length - 1][0]] : []);
+const full = { min: Math.min(...allTimes), max: Math.max(...allTimes) };
+if (!Number.isFinite(full.min)) { full.min = 0; full.max = 1; }
+let view = { ...full };
+let hoverX = null;
+let drag = null;
+
+function rgb(c) { return `rgb(${c[0]},${c[1]},${c[2]})`; }
+function fmtTime(t) {
+  const m = Math.floor(t / 60), s = t - m * 60;
+  return `${m}:${s.toFixed(2).padStart(5, '0')}`;
+}
+function plotWidth() { return canvas.clientWidth - pad.left - pad.right; }
+function plotHeight() { return canvas.clientHeight - pad.top - pad.bottom; }
+function toX(t) { return pad.left + (t - view.min) / (view.max - view.min) * plotWidth(); }
+function toTime(x) { return view.min + (x - pad.left) / plotWidth() * (view.max - view.min); }
+function toY(s, v) { return pad.top + (1 - (v - s.min) / (s.max - s.min)) * plotHeight(); }
+
+// Index of the point nearest to time t
+function nearest(points, t) {
+  let lo = 0, hi = points.length - 1;
+  while (hi - lo > 1) {
+    const mid = (lo + hi) >> 1;
+    if (points[mid][0] < t) lo = mid; else hi = mid;
+  }
+  return Math.abs(points[lo][0] - t) <= Math.abs(points[hi][0] - t) ? lo : hi;
+}
+
+function draw() {
+  const dpr = window.devicePixelRatio || 1;
+  canvas.width = canvas.clientWidth * dpr;
+  canvas.height = canvas.clientHeight * dpr;
+  ctx.setTransform(dpr, 0, 0, dpr, 0, 0);
+  ctx.clearRect(0, 0, canvas.clientWidth, canvas.clientHeight);
+
+  ctx.strokeStyle = '#3a3a3a';
+  ctx.fillStyle = '#aaa';
+  ctx.font = '12px sans-serif';
+  ctx.textAlign = 'center';
+  for (let i = 0; i <= 8; i++) {
+    const t = view.min + (view.max - view.min) * i / 8;
+    const x = toX(t);
+    ctx.beginPath(); ctx.moveTo(x, pad.top); ctx.lineTo(x, pad.top + plotHeight()); ctx.stroke();
+    ctx.fillText(fmtTime(t), x, canvas.clientHeight - 8);
+  }
+
+  ctx.save();
+  ctx.beginPath(); ctx.rect(pad.left, pad.top, plotWidth(), plotHeight()); ctx.clip();
+  for (const s of series) {
+    if (!s.visible || !s.points.length) continue;
+    ctx.strokeStyle = rgb(s.color);
+    ctx.lineWidth = 1.5;
+    ctx.beginPath();
+    let drawing = false;
+    for (const [t, v] of s.points) {
+      if (!Number.isFinite(v)) { drawing = false; continue; }
+      const x = toX(t), y = toY(s, v);
+      if (drawing) ctx.lineTo(x, y); else ctx.moveTo(x, y);
+      drawing = true;
+    }
+    ctx.stroke();
+  }
+  ctx.restore();
+
+  if (hoverX === null) { tooltip.style.display = 'none'; return; }
+  const t = toTime(hoverX);
+  ctx.strokeStyle = '#00ffff';
+  ctx.beginPath(); ctx.moveTo(hoverX, pad.top); ctx.lineTo(hoverX, pad.top + plotHeight()); ctx.stroke();
+  const rows = [`<b>${fmtTime(t)}</b>`];
+  for (const s of series) {
+    if (!s.visible || !s.points.length) continue;
+    const v = s.points[nearest(s.points, t)][1];
+    rows.push(`<span style="color:${rgb(s.color)}">■</span> ${s.name}: ${Number.isFinite(v) ? v.toFixed(2) : '-'} ${s.unit}`);
+  }
+  tooltip.innerHTML = rows.join('<br>');
+  tooltip.style.display = 'block';
+  const left = hoverX + 16 + tooltip.offsetWidth > canvas.clientWidth ? hoverX - 16 - tooltip.offsetWidth : hoverX + 16;
+  tooltip.style.left = `${left}px`;
+}
+
+canvas.addEventListener('mousemove', e => {
+  if (drag) {
+    const shift = (drag.x - e.offsetX) / plotWidth() * (drag.view.max - drag.view.min);
+    const span = drag.view.max - drag.view.min;
+    view.min = Math.min(Math.max(drag.view.min + shift, full.min), full.max - span);
+    view.max = view.min + span;
+  }
+  hoverX = e.offsetX;
+  draw();
+});
+canvas.addEventListener('mouseleave', () => { hoverX = null; drag = null; draw(); });
+canvas.addEventListener('mousedown', e => { drag = { x: e.offsetX, view: { ...view } }; });
+window.addEventListener('mouseup', () => { drag = null; });
+canvas.addEventListener('dblclick', () => { view = { ...full }; draw(); });
+canvas.addEventListener('wheel', e => {
+  e.preventDefault();
+  const t = toTime(e.offsetX);
+  const factor = e.deltaY < 0 ? 0.8 : 1.25;
+  const span = Math.min((view.max - view.min) * factor, full.max - full.min);
+  const ratio = (t - view.min) / (view.max - view.min);
+  view.min = Math.max(full.min, t - span * ratio);
+  view.max = Math.min(full.max, view.min + span);
+  view.min = view.max - span;
+  draw();
+}, { passive: false });
+window.addEventListener('resize', draw);
+
+for (const s of series) {
+  const item = document.createElement('span');
+  item.className = 'item';
+  item.innerHTML = `<span class="swatch" style="background:${rgb(s.color)}"></span>`;
+  item.append(s.unit ? `${s.name} (${s.unit})` : s.name);
+  item.addEventListener('click', () => {
+    s.visible = !s.visible;
+    item.classList.toggle('hidden', !s.visible);
+    draw();
+  });
+  legend.append(item);
+}
+draw();
+"#;
+
+/// The chart as a standalone HTML page
+pub fn chart_html(title: &str, subtitle: &str, series: &[HtmlSeries]) -> String {
+    let data = ChartData {
+        title,
+        subtitle,
+        series,
+    };
+    // Non-finite values (gaps) become null, which the script skips.
+    // "</" is escaped so no value can close the script element.
+    let json = serde_json::to_string(&data)
+        .unwrap_or_else(|_| "{\"series\":[]}".to_string())
+        .replace("</", "<\\/");
+    let title = crate::report::escape_html(title);
+    let subtitle = crate::report::escape_html(subtitle);
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body {{ background: #1e1e1e; color: #ddd; font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; margin: 1.5em; }}
+h1 {{ font-size: 1.4em; margin: 0; }}
+.subtitle {{ color: #999; margin: 0.3em 0 1em; }}
+#wrap {{ position: relative; }}
+#chart {{ width: 100%; height: 70vh; background: #282828; display: block; cursor: crosshair; }}
+#tooltip {{ position: absolute; top: 12px; display: none; background: rgba(20, 20, 20, 0.9); border: 1px solid #555; padding: 6px 8px; font-size: 12px; pointer-events: none; white-space: nowrap; }}
+#legend {{ margin-top: 0.8em; }}
+.item {{ margin-right: 1.2em; cursor: pointer; user-select: none; display: inline-block; }}
+.item.hidden {{ opacity: 0.35; }}
+.swatch {{ display: inline-block; width: 12px; height: 12px; margin-right: 6px; vertical-align: -1px; }}
+.help {{ color: #777; font-size: 0.85em; margin-top: 1em; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<p class="subtitle">{subtitle}</p>
+<div id="wrap"><canvas id="chart"></canvas><div id="tooltip"></div></div>
+<div id="legend"></div>
+<p class="help">Scroll to zoom, drag to pan, double-click to reset. Click a channel to hide or show it. Each channel is scaled to its own range. Exported from UltraLog {version}.</p>
+<script>
+const DATA = {json};
+{script}</script>
+</body>
+</html>
+"#,
+        version = env!("CARGO_PKG_VERSION"),
+        script = SCRIPT,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chart_html() {
+        let series = [HtmlSeries {
+            name: "AFR </script>".to_string(),
+            unit: "AFR".to_string(),
+            color: [113, 120, 78],
+            points: vec![[0.0, 14.7], [0.1, f64::NAN], [0.2, 12.5]],
+        }];
+        let html = chart_html("Run 1 & 2", "0:00.00 to 0:00.20", &series);
+
+        assert!(html.contains("<title>Run 1 &amp; 2</title>"));
+        assert!(html.contains(r#""points":[[0.0,14.7],[0.1,null],[0.2,12.5]]"#));
+        assert!(html.contains(r#""name":"AFR <\/script>""#));
+        assert_eq!(html.matches("</script>").count(), 1);
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+}
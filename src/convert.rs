@@ -0,0 +1,318 @@
+//! Headless log conversion for scripting and batch workflows.
+//!
+//! Backs the `ultralog convert` subcommand: parses any supported log and
+//! writes it as CSV, JSON or Arrow IPC with normalized channel names, without starting
+//! the GUI.
+
+use std::io::{self, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::arrow_ipc::write_arrow;
+use crate::binning::csv_field;
+use crate::normalize::normalize_channel_name_with_custom;
+use crate::parsers::Log;
+
+/// Usage text printed for `ultralog convert --help` and on argument errors
+pub const USAGE: &str = "\
+Usage: ultralog convert [OPTIONS] <INPUT>...
+
+Convert ECU logs to CSV, JSON or Arrow without opening the viewer.
+
+Options:
+  -f, --format <FORMAT>    csv, json or arrow (default: from the output extension, else csv)
+  -o, --output <PATH>      Output file, directory (for several inputs), or - for stdout
+      --raw-names          Keep the original channel names instead of normalizing them
+  -h, --help               Show this help";
+
+/// File format written by the converter
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Csv,
+    Json,
+    /// Arrow IPC file (Feather v2) for pandas/Polars
+    Arrow,
+}
+
+impl OutputFormat {
+    /// Parse a format name such as `csv` or `JSON`
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            "arrow" | "feather" | "ipc" => Some(Self::Arrow),
+            _ => None,
+        }
+    }
+
+    /// File extension for this format
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+            Self::Arrow => "arrow",
+        }
+    }
+}
+
+/// Parsed `ultralog convert` arguments
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConvertArgs {
+    pub inputs: Vec<PathBuf>,
+    pub output: Option<PathBuf>,
+    pub format: OutputFormat,
+    /// Normalize channel names (e.g. "Engine RPM4" -> "RPM")
+    pub normalize: bool,
+    pub help: bool,
+}
+
+impl ConvertArgs {
+    /// Parse the arguments following `convert`
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut parsed = Self {
+            normalize: true,
+            ..Default::default()
+        };
+        let mut format = None;
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-h" | "--help" => parsed.help = true,
+                "--raw-names" => parsed.normalize = false,
+                "-f" | "--format" => {
+                    let name = args.next().ok_or("--format needs a value")?;
+                    format = Some(
+                        OutputFormat::from_name(&name)
+                            .ok_or_else(|| format!("Unknown format '{}'", name))?,
+                    );
+                }
+                "-o" | "--output" => {
+                    let path = args.next().ok_or("--output needs a value")?;
+                    parsed.output = Some(PathBuf::from(path));
+                }
+                flag if flag.starts_with('-') && flag != "-" => {
+                    return Err(format!("Unknown option '{}'", flag));
+                }
+                _ => parsed.inputs.push(PathBuf::from(arg)),
+            }
+        }
+
+        if parsed.help {
+            return Ok(parsed);
+        }
+        if parsed.inputs.is_empty() {
+            return Err("No input files given".into());
+        }
+
+        // Without --format, an output file's extension picks the format
+        parsed.format = format
+            .or_else(|| {
+                parsed
+                    .output
+                    .as_ref()
+                    .and_then(|p| p.extension())
+                    .and_then(|ext| OutputFormat::from_name(&ext.to_string_lossy()))
+            })
+            .unwrap_or_default();
+
+        if parsed.inputs.len() > 1 && parsed.output.as_ref().is_some_and(|p| !p.is_dir()) {
+            return Err(
+                "--output must be an existing directory when converting several files".into(),
+            );
+        }
+        Ok(parsed)
+    }
+
+    /// Where to write the conversion of `input`; `None` means stdout
+    pub fn output_path(&self, input: &Path) -> Option<PathBuf> {
+        let extension = self.format.extension();
+        let file_name = |dir: &Path| {
+            let stem = input.file_stem().unwrap_or_default().to_string_lossy();
+            let mut path = dir.join(format!("{}.{}", stem, extension));
+            // Never overwrite the source, e.g. converting a CSV to CSV
+            if path == input {
+                path = dir.join(format!("{}_converted.{}", stem, extension));
+            }
+            path
+        };
+
+        match &self.output {
+            Some(path) if path.as_os_str() == "-" => None,
+            Some(dir) if dir.is_dir() => Some(file_name(dir)),
+            Some(path) => Some(path.clone()),
+            None => Some(file_name(input.parent().unwrap_or(Path::new("")))),
+        }
+    }
+}
+
+/// Output column names for each channel, normalized when requested
+pub fn channel_names(log: &Log, normalize: bool) -> Vec<String> {
+    log.channels
+        .iter()
+        .map(|c| {
+            let name = c.name();
+            if normalize {
+                normalize_channel_name_with_custom(&name, None)
+            } else {
+                name
+            }
+        })
+        .collect()
+}
+
+/// Write the log as CSV: a time column followed by one column per channel,
+/// with units in the header. Missing values are left empty.
+pub fn write_csv<W: Write>(log: &Log, names: &[String], out: &mut W) -> io::Result<()> {
+    write_csv_records(log, names, 0..log.times.len(), out)
+}
+
+/// Write a range of records as CSV, in the same layout as [`write_csv`]
+pub fn write_csv_records<W: Write>(
+    log: &Log,
+    names: &[String],
+    records: Range<usize>,
+    out: &mut W,
+) -> io::Result<()> {
+    let mut header = vec!["Time (s)".to_string()];
+    for (name, channel) in names.iter().zip(&log.channels) {
+        let unit = channel.unit();
+        header.push(if unit.is_empty() {
+            csv_field(name)
+        } else {
+            csv_field(&format!("{} ({})", name, unit))
+        });
+    }
+    writeln!(out, "{}", header.join(","))?;
+
+    let columns: Vec<&[f64]> = (0..log.channels.len())
+        .map(|idx| log.get_channel_data(idx))
+        .collect();
+    let mut row = String::new();
+    let records = records.start.min(log.times.len())..records.end.min(log.times.len());
+    for (record, time) in records.clone().zip(&log.times[records]) {
+        row.clear();
+        row.push_str(&time.to_string());
+        for column in &columns {
+            row.push(',');
+            if let Some(value) = column.get(record).filter(|v| v.is_finite()) {
+                row.push_str(&value.to_string());
+            }
+        }
+        writeln!(out, "{}", row)?;
+    }
+    Ok(())
+}
+
+/// Write the log in the [`Log::to_json_writer`] schema, with each channel's
+/// output name as its `normalized_name`
+pub fn write_json<W: Write>(log: &Log, names: &[String], out: &mut W) -> io::Result<()> {
+    log.to_json_writer_normalized(&mut *out, names)?;
+    writeln!(out)
+}
+
+/// Write the log in the requested format
+pub fn write_log<W: Write>(
+    log: &Log,
+    format: OutputFormat,
+    normalize: bool,
+    out: &mut W,
+) -> io::Result<()> {
+    let names = channel_names(log, normalize);
+    match format {
+        OutputFormat::Csv => write_csv(log, &names, out),
+        OutputFormat::Json => write_json(log, &names, out),
+        OutputFormat::Arrow => write_arrow(log, &names, out),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::{GenericCsv, Parseable};
+
+    fn args(list: &[&str]) -> Result<ConvertArgs, String> {
+        ConvertArgs::parse(list.iter().map(|s| s.to_string()))
+    }
+
+    fn sample_log() -> Log {
+        GenericCsv::default()
+            .parse("Time,Engine RPM4,Boost (kPa)\n0,1000,101.5\n0.1,1100,120\n")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parsed = args(&["-o", "out.json", "--raw-names", "log.csv"]).unwrap();
+        assert_eq!(parsed.inputs, vec![PathBuf::from("log.csv")]);
+        assert_eq!(parsed.format, OutputFormat::Json);
+        assert!(!parsed.normalize);
+
+        let parsed = args(&["--format", "JSON", "log.csv"]).unwrap();
+        assert_eq!(parsed.format, OutputFormat::Json);
+        assert!(parsed.normalize);
+
+        let parsed = args(&["-o", "out.feather", "log.csv"]).unwrap();
+        assert_eq!(parsed.format, OutputFormat::Arrow);
+
+        assert!(args(&[]).is_err());
+        assert!(args(&["--format", "xml", "log.csv"]).is_err());
+        assert!(args(&["--bogus", "log.csv"]).is_err());
+        assert!(args(&["--help"]).unwrap().help);
+    }
+
+    #[test]
+    fn test_output_path() {
+        let parsed = args(&["logs/run.mlg"]).unwrap();
+        assert_eq!(
+            parsed.output_path(Path::new("logs/run.mlg")),
+            Some(PathBuf::from("logs/run.csv"))
+        );
+        assert_eq!(
+            parsed.output_path(Path::new("logs/run.csv")),
+            Some(PathBuf::from("logs/run_converted.csv"))
+        );
+
+        let parsed = args(&["-o", "-", "run.csv"]).unwrap();
+        assert_eq!(parsed.output_path(Path::new("run.csv")), None);
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let log = sample_log();
+        let mut out = Vec::new();
+        write_log(&log, OutputFormat::Csv, true, &mut out).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "Time (s),RPM,Boost (kPa)");
+        assert_eq!(lines[1], "0,1000,101.5");
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_write_csv_records() {
+        let log = sample_log();
+        let names = channel_names(&log, true);
+        let mut out = Vec::new();
+        write_csv_records(&log, &names, 1..5, &mut out).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines, vec!["Time (s),RPM,Boost (kPa)", "0.1,1100,120"]);
+    }
+
+    #[test]
+    fn test_write_json() {
+        let log = sample_log();
+        let mut out = Vec::new();
+        write_log(&log, OutputFormat::Json, false, &mut out).unwrap();
+
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(value["channels"][0]["name"], "Engine RPM4");
+        assert_eq!(value["channels"][0]["normalized_name"], "Engine RPM4");
+        assert_eq!(value["times"][1], 0.1);
+        assert_eq!(value["data"][0][0], 1000.0);
+    }
+}
@@ -0,0 +1,154 @@
+//! Crash reports.
+//!
+//! A panic hook writes a report (version, OS, the panic message and
+//! backtrace, and the files open or loading at the time) to the app's data
+//! folder before the process goes down. On the next launch the app finds
+//! the report and offers to open a prefilled GitHub issue with it.
+
+use std::backtrace::Backtrace;
+use std::fs;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Extension of crash report files
+const REPORT_EXTENSION: &str = "txt";
+
+/// Folder crash reports are written to, set when the hook is installed
+static CRASH_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Files open and loading, kept up to date by the app for the report
+static FILE_CONTEXT: Mutex<FileContext> = Mutex::new(FileContext {
+    open: Vec::new(),
+    loading: Vec::new(),
+});
+
+/// Names of the files the app is working with
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileContext {
+    pub open: Vec<String>,
+    pub loading: Vec<String>,
+}
+
+/// Install a panic hook that writes a crash report, then runs the
+/// previous hook (which prints the panic to stderr)
+pub fn install_panic_hook() {
+    let Some(dir) = crate::data_dir::data_dir().map(|dir| dir.join("crashes")) else {
+        return;
+    };
+    let _ = CRASH_DIR.set(dir);
+
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let report = build_report(info, &Backtrace::force_capture(), &file_context());
+        match write_report(&report) {
+            Ok(path) => tracing::error!("Crash report written to {}", path.display()),
+            Err(e) => tracing::error!("Failed to write crash report: {}", e),
+        }
+        previous(info);
+    }));
+}
+
+/// Record the files open and loading, for any crash report written later
+pub fn set_file_context(context: FileContext) {
+    *FILE_CONTEXT.lock().unwrap_or_else(|e| e.into_inner()) = context;
+}
+
+fn file_context() -> FileContext {
+    FILE_CONTEXT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+}
+
+/// Reports left by earlier crashes, oldest first
+pub fn pending_reports() -> Vec<PathBuf> {
+    let Some(dir) = CRASH_DIR.get() else {
+        return Vec::new();
+    };
+    let mut reports: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == REPORT_EXTENSION))
+        .collect();
+    reports.sort();
+    reports
+}
+
+/// Remove a report once the user has dealt with it
+pub fn discard_report(path: &Path) {
+    if let Err(e) = fs::remove_file(path) {
+        tracing::warn!("Failed to remove crash report {}: {}", path.display(), e);
+    }
+}
+
+/// First line of a report's panic message, e.g. for an issue title
+pub fn report_summary(report: &str) -> &str {
+    report
+        .lines()
+        .skip_while(|line| !line.starts_with("Panic:"))
+        .map(|line| line.trim_start_matches("Panic:").trim())
+        .next()
+        .unwrap_or("Crash")
+}
+
+/// Text of a crash report
+fn build_report(info: &PanicHookInfo<'_>, backtrace: &Backtrace, files: &FileContext) -> String {
+    let message = info
+        .payload()
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Unknown panic".to_string());
+    let location = info
+        .location()
+        .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()))
+        .unwrap_or_else(|| "unknown".to_string());
+    let thread = std::thread::current();
+
+    let mut report = format!(
+        "UltraLog {} ({} {})\nThread: {}\nPanic: {}\nLocation: {}\n",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        thread.name().unwrap_or("unnamed"),
+        message,
+        location
+    );
+    for (label, names) in [("Open files", &files.open), ("Loading", &files.loading)] {
+        if !names.is_empty() {
+            report.push_str(&format!("{}: {}\n", label, names.join(", ")));
+        }
+    }
+    report.push_str(&format!("\nBacktrace:\n{}\n", backtrace));
+    report
+}
+
+/// Write a report to the crash folder, named after the time of the crash
+fn write_report(report: &str) -> std::io::Result<PathBuf> {
+    let dir = CRASH_DIR
+        .get()
+        .ok_or_else(|| std::io::Error::other("no crash report folder"))?;
+    fs::create_dir_all(dir)?;
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis());
+    let path = dir.join(format!("crash-{}.{}", timestamp, REPORT_EXTENSION));
+    fs::write(&path, report)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_summary() {
+        let report = "UltraLog 1.2.2 (linux x86_64)\nThread: main\nPanic: index out of bounds\nLocation: src/parsers/haltech.rs:10:5\n";
+        assert_eq!(report_summary(report), "index out of bounds");
+        assert_eq!(report_summary("garbage"), "Crash");
+    }
+}
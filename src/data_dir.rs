@@ -0,0 +1,88 @@
+//! Where UltraLog keeps its settings, parsed log cache, logs and crash
+//! reports.
+//!
+//! Normally this is the OS's per-user app data folder. In portable mode it
+//! is a folder next to the executable instead, so UltraLog can run from a
+//! USB stick and take its settings along. Portable mode is turned on by a
+//! [`PORTABLE_SENTINEL`] file next to the executable, or by starting with
+//! [`PORTABLE_FLAG`].
+
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// File next to the executable that turns on portable mode
+pub const PORTABLE_SENTINEL: &str = "portable.txt";
+
+/// Command-line flag that turns on portable mode
+pub const PORTABLE_FLAG: &str = "--portable";
+
+/// Folder next to the executable used in portable mode
+const PORTABLE_DIR_NAME: &str = "ultralog-data";
+
+/// File eframe saves settings to, matching eframe's own file name
+const SETTINGS_FILE_NAME: &str = "app.ron";
+
+/// The portable data folder, if portable mode is on
+static PORTABLE_DIR: LazyLock<Option<PathBuf>> = LazyLock::new(|| {
+    let flag = std::env::args_os().any(|arg| arg == PORTABLE_FLAG);
+    let exe = std::env::current_exe().ok()?;
+    portable_dir(exe.parent()?, flag)
+});
+
+/// The portable data folder for an executable in `exe_dir`, if portable
+/// mode is on
+fn portable_dir(exe_dir: &Path, flag: bool) -> Option<PathBuf> {
+    (flag || exe_dir.join(PORTABLE_SENTINEL).is_file()).then(|| exe_dir.join(PORTABLE_DIR_NAME))
+}
+
+/// Whether UltraLog is running in portable mode
+pub fn is_portable() -> bool {
+    PORTABLE_DIR.is_some()
+}
+
+/// Folder for UltraLog's data, if the platform has one
+pub fn data_dir() -> Option<PathBuf> {
+    PORTABLE_DIR
+        .clone()
+        .or_else(|| eframe::storage_dir("UltraLog"))
+}
+
+/// File eframe saves settings to, when it differs from eframe's default.
+/// eframe treats its persistence path as the settings file itself, not
+/// the folder holding it.
+pub fn settings_file() -> Option<PathBuf> {
+    PORTABLE_DIR.as_deref().map(settings_file_in)
+}
+
+/// The settings file inside data folder `dir`
+fn settings_file_in(dir: &Path) -> PathBuf {
+    dir.join(SETTINGS_FILE_NAME)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_portable_dir() {
+        let dir = std::env::temp_dir().join(format!("ultralog-portable-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        assert_eq!(portable_dir(&dir, false), None);
+        assert_eq!(portable_dir(&dir, true), Some(dir.join(PORTABLE_DIR_NAME)));
+
+        std::fs::write(dir.join(PORTABLE_SENTINEL), "").unwrap();
+        assert_eq!(portable_dir(&dir, false), Some(dir.join(PORTABLE_DIR_NAME)));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_settings_file_is_inside_data_dir() {
+        let dir = Path::new("portable").join(PORTABLE_DIR_NAME);
+        let file = settings_file_in(&dir);
+        assert_ne!(file, dir);
+        assert_eq!(file.parent(), Some(dir.as_path()));
+        assert_eq!(file.extension().and_then(|e| e.to_str()), Some("ron"));
+    }
+}
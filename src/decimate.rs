@@ -0,0 +1,147 @@
+//! Decimation of dense logs on import.
+//!
+//! Logs recorded at very high rates (1 kHz rusEFI logging) hold far more
+//! records than the chart can show. Decimating splits the log into windows
+//! and keeps two records per window: one with the lowest and one with the
+//! highest value of each channel, in the order they happened. A window is
+//! two records long at the target rate, so the result has about `rate_hz`
+//! records per second, and short spikes such as knock or a misfire dip are
+//! never averaged away.
+
+use crate::parsers::types::Log;
+
+/// Rate suggested for decimated logs (Hz)
+pub const DEFAULT_RATE_HZ: f64 = 100.0;
+
+/// Lowest rate a log can be decimated to (Hz)
+pub const MIN_RATE_HZ: f64 = 1.0;
+
+/// Indices of the lowest and highest finite values in `values`, earliest
+/// first, or `None` if there are none
+fn extremes(values: &[f64]) -> Option<(usize, usize)> {
+    let mut min: Option<usize> = None;
+    let mut max: Option<usize> = None;
+    for (i, &value) in values.iter().enumerate() {
+        if !value.is_finite() {
+            continue;
+        }
+        if min.is_none_or(|m| value < values[m]) {
+            min = Some(i);
+        }
+        if max.is_none_or(|m| value > values[m]) {
+            max = Some(i);
+        }
+    }
+    let (min, max) = (min?, max?);
+    Some((min.min(max), min.max(max)))
+}
+
+/// Reduce `log` to about `rate_hz` records per second, keeping the minimum
+/// and maximum of every channel in each window. Returns false, leaving the
+/// log untouched, if it is already at or below that rate.
+///
+/// Columns decoded on demand are read into memory at the lower rate.
+pub fn decimate(log: &mut Log, rate_hz: f64) -> bool {
+    let rate_hz = rate_hz.max(MIN_RATE_HZ);
+    if log.record_rate().is_none_or(|rate| rate <= rate_hz) {
+        return false;
+    }
+
+    let window = 2.0 / rate_hz;
+    let times = &log.times;
+    let start = times[0];
+    // Records [first, last) of each window
+    let mut windows = Vec::new();
+    let mut first = 0;
+    for i in 1..=times.len() {
+        let same_window = i < times.len()
+            && ((times[i] - start) / window).floor() == ((times[first] - start) / window).floor();
+        if !same_window {
+            windows.push((first, i));
+            first = i;
+        }
+    }
+
+    let mut new_times = Vec::with_capacity(windows.len() * 2);
+    for &(first, last) in &windows {
+        new_times.push(times[first]);
+        if last - first > 1 {
+            new_times.push(times[last - 1]);
+        }
+    }
+
+    let new_data = (0..log.channels.len().max(log.data.len()))
+        .map(|channel| {
+            let values = log.get_channel_data(channel);
+            let mut column = Vec::with_capacity(new_times.len());
+            for &(first, last) in &windows {
+                let window = &values[first.min(values.len())..last.min(values.len())];
+                if window.len() < 2 {
+                    column.push(window.first().copied().unwrap_or(f64::NAN));
+                    if last - first > 1 {
+                        column.push(f64::NAN);
+                    }
+                    continue;
+                }
+                match extremes(window) {
+                    Some((a, b)) => column.extend([window[a], window[b]]),
+                    None => column.extend([f64::NAN, f64::NAN]),
+                }
+            }
+            column
+        })
+        .collect();
+
+    log.times = new_times;
+    log.data = new_data;
+    log.lazy_data = None;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A log at 1 kHz with one channel
+    fn log(values: Vec<f64>) -> Log {
+        Log {
+            times: (0..values.len()).map(|i| i as f64 * 0.001).collect(),
+            data: vec![values],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_decimate_keeps_extremes() {
+        // A spike and a dip inside one 20 ms window at 100 Hz
+        let mut values = vec![10.0; 100];
+        values[5] = 50.0;
+        values[12] = -5.0;
+        values[47] = f64::NAN;
+        let mut log = log(values);
+
+        assert!(decimate(&mut log, 100.0));
+        assert_eq!(log.record_count(), 10);
+        assert_eq!(&log.data[0][..4], &[50.0, -5.0, 10.0, 10.0]);
+        assert!((log.times[1] - 0.019).abs() < 1e-9);
+        assert!(log.data[0].iter().all(|v| v.is_finite()));
+    }
+
+    #[test]
+    fn test_decimate_keeps_order_of_extremes() {
+        let mut values = vec![10.0; 40];
+        values[3] = -5.0;
+        values[9] = 50.0;
+        let mut log = log(values);
+
+        assert!(decimate(&mut log, 100.0));
+        assert_eq!(&log.data[0][..2], &[-5.0, 50.0]);
+    }
+
+    #[test]
+    fn test_decimate_skips_slow_logs() {
+        let mut log = log(vec![1.0; 50]);
+        assert!(!decimate(&mut log, 2000.0));
+        assert_eq!(log.record_count(), 50);
+    }
+}
@@ -0,0 +1,314 @@
+//! Built-in channels computed from logged channels.
+//!
+//! Derived channels are appended after a log's own channels when it is
+//! loaded, and recomputed whenever records are appended (follow mode and live
+//! data) so they always cover the whole log.
+
+use serde::Serialize;
+
+use crate::egt::{egt_spread, find_egt_channels, SPREAD_CHANNEL};
+use crate::normalize::normalize_channel_name_with_custom;
+use crate::parsers::types::{Channel, Log};
+
+/// Name of the channel list group holding derived channels
+pub const DERIVED_GROUP: &str = "Derived";
+
+/// A channel computed from other channels of the same log
+#[derive(Clone, Debug, Serialize)]
+pub struct DerivedChannel {
+    pub name: String,
+    pub unit: String,
+}
+
+impl DerivedChannel {
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+}
+
+/// Index of the first channel whose normalized name is `normalized`
+fn find_normalized(log: &Log, normalized: &str) -> Option<usize> {
+    log.channels
+        .iter()
+        .position(|c| normalize_channel_name_with_custom(&c.name(), None) == normalized)
+}
+
+/// Injector pulse width channel and the factor converting it to ms
+fn find_pulse_width(log: &Log) -> Option<(usize, f64)> {
+    let index = find_normalized(log, "Pulse Width").or_else(|| {
+        log.channels.iter().position(|c| {
+            let name = c.name().to_lowercase();
+            name.contains("pulse") || (name.contains("inj") && name.contains("pw"))
+        })
+    })?;
+    let scale = match log.channels[index].unit() {
+        "us" | "µs" | "μs" => 0.001,
+        "ms" | "" => 1.0,
+        _ => return None,
+    };
+    Some((index, scale))
+}
+
+/// Injector duty cycle (%) of a four-stroke engine firing each injector once
+/// per cycle: pulse width over the 120000/RPM ms a cycle takes
+pub fn injector_duty(pulse_width_ms: &[f64], rpm: &[f64]) -> Vec<f64> {
+    pulse_width_ms
+        .iter()
+        .zip(rpm)
+        .map(|(pw, rpm)| pw * rpm / 1200.0)
+        .collect()
+}
+
+/// Slowest vehicle speed (in the log's speed unit) used for gear estimation;
+/// below it the RPM/speed ratio is dominated by clutch slip and noise
+const MIN_GEAR_SPEED: f64 = 8.0;
+/// Lowest RPM used for gear estimation
+const MIN_GEAR_RPM: f64 = 900.0;
+/// Width of the log(RPM/speed) histogram bins used to find gear ratios
+const RATIO_BIN_WIDTH: f64 = 0.02;
+/// Largest log-ratio distance from a gear's ratio still counted as that gear
+const GEAR_TOLERANCE: f64 = 0.06;
+/// Most gears a gearbox is assumed to have
+const MAX_GEARS: usize = 8;
+
+/// Find the RPM/speed ratio of each gear by clustering the log ratio of
+/// every moving sample: histogram peaks holding enough samples are gears.
+/// Returns log ratios sorted from first gear (highest ratio) upwards.
+pub fn gear_ratios(rpm: &[f64], speed: &[f64]) -> Vec<f64> {
+    let ratios: Vec<f64> = rpm
+        .iter()
+        .zip(speed)
+        .filter(|(r, s)| **r >= MIN_GEAR_RPM && **s >= MIN_GEAR_SPEED)
+        .map(|(r, s)| (r / s).ln())
+        .filter(|r| r.is_finite())
+        .collect();
+    if ratios.is_empty() {
+        return Vec::new();
+    }
+
+    let low = ratios.iter().copied().fold(f64::INFINITY, f64::min);
+    let high = ratios.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let bins = ((high - low) / RATIO_BIN_WIDTH) as usize + 1;
+    let mut counts = vec![0usize; bins];
+    for r in &ratios {
+        counts[((r - low) / RATIO_BIN_WIDTH) as usize] += 1;
+    }
+    // Smooth over neighbouring bins so one gear doesn't split into two peaks
+    let smoothed: Vec<usize> = (0..bins)
+        .map(|i| counts[i.saturating_sub(1)..(i + 2).min(bins)].iter().sum())
+        .collect();
+
+    // Gears are peaks holding at least 3% of the moving samples, at least
+    // two tolerances apart (real gearboxes step by 15% or more)
+    let min_count = (ratios.len() * 3 / 100).max(3);
+    let mut peaks: Vec<(usize, f64)> = (0..bins)
+        .filter(|&i| {
+            smoothed[i] >= min_count
+                && (i == 0 || smoothed[i] > smoothed[i - 1])
+                && (i + 1 == bins || smoothed[i] >= smoothed[i + 1])
+        })
+        .map(|i| (smoothed[i], low + (i as f64 + 0.5) * RATIO_BIN_WIDTH))
+        .collect();
+    peaks.sort_by_key(|peak| std::cmp::Reverse(peak.0));
+    let mut gears: Vec<f64> = Vec::new();
+    for (_, ratio) in peaks {
+        if gears.len() < MAX_GEARS
+            && gears
+                .iter()
+                .all(|g| (g - ratio).abs() >= 2.0 * GEAR_TOLERANCE)
+        {
+            gears.push(ratio);
+        }
+    }
+    gears.sort_by(|a, b| b.total_cmp(a));
+    gears
+}
+
+/// Estimated gear per record (1 = first), or 0 when stopped, slipping the
+/// clutch or between gears
+pub fn estimate_gear(rpm: &[f64], speed: &[f64]) -> Vec<f64> {
+    let gears = gear_ratios(rpm, speed);
+    rpm.iter()
+        .zip(speed)
+        .map(|(r, s)| {
+            if *r < MIN_GEAR_RPM || *s < MIN_GEAR_SPEED {
+                return 0.0;
+            }
+            let ratio = (r / s).ln();
+            gears
+                .iter()
+                .position(|g| (g - ratio).abs() <= GEAR_TOLERANCE)
+                .map_or(0.0, |gear| (gear + 1) as f64)
+        })
+        .collect()
+}
+
+/// Remove the derived channels from a log (they always come last)
+pub fn remove_derived_channels(log: &mut Log) {
+    while matches!(log.channels.last(), Some(Channel::Derived(_))) {
+        log.channels.pop();
+        log.data.pop();
+    }
+}
+
+/// (Re)compute every derived channel whose inputs the log contains
+pub fn add_derived_channels(log: &mut Log) {
+    remove_derived_channels(log);
+
+    // Injector duty, unless the ECU already logs it
+    let has_duty = log.channels.iter().any(|c| {
+        let name = c.name().to_lowercase();
+        name.contains("inj") && name.contains("duty")
+    });
+    if !has_duty {
+        if let (Some((pw_index, scale)), Some(rpm_index)) =
+            (find_pulse_width(log), find_normalized(log, "RPM"))
+        {
+            let pulse_width: Vec<f64> = log
+                .get_channel_data(pw_index)
+                .iter()
+                .map(|pw| pw * scale)
+                .collect();
+            let duty = injector_duty(&pulse_width, log.get_channel_data(rpm_index));
+            log.push_channel(
+                Channel::Derived(DerivedChannel {
+                    name: "Injector Duty Cycle".to_string(),
+                    unit: "%".to_string(),
+                }),
+                duty,
+            );
+        }
+    }
+
+    // Estimated gear, for logs without a gear channel
+    let has_gear = log
+        .channels
+        .iter()
+        .any(|c| c.name().to_lowercase().contains("gear"));
+    if !has_gear {
+        if let (Some(rpm_index), Some(speed_index)) = (
+            find_normalized(log, "RPM"),
+            find_normalized(log, "Vehicle Speed"),
+        ) {
+            let gear = estimate_gear(
+                log.get_channel_data(rpm_index),
+                log.get_channel_data(speed_index),
+            );
+            if gear.iter().any(|g| *g > 0.0) {
+                log.push_channel(
+                    Channel::Derived(DerivedChannel {
+                        name: "Estimated Gear".to_string(),
+                        unit: String::new(),
+                    }),
+                    gear,
+                );
+            }
+        }
+    }
+
+    // Spread between the hottest and coldest EGT probe
+    let probes = find_egt_channels(log);
+    if probes.len() >= 2 {
+        let unit = format!("Δ{}", log.channels[probes[0]].unit());
+        let data: Vec<&[f64]> = probes.iter().map(|&i| log.get_channel_data(i)).collect();
+        let spread = egt_spread(&data);
+        log.push_channel(
+            Channel::Derived(DerivedChannel {
+                name: SPREAD_CHANNEL.to_string(),
+                unit,
+            }),
+            spread,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::{GenericCsv, Parseable};
+
+    fn parse(csv: &str) -> Log {
+        GenericCsv::default().parse(csv).unwrap()
+    }
+
+    #[test]
+    fn test_injector_duty() {
+        let duty = injector_duty(&[10.0, 6.0], &[6000.0, 1000.0]);
+        assert_eq!(duty, vec![50.0, 5.0]);
+    }
+
+    #[test]
+    fn test_add_derived_channels() {
+        let mut log = parse("Time,RPM,PW (ms)\n0,6000,10\n0.1,3000,4\n");
+        add_derived_channels(&mut log);
+        assert_eq!(log.channels.len(), 3);
+        assert_eq!(log.channels[2].name(), "Injector Duty Cycle");
+        assert_eq!(log.channels[2].unit(), "%");
+        assert_eq!(log.get_channel_data(2), &[50.0, 10.0]);
+
+        // Recomputing replaces rather than duplicates
+        add_derived_channels(&mut log);
+        assert_eq!(log.channels.len(), 3);
+
+        remove_derived_channels(&mut log);
+        assert_eq!(log.channels.len(), 2);
+    }
+
+    /// RPM and speed of a car accelerating through three gears, with a
+    /// stop at the start and a clutch slip between gears
+    fn gear_samples() -> (Vec<f64>, Vec<f64>) {
+        let mut rpm = vec![800.0; 20];
+        let mut speed = vec![0.0; 20];
+        for (gear_ratio, speeds) in [(120.0, 10..50), (75.0, 30..80), (50.0, 60..120)] {
+            for s in speeds {
+                rpm.push(gear_ratio * s as f64);
+                speed.push(s as f64);
+            }
+            // Clutch in: RPM drops while speed holds
+            rpm.push(2000.0);
+            speed.push(90.0);
+        }
+        (rpm, speed)
+    }
+
+    #[test]
+    fn test_estimate_gear() {
+        let (rpm, speed) = gear_samples();
+        assert_eq!(gear_ratios(&rpm, &speed).len(), 3);
+
+        let gear = estimate_gear(&rpm, &speed);
+        assert_eq!(gear[0], 0.0);
+        assert_eq!(gear[20], 1.0);
+        assert_eq!(gear[20 + 40 + 1], 2.0);
+        assert_eq!(gear[gear.len() - 2], 3.0);
+        // Clutch slip between first and second
+        assert_eq!(gear[20 + 40], 0.0);
+    }
+
+    #[test]
+    fn test_egt_spread_channel() {
+        let mut log = parse("Time,EGT 1 (C),EGT 2 (C)\n0,800,850\n0.1,900,820\n");
+        add_derived_channels(&mut log);
+        assert_eq!(log.channels.len(), 3);
+        assert_eq!(log.channels[2].name(), "EGT Spread");
+        assert_eq!(log.channels[2].unit(), "ΔC");
+        assert_eq!(log.get_channel_data(2), &[50.0, 80.0]);
+    }
+
+    #[test]
+    fn test_no_derived_without_inputs() {
+        let mut log = parse("Time,RPM,TPS\n0,6000,10\n");
+        add_derived_channels(&mut log);
+        assert_eq!(log.channels.len(), 2);
+
+        // One EGT probe has no spread
+        let mut log = parse("Time,RPM,EGT (C)\n0,6000,850\n");
+        add_derived_channels(&mut log);
+        assert_eq!(log.channels.len(), 2);
+
+        // Logs that already record injector duty keep their own
+        let mut log = parse("Time,RPM,PW (ms),Inj Duty (%)\n0,6000,10,50\n");
+        add_derived_channels(&mut log);
+        assert_eq!(log.channels.len(), 3);
+    }
+}
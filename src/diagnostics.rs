@@ -0,0 +1,332 @@
+//! Application diagnostics.
+//!
+//! Routes `tracing` output to three places: stderr as before, a log file in
+//! the app's data folder that users can attach to bug reports, and an
+//! in-memory buffer shown by the log console window (Help → Show Logs).
+//!
+//! The log file is rotated once it passes [`MAX_LOG_BYTES`]: `ultralog.log`
+//! becomes `ultralog.1.log`, and so on up to [`KEPT_LOG_FILES`] old files.
+
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+use std::time::Instant;
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::filter::{LevelFilter, Targets};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Size the log file may reach before it is rotated
+pub const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Rotated log files kept next to the current one
+pub const KEPT_LOG_FILES: usize = 3;
+
+/// Lines the log console keeps before dropping the oldest
+pub const MAX_CONSOLE_LINES: usize = 5000;
+
+/// Name of the current log file
+const LOG_FILE_NAME: &str = "ultralog.log";
+
+/// Lines captured for the log console
+static CONSOLE: LazyLock<LogConsole> = LazyLock::new(LogConsole::default);
+
+/// Folder holding the log files, once logging to a file has started
+static LOG_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Start routing `tracing` output to stderr, the log file and the console.
+/// Logging to the file is skipped if the data folder can't be written.
+pub fn init() {
+    // Our own debug output is kept; dependencies only report warnings and up
+    let targets = Targets::new()
+        .with_target("ultralog", LevelFilter::DEBUG)
+        .with_default(LevelFilter::WARN);
+
+    let log_dir = crate::data_dir::data_dir().map(|dir| dir.join("logs"));
+    let file = log_dir.as_ref().and_then(|dir| {
+        match RotatingFile::open(dir, MAX_LOG_BYTES, KEPT_LOG_FILES) {
+            Ok(file) => Some(file),
+            Err(e) => {
+                eprintln!("Failed to open log file in {}: {}", dir.display(), e);
+                None
+            }
+        }
+    });
+    let file_layer = file.map(|file| {
+        tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(SharedWriter(Arc::new(Mutex::new(file))))
+            .with_filter(targets.clone())
+    });
+    if let (Some(dir), Some(_)) = (log_dir, &file_layer) {
+        let _ = LOG_DIR.set(dir);
+    }
+
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_filter(LevelFilter::INFO))
+        .with(file_layer)
+        .with(ConsoleLayer.with_filter(targets))
+        .init();
+}
+
+/// Lines captured for the log console
+pub fn console() -> &'static LogConsole {
+    &CONSOLE
+}
+
+/// Folder holding the log files, if logging to a file started
+pub fn log_dir() -> Option<&'static Path> {
+    LOG_DIR.get().map(PathBuf::as_path)
+}
+
+/// One event shown in the log console
+#[derive(Clone, Debug)]
+pub struct ConsoleLine {
+    /// Seconds since the console started capturing
+    pub elapsed: f64,
+    pub level: Level,
+    /// Module the event came from, e.g. `ultralog::parsers::speeduino`
+    pub target: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConsoleLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>9.3} {:>5} {}: {}",
+            self.elapsed, self.level, self.target, self.message
+        )
+    }
+}
+
+/// Bounded buffer of recent log events
+pub struct LogConsole {
+    started: Instant,
+    lines: Mutex<VecDeque<ConsoleLine>>,
+}
+
+impl Default for LogConsole {
+    fn default() -> Self {
+        Self {
+            started: Instant::now(),
+            lines: Mutex::new(VecDeque::new()),
+        }
+    }
+}
+
+impl LogConsole {
+    /// Add a line, dropping the oldest once [`MAX_CONSOLE_LINES`] are kept
+    pub fn push(&self, level: Level, target: &str, message: String) {
+        let line = ConsoleLine {
+            elapsed: self.started.elapsed().as_secs_f64(),
+            level,
+            target: target.to_string(),
+            message,
+        };
+        let mut lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        if lines.len() == MAX_CONSOLE_LINES {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    /// Lines at `level` or more severe, oldest first
+    pub fn lines(&self, level: Level) -> Vec<ConsoleLine> {
+        self.lines
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .filter(|line| line.level <= level)
+            .cloned()
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        self.lines.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+}
+
+/// Layer that records events in the log console
+struct ConsoleLayer;
+
+impl<S: Subscriber> Layer<S> for ConsoleLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        console().push(*metadata.level(), metadata.target(), visitor.message);
+    }
+}
+
+/// Formats an event's message followed by its other fields
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        use std::fmt::Write as _;
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            let _ = write!(self.message, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+/// Log file that moves itself aside once it grows past a size limit
+struct RotatingFile {
+    dir: PathBuf,
+    /// Closed while rotating, since Windows can't rename an open file
+    file: Option<File>,
+    written: u64,
+    max_bytes: u64,
+    keep: usize,
+}
+
+impl RotatingFile {
+    fn open(dir: &Path, max_bytes: u64, keep: usize) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let mut file = Self {
+            dir: dir.to_path_buf(),
+            file: None,
+            written: fs::metadata(dir.join(LOG_FILE_NAME)).map_or(0, |m| m.len()),
+            max_bytes,
+            keep,
+        };
+        file.file = Some(Self::open_current(dir)?);
+        if file.written >= max_bytes {
+            file.rotate()?;
+        }
+        Ok(file)
+    }
+
+    fn open_current(dir: &Path) -> io::Result<File> {
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(dir.join(LOG_FILE_NAME))
+    }
+
+    /// Path of the `index`th rotated file (0 is the current file)
+    fn path(&self, index: usize) -> PathBuf {
+        if index == 0 {
+            self.dir.join(LOG_FILE_NAME)
+        } else {
+            self.dir.join(format!("ultralog.{}.log", index))
+        }
+    }
+
+    /// Shift every file up one place, dropping the oldest, and start afresh
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file = None;
+        let _ = fs::remove_file(self.path(self.keep));
+        for index in (0..self.keep).rev() {
+            let from = self.path(index);
+            if from.exists() {
+                fs::rename(&from, self.path(index + 1))?;
+            }
+        }
+        self.file = Some(Self::open_current(&self.dir)?);
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written > 0 && self.written + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let file = self
+            .file
+            .as_mut()
+            .ok_or_else(|| io::Error::other("log file could not be reopened"))?;
+        let written = file.write(buf)?;
+        self.written += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.as_mut().map_or(Ok(()), |file| file.flush())
+    }
+}
+
+/// Hands the fmt layer a locked handle to the shared log file per event
+#[derive(Clone)]
+struct SharedWriter<W>(Arc<Mutex<W>>);
+
+impl<'a, W: Write + 'a> MakeWriter<'a> for SharedWriter<W> {
+    type Writer = SharedWriterGuard<'a, W>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        SharedWriterGuard(self.0.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+}
+
+struct SharedWriterGuard<'a, W>(std::sync::MutexGuard<'a, W>);
+
+impl<W: Write> Write for SharedWriterGuard<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_console_keeps_newest_lines() {
+        let console = LogConsole::default();
+        for i in 0..MAX_CONSOLE_LINES + 10 {
+            let level = if i % 2 == 0 {
+                Level::WARN
+            } else {
+                Level::DEBUG
+            };
+            console.push(level, "ultralog", i.to_string());
+        }
+
+        let lines = console.lines(Level::TRACE);
+        assert_eq!(lines.len(), MAX_CONSOLE_LINES);
+        assert_eq!(lines[0].message, "10");
+        assert!(console
+            .lines(Level::WARN)
+            .iter()
+            .all(|line| line.level == Level::WARN));
+
+        console.clear();
+        assert!(console.lines(Level::TRACE).is_empty());
+    }
+
+    #[test]
+    fn test_log_file_rotation() {
+        let dir = std::env::temp_dir().join(format!("ultralog-logs-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let mut file = RotatingFile::open(&dir, 10, 2).unwrap();
+        for line in ["aaaaaaaa\n", "bbbbbbbb\n", "cccccccc\n", "dddddddd\n"] {
+            file.write_all(line.as_bytes()).unwrap();
+        }
+
+        let read = |name: &str| fs::read_to_string(dir.join(name)).unwrap();
+        assert_eq!(read("ultralog.log"), "dddddddd\n");
+        assert_eq!(read("ultralog.1.log"), "cccccccc\n");
+        assert_eq!(read("ultralog.2.log"), "bbbbbbbb\n");
+        assert!(!dir.join("ultralog.3.log").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
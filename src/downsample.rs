@@ -0,0 +1,488 @@
+//! Chart downsampling.
+//!
+//! Channels are reduced with LTTB (Largest Triangle Three Buckets) into a
+//! pyramid of resolutions, so zoomed-out views draw a few thousand points while
+//! zooming in progressively reveals detail down to the raw samples.
+//!
+//! Channels plotted against another channel (e.g. RPM) instead of time use
+//! [`DomainDownsample`], since their X values aren't ordered.
+
+/// Point count growth between successive pyramid levels
+const LEVEL_FACTOR: usize = 4;
+
+/// Zoom ratio beyond which a cached view downsample is recomputed
+const VIEW_ZOOM_TOLERANCE: f64 = 1.5;
+
+/// LTTB-downsampled copies of one channel at increasing resolutions
+#[derive(Clone, Debug, Default)]
+pub struct DownsamplePyramid {
+    /// Coarsest first; each level has about `LEVEL_FACTOR` times the points of
+    /// the previous one. Levels stop before reaching the raw sample count.
+    levels: Vec<Vec<[f64; 2]>>,
+}
+
+impl DownsamplePyramid {
+    /// Build every level for a channel, starting at `base_points`.
+    ///
+    /// The finest level is downsampled from the raw data and each coarser
+    /// level from the one above it, so building costs little more than a
+    /// single pass over the samples.
+    pub fn build(times: &[f64], values: &[f64], base_points: usize) -> Self {
+        let n = times.len().min(values.len());
+        let mut sizes = Vec::new();
+        let mut size = base_points.max(3);
+        while size * 2 <= n {
+            sizes.push(size);
+            size *= LEVEL_FACTOR;
+        }
+
+        let mut levels: Vec<Vec<[f64; 2]>> = Vec::with_capacity(sizes.len());
+        for &size in sizes.iter().rev() {
+            let level = match levels.last() {
+                Some(finer) => {
+                    let (t, v): (Vec<f64>, Vec<f64>) = finer.iter().map(|p| (p[0], p[1])).unzip();
+                    lttb(&t, &v, size)
+                }
+                None => lttb(&times[..n], &values[..n], size),
+            };
+            levels.push(level);
+        }
+        levels.reverse();
+        Self { levels }
+    }
+
+    /// Number of downsampled levels (raw data is an implicit extra level)
+    pub fn level_count(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Memory held by every level (bytes)
+    pub fn memory_bytes(&self) -> usize {
+        self.levels.iter().map(|level| point_bytes(level)).sum()
+    }
+
+    /// Points to draw for the visible range `x_min..=x_max`.
+    ///
+    /// Picks the coarsest level that still has at least `target_points`
+    /// samples inside the range, falling back to the raw data when zoomed in
+    /// past every level. One point either side of the range is included so
+    /// lines run off the edges of the plot.
+    pub fn visible_points(
+        &self,
+        times: &[f64],
+        values: &[f64],
+        x_min: f64,
+        x_max: f64,
+        target_points: usize,
+    ) -> Vec<[f64; 2]> {
+        for level in &self.levels {
+            let (start, end) = visible_span(level.len(), |i| level[i][0], x_min, x_max);
+            if end - start >= target_points {
+                return level[start..end].to_vec();
+            }
+        }
+
+        let n = times.len().min(values.len());
+        let (start, end) = visible_span(n, |i| times[i], x_min, x_max);
+        (start..end).map(|i| [times[i], values[i]]).collect()
+    }
+}
+
+/// LTTB computed over just the visible range of a channel.
+///
+/// Covers half a view width either side of the range it was computed for, so
+/// small pans reuse it; it is recomputed once the view leaves that span or
+/// the zoom level changes by more than `VIEW_ZOOM_TOLERANCE`.
+#[derive(Clone, Debug, Default)]
+pub struct ViewDownsample {
+    start: f64,
+    end: f64,
+    width: f64,
+    points: Vec<[f64; 2]>,
+}
+
+impl ViewDownsample {
+    /// Downsample the range around `x_min..=x_max` to about `target_points`
+    /// per view width, reading from the pyramid level matching the zoom
+    pub fn compute(
+        pyramid: &DownsamplePyramid,
+        times: &[f64],
+        values: &[f64],
+        x_min: f64,
+        x_max: f64,
+        target_points: usize,
+    ) -> Self {
+        let width = (x_max - x_min).max(0.0);
+        let (start, end) = (x_min - width / 2.0, x_max + width / 2.0);
+        let source = pyramid.visible_points(times, values, start, end, target_points * 2);
+        let (t, v): (Vec<f64>, Vec<f64>) = source.iter().map(|p| (p[0], p[1])).unzip();
+        Self {
+            start,
+            end,
+            width,
+            points: lttb(&t, &v, target_points * 2),
+        }
+    }
+
+    /// Memory held by the points (bytes)
+    pub fn memory_bytes(&self) -> usize {
+        point_bytes(&self.points)
+    }
+
+    /// Whether this downsample can still be drawn for `x_min..=x_max`
+    pub fn covers(&self, x_min: f64, x_max: f64) -> bool {
+        let width = x_max - x_min;
+        let zoom = if self.width > 0.0 && width > 0.0 {
+            (width / self.width).max(self.width / width)
+        } else {
+            f64::INFINITY
+        };
+        x_min >= self.start && x_max <= self.end && zoom <= VIEW_ZOOM_TOLERANCE
+    }
+
+    /// Points within `x_min..=x_max`, plus one either side
+    pub fn visible_points(&self, x_min: f64, x_max: f64) -> &[[f64; 2]] {
+        let points = &self.points;
+        let (start, end) = visible_span(points.len(), |i| points[i][0], x_min, x_max);
+        &points[start..end]
+    }
+}
+
+/// A channel plotted against another channel rather than time.
+///
+/// X values such as RPM rise and fall, so LTTB doesn't apply. Instead the
+/// range is split into columns and each keeps the samples with its lowest and
+/// highest value, preserving the envelope a scatter of every sample shows.
+/// Reused across frames like [`ViewDownsample`].
+#[derive(Clone, Debug, Default)]
+pub struct DomainDownsample {
+    /// Channel providing the X values
+    x_channel: usize,
+    start: f64,
+    end: f64,
+    width: f64,
+    /// Sorted by X
+    points: Vec<[f64; 2]>,
+}
+
+impl DomainDownsample {
+    /// Reduce the samples around `x_min..=x_max` to at most two per column,
+    /// with `columns` columns per view width
+    pub fn compute(
+        x_channel: usize,
+        xs: &[f64],
+        values: &[f64],
+        x_min: f64,
+        x_max: f64,
+        columns: usize,
+    ) -> Self {
+        let width = (x_max - x_min).max(0.0);
+        let (start, end) = (x_min - width / 2.0, x_max + width / 2.0);
+        let column_count = (columns * 2).max(1);
+        let column_width = (end - start) / column_count as f64;
+
+        // Lowest and highest sample of each column
+        let mut extremes: Vec<Option<([f64; 2], [f64; 2])>> = vec![None; column_count];
+        for (&x, &y) in xs.iter().zip(values) {
+            if !(x.is_finite() && y.is_finite()) || x < start || x > end {
+                continue;
+            }
+            let column = if column_width > 0.0 {
+                (((x - start) / column_width) as usize).min(column_count - 1)
+            } else {
+                0
+            };
+            let point = [x, y];
+            extremes[column] = Some(match extremes[column] {
+                None => (point, point),
+                Some((low, high)) => (
+                    if y < low[1] { point } else { low },
+                    if y > high[1] { point } else { high },
+                ),
+            });
+        }
+
+        let mut points = Vec::with_capacity(column_count * 2);
+        for (low, high) in extremes.into_iter().flatten() {
+            if low == high {
+                points.push(low);
+            } else if low[0] <= high[0] {
+                points.extend([low, high]);
+            } else {
+                points.extend([high, low]);
+            }
+        }
+        Self {
+            x_channel,
+            start,
+            end,
+            width,
+            points,
+        }
+    }
+
+    /// Memory held by the points (bytes)
+    pub fn memory_bytes(&self) -> usize {
+        point_bytes(&self.points)
+    }
+
+    /// Whether this downsample can still be drawn for `x_min..=x_max`
+    /// against `x_channel`
+    pub fn covers(&self, x_channel: usize, x_min: f64, x_max: f64) -> bool {
+        let width = x_max - x_min;
+        let zoom = if self.width > 0.0 && width > 0.0 {
+            (width / self.width).max(self.width / width)
+        } else {
+            f64::INFINITY
+        };
+        self.x_channel == x_channel
+            && x_min >= self.start
+            && x_max <= self.end
+            && zoom <= VIEW_ZOOM_TOLERANCE
+    }
+
+    /// Points within `x_min..=x_max`, plus one either side
+    pub fn visible_points(&self, x_min: f64, x_max: f64) -> &[[f64; 2]] {
+        let points = &self.points;
+        let (start, end) = visible_span(points.len(), |i| points[i][0], x_min, x_max);
+        &points[start..end]
+    }
+}
+
+/// Memory held by a list of points (bytes)
+fn point_bytes(points: &[[f64; 2]]) -> usize {
+    std::mem::size_of_val(points)
+}
+
+/// Keys to drop, least recently used first, until the rest fit in `budget`
+/// bytes. Each entry is (key, frame last used, bytes); entries used in frame
+/// `now` are on screen and never dropped, even if that leaves the caches
+/// over budget.
+pub fn lru_evictions<K: Clone>(entries: &[(K, u64, usize)], budget: usize, now: u64) -> Vec<K> {
+    let mut total: usize = entries.iter().map(|(_, _, bytes)| bytes).sum();
+    let mut oldest: Vec<&(K, u64, usize)> =
+        entries.iter().filter(|(_, used, _)| *used < now).collect();
+    oldest.sort_by_key(|(_, used, _)| *used);
+
+    let mut evicted = Vec::new();
+    for (key, _, bytes) in oldest {
+        if total <= budget {
+            break;
+        }
+        total -= bytes;
+        evicted.push(key.clone());
+    }
+    evicted
+}
+
+/// Index range of samples within `x_min..=x_max`, widened by one on each side
+fn visible_span(
+    len: usize,
+    time_at: impl Fn(usize) -> f64,
+    x_min: f64,
+    x_max: f64,
+) -> (usize, usize) {
+    let first = partition_point(len, |i| time_at(i) < x_min);
+    let last = partition_point(len, |i| time_at(i) <= x_max);
+    (first.saturating_sub(1), (last + 1).min(len))
+}
+
+/// Binary search over an index range (like `slice::partition_point`)
+fn partition_point(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut lo, mut hi) = (0, len);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Downsample data using the LTTB (Largest Triangle Three Buckets) algorithm.
+/// This preserves visual characteristics while reducing point count for performance.
+pub fn lttb(times: &[f64], values: &[f64], target_points: usize) -> Vec<[f64; 2]> {
+    let n = times.len();
+
+    if n <= target_points || target_points < 3 {
+        // No downsampling needed
+        return times
+            .iter()
+            .zip(values.iter())
+            .map(|(t, v)| [*t, *v])
+            .collect();
+    }
+
+    let mut result = Vec::with_capacity(target_points);
+
+    // Always include first point
+    result.push([times[0], values[0]]);
+
+    // Bucket size
+    let bucket_size = (n - 2) as f64 / (target_points - 2) as f64;
+
+    let mut a_index = 0usize;
+
+    for i in 0..(target_points - 2) {
+        // Calculate bucket range
+        let bucket_start = ((i as f64 + 1.0) * bucket_size).floor() as usize + 1;
+        let bucket_end = (((i + 2) as f64) * bucket_size).floor() as usize + 1;
+        let bucket_end = bucket_end.min(n - 1);
+
+        // Calculate average point for next bucket (for triangle calculation)
+        let next_bucket_start = bucket_end;
+        let next_bucket_end = (((i + 3) as f64) * bucket_size).floor() as usize + 1;
+        let next_bucket_end = next_bucket_end.min(n);
+
+        let (avg_x, avg_y) = if next_bucket_start < next_bucket_end {
+            let count = (next_bucket_end - next_bucket_start) as f64;
+            let sum_x: f64 = times[next_bucket_start..next_bucket_end].iter().sum();
+            let sum_y: f64 = values[next_bucket_start..next_bucket_end].iter().sum();
+            (sum_x / count, sum_y / count)
+        } else {
+            (times[n - 1], values[n - 1])
+        };
+
+        // Find point in current bucket with largest triangle area
+        let mut max_area = -1.0f64;
+        let mut max_index = bucket_start;
+
+        let a_x = times[a_index];
+        let a_y = values[a_index];
+
+        for j in bucket_start..bucket_end {
+            // Calculate triangle area
+            let area = ((a_x - avg_x) * (values[j] - a_y) - (a_x - times[j]) * (avg_y - a_y)).abs();
+
+            if area > max_area {
+                max_area = area;
+                max_index = j;
+            }
+        }
+
+        result.push([times[max_index], values[max_index]]);
+        a_index = max_index;
+    }
+
+    // Always include last point
+    result.push([times[n - 1], values[n - 1]]);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ramp(n: usize) -> (Vec<f64>, Vec<f64>) {
+        let times: Vec<f64> = (0..n).map(|i| i as f64 * 0.01).collect();
+        let values: Vec<f64> = (0..n).map(|i| ((i as f64) * 0.1).sin()).collect();
+        (times, values)
+    }
+
+    #[test]
+    fn test_domain_downsample() {
+        // RPM sweeping up and down, with the value following RPM plus noise
+        let xs: Vec<f64> = (0..10_000)
+            .map(|i| 1000.0 + (i % 2000) as f64 * 3.0)
+            .collect();
+        let values: Vec<f64> = xs
+            .iter()
+            .enumerate()
+            .map(|(i, x)| x / 100.0 + (i % 7) as f64)
+            .collect();
+
+        let view = DomainDownsample::compute(0, &xs, &values, 2000.0, 4000.0, 100);
+        let points = view.visible_points(2000.0, 4000.0);
+        assert!(points.len() <= 400);
+        assert!(points.windows(2).all(|w| w[0][0] <= w[1][0]));
+        // Column extremes keep the envelope of the noisy samples
+        let in_view = |p: &&[f64; 2]| (2000.0..=4000.0).contains(&p[0]);
+        let max = points
+            .iter()
+            .filter(in_view)
+            .map(|p| p[1])
+            .fold(f64::MIN, f64::max);
+        assert!(max >= 40.0 + 5.0);
+
+        assert!(view.covers(0, 2100.0, 4100.0));
+        assert!(!view.covers(1, 2100.0, 4100.0));
+        assert!(!view.covers(0, 1000.0, 6000.0));
+    }
+
+    #[test]
+    fn test_lttb_keeps_endpoints() {
+        let (times, values) = ramp(10_000);
+        let points = lttb(&times, &values, 100);
+        assert_eq!(points.len(), 100);
+        assert_eq!(points[0], [times[0], values[0]]);
+        assert_eq!(points[99], [times[9_999], values[9_999]]);
+    }
+
+    #[test]
+    fn test_lttb_short_input_unchanged() {
+        let points = lttb(&[0.0, 1.0], &[5.0, 6.0], 100);
+        assert_eq!(points, vec![[0.0, 5.0], [1.0, 6.0]]);
+    }
+
+    #[test]
+    fn test_pyramid_levels() {
+        let (times, values) = ramp(100_000);
+        let pyramid = DownsamplePyramid::build(&times, &values, 1000);
+        // 1000, 4000, 16000 (64000 would exceed half the samples)
+        assert_eq!(pyramid.level_count(), 3);
+
+        // Small logs need no levels at all
+        let (times, values) = ramp(1500);
+        assert_eq!(
+            DownsamplePyramid::build(&times, &values, 1000).level_count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_pyramid_zoom_selects_resolution() {
+        let (times, values) = ramp(100_000);
+        let pyramid = DownsamplePyramid::build(&times, &values, 1000);
+        let end = *times.last().unwrap();
+
+        // Whole log: coarsest level
+        let full = pyramid.visible_points(&times, &values, 0.0, end, 1000);
+        assert_eq!(full.len(), 1000);
+
+        // Deep zoom: raw samples covering the range plus one either side
+        let zoomed = pyramid.visible_points(&times, &values, 9.995, 10.505, 1000);
+        assert_eq!(zoomed.len(), 53);
+        assert_eq!(zoomed[1], [times[1000], values[1000]]);
+        assert!(zoomed.windows(2).all(|w| w[1][0] - w[0][0] < 0.011));
+    }
+
+    #[test]
+    fn test_view_downsample_reuse() {
+        let (times, values) = ramp(1_000_000);
+        let pyramid = DownsamplePyramid::build(&times, &values, 1000);
+
+        let view = ViewDownsample::compute(&pyramid, &times, &values, 100.0, 200.0, 1000);
+        let visible = view.visible_points(100.0, 200.0);
+        assert!(visible.len() >= 1000 && visible.len() <= 1100);
+
+        // Small pans and zooms reuse the cached view
+        assert!(view.covers(120.0, 220.0));
+        assert!(view.covers(110.0, 190.0));
+        // Leaving the padded span or zooming significantly does not
+        assert!(!view.covers(300.0, 400.0));
+        assert!(!view.covers(140.0, 160.0));
+        assert!(!view.covers(0.0, 1000.0));
+    }
+
+    #[test]
+    fn test_lru_evictions() {
+        let entries = [("a", 3, 100), ("b", 1, 100), ("c", 5, 100), ("d", 2, 100)];
+        assert_eq!(lru_evictions(&entries, 250, 5), vec!["b", "d"]);
+        assert!(lru_evictions(&entries, 400, 5).is_empty());
+        // Channels on screen stay even when over budget
+        assert_eq!(lru_evictions(&entries, 0, 3), vec!["b", "d"]);
+    }
+}
@@ -0,0 +1,307 @@
+//! Drag run timing from vehicle speed.
+//!
+//! A run starts when the car pulls away from a standstill and ends when it
+//! slows down (lifting or braking) or the log ends. Distance is the integral
+//! of speed from the launch, so the 60 ft, 330 ft, 1/8 and 1/4 mile times
+//! and trap speeds are estimates: they are timed from the first movement
+//! rather than a beam, and are only as accurate as the speed channel.
+
+use std::fmt::Write as _;
+
+/// Speed (m/s) at or below which the car counts as stopped
+const STOPPED_MPS: f64 = 0.5;
+
+/// Fall from the run's peak speed that ends it (lifting or braking)
+const END_SPEED_DROP: f64 = 0.1;
+
+/// 60 mph in m/s
+const MPH_60_MPS: f64 = 26.8224;
+
+/// 100 km/h in m/s
+const KMH_100_MPS: f64 = 100.0 / 3.6;
+
+/// Metres per foot
+const METRES_PER_FOOT: f64 = 0.3048;
+
+/// Metres per mile
+const METRES_PER_MILE: f64 = 1609.344;
+
+/// A distance a drag run is timed over
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DragMark {
+    SixtyFoot,
+    ThreeThirtyFoot,
+    EighthMile,
+    QuarterMile,
+}
+
+impl DragMark {
+    /// All marks, shortest first
+    pub const ALL: [DragMark; 4] = [
+        DragMark::SixtyFoot,
+        DragMark::ThreeThirtyFoot,
+        DragMark::EighthMile,
+        DragMark::QuarterMile,
+    ];
+
+    /// Display name, e.g. "1/8 mile"
+    pub fn label(&self) -> &'static str {
+        match self {
+            DragMark::SixtyFoot => "60 ft",
+            DragMark::ThreeThirtyFoot => "330 ft",
+            DragMark::EighthMile => "1/8 mile",
+            DragMark::QuarterMile => "1/4 mile",
+        }
+    }
+
+    /// Distance from the launch in metres
+    pub fn distance_m(&self) -> f64 {
+        match self {
+            DragMark::SixtyFoot => 60.0 * METRES_PER_FOOT,
+            DragMark::ThreeThirtyFoot => 330.0 * METRES_PER_FOOT,
+            DragMark::EighthMile => METRES_PER_MILE / 8.0,
+            DragMark::QuarterMile => METRES_PER_MILE / 4.0,
+        }
+    }
+}
+
+/// Time and speed of a run passing a mark
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MarkTime {
+    pub mark: DragMark,
+    /// Seconds from the launch
+    pub elapsed: f64,
+    /// Speed passing the mark (m/s)
+    pub speed_mps: f64,
+}
+
+/// A run from a standstill
+#[derive(Clone, Debug, PartialEq)]
+pub struct DragRun {
+    /// Index of the record where speed started rising
+    pub launch_record: usize,
+    /// Index of the record with the run's peak speed
+    pub end_record: usize,
+    /// Time of the launch (seconds)
+    pub launch_time: f64,
+    /// Time of the peak speed (seconds)
+    pub end_time: f64,
+    /// Seconds from the launch to 60 mph, if reached
+    pub zero_to_60_mph: Option<f64>,
+    /// Seconds from the launch to 100 km/h, if reached
+    pub zero_to_100_kmh: Option<f64>,
+    /// Marks the run passed, shortest first
+    pub marks: Vec<MarkTime>,
+    /// Highest speed of the run (m/s)
+    pub peak_speed_mps: f64,
+    /// Distance covered before the car slowed down (m)
+    pub distance_m: f64,
+}
+
+impl DragRun {
+    /// Time and speed at a mark, if the run got that far
+    pub fn mark(&self, mark: DragMark) -> Option<&MarkTime> {
+        self.marks.iter().find(|m| m.mark == mark)
+    }
+}
+
+/// Value of `y` where `x` crosses `target` between two samples
+fn interpolate(x0: f64, x1: f64, y0: f64, y1: f64, target: f64) -> f64 {
+    if x1 == x0 {
+        y1
+    } else {
+        y0 + (y1 - y0) * (target - x0) / (x1 - x0)
+    }
+}
+
+/// Find every run from a standstill that reaches 60 ft. `speed_mps` is the
+/// vehicle speed in m/s, one value per entry in `times`.
+pub fn detect_runs(times: &[f64], speed_mps: &[f64]) -> Vec<DragRun> {
+    let len = times.len().min(speed_mps.len());
+    let mut runs = Vec::new();
+    let mut i = 0;
+    while i + 1 < len {
+        if !(speed_mps[i] <= STOPPED_MPS && speed_mps[i + 1] > STOPPED_MPS) {
+            i += 1;
+            continue;
+        }
+
+        // Time the run from where speed started rising, not where it
+        // crossed the stopped threshold
+        let mut launch = i;
+        while launch > 0 && speed_mps[launch - 1] < speed_mps[launch] {
+            launch -= 1;
+        }
+        let launch_time = times[launch];
+        let mut run = DragRun {
+            launch_record: launch,
+            end_record: launch,
+            launch_time,
+            end_time: launch_time,
+            zero_to_60_mph: None,
+            zero_to_100_kmh: None,
+            marks: Vec::new(),
+            peak_speed_mps: 0.0,
+            distance_m: 0.0,
+        };
+
+        let mut j = launch + 1;
+        while j < len {
+            let (t0, t1) = (times[j - 1], times[j]);
+            let (v0, v1) = (speed_mps[j - 1], speed_mps[j]);
+            if !(v1.is_finite() && t1 > t0) {
+                j += 1;
+                continue;
+            }
+            // Records up to the threshold crossing are still the launch
+            if j > i && (v1 <= STOPPED_MPS || v1 < run.peak_speed_mps * (1.0 - END_SPEED_DROP)) {
+                break;
+            }
+
+            let d0 = run.distance_m;
+            let d1 = d0 + (v0 + v1) / 2.0 * (t1 - t0);
+            for mark in DragMark::ALL {
+                let target = mark.distance_m();
+                if d0 < target && d1 >= target {
+                    run.marks.push(MarkTime {
+                        mark,
+                        elapsed: interpolate(d0, d1, t0, t1, target) - launch_time,
+                        speed_mps: interpolate(d0, d1, v0, v1, target),
+                    });
+                }
+            }
+            for (target, result) in [
+                (MPH_60_MPS, &mut run.zero_to_60_mph),
+                (KMH_100_MPS, &mut run.zero_to_100_kmh),
+            ] {
+                if result.is_none() && v0 < target && v1 >= target {
+                    *result = Some(interpolate(v0, v1, t0, t1, target) - launch_time);
+                }
+            }
+
+            run.distance_m = d1;
+            if v1 >= run.peak_speed_mps {
+                run.peak_speed_mps = v1;
+                run.end_record = j;
+                run.end_time = t1;
+            }
+            j += 1;
+        }
+
+        if run.mark(DragMark::SixtyFoot).is_some() {
+            runs.push(run);
+        }
+        i = j.max(i + 1);
+    }
+    runs
+}
+
+/// Runs as CSV, one row per run, with speeds converted by `speed` into
+/// `speed_unit`
+pub fn runs_to_csv(runs: &[DragRun], speed: impl Fn(f64) -> f64, speed_unit: &str) -> String {
+    let mut csv = String::from("Run,Launch (s),0-60 mph (s),0-100 km/h (s)");
+    for mark in DragMark::ALL {
+        let _ = write!(
+            csv,
+            ",{label} (s),{label} speed ({unit})",
+            label = mark.label(),
+            unit = speed_unit
+        );
+    }
+    let _ = writeln!(csv, ",Peak speed ({})", speed_unit);
+
+    let optional = |value: Option<f64>, precision: usize| {
+        value.map_or_else(String::new, |v| format!("{:.*}", precision, v))
+    };
+    for (i, run) in runs.iter().enumerate() {
+        let _ = write!(
+            csv,
+            "{},{:.3},{},{}",
+            i + 1,
+            run.launch_time,
+            optional(run.zero_to_60_mph, 3),
+            optional(run.zero_to_100_kmh, 3)
+        );
+        for mark in DragMark::ALL {
+            let result = run.mark(mark);
+            let _ = write!(
+                csv,
+                ",{},{}",
+                optional(result.map(|m| m.elapsed), 3),
+                optional(result.map(|m| speed(m.speed_mps)), 1)
+            );
+        }
+        let _ = writeln!(csv, ",{:.1}", speed(run.peak_speed_mps));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A car sitting still for a second, then accelerating at a constant
+    /// 8 m/s² for 12 seconds and braking, sampled at 100 Hz
+    fn launch() -> (Vec<f64>, Vec<f64>) {
+        let times: Vec<f64> = (0..1500).map(|i| i as f64 * 0.01).collect();
+        let speed = times
+            .iter()
+            .map(|&t| match t {
+                t if t <= 1.0 => 0.0,
+                t if t <= 13.0 => 8.0 * (t - 1.0),
+                t => (96.0 - 20.0 * (t - 13.0)).max(0.0),
+            })
+            .collect();
+        (times, speed)
+    }
+
+    #[test]
+    fn test_detect_run() {
+        let (times, speed) = launch();
+        let runs = detect_runs(&times, &speed);
+        assert_eq!(runs.len(), 1);
+        let run = &runs[0];
+        assert!((run.launch_time - 1.0).abs() < 1e-9);
+
+        // Constant acceleration: d = a·t²/2, v = a·t
+        for mark in DragMark::ALL {
+            let expected = (2.0 * mark.distance_m() / 8.0).sqrt();
+            let result = run.mark(mark).unwrap();
+            assert!(
+                (result.elapsed - expected).abs() < 0.02,
+                "{}: {} vs {}",
+                mark.label(),
+                result.elapsed,
+                expected
+            );
+            assert!((result.speed_mps - 8.0 * expected).abs() < 0.2);
+        }
+        assert!((run.zero_to_60_mph.unwrap() - MPH_60_MPS / 8.0).abs() < 0.01);
+        assert!((run.zero_to_100_kmh.unwrap() - KMH_100_MPS / 8.0).abs() < 0.01);
+        // The run ends at its peak speed, before braking
+        assert!((run.end_time - 13.0).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_short_roll_is_not_a_run() {
+        let times: Vec<f64> = (0..50).map(|i| i as f64 * 0.1).collect();
+        let speed: Vec<f64> = times
+            .iter()
+            .map(|&t| if (1.0..2.0).contains(&t) { 2.0 } else { 0.0 })
+            .collect();
+        assert!(detect_runs(&times, &speed).is_empty());
+    }
+
+    #[test]
+    fn test_runs_to_csv() {
+        let (times, speed) = launch();
+        let csv = runs_to_csv(&detect_runs(&times, &speed), |v| v * 3.6, "km/h");
+        let mut lines = csv.lines();
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("Run,Launch (s),0-60 mph (s)"));
+        assert!(lines.next().unwrap().starts_with("1,1.000,"));
+        assert!(lines.next().is_none());
+    }
+}
@@ -0,0 +1,249 @@
+//! Virtual dyno power estimation.
+//!
+//! Estimates wheel power and torque for a wide-open-throttle pull from vehicle
+//! speed (or RPM with a known gear ratio), using a simple road-load model:
+//!
+//! `F = m·a + ½·ρ·Cd·A·v² + Crr·m·g`, `P = F·v`
+//!
+//! Results are only as good as the vehicle parameters, but are consistent
+//! enough to compare tune changes on the same car and road.
+
+use serde::{Deserialize, Serialize};
+
+/// Air density at sea level, 15°C (kg/m³)
+const AIR_DENSITY: f64 = 1.225;
+
+/// Standard gravity (m/s²)
+const GRAVITY: f64 = 9.80665;
+
+/// Watts per mechanical horsepower
+pub const WATTS_PER_HP: f64 = 745.699872;
+
+/// Newton-metres per pound-foot
+pub const NM_PER_LB_FT: f64 = 1.355818;
+
+/// Where vehicle speed comes from
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpeedSource {
+    /// Use a logged vehicle speed channel
+    #[default]
+    VehicleSpeed,
+    /// Derive speed from RPM, gear ratio, final drive, and tire diameter
+    RpmAndGear,
+}
+
+/// Vehicle parameters used for the power estimate
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DynoConfig {
+    /// Vehicle mass including driver (kg)
+    pub mass_kg: f64,
+    /// Aerodynamic drag coefficient
+    pub drag_coefficient: f64,
+    /// Frontal area (m²)
+    pub frontal_area_m2: f64,
+    /// Rolling resistance coefficient
+    pub rolling_resistance: f64,
+    /// Speed source
+    pub speed_source: SpeedSource,
+    /// Gear ratio of the gear used for the pull (RPM-derived speed only)
+    pub gear_ratio: f64,
+    /// Final drive ratio (RPM-derived speed only)
+    pub final_drive: f64,
+    /// Tire outer diameter (m) (RPM-derived speed only)
+    pub tire_diameter_m: f64,
+    /// Moving-average window (samples) applied to speed before differentiating
+    pub smoothing_samples: usize,
+    /// Show results in hp / lb·ft instead of kW / Nm
+    pub imperial_output: bool,
+}
+
+impl Default for DynoConfig {
+    fn default() -> Self {
+        Self {
+            mass_kg: 1400.0,
+            drag_coefficient: 0.32,
+            frontal_area_m2: 2.0,
+            rolling_resistance: 0.015,
+            speed_source: SpeedSource::default(),
+            gear_ratio: 1.0,
+            final_drive: 4.1,
+            tire_diameter_m: 0.63,
+            smoothing_samples: 9,
+            imperial_output: true,
+        }
+    }
+}
+
+/// One point on the power curve
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DynoPoint {
+    /// Time of the sample (seconds)
+    pub time: f64,
+    /// Engine RPM, if an RPM channel was available
+    pub rpm: Option<f64>,
+    /// Vehicle speed (m/s)
+    pub speed_mps: f64,
+    /// Estimated wheel power (W)
+    pub power_w: f64,
+    /// Estimated torque (Nm) - at the crank when RPM is known, otherwise at the wheel
+    pub torque_nm: f64,
+}
+
+impl DynoPoint {
+    /// Power in horsepower
+    pub fn power_hp(&self) -> f64 {
+        self.power_w / WATTS_PER_HP
+    }
+
+    /// Power in kilowatts
+    pub fn power_kw(&self) -> f64 {
+        self.power_w / 1000.0
+    }
+}
+
+impl DynoConfig {
+    /// Vehicle speed (m/s) for a given engine RPM using the gearing parameters
+    pub fn speed_from_rpm(&self, rpm: f64) -> f64 {
+        let overall_ratio = self.gear_ratio * self.final_drive;
+        if overall_ratio <= 0.0 {
+            return 0.0;
+        }
+        let wheel_rps = rpm / 60.0 / overall_ratio;
+        wheel_rps * std::f64::consts::PI * self.tire_diameter_m
+    }
+
+    /// Road-load force (N) excluding acceleration at a given speed
+    fn resistance_force(&self, speed_mps: f64) -> f64 {
+        let aero =
+            0.5 * AIR_DENSITY * self.drag_coefficient * self.frontal_area_m2 * speed_mps.powi(2);
+        let rolling = self.rolling_resistance * self.mass_kg * GRAVITY;
+        aero + rolling
+    }
+
+    /// Compute the power curve for a pull.
+    ///
+    /// `speed_mps` must contain one entry per time sample. `rpm`, when given,
+    /// is used for the X axis and to report crank-side torque.
+    pub fn compute(&self, times: &[f64], speed_mps: &[f64], rpm: Option<&[f64]>) -> Vec<DynoPoint> {
+        let n = times.len().min(speed_mps.len());
+        if n < 3 {
+            return Vec::new();
+        }
+
+        let smoothed = moving_average(&speed_mps[..n], self.smoothing_samples.max(1));
+
+        (1..n - 1)
+            .filter_map(|i| {
+                let dt = times[i + 1] - times[i - 1];
+                if dt <= 0.0 {
+                    return None;
+                }
+                let v = smoothed[i];
+                let accel = (smoothed[i + 1] - smoothed[i - 1]) / dt;
+                let force = self.mass_kg * accel + self.resistance_force(v);
+                let power_w = force * v;
+
+                let engine_rpm = rpm.and_then(|r| r.get(i).copied());
+                let torque_nm = match engine_rpm {
+                    Some(r) if r > 0.0 => power_w / (r * 2.0 * std::f64::consts::PI / 60.0),
+                    _ => force * self.tire_diameter_m / 2.0,
+                };
+
+                Some(DynoPoint {
+                    time: times[i],
+                    rpm: engine_rpm,
+                    speed_mps: v,
+                    power_w,
+                    torque_nm,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Centered moving average with a window of `window` samples
+fn moving_average(values: &[f64], window: usize) -> Vec<f64> {
+    let half = window / 2;
+    (0..values.len())
+        .map(|i| {
+            let start = i.saturating_sub(half);
+            let end = (i + half + 1).min(values.len());
+            values[start..end].iter().sum::<f64>() / (end - start) as f64
+        })
+        .collect()
+}
+
+/// Convert a logged speed value to m/s based on its unit string
+pub fn speed_to_mps(value: f64, unit: &str) -> f64 {
+    match unit.to_lowercase().as_str() {
+        "m/s" => value,
+        "mph" => value * 0.44704,
+        // km/h is the common source unit across supported ECUs
+        _ => value / 3.6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_speed_from_rpm() {
+        let config = DynoConfig {
+            gear_ratio: 1.0,
+            final_drive: 1.0,
+            tire_diameter_m: 1.0 / std::f64::consts::PI,
+            ..Default::default()
+        };
+        // 60 RPM = 1 rev/s, circumference 1 m => 1 m/s
+        assert!((config.speed_from_rpm(60.0) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_constant_acceleration_power() {
+        let config = DynoConfig {
+            mass_kg: 1000.0,
+            drag_coefficient: 0.0,
+            rolling_resistance: 0.0,
+            smoothing_samples: 1,
+            ..Default::default()
+        };
+        // 2 m/s² acceleration from 10 m/s
+        let times: Vec<f64> = (0..10).map(|i| i as f64 * 0.1).collect();
+        let speeds: Vec<f64> = times.iter().map(|t| 10.0 + 2.0 * t).collect();
+
+        let points = config.compute(&times, &speeds, None);
+        assert_eq!(points.len(), 8);
+        // P = m * a * v = 1000 * 2 * v
+        for p in &points {
+            assert!((p.power_w - 2000.0 * p.speed_mps).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_crank_torque_from_rpm() {
+        let config = DynoConfig {
+            mass_kg: 1000.0,
+            drag_coefficient: 0.0,
+            rolling_resistance: 0.0,
+            smoothing_samples: 1,
+            ..Default::default()
+        };
+        let times = [0.0, 1.0, 2.0];
+        let speeds = [10.0, 11.0, 12.0];
+        let rpm = [3000.0, 3000.0, 3000.0];
+
+        let points = config.compute(&times, &speeds, Some(&rpm));
+        assert_eq!(points.len(), 1);
+        let expected = points[0].power_w / (3000.0 * 2.0 * std::f64::consts::PI / 60.0);
+        assert!((points[0].torque_nm - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_speed_to_mps() {
+        assert!((speed_to_mps(36.0, "km/h") - 10.0).abs() < 1e-9);
+        assert!((speed_to_mps(10.0, "m/s") - 10.0).abs() < 1e-9);
+        assert!((speed_to_mps(1.0, "mph") - 0.44704).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,158 @@
+//! Exhaust gas temperature spread.
+//!
+//! On engines logging one EGT per cylinder (or per bank), the spread between
+//! the hottest and coldest probe shows cylinders running leaner, richer or
+//! with different timing than the rest. The spread is added to the log as a
+//! derived channel, and periods where it stays above a threshold are listed
+//! as imbalances.
+
+use serde::{Deserialize, Serialize};
+
+use crate::alarms::Violation;
+use crate::parsers::types::{Channel, Log};
+use crate::units::TemperatureUnit;
+
+/// Name of the derived spread channel
+pub const SPREAD_CHANNEL: &str = "EGT Spread";
+
+/// Name fragments of channels that summarize several probes rather than
+/// measure one (averages, limits, targets and the like)
+const SUMMARY_KEYWORDS: [&str; 7] = ["avg", "average", "max", "min", "spread", "diff", "target"];
+
+/// Thresholds a stretch of the log must meet to count as an imbalance
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EgtSpreadConfig {
+    /// Spread above which the cylinders are imbalanced, in display units
+    pub threshold: f64,
+    /// Minimum length of an imbalance (seconds)
+    pub min_duration: f64,
+}
+
+impl Default for EgtSpreadConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 100.0,
+            min_duration: 0.5,
+        }
+    }
+}
+
+/// Per-probe EGT channels of a log, all in the same unit as the first.
+/// Fewer than two means there is no spread to compute.
+pub fn find_egt_channels(log: &Log) -> Vec<usize> {
+    let probes: Vec<usize> = log
+        .channels
+        .iter()
+        .enumerate()
+        .filter(|(_, channel)| {
+            let name = channel.name().to_lowercase();
+            !matches!(channel, Channel::Derived(_))
+                && (name.contains("egt") || (name.contains("exhaust") && name.contains("temp")))
+                && !SUMMARY_KEYWORDS.iter().any(|k| name.contains(k))
+                && TemperatureUnit::from_source_unit(channel.unit()).is_some()
+        })
+        .map(|(index, _)| index)
+        .collect();
+
+    let Some(&first) = probes.first() else {
+        return probes;
+    };
+    let unit = log.channels[first].unit();
+    probes
+        .into_iter()
+        .filter(|&index| log.channels[index].unit() == unit)
+        .collect()
+}
+
+/// Hottest minus coldest probe per record, ignoring non-finite readings.
+/// Records with fewer than two readings have no spread (0).
+pub fn egt_spread(probes: &[&[f64]]) -> Vec<f64> {
+    let len = probes.iter().map(|p| p.len()).min().unwrap_or(0);
+    (0..len)
+        .map(|i| {
+            let (count, low, high) = probes.iter().map(|p| p[i]).filter(|v| v.is_finite()).fold(
+                (0, f64::INFINITY, f64::NEG_INFINITY),
+                |(count, low, high), v| (count + 1, low.min(v), high.max(v)),
+            );
+            if count < 2 {
+                0.0
+            } else {
+                high - low
+            }
+        })
+        .collect()
+}
+
+impl EgtSpreadConfig {
+    /// Stretches where `spread` (in display units) stays above the threshold
+    /// for at least the minimum duration, in time order
+    pub fn imbalances(&self, times: &[f64], spread: &[f64]) -> Vec<Violation> {
+        let mut periods = Vec::new();
+        let mut current: Option<Violation> = None;
+        for (i, (&time, &value)) in times.iter().zip(spread).enumerate() {
+            if value > self.threshold {
+                match &mut current {
+                    Some(period) => {
+                        period.end_time = time;
+                        period.end_record = i;
+                        period.peak_value = period.peak_value.max(value);
+                    }
+                    None => {
+                        current = Some(Violation {
+                            start_time: time,
+                            end_time: time,
+                            start_record: i,
+                            end_record: i,
+                            peak_value: value,
+                        });
+                    }
+                }
+            } else {
+                periods.extend(current.take());
+            }
+        }
+        periods.extend(current);
+        periods.retain(|p| p.duration() >= self.min_duration);
+        periods
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::{GenericCsv, Parseable};
+
+    #[test]
+    fn test_find_egt_channels() {
+        let log = GenericCsv::default()
+            .parse("Time,EGT 1 (C),EGT 2 (C),EGT Avg (C),Exhaust Temp 3 (C),RPM\n0,800,820,810,790,3000\n")
+            .unwrap();
+        assert_eq!(find_egt_channels(&log), vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn test_egt_spread() {
+        let egt1 = [800.0, 850.0, f64::NAN];
+        let egt2 = [820.0, 700.0, 900.0];
+        let egt3 = [810.0, 760.0, f64::NAN];
+        assert_eq!(egt_spread(&[&egt1, &egt2, &egt3]), vec![20.0, 150.0, 0.0]);
+    }
+
+    #[test]
+    fn test_imbalances() {
+        let config = EgtSpreadConfig {
+            threshold: 100.0,
+            min_duration: 0.15,
+        };
+        let times = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6];
+        // A long imbalance, then one too short to count
+        let spread = [50.0, 120.0, 150.0, 130.0, 40.0, 140.0, 60.0];
+
+        let periods = config.imbalances(&times, &spread);
+        assert_eq!(periods.len(), 1);
+        assert_eq!(periods[0].start_record, 1);
+        assert_eq!(periods[0].end_record, 3);
+        assert_eq!(periods[0].peak_value, 150.0);
+    }
+}
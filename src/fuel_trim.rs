@@ -0,0 +1,184 @@
+//! Fuel trim summary.
+//!
+//! Bins closed-loop fuel correction (short plus long term trim, or EGO
+//! correction) into RPM/load cells and flags the cells whose average
+//! correction is past a threshold. A cell the ECU keeps adding fuel to has
+//! too little VE for that region, and one it keeps pulling fuel from has too
+//! much, so the flagged cells point at the parts of the VE table to rework.
+
+use serde::{Deserialize, Serialize};
+
+use crate::binning::{AxisBins, BinnedGrid};
+
+/// Median above which a correction channel is taken to be 100-based (100% =
+/// no correction) rather than centered on 0
+const HUNDRED_BASED_MEDIAN: f64 = 50.0;
+
+/// Settings for the fuel trim summary
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FuelTrimConfig {
+    /// Average correction (%) past which a cell is flagged
+    pub threshold: f64,
+    /// Fewest samples a cell needs before it can be flagged
+    pub min_hits: u32,
+    /// Width of the RPM cells
+    pub rpm_step: f64,
+    /// Width of the load (MAP) cells, in display units
+    pub load_step: f64,
+}
+
+impl Default for FuelTrimConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 5.0,
+            min_hits: 20,
+            rpm_step: 500.0,
+            load_step: 10.0,
+        }
+    }
+}
+
+/// A cell whose average correction is past the threshold
+#[derive(Clone, Debug, PartialEq)]
+pub struct FlaggedCell {
+    /// RPM at the cell's center
+    pub rpm: f64,
+    /// Load at the cell's center
+    pub load: f64,
+    /// Average correction (%); positive means the ECU added fuel
+    pub correction: f64,
+    pub hits: u32,
+}
+
+/// Fuel correction binned by RPM and load
+#[derive(Clone, Debug)]
+pub struct TrimReport {
+    /// Correction (%) per cell, RPM along X and load along Y
+    pub grid: BinnedGrid,
+    /// Cells past the threshold, largest correction first
+    pub flagged: Vec<FlaggedCell>,
+}
+
+/// Total correction in percent centered on 0: the sum of whichever trims
+/// the log has, each re-centered if it is 100-based
+pub fn total_correction(trims: &[&[f64]]) -> Vec<f64> {
+    let len = trims.iter().map(|t| t.len()).min().unwrap_or(0);
+    let offsets: Vec<f64> = trims.iter().map(|t| neutral_value(t)).collect();
+    (0..len)
+        .map(|i| {
+            trims
+                .iter()
+                .zip(&offsets)
+                .map(|(trim, offset)| trim[i] - offset)
+                .sum()
+        })
+        .collect()
+}
+
+/// The value meaning "no correction": 100 for channels logged as a
+/// percentage of base fuel (e.g. Speeduino's Gego), otherwise 0
+fn neutral_value(values: &[f64]) -> f64 {
+    let mut finite: Vec<f64> = values.iter().copied().filter(|v| v.is_finite()).collect();
+    if finite.is_empty() {
+        return 0.0;
+    }
+    let mid = finite.len() / 2;
+    let (_, median, _) = finite.select_nth_unstable_by(mid, |a, b| a.total_cmp(b));
+    if *median > HUNDRED_BASED_MEDIAN {
+        100.0
+    } else {
+        0.0
+    }
+}
+
+/// Bins covering `values` in steps of `step`, centered on its multiples
+fn step_bins(values: &[f64], step: f64) -> Option<AxisBins> {
+    let (min, max) = values.iter().filter(|v| v.is_finite()).fold(
+        None,
+        |acc: Option<(f64, f64)>, &v| match acc {
+            None => Some((v, v)),
+            Some((lo, hi)) => Some((lo.min(v), hi.max(v))),
+        },
+    )?;
+    let step = step.max(f64::EPSILON);
+    let first = (min / step).round() as i64;
+    let last = (max / step).round() as i64;
+    let centers: Vec<f64> = (first..=last).map(|i| i as f64 * step).collect();
+    AxisBins::from_breakpoints(&centers)
+}
+
+impl FuelTrimConfig {
+    /// Bin `correction` (%, centered on 0) by RPM and load, and flag cells
+    /// past the threshold. Returns `None` if there are no RPM or load values.
+    pub fn analyze(&self, rpm: &[f64], load: &[f64], correction: &[f64]) -> Option<TrimReport> {
+        let x = step_bins(rpm, self.rpm_step)?;
+        let y = step_bins(load, self.load_step)?;
+        let grid = BinnedGrid::build(x, y, rpm, load, Some(correction));
+
+        let mut flagged = Vec::new();
+        for (yi, &load) in grid.y.centers().iter().enumerate() {
+            for (xi, &rpm) in grid.x.centers().iter().enumerate() {
+                let cell = grid.cell(xi, yi);
+                let Some(mean) = cell.mean() else {
+                    continue;
+                };
+                if cell.count >= self.min_hits && mean.abs() > self.threshold {
+                    flagged.push(FlaggedCell {
+                        rpm,
+                        load,
+                        correction: mean,
+                        hits: cell.count,
+                    });
+                }
+            }
+        }
+        flagged.sort_by(|a, b| b.correction.abs().total_cmp(&a.correction.abs()));
+
+        Some(TrimReport { grid, flagged })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_total_correction() {
+        let stft = [2.0, -1.0, 0.0];
+        let ltft = [3.0, 3.0, 3.0];
+        assert_eq!(total_correction(&[&stft, &ltft]), vec![5.0, 2.0, 3.0]);
+
+        // Speeduino's Gego logs 100 for no correction
+        let gego = [100.0, 108.0, 95.0];
+        assert_eq!(total_correction(&[&gego]), vec![0.0, 8.0, -5.0]);
+    }
+
+    #[test]
+    fn test_flagged_cells() {
+        let config = FuelTrimConfig {
+            min_hits: 2,
+            ..Default::default()
+        };
+        // Lean at 3000 RPM / 100 kPa, fine at 2000 RPM / 50 kPa, one rich sample
+        let rpm = [2000.0, 2010.0, 3000.0, 2990.0, 3020.0, 4000.0];
+        let map = [50.0, 52.0, 100.0, 99.0, 101.0, 150.0];
+        let correction = [1.0, -1.0, 9.0, 11.0, 10.0, -20.0];
+
+        let report = config.analyze(&rpm, &map, &correction).unwrap();
+        assert_eq!(report.grid.x.centers().first(), Some(&2000.0));
+        assert_eq!(report.grid.y.centers().last(), Some(&150.0));
+        // The rich cell has too few hits to be flagged
+        assert_eq!(
+            report.flagged,
+            vec![FlaggedCell {
+                rpm: 3000.0,
+                load: 100.0,
+                correction: 10.0,
+                hits: 3,
+            }]
+        );
+
+        assert!(config.analyze(&[], &[], &[]).is_none());
+    }
+}
@@ -0,0 +1,124 @@
+//! Fuzzy matching for the channel search box.
+//!
+//! Matches queries as case-insensitive subsequences ("clt" matches "Coolant
+//! Temperature"), scoring exact, prefix and whole-word matches above scattered
+//! ones, and rewarding characters that start words or follow each other.
+
+/// Score for a query equal to the channel's unit ("kPa" lists pressures)
+const UNIT_MATCH_SCORE: i64 = 500;
+
+/// Whether the character at `index` starts a word: the first character, one
+/// after a separator, or an uppercase letter following a lowercase one
+fn is_word_start(chars: &[char], index: usize) -> bool {
+    let Some(&current) = chars.get(index) else {
+        return false;
+    };
+    match index.checked_sub(1).map(|i| chars[i]) {
+        None => true,
+        Some(previous) => {
+            !previous.is_alphanumeric() || (previous.is_lowercase() && current.is_uppercase())
+        }
+    }
+}
+
+/// Score how well `query` matches `text`; higher is better and `None` means
+/// no match. An empty query matches everything equally.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let text_lower = text.to_lowercase();
+
+    if text_lower == query {
+        return Some(1000);
+    }
+    if text_lower.starts_with(&query) {
+        return Some(900 - text_lower.len() as i64);
+    }
+
+    // Substring starting a word, e.g. "rpm" in "Engine RPM"
+    let chars: Vec<char> = text.chars().collect();
+    // Lowercase per character so indices line up with `chars`
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let query_chars: Vec<char> = query.chars().collect();
+    if let Some(position) =
+        (0..lower.len()).find(|&i| is_word_start(&chars, i) && lower[i..].starts_with(&query_chars))
+    {
+        return Some(800 - position as i64);
+    }
+
+    // Subsequence match, matching each query character as early as possible
+    let mut score = 0;
+    let mut next = 0;
+    let mut previous_match: Option<usize> = None;
+    for q in query.chars() {
+        let offset = lower.get(next..)?.iter().position(|&c| c == q)?;
+        let index = next + offset;
+        score += 10;
+        if is_word_start(&chars, index) {
+            score += 30;
+        }
+        match previous_match {
+            Some(previous) if previous + 1 == index => score += 20,
+            Some(previous) => score -= (index - previous - 1).min(10) as i64,
+            None => score -= index.min(10) as i64,
+        }
+        previous_match = Some(index);
+        next = index + 1;
+    }
+    // Keep scattered matches below any whole-word match
+    Some(score.min(600))
+}
+
+/// Best score of a channel against the query, matching its names fuzzily
+/// and its unit exactly
+pub fn channel_score(query: &str, names: &[&str], unit: &str) -> Option<i64> {
+    let unit_score =
+        (!unit.is_empty() && query.trim().eq_ignore_ascii_case(unit)).then_some(UNIT_MATCH_SCORE);
+    names
+        .iter()
+        .filter_map(|name| fuzzy_score(query, name))
+        .chain(unit_score)
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score() {
+        assert!(fuzzy_score("clt", "Coolant Temperature").is_some());
+        assert!(fuzzy_score("afr", "Air Fuel Ratio").is_some());
+        assert!(fuzzy_score("xyz", "Coolant Temperature").is_none());
+        assert_eq!(fuzzy_score("", "RPM"), Some(0));
+
+        // Exact beats prefix beats word substring beats subsequence
+        let exact = fuzzy_score("rpm", "RPM").unwrap();
+        let prefix = fuzzy_score("rpm", "RPM Target").unwrap();
+        let substring = fuzzy_score("rpm", "Engine RPM").unwrap();
+        let scattered = fuzzy_score("rpm", "Rear Pump Motor").unwrap();
+        assert!(exact > prefix && prefix > substring && substring > scattered);
+
+        // Word starts rank above matches in the middle of words
+        assert!(fuzzy_score("ct", "Coolant Temp").unwrap() > fuzzy_score("ct", "Octane").unwrap());
+        assert!(fuzzy_score("ect", "engineCoolantTemp").is_some());
+    }
+
+    #[test]
+    fn test_channel_score() {
+        assert_eq!(
+            channel_score("kPa", &["MAP"], "kPa"),
+            Some(UNIT_MATCH_SCORE)
+        );
+        assert_eq!(channel_score("kpa", &["Coolant"], "°C"), None);
+        assert_eq!(
+            channel_score("map", &["Inlet Manifold Pressure", "MAP"], "kPa"),
+            Some(1000)
+        );
+    }
+}
@@ -0,0 +1,924 @@
+//! Translation of UI strings.
+//!
+//! Strings are looked up by their English text, so the code reads the same
+//! as before translation and anything without a translation falls back to
+//! English. Each language other than English has a table of English text
+//! and its translation; adding a language means adding a variant and a
+//! table. Menu and dialog labels keep their icon prefixes in both columns.
+
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use serde::{Deserialize, Serialize};
+
+/// Language of the user interface
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::German];
+
+    /// Name of the language in that language
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+        }
+    }
+
+    /// The translation of an English UI string, or the string itself if
+    /// this language has none
+    pub fn tr<'a>(&self, text: &'a str) -> &'a str {
+        let table = match self {
+            Language::English => return text,
+            Language::German => &GERMAN,
+        };
+        table.get(text).copied().unwrap_or(text)
+    }
+}
+
+static GERMAN: LazyLock<HashMap<&'static str, &'static str>> =
+    LazyLock::new(|| GERMAN_STRINGS.iter().copied().collect());
+
+/// English UI strings and their German translations
+const GERMAN_STRINGS: &[(&str, &str)] = &[
+    // File menu
+    ("File", "Datei"),
+    ("📂  Open Log File...", "📂  Logdatei öffnen..."),
+    ("Log Files", "Logdateien"),
+    ("🔄  Reload from Disk", "🔄  Neu von Festplatte laden"),
+    (
+        "Parse the active log again, keeping its channels and view",
+        "Aktives Log erneut einlesen; Kanäle und Ansicht bleiben erhalten",
+    ),
+    ("🗂  Open Session...", "🗂  Sitzung öffnen..."),
+    ("UltraLog Session", "UltraLog-Sitzung"),
+    ("💾  Save Session...", "💾  Sitzung speichern..."),
+    ("📤  Export", "📤  Exportieren"),
+    ("Export as PNG...", "Als PNG exportieren..."),
+    ("Export as PDF...", "Als PDF exportieren..."),
+    ("Export as HTML...", "Als HTML exportieren..."),
+    (
+        "The visible chart as an interactive page that opens in any browser",
+        "Das sichtbare Diagramm als interaktive Seite, die sich in jedem Browser öffnet",
+    ),
+    ("Export Selection as CSV...", "Auswahl als CSV exportieren..."),
+    (
+        "Records in the A/B loop region, or the visible chart, as a new CSV log",
+        "Datensätze im A/B-Schleifenbereich oder im sichtbaren Diagramm als neues CSV-Log",
+    ),
+    ("Export Log as Arrow...", "Log als Arrow exportieren..."),
+    (
+        "All channels as an Arrow IPC (Feather) file for pandas/Polars",
+        "Alle Kanäle als Arrow-IPC-Datei (Feather) für pandas/Polars",
+    ),
+    ("✂  Trim to Selection", "✂  Auf Auswahl zuschneiden"),
+    (
+        "Open the records in the A/B loop region, or the visible chart, as a new log",
+        "Datensätze im A/B-Schleifenbereich oder im sichtbaren Diagramm als neues Log öffnen",
+    ),
+    ("🔗  Merge Logs...", "🔗  Logs zusammenführen..."),
+    (
+        "Combine channels from two logs recorded at the same time",
+        "Kanäle zweier gleichzeitig aufgezeichneter Logs kombinieren",
+    ),
+    ("📄  Generate Report...", "📄  Bericht erstellen..."),
+    (
+        "Combine analyses of this log into an HTML or PDF report",
+        "Analysen dieses Logs in einem HTML- oder PDF-Bericht zusammenfassen",
+    ),
+    // View menu
+    ("View", "Ansicht"),
+    ("🎯  Cursor Tracking", "🎯  Cursor verfolgen"),
+    ("◫  Split View", "◫  Geteilte Ansicht"),
+    ("Show two tabs at once", "Zwei Tabs gleichzeitig anzeigen"),
+    ("Side by Side", "Nebeneinander"),
+    ("Stacked", "Übereinander"),
+    ("Link Cursors", "Cursor koppeln"),
+    (
+        "Move both panes' cursors together",
+        "Die Cursor beider Bereiche gemeinsam bewegen",
+    ),
+    ("🔗  Link Cursors Across Tabs", "🔗  Cursor über alle Tabs koppeln"),
+    (
+        "Move every tab's cursor to the same time from the start of its log",
+        "Den Cursor jedes Tabs auf dieselbe Zeit ab Beginn seines Logs setzen",
+    ),
+    ("🕐  Wall-Clock Time", "🕐  Uhrzeit"),
+    (
+        "Show the time of day the log was recorded instead of the time from its start (Haltech and MLG logs)",
+        "Die Uhrzeit der Aufzeichnung statt der Zeit ab Log-Beginn anzeigen (Haltech- und MLG-Logs)",
+    ),
+    ("👁  Color Blind Mode", "👁  Farbenblind-Modus"),
+    ("📝  Field Normalization", "📝  Feldnormalisierung"),
+    (
+        "Standardize channel names across different ECU types",
+        "Kanalnamen verschiedener Steuergeräte vereinheitlichen",
+    ),
+    ("Show Original Names", "Originalnamen anzeigen"),
+    (
+        "Follow normalized names with the ECU's own name, e.g. \"Engine Speed (RPM Ch1)\"",
+        "Hinter normalisierten Namen den Namen des Steuergeräts anzeigen, z. B. \"Engine Speed (RPM Ch1)\"",
+    ),
+    ("      Edit Mappings...", "      Zuordnungen bearbeiten..."),
+    ("🏷  Annotations...", "🏷  Anmerkungen..."),
+    ("🚨  Alarm Rules...", "🚨  Alarmregeln..."),
+    ("🔍  Find Value...", "🔍  Wert suchen..."),
+    ("🏁  WOT Pulls...", "🏁  Volllastzüge..."),
+    ("🌀  Boost Control...", "🌀  Ladedruckregelung..."),
+    ("⛽  Fuel Trims...", "⛽  Gemischkorrekturen..."),
+    ("💥  Misfire Detector...", "💥  Zündaussetzer-Erkennung..."),
+    ("🔥  EGT Spread...", "🔥  AGT-Streuung..."),
+    ("⏳  Idle Quality...", "⏳  Leerlaufqualität..."),
+    ("🏁  Drag Runs...", "🏁  Beschleunigungsläufe..."),
+    ("🔀  Shift Points...", "🔀  Schaltpunkte..."),
+    ("📡  Live Data...", "📡  Live-Daten..."),
+    ("⚙  Preferences...", "⚙  Einstellungen..."),
+    // Units menu
+    ("Units", "Einheiten"),
+    ("📑  Override for This Tab", "📑  Nur für diesen Tab"),
+    (
+        "Use different units in this tab only; other tabs keep the global defaults",
+        "Andere Einheiten nur in diesem Tab verwenden; andere Tabs behalten die globalen Vorgaben",
+    ),
+    ("Editing this tab's units", "Einheiten dieses Tabs"),
+    ("Editing global defaults", "Globale Vorgaben"),
+    ("°C  Temperature", "°C  Temperatur"),
+    ("💨  Pressure", "💨  Druck"),
+    ("🚗  Speed", "🚗  Geschwindigkeit"),
+    ("Kilometers/hour (km/h)", "Kilometer/Stunde (km/h)"),
+    ("Miles/hour (mph)", "Meilen/Stunde (mph)"),
+    ("📏  Distance", "📏  Strecke"),
+    ("Kilometers (km)", "Kilometer (km)"),
+    ("Miles (mi)", "Meilen (mi)"),
+    ("⛽  Fuel Economy", "⛽  Verbrauch"),
+    ("Liters/100km (L/100km)", "Liter/100 km (L/100km)"),
+    ("Miles/gallon (mpg)", "Meilen/Gallone (mpg)"),
+    ("Kilometers/liter (km/L)", "Kilometer/Liter (km/L)"),
+    ("📊  Volume", "📊  Volumen"),
+    ("Liters (L)", "Liter (L)"),
+    ("Gallons (gal)", "Gallonen (gal)"),
+    ("💧  Flow Rate", "💧  Durchfluss"),
+    ("📈  Acceleration", "📈  Beschleunigung"),
+    ("g-force (g)", "g-Kraft (g)"),
+    ("λ  Air-Fuel Ratio", "λ  Luft-Kraftstoff-Verhältnis"),
+    ("AFR Gasoline (14.7)", "AFR Benzin (14.7)"),
+    ("AFR Custom", "AFR benutzerdefiniert"),
+    (
+        "Stoichiometric ratio of the fuel",
+        "Stöchiometrisches Verhältnis des Kraftstoffs",
+    ),
+    ("🚀  Manifold Pressure", "🚀  Saugrohrdruck"),
+    ("Absolute (MAP)", "Absolut (MAP)"),
+    ("Boost PSI / Vacuum inHg", "Ladedruck PSI / Unterdruck inHg"),
+    (
+        "Barometric pressure used when the log has no baro channel",
+        "Luftdruck für Logs ohne Barometer-Kanal",
+    ),
+    // Help menu
+    ("Help", "Hilfe"),
+    ("📖  Documentation", "📖  Dokumentation"),
+    ("🐛  Report Issue", "🐛  Problem melden"),
+    ("📜  Show Logs", "📜  Protokoll anzeigen"),
+    ("💝  Support Development", "💝  Entwicklung unterstützen"),
+    ("🔄  Checking for Updates...", "🔄  Suche nach Updates..."),
+    ("🔄  Check for Updates", "🔄  Nach Updates suchen"),
+    // Files panel
+    ("Files", "Dateien"),
+    ("+ Add File", "+ Datei hinzufügen"),
+    ("Select a file", "Datei auswählen"),
+    ("or", "oder"),
+    ("Drop file here", "Datei hier ablegen"),
+    ("View Options", "Ansichtsoptionen"),
+    ("Keep cursor centered while scrubbing", "Cursor beim Scrubben mittig halten"),
+    ("View Window:", "Ansichtsfenster:"),
+    ("Use accessible color palette", "Barrierefreie Farbpalette verwenden"),
+    ("Edit", "Bearbeiten"),
+    ("Standardize channel names across ECU types", "Kanalnamen verschiedener Steuergeräte vereinheitlichen"),
+    ("Show original names", "Originalnamen anzeigen"),
+    ("Follow normalized names with the ECU's own name", "Hinter normalisierten Namen den Namen des Steuergeräts anzeigen"),
+    ("Right-click for more actions", "Rechtsklick für weitere Aktionen"),
+    ("ℹ  File Info", "ℹ  Dateiinfo"),
+    ("📂  Reveal in File Manager", "📂  Im Dateimanager zeigen"),
+    ("⏱  Time Offset", "⏱  Zeitversatz"),
+    ("Shift by", "Verschieben um"),
+    (
+        "Seconds added to every time in this log",
+        "Sekunden, die zu jeder Zeit in diesem Log addiert werden",
+    ),
+    ("Start at 0:00", "Bei 0:00 beginnen"),
+    ("Export as CSV...", "Als CSV exportieren..."),
+    ("Export as Arrow...", "Als Arrow exportieren..."),
+    ("Close", "Schließen"),
+    ("Close Others", "Andere schließen"),
+    // Preferences window
+    ("Preferences", "Einstellungen"),
+    ("General", "Allgemein"),
+    ("Display", "Anzeige"),
+    ("Accessibility", "Barrierefreiheit"),
+    ("Performance", "Leistung"),
+    ("Language:", "Sprache:"),
+    ("Check for updates on startup", "Beim Start nach Updates suchen"),
+    (
+        "Automatically check for new versions when the app starts",
+        "Beim Programmstart automatisch nach neuen Versionen suchen",
+    ),
+    ("Update channel:", "Update-Kanal:"),
+    ("Stable", "Stabil"),
+    (
+        "Beta also offers prereleases, which may be less stable",
+        "Beta bietet auch Vorabversionen an, die weniger stabil sein können",
+    ),
+    ("Update proxy:", "Update-Proxy:"),
+    (
+        "Proxy for update checks and downloads. Leave blank to use the HTTPS_PROXY environment variable, if set.",
+        "Proxy für Update-Prüfungen und Downloads. Leer lassen, um die Umgebungsvariable HTTPS_PROXY zu verwenden, falls gesetzt.",
+    ),
+    ("Skipped versions:", "Übersprungene Versionen:"),
+    ("Clear", "Leeren"),
+    (
+        "Portable mode: data is kept next to UltraLog",
+        "Portabler Modus: Daten liegen neben UltraLog",
+    ),
+    ("Data folder", "Datenordner"),
+    ("Open", "Öffnen"),
+    ("Cache parsed logs", "Eingelesene Logs zwischenspeichern"),
+    (
+        "Keep a copy of parsed CSV logs on disk so they reopen in moments",
+        "Eingelesene CSV-Logs auf der Festplatte zwischenspeichern, damit sie sich sofort wieder öffnen",
+    ),
+    ("Clear Log Cache", "Log-Cache leeren"),
+    ("Theme:", "Design:"),
+    ("Dark", "Dunkel"),
+    ("Light", "Hell"),
+    ("UI scale:", "UI-Skalierung:"),
+    ("Reset", "Zurücksetzen"),
+    ("Initial chart window:", "Anfängliches Diagrammfenster:"),
+    (
+        "Seconds shown when a log's chart is first drawn",
+        "Sekunden, die beim ersten Zeichnen eines Diagramms sichtbar sind",
+    ),
+    (
+        "UI scale is applied on top of each monitor's own scaling. Ctrl +/- also zoom.",
+        "Die UI-Skalierung wirkt zusätzlich zur Skalierung jedes Monitors. Strg +/- zoomt ebenfalls.",
+    ),
+    ("Colorblind-friendly palette", "Farbenblind-freundliche Palette"),
+    ("High contrast", "Hoher Kontrast"),
+    (
+        "Stronger text and outlines, with a thick outline around the focused control",
+        "Kräftigere Schrift und Umrisse, mit dickem Rahmen um das fokussierte Element",
+    ),
+    ("Line thickness:", "Linienstärke:"),
+    (
+        "Scales every chart line and the cursor",
+        "Skaliert alle Diagrammlinien und den Cursor",
+    ),
+    (
+        "Tab and Shift+Tab move between controls, and Space or Enter activates them. In the channel list, the arrow keys move between channels.",
+        "Tab und Umschalt+Tab wechseln zwischen Elementen, Leertaste oder Eingabe betätigen sie. In der Kanalliste wechseln die Pfeiltasten zwischen Kanälen.",
+    ),
+    (
+        "Default units, used by every tab without its own override",
+        "Standardeinheiten für alle Tabs ohne eigene Einheiten",
+    ),
+    ("Chart points per channel:", "Diagrammpunkte pro Kanal:"),
+    (
+        "More points show finer detail but draw slower",
+        "Mehr Punkte zeigen feinere Details, zeichnen aber langsamer",
+    ),
+    ("Channel limit:", "Kanallimit:"),
+    (
+        "Maximum channels per tab; more channels make large logs slower to draw",
+        "Höchstzahl der Kanäle pro Tab; mehr Kanäle verlangsamen das Zeichnen großer Logs",
+    ),
+    ("Memory-map MLG files over:", "MLG-Dateien per Memory-Mapping ab:"),
+    (
+        "Larger files are decoded on demand, so they open quickly and use less memory",
+        "Größere Dateien werden bei Bedarf dekodiert, öffnen schnell und brauchen weniger Speicher",
+    ),
+    ("Decimate logs faster than:", "Logs dezimieren schneller als:"),
+    (
+        "Keep the lowest and highest value of each channel per window, so very dense logs use less memory while spikes stay visible",
+        "Pro Fenster den niedrigsten und höchsten Wert jedes Kanals behalten, damit sehr dichte Logs weniger Speicher brauchen und Spitzen sichtbar bleiben",
+    ),
+    (
+        "Applies to logs opened from now on",
+        "Gilt für ab jetzt geöffnete Logs",
+    ),
+    ("Chart cache limit:", "Diagramm-Cache-Limit:"),
+    (
+        "Above this, downsampled data for the channels drawn least recently is dropped and rebuilt when they are shown again",
+        "Darüber werden die reduzierten Daten der am längsten nicht gezeichneten Kanäle verworfen und bei Bedarf neu erstellt",
+    ),
+    ("in use", "belegt"),
+    // Update dialog
+    ("Update Available", "Update verfügbar"),
+    ("A new version is available!", "Eine neue Version ist verfügbar!"),
+    ("Current version:", "Aktuelle Version:"),
+    ("New version:", "Neue Version:"),
+    ("Download size:", "Downloadgröße:"),
+    ("Release Notes", "Versionshinweise"),
+    (
+        "⚠ No checksum was published for this release",
+        "⚠ Für diese Version wurde keine Prüfsumme veröffentlicht",
+    ),
+    ("Download without checksum verification", "Ohne Prüfsummenkontrolle herunterladen"),
+    ("Download & Install", "Herunterladen & installieren"),
+    ("View on GitHub", "Auf GitHub ansehen"),
+    ("Remind Me Later", "Später erinnern"),
+    ("Ask again the next time UltraLog starts", "Beim nächsten Start von UltraLog erneut fragen"),
+    ("Skip This Version", "Diese Version überspringen"),
+    ("Don't offer this version again on startup", "Diese Version beim Start nicht mehr anbieten"),
+    ("Downloading update...", "Update wird heruntergeladen..."),
+    ("of", "von"),
+    ("Cancel", "Abbrechen"),
+    ("Download complete!", "Download abgeschlossen!"),
+    ("✔ SHA-256 checksum verified", "✔ SHA-256-Prüfsumme bestätigt"),
+    (
+        "Open the download folder to install the update by hand.",
+        "Öffnen Sie den Download-Ordner, um das Update von Hand zu installieren.",
+    ),
+    (
+        "Click Install to replace UltraLog with the new version and restart it.",
+        "Klicken Sie auf Installieren, um UltraLog durch die neue Version zu ersetzen und neu zu starten.",
+    ),
+    (
+        "Click Install to open the update file.",
+        "Klicken Sie auf Installieren, um die Update-Datei zu öffnen.",
+    ),
+    (
+        "Open the DMG and drag the app to Applications.",
+        "Öffnen Sie das DMG und ziehen Sie die App in den Ordner Programme.",
+    ),
+    (
+        "Extract the archive and replace the binary.",
+        "Entpacken Sie das Archiv und ersetzen Sie die Programmdatei.",
+    ),
+    ("Open Folder", "Ordner öffnen"),
+    ("Install and Restart", "Installieren und neu starten"),
+    ("Open ZIP", "ZIP öffnen"),
+    ("Install the update by hand instead", "Das Update stattdessen von Hand installieren"),
+    ("Install Now", "Jetzt installieren"),
+    (
+        "Update file opened. Follow the installer instructions.",
+        "Update-Datei geöffnet. Folgen Sie den Anweisungen des Installers.",
+    ),
+    ("Install Later", "Später installieren"),
+    ("Update saved to your temp folder.", "Update im temporären Ordner gespeichert."),
+    ("Update Error", "Update-Fehler"),
+    // Field normalization editor
+    ("Field Normalization Editor", "Editor für Feldnormalisierung"),
+    ("Field Name Mappings", "Feldnamen-Zuordnungen"),
+    ("Export...", "Exportieren..."),
+    ("Save your custom mappings as JSON or CSV", "Eigene Zuordnungen als JSON oder CSV speichern"),
+    ("Import...", "Importieren..."),
+    (
+        "Add mappings from a JSON or CSV file",
+        "Zuordnungen aus einer JSON- oder CSV-Datei hinzufügen",
+    ),
+    ("Reset to Defaults", "Auf Standard zurücksetzen"),
+    ("Extend Built-in Mappings", "Eingebaute Zuordnungen erweitern"),
+    (
+        "Add your own source names that map to existing normalized field names.",
+        "Eigene Quellnamen hinzufügen, die vorhandenen normalisierten Feldnamen zugeordnet werden.",
+    ),
+    ("Source Name:", "Quellname:"),
+    ("e.g., MyCustomRPM", "z. B. MeineDrehzahl"),
+    ("Maps To:", "Zuordnen zu:"),
+    ("Select...", "Auswählen..."),
+    ("Add", "Hinzufügen"),
+    ("Create New Mapping", "Neue Zuordnung anlegen"),
+    (
+        "Define completely new normalized names for your custom channels.",
+        "Völlig neue normalisierte Namen für eigene Kanäle festlegen.",
+    ),
+    (
+        "Use * to match a family (EGT Cyl * → EGT {n}) or re: for a regular expression ({1}, {2} insert its groups).",
+        "Mit * eine Familie erfassen (EGT Cyl * → EGT {n}) oder mit re: einen regulären Ausdruck ({1}, {2} fügen seine Gruppen ein).",
+    ),
+    ("e.g., Sensor_XYZ", "z. B. Sensor_XYZ"),
+    ("Display As:", "Anzeigen als:"),
+    ("e.g., Custom Sensor", "z. B. Eigener Sensor"),
+    ("Your Custom Mappings", "Eigene Zuordnungen"),
+    ("Source", "Quelle"),
+    ("Display As", "Anzeigen als"),
+    ("Remove", "Entfernen"),
+    ("Built-in Mappings Reference", "Übersicht der eingebauten Zuordnungen"),
+    (
+        "These are the default mappings. You can extend them by adding new source names above.",
+        "Dies sind die Standardzuordnungen. Sie lassen sich oben um neue Quellnamen erweitern.",
+    ),
+    ("Invalid pattern:", "Ungültiges Muster:"),
+    ("Mappings", "Zuordnungen"),
+    ("Mappings imported:", "Zuordnungen importiert:"),
+    ("Import failed:", "Import fehlgeschlagen:"),
+    ("Mappings exported", "Zuordnungen exportiert"),
+    ("Export failed:", "Export fehlgeschlagen:"),
+    // Alarm rules window
+    ("Alarm Rules", "Alarmregeln"),
+    ("Rules", "Regeln"),
+    ("➕ Add Rule", "➕ Regel hinzufügen"),
+    ("Rule", "Regel"),
+    (
+        "Thresholds are in your selected display units. Channel names match either the original or normalized name.",
+        "Schwellwerte gelten in den gewählten Anzeigeeinheiten. Kanalnamen passen zum Original- oder zum normalisierten Namen.",
+    ),
+    ("Rule name", "Regelname"),
+    ("When", "Wenn"),
+    ("While", "Solange"),
+    ("And", "Und"),
+    ("+ While condition", "+ Solange-Bedingung"),
+    ("No rules defined yet.", "Noch keine Regeln definiert."),
+    ("Violations", "Verstöße"),
+    ("Open a log file to evaluate rules.", "Öffnen Sie eine Logdatei, um die Regeln auszuwerten."),
+    ("Channel", "Kanal"),
+    ("No violations found.", "Keine Verstöße gefunden."),
+    ("violation(s)", "Verstoß/Verstöße"),
+    ("Start", "Beginn"),
+    ("Duration", "Dauer"),
+    ("Peak", "Spitze"),
+    ("Jump", "Springen"),
+    // Find value window
+    ("Find Value", "Wert suchen"),
+    ("Open a log file to search it.", "Öffnen Sie eine Logdatei, um sie zu durchsuchen."),
+    (
+        "Find where a channel meets a condition. Values are in your selected display units.",
+        "Stellen finden, an denen ein Kanal eine Bedingung erfüllt. Werte gelten in den gewählten Anzeigeeinheiten.",
+    ),
+    ("Find", "Suche"),
+    ("◀ Previous", "◀ Zurück"),
+    ("Next ▶", "Weiter ▶"),
+    ("No matches", "Keine Treffer"),
+    ("Match", "Treffer"),
+    ("match", "Treffer"),
+    ("matches", "Treffer"),
+    // WOT pulls window
+    ("WOT Pulls", "Volllastzüge"),
+    ("Throttle ≥", "Drosselklappe ≥"),
+    ("for at least", "für mindestens"),
+    ("gaining at least", "mit einem Anstieg von mindestens"),
+    (
+        "A pull ends at its peak RPM; shifting during a WOT run starts a new pull.",
+        "Ein Zug endet bei seiner Höchstdrehzahl; ein Schaltvorgang unter Volllast beginnt einen neuen Zug.",
+    ),
+    ("Open a log file to detect pulls.", "Öffnen Sie eine Logdatei, um Züge zu erkennen."),
+    ("Compare Pulls", "Züge vergleichen"),
+    ("Channel:", "Kanal:"),
+    ("Channel name", "Kanalname"),
+    (
+        "Tick Compare on pulls (from any open log) to overlay them against RPM.",
+        "Aktivieren Sie Vergleichen bei Zügen (aus jedem geöffneten Log), um sie über der Drehzahl zu überlagern.",
+    ),
+    ("Remove from comparison", "Aus dem Vergleich entfernen"),
+    ("No pulls found.", "Keine Züge gefunden."),
+    ("pull(s)", "Zug/Züge"),
+    ("Peak Boost", "Max. Ladedruck"),
+    ("Peak Speed", "Höchstgeschwindigkeit"),
+    ("Compare", "Vergleichen"),
+    ("Overlay this pull in the comparison plot", "Diesen Zug im Vergleichsdiagramm überlagern"),
+    ("Zoom", "Zoomen"),
+    ("Show this pull in the Log Viewer", "Diesen Zug in der Log-Ansicht zeigen"),
+    ("Export", "Exportieren"),
+    ("Save this pull's records as CSV", "Die Datensätze dieses Zugs als CSV speichern"),
+    ("Pull exported:", "Zug exportiert:"),
+    // Boost control window
+    ("Boost Control", "Ladedruckregelung"),
+    (
+        "Boost counts as settled once manifold pressure stays within",
+        "Der Ladedruck gilt als eingeschwungen, sobald der Saugrohrdruck innerhalb von",
+    ),
+    (
+        "of the target for the rest of the pull. Pulls use the WOT Pulls window's thresholds.",
+        "des Sollwerts bis zum Ende des Zugs bleibt. Züge verwenden die Schwellwerte des Fensters Volllastzüge.",
+    ),
+    (
+        "Open a log file to analyze boost control.",
+        "Öffnen Sie eine Logdatei, um die Ladedruckregelung zu analysieren.",
+    ),
+    ("Gear", "Gang"),
+    ("Peak Target", "Max. Sollwert"),
+    ("Overshoot", "Überschwingen"),
+    ("Settling", "Einschwingzeit"),
+    ("Steady Error", "Bleibende Abweichung"),
+    ("Steady Duty", "Bleibendes Tastverhältnis"),
+    ("Never", "Nie"),
+    // Fuel trims window
+    ("Fuel Trims", "Gemischkorrekturen"),
+    ("Flag cells averaging more than ±", "Zellen markieren mit durchschnittlich mehr als ±"),
+    ("over at least", "über mindestens"),
+    ("samples", "Messwerte"),
+    ("Cell size:", "Zellgröße:"),
+    (
+        "Open a log file to summarize its fuel trims.",
+        "Öffnen Sie eine Logdatei, um ihre Gemischkorrekturen auszuwerten.",
+    ),
+    ("Correction from", "Korrektur aus"),
+    (
+        "Red cells: the ECU added fuel (VE too low). Blue cells: it removed fuel (VE too high).",
+        "Rote Zellen: Das Steuergerät hat Kraftstoff hinzugefügt (VE zu niedrig). Blaue Zellen: Es hat Kraftstoff reduziert (VE zu hoch).",
+    ),
+    ("📋 Copy as CSV", "📋 Als CSV kopieren"),
+    (
+        "Copy the average, min, max and hit tables",
+        "Tabellen für Mittelwert, Minimum, Maximum und Treffer kopieren",
+    ),
+    ("Cells Needing Work", "Zellen mit Handlungsbedarf"),
+    ("No cells are past the threshold.", "Keine Zelle überschreitet den Schwellwert."),
+    ("adding fuel, raise VE", "fügt Kraftstoff hinzu, VE erhöhen"),
+    ("removing fuel, lower VE", "reduziert Kraftstoff, VE senken"),
+    ("at", "bei"),
+    ("to", "bis"),
+    (
+        "Fuel trim tables copied to clipboard",
+        "Gemischkorrektur-Tabellen in die Zwischenablage kopiert",
+    ),
+    // Misfire detector window
+    ("Misfire Detector", "Zündaussetzer-Erkennung"),
+    ("RPM falling faster than", "Drehzahl fällt schneller als"),
+    ("above", "über"),
+    ("with throttle above", "bei Drosselklappe über"),
+    ("and recovering within", "und erholt sich innerhalb von"),
+    (
+        "Dips RPM doesn't recover from, like shifts and lifts, are left out. Knock and lambda values are taken at the bottom of each dip.",
+        "Einbrüche ohne Erholung der Drehzahl, etwa Schaltvorgänge und Gaswegnahme, werden ausgelassen. Klopf- und Lambdawerte stammen vom Tiefpunkt jedes Einbruchs.",
+    ),
+    (
+        "Open a log file to look for misfires.",
+        "Öffnen Sie eine Logdatei, um nach Zündaussetzern zu suchen.",
+    ),
+    ("No misfires found.", "Keine Zündaussetzer gefunden."),
+    ("candidate misfire", "möglicher Zündaussetzer"),
+    ("candidate misfires", "mögliche Zündaussetzer"),
+    ("Time", "Zeit"),
+    ("Drop", "Abfall"),
+    ("Drop Rate", "Abfallrate"),
+    ("Move the cursor to this event", "Den Cursor zu diesem Ereignis bewegen"),
+    // EGT spread window
+    ("EGT Spread", "AGT-Streuung"),
+    ("Flag spread above", "Streuung markieren über"),
+    ("lasting at least", "mit einer Dauer von mindestens"),
+    (
+        "Open a log file to check its EGT spread.",
+        "Öffnen Sie eine Logdatei, um ihre AGT-Streuung zu prüfen.",
+    ),
+    (
+        "Spread between the hottest and coldest of:",
+        "Streuung zwischen dem heißesten und kältesten von:",
+    ),
+    ("📈 Plot Spread", "📈 Streuung anzeigen"),
+    ("Add the EGT Spread channel to the chart", "Den Kanal EGT Spread zum Diagramm hinzufügen"),
+    ("No imbalances found.", "Keine Ungleichgewichte gefunden."),
+    ("Peak Spread", "Max. Streuung"),
+    ("Hottest", "Heißester"),
+    ("Coldest", "Kältester"),
+    ("Show this imbalance in the Log Viewer", "Dieses Ungleichgewicht in der Log-Ansicht zeigen"),
+    // Idle quality window
+    ("Idle Quality", "Leerlaufqualität"),
+    ("Idling is throttle below", "Leerlauf heißt Drosselklappe unter"),
+    ("and RPM below", "und Drehzahl unter"),
+    ("for", "für"),
+    ("Stalled below", "Abgewürgt unter"),
+    (
+        "Open a log file to analyze its idle.",
+        "Öffnen Sie eine Logdatei, um ihren Leerlauf zu analysieren.",
+    ),
+    ("Time idling:", "Zeit im Leerlauf:"),
+    ("over", "in"),
+    ("period", "Abschnitt"),
+    ("periods", "Abschnitten"),
+    ("Idle RPM:", "Leerlaufdrehzahl:"),
+    ("average,", "Mittelwert,"),
+    ("standard deviation", "Standardabweichung"),
+    ("Idle valve:", "Leerlaufsteller:"),
+    ("Stalls:", "Motorstillstände:"),
+    ("None", "Keine"),
+    (
+        "The idle is steady with a standard deviation up to",
+        "Der Leerlauf ist stabil bei einer Standardabweichung bis",
+    ),
+    ("It is hunting above", "Er pendelt oberhalb von"),
+    (
+        "Stalls include switching the engine off.",
+        "Motorstillstände schließen das Abstellen des Motors ein.",
+    ),
+    ("Idle Periods", "Leerlaufabschnitte"),
+    ("No idle periods found.", "Keine Leerlaufabschnitte gefunden."),
+    ("Mean RPM", "Mittlere Drehzahl"),
+    ("Std Dev", "Std.-Abw."),
+    ("Range", "Bereich"),
+    ("Target Error", "Sollabweichung"),
+    ("Idle Valve", "Leerlaufsteller"),
+    ("Show this period in the Log Viewer", "Diesen Abschnitt in der Log-Ansicht zeigen"),
+    ("Stalls", "Motorstillstände"),
+    ("from", "ab"),
+    ("Move the cursor to this stall", "Den Cursor zu diesem Motorstillstand bewegen"),
+    // Drag runs window
+    ("Drag Runs", "Beschleunigungsläufe"),
+    (
+        "Open a log file to find its drag runs.",
+        "Öffnen Sie eine Logdatei, um ihre Beschleunigungsläufe zu finden.",
+    ),
+    ("Timed from", "Gemessen mit"),
+    ("💾 Save CSV...", "💾 CSV speichern..."),
+    (
+        "Times are estimated from vehicle speed, starting at first movement rather than a timing beam.",
+        "Zeiten werden aus der Fahrzeuggeschwindigkeit geschätzt und beginnen bei der ersten Bewegung statt an einer Lichtschranke.",
+    ),
+    (
+        "No runs from a standstill reaching 60 ft found.",
+        "Keine Läufe aus dem Stand gefunden, die 60 ft erreichen.",
+    ),
+    ("Drag runs copied as CSV", "Beschleunigungsläufe als CSV kopiert"),
+    ("Run", "Lauf"),
+    ("launched at", "gestartet bei"),
+    ("Show this run in the Log Viewer", "Diesen Lauf in der Log-Ansicht zeigen"),
+    ("Trap Speed", "Endgeschwindigkeit"),
+    ("Peak speed", "Höchstgeschwindigkeit"),
+    ("Drag runs exported", "Beschleunigungsläufe exportiert"),
+    // Shift points window
+    ("Shift Points", "Schaltpunkte"),
+    ("Ignore shifts below", "Schaltvorgänge ignorieren unter"),
+    ("Show on chart", "Im Diagramm zeigen"),
+    ("Without a gear channel, RPM must drop", "Ohne Gangkanal muss die Drehzahl fallen um"),
+    ("within", "innerhalb von"),
+    (
+        "Open a log file to find its shifts.",
+        "Öffnen Sie eine Logdatei, um ihre Schaltvorgänge zu finden.",
+    ),
+    ("Shifts found from the Gear channel.", "Schaltvorgänge aus dem Gangkanal ermittelt."),
+    (
+        "No Gear channel in this log, so shifts are found from RPM drops.",
+        "Kein Gangkanal in diesem Log, daher werden Schaltvorgänge aus Drehzahlabfällen ermittelt.",
+    ),
+    ("No shifts found.", "Keine Schaltvorgänge gefunden."),
+    ("Per Gear", "Pro Gang"),
+    ("Shifts", "Schaltvorgänge"),
+    ("Shift RPM", "Schaltdrehzahl"),
+    ("RPM Drop", "Drehzahlabfall"),
+    ("All", "Alle"),
+    ("Shift", "Schaltvorgang"),
+    ("Landing RPM", "Anschlussdrehzahl"),
+    ("Move the cursor to this shift", "Den Cursor zu diesem Schaltvorgang bewegen"),
+    // Welcome screen
+    ("Welcome to UltraLog", "Willkommen bei UltraLog"),
+    (
+        "Open an ECU log to chart and analyze it",
+        "Öffnen Sie ein Steuergeräte-Log, um es darzustellen und zu analysieren",
+    ),
+    (
+        "or drop files anywhere on the window",
+        "oder Dateien an beliebiger Stelle ins Fenster ziehen",
+    ),
+    ("No log handy? Try a sample:", "Kein Log zur Hand? Probieren Sie ein Beispiel:"),
+    ("Haltech sample", "Haltech-Beispiel"),
+    ("Speeduino sample", "Speeduino-Beispiel"),
+    ("📖  Read the user guide", "📖  Benutzerhandbuch lesen"),
+    ("Failed to open sample log:", "Beispiel-Log konnte nicht geöffnet werden:"),
+    // Crash dialog
+    ("UltraLog Closed Unexpectedly", "UltraLog wurde unerwartet beendet"),
+    (
+        "UltraLog crashed the last time it ran. Reporting it on GitHub, along with the log file that was open if you can share it, helps get it fixed.",
+        "UltraLog ist beim letzten Start abgestürzt. Ein Bericht auf GitHub, zusammen mit der geöffneten Logdatei, falls Sie sie teilen können, hilft bei der Behebung.",
+    ),
+    ("Report on GitHub", "Auf GitHub melden"),
+    (
+        "Open a GitHub issue prefilled with this report",
+        "Ein GitHub-Issue mit diesem Bericht vorausgefüllt öffnen",
+    ),
+    ("Copy Report", "Bericht kopieren"),
+    ("Dismiss", "Verwerfen"),
+    ("Delete this report", "Diesen Bericht löschen"),
+    ("Crash report copied to clipboard", "Absturzbericht in die Zwischenablage kopiert"),
+    ("Failed to open browser", "Browser konnte nicht geöffnet werden"),
+    // Load error dialog
+    ("Format", "Format"),
+    ("Location", "Position"),
+    ("Failed to Load File", "Datei konnte nicht geladen werden"),
+    ("Copy Diagnostics", "Diagnose kopieren"),
+    (
+        "Copy the details for pasting into a GitHub issue",
+        "Die Details zum Einfügen in ein GitHub-Issue kopieren",
+    ),
+    ("Next", "Weiter"),
+    ("more", "weitere"),
+    ("Diagnostics copied to clipboard", "Diagnose in die Zwischenablage kopiert"),
+    // Log console
+    ("Logs", "Protokoll"),
+    ("Show:", "Anzeigen:"),
+    ("Copy All", "Alles kopieren"),
+    ("Open Log Folder", "Protokollordner öffnen"),
+    // Annotations window
+    ("Annotations", "Anmerkungen"),
+    (
+        "Open a log file to add annotations.",
+        "Öffnen Sie eine Logdatei, um Anmerkungen hinzuzufügen.",
+    ),
+    ("➕ At Cursor", "➕ Am Cursor"),
+    ("Add an annotation starting at the cursor", "Eine Anmerkung ab dem Cursor hinzufügen"),
+    ("➕ Visible Range", "➕ Sichtbarer Bereich"),
+    (
+        "Add an annotation covering the visible chart range",
+        "Eine Anmerkung über den sichtbaren Diagrammbereich hinzufügen",
+    ),
+    ("Annotation", "Anmerkung"),
+    ("No annotations on this tab.", "Keine Anmerkungen auf diesem Tab."),
+    ("Label", "Bezeichnung"),
+    ("Start (s)", "Start (s)"),
+    ("End (s)", "Ende (s)"),
+    // Live data window
+    ("Live Data", "Live-Daten"),
+    ("Connection:", "Verbindung:"),
+    ("Serial", "Seriell"),
+    ("Port:", "Port:"),
+    ("/dev/ttyACM0 or COM3", "/dev/ttyACM0 oder COM3"),
+    ("Baud rate:", "Baudrate:"),
+    ("Host:", "Host:"),
+    ("INI file:", "INI-Datei:"),
+    ("None selected", "Keine ausgewählt"),
+    ("Browse...", "Durchsuchen..."),
+    ("Poll interval:", "Abfrageintervall:"),
+    ("⏹ Disconnect", "⏹ Trennen"),
+    ("Waiting for data...", "Warte auf Daten..."),
+    ("Streaming —", "Empfange —"),
+    ("▶ Connect", "▶ Verbinden"),
+    ("Not connected", "Nicht verbunden"),
+    // Merge logs window
+    ("Merge Logs", "Logs zusammenführen"),
+    (
+        "Open two logs recorded at the same time to merge them.",
+        "Öffnen Sie zwei gleichzeitig aufgezeichnete Logs, um sie zusammenzuführen.",
+    ),
+    ("Time base:", "Zeitbasis:"),
+    ("Add channels from:", "Kanäle hinzufügen aus:"),
+    (
+        "The second log's channels are interpolated onto the first log's",
+        "Die Kanäle des zweiten Logs werden interpoliert auf die",
+    ),
+    (
+        "records. Times are matched as shown, so give one log a time offset first if they started at different moments.",
+        "Datensätze des ersten Logs. Zeiten werden wie angezeigt abgeglichen; geben Sie einem Log zuerst einen Zeitversatz, falls sie zu unterschiedlichen Zeitpunkten begonnen haben.",
+    ),
+    ("⚠ Choose two different logs", "⚠ Wählen Sie zwei verschiedene Logs"),
+    ("⚠ The logs' times don't overlap", "⚠ Die Zeiten der Logs überschneiden sich nicht"),
+    ("Overlap:", "Überschneidung:"),
+    ("🔗 Merge", "🔗 Zusammenführen"),
+    // Unsupported file dialog
+    ("Unsupported File", "Nicht unterstützte Datei"),
+    ("UltraLog couldn't recognise", "UltraLog konnte nicht erkennen:"),
+    ("Preview", "Vorschau"),
+    ("first", "erste"),
+    ("bytes", "Bytes"),
+    ("Text", "Text"),
+    ("Load as delimited text", "Als Text mit Trennzeichen laden"),
+    (
+        "Pick the delimiter, header line and time column. Every other column becomes a channel.",
+        "Wählen Sie Trennzeichen, Kopfzeile und Zeitspalte. Jede andere Spalte wird ein Kanal.",
+    ),
+    ("Delimiter:", "Trennzeichen:"),
+    ("Comma", "Komma"),
+    ("Semicolon", "Semikolon"),
+    ("Tab", "Tabulator"),
+    ("Pipe", "Senkrechter Strich"),
+    ("Space", "Leerzeichen"),
+    ("Header line:", "Kopfzeile:"),
+    ("Time column:", "Zeitspalte:"),
+    ("columns detected", "Spalten erkannt"),
+    ("Load as CSV", "Als CSV laden"),
+    ("Request Format Support", "Formatunterstützung anfragen"),
+    (
+        "Open a GitHub issue pre-filled with the file's header",
+        "Ein GitHub-Issue mit dem Dateikopf vorausgefüllt öffnen",
+    ),
+    ("Copy Header", "Kopf kopieren"),
+    ("Header copied to clipboard", "Kopf in die Zwischenablage kopiert"),
+    // Report window
+    ("HTML Page", "HTML-Seite"),
+    ("PDF Document", "PDF-Dokument"),
+    ("Report saved", "Bericht gespeichert"),
+    ("Generate Report", "Bericht erstellen"),
+    (
+        "Open a log file to report on it.",
+        "Öffnen Sie eine Logdatei, um einen Bericht zu erstellen.",
+    ),
+    ("Title:", "Titel:"),
+    ("Include", "Enthalten"),
+    ("WOT pulls", "Volllastzüge"),
+    ("AFR safety over each pull", "AFR-Sicherheit je Volllastzug"),
+    ("Boost control over each pull", "Ladedruckregelung je Volllastzug"),
+    ("Statistics of the charted channels", "Statistik der dargestellten Kanäle"),
+    (
+        "Sections use the thresholds from their windows and your display units.",
+        "Abschnitte verwenden die Schwellwerte aus ihren Fenstern und Ihre Anzeigeeinheiten.",
+    ),
+    ("🌐 Save as HTML...", "🌐 Als HTML speichern..."),
+    ("📄 Save as PDF...", "📄 Als PDF speichern..."),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    /// Read the string literal at the start of `source`, resolving escapes
+    fn string_literal(source: &str) -> Option<String> {
+        let mut chars = source.strip_prefix('"')?.chars();
+        let mut text = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => return Some(text),
+                '\\' => match chars.next()? {
+                    'n' => text.push('\n'),
+                    't' => text.push('\t'),
+                    // A line continuation skips the newline and indentation
+                    '\n' => {
+                        let rest = chars.as_str().trim_start();
+                        chars = rest.chars();
+                    }
+                    escaped => text.push(escaped),
+                },
+                c => text.push(c),
+            }
+        }
+        None
+    }
+
+    /// Literals passed to `tr(...)` in the Rust sources under `dir`
+    fn tr_literals(dir: &Path, found: &mut Vec<(String, String)>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                tr_literals(&path, found);
+                continue;
+            }
+            // This module's own tests use untranslated strings on purpose
+            if path.extension().is_none_or(|ext| ext != "rs") || path.ends_with("i18n.rs") {
+                continue;
+            }
+            let source = std::fs::read_to_string(&path).unwrap();
+            for (start, _) in source.match_indices(".tr(") {
+                let argument = source[start + 4..].trim_start();
+                if let Some(text) = string_literal(argument) {
+                    found.push((path.display().to_string(), text));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_translate() {
+        assert_eq!(Language::German.tr("File"), "Datei");
+        assert_eq!(Language::English.tr("File"), "File");
+        // Strings without a translation fall back to English
+        assert_eq!(Language::German.tr("RPM"), "RPM");
+    }
+
+    #[test]
+    fn test_every_tr_literal_has_german_entry() {
+        let mut literals = Vec::new();
+        tr_literals(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+            &mut literals,
+        );
+        assert!(
+            literals.len() > 100,
+            "found only {} tr() literals",
+            literals.len()
+        );
+
+        let missing: Vec<String> = literals
+            .iter()
+            .filter(|(_, text)| !GERMAN.contains_key(text.as_str()))
+            .map(|(file, text)| format!("{}: {:?}", file, text))
+            .collect();
+        assert!(
+            missing.is_empty(),
+            "tr() strings without a German entry:\n{}",
+            missing.join("\n")
+        );
+    }
+
+    #[test]
+    fn test_string_literal() {
+        assert_eq!(
+            string_literal(r#""Say \"hi\"", x"#),
+            Some("Say \"hi\"".to_string())
+        );
+        assert_eq!(
+            string_literal("\"one \\\n         two\")"),
+            Some("one two".to_string())
+        );
+        assert_eq!(string_literal("name)"), None);
+    }
+
+    #[test]
+    fn test_tables_have_unique_entries() {
+        assert_eq!(GERMAN.len(), GERMAN_STRINGS.len());
+        assert!(GERMAN_STRINGS
+            .iter()
+            .all(|(english, german)| !english.is_empty() && !german.is_empty()));
+    }
+}
@@ -0,0 +1,247 @@
+//! Idle quality analysis.
+//!
+//! Finds the stretches of a log where the engine is idling (throttle closed,
+//! RPM low but running) and summarizes how steady it was: RPM spread, the
+//! idle valve's position or duty, and how far RPM sat from the idle target.
+//! RPM falling from running to below the stall threshold is reported as a
+//! stall, which includes switching the engine off.
+
+use serde::{Deserialize, Serialize};
+
+/// Thresholds a stretch of the log must meet to count as idling
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IdleConfig {
+    /// Highest throttle position (%) at idle
+    pub max_tps: f64,
+    /// Highest RPM at idle
+    pub max_rpm: f64,
+    /// Minimum length of an idle period (seconds)
+    pub min_duration: f64,
+    /// RPM below which the engine counts as stalled
+    pub stall_rpm: f64,
+}
+
+impl Default for IdleConfig {
+    fn default() -> Self {
+        Self {
+            max_tps: 2.0,
+            max_rpm: 1400.0,
+            min_duration: 3.0,
+            stall_rpm: 300.0,
+        }
+    }
+}
+
+/// Mean, spread and range of a channel's values
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChannelStats {
+    pub mean: f64,
+    /// Population standard deviation
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl ChannelStats {
+    /// Statistics of the finite values, or `None` if there are none
+    pub fn of(values: impl IntoIterator<Item = f64>) -> Option<Self> {
+        let values: Vec<f64> = values.into_iter().filter(|v| v.is_finite()).collect();
+        if values.is_empty() {
+            return None;
+        }
+        let count = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / count;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / count;
+        Some(Self {
+            mean,
+            std_dev: variance.sqrt(),
+            min: values.iter().copied().fold(f64::INFINITY, f64::min),
+            max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        })
+    }
+}
+
+/// A stretch of the log spent idling
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdlePeriod {
+    /// Index of the first record of the period
+    pub start_record: usize,
+    /// Index of the last record of the period
+    pub end_record: usize,
+    /// Time of the first record (seconds)
+    pub start_time: f64,
+    /// Time of the last record (seconds)
+    pub end_time: f64,
+    /// RPM over the period
+    pub rpm: ChannelStats,
+    /// Idle valve position or duty over the period, if the log has it
+    pub valve: Option<ChannelStats>,
+    /// Average of RPM minus the idle target, if the log has a target
+    pub target_error: Option<f64>,
+}
+
+impl IdlePeriod {
+    /// Duration of the period in seconds
+    pub fn duration(&self) -> f64 {
+        self.end_time - self.start_time
+    }
+}
+
+/// RPM falling from running to below the stall threshold
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stall {
+    /// Index of the first record below the stall threshold
+    pub record: usize,
+    /// Time of that record (seconds)
+    pub time: f64,
+    /// RPM of the record before
+    pub rpm_before: f64,
+}
+
+/// Idle periods and stalls of a log
+#[derive(Clone, Debug, Default)]
+pub struct IdleReport {
+    /// Idle periods in time order
+    pub periods: Vec<IdlePeriod>,
+    /// Stalls in time order
+    pub stalls: Vec<Stall>,
+    /// RPM over every idle period together
+    pub rpm: Option<ChannelStats>,
+    /// Idle valve over every idle period together
+    pub valve: Option<ChannelStats>,
+}
+
+impl IdleReport {
+    /// Total time spent idling (seconds)
+    pub fn idle_time(&self) -> f64 {
+        self.periods.iter().map(IdlePeriod::duration).sum()
+    }
+}
+
+impl IdleConfig {
+    /// Find idle periods and stalls. `valve` is the idle valve position or
+    /// duty and `target` the idle RPM target, if the log has them.
+    pub fn analyze(
+        &self,
+        times: &[f64],
+        rpm: &[f64],
+        tps: &[f64],
+        valve: Option<&[f64]>,
+        target: Option<&[f64]>,
+    ) -> IdleReport {
+        let len = times.len().min(rpm.len()).min(tps.len());
+        let idling =
+            |i: usize| tps[i] <= self.max_tps && rpm[i] >= self.stall_rpm && rpm[i] <= self.max_rpm;
+        let slice = |data: &[f64], start: usize, end: usize| -> Vec<f64> {
+            data.get(start..=end).unwrap_or_default().to_vec()
+        };
+
+        let mut report = IdleReport::default();
+        let mut start: Option<usize> = None;
+        for i in 0..=len {
+            match (start, i < len && idling(i)) {
+                (None, true) => start = Some(i),
+                (Some(first), false) => {
+                    start = None;
+                    let last = i - 1;
+                    if times[last] - times[first] < self.min_duration {
+                        continue;
+                    }
+                    let Some(rpm_stats) = ChannelStats::of(slice(rpm, first, last)) else {
+                        continue;
+                    };
+                    let target_error = target.and_then(|target| {
+                        let errors = slice(rpm, first, last)
+                            .into_iter()
+                            .zip(slice(target, first, last))
+                            .map(|(r, t)| r - t);
+                        ChannelStats::of(errors).map(|s| s.mean)
+                    });
+                    report.periods.push(IdlePeriod {
+                        start_record: first,
+                        end_record: last,
+                        start_time: times[first],
+                        end_time: times[last],
+                        rpm: rpm_stats,
+                        valve: valve.and_then(|v| ChannelStats::of(slice(v, first, last))),
+                        target_error,
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        report.stalls = (1..len)
+            .filter(|&i| rpm[i - 1] >= self.stall_rpm && rpm[i] < self.stall_rpm)
+            .map(|i| Stall {
+                record: i,
+                time: times[i],
+                rpm_before: rpm[i - 1],
+            })
+            .collect();
+
+        let idle_values = |data: &[f64]| -> Vec<f64> {
+            report
+                .periods
+                .iter()
+                .flat_map(|p| slice(data, p.start_record, p.end_record))
+                .collect()
+        };
+        let rpm_stats = ChannelStats::of(idle_values(rpm));
+        let valve_stats = valve.and_then(|v| ChannelStats::of(idle_values(v)));
+        report.rpm = rpm_stats;
+        report.valve = valve_stats;
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_stats() {
+        let stats = ChannelStats::of([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0, f64::NAN]).unwrap();
+        assert_eq!(stats.mean, 5.0);
+        assert_eq!(stats.std_dev, 2.0);
+        assert_eq!((stats.min, stats.max), (2.0, 9.0));
+        assert!(ChannelStats::of([f64::NAN]).is_none());
+    }
+
+    #[test]
+    fn test_idle_periods_and_stalls() {
+        let config = IdleConfig {
+            min_duration: 1.0,
+            ..Default::default()
+        };
+        let times: Vec<f64> = (0..12).map(|i| i as f64 * 0.5).collect();
+        // Idle, a blip of throttle, idle again, then a stall
+        let rpm = [
+            850.0, 900.0, 800.0, 850.0, 2500.0, 900.0, 880.0, 860.0, 840.0, 600.0, 0.0, 0.0,
+        ];
+        let tps = [0.0, 0.0, 0.0, 0.0, 30.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+        let valve = [30.0; 12];
+        let target = [850.0; 12];
+
+        let report = config.analyze(&times, &rpm, &tps, Some(&valve), Some(&target));
+        assert_eq!(report.periods.len(), 2);
+        let first = &report.periods[0];
+        assert_eq!((first.start_record, first.end_record), (0, 3));
+        assert_eq!(first.rpm.mean, 850.0);
+        assert_eq!(first.target_error, Some(0.0));
+        assert_eq!(first.valve.map(|v| v.mean), Some(30.0));
+        assert_eq!(report.periods[1].start_record, 5);
+        assert_eq!(report.periods[1].end_record, 9);
+        assert!((report.idle_time() - 3.5).abs() < 1e-9);
+
+        assert_eq!(
+            report.stalls,
+            vec![Stall {
+                record: 10,
+                time: 5.0,
+                rpm_before: 600.0,
+            }]
+        );
+    }
+}
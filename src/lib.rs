@@ -5,9 +5,37 @@
 //!
 //! ## Module Structure
 //!
+//! - [`alarms`] - Threshold alarm rules engine
 //! - [`app`] - Main application state and eframe::App implementation
+//! - [`arrow_ipc`] - Apache Arrow IPC (Feather) export
+//! - [`boost`] - Boost control diagnostics over WOT pulls
+//! - [`binning`] - 2D binning and cell statistics for scatter plots
+//! - [`chart_html`] - Standalone interactive HTML export of a chart view
+//! - [`convert`] - Headless log conversion to CSV/JSON
+//! - [`crash`] - Panic hook writing crash reports for the next launch
+//! - [`data_dir`] - App data folder, including portable mode next to the executable
+//! - [`decimate`] - Min/max-preserving decimation of dense logs on import
+//! - [`derived`] - Built-in channels computed from logged channels
+//! - [`diagnostics`] - Log file and in-app log console for `tracing` output
+//! - [`downsample`] - LTTB downsampling pyramid for chart rendering
+//! - [`drag`] - Drag run timing (60 ft to 1/4 mile) from vehicle speed
+//! - [`dyno`] - Virtual dyno power and torque estimation
+//! - [`egt`] - Exhaust gas temperature spread between cylinders
+//! - [`fuel_trim`] - Closed-loop fuel correction binned by RPM and load
+//! - [`fuzzy`] - Fuzzy matching for channel search
+//! - [`i18n`] - Translation of menu and dialog strings
+//! - [`idle`] - Idle period detection, idle stability and stall reporting
+//! - [`live`] - Realtime data acquisition from Speeduino/rusEFI ECUs
+//! - [`log_cache`] - On-disk cache of parsed logs for fast reopening
+//! - [`merge`] - Merging two simultaneously recorded logs onto one time base
+//! - [`misfire`] - Candidate misfire detection from RPM dips under load
 //! - [`parsers`] - ECU log file parsers (Haltech, etc.)
+//! - [`pulls`] - Wide-open-throttle pull detection
+//! - [`report`] - Customer reports combining several analyses, as HTML or PDF
+//! - [`session`] - Session save/load (open files, channels, annotations)
+//! - [`shifts`] - Gear shift detection with shift RPM, RPM drop and duration
 //! - [`state`] - Core data types and constants
+//! - [`state_bands`] - State band rendering helpers for boolean/enum channels
 //! - [`units`] - Unit preference types and conversion utilities
 //! - [`normalize`] - Field name normalization for standardizing channel names
 //! - [`updater`] - Auto-update functionality for checking and downloading updates
@@ -20,10 +48,38 @@
 //!   - `toast` - Toast notification system
 //!   - `icons` - Custom icon drawing utilities
 
+pub mod alarms;
 pub mod app;
+pub mod arrow_ipc;
+pub mod binning;
+pub mod boost;
+pub mod chart_html;
+pub mod convert;
+pub mod crash;
+pub mod data_dir;
+pub mod decimate;
+pub mod derived;
+pub mod diagnostics;
+pub mod downsample;
+pub mod drag;
+pub mod dyno;
+pub mod egt;
+pub mod fuel_trim;
+pub mod fuzzy;
+pub mod i18n;
+pub mod idle;
+pub mod live;
+pub mod log_cache;
+pub mod merge;
+pub mod misfire;
 pub mod normalize;
 pub mod parsers;
+pub mod pulls;
+pub mod report;
+pub mod session;
+pub mod shifts;
 pub mod state;
+pub mod state_bands;
 pub mod ui;
 pub mod units;
 pub mod updater;
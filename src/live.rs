@@ -0,0 +1,671 @@
+//! Realtime data acquisition from Speeduino/rusEFI ECUs.
+//!
+//! Connects over a serial port or TCP (e.g. a WiFi/Ethernet bridge) and polls
+//! the ECU's output channel block using the TunerStudio protocol. The channel
+//! layout and request command come from the ECU's TunerStudio INI file, so any
+//! firmware version is supported as long as its INI is available.
+//!
+//! Samples are streamed from a background thread to the UI over a channel.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Read/write timeout for ECU requests
+const IO_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Baud rates offered for serial connections
+pub const BAUD_RATES: &[u32] = &[9600, 19200, 38400, 57600, 115200, 230400];
+
+/// How to reach the ECU
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LiveTransport {
+    /// USB/serial port
+    #[default]
+    Serial,
+    /// TCP socket (WiFi/Ethernet bridge or simulator)
+    Tcp,
+}
+
+/// Connection settings for live data
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LiveConfig {
+    pub transport: LiveTransport,
+    /// Serial device (e.g. /dev/ttyACM0 or COM3)
+    pub serial_port: String,
+    pub baud_rate: u32,
+    pub host: String,
+    pub port: u16,
+    /// TunerStudio INI file describing the ECU's output channels
+    pub ini_path: Option<PathBuf>,
+    /// Delay between output channel requests (ms)
+    pub poll_interval_ms: u64,
+}
+
+impl Default for LiveConfig {
+    fn default() -> Self {
+        Self {
+            transport: LiveTransport::default(),
+            serial_port: String::new(),
+            baud_rate: 115200,
+            host: "127.0.0.1".to_string(),
+            port: 29001,
+            ini_path: None,
+            poll_interval_ms: 50,
+        }
+    }
+}
+
+impl LiveConfig {
+    /// Short description of the connection target
+    pub fn target(&self) -> String {
+        match self.transport {
+            LiveTransport::Serial => self.serial_port.clone(),
+            LiveTransport::Tcp => format!("{}:{}", self.host, self.port),
+        }
+    }
+}
+
+/// Storage type of an output channel
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ScalarType {
+    U08,
+    S08,
+    U16,
+    S16,
+    U32,
+    S32,
+    F32,
+}
+
+impl ScalarType {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "U08" => Some(Self::U08),
+            "S08" => Some(Self::S08),
+            "U16" => Some(Self::U16),
+            "S16" => Some(Self::S16),
+            "U32" => Some(Self::U32),
+            "S32" => Some(Self::S32),
+            "F32" => Some(Self::F32),
+            _ => None,
+        }
+    }
+
+    fn byte_size(self) -> usize {
+        match self {
+            Self::U08 | Self::S08 => 1,
+            Self::U16 | Self::S16 => 2,
+            Self::U32 | Self::S32 | Self::F32 => 4,
+        }
+    }
+}
+
+/// One scalar output channel from the INI `[OutputChannels]` section
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutputChannel {
+    pub name: String,
+    pub unit: String,
+    kind: ScalarType,
+    offset: usize,
+    pub scale: f64,
+    pub translate: f64,
+}
+
+impl OutputChannel {
+    /// Offset just past the channel's bytes in the output block
+    pub fn end(&self) -> usize {
+        self.offset + self.kind.byte_size()
+    }
+}
+
+/// Output channel block layout and request command parsed from an INI file
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutputLayout {
+    pub channels: Vec<OutputChannel>,
+    /// Size of the output channel block (bytes)
+    pub block_size: usize,
+    /// Largest block the ECU returns per request
+    blocking_factor: usize,
+    /// Raw `ochGetCommand` template
+    command: String,
+    big_endian: bool,
+    /// Whether requests use the CRC32 message envelope
+    framed: bool,
+}
+
+impl OutputLayout {
+    /// Parse the output channel definitions from a TunerStudio INI file.
+    ///
+    /// Only plain scalar channels are used; bit fields, arrays and computed
+    /// channels are skipped. When `#if` branches define a channel twice, the
+    /// first definition wins.
+    pub fn from_ini(ini: &str) -> Result<Self, String> {
+        let mut section = String::new();
+        let mut channels = Vec::new();
+        let mut names = HashSet::new();
+        let mut block_size = None;
+        let mut command = None;
+        let mut blocking_factor = None;
+        let mut big_endian = false;
+        let mut framed = false;
+
+        for raw_line in ini.lines() {
+            let line = strip_comment(raw_line).trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if line.starts_with('[') && line.ends_with(']') {
+                section = line[1..line.len() - 1].trim().to_string();
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let (key, value) = (key.trim(), value.trim());
+
+            match key {
+                "endianness" => big_endian = value.eq_ignore_ascii_case("big"),
+                "messageEnvelopeFormat" => framed = value.starts_with("msEnvelope"),
+                "blockingFactor" if blocking_factor.is_none() => {
+                    blocking_factor = value.parse().ok()
+                }
+                _ => {}
+            }
+
+            if section != "OutputChannels" {
+                continue;
+            }
+            match key {
+                "ochBlockSize" => block_size = value.parse().ok(),
+                "ochGetCommand" => command = Some(unquote(value).to_string()),
+                _ => {
+                    if let Some(channel) = parse_scalar(key, value) {
+                        if names.insert(channel.name.clone()) {
+                            channels.push(channel);
+                        }
+                    }
+                }
+            }
+        }
+
+        let block_size = block_size.ok_or("INI has no ochBlockSize in [OutputChannels]")?;
+        let command = command.ok_or("INI has no ochGetCommand in [OutputChannels]")?;
+        channels.retain(|c| c.end() <= block_size);
+        if channels.is_empty() {
+            return Err("INI defines no scalar output channels".into());
+        }
+
+        Ok(Self {
+            channels,
+            block_size,
+            blocking_factor: blocking_factor.unwrap_or(block_size).max(1),
+            command,
+            big_endian,
+            framed,
+        })
+    }
+
+    /// Build the request for `count` bytes of the output block at `offset`.
+    ///
+    /// Expands the `ochGetCommand` template: `%2o` and `%2c` become the
+    /// offset and count as 16-bit little-endian values, `\xNN` a raw byte and
+    /// `\$tsCanId` CAN ID 0.
+    fn request(&self, offset: usize, count: usize) -> Vec<u8> {
+        let mut out = Vec::new();
+        let template = self.command.as_bytes();
+        let mut i = 0;
+        while i < template.len() {
+            let rest = &template[i..];
+            if rest.starts_with(b"%2o") {
+                out.extend((offset as u16).to_le_bytes());
+                i += 3;
+            } else if rest.starts_with(b"%2c") {
+                out.extend((count as u16).to_le_bytes());
+                i += 3;
+            } else if rest.starts_with(b"\\$tsCanId") {
+                out.push(0);
+                i += 9;
+            } else if let Some(byte) = rest
+                .strip_prefix(b"\\x")
+                .and_then(|hex| hex.get(..2))
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                out.push(byte);
+                i += 4;
+            } else {
+                out.push(template[i]);
+                i += 1;
+            }
+        }
+        out
+    }
+
+    /// Decode every channel from a complete output channel block
+    pub fn decode(&self, block: &[u8]) -> Vec<f64> {
+        self.channels
+            .iter()
+            .map(|c| {
+                let Some(bytes) = block.get(c.offset..c.offset + c.kind.byte_size()) else {
+                    return f64::NAN;
+                };
+                let raw = read_scalar(c.kind, bytes, self.big_endian);
+                // TunerStudio formula: (raw + translate) * scale
+                (raw + c.translate) * c.scale
+            })
+            .collect()
+    }
+}
+
+fn read_scalar(kind: ScalarType, bytes: &[u8], big_endian: bool) -> f64 {
+    let mut buf = [0u8; 4];
+    let n = kind.byte_size();
+    buf[..n].copy_from_slice(bytes);
+    if big_endian {
+        buf[..n].reverse();
+    }
+    match kind {
+        ScalarType::U08 => buf[0] as f64,
+        ScalarType::S08 => buf[0] as i8 as f64,
+        ScalarType::U16 => u16::from_le_bytes([buf[0], buf[1]]) as f64,
+        ScalarType::S16 => i16::from_le_bytes([buf[0], buf[1]]) as f64,
+        ScalarType::U32 => u32::from_le_bytes(buf) as f64,
+        ScalarType::S32 => i32::from_le_bytes(buf) as f64,
+        ScalarType::F32 => f32::from_le_bytes(buf) as f64,
+    }
+}
+
+/// Parse `scalar, U16, 14, "rpm", 1.000, 0.000` into a channel
+fn parse_scalar(name: &str, value: &str) -> Option<OutputChannel> {
+    let parts: Vec<&str> = value.split(',').map(str::trim).collect();
+    if parts.len() < 6 || parts[0] != "scalar" {
+        return None;
+    }
+    Some(OutputChannel {
+        name: name.to_string(),
+        kind: ScalarType::from_name(parts[1])?,
+        offset: parts[2].parse().ok()?,
+        unit: unquote(parts[3]).to_string(),
+        // Expressions like `{ 1/10 }` can't be evaluated here; skip those channels
+        scale: parts[4].parse().ok()?,
+        translate: parts[5].parse().ok()?,
+    })
+}
+
+/// Remove a trailing `;` comment that isn't inside quotes
+fn strip_comment(line: &str) -> &str {
+    let mut in_quotes = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ';' if !in_quotes => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches('"')
+}
+
+/// Wrap a request in the TunerStudio message envelope:
+/// big-endian u16 length, payload, big-endian CRC32 of the payload
+fn frame(payload: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(payload.len() + 6);
+    out.extend((payload.len() as u16).to_be_bytes());
+    out.extend_from_slice(payload);
+    out.extend(crc32fast::hash(payload).to_be_bytes());
+    out
+}
+
+/// Read one enveloped response and return its data (without the status byte)
+fn read_frame(conn: &mut dyn Connection) -> Result<Vec<u8>, String> {
+    let mut len = [0u8; 2];
+    conn.read_exact(&mut len).map_err(io_message)?;
+    let mut payload = vec![0u8; u16::from_be_bytes(len) as usize];
+    conn.read_exact(&mut payload).map_err(io_message)?;
+    let mut crc = [0u8; 4];
+    conn.read_exact(&mut crc).map_err(io_message)?;
+
+    if u32::from_be_bytes(crc) != crc32fast::hash(&payload) {
+        return Err("Response failed CRC check".into());
+    }
+    match payload.split_first() {
+        Some((0, data)) => Ok(data.to_vec()),
+        Some((status, _)) => Err(format!("ECU returned error status 0x{:02X}", status)),
+        None => Err("Empty response from ECU".into()),
+    }
+}
+
+fn io_message(e: io::Error) -> String {
+    match e.kind() {
+        io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock | io::ErrorKind::UnexpectedEof => {
+            "Timed out waiting for the ECU".to_string()
+        }
+        _ => e.to_string(),
+    }
+}
+
+/// Byte stream to the ECU
+trait Connection: Read + Write + Send {}
+impl<T: Read + Write + Send> Connection for T {}
+
+fn connect(config: &LiveConfig) -> Result<Box<dyn Connection>, String> {
+    match config.transport {
+        LiveTransport::Tcp => {
+            let stream = TcpStream::connect((config.host.as_str(), config.port))
+                .map_err(|e| format!("Failed to connect to {}: {}", config.target(), e))?;
+            stream
+                .set_read_timeout(Some(IO_TIMEOUT))
+                .and_then(|_| stream.set_write_timeout(Some(IO_TIMEOUT)))
+                .and_then(|_| stream.set_nodelay(true))
+                .map_err(|e| e.to_string())?;
+            Ok(Box::new(stream))
+        }
+        LiveTransport::Serial => {
+            let file = open_serial(&config.serial_port)
+                .map_err(|e| format!("Failed to open {}: {}", config.serial_port, e))?;
+            configure_serial(&file, config.baud_rate)
+                .map_err(|e| format!("Failed to configure {}: {}", config.serial_port, e))?;
+            Ok(Box::new(file))
+        }
+    }
+}
+
+fn open_serial(port: &str) -> io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.read(true).write(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.custom_flags(libc::O_NOCTTY);
+    }
+    #[cfg(windows)]
+    {
+        // COM ports above 9 need the device namespace prefix
+        if !port.starts_with(r"\\.\") {
+            return options.open(format!(r"\\.\{}", port));
+        }
+    }
+    options.open(port)
+}
+
+/// Put the port into raw mode at the requested baud rate with a read timeout
+#[cfg(unix)]
+fn configure_serial(file: &File, baud_rate: u32) -> io::Result<()> {
+    use std::os::unix::io::AsRawFd;
+
+    let speed = match baud_rate {
+        9600 => libc::B9600,
+        19200 => libc::B19200,
+        38400 => libc::B38400,
+        57600 => libc::B57600,
+        115200 => libc::B115200,
+        230400 => libc::B230400,
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Unsupported baud rate {}", baud_rate),
+            ))
+        }
+    };
+
+    let fd = file.as_raw_fd();
+    // SAFETY: termios is plain data filled in by tcgetattr, and fd is a valid
+    // open descriptor for the lifetime of `file`.
+    unsafe {
+        let mut tty: libc::termios = std::mem::zeroed();
+        if libc::tcgetattr(fd, &mut tty) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        libc::cfmakeraw(&mut tty);
+        libc::cfsetispeed(&mut tty, speed);
+        libc::cfsetospeed(&mut tty, speed);
+        tty.c_cflag |= libc::CLOCAL | libc::CREAD;
+        tty.c_cc[libc::VMIN] = 0;
+        tty.c_cc[libc::VTIME] = (IO_TIMEOUT.as_millis() / 100) as libc::cc_t;
+        if libc::tcsetattr(fd, libc::TCSANOW, &tty) != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+/// Set the baud rate, 8N1 framing without flow control, and read/write
+/// timeouts on a Windows COM port
+#[cfg(windows)]
+fn configure_serial(file: &File, baud_rate: u32) -> io::Result<()> {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Devices::Communication::{
+        GetCommState, SetCommState, SetCommTimeouts, COMMTIMEOUTS, DCB, NOPARITY, ONESTOPBIT,
+    };
+
+    /// fBinary, with DTR and RTS held on (DTR_CONTROL_ENABLE, RTS_CONTROL_ENABLE)
+    const RAW_FLAGS: u32 = 0x0001 | (1 << 4) | (1 << 12);
+
+    let handle = file.as_raw_handle() as _;
+    let timeout_ms = IO_TIMEOUT.as_millis() as u32;
+    // SAFETY: the calls only read and write the DCB and COMMTIMEOUTS passed
+    // to them, and the handle is a valid open COM port for the lifetime of `file`.
+    unsafe {
+        let mut dcb = DCB {
+            DCBlength: std::mem::size_of::<DCB>() as u32,
+            ..Default::default()
+        };
+        if GetCommState(handle, &mut dcb) == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        dcb.BaudRate = baud_rate;
+        dcb._bitfield = RAW_FLAGS;
+        dcb.ByteSize = 8;
+        dcb.Parity = NOPARITY;
+        dcb.StopBits = ONESTOPBIT;
+        if SetCommState(handle, &dcb) == 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // Reads return whatever arrived once the timeout expires, so a silent
+        // ECU ends the read instead of blocking the acquisition thread
+        let timeouts = COMMTIMEOUTS {
+            ReadIntervalTimeout: 0,
+            ReadTotalTimeoutMultiplier: 0,
+            ReadTotalTimeoutConstant: timeout_ms,
+            WriteTotalTimeoutMultiplier: 0,
+            WriteTotalTimeoutConstant: timeout_ms,
+        };
+        if SetCommTimeouts(handle, &timeouts) == 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(any(unix, windows)))]
+fn configure_serial(_file: &File, _baud_rate: u32) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Serial connections are not supported on this platform",
+    ))
+}
+
+/// Messages from the acquisition thread to the UI
+#[derive(Debug)]
+pub enum LiveMessage {
+    /// One output channel sample; `time` is seconds since connecting
+    Sample { time: f64, values: Vec<f64> },
+    /// The connection failed or was lost
+    Error(String),
+}
+
+/// A running acquisition thread
+pub struct LiveSession {
+    pub receiver: Receiver<LiveMessage>,
+    stop: Arc<AtomicBool>,
+}
+
+impl LiveSession {
+    /// Connect and start polling the ECU in the background
+    pub fn start(config: LiveConfig, layout: OutputLayout) -> Self {
+        let (sender, receiver) = channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+
+        thread::spawn(move || {
+            if let Err(e) = Self::run(&config, &layout, &sender, &thread_stop) {
+                let _ = sender.send(LiveMessage::Error(e));
+            }
+        });
+
+        Self { receiver, stop }
+    }
+
+    /// Ask the acquisition thread to stop after its current request
+    pub fn stop(&self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+
+    fn run(
+        config: &LiveConfig,
+        layout: &OutputLayout,
+        sender: &Sender<LiveMessage>,
+        stop: &AtomicBool,
+    ) -> Result<(), String> {
+        let mut conn = connect(config)?;
+        let started = Instant::now();
+        let interval = Duration::from_millis(config.poll_interval_ms);
+
+        while !stop.load(Ordering::Relaxed) {
+            let block = Self::read_block(conn.as_mut(), layout)?;
+            let sample = LiveMessage::Sample {
+                time: started.elapsed().as_secs_f64(),
+                values: layout.decode(&block),
+            };
+            if sender.send(sample).is_err() {
+                // UI dropped the session
+                break;
+            }
+            thread::sleep(interval);
+        }
+        Ok(())
+    }
+
+    /// Request the full output channel block, in chunks of the blocking factor
+    fn read_block(conn: &mut dyn Connection, layout: &OutputLayout) -> Result<Vec<u8>, String> {
+        let mut block = Vec::with_capacity(layout.block_size);
+        while block.len() < layout.block_size {
+            let count = (layout.block_size - block.len()).min(layout.blocking_factor);
+            let request = layout.request(block.len(), count);
+
+            if layout.framed {
+                conn.write_all(&frame(&request)).map_err(io_message)?;
+                let data = read_frame(conn)?;
+                if data.len() != count {
+                    return Err(format!(
+                        "Expected {} bytes of output channels, got {}",
+                        count,
+                        data.len()
+                    ));
+                }
+                block.extend(data);
+            } else {
+                conn.write_all(&request).map_err(io_message)?;
+                let mut data = vec![0u8; count];
+                conn.read_exact(&mut data).map_err(io_message)?;
+                block.extend(data);
+            }
+        }
+        Ok(block)
+    }
+}
+
+impl Drop for LiveSession {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INI: &str = r#"
+[MegaTune]
+   messageEnvelopeFormat = msEnvelope_1.0
+
+[Constants]
+   blockingFactor = 64
+
+[OutputChannels]
+   ochGetCommand = "r\$tsCanId\x30%2o%2c"
+   ochBlockSize = 8
+
+#if CELSIUS
+   coolant = scalar, U08, 0, "C", 1.000, -40.000
+#else
+   coolant = scalar, U08, 0, "F", 1.800, -22.23
+#endif
+   rpm      = scalar, U16, 1, "rpm", 1.000, 0.000 ; engine speed
+   map      = scalar, S16, 3, "kPa", 0.1, 0.0
+   status   = bits,   U08, 5, [0:0]
+   afrTgt   = { afrTarget / 10 }
+   lambda   = scalar, U16, 6, "", { 1/1000 }, 0
+"#;
+
+    #[test]
+    fn test_parse_output_channels() {
+        let layout = OutputLayout::from_ini(SAMPLE_INI).unwrap();
+
+        let names: Vec<&str> = layout.channels.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["coolant", "rpm", "map"]);
+        assert_eq!(layout.channels[0].unit, "C");
+        assert_eq!(layout.block_size, 8);
+        assert_eq!(layout.blocking_factor, 64);
+        assert!(layout.framed);
+        assert!(!layout.big_endian);
+    }
+
+    #[test]
+    fn test_request_command() {
+        let layout = OutputLayout::from_ini(SAMPLE_INI).unwrap();
+        assert_eq!(layout.request(2, 6), vec![b'r', 0, 0x30, 2, 0, 6, 0]);
+
+        // Non-ASCII characters are sent as their UTF-8 bytes
+        let ini = SAMPLE_INI.replace(r#""r\$tsCanId"#, r#""é\$tsCanId"#);
+        let layout = OutputLayout::from_ini(&ini).unwrap();
+        assert_eq!(layout.request(2, 6), vec![0xC3, 0xA9, 0, 0x30, 2, 0, 6, 0]);
+    }
+
+    #[test]
+    fn test_decode_block() {
+        let layout = OutputLayout::from_ini(SAMPLE_INI).unwrap();
+        let block = [130, 0xDC, 0x05, 0x18, 0xFC, 0, 0, 0];
+        let values = layout.decode(&block);
+
+        assert_eq!(values[0], 90.0);
+        assert_eq!(values[1], 1500.0);
+        assert!((values[2] - -100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_frame_round_trip() {
+        let mut response = frame(&[0, 1, 2, 3]);
+        let mut conn = io::Cursor::new(&mut response);
+        assert_eq!(read_frame(&mut conn).unwrap(), vec![1, 2, 3]);
+
+        let mut corrupt = frame(&[0, 1, 2, 3]);
+        corrupt[3] ^= 0xFF;
+        let mut conn = io::Cursor::new(&mut corrupt);
+        assert!(read_frame(&mut conn).is_err());
+    }
+}
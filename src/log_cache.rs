@@ -0,0 +1,409 @@
+//! On-disk cache of parsed text logs.
+//!
+//! Parsing a large Haltech or ECUMaster CSV takes seconds, so after the first
+//! load the parsed log is written to a compact binary file in the app's data
+//! folder. Reopening the same file reads the columns straight back instead.
+//!
+//! Each source file has one cache entry, named after a hash of its path. The
+//! entry records the source's size, modification time and a checksum of its
+//! first and last blocks, and is ignored (then replaced) when any of them no
+//! longer match. Entries written with another [`CACHE_VERSION`] are ignored
+//! too, so changing the layout or the parsers only needs a version bump.
+//!
+//! Layout: magic, format version, header length, a JSON header (source
+//! fingerprint, metadata and channels), then the time column and each channel
+//! column as little-endian f64s.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+
+use crate::parsers::ecumaster::{EcuMasterChannel, EcuMasterMeta};
+use crate::parsers::haltech::{HaltechChannel, HaltechMeta};
+use crate::parsers::types::Meta;
+use crate::parsers::{Channel, EcuType, Log};
+
+/// Version of the cache file layout; bump when it or the parsed output changes
+pub const CACHE_VERSION: u32 = 2;
+
+/// Leading magic of a cache file
+const MAGIC: &[u8; 4] = b"ULGC";
+
+/// Extension of cache files in the cache folder
+const CACHE_EXTENSION: &str = "ulcache";
+
+/// Bytes checksummed at each end of the source file
+const FINGERPRINT_BLOCK: u64 = 64 * 1024;
+
+/// Total size the cache folder may grow to before the oldest entries are removed
+pub const MAX_CACHE_BYTES: u64 = 4 * 1024 * 1024 * 1024;
+
+/// Identifies one version of a source file
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct SourceFingerprint {
+    len: u64,
+    /// Modification time (nanoseconds since the Unix epoch)
+    modified_ns: u64,
+    /// CRC32 of the first and last [`FINGERPRINT_BLOCK`] bytes
+    checksum: u32,
+}
+
+impl SourceFingerprint {
+    fn of(path: &Path) -> io::Result<Self> {
+        let mut file = File::open(path)?;
+        let metadata = file.metadata()?;
+        let len = metadata.len();
+        let modified_ns = metadata
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+
+        let mut hasher = crc32fast::Hasher::new();
+        let mut block = Vec::new();
+        (&mut file)
+            .take(FINGERPRINT_BLOCK)
+            .read_to_end(&mut block)?;
+        hasher.update(&block);
+        if len > FINGERPRINT_BLOCK {
+            block.clear();
+            file.seek(SeekFrom::Start(
+                len.saturating_sub(FINGERPRINT_BLOCK).max(FINGERPRINT_BLOCK),
+            ))?;
+            file.read_to_end(&mut block)?;
+            hasher.update(&block);
+        }
+
+        Ok(Self {
+            len,
+            modified_ns,
+            checksum: hasher.finalize(),
+        })
+    }
+}
+
+/// Format-specific metadata and channels of a cached log
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "format")]
+enum CachedFormat {
+    Haltech {
+        meta: HaltechMeta,
+        channels: Vec<HaltechChannel>,
+    },
+    EcuMaster {
+        meta: EcuMasterMeta,
+        channels: Vec<EcuMasterChannel>,
+    },
+}
+
+/// JSON header of a cache file
+#[derive(Serialize, Deserialize)]
+struct CacheHeader {
+    /// Source path, to rule out path hash collisions
+    source: PathBuf,
+    fingerprint: SourceFingerprint,
+    /// Bytes of the source the parser consumed, where following resumes
+    consumed: u64,
+    records: usize,
+    /// Damaged records skipped when the source was parsed
+    #[serde(default)]
+    skipped_records: usize,
+    #[serde(flatten)]
+    format: CachedFormat,
+}
+
+/// A log read back from the cache
+pub struct CachedLog {
+    pub log: Log,
+    pub ecu_type: EcuType,
+    /// Bytes of the source the parser consumed when the log was cached
+    pub consumed: u64,
+}
+
+/// Whether a parsed log can be cached: text formats decoded in full, before
+/// any derived channels are added
+pub fn is_cacheable(log: &Log) -> bool {
+    log.lazy_data.is_none()
+        && matches!(log.meta, Meta::Haltech(_) | Meta::EcuMaster(_))
+        && !log.channels.iter().any(Channel::is_derived)
+}
+
+/// Cache folder holding one entry per source file
+#[derive(Clone, Debug)]
+pub struct LogCache {
+    dir: PathBuf,
+}
+
+impl LogCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// Cache in the app's data folder, if the platform has one
+    pub fn default_location() -> Option<Self> {
+        crate::data_dir::data_dir().map(|dir| Self::new(dir.join("log-cache")))
+    }
+
+    /// Cache file for a source path
+    fn entry_path(&self, source: &Path) -> PathBuf {
+        let hash = crc32fast::hash(source.to_string_lossy().as_bytes());
+        self.dir.join(format!("{:08x}.{}", hash, CACHE_EXTENSION))
+    }
+
+    /// Read a source file's log from the cache, if it was cached from the
+    /// file as it is now
+    pub fn load(&self, source: &Path) -> Option<CachedLog> {
+        let source = fs::canonicalize(source).ok()?;
+        let bytes = fs::read(self.entry_path(&source)).ok()?;
+
+        let rest = bytes.strip_prefix(MAGIC)?;
+        let (version, rest) = split_u32(rest)?;
+        let (header_len, rest) = split_u32(rest)?;
+        if version != CACHE_VERSION {
+            return None;
+        }
+        let (header, columns) = rest.split_at_checked(header_len as usize)?;
+        let header: CacheHeader = serde_json::from_slice(header).ok()?;
+        if header.source != source || header.fingerprint != SourceFingerprint::of(&source).ok()? {
+            return None;
+        }
+
+        let (meta, channels, ecu_type) = match header.format {
+            CachedFormat::Haltech { meta, channels } => (
+                Meta::Haltech(meta),
+                channels
+                    .into_iter()
+                    .map(Channel::Haltech)
+                    .collect::<Vec<_>>(),
+                EcuType::Haltech,
+            ),
+            CachedFormat::EcuMaster { meta, channels } => (
+                Meta::EcuMaster(meta),
+                channels.into_iter().map(Channel::EcuMaster).collect(),
+                EcuType::EcuMaster,
+            ),
+        };
+
+        // Time column followed by one column per channel
+        let column_bytes = header.records * 8;
+        if columns.len() != column_bytes * (channels.len() + 1) {
+            return None;
+        }
+        let mut columns = columns.chunks_exact(column_bytes.max(1)).map(decode_column);
+        let times = if header.records == 0 {
+            Vec::new()
+        } else {
+            columns.next()?
+        };
+        let data = (0..channels.len())
+            .map(|_| columns.next().unwrap_or_default())
+            .collect();
+
+        Some(CachedLog {
+            log: Log {
+                meta,
+                channels,
+                times,
+                data,
+                lazy_data: None,
+                skipped_records: header.skipped_records,
+                markers: Vec::new(),
+            },
+            ecu_type,
+            consumed: header.consumed,
+        })
+    }
+
+    /// Write a freshly parsed log to the cache, replacing any older entry for
+    /// the same source. Logs that can't be cached are skipped.
+    pub fn store(&self, source: &Path, log: &Log, consumed: u64) -> io::Result<()> {
+        if !is_cacheable(log) {
+            return Ok(());
+        }
+        let source = fs::canonicalize(source)?;
+        let format = match &log.meta {
+            Meta::Haltech(meta) => CachedFormat::Haltech {
+                meta: meta.clone(),
+                channels: log
+                    .channels
+                    .iter()
+                    .filter_map(|c| match c {
+                        Channel::Haltech(h) => Some(h.clone()),
+                        _ => None,
+                    })
+                    .collect(),
+            },
+            Meta::EcuMaster(meta) => CachedFormat::EcuMaster {
+                meta: meta.clone(),
+                channels: log
+                    .channels
+                    .iter()
+                    .filter_map(|c| match c {
+                        Channel::EcuMaster(e) => Some(e.clone()),
+                        _ => None,
+                    })
+                    .collect(),
+            },
+            _ => return Ok(()),
+        };
+        let header = CacheHeader {
+            fingerprint: SourceFingerprint::of(&source)?,
+            source,
+            consumed,
+            records: log.record_count(),
+            skipped_records: log.skipped_records,
+            format,
+        };
+        let header_json = serde_json::to_vec(&header).map_err(io::Error::other)?;
+
+        fs::create_dir_all(&self.dir)?;
+        let entry = self.entry_path(&header.source);
+        // Write beside the entry and rename, so a crash never leaves a torn entry
+        let partial = entry.with_extension("partial");
+        {
+            let mut out = BufWriter::new(File::create(&partial)?);
+            out.write_all(MAGIC)?;
+            out.write_all(&CACHE_VERSION.to_le_bytes())?;
+            out.write_all(&(header_json.len() as u32).to_le_bytes())?;
+            out.write_all(&header_json)?;
+            for column in std::iter::once(log.times.as_slice())
+                .chain((0..log.channels.len()).map(|i| log.get_channel_data(i)))
+            {
+                for value in column {
+                    out.write_all(&value.to_le_bytes())?;
+                }
+            }
+            out.flush()?;
+        }
+        fs::rename(&partial, &entry)?;
+
+        self.prune(MAX_CACHE_BYTES)
+    }
+
+    /// Remove the oldest entries until the cache fits in `max_bytes`
+    fn prune(&self, max_bytes: u64) -> io::Result<()> {
+        let mut entries: Vec<(PathBuf, u64, std::time::SystemTime)> = fs::read_dir(&self.dir)?
+            .filter_map(Result::ok)
+            .filter(|e| {
+                e.path()
+                    .extension()
+                    .is_some_and(|ext| ext == CACHE_EXTENSION)
+            })
+            .filter_map(|e| {
+                let metadata = e.metadata().ok()?;
+                Some((e.path(), metadata.len(), metadata.modified().ok()?))
+            })
+            .collect();
+        entries.sort_by_key(|(_, _, modified)| *modified);
+
+        let mut total: u64 = entries.iter().map(|(_, len, _)| len).sum();
+        for (path, len, _) in entries {
+            if total <= max_bytes {
+                break;
+            }
+            fs::remove_file(path)?;
+            total -= len;
+        }
+        Ok(())
+    }
+
+    /// Delete every cached log
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn split_u32(bytes: &[u8]) -> Option<(u32, &[u8])> {
+    let (value, rest) = bytes.split_first_chunk::<4>()?;
+    Some((u32::from_le_bytes(*value), rest))
+}
+
+fn decode_column(bytes: &[u8]) -> Vec<f64> {
+    bytes
+        .chunks_exact(8)
+        .map(|b| f64::from_le_bytes(b.try_into().expect("8-byte chunk")))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scratch folder for one test, removed first in case an earlier run failed
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("ultralog-log-cache-{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn sample_log() -> Log {
+        Log {
+            meta: Meta::EcuMaster(EcuMasterMeta {
+                channel_count: 2,
+                data_points: 3,
+            }),
+            channels: vec![
+                Channel::EcuMaster(EcuMasterChannel::from_path("engine/rpm")),
+                Channel::EcuMaster(EcuMasterChannel::from_path("sensors/map")),
+            ],
+            times: vec![0.0, 0.1, 0.2],
+            data: vec![vec![1000.0, 1500.0, 2000.0], vec![30.0, f64::NAN, 101.5]],
+            lazy_data: None,
+            skipped_records: 4,
+            markers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_cache_round_trip() {
+        let dir = scratch_dir("round-trip");
+        let source = dir.join("log.csv");
+        fs::write(&source, "TIME;engine/rpm;sensors/map\n").unwrap();
+        let cache = LogCache::new(dir.join("cache"));
+
+        assert!(cache.load(&source).is_none());
+        cache.store(&source, &sample_log(), 42).unwrap();
+
+        let cached = cache.load(&source).expect("cache hit");
+        assert_eq!(cached.ecu_type, EcuType::EcuMaster);
+        assert_eq!(cached.consumed, 42);
+        assert_eq!(cached.log.skipped_records, 4);
+        assert_eq!(cached.log.times, vec![0.0, 0.1, 0.2]);
+        assert_eq!(cached.log.channels.len(), 2);
+        assert_eq!(cached.log.channels[1].name(), "map");
+        assert_eq!(cached.log.data[0], vec![1000.0, 1500.0, 2000.0]);
+        assert!(cached.log.data[1][1].is_nan());
+
+        // Changing the source invalidates its entry
+        fs::write(&source, "TIME;engine/rpm;sensors/map;extra\n").unwrap();
+        assert!(cache.load(&source).is_none());
+
+        cache.clear().unwrap();
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_is_cacheable() {
+        assert!(is_cacheable(&sample_log()));
+
+        let mut derived = sample_log();
+        derived.push_channel(
+            Channel::Derived(crate::derived::DerivedChannel {
+                name: "Estimated Gear".to_string(),
+                unit: String::new(),
+            }),
+            vec![1.0, 1.0, 2.0],
+        );
+        assert!(!is_cacheable(&derived));
+
+        let mut generic = sample_log();
+        generic.meta = Meta::Empty;
+        assert!(!is_cacheable(&generic));
+    }
+}
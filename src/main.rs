@@ -7,8 +7,10 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 use eframe::egui::IconData;
+use std::path::PathBuf;
 use std::sync::Arc;
 use ultralog::app::UltraLogApp;
+use ultralog::convert::{self, ConvertArgs};
 
 /// Load the platform-specific application icon
 fn load_app_icon() -> Option<Arc<IconData>> {
@@ -62,12 +64,116 @@ fn set_macos_app_name() {
 #[cfg(not(target_os = "macos"))]
 fn set_macos_app_name() {}
 
+/// Release builds on Windows have no console of their own; attach to the
+/// terminal that launched us so CLI output is visible
+#[cfg(windows)]
+fn attach_parent_console() {
+    const ATTACH_PARENT_PROCESS: u32 = u32::MAX;
+    extern "system" {
+        fn AttachConsole(process_id: u32) -> i32;
+    }
+    // SAFETY: AttachConsole has no preconditions; failure just leaves us without a console
+    unsafe {
+        AttachConsole(ATTACH_PARENT_PROCESS);
+    }
+}
+
+#[cfg(not(windows))]
+fn attach_parent_console() {}
+
+/// Run `ultralog convert ...` and return the process exit code
+fn run_convert(args: Vec<String>) -> i32 {
+    attach_parent_console();
+
+    let args = match ConvertArgs::parse(args) {
+        Ok(args) if args.help => {
+            println!("{}", convert::USAGE);
+            return 0;
+        }
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, convert::USAGE);
+            return 2;
+        }
+    };
+
+    let mut failed = 0;
+    for input in &args.inputs {
+        let file = match UltraLogApp::load_file_blocking(input.clone()) {
+            Ok(file) => file,
+            Err(e) => {
+                eprintln!("{}: {}", input.display(), e);
+                failed += 1;
+                continue;
+            }
+        };
+
+        let result = match args.output_path(input) {
+            Some(output) => std::fs::File::create(&output)
+                .map(std::io::BufWriter::new)
+                .and_then(|mut out| {
+                    convert::write_log(&file.log, args.format, args.normalize, &mut out)
+                })
+                .map(|_| eprintln!("{} -> {}", input.display(), output.display())),
+            None => convert::write_log(
+                &file.log,
+                args.format,
+                args.normalize,
+                &mut std::io::stdout().lock(),
+            ),
+        };
+        if let Err(e) = result {
+            eprintln!("{}: failed to write output: {}", input.display(), e);
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        1
+    } else {
+        0
+    }
+}
+
+/// Collect log file paths passed on the command line, e.g. `ultralog mylog.csv`
+/// or a double-clicked file from the OS file association
+fn startup_files() -> Vec<PathBuf> {
+    std::env::args_os()
+        .skip(1)
+        .map(PathBuf::from)
+        // Skip flags such as the process serial number older macOS passes to apps
+        .filter(|path| !path.to_string_lossy().starts_with('-'))
+        .filter(|path| {
+            let exists = path.is_file();
+            if !exists {
+                eprintln!("Ignoring missing file: {}", path.display());
+            }
+            exists
+        })
+        .collect()
+}
+
 fn main() -> eframe::Result<()> {
+    // Headless conversion runs without creating a window
+    if std::env::args_os()
+        .nth(1)
+        .is_some_and(|arg| arg == "convert")
+    {
+        let args = std::env::args_os()
+            .skip(2)
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        std::process::exit(run_convert(args));
+    }
+
     // Set macOS app name before anything else
     set_macos_app_name();
 
-    // Initialize logging
-    tracing_subscriber::fmt::init();
+    // Initialize logging to stderr, the log file and the log console
+    ultralog::diagnostics::init();
+    ultralog::crash::install_panic_hook();
+
+    let startup_files = startup_files();
 
     // Load platform-specific app icon
     let icon = load_app_icon();
@@ -87,6 +193,8 @@ fn main() -> eframe::Result<()> {
 
     let native_options = eframe::NativeOptions {
         viewport,
+        // Portable mode keeps settings next to the executable
+        persistence_path: ultralog::data_dir::settings_file(),
         ..Default::default()
     };
 
@@ -94,6 +202,6 @@ fn main() -> eframe::Result<()> {
     eframe::run_native(
         "UltraLog",
         native_options,
-        Box::new(|cc| Ok(Box::new(UltraLogApp::new(cc)))),
+        Box::new(|cc| Ok(Box::new(UltraLogApp::new(cc, startup_files)))),
     )
 }
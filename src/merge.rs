@@ -0,0 +1,87 @@
+//! Merging two simultaneously recorded logs.
+//!
+//! The channels of a second log, e.g. an external wideband logger's CSV,
+//! are added to a copy of the first (usually the ECU log) by resampling them
+//! onto its timestamps with linear interpolation. Times are matched as
+//! recorded, so both logs should start at the same moment. Outside the time
+//! the second log covers its channels have no values.
+
+use crate::parsers::types::{Channel, Log};
+
+/// Whether a channel was recorded rather than derived from others. Derived
+/// channels are left out of a merge and computed again for the result.
+fn is_recorded(channel: &Channel) -> bool {
+    !matches!(channel, Channel::Derived(_))
+}
+
+/// A log with every recorded channel of `primary` followed by those of
+/// `secondary` resampled onto `primary`'s timestamps. Markers of both logs
+/// are kept.
+pub fn merge(primary: &Log, secondary: &Log) -> Log {
+    let mut channels = Vec::new();
+    let mut data = Vec::new();
+    for (index, channel) in primary.channels.iter().enumerate() {
+        if is_recorded(channel) {
+            channels.push(channel.clone());
+            data.push(primary.get_channel_data(index).to_vec());
+        }
+    }
+    let added: Vec<usize> = (0..secondary.channels.len())
+        .filter(|&index| is_recorded(&secondary.channels[index]))
+        .collect();
+    let first_added = data.len();
+    for &index in &added {
+        channels.push(secondary.channels[index].clone());
+        data.push(Vec::with_capacity(primary.times.len()));
+    }
+    for &time in &primary.times {
+        let values = secondary.interpolate_at(time);
+        for (column, &index) in data[first_added..].iter_mut().zip(&added) {
+            column.push(values[index]);
+        }
+    }
+
+    let mut markers: Vec<_> = primary
+        .markers
+        .iter()
+        .chain(&secondary.markers)
+        .cloned()
+        .collect();
+    markers.sort_by(|a, b| a.time.total_cmp(&b.time));
+
+    Log {
+        meta: primary.meta.clone(),
+        channels,
+        times: primary.times.clone(),
+        data,
+        lazy_data: None,
+        skipped_records: 0,
+        markers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::{GenericCsv, Parseable};
+
+    #[test]
+    fn test_merge() {
+        let ecu = GenericCsv::default()
+            .parse("Time,RPM\n0,1000\n0.5,1500\n1,2000\n1.5,2500\n")
+            .unwrap();
+        let wideband = GenericCsv::default()
+            .parse("Time,AFR\n0.2,14.0\n1.2,12.0\n")
+            .unwrap();
+        let merged = merge(&ecu, &wideband);
+
+        assert_eq!(merged.times, ecu.times);
+        let names: Vec<String> = merged.channels.iter().map(|c| c.name()).collect();
+        assert_eq!(names, ["RPM", "AFR"]);
+        let afr = merged.get_channel_data(1);
+        assert!(afr[0].is_nan());
+        assert!((afr[1] - 13.4).abs() < 1e-9);
+        assert!((afr[2] - 12.4).abs() < 1e-9);
+        assert!(afr[3].is_nan());
+    }
+}
@@ -0,0 +1,152 @@
+//! Misfire detection.
+//!
+//! A misfire under load shows up in the log as a sudden RPM dip that the
+//! engine recovers from a moment later. Shifts and lifts also drop RPM
+//! quickly, but RPM doesn't come back, so a dip only counts as a candidate
+//! misfire once RPM climbs back within the recovery time.
+
+use serde::{Deserialize, Serialize};
+
+/// Share of the dip RPM must regain to count as recovered
+const RECOVERED_FRACTION: f64 = 0.5;
+
+/// Thresholds a dip in RPM must meet to count as a candidate misfire
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MisfireConfig {
+    /// Minimum rate RPM falls at (RPM per second)
+    pub min_drop_rate: f64,
+    /// Minimum throttle position (%) for the engine to be under load
+    pub min_tps: f64,
+    /// Minimum RPM before the dip
+    pub min_rpm: f64,
+    /// Longest time after the dip for RPM to recover (seconds)
+    pub recovery_time: f64,
+}
+
+impl Default for MisfireConfig {
+    fn default() -> Self {
+        Self {
+            min_drop_rate: 1500.0,
+            min_tps: 20.0,
+            min_rpm: 1500.0,
+            recovery_time: 0.5,
+        }
+    }
+}
+
+/// A candidate misfire
+#[derive(Clone, Debug, PartialEq)]
+pub struct MisfireEvent {
+    /// Index of the record with the lowest RPM of the dip
+    pub record: usize,
+    /// Time of that record (seconds)
+    pub time: f64,
+    /// RPM just before the dip
+    pub rpm_before: f64,
+    /// Lowest RPM of the dip
+    pub rpm_min: f64,
+    /// Steepest rate RPM fell at during the dip (RPM per second)
+    pub drop_rate: f64,
+}
+
+impl MisfireEvent {
+    /// RPM lost over the dip
+    pub fn drop(&self) -> f64 {
+        self.rpm_before - self.rpm_min
+    }
+}
+
+impl MisfireConfig {
+    /// Find RPM dips under load that recover, in time order
+    pub fn detect(&self, times: &[f64], rpm: &[f64], tps: &[f64]) -> Vec<MisfireEvent> {
+        let len = times.len().min(rpm.len()).min(tps.len());
+        // RPM per second from the previous record, if time moved forward
+        let rate = |i: usize| {
+            let dt = times[i] - times[i - 1];
+            (dt > 0.0).then(|| (rpm[i] - rpm[i - 1]) / dt)
+        };
+
+        let mut events = Vec::new();
+        let mut i = 1;
+        while i < len {
+            let dipping = rate(i).is_some_and(|r| r <= -self.min_drop_rate)
+                && tps[i] >= self.min_tps
+                && rpm[i - 1] >= self.min_rpm;
+            if !dipping {
+                i += 1;
+                continue;
+            }
+
+            // Follow the dip down to its lowest point
+            let rpm_before = rpm[i - 1];
+            let mut low = i;
+            let mut steepest = rate(i).unwrap_or(0.0);
+            while low + 1 < len && rpm[low + 1] < rpm[low] {
+                low += 1;
+                steepest = steepest.min(rate(low).unwrap_or(0.0));
+            }
+
+            let rpm_min = rpm[low];
+            let recovered_rpm = rpm_min + (rpm_before - rpm_min) * RECOVERED_FRACTION;
+            let recovered = (low + 1..len)
+                .take_while(|&j| times[j] - times[low] <= self.recovery_time)
+                .any(|j| rpm[j] >= recovered_rpm);
+            if recovered {
+                events.push(MisfireEvent {
+                    record: low,
+                    time: times[low],
+                    rpm_before,
+                    rpm_min,
+                    drop_rate: -steepest,
+                });
+            }
+            i = low + 1;
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn times(len: usize) -> Vec<f64> {
+        (0..len).map(|i| i as f64 * 0.05).collect()
+    }
+
+    #[test]
+    fn test_detect_recovered_dip() {
+        let config = MisfireConfig::default();
+        let rpm = [
+            3000.0, 3010.0, 3020.0, 2900.0, 2850.0, 2990.0, 3030.0, 3040.0,
+        ];
+        let tps = [50.0; 8];
+
+        let events = config.detect(&times(8), &rpm, &tps);
+        assert_eq!(events.len(), 1);
+        let event = &events[0];
+        assert_eq!(event.record, 4);
+        assert_eq!(event.rpm_before, 3020.0);
+        assert_eq!(event.rpm_min, 2850.0);
+        assert!((event.drop_rate - 2400.0).abs() < 1e-6);
+        assert!((event.drop() - 170.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ignore_shifts_and_light_load() {
+        let config = MisfireConfig::default();
+
+        // A shift: RPM falls and stays down
+        let rpm = [
+            6500.0, 6600.0, 6000.0, 5200.0, 4800.0, 4850.0, 4900.0, 4950.0,
+        ];
+        assert!(config.detect(&times(8), &rpm, &[100.0; 8]).is_empty());
+
+        // The same recovered dip as above, but off throttle
+        let rpm = [
+            3000.0, 3010.0, 3020.0, 2900.0, 2850.0, 2990.0, 3030.0, 3040.0,
+        ];
+        assert!(config.detect(&times(8), &rpm, &[5.0; 8]).is_empty());
+    }
+}
@@ -3,8 +3,12 @@
 //! This module provides mappings from various ECU-specific channel names to standardized names,
 //! making it easier for users to compare data from different logging systems.
 
-use std::collections::HashMap;
-use std::sync::LazyLock;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{LazyLock, Mutex};
+
+use regex::{Captures, Regex};
+
+use crate::binning::csv_field;
 
 /// Mapping from normalized (standard) names to their possible source names
 static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
@@ -23,7 +27,10 @@ static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
                 "AFR1",
                 "WB2 AFR 1",
                 "Air Fuel Ratio",
+                "AF Sens 1 Ratio",
+                "A/F Sensor #1",
                 "AFR_R_BANK",
+                "AFR Left",
             ],
         );
 
@@ -70,6 +77,7 @@ static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
                 "Bat Volts ECU",
                 "Battery Voltage",
                 "ECU Battery Voltage",
+                "Control Module Voltage",
                 "Ecu power",
                 "BATTERY_VOLTAGE",
                 "Bat_V",
@@ -89,6 +97,9 @@ static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
                 "CoolantTemp",
                 "coolantTemp",
                 "Coolant Temp",
+                "Engine Coolant Temp",
+                "WAT",
+                "CTS",
             ],
         );
 
@@ -108,7 +119,35 @@ static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
         // EGO Correction 1
         map.insert(
             "EGO Cor 1",
-            vec!["EGO Correction", "ID2 Cor", "EGO_Cor_1", "EGO Cor 1"],
+            vec![
+                "EGO Correction",
+                "ID2 Cor",
+                "EGO_Cor_1",
+                "EGO Cor 1",
+                "Gego",
+            ],
+        );
+
+        // Closed-loop fuel trims
+        map.insert(
+            "Short Term Trim",
+            vec![
+                "Short Term Fuel Trim",
+                "O2 Control Bank 1 Short Term Fuel Trim",
+                "STFT",
+                "STFT: Bank 1",
+                "STFT1",
+            ],
+        );
+        map.insert(
+            "Long Term Trim",
+            vec![
+                "Long Term Fuel Trim",
+                "O2 Control Bank 1 Long Term Fuel Trim",
+                "LTFT",
+                "LTFT: Bank 1",
+                "LTFT1",
+            ],
         );
 
         // EGO Correction 2
@@ -132,6 +171,9 @@ static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
                 "IAT - Inlet Air Temp",
                 "IAT Intake Air Temp",
                 "Intake Air Temp",
+                "Intake Temp",
+                "Intake Air Temperature",
+                "Inlet Air Temp",
             ],
         );
 
@@ -154,7 +196,13 @@ static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
         // Mass Air Flow
         map.insert(
             "MAF",
-            vec!["Mass Air Flow", "Mass Air Flow Rate", "MAF", "maf"],
+            vec![
+                "Mass Air Flow",
+                "Mass Air Flow Rate",
+                "Mass Airflow",
+                "MAF",
+                "maf",
+            ],
         );
 
         // Manifold Absolute Pressure
@@ -166,6 +214,7 @@ static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
                 "map",
                 "Manifold Pressure",
                 "Inlet Manifold Pressure",
+                "Manifold Absolute Pressure",
             ],
         );
 
@@ -210,6 +259,8 @@ static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
                 "Engine RPM4",
                 "RPM_INC_RPM",
                 "engine/rpm",
+                "Engine RPM",
+                "Engine Speed",
             ],
         );
 
@@ -227,6 +278,8 @@ static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
                 "TPS",
                 "tps",
                 "tps1",
+                "Throttle Pos.",
+                "Throttle Opening Angle",
             ],
         );
 
@@ -252,18 +305,80 @@ static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
                 "Timing",
                 "Spark Advance",
                 "IgnAdv",
+                "Ignition Timing",
+                "Ignition Angle",
+                "Ign Adv",
                 "ignition/angle",
             ],
         );
 
         map.insert(
             "Knock",
-            vec!["Knock", "Knock Retard", "KnockRetard", "Knock Count"],
+            vec![
+                "Knock",
+                "Knock Retard",
+                "KnockRetard",
+                "Knock Count",
+                "Feedback Knock",
+            ],
         );
 
         map.insert(
             "Boost",
-            vec!["Boost", "Boost Pressure", "BoostPressure", "boost"],
+            vec![
+                "Boost",
+                "Boost Pressure",
+                "BoostPressure",
+                "Manifold Relative Pressure",
+                "boost",
+            ],
+        );
+
+        map.insert(
+            "Boost Target",
+            vec![
+                "Boost Target",
+                "Target Boost",
+                "Boost Control Target Pressure",
+                "Target MAP",
+                "MAP Target",
+                "boostTarget",
+            ],
+        );
+
+        map.insert(
+            "Wastegate Duty",
+            vec![
+                "Wastegate Duty",
+                "WG Duty",
+                "Boost Duty",
+                "Boost Control Solenoid Duty Cycle",
+                "boostDuty",
+            ],
+        );
+
+        map.insert(
+            "Idle Valve",
+            vec![
+                "Idle Valve",
+                "Idle Control Output",
+                "IAC value",
+                "IAC Duty",
+                "IAC Position",
+                "Idle Air Valve",
+                "ISC Duty",
+            ],
+        );
+
+        map.insert(
+            "Idle Target",
+            vec![
+                "Idle Target",
+                "Idle Control target RPM",
+                "Idle: Target RPM",
+                "Idle Target RPM",
+                "Target Idle RPM",
+            ],
         );
 
         map.insert(
@@ -296,7 +411,16 @@ static NORMALIZATION_MAP: LazyLock<HashMap<&'static str, Vec<&'static str>>> =
             vec!["Vehicle Speed", "VSS", "Speed", "vss", "vehicleSpeed"],
         );
 
-        map.insert("Gear", vec!["Gear", "Current Gear", "GearPosition", "gear"]);
+        map.insert(
+            "Gear",
+            vec![
+                "Gear",
+                "Current Gear",
+                "GearPosition",
+                "Gear Position",
+                "gear",
+            ],
+        );
 
         map
     });
@@ -312,6 +436,144 @@ static REVERSE_MAP: LazyLock<HashMap<String, &'static str>> = LazyLock::new(|| {
     reverse
 });
 
+/// Channel list groups shown first, in this order
+pub const CHANNEL_CATEGORIES: &[&str] = &["Engine", "Fuel", "Ignition", "Sensors"];
+
+/// Group for channels without a known category
+pub const OTHER_CATEGORY: &str = "Other";
+
+/// Category of a standard (normalized) channel name
+pub fn normalized_category(normalized: &str) -> Option<&'static str> {
+    match normalized {
+        "RPM" | "MAP" | "MAF" | "Boost" | "Boost Target" | "Wastegate Duty" | "Idle Valve"
+        | "Idle Target" | "Vehicle Speed" | "Gear" => Some("Engine"),
+        "AFR" | "AFR Target" | "AFR 1" | "AFR 2" | "AFR 1 Error" | "Lambda 1" | "O2"
+        | "EGO Cor 1" | "EGO Cor 2" | "Short Term Trim" | "Long Term Trim" | "Pulse Width"
+        | "Duty Cycle" | "Fuel Pressure" => Some("Fuel"),
+        "Ignition Adv" | "Knock" => Some("Ignition"),
+        "Battery V" | "Coolant Temp" | "IAT" | "MAT" | "TPS" | "Oil Pressure" | "Oil Temp"
+        | "EGT" => Some("Sensors"),
+        _ => None,
+    }
+}
+
+/// Channel list group for a channel: the category its ECU assigns, else the
+/// category of its normalized name, else [`OTHER_CATEGORY`]
+pub fn channel_category(
+    ecu_category: Option<String>,
+    name: &str,
+    custom_mappings: Option<&HashMap<String, String>>,
+) -> String {
+    ecu_category
+        .or_else(|| {
+            normalized_category(&normalize_channel_name_with_custom(name, custom_mappings))
+                .map(str::to_string)
+        })
+        .unwrap_or_else(|| OTHER_CATEGORY.to_string())
+}
+
+/// Sort channel list groups: the standard categories first, then ECU
+/// categories alphabetically, with Other and Derived last
+pub fn sort_categories(categories: &mut [String]) {
+    categories.sort_by_cached_key(|category| {
+        let rank = match CHANNEL_CATEGORIES.iter().position(|c| c == category) {
+            Some(position) => position,
+            None if category == OTHER_CATEGORY => CHANNEL_CATEGORIES.len() + 1,
+            None if category == crate::derived::DERIVED_GROUP => CHANNEL_CATEGORIES.len() + 2,
+            None => CHANNEL_CATEGORIES.len(),
+        };
+        (rank, category.to_lowercase())
+    });
+}
+
+/// Prefix marking a custom mapping source as a regular expression
+pub const REGEX_PREFIX: &str = "re:";
+
+/// Compiled custom patterns, keyed by their mapping source (`None` if invalid)
+static PATTERN_CACHE: LazyLock<Mutex<HashMap<String, Option<Regex>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Whether a custom mapping source is a pattern rather than a plain name:
+/// either a `re:` regular expression or a name containing `*` wildcards
+pub fn is_pattern(source: &str) -> bool {
+    source.starts_with(REGEX_PREFIX) || source.contains('*')
+}
+
+/// Key a custom mapping is stored under. Plain names and wildcards are
+/// lowercased; regular expressions are kept as typed since case changes
+/// their meaning (e.g. `\D`).
+pub fn mapping_key(source: &str) -> String {
+    let source = source.trim();
+    if source.starts_with(REGEX_PREFIX) {
+        source.to_string()
+    } else {
+        source.to_lowercase()
+    }
+}
+
+/// Case-insensitive regex matching a whole channel name against a pattern
+/// source. Each `*` wildcard becomes a capture group.
+fn build_pattern(source: &str) -> Result<Regex, regex::Error> {
+    let body = match source.strip_prefix(REGEX_PREFIX) {
+        Some(expression) => expression.to_string(),
+        None => source
+            .split('*')
+            .map(regex::escape)
+            .collect::<Vec<_>>()
+            .join("(.+?)"),
+    };
+    Regex::new(&format!("(?i)^(?:{})$", body))
+}
+
+/// Check that a pattern source compiles, returning the error message if not
+pub fn validate_pattern(source: &str) -> Result<(), String> {
+    if is_pattern(source) {
+        build_pattern(source).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+/// Replace `{n}` (the first capture) and `{1}`..`{9}` in a pattern's target
+fn expand_target(target: &str, captures: &Captures) -> String {
+    let group = |i: usize| captures.get(i).map_or("", |m| m.as_str().trim());
+    let mut expanded = target.replace("{n}", group(1));
+    for i in 1..=9 {
+        let placeholder = format!("{{{}}}", i);
+        if expanded.contains(&placeholder) {
+            expanded = expanded.replace(&placeholder, group(i));
+        }
+    }
+    expanded
+}
+
+/// Apply the first matching pattern mapping to `name`. Longer patterns are
+/// tried first so the most specific rule wins.
+fn match_patterns(name: &str, custom: &HashMap<String, String>) -> Option<String> {
+    let mut patterns: Vec<(&String, &String)> = custom
+        .iter()
+        .filter(|(source, _)| is_pattern(source))
+        .collect();
+    if patterns.is_empty() {
+        return None;
+    }
+    patterns.sort_by(|a, b| b.0.len().cmp(&a.0.len()).then_with(|| a.0.cmp(b.0)));
+
+    let mut cache = PATTERN_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    let segment = name.rsplit('/').next().unwrap_or(name);
+    for (source, target) in patterns {
+        let regex = cache
+            .entry(source.clone())
+            .or_insert_with(|| build_pattern(source).ok());
+        let Some(regex) = regex else {
+            continue;
+        };
+        if let Some(captures) = regex.captures(name).or_else(|| regex.captures(segment)) {
+            return Some(expand_target(target, &captures));
+        }
+    }
+    None
+}
+
 /// Normalize a channel name to its standard form.
 /// Returns the normalized name if a mapping exists, otherwise returns the original name.
 pub fn normalize_channel_name(name: &str) -> String {
@@ -343,6 +605,10 @@ pub fn normalize_channel_name_with_custom(
                 return normalized.clone();
             }
         }
+        // Wildcard and regex rules covering whole channel families
+        if let Some(normalized) = match_patterns(name, custom) {
+            return normalized;
+        }
     }
 
     // Fall back to built-in mappings
@@ -375,13 +641,23 @@ pub fn get_builtin_mappings() -> Vec<(&'static str, Vec<&'static str>)> {
 /// Returns "Normalized (Original)" format when normalization occurs.
 pub fn get_display_name(name: &str, show_original: bool) -> String {
     let normalized = normalize_channel_name(name);
-    if normalized != name && show_original {
-        format!("{} ({})", normalized, name)
+    if show_original {
+        with_original_name(&normalized, name)
     } else {
         normalized
     }
 }
 
+/// A normalized name followed by the ECU's own name in brackets, e.g.
+/// "Engine Speed (RPM Ch1)", or the name alone if normalizing kept it
+pub fn with_original_name(normalized: &str, original: &str) -> String {
+    if normalized == original {
+        original.to_string()
+    } else {
+        format!("{} ({})", normalized, original)
+    }
+}
+
 /// Check if a channel name has a known normalization mapping.
 /// Returns true if the name exists in the normalization mappings (built-in or custom).
 pub fn has_normalization(name: &str, custom_mappings: Option<&HashMap<String, String>>) -> bool {
@@ -399,6 +675,9 @@ pub fn has_normalization(name: &str, custom_mappings: Option<&HashMap<String, St
                 return true;
             }
         }
+        if match_patterns(name, custom).is_some() {
+            return true;
+        }
     }
 
     // Check built-in mappings
@@ -417,6 +696,79 @@ pub fn has_normalization(name: &str, custom_mappings: Option<&HashMap<String, St
     false
 }
 
+/// Write custom mappings as a JSON object of source name to display name,
+/// sorted by source
+pub fn mappings_to_json(mappings: &HashMap<String, String>) -> serde_json::Result<String> {
+    let sorted: BTreeMap<&String, &String> = mappings.iter().collect();
+    serde_json::to_string_pretty(&sorted)
+}
+
+/// Write custom mappings as CSV with a `source,target` header, sorted by source
+pub fn mappings_to_csv(mappings: &HashMap<String, String>) -> String {
+    let sorted: BTreeMap<&String, &String> = mappings.iter().collect();
+    let mut csv = String::from("source,target\n");
+    for (source, target) in sorted {
+        csv.push_str(&format!("{},{}\n", csv_field(source), csv_field(target)));
+    }
+    csv
+}
+
+/// Split one CSV record, honouring quoted fields and doubled quotes
+fn split_csv_record(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// Parse custom mappings exported by [`mappings_to_json`] or
+/// [`mappings_to_csv`]. Source names are keyed with [`mapping_key`] to match
+/// how the editor stores them.
+pub fn parse_mappings(text: &str, is_json: bool) -> Result<HashMap<String, String>, String> {
+    let pairs: Vec<(String, String)> = if is_json {
+        let object: HashMap<String, String> = serde_json::from_str(text)
+            .map_err(|e| format!("Expected an object of source to display names: {}", e))?;
+        object.into_iter().collect()
+    } else {
+        let mut pairs = Vec::new();
+        for (line_number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || (line_number == 0 && line.eq_ignore_ascii_case("source,target")) {
+                continue;
+            }
+            let fields = split_csv_record(line);
+            let [source, target] = fields.as_slice() else {
+                return Err(format!(
+                    "Line {}: expected source,target but found {} fields",
+                    line_number + 1,
+                    fields.len()
+                ));
+            };
+            pairs.push((source.trim().to_string(), target.trim().to_string()));
+        }
+        pairs
+    };
+
+    Ok(pairs
+        .into_iter()
+        .filter(|(source, target)| !source.is_empty() && !target.is_empty())
+        .map(|(source, target)| (mapping_key(&source), target))
+        .collect())
+}
+
 /// Sort channel indices by: normalized fields first, then alphabetically by display name.
 /// Returns a sorted vector of (original_index, display_name, is_normalized).
 pub fn sort_channels_by_priority<F>(
@@ -471,6 +823,38 @@ mod tests {
         assert_eq!(normalize_channel_name("RPM"), "RPM");
         assert_eq!(normalize_channel_name("rpm"), "RPM");
         assert_eq!(normalize_channel_name("Engine RPM4"), "RPM");
+        assert_eq!(normalize_channel_name("Engine RPM"), "RPM");
+        assert_eq!(normalize_channel_name("Engine Speed"), "RPM");
+    }
+
+    #[test]
+    fn test_normalize_fuel_trims() {
+        assert_eq!(
+            normalize_channel_name("O2 Control Bank 1 Short Term Fuel Trim"),
+            "Short Term Trim"
+        );
+        assert_eq!(normalize_channel_name("LTFT: Bank 1"), "Long Term Trim");
+        assert_eq!(normalize_channel_name("Gego"), "EGO Cor 1");
+    }
+
+    #[test]
+    fn test_normalize_boost_control() {
+        assert_eq!(
+            normalize_channel_name("Boost Control Target Pressure"),
+            "Boost Target"
+        );
+        assert_eq!(normalize_channel_name("Target MAP"), "Boost Target");
+        assert_eq!(
+            normalize_channel_name("Boost Control Solenoid Duty Cycle"),
+            "Wastegate Duty"
+        );
+    }
+
+    #[test]
+    fn test_normalize_idle_control() {
+        assert_eq!(normalize_channel_name("Idle Control Output"), "Idle Valve");
+        assert_eq!(normalize_channel_name("IAC value"), "Idle Valve");
+        assert_eq!(normalize_channel_name("Idle: Target RPM"), "Idle Target");
     }
 
     #[test]
@@ -478,6 +862,7 @@ mod tests {
         assert_eq!(normalize_channel_name("TPS"), "TPS");
         assert_eq!(normalize_channel_name("Throttle Position"), "TPS");
         assert_eq!(normalize_channel_name("PedalPos"), "TPS");
+        assert_eq!(normalize_channel_name("Throttle Pos."), "TPS");
     }
 
     #[test]
@@ -491,10 +876,89 @@ mod tests {
         assert_eq!(normalize_channel_name("MyUnknownSensor"), "MyUnknownSensor");
     }
 
+    #[test]
+    fn test_channel_category() {
+        assert_eq!(channel_category(None, "Engine RPM4", None), "Engine");
+        assert_eq!(channel_category(None, "Act_AFR", None), "Fuel");
+        assert_eq!(channel_category(None, "MyUnknownSensor", None), "Other");
+        assert_eq!(
+            channel_category(Some("Sensors".to_string()), "engine/rpm", None),
+            "Sensors"
+        );
+
+        let mut categories: Vec<String> = ["Derived", "Other", "Traction", "Fuel", "Engine"]
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        sort_categories(&mut categories);
+        assert_eq!(
+            categories,
+            vec!["Engine", "Fuel", "Traction", "Other", "Derived"]
+        );
+    }
+
+    #[test]
+    fn test_mappings_round_trip() {
+        let mappings: HashMap<String, String> = [
+            ("sensor_xyz".to_string(), "Custom Sensor".to_string()),
+            ("egt, cyl 1".to_string(), "EGT \"1\"".to_string()),
+        ]
+        .into_iter()
+        .collect();
+
+        let json = mappings_to_json(&mappings).unwrap();
+        assert_eq!(parse_mappings(&json, true).unwrap(), mappings);
+
+        let csv = mappings_to_csv(&mappings);
+        assert!(csv.starts_with("source,target\n"));
+        assert_eq!(parse_mappings(&csv, false).unwrap(), mappings);
+
+        // Sources are lowercased like mappings added in the editor
+        let imported = parse_mappings("MyRPM,RPM\n", false).unwrap();
+        assert_eq!(imported.get("myrpm").map(String::as_str), Some("RPM"));
+
+        assert!(parse_mappings("a,b,c\n", false).is_err());
+        assert!(parse_mappings("[1, 2]", true).is_err());
+    }
+
+    #[test]
+    fn test_pattern_mappings() {
+        let custom: HashMap<String, String> = [
+            (mapping_key("EGT Cyl *"), "EGT {n}".to_string()),
+            (
+                mapping_key(r"re:Knock(\d+)_(\w+)"),
+                "Knock {1} {2}".to_string(),
+            ),
+            ("wb* bank *".to_string(), "AFR {2}".to_string()),
+        ]
+        .into_iter()
+        .collect();
+        let normalize = |name| normalize_channel_name_with_custom(name, Some(&custom));
+
+        assert_eq!(normalize("EGT Cyl 3"), "EGT 3");
+        assert_eq!(normalize("egt cyl 12"), "EGT 12");
+        assert_eq!(normalize("sensors/EGT Cyl 4"), "EGT 4");
+        assert_eq!(normalize("Knock2_Retard"), "Knock 2 Retard");
+        assert_eq!(normalize("WB2 Bank 1"), "AFR 1");
+        assert!(has_normalization("EGT Cyl 1", Some(&custom)));
+
+        // Wildcards match whole names only, and regexes keep their case
+        assert_eq!(normalize("EGT Cyl"), "EGT Cyl");
+        assert!(custom.contains_key(r"re:Knock(\d+)_(\w+)"));
+
+        assert!(validate_pattern("re:(").is_err());
+        assert!(validate_pattern("EGT *").is_ok());
+    }
+
     #[test]
     fn test_display_name() {
         assert_eq!(get_display_name("Act_AFR", true), "AFR (Act_AFR)");
         assert_eq!(get_display_name("AFR", true), "AFR");
         assert_eq!(get_display_name("CustomChannel", true), "CustomChannel");
+        assert_eq!(get_display_name("Act_AFR", false), "AFR");
+        assert_eq!(
+            with_original_name("Engine Speed", "RPM Ch1"),
+            "Engine Speed (RPM Ch1)"
+        );
     }
 }
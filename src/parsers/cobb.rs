@@ -0,0 +1,212 @@
+//! Cobb Accessport datalog parser.
+//!
+//! Parses CSV datalogs recorded by the Accessport. Logs carry an "AP Info:"
+//! field naming the Accessport and vehicle, either on a line of its own at
+//! the top of the file or as the last column of the header row. Channel
+//! names have their units in parentheses, e.g. "Boost (psi)".
+
+use serde::Serialize;
+use std::error::Error;
+use std::io::BufRead;
+
+use super::generic_csv::{GenericCsv, GenericCsvChannel, RowCollector};
+use super::types::{Channel, LineReader, Log, Meta, ParseError, Parseable};
+
+/// Prefix of the Accessport identification field
+const AP_INFO: &str = "AP Info:";
+
+/// Cobb log metadata
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct CobbMeta {
+    /// Accessport and vehicle identification, e.g. "[AP3-SUB-004 v1.7.4.0][USDM WRX]"
+    pub ap_info: String,
+    /// Number of channels in the log
+    pub channel_count: usize,
+    /// Number of data points
+    pub data_points: usize,
+}
+
+/// Cobb Accessport CSV parser
+pub struct Cobb;
+
+impl Cobb {
+    /// Detect if file contents look like an Accessport datalog: the AP Info
+    /// field on the first line or in the header row below it
+    pub fn detect(contents: &str) -> bool {
+        contents
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .take(2)
+            .any(|line| line.contains(AP_INFO))
+    }
+
+    /// Scale from the time column's unit to seconds
+    fn time_scale(unit: &str) -> f64 {
+        match unit.to_lowercase().as_str() {
+            "ms" | "msec" => 0.001,
+            _ => 1.0,
+        }
+    }
+
+    /// Parse a log line-by-line from a reader, calling `progress` with the
+    /// number of bytes consumed so far.
+    pub fn parse_reader<R: BufRead>(
+        &self,
+        reader: R,
+        progress: &dyn Fn(u64),
+    ) -> Result<Log, Box<dyn Error>> {
+        let mut lines = LineReader::new(reader, progress);
+        let mut meta = CobbMeta::default();
+
+        // Header row, after an AP Info line if the log starts with one
+        let mut line_number = 0;
+        let mut header = None;
+        while let Some(line) = lines.next_line()? {
+            line_number += 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(info) = line.strip_prefix(AP_INFO) {
+                meta.ap_info = info.trim().trim_matches(',').to_string();
+                continue;
+            }
+            header = Some(GenericCsv::split_line(line, ','));
+            break;
+        }
+        let Some(mut column_names) = header else {
+            return Err(Box::new(ParseError::at_line(
+                line_number,
+                "Invalid Cobb log: no header row found",
+            )));
+        };
+
+        // The AP Info field ends the header row rather than naming a channel
+        if let Some(pos) = column_names.iter().position(|c| c.starts_with(AP_INFO)) {
+            let info = column_names[pos][AP_INFO.len()..].trim().to_string();
+            if meta.ap_info.is_empty() {
+                meta.ap_info = info;
+            }
+            column_names.truncate(pos);
+        }
+        if column_names.len() < 2 {
+            return Err(Box::new(ParseError::at_line(
+                line_number,
+                "Invalid Cobb log: no channels after the time column",
+            )));
+        }
+
+        // First column is time; the rest are channels
+        let time_scale = Self::time_scale(GenericCsvChannel::from_header(&column_names[0]).unit());
+        let channels: Vec<Channel> = column_names[1..]
+            .iter()
+            .map(|name| Channel::Generic(GenericCsvChannel::from_header(name)))
+            .collect();
+
+        let mut rows = RowCollector::new(channels.len());
+        let mut skipped = 0;
+        while let Some(line) = lines.next_line()? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let parts = GenericCsv::split_line(line, ',');
+            let Some(time) = GenericCsv::parse_value(&parts[0], false) else {
+                skipped += 1;
+                continue;
+            };
+            rows.push(time * time_scale, &parts, false);
+        }
+        let RowCollector { times, data, .. } = rows;
+
+        tracing::info!(
+            "Parsed Cobb log: {} channels, {} data points",
+            channels.len(),
+            times.len()
+        );
+
+        meta.channel_count = channels.len();
+        meta.data_points = times.len();
+        Ok(Log {
+            meta: Meta::Cobb(meta),
+            channels,
+            times,
+            data,
+            lazy_data: None,
+            skipped_records: skipped,
+            markers: Vec::new(),
+        })
+    }
+}
+
+impl Parseable for Cobb {
+    fn parse(&self, file_contents: &str) -> Result<Log, Box<dyn Error>> {
+        self.parse_reader(file_contents.as_bytes(), &|_| {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_cobb() {
+        assert!(Cobb::detect(
+            "AP Info:[AP3-SUB-004 v1.7.4.0][USDM WRX]\nTime (sec),RPM (RPM)\n"
+        ));
+        assert!(Cobb::detect(
+            "Time (sec),RPM (RPM),AP Info:[AP3-SUB-004 v1.7.4.0]\n0.0,800,\n"
+        ));
+        assert!(!Cobb::detect("Offset,Engine RPM\ns,rpm\n"));
+        assert!(!Cobb::detect("%DataLog%\nSomething else"));
+    }
+
+    #[test]
+    fn test_parse_cobb_log() {
+        let sample = "AP Info:[AP3-SUB-004 v1.7.4.0][USDM WRX]\n\
+                      Time (sec),Boost (psi),RPM (RPM),Throttle Pos. (%)\n\
+                      0.00,-10.2,800,1.2\n\
+                      0.05,-9.8,850,\n\
+                      \u{FFFD}\u{FFFD},7\n\
+                      0.10,-9.5,900,2.5\n";
+        let log = Cobb.parse(sample).unwrap();
+
+        assert_eq!(log.channels.len(), 3);
+        assert_eq!(log.channels[0].name(), "Boost");
+        assert_eq!(log.channels[0].unit(), "psi");
+        assert_eq!(log.channels[2].name(), "Throttle Pos.");
+        assert_eq!(log.channels[2].unit(), "%");
+
+        assert_eq!(log.times, vec![0.0, 0.05, 0.1]);
+        assert_eq!(log.get_channel_data(0), &[-10.2, -9.8, -9.5]);
+        // Empty cells hold the previous value
+        assert_eq!(log.get_channel_data(2), &[1.2, 1.2, 2.5]);
+        assert_eq!(log.skipped_records, 1);
+
+        let Meta::Cobb(meta) = &log.meta else {
+            panic!("expected Cobb metadata");
+        };
+        assert_eq!(meta.ap_info, "[AP3-SUB-004 v1.7.4.0][USDM WRX]");
+    }
+
+    #[test]
+    fn test_ap_info_header_column() {
+        let sample = "Time (ms),RPM (RPM),AP Info:[AP3-FOR-003 v1.8.0.0]\n\
+                      0,800,\n\
+                      50,850,\n";
+        let log = Cobb.parse(sample).unwrap();
+
+        // The AP Info column is not a channel, and times are in seconds
+        assert_eq!(log.channels.len(), 1);
+        assert_eq!(log.times, vec![0.0, 0.05]);
+        let Meta::Cobb(meta) = &log.meta else {
+            panic!("expected Cobb metadata");
+        };
+        assert_eq!(meta.ap_info, "[AP3-FOR-003 v1.8.0.0]");
+    }
+
+    #[test]
+    fn test_missing_channels() {
+        assert!(Cobb.parse("AP Info:[AP3]\n").is_err());
+        assert!(Cobb.parse("Time (sec),AP Info:[AP3]\n0.0\n").is_err());
+    }
+}
@@ -0,0 +1,395 @@
+//! CSV logs from tuning tools and stock-ROM loggers that differ only in
+//! their header.
+//!
+//! These logs are a header row of channel names, with units in parentheses
+//! or brackets or in a unit row below, above one row per record. Lines
+//! before the header (tool banners, vehicle details) are skipped.
+//!
+//! Each profile names a tool, the text that identifies its logs and how its
+//! time column is written, so supporting another logger of this kind means
+//! adding a profile.
+
+use serde::Serialize;
+use std::error::Error;
+use std::io::BufRead;
+
+use super::generic_csv::{GenericCsv, GenericCsvChannel, RowCollector};
+use super::types::{Channel, EcuType, LineReader, Log, Meta, ParseError, Parseable};
+
+/// Lines at the start of a file searched for a profile's markers
+const DETECT_LINES: usize = 10;
+
+/// How to recognise and read one tool's CSV logs
+#[derive(Debug)]
+pub struct CsvProfile {
+    /// Tool name, shown in errors and file info
+    pub name: &'static str,
+    /// Format shown for logs from this tool
+    pub ecu_type: EcuType,
+    /// Text found in the first lines of the tool's logs
+    pub markers: &'static [&'static str],
+    /// Channel names that identify the tool only when they are in its
+    /// header row, beside its time column
+    pub header_markers: &'static [&'static str],
+    /// Name the header row's first (time) column starts with
+    pub time_column: &'static str,
+    /// Seconds per unit of the time column when its header gives no unit
+    pub time_scale: f64,
+}
+
+/// Known loggers, checked in order
+pub const PROFILES: &[CsvProfile] = &[
+    CsvProfile {
+        name: "EcuTek ProECU",
+        ecu_type: EcuType::EcuTek,
+        markers: &["EcuTek", "ProECU"],
+        header_markers: &[],
+        time_column: "Time",
+        time_scale: 1.0,
+    },
+    // RomRaider logs Subaru (and Toyota 86) ECUs over SSM. Its logs have no
+    // banner, so they are recognised by SSM parameter names; the time
+    // column is in milliseconds.
+    CsvProfile {
+        name: "RomRaider (Subaru SSM)",
+        ecu_type: EcuType::RomRaider,
+        markers: &[
+            "Manifold Relative Pressure",
+            "Fine Learning Knock Correction",
+            "IAM (",
+            "A/F Sensor #1",
+        ],
+        header_markers: &[],
+        time_column: "Time",
+        time_scale: 0.001,
+    },
+    // Emtron View's eLog export puts units in a row under the names
+    CsvProfile {
+        name: "Emtron",
+        ecu_type: EcuType::Emtron,
+        markers: &["Emtron", "eLog"],
+        header_markers: &[],
+        time_column: "Time",
+        time_scale: 1.0,
+    },
+    // Holley EFI software exports time as "RTC", in seconds. Exports often
+    // carry no banner; an "AFR Left" channel after the RTC column marks them.
+    CsvProfile {
+        name: "Holley EFI",
+        ecu_type: EcuType::Holley,
+        markers: &["Holley", "Sniper"],
+        header_markers: &["AFR Left"],
+        time_column: "RTC",
+        time_scale: 1.0,
+    },
+    CsvProfile {
+        name: "Adaptronic",
+        ecu_type: EcuType::Adaptronic,
+        markers: &["Adaptronic", "Eugene"],
+        header_markers: &[],
+        time_column: "Time",
+        time_scale: 1.0,
+    },
+];
+
+impl CsvProfile {
+    /// The profile whose markers appear in the first lines of a file that
+    /// also has a header row starting with the profile's time column
+    pub fn detect(contents: &str) -> Option<&'static CsvProfile> {
+        let head: Vec<&str> = contents.lines().take(DETECT_LINES).collect();
+        PROFILES.iter().find(|profile| {
+            let marked = head
+                .iter()
+                .any(|line| profile.markers.iter().any(|m| line.contains(m)));
+            head.iter()
+                .any(|line| profile.is_header(line) && (marked || profile.has_header_marker(line)))
+        })
+    }
+
+    /// Whether a header row names one of the profile's header marker channels
+    fn has_header_marker(&self, line: &str) -> bool {
+        GenericCsv::split_line(line, GenericCsv::header_delimiter(line))
+            .iter()
+            .any(|field| {
+                let name = GenericCsvChannel::from_header(field).name;
+                self.header_markers
+                    .iter()
+                    .any(|m| name.eq_ignore_ascii_case(m))
+            })
+    }
+
+    /// Whether a line is this profile's header row
+    fn is_header(&self, line: &str) -> bool {
+        let delimiter = GenericCsv::header_delimiter(line);
+        let fields = GenericCsv::split_line(line, delimiter);
+        fields.len() > 1
+            && fields[0]
+                .to_lowercase()
+                .starts_with(&self.time_column.to_lowercase())
+    }
+
+    /// Seconds per unit of a time column header such as "Time (ms)"
+    fn time_scale(&self, header: &str) -> f64 {
+        match GenericCsvChannel::from_header(header)
+            .unit()
+            .to_lowercase()
+            .as_str()
+        {
+            "s" | "sec" | "secs" | "seconds" => 1.0,
+            "ms" | "msec" => 0.001,
+            _ => self.time_scale,
+        }
+    }
+}
+
+/// Profile CSV log metadata
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ProfileCsvMeta {
+    /// Name of the tool that wrote the log
+    pub tool: String,
+    /// Number of channels in the log
+    pub channel_count: usize,
+    /// Number of data points
+    pub data_points: usize,
+}
+
+/// Parser for a CSV log matching a [`CsvProfile`]
+pub struct ProfileCsv(pub &'static CsvProfile);
+
+impl ProfileCsv {
+    /// Parse a log line-by-line from a reader, calling `progress` with the
+    /// number of bytes consumed so far.
+    pub fn parse_reader<R: BufRead>(
+        &self,
+        reader: R,
+        progress: &dyn Fn(u64),
+    ) -> Result<Log, Box<dyn Error>> {
+        let profile = self.0;
+        let mut lines = LineReader::new(reader, progress);
+
+        // Skip any banner down to the header row
+        let mut line_number = 0;
+        let mut header = None;
+        while let Some(line) = lines.next_line()? {
+            line_number += 1;
+            if profile.is_header(line) {
+                header = Some(line.to_string());
+                break;
+            }
+        }
+        let Some(header) = header else {
+            return Err(Box::new(ParseError::at_line(
+                line_number,
+                format!(
+                    "Invalid {} log: no header row starting with {}",
+                    profile.name, profile.time_column
+                ),
+            )));
+        };
+
+        let delimiter = GenericCsv::header_delimiter(&header);
+        let decimal_comma = delimiter != ',';
+        let column_names = GenericCsv::split_line(&header, delimiter);
+        let time_scale = profile.time_scale(&column_names[0]);
+        let mut channels: Vec<GenericCsvChannel> = column_names[1..]
+            .iter()
+            .map(|name| GenericCsvChannel::from_header(name))
+            .collect();
+
+        let mut rows = RowCollector::new(channels.len());
+        let mut skipped = 0;
+        let mut unit_row_checked = false;
+        while let Some(line) = lines.next_line()? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let parts = GenericCsv::split_line(line, delimiter);
+            // Numeric times are in the column's unit; clock times in seconds
+            let time = GenericCsv::parse_value(&parts[0], decimal_comma)
+                .map(|t| t * time_scale)
+                .or_else(|| GenericCsv::parse_time(&parts[0]));
+            let Some(time) = time else {
+                // A row of units may follow the names
+                if !unit_row_checked && rows.times.is_empty() {
+                    unit_row_checked = true;
+                    for (channel, unit) in channels.iter_mut().zip(parts.iter().skip(1)) {
+                        if !unit.is_empty() {
+                            channel.unit = unit.clone();
+                        }
+                    }
+                    continue;
+                }
+                skipped += 1;
+                continue;
+            };
+            rows.push(time, &parts, decimal_comma);
+        }
+        let RowCollector { times, data, .. } = rows;
+
+        let channels: Vec<Channel> = channels.into_iter().map(Channel::Generic).collect();
+        tracing::info!(
+            "Parsed {} log: {} channels, {} data points",
+            profile.name,
+            channels.len(),
+            times.len()
+        );
+
+        Ok(Log {
+            meta: Meta::Profile(ProfileCsvMeta {
+                tool: profile.name.to_string(),
+                channel_count: channels.len(),
+                data_points: times.len(),
+            }),
+            channels,
+            times,
+            data,
+            lazy_data: None,
+            skipped_records: skipped,
+            markers: Vec::new(),
+        })
+    }
+}
+
+impl Parseable for ProfileCsv {
+    fn parse(&self, file_contents: &str) -> Result<Log, Box<dyn Error>> {
+        self.parse_reader(file_contents.as_bytes(), &|_| {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ECUTEK: &str = "EcuTek ProECU Datalog\n\
+                          Vehicle: Nissan GT-R R35\n\
+                          \n\
+                          Time (s),Engine Speed (rpm),Boost Pressure (psi),Ignition Timing [deg]\n\
+                          0.000,900,-10.0,15\n\
+                          0.100,950,,16\n\
+                          0.200,1000,-9.0,\n";
+
+    const ROMRAIDER: &str =
+        "Time,Engine Speed (rpm),Manifold Relative Pressure (psi),IAM (multiplier)\n\
+                             1000,800,-10.5,1\n\
+                             1050,820,-10.4,1\n";
+
+    #[test]
+    fn test_detect_profiles() {
+        assert_eq!(
+            CsvProfile::detect(ECUTEK).map(|p| p.ecu_type),
+            Some(EcuType::EcuTek)
+        );
+        assert_eq!(
+            CsvProfile::detect(ROMRAIDER).map(|p| p.ecu_type),
+            Some(EcuType::RomRaider)
+        );
+        // A marker without a time header is not enough
+        assert!(CsvProfile::detect("EcuTek ProECU\nRPM,MAP\n").is_none());
+        assert!(CsvProfile::detect("%DataLog%\nSomething else").is_none());
+        assert!(CsvProfile::detect("Time,RPM\n0,800\n").is_none());
+    }
+
+    #[test]
+    fn test_parse_ecutek_log() {
+        let profile = CsvProfile::detect(ECUTEK).unwrap();
+        let log = ProfileCsv(profile).parse(ECUTEK).unwrap();
+
+        assert_eq!(log.channels.len(), 3);
+        assert_eq!(log.channels[0].name(), "Engine Speed");
+        assert_eq!(log.channels[0].unit(), "rpm");
+        assert_eq!(log.channels[2].name(), "Ignition Timing");
+        assert_eq!(log.channels[2].unit(), "deg");
+
+        assert_eq!(log.times, vec![0.0, 0.1, 0.2]);
+        // Empty cells hold the previous value
+        assert_eq!(log.get_channel_data(1), &[-10.0, -10.0, -9.0]);
+        assert_eq!(log.get_channel_data(2), &[15.0, 16.0, 16.0]);
+        assert_eq!(log.skipped_records, 0);
+    }
+
+    #[test]
+    fn test_parse_romraider_log() {
+        let profile = CsvProfile::detect(ROMRAIDER).unwrap();
+        let log = ProfileCsv(profile).parse(ROMRAIDER).unwrap();
+
+        // Time without a unit is in milliseconds
+        assert_eq!(log.times, vec![1.0, 1.05]);
+        assert_eq!(log.channels[1].name(), "Manifold Relative Pressure");
+        assert_eq!(log.channels[2].unit(), "multiplier");
+        let Meta::Profile(meta) = &log.meta else {
+            panic!("expected profile metadata");
+        };
+        assert_eq!(meta.tool, "RomRaider (Subaru SSM)");
+    }
+
+    #[test]
+    fn test_unit_row() {
+        let sample = "Emtron eLog export\n\
+                      Time,Engine Speed,MAP,Lambda 1\n\
+                      s,rpm,kPa,\n\
+                      0.00,900,35,0.98\n\
+                      0.02,950,36,1.01\n";
+        let profile = CsvProfile::detect(sample).unwrap();
+        assert_eq!(profile.ecu_type, EcuType::Emtron);
+        let log = ProfileCsv(profile).parse(sample).unwrap();
+
+        assert_eq!(log.channels[0].unit(), "rpm");
+        assert_eq!(log.channels[1].unit(), "kPa");
+        assert_eq!(log.channels[2].unit(), "");
+        assert_eq!(log.times, vec![0.0, 0.02]);
+        // The unit row is not a damaged record
+        assert_eq!(log.skipped_records, 0);
+    }
+
+    #[test]
+    fn test_adaptronic_channels_normalize() {
+        let sample = "Adaptronic Eugene log\nTime (s),RPM,MAP (kPa),WAT (C),Ign Adv (deg)\n0.0,800,40,82,12\n";
+        let profile = CsvProfile::detect(sample).unwrap();
+        assert_eq!(profile.ecu_type, EcuType::Adaptronic);
+        let log = ProfileCsv(profile).parse(sample).unwrap();
+
+        let names: Vec<String> = log
+            .channels
+            .iter()
+            .map(|c| crate::normalize::normalize_channel_name(&c.name()))
+            .collect();
+        assert_eq!(names, ["RPM", "MAP", "Coolant Temp", "Ignition Adv"]);
+    }
+
+    #[test]
+    fn test_parse_holley_export() {
+        let sample = "RTC,RPM,MAP (kPa),CTS (F),AFR Left,Target AFR\n\
+                      0.000,850,38.5,180,14.6,14.7\n\
+                      0.033,870,38.0,180,14.5,14.7\n";
+        let profile = CsvProfile::detect(sample).unwrap();
+        assert_eq!(profile.ecu_type, EcuType::Holley);
+        let log = ProfileCsv(profile).parse(sample).unwrap();
+
+        // A wideband channel name alone doesn't make another tool's log Holley
+        assert!(CsvProfile::detect("Time,RPM,AFR Left\n0.0,850,14.6\n").is_none());
+        assert!(CsvProfile::detect("Note: AFR Left sensor\nRTC,RPM\n0.0,850\n").is_none());
+
+        assert_eq!(log.channels.len(), 5);
+        assert_eq!(log.channels[2].name(), "CTS");
+        assert_eq!(log.channels[2].unit(), "F");
+        assert_eq!(log.times, vec![0.0, 0.033]);
+        assert_eq!(
+            crate::normalize::normalize_channel_name(&log.channels[3].name()),
+            "AFR"
+        );
+    }
+
+    #[test]
+    fn test_decimal_comma() {
+        let log = ProfileCsv(&PROFILES[0])
+            .parse("ProECU\nTime (s);Boost (bar)\n0,5;1,25\n")
+            .unwrap();
+        assert_eq!(log.times, vec![0.5]);
+        assert_eq!(log.get_channel_data(0), &[1.25]);
+    }
+
+    #[test]
+    fn test_missing_header() {
+        assert!(ProfileCsv(&PROFILES[0]).parse("EcuTek\n1,2\n").is_err());
+    }
+}
@@ -3,13 +3,15 @@
 //! Parses CSV log files exported from ECUMaster EMU Pro ECUs.
 //! Format: Semicolon-delimited CSV with hierarchical channel names.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::error::Error;
+use std::io::BufRead;
 
-use super::types::{Channel, Log, Meta, Parseable, Value};
+use super::types::{Channel, LineReader, Log, Meta, ParseError, Parseable};
 
 /// ECUMaster log file metadata
-#[derive(Clone, Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct EcuMasterMeta {
     /// Number of channels in the log
     pub channel_count: usize,
@@ -18,7 +20,7 @@ pub struct EcuMasterMeta {
 }
 
 /// ECUMaster channel definition
-#[derive(Clone, Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct EcuMasterChannel {
     /// Full channel path (e.g., "engine/rpm")
     pub path: String,
@@ -42,6 +44,15 @@ impl EcuMasterChannel {
         Self { path, name, unit }
     }
 
+    /// Use the user's unit for this channel path if they set one, otherwise
+    /// the inferred unit
+    pub fn apply_unit_override(&mut self, overrides: &HashMap<String, String>) {
+        self.unit = overrides
+            .get(&self.path)
+            .cloned()
+            .unwrap_or_else(|| Self::infer_unit(&self.path, &self.name));
+    }
+
     /// Infer the unit based on channel path and name
     fn infer_unit(path: &str, name: &str) -> String {
         let path_lower = path.to_lowercase();
@@ -98,10 +109,10 @@ impl EcuMasterChannel {
         }
 
         // VVT/Cam angle channels
-        if path_lower.contains("vvt") || path_lower.contains("cam") {
-            if name_lower.contains("angle") || name_lower.contains("position") {
-                return "°".to_string();
-            }
+        if (path_lower.contains("vvt") || path_lower.contains("cam"))
+            && (name_lower.contains("angle") || name_lower.contains("position"))
+        {
+            return "°".to_string();
         }
 
         // Voltage channels
@@ -176,20 +187,24 @@ impl EcuMaster {
     }
 }
 
-impl Parseable for EcuMaster {
-    fn parse(&self, file_contents: &str) -> Result<Log, Box<dyn Error>> {
-        // Pre-allocate based on estimated row count (first line is header)
-        let line_count = file_contents.lines().count();
-        let estimated_data_rows = line_count.saturating_sub(1);
-
+impl EcuMaster {
+    /// Parse a log line-by-line from a reader, calling `progress` with the
+    /// number of bytes consumed so far.
+    pub fn parse_reader<R: BufRead>(
+        &self,
+        reader: R,
+        progress: &dyn Fn(u64),
+    ) -> Result<Log, Box<dyn Error>> {
         let mut channels: Vec<Channel> = Vec::with_capacity(50);
-        let mut times: Vec<f64> = Vec::with_capacity(estimated_data_rows);
-        let mut data: Vec<Vec<Value>> = Vec::with_capacity(estimated_data_rows);
+        let mut times: Vec<f64> = Vec::new();
 
-        let mut lines = file_contents.lines();
+        let mut lines = LineReader::new(reader, progress);
 
         // Parse header line to get channel names
-        let header = lines.next().ok_or("Empty file: no header found")?;
+        let header = lines
+            .next_line()?
+            .ok_or("Empty file: no header found")?
+            .to_string();
 
         // Determine delimiter (semicolon or tab)
         let delimiter = if header.contains(';') { ';' } else { '\t' };
@@ -197,7 +212,10 @@ impl Parseable for EcuMaster {
         let column_names: Vec<&str> = header.split(delimiter).collect();
 
         if column_names.is_empty() || column_names[0].to_uppercase() != "TIME" {
-            return Err("Invalid ECUMaster log: first column must be TIME".into());
+            return Err(Box::new(ParseError::at_line(
+                1,
+                "Invalid ECUMaster log: first column must be TIME",
+            )));
         }
 
         // Create channels from header (skip TIME column)
@@ -206,78 +224,115 @@ impl Parseable for EcuMaster {
             channels.push(Channel::EcuMaster(channel));
         }
 
+        let mut data: Vec<Vec<f64>> = vec![Vec::new(); channels.len()];
+
         // Track last known values for sparse data interpolation
         let mut last_values: Vec<Option<f64>> = vec![None; channels.len()];
 
-        // Parse data rows
-        for line in lines {
-            let line = line.trim();
-            if line.is_empty() {
-                continue;
-            }
-
-            let parts: Vec<&str> = line.split(delimiter).collect();
-            if parts.is_empty() {
-                continue;
-            }
-
-            // First column is time (already in seconds)
-            let time_str = parts[0].trim();
-            if let Ok(time_val) = time_str.parse::<f64>() {
-                times.push(time_val);
-
-                // Parse remaining values (may be sparse/empty)
-                let mut row_values: Vec<Value> = Vec::with_capacity(channels.len());
-
-                for (idx, part) in parts.iter().skip(1).enumerate() {
-                    let part = part.trim();
-
-                    if part.is_empty() {
-                        // Empty value - use last known value or 0
-                        let value = last_values.get(idx).and_then(|v| *v).unwrap_or(0.0);
-                        row_values.push(Value::Float(value));
-                    } else if let Ok(val) = part.parse::<f64>() {
-                        // Valid numeric value
-                        if idx < last_values.len() {
-                            last_values[idx] = Some(val);
-                        }
-                        row_values.push(Value::Float(val));
-                    } else {
-                        // Non-numeric value - use last known or 0
-                        let value = last_values.get(idx).and_then(|v| *v).unwrap_or(0.0);
-                        row_values.push(Value::Float(value));
-                    }
-                }
-
-                // Pad row to match channel count if needed
-                while row_values.len() < channels.len() {
-                    let idx = row_values.len();
-                    let value = last_values.get(idx).and_then(|v| *v).unwrap_or(0.0);
-                    row_values.push(Value::Float(value));
-                }
-
-                data.push(row_values);
+        // Parse data rows, skipping damaged ones
+        let mut skipped = 0;
+        while let Some(line) = lines.next_line()? {
+            if !Self::push_line(line, delimiter, &mut times, &mut data, &mut last_values) {
+                skipped += 1;
             }
         }
 
         tracing::info!(
             "Parsed ECUMaster log: {} channels, {} data points",
             channels.len(),
-            data.len()
+            times.len()
         );
 
         Ok(Log {
             meta: Meta::EcuMaster(EcuMasterMeta {
                 channel_count: channels.len(),
-                data_points: data.len(),
+                data_points: times.len(),
             }),
             channels,
             times,
             data,
+            lazy_data: None,
+            skipped_records: skipped,
+            markers: Vec::new(),
         })
     }
 }
 
+impl EcuMaster {
+    /// Append data lines written after the log was loaded (follow mode).
+    /// Returns the number of records added.
+    pub fn append_lines(log: &mut Log, lines: &[String]) -> usize {
+        // Empty cells continue from the last loaded value of each channel
+        let mut last_values: Vec<Option<f64>> = log
+            .data
+            .iter()
+            .map(|column| column.last().copied())
+            .collect();
+        let before = log.times.len();
+        for line in lines {
+            let delimiter = if line.contains(';') { ';' } else { '\t' };
+            Self::push_line(
+                line,
+                delimiter,
+                &mut log.times,
+                &mut log.data,
+                &mut last_values,
+            );
+        }
+
+        let added = log.times.len() - before;
+        if let Meta::EcuMaster(meta) = &mut log.meta {
+            meta.data_points += added;
+        }
+        added
+    }
+
+    /// Parse one data line onto the time and channel columns, ignoring lines
+    /// without a numeric time. Returns false if a non-empty line was skipped.
+    fn push_line(
+        line: &str,
+        delimiter: char,
+        times: &mut Vec<f64>,
+        data: &mut [Vec<f64>],
+        last_values: &mut [Option<f64>],
+    ) -> bool {
+        let line = line.trim();
+        if line.is_empty() {
+            return true;
+        }
+
+        let parts: Vec<&str> = line.split(delimiter).collect();
+
+        // First column is time (already in seconds)
+        let Ok(time_val) = parts[0].trim().parse::<f64>() else {
+            return false;
+        };
+        times.push(time_val);
+
+        // Parse remaining values (may be sparse/empty). Columns missing
+        // from a short row are padded with the last known value.
+        for (idx, column) in data.iter_mut().enumerate() {
+            let part = parts.get(idx + 1).map_or("", |p| p.trim());
+
+            if let Ok(val) = part.parse::<f64>() {
+                // Valid numeric value
+                last_values[idx] = Some(val);
+                column.push(val);
+            } else {
+                // Empty or non-numeric value - use last known value or 0
+                column.push(last_values[idx].unwrap_or(0.0));
+            }
+        }
+        true
+    }
+}
+
+impl Parseable for EcuMaster {
+    fn parse(&self, file_contents: &str) -> Result<Log, Box<dyn Error>> {
+        self.parse_reader(file_contents.as_bytes(), &|_| {})
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -326,17 +381,17 @@ mod tests {
         assert_eq!(log.channels[2].name(), "angle");
 
         assert_eq!(log.times.len(), 3);
-        assert_eq!(log.data.len(), 3);
+        assert_eq!(log.record_count(), 3);
 
         // Check first row
-        assert_eq!(log.data[0][0].as_f64(), 1000.0);
-        assert_eq!(log.data[0][1].as_f64(), 10.5);
-        assert_eq!(log.data[0][2].as_f64(), 15.0);
+        assert_eq!(log.value_at(0, 0), Some(1000.0));
+        assert_eq!(log.value_at(1, 0), Some(10.5));
+        assert_eq!(log.value_at(2, 0), Some(15.0));
 
         // Check sparse data handling (empty values use previous)
-        assert_eq!(log.data[1][0].as_f64(), 1050.0);
-        assert_eq!(log.data[1][1].as_f64(), 10.5); // Previous value
-        assert_eq!(log.data[1][2].as_f64(), 15.5);
+        assert_eq!(log.value_at(0, 1), Some(1050.0));
+        assert_eq!(log.value_at(1, 1), Some(10.5)); // Previous value
+        assert_eq!(log.value_at(2, 1), Some(15.5));
 
         // Check units
         assert_eq!(log.channels[0].unit(), "RPM");
@@ -344,6 +399,62 @@ mod tests {
         assert_eq!(log.channels[2].unit(), "°");
     }
 
+    #[test]
+    fn test_damaged_rows_skipped() {
+        let sample = "TIME;engine/rpm\n0.000;1000\n\u{FFFD}\u{FFFD};7\n\n0.040;1100\nTI";
+        let log = EcuMaster.parse(sample).unwrap();
+        assert_eq!(log.times, vec![0.0, 0.04]);
+        assert_eq!(log.get_channel_data(0), &[1000.0, 1100.0]);
+        assert_eq!(log.skipped_records, 2);
+    }
+
+    #[test]
+    fn test_parse_reader_crlf_and_progress() {
+        let sample = "TIME;engine/rpm\r\n0.000;1000\r\n0.020;1100\r\n";
+
+        let reported = std::cell::Cell::new(0u64);
+        let log = EcuMaster
+            .parse_reader(sample.as_bytes(), &|bytes| reported.set(bytes))
+            .unwrap();
+
+        assert_eq!(log.channels[0].name(), "rpm");
+        assert_eq!(log.record_count(), 2);
+        assert_eq!(log.get_channel_data(0), &[1000.0, 1100.0]);
+        assert_eq!(reported.get(), sample.len() as u64);
+    }
+
+    #[test]
+    fn test_append_lines() {
+        let mut log = EcuMaster
+            .parse("TIME;engine/rpm;tps1\n0.000;1000;10.5\n")
+            .unwrap();
+
+        let lines = vec!["0.020;;11.0".to_string(), "0.040;1100;".to_string()];
+        assert_eq!(log.append_lines(&lines), 2);
+        assert_eq!(log.times, vec![0.0, 0.02, 0.04]);
+        // Empty cells continue from the last value, including loaded records
+        assert_eq!(log.get_channel_data(0), &[1000.0, 1000.0, 1100.0]);
+        assert_eq!(log.get_channel_data(1), &[10.5, 11.0, 11.0]);
+    }
+
+    #[test]
+    fn test_unit_override() {
+        let mut channel = EcuMasterChannel::from_path("CAN/customInput1");
+        let overrides: HashMap<String, String> =
+            [("CAN/customInput1".to_string(), "kPa".to_string())]
+                .into_iter()
+                .collect();
+        channel.apply_unit_override(&overrides);
+        assert_eq!(channel.unit, "kPa");
+
+        // Removing the override restores the inferred unit
+        channel.apply_unit_override(&HashMap::new());
+        assert_eq!(
+            channel.unit,
+            EcuMasterChannel::infer_unit(&channel.path, &channel.name)
+        );
+    }
+
     #[test]
     fn test_unit_inference() {
         // Temperature
@@ -0,0 +1,469 @@
+//! Generic delimited-text log parser.
+//!
+//! Fallback parser for CSV-like logs that none of the ECU-specific parsers
+//! recognise. The delimiter, header line and time column are chosen by the
+//! user (see the unsupported file triage dialog); every other column becomes
+//! a channel.
+
+use serde::Serialize;
+use std::error::Error;
+
+use super::types::{Channel, Log, Meta, ParseError, Parseable};
+
+/// Delimiters offered when configuring the generic parser
+pub const DELIMITERS: &[(char, &str)] = &[
+    (',', "Comma"),
+    (';', "Semicolon"),
+    ('\t', "Tab"),
+    ('|', "Pipe"),
+    (' ', "Space"),
+];
+
+/// Generic CSV log metadata
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct GenericCsvMeta {
+    /// Name of the column used as the time axis
+    pub time_column: String,
+    /// Number of channels in the log
+    pub channel_count: usize,
+    /// Number of data points
+    pub data_points: usize,
+    /// Parser settings the log was loaded with
+    #[serde(skip)]
+    pub parser: GenericCsv,
+}
+
+/// Generic CSV channel definition
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct GenericCsvChannel {
+    /// Column name with any unit suffix removed
+    pub name: String,
+    /// Unit parsed from the column header (e.g. "RPM [rpm]" or "MAP (kPa)")
+    pub unit: String,
+}
+
+impl GenericCsvChannel {
+    /// Create a channel from a raw column header
+    pub fn from_header(header: &str) -> Self {
+        let header = header.trim();
+
+        for (open, close) in [('[', ']'), ('(', ')')] {
+            if let (Some(start), true) = (header.rfind(open), header.ends_with(close)) {
+                let name = header[..start].trim();
+                if !name.is_empty() {
+                    return Self {
+                        name: name.to_string(),
+                        unit: header[start + 1..header.len() - 1].trim().to_string(),
+                    };
+                }
+            }
+        }
+
+        Self {
+            name: header.to_string(),
+            unit: String::new(),
+        }
+    }
+
+    /// Get the display unit for this channel
+    pub fn unit(&self) -> &str {
+        &self.unit
+    }
+}
+
+/// Generic delimited-text parser
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GenericCsv {
+    /// Column delimiter
+    pub delimiter: char,
+    /// Zero-based index of the line containing column names
+    pub header_line: usize,
+    /// Zero-based index of the time column
+    pub time_column: usize,
+}
+
+impl Default for GenericCsv {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            header_line: 0,
+            time_column: 0,
+        }
+    }
+}
+
+impl GenericCsv {
+    /// Guess the delimiter by counting candidate characters in the first non-empty line
+    pub fn guess_delimiter(contents: &str) -> char {
+        let first_line = contents
+            .lines()
+            .find(|l| !l.trim().is_empty())
+            .unwrap_or("");
+
+        DELIMITERS
+            .iter()
+            .map(|(d, _)| (*d, first_line.matches(*d).count()))
+            .filter(|(_, count)| *count > 0)
+            .max_by_key(|(_, count)| *count)
+            .map(|(d, _)| d)
+            .unwrap_or(',')
+    }
+
+    /// Split a line into trimmed, unquoted fields
+    pub fn split_line(line: &str, delimiter: char) -> Vec<String> {
+        line.split(delimiter)
+            .map(|field| field.trim().trim_matches('"').trim().to_string())
+            .collect()
+    }
+
+    /// Delimiter of a tool's CSV export, from its header row: semicolon or
+    /// tab where present, otherwise comma. Exports that aren't
+    /// comma-delimited are written with a decimal comma.
+    pub fn header_delimiter(line: &str) -> char {
+        if line.contains(';') {
+            ';'
+        } else if line.contains('\t') {
+            '\t'
+        } else {
+            ','
+        }
+    }
+
+    /// Parse a number, reading a comma as the decimal point when the log
+    /// was written with a decimal comma
+    pub fn parse_value(value: &str, decimal_comma: bool) -> Option<f64> {
+        let value = value.trim();
+        if decimal_comma {
+            value.replace(',', ".").parse().ok()
+        } else {
+            value.parse().ok()
+        }
+    }
+
+    /// Parse a time value in seconds, or in HH:MM:SS(.fff) / MM:SS(.fff) form
+    pub fn parse_time(value: &str) -> Option<f64> {
+        let value = value.trim().trim_matches('"');
+        if let Ok(seconds) = value.parse::<f64>() {
+            return Some(seconds);
+        }
+
+        let parts: Vec<&str> = value.split(':').collect();
+        if parts.len() < 2 || parts.len() > 3 {
+            return None;
+        }
+
+        let mut total = 0.0;
+        for part in &parts {
+            total = total * 60.0 + part.parse::<f64>().ok()?;
+        }
+        Some(total)
+    }
+}
+
+/// Time and channel columns of a tool's CSV export whose first column is
+/// time and the rest are channels in header order
+pub struct RowCollector {
+    pub times: Vec<f64>,
+    pub data: Vec<Vec<f64>>,
+    last_values: Vec<f64>,
+}
+
+impl RowCollector {
+    pub fn new(channel_count: usize) -> Self {
+        Self {
+            times: Vec::new(),
+            data: vec![Vec::new(); channel_count],
+            last_values: vec![0.0; channel_count],
+        }
+    }
+
+    /// Add a record at `time` from a row's fields, the first of which is
+    /// the time. Missing or non-numeric cells hold the channel's last value.
+    pub fn push<S: AsRef<str>>(&mut self, time: f64, fields: &[S], decimal_comma: bool) {
+        self.times.push(time);
+        for (idx, column) in self.data.iter_mut().enumerate() {
+            if let Some(v) = fields
+                .get(idx + 1)
+                .and_then(|p| GenericCsv::parse_value(p.as_ref(), decimal_comma))
+            {
+                self.last_values[idx] = v;
+            }
+            column.push(self.last_values[idx]);
+        }
+    }
+}
+
+impl GenericCsv {
+    /// Append data lines written after the log was loaded (follow mode).
+    /// Returns the number of records added.
+    pub fn append_lines(log: &mut Log, lines: &[String]) -> usize {
+        let Meta::Generic(meta) = &log.meta else {
+            return 0;
+        };
+        let parser = meta.parser;
+
+        // Every column other than time is a channel, in order
+        let channel_columns: Vec<usize> = (0..=log.channels.len())
+            .filter(|&i| i != parser.time_column)
+            .collect();
+        // Empty cells continue from the last loaded value of each channel
+        let mut last_values: Vec<f64> = log
+            .data
+            .iter()
+            .map(|column| column.last().copied().unwrap_or(0.0))
+            .collect();
+
+        let before = log.times.len();
+        for line in lines {
+            parser.push_line(
+                line,
+                &channel_columns,
+                &mut log.times,
+                &mut log.data,
+                &mut last_values,
+            );
+        }
+
+        let added = log.times.len() - before;
+        if let Meta::Generic(meta) = &mut log.meta {
+            meta.data_points += added;
+        }
+        added
+    }
+
+    /// Parse one line onto the time and channel columns, skipping unit rows,
+    /// comments and other lines without a valid time. Returns false if a
+    /// non-empty line was skipped.
+    fn push_line(
+        &self,
+        line: &str,
+        channel_columns: &[usize],
+        times: &mut Vec<f64>,
+        data: &mut [Vec<f64>],
+        last_values: &mut [f64],
+    ) -> bool {
+        if line.trim().is_empty() {
+            return true;
+        }
+
+        let parts = Self::split_line(line, self.delimiter);
+        let Some(time) = parts
+            .get(self.time_column)
+            .and_then(|t| Self::parse_time(t))
+        else {
+            return false;
+        };
+
+        for (channel_idx, &column) in channel_columns.iter().enumerate() {
+            // Missing or non-numeric cells hold the last known value
+            if let Some(v) = parts.get(column).and_then(|p| p.parse::<f64>().ok()) {
+                last_values[channel_idx] = v;
+            }
+            data[channel_idx].push(last_values[channel_idx]);
+        }
+        times.push(time);
+        true
+    }
+}
+
+impl Parseable for GenericCsv {
+    fn parse(&self, file_contents: &str) -> Result<Log, Box<dyn Error>> {
+        let mut lines = file_contents.lines().skip(self.header_line);
+
+        let header = lines
+            .next()
+            .ok_or("Header line is past the end of the file")?;
+        let column_names = Self::split_line(header, self.delimiter);
+
+        if self.time_column >= column_names.len() {
+            return Err(Box::new(ParseError::at_line(
+                self.header_line + 1,
+                format!(
+                    "Time column {} does not exist (header has {} columns)",
+                    self.time_column + 1,
+                    column_names.len()
+                ),
+            )));
+        }
+
+        // Every column other than time becomes a channel
+        let channel_columns: Vec<usize> = (0..column_names.len())
+            .filter(|&i| i != self.time_column)
+            .collect();
+        let channels: Vec<Channel> = channel_columns
+            .iter()
+            .map(|&i| Channel::Generic(GenericCsvChannel::from_header(&column_names[i])))
+            .collect();
+
+        let mut times: Vec<f64> = Vec::new();
+        let mut data: Vec<Vec<f64>> = vec![Vec::new(); channels.len()];
+        let mut last_values: Vec<f64> = vec![0.0; channels.len()];
+
+        let mut skipped = 0;
+        for line in lines {
+            let recorded = self.push_line(
+                line,
+                &channel_columns,
+                &mut times,
+                &mut data,
+                &mut last_values,
+            );
+            // Unit rows and comments come before the data; past the first
+            // record, a line without a valid time is damaged
+            if !recorded && !times.is_empty() {
+                skipped += 1;
+            }
+        }
+
+        if times.is_empty() {
+            return Err("No data rows found with a valid time value".into());
+        }
+
+        tracing::info!(
+            "Parsed generic CSV log: {} channels, {} data points",
+            channels.len(),
+            times.len()
+        );
+
+        Ok(Log {
+            meta: Meta::Generic(GenericCsvMeta {
+                time_column: column_names[self.time_column].clone(),
+                channel_count: channels.len(),
+                data_points: times.len(),
+                parser: *self,
+            }),
+            channels,
+            times,
+            data,
+            lazy_data: None,
+            skipped_records: skipped,
+            markers: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guess_delimiter() {
+        assert_eq!(GenericCsv::guess_delimiter("time,rpm,tps\n0,1,2"), ',');
+        assert_eq!(GenericCsv::guess_delimiter("time;rpm;tps\n0;1;2"), ';');
+        assert_eq!(GenericCsv::guess_delimiter("time\trpm\ttps\n0\t1\t2"), '\t');
+        assert_eq!(GenericCsv::guess_delimiter(""), ',');
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(GenericCsv::parse_time("1.5"), Some(1.5));
+        assert_eq!(GenericCsv::parse_time("01:30"), Some(90.0));
+        assert_eq!(GenericCsv::parse_time("01:00:01.5"), Some(3601.5));
+        assert_eq!(GenericCsv::parse_time("Time"), None);
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(GenericCsv::parse_value(" 1.5 ", false), Some(1.5));
+        assert_eq!(GenericCsv::parse_value("1,5", true), Some(1.5));
+        assert_eq!(GenericCsv::parse_value("1,5", false), None);
+        assert_eq!(GenericCsv::parse_value("", true), None);
+    }
+
+    #[test]
+    fn test_row_collector() {
+        let mut rows = RowCollector::new(2);
+        rows.push(0.0, &["0", "800", "1,5"], true);
+        rows.push(0.1, &["0,1", "", "x"], true);
+        rows.push(0.2, &["0,2", "900"], true);
+
+        assert_eq!(rows.times, vec![0.0, 0.1, 0.2]);
+        // Empty, unreadable and missing cells hold the last value
+        assert_eq!(rows.data[0], vec![800.0, 800.0, 900.0]);
+        assert_eq!(rows.data[1], vec![1.5, 1.5, 1.5]);
+    }
+
+    #[test]
+    fn test_channel_from_header() {
+        let ch = GenericCsvChannel::from_header("Engine Speed [rpm]");
+        assert_eq!(ch.name, "Engine Speed");
+        assert_eq!(ch.unit, "rpm");
+
+        let ch = GenericCsvChannel::from_header("MAP (kPa)");
+        assert_eq!(ch.name, "MAP");
+        assert_eq!(ch.unit, "kPa");
+
+        let ch = GenericCsvChannel::from_header("TPS");
+        assert_eq!(ch.name, "TPS");
+        assert_eq!(ch.unit, "");
+    }
+
+    #[test]
+    fn test_parse_generic_csv() {
+        let sample = "Logged by SomeTool\n\
+                      RPM,Time (s),\"TPS (%)\"\n\
+                      rpm,s,%\n\
+                      1000,0.00,10\n\
+                      1100,0.05,\n\
+                      1200,0.10,12\n";
+
+        let parser = GenericCsv {
+            delimiter: ',',
+            header_line: 1,
+            time_column: 1,
+        };
+        let log = parser.parse(sample).unwrap();
+
+        assert_eq!(log.channels.len(), 2);
+        assert_eq!(log.channels[0].name(), "RPM");
+        assert_eq!(log.channels[1].name(), "TPS");
+        assert_eq!(log.channels[1].unit(), "%");
+
+        // Unit row is skipped because its time cell isn't numeric
+        assert_eq!(log.times, vec![0.0, 0.05, 0.10]);
+        assert_eq!(log.skipped_records, 0);
+        assert_eq!(log.get_channel_data(0)[0], 1000.0);
+        // Empty cell holds the previous value
+        assert_eq!(log.get_channel_data(1), &[10.0, 10.0, 12.0]);
+    }
+
+    #[test]
+    fn test_damaged_rows_skipped() {
+        let parser = GenericCsv {
+            delimiter: ',',
+            header_line: 0,
+            time_column: 0,
+        };
+        let log = parser
+            .parse("Time,RPM\n0.0,1000\n\u{0}\u{0}\u{0}\n#@!,5\n0.2,1200\n\n")
+            .unwrap();
+        assert_eq!(log.times, vec![0.0, 0.2]);
+        assert_eq!(log.skipped_records, 2);
+    }
+
+    #[test]
+    fn test_invalid_time_column() {
+        let parser = GenericCsv {
+            delimiter: ',',
+            header_line: 0,
+            time_column: 5,
+        };
+        assert!(parser.parse("a,b\n1,2\n").is_err());
+    }
+
+    #[test]
+    fn test_append_lines() {
+        let parser = GenericCsv {
+            delimiter: ',',
+            header_line: 0,
+            time_column: 1,
+        };
+        let mut log = parser.parse("rpm,time,tps\n1000,0.0,10\n").unwrap();
+
+        let lines = vec!["1100,0.1,".to_string(), "units,s,%".to_string()];
+        assert_eq!(log.append_lines(&lines), 1);
+        assert_eq!(log.times, vec![0.0, 0.1]);
+        assert_eq!(log.get_channel_data(0), &[1000.0, 1100.0]);
+        assert_eq!(log.get_channel_data(1), &[10.0, 10.0]);
+    }
+}
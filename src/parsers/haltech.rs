@@ -1,19 +1,21 @@
 use rayon::prelude::*;
 use regex::Regex;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::error::Error;
+use std::io::BufRead;
 use std::str::FromStr;
 use std::sync::LazyLock;
 use strum::{AsRefStr, EnumString};
 
-/// Pre-compiled regex for detecting data rows (timestamp pattern)
-static TIMESTAMP_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\d{1,2}:\d{2}:\d{2}").expect("Invalid timestamp regex"));
+/// Pre-compiled regex for header key-value pairs like "Key : Value"
+static KV_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?<name>[^:]+?)\s*:\s*(?<value>.+)$").expect("Invalid key-value regex")
+});
 
-use super::types::{Channel, Log, Meta, Parseable, Value};
+use super::types::{Channel, LineReader, Log, Meta, Parseable};
 
 /// Haltech channel types - comprehensive list from actual log files
-#[derive(AsRefStr, Clone, Debug, EnumString, Serialize, Default)]
+#[derive(AsRefStr, Clone, Debug, EnumString, Serialize, Deserialize, Default)]
 pub enum ChannelType {
     AFR,
     AbsPressure,
@@ -231,7 +233,7 @@ impl ChannelType {
 }
 
 /// Haltech log file metadata
-#[derive(Clone, Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct HaltechMeta {
     pub data_log_version: String,
     pub software: String,
@@ -240,10 +242,40 @@ pub struct HaltechMeta {
     pub log_source: String,
     pub log_number: String,
     pub log_date_time: String,
+    /// Time of day of the first record (seconds); record times are relative to it
+    pub first_timestamp: f64,
+    /// Logging sessions of a multi-log export, in order. Split into logs of
+    /// their own by [`Log::split_runs`].
+    #[serde(default)]
+    pub runs: Vec<HaltechRun>,
+}
+
+impl HaltechMeta {
+    /// Short description of the session, e.g. "Log 1118, 20250718 02:15:46"
+    pub fn run_label(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.log_number.is_empty() {
+            parts.push(format!("Log {}", self.log_number));
+        }
+        if !self.log_date_time.is_empty() {
+            parts.push(self.log_date_time.clone());
+        }
+        parts.join(", ")
+    }
+}
+
+/// One logging session of a multi-log export, started by `Log Number :` or
+/// `Log :` lines between the data rows
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct HaltechRun {
+    pub log_number: String,
+    pub log_date_time: String,
+    /// Index of the session's first record
+    pub start: usize,
 }
 
 /// Haltech channel definition
-#[derive(Clone, Debug, Default, Serialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct HaltechChannel {
     pub name: String,
     pub id: String,
@@ -266,41 +298,112 @@ impl Haltech {
     /// Parse timestamp from HH:MM:SS.mmm format to seconds
     fn parse_timestamp(timestamp: &str) -> Option<f64> {
         // Format: "HH:MM:SS.mmm" e.g., "14:15:46.000"
-        let parts: Vec<&str> = timestamp.split(':').collect();
-        if parts.len() != 3 {
-            return None;
-        }
-
-        let hours: f64 = parts[0].parse().ok()?;
-        let minutes: f64 = parts[1].parse().ok()?;
+        let mut parts = timestamp.splitn(3, ':');
+        let hours = Self::parse_value(parts.next()?)?;
+        let minutes = Self::parse_value(parts.next()?)?;
 
         // Seconds may include milliseconds
-        let seconds: f64 = parts[2].parse().ok()?;
+        let seconds = Self::parse_value(parts.next()?)?;
 
         Some(hours * 3600.0 + minutes * 60.0 + seconds)
     }
 
-    /// Check if a line looks like a data row (starts with timestamp)
+    /// Check if a line looks like a data row (starts with an H:MM:SS or
+    /// HH:MM:SS timestamp)
     fn is_data_row(line: &str) -> bool {
-        // Data rows start with HH:MM:SS pattern - uses pre-compiled static regex
-        TIMESTAMP_REGEX.is_match(line)
+        let bytes = line.as_bytes();
+        let hours = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+        (1..=2).contains(&hours)
+            && matches!(
+                bytes.get(hours..hours + 6),
+                Some([b':', m1, m2, b':', s1, s2])
+                    if [m1, m2, s1, s2].iter().all(|b| b.is_ascii_digit())
+            )
+    }
+
+    /// Parse one field. Haltech logs raw integers and short decimals, which
+    /// are read digit by digit: with at most 15 digits both the digits and
+    /// the power of ten are exact in an f64, so one division rounds exactly
+    /// like the float parser. Anything else falls back to the float parser.
+    fn parse_value(field: &str) -> Option<f64> {
+        const POW10: [f64; 16] = [
+            1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15,
+        ];
+        let (negative, bytes) = match field.as_bytes() {
+            [b'-', rest @ ..] => (true, rest),
+            bytes => (false, bytes),
+        };
+
+        let mut mantissa: i64 = 0;
+        let mut digits = 0;
+        let mut decimals: Option<usize> = None;
+        for &b in bytes {
+            match b {
+                b'0'..=b'9' if digits < 15 => {
+                    mantissa = mantissa * 10 + i64::from(b - b'0');
+                    digits += 1;
+                    if let Some(decimals) = &mut decimals {
+                        *decimals += 1;
+                    }
+                }
+                b'.' if decimals.is_none() => decimals = Some(0),
+                _ => return field.parse().ok(),
+            }
+        }
+        if digits == 0 {
+            return field.parse().ok();
+        }
+
+        let value = mantissa as f64 / POW10[decimals.unwrap_or(0)];
+        Some(if negative { -value } else { value })
+    }
+
+    /// Split a data row at commas. Scanning the bytes directly beats
+    /// `str::split` on the short fields of a data row.
+    fn fields(line: &str) -> impl Iterator<Item = &str> {
+        let mut rest = Some(line);
+        std::iter::from_fn(move || {
+            let current = rest?;
+            match current.bytes().position(|b| b == b',') {
+                Some(i) => {
+                    rest = Some(&current[i + 1..]);
+                    Some(&current[..i])
+                }
+                None => {
+                    rest = None;
+                    Some(current)
+                }
+            }
+        })
     }
 }
 
-impl Parseable for Haltech {
-    fn parse(&self, file_contents: &str) -> Result<Log, Box<dyn Error>> {
+impl Haltech {
+    /// Bytes of data lines read and parsed together in one parallel batch
+    const DATA_BATCH_BYTES: usize = 1024 * 1024;
+
+    /// Number of data lines each parallel task of a batch parses
+    const LINES_PER_TASK: usize = 1024;
+
+    /// Parse a log line-by-line from a reader, calling `progress` with the
+    /// number of bytes consumed so far. Data rows are read in blocks and
+    /// parsed in parallel batches, so the whole file never has to be held in
+    /// memory as text. Each row's values go straight into a flat buffer
+    /// rather than a vector per row.
+    pub fn parse_reader<R: BufRead>(
+        &self,
+        reader: R,
+        progress: &dyn Fn(u64),
+    ) -> Result<Log, Box<dyn Error>> {
         let mut meta = HaltechMeta::default();
         let mut channels: Vec<Channel> = Vec::with_capacity(50); // Typical log has ~20-50 channels
 
-        // Regex for key-value pairs like "Key : Value"
-        let kv_regex =
-            Regex::new(r"^(?<name>[^:]+?)\s*:\s*(?<value>.+)$").expect("Failed to compile regex");
-
         let mut current_channel = HaltechChannel::default();
-        let mut data_lines: Vec<&str> = Vec::new();
+        let mut first_row: Option<String> = None;
 
-        // Phase 1: Parse metadata and channels, collect data lines
-        for line in file_contents.lines() {
+        // Header: metadata and channel definitions, up to the first data row
+        let mut lines = LineReader::new(reader, progress);
+        while let Some(line) = lines.next_line()? {
             let line = line.trim();
 
             // Skip empty lines and header marker
@@ -308,147 +411,351 @@ impl Parseable for Haltech {
                 continue;
             }
 
-            // Check if this is a data row
+            // Data rows start once the channel definitions end
             if Self::is_data_row(line) {
-                // Push any pending channel before collecting data lines
-                if !current_channel.name.is_empty() {
-                    channels.push(Channel::Haltech(current_channel));
-                    current_channel = HaltechChannel::default();
-                }
-
-                // Collect data line for parallel processing
-                data_lines.push(line);
-                continue;
+                first_row = Some(line.to_string());
+                break;
             }
 
-            // Not in data section yet - parse metadata and channel definitions
-            if data_lines.is_empty() {
-                if let Some(captures) = kv_regex.captures(line) {
-                    let name = captures["name"].trim();
-                    let value = captures["value"].trim().to_string();
-
-                    match name {
-                        "DataLogVersion" => meta.data_log_version = value,
-                        "Software" => meta.software = value,
-                        "SoftwareVersion" => meta.software_version = value,
-                        "DownloadDateTime" | "DownloadDate/Time" => meta.download_date_time = value,
-                        "Log Source" => meta.log_source = value,
-                        "Log Number" => meta.log_number = value,
-                        "Log" => meta.log_date_time = value,
-                        // "Channel" key indicates start of a new channel definition
-                        "Channel" => {
-                            if !current_channel.name.is_empty() {
-                                channels.push(Channel::Haltech(current_channel));
-                            }
-                            current_channel = HaltechChannel::default();
-                            current_channel.name = value;
+            if let Some(captures) = KV_REGEX.captures(line) {
+                let name = captures["name"].trim();
+                let value = captures["value"].trim().to_string();
+
+                match name {
+                    "DataLogVersion" => meta.data_log_version = value,
+                    "Software" => meta.software = value,
+                    "SoftwareVersion" => meta.software_version = value,
+                    "DownloadDateTime" | "DownloadDate/Time" => meta.download_date_time = value,
+                    "Log Source" => meta.log_source = value,
+                    "Log Number" => meta.log_number = value,
+                    "Log" => meta.log_date_time = value,
+                    // "Channel" key indicates start of a new channel definition
+                    "Channel" => {
+                        if !current_channel.name.is_empty() {
+                            channels.push(Channel::Haltech(current_channel));
                         }
-                        "ID" => current_channel.id = value,
-                        "Type" => {
-                            if let Ok(channel_type) = ChannelType::from_str(&value) {
-                                current_channel.r#type = channel_type;
-                            } else {
-                                tracing::warn!("Unknown channel type: {}", value);
-                                current_channel.r#type = ChannelType::Raw;
-                            }
+                        current_channel = HaltechChannel::default();
+                        current_channel.name = value;
+                    }
+                    "ID" => current_channel.id = value,
+                    "Type" => {
+                        if let Ok(channel_type) = ChannelType::from_str(&value) {
+                            current_channel.r#type = channel_type;
+                        } else {
+                            tracing::warn!("Unknown channel type: {}", value);
+                            current_channel.r#type = ChannelType::Raw;
                         }
-                        "DisplayMaxMin" => {
-                            let values: Vec<&str> = value.split(',').collect();
-                            if values.len() >= 2 {
-                                current_channel.display_max = values[0].trim().parse().ok();
-                                current_channel.display_min = values[1].trim().parse().ok();
-                            }
+                    }
+                    "DisplayMaxMin" => {
+                        let values: Vec<&str> = value.split(',').collect();
+                        if values.len() >= 2 {
+                            current_channel.display_max = values[0].trim().parse().ok();
+                            current_channel.display_min = values[1].trim().parse().ok();
                         }
-                        _ => {}
                     }
+                    _ => {}
                 }
             }
         }
+        if !current_channel.name.is_empty() {
+            channels.push(Channel::Haltech(current_channel));
+        }
 
-        // Phase 2: Parse data rows in parallel
-        // Each row is parsed independently, returning (timestamp, values)
-        let parsed_rows: Vec<(f64, Vec<Value>)> = data_lines
-            .par_iter()
-            .filter_map(|line| {
-                let parts: Vec<&str> = line.split(',').collect();
-                if parts.is_empty() {
-                    return None;
-                }
-
-                // First column is timestamp
-                let timestamp_str = parts[0].trim();
-                let timestamp_secs = Self::parse_timestamp(timestamp_str)?;
-
-                // Parse remaining values and apply unit conversions
-                let values: Vec<Value> = parts[1..]
-                    .iter()
-                    .enumerate()
-                    .filter_map(|(idx, v)| {
-                        let v = v.trim();
-                        let raw_value: f64 = v.parse().ok()?;
-
-                        // Apply conversion based on channel type if available
-                        let converted = if let Some(Channel::Haltech(ch)) = channels.get(idx) {
-                            ch.r#type.convert_value(raw_value)
-                        } else {
-                            raw_value
-                        };
-                        Some(Value::Float(converted))
-                    })
-                    .collect();
-
-                if values.is_empty() {
-                    None
-                } else {
-                    Some((timestamp_secs, values))
-                }
-            })
-            .collect();
-
-        // Phase 3: Post-process results (sequential for ordering)
-        let data_count = parsed_rows.len();
-        let mut times: Vec<f64> = Vec::with_capacity(data_count);
-        let mut data: Vec<Vec<Value>> = Vec::with_capacity(data_count);
-
-        if !parsed_rows.is_empty() {
+        // Data: whole blocks of lines, each parsed in parallel, then pushed
+        // onto the channel columns in order
+        let mut runs = vec![HaltechRun {
+            log_number: meta.log_number.clone(),
+            log_date_time: meta.log_date_time.clone(),
+            start: 0,
+        }];
+        let mut times: Vec<f64> = Vec::new();
+        let mut data: Vec<Vec<f64>> = vec![Vec::new(); channels.len()];
+        let mut skipped = 0;
+        let mut rows = Self::parse_batch(first_row.as_deref().unwrap_or_default(), &channels);
+        loop {
             // First timestamp is the base for relative times
-            let first_timestamp = parsed_rows[0].0;
-
-            for (timestamp, values) in parsed_rows {
-                times.push(timestamp - first_timestamp);
-                data.push(values);
+            if times.is_empty() {
+                meta.first_timestamp = rows.timestamps.first().copied().unwrap_or(0.0);
             }
-        }
-
-        // Verify data integrity
-        let channel_count = channels.len();
-        if channel_count > 0 {
-            // Filter out data rows that don't match channel count
-            let mut filtered_times = Vec::with_capacity(times.len());
-            let mut filtered_data = Vec::with_capacity(data.len());
-            for (time, row) in times.into_iter().zip(data.into_iter()) {
-                if row.len() >= channel_count {
-                    filtered_times.push(time);
-                    filtered_data.push(row);
-                }
+            for (row, name, value) in &rows.runs {
+                Self::mark_run(&mut runs, times.len() + row, name, value);
             }
-            times = filtered_times;
-            data = filtered_data;
+            Self::push_rows(&rows, meta.first_timestamp, &mut times, &mut data);
+            skipped += rows.skipped;
+
+            let Some(block) = lines.next_lines(Self::DATA_BATCH_BYTES)? else {
+                break;
+            };
+            rows = Self::parse_batch(block, &channels);
         }
 
+        // Markers after the last record start no session
+        runs.retain(|run| run.start == 0 || run.start < times.len());
+        meta.runs = runs;
+
         tracing::info!(
-            "Parsed Haltech log: {} channels, {} data points",
+            "Parsed Haltech log: {} channels, {} data points, {} sessions",
             channels.len(),
-            data.len()
+            times.len(),
+            meta.runs.len()
         );
 
+        if skipped > 0 {
+            tracing::warn!("Skipped {} damaged Haltech data rows", skipped);
+        }
+
         Ok(Log {
             meta: Meta::Haltech(meta),
             channels,
             times,
             data,
+            lazy_data: None,
+            skipped_records: skipped,
+            markers: Vec::new(),
         })
     }
+
+    /// Append data lines written after the log was loaded (follow mode).
+    /// Returns the number of records added.
+    pub fn append_lines(log: &mut Log, lines: &[String]) -> usize {
+        let lines: Vec<&str> = lines
+            .iter()
+            .map(|l| l.trim())
+            .filter(|l| Self::is_data_row(l))
+            .collect();
+        let rows = Self::parse_data_rows(&lines, &log.channels);
+
+        let Meta::Haltech(meta) = &mut log.meta else {
+            return 0;
+        };
+        if log.times.is_empty() {
+            meta.first_timestamp = rows.timestamps.first().copied().unwrap_or(0.0);
+        }
+        let before = log.times.len();
+        Self::push_rows(&rows, meta.first_timestamp, &mut log.times, &mut log.data);
+        log.times.len() - before
+    }
+
+    /// Apply a session marker line preceding record `start`: a new run
+    /// starts there, or the run already starting there takes its value
+    fn mark_run(runs: &mut Vec<HaltechRun>, start: usize, name: &str, value: &str) {
+        if runs.last().is_none_or(|run| run.start != start) {
+            runs.push(HaltechRun {
+                start,
+                ..Default::default()
+            });
+        }
+        if let Some(run) = runs.last_mut() {
+            match name {
+                "Log Number" => run.log_number = value.to_string(),
+                _ => run.log_date_time = value.to_string(),
+            }
+        }
+    }
+
+    /// Split a log of several sessions into one log per session, each with
+    /// times starting at zero and the session's log number and date
+    pub(crate) fn split_runs(log: Log) -> Vec<Log> {
+        let Meta::Haltech(meta) = &log.meta else {
+            return vec![log];
+        };
+        let ends = meta
+            .runs
+            .iter()
+            .skip(1)
+            .map(|run| run.start)
+            .chain([log.times.len()]);
+        meta.runs
+            .iter()
+            .zip(ends)
+            .map(|(run, end)| {
+                let offset = log.times.get(run.start).copied().unwrap_or(0.0);
+                let mut run_meta = meta.clone();
+                run_meta.log_number = run.log_number.clone();
+                run_meta.log_date_time = run.log_date_time.clone();
+                run_meta.first_timestamp += offset;
+                run_meta.runs = Vec::new();
+                Log {
+                    meta: Meta::Haltech(run_meta),
+                    channels: log.channels.clone(),
+                    times: log.times[run.start..end]
+                        .iter()
+                        .map(|t| t - offset)
+                        .collect(),
+                    data: log
+                        .data
+                        .iter()
+                        .map(|column| column[run.start..end].to_vec())
+                        .collect(),
+                    lazy_data: None,
+                    skipped_records: if run.start == 0 {
+                        log.skipped_records
+                    } else {
+                        0
+                    },
+                    markers: Vec::new(),
+                }
+            })
+            .collect()
+    }
+
+    /// Push parsed rows onto time and channel columns, making times relative
+    /// to `first_timestamp`
+    fn push_rows(
+        rows: &ParsedRows,
+        first_timestamp: f64,
+        times: &mut Vec<f64>,
+        data: &mut [Vec<f64>],
+    ) {
+        times.extend(rows.timestamps.iter().map(|t| t - first_timestamp));
+        // One column at a time keeps the writes sequential
+        let channel_count = data.len();
+        for (channel, column) in data.iter_mut().enumerate() {
+            column.extend(rows.values.iter().skip(channel).step_by(channel_count));
+        }
+    }
+
+    /// Parse the data rows of a block of lines. Other lines are counted as
+    /// skipped damaged rows, except blank lines and `Key : value` lines such
+    /// as the `Log Number :` and `Log :` markers between sessions of a
+    /// multi-log export, which are kept in `runs`.
+    fn parse_batch(block: &str, channels: &[Channel]) -> ParsedRows {
+        let mut rows = ParsedRows::default();
+        let mut lines: Vec<&str> = Vec::new();
+        for line in block.lines().map(str::trim) {
+            if Self::is_data_row(line) {
+                lines.push(line);
+                continue;
+            }
+            if line.is_empty() {
+                continue;
+            }
+            let Some(captures) = KV_REGEX.captures(line) else {
+                rows.skipped += 1;
+                continue;
+            };
+            let name = captures["name"].trim();
+            if matches!(name, "Log Number" | "Log") {
+                // Rows before the marker belong to the previous session
+                rows.extend(Self::parse_data_rows(&lines, channels));
+                lines.clear();
+                rows.runs.push((
+                    rows.timestamps.len(),
+                    name.to_string(),
+                    captures["value"].trim().to_string(),
+                ));
+            }
+        }
+        rows.extend(Self::parse_data_rows(&lines, channels));
+        rows
+    }
+
+    /// Parse data rows in parallel, each task taking a run of lines. Rows
+    /// keep their order.
+    fn parse_data_rows(lines: &[&str], channels: &[Channel]) -> ParsedRows {
+        // Unit conversion for each column; columns beyond the channel list are ignored
+        let conversions: Vec<Option<&ChannelType>> = channels
+            .iter()
+            .map(|c| match c {
+                Channel::Haltech(h) => Some(&h.r#type),
+                _ => None,
+            })
+            .collect();
+
+        let parts: Vec<ParsedRows> = lines
+            .par_chunks(Self::LINES_PER_TASK)
+            .map(|chunk| {
+                let mut rows = ParsedRows::default();
+                for line in chunk {
+                    if let Some(timestamp) =
+                        Self::parse_data_row(line, &conversions, &mut rows.values)
+                    {
+                        rows.timestamps.push(timestamp);
+                    } else {
+                        rows.skipped += 1;
+                    }
+                }
+                rows
+            })
+            .collect();
+
+        let mut rows = ParsedRows::default();
+        for part in parts {
+            rows.extend(part);
+        }
+        rows
+    }
+
+    /// Parse one data row, appending one converted value per channel to
+    /// `values` and returning its timestamp. Fields that aren't numbers are
+    /// skipped, and rows left with fewer values than channels are rejected
+    /// with `values` unchanged.
+    fn parse_data_row(
+        line: &str,
+        conversions: &[Option<&ChannelType>],
+        values: &mut Vec<f64>,
+    ) -> Option<f64> {
+        let mut fields = Self::fields(line);
+
+        // First column is timestamp
+        let timestamp = Self::parse_timestamp(fields.next()?.trim_ascii())?;
+
+        let start = values.len();
+        for field in fields {
+            let column = values.len() - start;
+            let Some(conversion) = conversions.get(column) else {
+                break;
+            };
+            if let Some(raw) = Self::parse_value(field.trim_ascii()) {
+                values.push(conversion.map_or(raw, |c| c.convert_value(raw)));
+            }
+        }
+
+        // Verify data integrity: skip rows that don't match the channel count
+        if values.len() - start < conversions.len() || conversions.is_empty() {
+            values.truncate(start);
+            return None;
+        }
+        Some(timestamp)
+    }
+}
+
+/// Data rows parsed from a batch of lines: one timestamp per row and
+/// row-major values, one per channel
+#[derive(Default)]
+struct ParsedRows {
+    timestamps: Vec<f64>,
+    values: Vec<f64>,
+    /// Lines rejected as damaged
+    skipped: usize,
+    /// Session marker lines: index of the row they precede, key and value
+    runs: Vec<(usize, String, String)>,
+}
+
+impl ParsedRows {
+    fn extend(&mut self, other: ParsedRows) {
+        let offset = self.timestamps.len();
+        self.runs.extend(
+            other
+                .runs
+                .into_iter()
+                .map(|(row, name, value)| (row + offset, name, value)),
+        );
+        self.skipped += other.skipped;
+        if self.timestamps.is_empty() {
+            self.timestamps = other.timestamps;
+            self.values = other.values;
+            return;
+        }
+        self.timestamps.extend(other.timestamps);
+        self.values.extend(other.values);
+    }
+}
+
+impl Parseable for Haltech {
+    fn parse(&self, file_contents: &str) -> Result<Log, Box<dyn Error>> {
+        self.parse_reader(file_contents.as_bytes(), &|_| {})
+    }
 }
 
 #[cfg(test)]
@@ -494,7 +801,8 @@ Log : 20250718 02:15:46
         assert_eq!(log.channels[0].name(), "RPM");
         assert_eq!(log.channels[1].name(), "Manifold Pressure");
         assert_eq!(log.times.len(), 3);
-        assert_eq!(log.data.len(), 3);
+        assert_eq!(log.data.len(), 2);
+        assert_eq!(log.record_count(), 3);
 
         // Check relative timestamps (now stored as f64)
         assert!((log.times[0] - 0.0).abs() < 0.001);
@@ -503,10 +811,10 @@ Log : 20250718 02:15:46
 
         // Check unit conversions are applied
         // RPM: y = x (no conversion) - raw 5000 -> 5000 RPM
-        assert_eq!(log.data[0][0].as_f64(), 5000.0);
+        assert_eq!(log.get_channel_data(0)[0], 5000.0);
 
         // Pressure: y = x/10 - 101.3 (gauge kPa) - raw 1013 -> 0.0 kPa
-        let pressure_value = log.data[0][1].as_f64();
+        let pressure_value = log.get_channel_data(1)[0];
         assert!(
             (pressure_value - 0.0).abs() < 0.01,
             "Expected ~0.0, got {}",
@@ -558,6 +866,96 @@ Log : 20250718 02:15:46
         assert_eq!(ChannelType::TimeMicroseconds.convert_value(5000.0), 5.0);
     }
 
+    #[test]
+    fn test_parse_reader_batches_and_progress() {
+        let mut sample = String::from("%DataLog%\nChannel : RPM\nID : 384\nType : EngineSpeed\n");
+        // Enough rows (at least 15 bytes each) for a few batches
+        let rows = 3 * Haltech::DATA_BATCH_BYTES / 15;
+        for i in 0..rows {
+            sample.push_str(&format!(
+                "00:00:{:02}.{:03},{}\n",
+                i / 1000 % 60,
+                i % 1000,
+                i
+            ));
+        }
+
+        let reported = std::cell::Cell::new(0u64);
+        let log = Haltech
+            .parse_reader(sample.as_bytes(), &|bytes| reported.set(bytes))
+            .unwrap();
+
+        assert_eq!(log.record_count(), rows);
+        assert_eq!(log.get_channel_data(0)[rows - 1], (rows - 1) as f64);
+        assert_eq!(reported.get(), sample.len() as u64);
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(Haltech::parse_value("5000"), Some(5000.0));
+        assert_eq!(Haltech::parse_value("-300"), Some(-300.0));
+        assert_eq!(Haltech::parse_value("46.500"), Some(46.5));
+        assert_eq!(Haltech::parse_value("0.020"), Some(0.02));
+        assert_eq!(Haltech::parse_value(".5"), Some(0.5));
+        assert_eq!(Haltech::parse_value("-0"), Some(-0.0));
+        assert_eq!(Haltech::parse_value("1e3"), Some(1000.0));
+        assert_eq!(
+            Haltech::parse_value("12345678901234567890"),
+            Some(1.2345678901234567e19)
+        );
+        assert_eq!(Haltech::parse_value(""), None);
+        assert_eq!(Haltech::parse_value("-"), None);
+        assert_eq!(Haltech::parse_value("."), None);
+        assert_eq!(Haltech::parse_value("1.2.3"), None);
+
+        // The digit-by-digit path rounds exactly like the float parser
+        for i in (0..2_000_000u64).step_by(7919) {
+            for text in [format!("{}", i), format!("-{}.{:03}", i / 1000, i % 1000)] {
+                assert_eq!(Haltech::parse_value(&text), text.parse().ok(), "{}", text);
+            }
+        }
+        assert_eq!(Haltech::parse_value("abc"), None);
+    }
+
+    #[test]
+    fn test_short_rows_skipped() {
+        let sample = "%DataLog%\nChannel : RPM\nID : 1\nType : EngineSpeed\n\
+                      Channel : TPS\nID : 2\nType : Percentage\n\
+                      10:00:00.000,5000,500\n10:00:00.020,5100\nLog : 20250330 01:53:14\n\
+                      10:00:00.040,5200,x,600,7\n\u{0}\u{0}\u{0}\n";
+        let log = Haltech.parse(sample).unwrap();
+        assert_eq!(log.record_count(), 2);
+        // Short row and garbage, but not the session marker
+        assert_eq!(log.skipped_records, 2);
+        assert_eq!(log.get_channel_data(0), &[5000.0, 5200.0]);
+        assert_eq!(log.get_channel_data(1), &[50.0, 60.0]);
+        assert!((log.times[1] - 0.040).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_split_runs() {
+        let sample = "%DataLog%\nLog Number : 7\nLog : 20250330 01:53:14\n\
+                      Channel : RPM\nID : 1\nType : EngineSpeed\n\
+                      10:00:00.000,5000\n10:00:00.020,5100\n\
+                      Log Number : 8\nLog : 20250330 02:10:00\n\
+                      10:16:46.000,800\n10:16:46.500,850\n10:16:47.000,900\n\
+                      Log Number : 9\n";
+        let log = Haltech.parse(sample).unwrap();
+        assert_eq!(log.record_count(), 5);
+        assert_eq!(log.skipped_records, 0);
+
+        let runs = log.split_runs();
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].get_channel_data(0), &[5000.0, 5100.0]);
+        assert_eq!(runs[1].get_channel_data(0), &[800.0, 850.0, 900.0]);
+        assert_eq!(runs[1].times, [0.0, 0.5, 1.0]);
+        let Meta::Haltech(meta) = &runs[1].meta else {
+            panic!("expected Haltech metadata");
+        };
+        assert_eq!(meta.run_label(), "Log 8, 20250330 02:10:00");
+        assert_eq!(meta.first_timestamp, 10.0 * 3600.0 + 16.0 * 60.0 + 46.0);
+    }
+
     #[test]
     fn test_is_data_row() {
         assert!(Haltech::is_data_row("14:15:46.000,5000,1013"));
@@ -565,5 +963,20 @@ Log : 20250718 02:15:46
         assert!(!Haltech::is_data_row("Channel : RPM"));
         assert!(!Haltech::is_data_row("ID : 384"));
         assert!(!Haltech::is_data_row("%DataLog%"));
+        assert!(!Haltech::is_data_row("123:45:67,1"));
+        assert!(!Haltech::is_data_row("1:2:3,1"));
+    }
+
+    #[test]
+    fn test_append_lines() {
+        let sample = "%DataLog%\nChannel : RPM\nID : 384\nType : EngineSpeed\n\
+                      10:00:00.000,5000\n10:00:00.020,5100\n";
+        let mut log = Haltech.parse(sample).unwrap();
+
+        let lines = vec!["10:00:00.040,5200".to_string(), "Garbage".to_string()];
+        assert_eq!(log.append_lines(&lines), 1);
+        assert_eq!(log.get_channel_data(0), &[5000.0, 5100.0, 5200.0]);
+        // New records stay relative to the first loaded record
+        assert!((log.times[2] - 0.040).abs() < 1e-9);
     }
 }
@@ -0,0 +1,288 @@
+//! HP Tuners VCM Scanner log parser.
+//!
+//! Parses CSV exports from VCM Scanner. Full exports start with an
+//! "HP Tuners CSV Log File" banner and hold `[Channel Information]` and
+//! `[Channel Data]` sections; the channel information is a row of channel
+//! IDs, a row of names and a row of units. Plain exports with just a name
+//! row and a unit row above the data are read too. Exports from machines
+//! that use a decimal comma are semicolon-delimited.
+
+use serde::Serialize;
+use std::error::Error;
+use std::io::BufRead;
+
+use super::generic_csv::{GenericCsv, GenericCsvChannel, RowCollector};
+use super::types::{Channel, LineReader, Log, Meta, ParseError, Parseable};
+
+/// Banner on the first line of full VCM Scanner exports
+const BANNER: &str = "HP Tuners";
+const INFO_SECTION: &str = "[Channel Information]";
+const DATA_SECTION: &str = "[Channel Data]";
+/// Name VCM Scanner gives the time column
+const TIME_COLUMN: &str = "Offset";
+
+/// HP Tuners log metadata
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct HpTunersMeta {
+    /// Export format version from the banner lines, if given
+    pub version: String,
+    /// Number of channels in the log
+    pub channel_count: usize,
+    /// Number of data points
+    pub data_points: usize,
+}
+
+/// HP Tuners VCM Scanner CSV parser
+pub struct HpTuners;
+
+impl HpTuners {
+    /// Detect if file contents look like a VCM Scanner export: the banner,
+    /// the channel information section, or a plain export whose first
+    /// column is VCM Scanner's "Offset" time column
+    pub fn detect(contents: &str) -> bool {
+        let Some(first) = contents.lines().map(str::trim).find(|l| !l.is_empty()) else {
+            return false;
+        };
+        if first.to_lowercase().starts_with(&BANNER.to_lowercase()) {
+            return true;
+        }
+        let first_field = Self::split(first, GenericCsv::header_delimiter(first))[0];
+        first_field.eq_ignore_ascii_case(TIME_COLUMN)
+            || contents
+                .lines()
+                .any(|line| line.trim().eq_ignore_ascii_case(INFO_SECTION))
+    }
+
+    /// Split a header or data line on the export's delimiter
+    fn split(line: &str, delimiter: char) -> Vec<&str> {
+        line.split(delimiter)
+            .map(|field| field.trim().trim_matches('"').trim())
+            .collect()
+    }
+
+    /// True for the row of numeric channel IDs above the channel names
+    fn is_id_row(fields: &[&str]) -> bool {
+        fields
+            .iter()
+            .filter(|f| !f.is_empty())
+            .all(|f| f.parse::<u64>().is_ok())
+    }
+
+    /// Parse a log line-by-line from a reader, calling `progress` with the
+    /// number of bytes consumed so far.
+    pub fn parse_reader<R: BufRead>(
+        &self,
+        reader: R,
+        progress: &dyn Fn(u64),
+    ) -> Result<Log, Box<dyn Error>> {
+        let mut lines = LineReader::new(reader, progress);
+        let mut meta = HpTunersMeta::default();
+
+        // Header: banner lines, then channel names and units
+        let mut line_number = 0;
+        let mut in_banner = false;
+        let mut sectioned = false;
+        let mut names: Option<(Vec<String>, char)> = None;
+        let mut units: Vec<String> = Vec::new();
+        while let Some(line) = lines.next_line()? {
+            line_number += 1;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if line_number == 1 && line.to_lowercase().starts_with(&BANNER.to_lowercase()) {
+                in_banner = true;
+                continue;
+            }
+            if line.eq_ignore_ascii_case(INFO_SECTION) {
+                in_banner = false;
+                sectioned = true;
+                continue;
+            }
+            if line.eq_ignore_ascii_case(DATA_SECTION) {
+                break;
+            }
+            if in_banner {
+                // e.g. "Version,4.1" or "Version: 4.1"
+                if let Some(rest) = line.strip_prefix("Version") {
+                    meta.version = rest
+                        .trim_start_matches([',', ';', ':', ' '])
+                        .trim_matches('"')
+                        .to_string();
+                }
+                continue;
+            }
+
+            match &names {
+                None => {
+                    let delimiter = GenericCsv::header_delimiter(line);
+                    let fields = Self::split(line, delimiter);
+                    if Self::is_id_row(&fields) {
+                        continue;
+                    }
+                    names = Some((fields.iter().map(|f| f.to_string()).collect(), delimiter));
+                }
+                Some((_, delimiter)) => {
+                    units = Self::split(line, *delimiter)
+                        .iter()
+                        .map(|f| f.to_string())
+                        .collect();
+                    // Without sections the data starts straight after the units
+                    if !sectioned {
+                        break;
+                    }
+                }
+            }
+        }
+
+        let Some((names, delimiter)) = names else {
+            return Err(Box::new(ParseError::at_line(
+                line_number,
+                "Invalid HP Tuners log: no channel names found",
+            )));
+        };
+        if names.len() < 2 {
+            return Err(Box::new(ParseError::at_line(
+                line_number,
+                "Invalid HP Tuners log: no channels after the time column",
+            )));
+        }
+        let decimal_comma = delimiter != ',';
+
+        // First column is the time offset; the rest are channels. Units come
+        // from the unit row, or from the name when that row left them out.
+        let channels: Vec<Channel> = names
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, name)| {
+                let mut channel = GenericCsvChannel::from_header(name);
+                if let Some(unit) = units.get(i).filter(|u| !u.is_empty()) {
+                    channel.unit = unit.clone();
+                }
+                Channel::Generic(channel)
+            })
+            .collect();
+
+        // Channels not sampled on a row hold their last value
+        let mut rows = RowCollector::new(channels.len());
+        let mut skipped = 0;
+        while let Some(line) = lines.next_line()? {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let parts = Self::split(line, delimiter);
+            let Some(time) = GenericCsv::parse_value(parts[0], decimal_comma) else {
+                skipped += 1;
+                continue;
+            };
+            rows.push(time, &parts, decimal_comma);
+        }
+        let RowCollector { times, data, .. } = rows;
+
+        tracing::info!(
+            "Parsed HP Tuners log: {} channels, {} data points",
+            channels.len(),
+            times.len()
+        );
+
+        meta.channel_count = channels.len();
+        meta.data_points = times.len();
+        Ok(Log {
+            meta: Meta::HpTuners(meta),
+            channels,
+            times,
+            data,
+            lazy_data: None,
+            skipped_records: skipped,
+            markers: Vec::new(),
+        })
+    }
+}
+
+impl Parseable for HpTuners {
+    fn parse(&self, file_contents: &str) -> Result<Log, Box<dyn Error>> {
+        self.parse_reader(file_contents.as_bytes(), &|_| {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_hptuners() {
+        assert!(HpTuners::detect("HP Tuners CSV Log File\nVersion,1.1\n"));
+        assert!(HpTuners::detect(
+            "\n[Channel Information]\n12,50\nOffset,Engine RPM\n"
+        ));
+        assert!(HpTuners::detect("Offset;Engine RPM\ns;rpm\n0,0;800"));
+        assert!(!HpTuners::detect("%DataLog%\nSomething else"));
+        assert!(!HpTuners::detect("TIME;engine/rpm\n0.000;1000"));
+    }
+
+    #[test]
+    fn test_parse_sectioned_export() {
+        let sample = "HP Tuners CSV Log File\n\
+                      Version,4.1\n\
+                      \n\
+                      [Channel Information]\n\
+                      0,12,50,1230\n\
+                      Offset,Engine RPM,Throttle Position (%),Spark Advance\n\
+                      s,rpm,,°\n\
+                      \n\
+                      [Channel Data]\n\
+                      0.000,800,1.5,12\n\
+                      0.050,850,,12.5\n\
+                      0.100,900,2.0,\n";
+        let log = HpTuners.parse(sample).unwrap();
+
+        assert_eq!(log.channels.len(), 3);
+        assert_eq!(log.channels[0].name(), "Engine RPM");
+        assert_eq!(log.channels[0].unit(), "rpm");
+        // The unit row is blank here, so the name's unit is used
+        assert_eq!(log.channels[1].name(), "Throttle Position");
+        assert_eq!(log.channels[1].unit(), "%");
+        assert_eq!(log.channels[2].unit(), "°");
+
+        assert_eq!(log.times, vec![0.0, 0.05, 0.1]);
+        // Unsampled cells hold the previous value
+        assert_eq!(log.get_channel_data(1), &[1.5, 1.5, 2.0]);
+        assert_eq!(log.get_channel_data(2), &[12.0, 12.5, 12.5]);
+        assert_eq!(log.skipped_records, 0);
+
+        let Meta::HpTuners(meta) = &log.meta else {
+            panic!("expected HP Tuners metadata");
+        };
+        assert_eq!(meta.version, "4.1");
+        assert_eq!(meta.data_points, 3);
+    }
+
+    #[test]
+    fn test_parse_decimal_comma_export() {
+        let sample = "Offset;Engine RPM;Mass Airflow\n\
+                      s;rpm;g/s\n\
+                      0,000;800;4,5\n\
+                      0,125;825;4,75\n";
+        let log = HpTuners.parse(sample).unwrap();
+
+        assert_eq!(log.channels.len(), 2);
+        assert_eq!(log.channels[1].unit(), "g/s");
+        assert_eq!(log.times, vec![0.0, 0.125]);
+        assert_eq!(log.get_channel_data(1), &[4.5, 4.75]);
+    }
+
+    #[test]
+    fn test_damaged_rows_skipped() {
+        let sample = "Offset,Engine RPM\ns,rpm\n0.0,800\n\u{FFFD}\u{FFFD},7\n0.1,900\n";
+        let log = HpTuners.parse(sample).unwrap();
+        assert_eq!(log.times, vec![0.0, 0.1]);
+        assert_eq!(log.skipped_records, 1);
+    }
+
+    #[test]
+    fn test_missing_channels() {
+        assert!(HpTuners.parse("HP Tuners CSV Log File\n").is_err());
+        assert!(HpTuners.parse("Offset\ns\n0.0\n").is_err());
+    }
+}
@@ -0,0 +1,332 @@
+//! MegaSquirt FRD binary datalog parser.
+//!
+//! FRD logs written by older MS2/MS3 logging tools hold the ECU's raw
+//! realtime data blocks one after another, behind a short header naming the
+//! firmware. The file carries no channel definitions, so records are decoded
+//! with the `[OutputChannels]` layout from the ECU's TunerStudio INI file.
+
+use serde::Serialize;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use super::generic_csv::GenericCsvChannel;
+use super::types::{Channel, Log, Meta, ParseError};
+use crate::live::OutputLayout;
+
+/// "FRD" followed by two zero bytes
+const MAGIC: &[u8] = b"FRD\0\0";
+/// Magic, format version (u16), capture time (u32), firmware signature,
+/// data start (u32) and record block size (u16); all big-endian
+const HEADER_LEN: usize = 81;
+const SIGNATURE_LEN: usize = 64;
+/// Record type of an output channel block; other records are skipped
+const OUTPUT_RECORD: u8 = 1;
+/// Name of the ECU's seconds counter, used as the time base when present
+const SECONDS_CHANNEL: &str = "seconds";
+
+/// MegaSquirt FRD log metadata
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct MegaSquirtMeta {
+    /// Firmware signature from the header, e.g. "MS2Extra comms342h2"
+    pub signature: String,
+    /// Capture time from the header (Unix seconds)
+    pub capture_time: u32,
+    /// Number of channels decoded from the INI
+    pub channel_count: usize,
+    /// Number of data points
+    pub data_points: usize,
+}
+
+/// MegaSquirt FRD binary parser
+pub struct MegaSquirt;
+
+impl MegaSquirt {
+    /// Detect an FRD log from its header magic
+    pub fn detect(data: &[u8]) -> bool {
+        data.starts_with(MAGIC)
+    }
+
+    /// Find the TunerStudio INI describing an FRD log: an INI with the same
+    /// name as the log, or else the only INI in the log's folder
+    pub fn find_ini(log_path: &Path) -> Option<PathBuf> {
+        let same_name = log_path.with_extension("ini");
+        if same_name.is_file() {
+            return Some(same_name);
+        }
+        let mut inis = std::fs::read_dir(log_path.parent()?)
+            .ok()?
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| {
+                p.extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("ini"))
+            });
+        let ini = inis.next()?;
+        inis.next().is_none().then_some(ini)
+    }
+
+    /// Parse an FRD log, decoding each record with the INI's output layout
+    pub fn parse_frd(data: &[u8], layout: &OutputLayout) -> Result<Log, Box<dyn Error>> {
+        if data.len() < HEADER_LEN || !Self::detect(data) {
+            return Err(Box::new(ParseError::at_offset(
+                0,
+                "Invalid FRD log: header is missing or truncated",
+            )));
+        }
+
+        let capture_time = u32::from_be_bytes([data[7], data[8], data[9], data[10]]);
+        let signature = String::from_utf8_lossy(&data[11..11 + SIGNATURE_LEN])
+            .trim_end_matches('\0')
+            .trim()
+            .to_string();
+        let data_start = u32::from_be_bytes([data[75], data[76], data[77], data[78]]) as usize;
+        let block_size = u16::from_be_bytes([data[79], data[80]]) as usize;
+
+        let fitting = layout
+            .channels
+            .iter()
+            .filter(|c| c.end() <= block_size)
+            .count();
+        if fitting < layout.channels.len() {
+            return Err(Box::new(ParseError::at_offset(
+                79,
+                format!(
+                    "The INI describes {} channels but FRD records only hold {} of them; \
+                     use the INI for the firmware that recorded the log ({})",
+                    layout.channels.len(),
+                    fitting,
+                    signature
+                ),
+            )));
+        }
+        if block_size != layout.block_size {
+            return Err(Box::new(ParseError::at_offset(
+                79,
+                format!(
+                    "FRD records are {} bytes but the INI describes {} bytes; \
+                     use the INI for the firmware that recorded the log ({})",
+                    block_size, layout.block_size, signature
+                ),
+            )));
+        }
+        if data_start < HEADER_LEN || data_start > data.len() {
+            return Err(Box::new(ParseError::at_offset(
+                75,
+                "Invalid FRD log: data start is outside the file",
+            )));
+        }
+
+        let channels: Vec<Channel> = layout
+            .channels
+            .iter()
+            .map(|c| {
+                Channel::Generic(GenericCsvChannel {
+                    name: c.name.clone(),
+                    unit: c.unit.clone(),
+                })
+            })
+            .collect();
+        let seconds_idx = layout
+            .channels
+            .iter()
+            .position(|c| c.name.eq_ignore_ascii_case(SECONDS_CHANNEL));
+
+        // Each record is a type byte, a rolling counter and one block
+        let record_len = block_size + 2;
+        let records = &data[data_start..];
+        let partial = records.len() % record_len;
+        if partial != 0 {
+            let complete = records.len() / record_len;
+            return Err(Box::new(ParseError::at_offset(
+                data_start + complete * record_len,
+                format!(
+                    "Invalid FRD log: record {} is truncated ({} of {} bytes)",
+                    complete + 1,
+                    partial,
+                    record_len
+                ),
+            )));
+        }
+        let mut data_columns: Vec<Vec<f64>> = vec![Vec::new(); channels.len()];
+        let mut skipped = 0;
+        for record in records.chunks_exact(record_len) {
+            if record[0] != OUTPUT_RECORD {
+                skipped += 1;
+                continue;
+            }
+            for (column, value) in data_columns.iter_mut().zip(layout.decode(&record[2..])) {
+                column.push(value);
+            }
+        }
+
+        let times = match seconds_idx {
+            Some(idx) => Self::spread_seconds(&data_columns[idx]),
+            None => (0..data_columns[0].len()).map(|i| i as f64).collect(),
+        };
+
+        tracing::info!(
+            "Parsed MegaSquirt FRD log: {} channels, {} data points",
+            channels.len(),
+            times.len()
+        );
+
+        let meta = MegaSquirtMeta {
+            signature,
+            capture_time,
+            channel_count: channels.len(),
+            data_points: times.len(),
+        };
+        Ok(Log {
+            meta: Meta::MegaSquirt(meta),
+            channels,
+            times,
+            data: data_columns,
+            lazy_data: None,
+            skipped_records: skipped,
+            markers: Vec::new(),
+        })
+    }
+
+    /// Build record times from the ECU's whole-second counter, spreading the
+    /// records logged within each second evenly across it. A counter that
+    /// goes backwards (ECU reset) continues from the previous time.
+    fn spread_seconds(seconds: &[f64]) -> Vec<f64> {
+        let mut times = Vec::with_capacity(seconds.len());
+        let mut offset = 0.0;
+        let mut start = 0;
+        while start < seconds.len() {
+            let mut end = start + 1;
+            while end < seconds.len() && seconds[end] == seconds[start] {
+                end += 1;
+            }
+            if start > 0 && seconds[start] < seconds[start - 1] {
+                offset += seconds[start - 1] - seconds[start] + 1.0;
+            }
+            let count = (end - start) as f64;
+            for i in 0..end - start {
+                times.push(seconds[start] + offset + i as f64 / count);
+            }
+            start = end;
+        }
+        match times.first().copied() {
+            Some(first) => times.iter().map(|t| t - first).collect(),
+            None => times,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_INI: &str = r#"
+[MegaTune]
+   endianness = big
+
+[OutputChannels]
+   ochGetCommand = "A"
+   ochBlockSize = 5
+   seconds  = scalar, U16, 0, "s", 1.000, 0.000
+   rpm      = scalar, U16, 2, "RPM", 1.000, 0.000
+   coolant  = scalar, U08, 4, "C", 1.000, -40.000
+"#;
+
+    fn frd(records: &[(u8, [u8; 5])]) -> Vec<u8> {
+        let mut data = MAGIC.to_vec();
+        data.extend(1u16.to_be_bytes());
+        data.extend(1_700_000_000u32.to_be_bytes());
+        let mut signature = b"MS2Extra comms342h2".to_vec();
+        signature.resize(SIGNATURE_LEN, 0);
+        data.extend(signature);
+        data.extend((HEADER_LEN as u32).to_be_bytes());
+        data.extend(5u16.to_be_bytes());
+        for (i, (kind, block)) in records.iter().enumerate() {
+            data.push(*kind);
+            data.push(i as u8);
+            data.extend(block);
+        }
+        data
+    }
+
+    #[test]
+    fn test_detect_frd() {
+        assert!(MegaSquirt::detect(&frd(&[])));
+        assert!(!MegaSquirt::detect(b"MLVLG\0\x01"));
+        assert!(!MegaSquirt::detect(b"FRD"));
+    }
+
+    #[test]
+    fn test_parse_frd() {
+        let layout = OutputLayout::from_ini(SAMPLE_INI).unwrap();
+        let data = frd(&[
+            (OUTPUT_RECORD, [0, 10, 0x03, 0x20, 120]),
+            (OUTPUT_RECORD, [0, 10, 0x03, 0x84, 121]),
+            (2, [0; 5]),
+            (OUTPUT_RECORD, [0, 11, 0x03, 0xE8, 122]),
+        ]);
+        let log = MegaSquirt::parse_frd(&data, &layout).unwrap();
+
+        assert_eq!(log.channels.len(), 3);
+        assert_eq!(log.channels[1].name(), "rpm");
+        assert_eq!(log.channels[1].unit(), "RPM");
+        assert_eq!(log.get_channel_data(1), &[800.0, 900.0, 1000.0]);
+        assert_eq!(log.get_channel_data(2), &[80.0, 81.0, 82.0]);
+        // Two records in second 10 are spread across it
+        assert_eq!(log.times, vec![0.0, 0.5, 1.0]);
+        assert_eq!(log.skipped_records, 1);
+
+        let Meta::MegaSquirt(meta) = &log.meta else {
+            panic!("expected MegaSquirt metadata");
+        };
+        assert_eq!(meta.signature, "MS2Extra comms342h2");
+        assert_eq!(meta.capture_time, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_block_size_mismatch() {
+        let ini = SAMPLE_INI.replace("ochBlockSize = 5", "ochBlockSize = 8");
+        let layout = OutputLayout::from_ini(&ini).unwrap();
+        assert!(MegaSquirt::parse_frd(&frd(&[]), &layout).is_err());
+    }
+
+    #[test]
+    fn test_truncated_header() {
+        let layout = OutputLayout::from_ini(SAMPLE_INI).unwrap();
+        let data = frd(&[]);
+        for len in [MAGIC.len(), 40, HEADER_LEN - 1] {
+            assert!(MegaSquirt::parse_frd(&data[..len], &layout).is_err());
+        }
+    }
+
+    #[test]
+    fn test_truncated_final_record() {
+        let layout = OutputLayout::from_ini(SAMPLE_INI).unwrap();
+        let mut data = frd(&[(OUTPUT_RECORD, [0, 10, 0x03, 0x20, 120])]);
+        data.extend([OUTPUT_RECORD, 1, 0]);
+        let error = MegaSquirt::parse_frd(&data, &layout).unwrap_err();
+        assert!(error.to_string().contains("record 2 is truncated"));
+    }
+
+    #[test]
+    fn test_channel_count_mismatch() {
+        // An INI for firmware with one more channel than the log's records hold
+        let ini = SAMPLE_INI
+            .replace("ochBlockSize = 5", "ochBlockSize = 7")
+            .replace(
+                "   coolant",
+                "   map      = scalar, U16, 5, \"kPa\", 0.100, 0.000\n   coolant",
+            );
+        let layout = OutputLayout::from_ini(&ini).unwrap();
+        assert_eq!(layout.channels.len(), 4);
+        let data = frd(&[(OUTPUT_RECORD, [0, 10, 0x03, 0x20, 120])]);
+        let error = MegaSquirt::parse_frd(&data, &layout).unwrap_err();
+        assert!(error.to_string().contains("4 channels"));
+    }
+
+    #[test]
+    fn test_spread_seconds_after_reset() {
+        assert_eq!(
+            MegaSquirt::spread_seconds(&[5.0, 6.0, 6.0, 0.0]),
+            vec![0.0, 1.0, 1.5, 2.0]
+        );
+    }
+}
@@ -1,9 +1,25 @@
+pub mod cobb;
+pub mod csv_profile;
 pub mod ecumaster;
+pub mod generic_csv;
 pub mod haltech;
+pub mod hptuners;
+pub mod megasquirt;
 pub mod speeduino;
 pub mod types;
 
+pub use cobb::Cobb;
+pub use csv_profile::{CsvProfile, ProfileCsv};
 pub use ecumaster::EcuMaster;
+pub use generic_csv::GenericCsv;
 pub use haltech::Haltech;
+pub use hptuners::HpTuners;
+pub use megasquirt::MegaSquirt;
 pub use speeduino::Speeduino;
-pub use types::{Channel, EcuType, Log, Parseable, Value};
+pub use types::{
+    Channel, ColumnSource, EcuType, ErrorLocation, LazyColumns, LineReader, Log, LogMarker,
+    LogTail, ParseError, Parseable,
+};
+
+/// Extensions of the log files UltraLog reads, offered by every file picker
+pub const LOG_EXTENSIONS: &[&str] = &["csv", "log", "txt", "mlg", "frd"];
@@ -9,8 +9,11 @@
 
 use serde::Serialize;
 use std::error::Error;
+use std::sync::Arc;
 
-use super::types::{Log, Parseable, Value};
+use super::types::{
+    Channel, ColumnSource, LazyColumns, Log, LogMarker, Meta, ParseError, Parseable,
+};
 
 /// MLG field data types (from mlg-converter)
 #[derive(Clone, Copy, Debug)]
@@ -57,6 +60,77 @@ impl FieldType {
     }
 }
 
+/// Where and how one channel is stored within a data record
+#[derive(Clone, Copy, Debug)]
+struct FieldLayout {
+    field_type: FieldType,
+    /// Byte offset of the field from the start of the record's field data
+    offset: usize,
+    scale: f64,
+    transform: f64,
+}
+
+impl FieldLayout {
+    /// Decode this field from the record whose field data starts at `record_offset`
+    /// (NaN if the record is cut short)
+    fn decode(&self, data: &[u8], record_offset: usize) -> f64 {
+        let start = record_offset + self.offset;
+        let Some(b) = data.get(start..start + self.field_type.byte_size()) else {
+            return f64::NAN;
+        };
+        let v = match self.field_type {
+            FieldType::U08 => b[0] as f64,
+            FieldType::S08 => b[0] as i8 as f64,
+            FieldType::U16 => u16::from_be_bytes([b[0], b[1]]) as f64,
+            FieldType::S16 => i16::from_be_bytes([b[0], b[1]]) as f64,
+            FieldType::U32 => u32::from_be_bytes([b[0], b[1], b[2], b[3]]) as f64,
+            FieldType::S32 => i32::from_be_bytes([b[0], b[1], b[2], b[3]]) as f64,
+            FieldType::F32 => f32::from_be_bytes([b[0], b[1], b[2], b[3]]) as f64,
+            FieldType::S64 => {
+                i64::from_be_bytes([b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]) as f64
+            }
+            FieldType::U08Bitfield | FieldType::U16Bitfield | FieldType::U32Bitfield => {
+                return 0.0; // Bitfields not fully supported yet
+            }
+        };
+        // Formula: (value + transform) * scale
+        (v + self.transform) * self.scale
+    }
+}
+
+/// Raw MLG data records, decoded one channel at a time
+struct MlgColumns<D> {
+    data: D,
+    fields: Vec<FieldLayout>,
+    /// Offset of each data record's field data within `data`
+    record_offsets: Vec<usize>,
+}
+
+impl<D: AsRef<[u8]> + Send + Sync> ColumnSource for MlgColumns<D> {
+    fn decode_column(&self, channel_index: usize) -> Vec<f64> {
+        let Some(field) = self.fields.get(channel_index) else {
+            return Vec::new();
+        };
+        let data = self.data.as_ref();
+        self.record_offsets
+            .iter()
+            .map(|&offset| field.decode(data, offset))
+            .collect()
+    }
+}
+
+/// Result of scanning an MLG file: everything except the channel values
+struct MlgScan {
+    meta: SpeeduinoMeta,
+    channels: Vec<SpeeduinoChannel>,
+    fields: Vec<FieldLayout>,
+    times: Vec<f64>,
+    record_offsets: Vec<usize>,
+    /// Data records lost to damaged or truncated blocks
+    skipped: usize,
+    markers: Vec<LogMarker>,
+}
+
 /// Speeduino field metadata
 #[derive(Clone, Debug, Serialize)]
 pub struct SpeeduinoChannel {
@@ -65,6 +139,8 @@ pub struct SpeeduinoChannel {
     pub scale: f32,
     pub transform: f32,
     pub field_type: u8,
+    /// Channel category from MLG v2 headers (empty in v1)
+    pub category: String,
 }
 
 impl SpeeduinoChannel {
@@ -80,6 +156,25 @@ pub struct SpeeduinoMeta {
     pub capture_date: String,
 }
 
+impl SpeeduinoMeta {
+    /// Time of day the log was captured (seconds since midnight), read from
+    /// the `H:MM:SS` time in the capture date
+    pub fn capture_time_of_day(&self) -> Option<f64> {
+        self.capture_date.split_whitespace().find_map(|word| {
+            let mut parts = word.split(':');
+            let (hours, minutes, seconds) = (parts.next()?, parts.next()?, parts.next()?);
+            if parts.next().is_some() || minutes.len() != 2 || !(1..=2).contains(&hours.len()) {
+                return None;
+            }
+            let hours: u32 = hours.parse().ok()?;
+            let minutes: u32 = minutes.parse().ok()?;
+            let seconds: f64 = seconds.parse().ok()?;
+            (hours < 24 && minutes < 60 && (0.0..60.0).contains(&seconds))
+                .then(|| f64::from(hours * 3600 + minutes * 60) + seconds)
+        })
+    }
+}
+
 /// Speeduino parser for MegaLogViewer binary format
 pub struct Speeduino;
 
@@ -89,63 +184,109 @@ impl Speeduino {
         data.len() >= 5 && &data[0..5] == b"MLVLG"
     }
 
-    /// Parse MegaLogViewer binary format (based on mlg-converter reference)
+    /// Parse MegaLogViewer binary format (based on mlg-converter reference),
+    /// decoding every channel up front
     pub fn parse_binary(data: &[u8]) -> Result<Log, Box<dyn Error>> {
-        let mut offset = 0;
+        let scan = Self::scan(data)?;
+        let columns = MlgColumns {
+            data,
+            fields: scan.fields,
+            record_offsets: scan.record_offsets,
+        };
+        let data = (0..scan.channels.len())
+            .map(|idx| columns.decode_column(idx))
+            .collect();
+
+        Ok(Log {
+            meta: Meta::Speeduino(scan.meta),
+            channels: scan.channels.into_iter().map(Channel::Speeduino).collect(),
+            times: scan.times,
+            data,
+            lazy_data: None,
+            skipped_records: scan.skipped,
+            markers: scan.markers,
+        })
+    }
+
+    /// Parse only the header, timestamps and record offsets of an MLG file.
+    ///
+    /// Channel values stay in `data` and are decoded the first time each
+    /// channel is read, so opening a huge log is near-instant and memory use
+    /// grows only with the channels actually viewed.
+    pub fn parse_binary_lazy<D>(data: D) -> Result<Log, Box<dyn Error>>
+    where
+        D: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        let scan = Self::scan(data.as_ref())?;
+        let channel_count = scan.channels.len();
+        let columns = MlgColumns {
+            data,
+            fields: scan.fields,
+            record_offsets: scan.record_offsets,
+        };
+
+        Ok(Log {
+            meta: Meta::Speeduino(scan.meta),
+            channels: scan.channels.into_iter().map(Channel::Speeduino).collect(),
+            times: scan.times,
+            data: Vec::new(),
+            lazy_data: Some(LazyColumns::new(Arc::new(columns), channel_count)),
+            skipped_records: scan.skipped,
+            markers: scan.markers,
+        })
+    }
+
+    /// Read the header, field definitions, and the timestamp and offset of
+    /// every data record. Every read is bounds-checked, so truncated or
+    /// corrupt files return an error rather than panicking. A damaged block
+    /// is skipped by resyncing on the next intact data record, and a
+    /// truncated last record ends the log.
+    fn scan(data: &[u8]) -> Result<MlgScan, Box<dyn Error>> {
+        let mut reader = ByteReader::new(data);
 
         // Read file format (6 bytes: "MLVLG" + 1 extra byte)
-        if data.len() < 6 || &data[0..5] != b"MLVLG" {
-            return Err("Invalid MLG file header".into());
+        if reader.bytes(6).ok().and_then(|magic| magic.get(..5)) != Some(b"MLVLG".as_slice()) {
+            return Err(Box::new(ParseError::at_offset(
+                0,
+                "Invalid MLG file header",
+            )));
         }
-        offset += 6;
 
         // Read format version (int16, big-endian like DataView default)
-        let format_version = i16::from_be_bytes([data[offset], data[offset + 1]]);
-        offset += 2;
+        let format_version = reader.i16()?;
 
         let is_v2 = format_version == 2;
         let field_length = if is_v2 { 89 } else { 55 };
 
-        eprintln!(
-            "DEBUG: MLG format version: {}, field_length: {}",
-            format_version, field_length
+        tracing::debug!(
+            "MLG format version: {}, field_length: {}",
+            format_version,
+            field_length
         );
 
-        // Read timestamp (int32, big-endian)
-        offset += 4;
+        // Skip timestamp (int32, big-endian)
+        reader.skip(4)?;
 
         // Read info_data_start (int16 for v1, int32 for v2, big-endian)
         let info_data_start = if is_v2 {
-            u32::from_be_bytes([
-                data[offset],
-                data[offset + 1],
-                data[offset + 2],
-                data[offset + 3],
-            ]) as usize
+            reader.u32()? as usize
         } else {
-            u16::from_be_bytes([data[offset], data[offset + 1]]) as usize
+            reader.u16()? as usize
         };
-        offset += if is_v2 { 4 } else { 2 };
 
         // Read data_begin_index (int32, big-endian)
-        let data_begin_index = u32::from_be_bytes([
-            data[offset],
-            data[offset + 1],
-            data[offset + 2],
-            data[offset + 3],
-        ]) as usize;
-        offset += 4;
+        let data_begin_index = reader.u32()? as usize;
 
-        // Read record_length (int16, big-endian)
-        offset += 2;
+        // Skip record_length (int16, big-endian)
+        reader.skip(2)?;
 
         // Read num_logger_fields (int16, big-endian)
-        let num_fields = u16::from_be_bytes([data[offset], data[offset + 1]]) as usize;
-        offset += 2;
+        let num_fields = reader.u16()? as usize;
 
-        eprintln!(
-            "DEBUG: num_fields: {}, data_begin_index: {}",
-            num_fields, data_begin_index
+        tracing::debug!(
+            "MLG num_fields: {}, data_begin_index: {}",
+            num_fields,
+            data_begin_index
         );
 
         // Validate bounds before parsing
@@ -164,70 +305,43 @@ impl Speeduino {
         // Parse field definitions
         let mut channels = Vec::new();
         for i in 0..num_fields {
-            if offset + field_length > data.len() {
+            if reader.remaining() < field_length {
                 return Err(format!(
                     "Not enough data for field {} at offset {} (need {}, have {})",
                     i,
-                    offset,
+                    reader.position(),
                     field_length,
-                    data.len() - offset
+                    reader.remaining()
                 )
                 .into());
             }
-            // Read type (1 byte)
-            let field_type = data[offset];
-            offset += 1;
-
-            // Read name (34 bytes)
-            let name_bytes = &data[offset..offset + 34];
-            let name = String::from_utf8_lossy(name_bytes)
-                .trim_end_matches('\0')
-                .trim()
-                .to_string();
-            offset += 34;
-
-            // Read units (10 bytes)
-            let units_bytes = &data[offset..offset + 10];
-            let unit = String::from_utf8_lossy(units_bytes)
-                .trim_end_matches('\0')
-                .trim()
-                .to_string();
-            offset += 10;
-
-            // Read display_style (1 byte)
-            offset += 1;
-
-            let (scale, transform) = if field_type < 10 {
+            let field_type = reader.u8()?;
+            let name = reader.string(34)?;
+            let unit = reader.string(10)?;
+
+            // Skip display_style (1 byte)
+            reader.skip(1)?;
+
+            let (scale, transform, category) = if field_type < 10 {
                 // Scalar field
-                let scale = f32::from_be_bytes([
-                    data[offset],
-                    data[offset + 1],
-                    data[offset + 2],
-                    data[offset + 3],
-                ]);
-                offset += 4;
-
-                let transform = f32::from_be_bytes([
-                    data[offset],
-                    data[offset + 1],
-                    data[offset + 2],
-                    data[offset + 3],
-                ]);
-                offset += 4;
+                let scale = reader.f32()?;
+                let transform = reader.f32()?;
 
                 // Skip digits (1 byte)
-                offset += 1;
+                reader.skip(1)?;
 
-                // Skip category if v2 (34 bytes)
-                if is_v2 {
-                    offset += 34;
-                }
+                // Read category if v2 (34 bytes)
+                let category = if is_v2 {
+                    reader.string(34)?
+                } else {
+                    String::new()
+                };
 
-                (scale, transform)
+                (scale, transform, category)
             } else {
                 // Bitfield - skip remaining bytes
-                offset += field_length - 46; // Already read 46 bytes
-                (1.0, 0.0)
+                reader.skip(field_length - 46)?; // Already read 46 bytes
+                (1.0, 0.0, String::new())
             };
 
             channels.push(SpeeduinoChannel {
@@ -236,21 +350,15 @@ impl Speeduino {
                 scale,
                 transform,
                 field_type,
+                category,
             });
         }
 
-        eprintln!("DEBUG: Parsed {} channels", channels.len());
-        for (idx, ch) in channels.iter().enumerate() {
-            eprintln!(
-                "  [{}] {} ({}) type={} scale={} transform={}",
-                idx, ch.name, ch.unit, ch.field_type, ch.scale, ch.transform
-            );
-        }
+        tracing::debug!("MLG parsed {} channels", channels.len());
 
         // Extract metadata from info section
         let mut meta = SpeeduinoMeta::default();
-        if info_data_start < data_begin_index && data_begin_index < data.len() {
-            let info_bytes = &data[info_data_start..data_begin_index];
+        if let Some(info_bytes) = data.get(info_data_start..data_begin_index) {
             let info_str = String::from_utf8_lossy(info_bytes);
 
             if let Some(version_start) = info_str.find("speeduino") {
@@ -265,19 +373,29 @@ impl Speeduino {
             }
         }
 
+        // Work out where each channel lives within a data record
+        let mut fields = Vec::with_capacity(channels.len());
+        let mut record_size = 0;
+        for channel in &channels {
+            let field_type = FieldType::from_u8(channel.field_type)
+                .ok_or_else(|| format!("Unknown field type: {}", channel.field_type))?;
+            fields.push(FieldLayout {
+                field_type,
+                offset: record_size,
+                scale: channel.scale as f64,
+                transform: channel.transform as f64,
+            });
+            record_size += field_type.byte_size();
+        }
+
         // Parse data blocks
-        offset = data_begin_index;
-        // Estimate record count: remaining data / approximate record size (timestamp + data + CRC)
-        // Each record is roughly: 1 (block type) + 2 (timestamp) + num_fields * ~4 bytes + 1 (CRC)
-        let remaining_data = data.len().saturating_sub(data_begin_index);
-        let estimated_record_size = 4 + channels.len() * 4;
-        let estimated_records = if estimated_record_size > 0 {
-            remaining_data / estimated_record_size
-        } else {
-            1000 // Fallback estimate
-        };
+        reader.seek(data_begin_index);
+        // Estimate record count: remaining data / record size (header + data + CRC)
+        let estimated_records = reader.remaining() / (4 + record_size + 1);
         let mut times: Vec<f64> = Vec::with_capacity(estimated_records);
-        let mut data_records: Vec<Vec<Value>> = Vec::with_capacity(estimated_records);
+        let mut record_offsets: Vec<usize> = Vec::with_capacity(estimated_records);
+        let mut skipped = 0;
+        let mut markers = Vec::new();
 
         // Track timestamp wraparound (u16 wraps at 65535ms = 65.535 seconds)
         let mut prev_raw_timestamp: u16 = 0;
@@ -286,20 +404,34 @@ impl Speeduino {
         // (actual wraparounds show ~58.7s drop when going from ~65s to ~6s)
         const WRAP_THRESHOLD: u16 = 30000;
 
-        while offset + 4 <= data.len() {
-            // Read block type (1 byte)
-            let block_type = data[offset];
-            offset += 1;
+        while reader.remaining() >= 4 {
+            let block_type = reader.u8()?;
 
             // Skip counter (1 byte)
-            offset += 1;
-
-            // Read timestamp (uint16, big-endian)
-            if offset + 2 > data.len() {
-                break;
+            reader.skip(1)?;
+
+            let raw_timestamp = reader.u16()?;
+
+            if block_type > 1 {
+                // Damaged block: carry on from the next intact data record,
+                // counting the records that fit in the gap as lost. Its
+                // timestamp is garbage, so wraparound tracking ignores it.
+                let start = reader.position() - 4;
+                let resume = Self::resync(data, start + 1, record_size);
+                let gap = resume.unwrap_or(data.len()) - start;
+                skipped += (gap / (4 + record_size + 1)).max(1);
+                tracing::debug!(
+                    "MLG unknown block type {} at offset {}, resuming at {:?}",
+                    block_type,
+                    start,
+                    resume
+                );
+                let Some(resume) = resume else {
+                    break;
+                };
+                reader.seek(resume);
+                continue;
             }
-            let raw_timestamp = u16::from_be_bytes([data[offset], data[offset + 1]]);
-            offset += 2;
 
             // Detect wraparound: if current timestamp is much smaller than previous, it wrapped
             if raw_timestamp < prev_raw_timestamp
@@ -313,230 +445,154 @@ impl Speeduino {
             let timestamp = (raw_timestamp as f64 / 1000.0) + (wrap_count as f64 * 65.536);
 
             if block_type == 0 {
-                // Data record - calculate required bytes for all channels
-                let mut required_bytes = 0;
-                for channel in &channels {
-                    if let Some(field_type) = FieldType::from_u8(channel.field_type) {
-                        required_bytes += field_type.byte_size();
-                    }
-                }
-                required_bytes += 1; // Add CRC byte
-
-                // Check if we have enough data for this record BEFORE adding timestamp
-                if offset + required_bytes > data.len() {
-                    eprintln!(
-                        "DEBUG: Not enough data for complete record at offset {} (need {}, have {})",
-                        offset,
+                // Data record - check we have the field data plus CRC byte
+                let required_bytes = record_size + 1;
+                if reader.remaining() < required_bytes {
+                    tracing::debug!(
+                        "MLG record at offset {} is truncated (need {}, have {})",
+                        reader.position(),
                         required_bytes,
-                        data.len() - offset
+                        reader.remaining()
                     );
+                    skipped += 1;
                     break;
                 }
 
-                // Now it's safe to add the timestamp and read the record
-                let mut record = Vec::new();
-
-                for channel in &channels {
-                    if let Some(field_type) = FieldType::from_u8(channel.field_type) {
-                        let value = match field_type {
-                            FieldType::U08 => {
-                                let v = data[offset] as f64;
-                                offset += 1;
-                                // Formula: (value + transform) * scale
-                                Value::Float((v + channel.transform as f64) * channel.scale as f64)
-                            }
-                            FieldType::S08 => {
-                                let v = data[offset] as i8 as f64;
-                                offset += 1;
-                                Value::Float((v + channel.transform as f64) * channel.scale as f64)
-                            }
-                            FieldType::U16 => {
-                                let v = u16::from_be_bytes([data[offset], data[offset + 1]]) as f64;
-                                offset += 2;
-                                Value::Float((v + channel.transform as f64) * channel.scale as f64)
-                            }
-                            FieldType::S16 => {
-                                let v = i16::from_be_bytes([data[offset], data[offset + 1]]) as f64;
-                                offset += 2;
-                                Value::Float((v + channel.transform as f64) * channel.scale as f64)
-                            }
-                            FieldType::U32 => {
-                                let v = u32::from_be_bytes([
-                                    data[offset],
-                                    data[offset + 1],
-                                    data[offset + 2],
-                                    data[offset + 3],
-                                ]) as f64;
-                                offset += 4;
-                                Value::Float((v + channel.transform as f64) * channel.scale as f64)
-                            }
-                            FieldType::S32 => {
-                                let v = i32::from_be_bytes([
-                                    data[offset],
-                                    data[offset + 1],
-                                    data[offset + 2],
-                                    data[offset + 3],
-                                ]) as f64;
-                                offset += 4;
-                                Value::Float((v + channel.transform as f64) * channel.scale as f64)
-                            }
-                            FieldType::F32 => {
-                                let v = f32::from_be_bytes([
-                                    data[offset],
-                                    data[offset + 1],
-                                    data[offset + 2],
-                                    data[offset + 3],
-                                ]) as f64;
-                                offset += 4;
-                                Value::Float((v + channel.transform as f64) * channel.scale as f64)
-                            }
-                            FieldType::S64 => {
-                                let v = i64::from_be_bytes([
-                                    data[offset],
-                                    data[offset + 1],
-                                    data[offset + 2],
-                                    data[offset + 3],
-                                    data[offset + 4],
-                                    data[offset + 5],
-                                    data[offset + 6],
-                                    data[offset + 7],
-                                ]) as f64;
-                                offset += 8;
-                                Value::Float((v + channel.transform as f64) * channel.scale as f64)
-                            }
-                            FieldType::U08Bitfield
-                            | FieldType::U16Bitfield
-                            | FieldType::U32Bitfield => {
-                                offset += field_type.byte_size();
-                                Value::Float(0.0) // Bitfields not fully supported yet
-                            }
-                        };
-                        record.push(value);
-                    } else {
-                        return Err(format!("Unknown field type: {}", channel.field_type).into());
-                    }
-                }
-
-                // Only add the timestamp and record together to ensure they stay in sync
+                // Values are decoded later from the recorded offset
                 times.push(timestamp);
-                data_records.push(record);
-
-                // Skip CRC (1 byte)
-                offset += 1;
-            } else if block_type == 1 {
-                // Marker record - skip marker message (50 bytes)
-                if offset + 50 > data.len() {
-                    eprintln!(
-                        "DEBUG: Not enough data for marker block at offset {} (need 50, have {})",
-                        offset,
-                        data.len() - offset
+                record_offsets.push(reader.position());
+                reader.skip(required_bytes)?;
+            } else {
+                // Marker record - 50 byte NUL-terminated message
+                let Ok(message) = reader.bytes(50) else {
+                    tracing::debug!(
+                        "MLG marker block at offset {} is truncated",
+                        reader.position()
                     );
                     break;
-                }
-                offset += 50;
-            } else {
-                eprintln!(
-                    "DEBUG: Unknown block type {} at offset {}",
-                    block_type,
-                    offset - 3
-                );
-                break; // Unknown block type
+                };
+                let message = message.split(|&b| b == 0).next().unwrap_or_default();
+                markers.push(LogMarker {
+                    time: timestamp,
+                    message: String::from_utf8_lossy(message).trim().to_string(),
+                });
             }
         }
 
-        eprintln!("DEBUG: Parsed {} data records", data_records.len());
-        eprintln!("DEBUG: Times vector length: {}", times.len());
-
-        // Debug: Check if timestamps are monotonically increasing
-        if times.len() > 1 {
-            eprintln!("DEBUG: Timestamp analysis:");
-            let mut non_monotonic_count = 0;
-            let mut prev_time: f64 = 0.0;
-            for (i, &t) in times.iter().enumerate() {
-                if i > 0 && t < prev_time {
-                    non_monotonic_count += 1;
-                    if non_monotonic_count <= 5 {
-                        eprintln!(
-                            "  Non-monotonic at index {}: {} -> {} (delta: {:.3})",
-                            i,
-                            prev_time,
-                            t,
-                            t - prev_time
-                        );
-                    }
-                }
-                prev_time = t;
-            }
-            if non_monotonic_count > 0 {
-                eprintln!("  Total non-monotonic jumps: {}", non_monotonic_count);
-            } else {
-                eprintln!("  All timestamps are monotonically increasing");
-            }
+        tracing::debug!(
+            "MLG parsed {} data records, skipped {}",
+            times.len(),
+            skipped
+        );
 
-            // Print first 10 and last 5 timestamps
-            eprintln!("DEBUG: First 10 timestamps:");
-            for (i, t) in times.iter().take(10).enumerate() {
-                eprintln!("  [{}] {}", i, t);
-            }
-            if times.len() > 15 {
-                eprintln!("DEBUG: Last 5 timestamps:");
-                for (i, t) in times.iter().skip(times.len() - 5).enumerate() {
-                    eprintln!("  [{}] {}", times.len() - 5 + i, t);
-                }
-            }
-        }
+        Ok(MlgScan {
+            meta,
+            channels,
+            fields,
+            times,
+            record_offsets,
+            skipped,
+            markers,
+        })
+    }
 
-        // Debug: Show first few records to verify data structure
-        if !data_records.is_empty() {
-            eprintln!("DEBUG: First record (time={}):", times[0]);
-            for (idx, val) in data_records[0].iter().enumerate() {
-                if idx < channels.len() {
-                    eprintln!("  [{}] {} = {:.3}", idx, channels[idx].name, val.as_f64());
-                }
-            }
-            if data_records.len() > 1 {
-                eprintln!("DEBUG: Second record (time={}):", times[1]);
-                for (idx, val) in data_records[1].iter().enumerate() {
-                    if idx < channels.len() {
-                        eprintln!("  [{}] {} = {:.3}", idx, channels[idx].name, val.as_f64());
-                    }
-                }
-            }
-        }
+    /// Offset of the first intact data record at or after `from`: a data
+    /// block whose checksum byte (the low byte of the sum of its field
+    /// bytes) matches, followed by another block or the end of the file
+    fn resync(data: &[u8], from: usize, record_size: usize) -> Option<usize> {
+        (from..data.len()).find(|&start| {
+            let end = start + 4 + record_size;
+            data[start] == 0
+                && matches!(data.get(end + 1), None | Some(0) | Some(1))
+                && data.get(start + 4..end).is_some_and(|fields| {
+                    let sum = fields.iter().fold(0u8, |sum, &b| sum.wrapping_add(b));
+                    data.get(end) == Some(&sum)
+                })
+        })
+    }
+}
 
-        // Validate that times and data match
-        if times.len() != data_records.len() {
-            return Err(format!(
-                "Data integrity error: {} timestamps but {} data records",
-                times.len(),
-                data_records.len()
-            )
-            .into());
-        }
+/// Bounds-checked big-endian reader over a byte slice. Reading past the end
+/// returns an error instead of panicking.
+struct ByteReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
 
-        // Validate that all data records have the correct number of values
-        let channel_count = channels.len();
-        for (i, record) in data_records.iter().enumerate() {
-            if record.len() != channel_count {
-                return Err(format!(
-                    "Data integrity error: record {} has {} values but {} channels expected",
-                    i,
-                    record.len(),
-                    channel_count
+impl<'a> ByteReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    fn position(&self) -> usize {
+        self.offset
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len().saturating_sub(self.offset)
+    }
+
+    /// Move to an absolute offset (reads past the end will fail)
+    fn seek(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+
+    /// The next `len` bytes
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], ParseError> {
+        let bytes = self
+            .offset
+            .checked_add(len)
+            .and_then(|end| self.data.get(self.offset..end))
+            .ok_or_else(|| {
+                ParseError::at_offset(
+                    self.offset,
+                    format!(
+                        "Unexpected end of file (need {} bytes, have {})",
+                        len,
+                        self.remaining()
+                    ),
                 )
-                .into());
-            }
-        }
+            })?;
+        self.offset += len;
+        Ok(bytes)
+    }
 
-        Ok(Log {
-            meta: super::types::Meta::Speeduino(meta),
-            channels: channels
-                .into_iter()
-                .map(super::types::Channel::Speeduino)
-                .collect(),
-            times,
-            data: data_records,
-        })
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], ParseError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.bytes(N)?);
+        Ok(array)
+    }
+
+    fn skip(&mut self, len: usize) -> Result<(), ParseError> {
+        self.bytes(len).map(|_| ())
+    }
+
+    fn u8(&mut self) -> Result<u8, ParseError> {
+        Ok(self.array::<1>()?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, ParseError> {
+        self.array().map(u16::from_be_bytes)
+    }
+
+    fn i16(&mut self) -> Result<i16, ParseError> {
+        self.array().map(i16::from_be_bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, ParseError> {
+        self.array().map(u32::from_be_bytes)
+    }
+
+    fn f32(&mut self) -> Result<f32, ParseError> {
+        self.array().map(f32::from_be_bytes)
+    }
+
+    /// A fixed-width, NUL-padded text field
+    fn string(&mut self, len: usize) -> Result<String, ParseError> {
+        Ok(String::from_utf8_lossy(self.bytes(len)?)
+            .trim_end_matches('\0')
+            .trim()
+            .to_string())
     }
 }
 
@@ -547,3 +603,207 @@ impl Parseable for Speeduino {
         Err("Speeduino/rusEFI MLG files are binary format. Use parse_binary() instead.".into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::types::ErrorLocation;
+
+    /// Build a minimal v1 MLG file with a U16 and an S08 field
+    fn sample_mlg(records: &[(u16, u16, i8)]) -> Vec<u8> {
+        let fields: [(u8, &str, &str, f32, f32); 2] =
+            [(2, "RPM", "rpm", 1.0, 0.0), (1, "CLT", "C", 0.5, 10.0)];
+        let data_begin = 22 + fields.len() * 55;
+
+        let mut data = b"MLVLG\0".to_vec();
+        data.extend(1i16.to_be_bytes()); // format version
+        data.extend(0i32.to_be_bytes()); // timestamp
+        data.extend(0u16.to_be_bytes()); // info_data_start
+        data.extend((data_begin as u32).to_be_bytes());
+        data.extend(0i16.to_be_bytes()); // record_length
+        data.extend((fields.len() as u16).to_be_bytes());
+
+        for (field_type, name, unit, scale, transform) in fields {
+            data.push(field_type);
+            let mut name_bytes = name.as_bytes().to_vec();
+            name_bytes.resize(34, 0);
+            data.extend(name_bytes);
+            let mut unit_bytes = unit.as_bytes().to_vec();
+            unit_bytes.resize(10, 0);
+            data.extend(unit_bytes);
+            data.push(0); // display style
+            data.extend(scale.to_be_bytes());
+            data.extend(transform.to_be_bytes());
+            data.push(0); // digits
+        }
+
+        for (i, &(timestamp_ms, rpm, clt)) in records.iter().enumerate() {
+            data.extend([0, i as u8]);
+            data.extend(timestamp_ms.to_be_bytes());
+            data.extend(rpm.to_be_bytes());
+            data.push(clt as u8);
+            let checksum = rpm
+                .to_be_bytes()
+                .iter()
+                .fold(clt as u8, |s, &b| s.wrapping_add(b));
+            data.push(checksum);
+        }
+        // Marker block
+        data.extend([1, 0, 0, 0]);
+        data.extend([0u8; 50]);
+        data
+    }
+
+    #[test]
+    fn test_parse_binary() {
+        let data = sample_mlg(&[(0, 1000, 60), (100, 1100, -20)]);
+        let log = Speeduino::parse_binary(&data).unwrap();
+
+        assert_eq!(log.channels.len(), 2);
+        assert_eq!(log.channels[0].name(), "RPM");
+        assert_eq!(log.times, vec![0.0, 0.1]);
+        assert_eq!(log.get_channel_data(0), &[1000.0, 1100.0]);
+        // (raw + transform) * scale
+        assert_eq!(log.get_channel_data(1), &[35.0, -5.0]);
+    }
+
+    #[test]
+    fn test_capture_time_of_day() {
+        let meta = |date: &str| SpeeduinoMeta {
+            capture_date: date.to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            meta("Capture Date: Fri Nov 18 13:24:56 EST 2022").capture_time_of_day(),
+            Some(13.0 * 3600.0 + 24.0 * 60.0 + 56.0)
+        );
+        assert_eq!(meta("Capture Date: 2022-11-18").capture_time_of_day(), None);
+        assert_eq!(meta("").capture_time_of_day(), None);
+    }
+
+    #[test]
+    fn test_markers() {
+        let mut data = sample_mlg(&[(0, 1000, 60), (100, 1100, -20)]);
+        let message_start = data.len() - 50;
+        data[message_start - 2..message_start].copy_from_slice(&150u16.to_be_bytes());
+        data[message_start..message_start + 6].copy_from_slice(b"Pull 1");
+        data[message_start + 7] = b'x'; // after the NUL terminator
+
+        let log = Speeduino::parse_binary(&data).unwrap();
+        assert_eq!(
+            log.markers,
+            vec![LogMarker {
+                time: 0.15,
+                message: "Pull 1".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_lazy_matches_eager() {
+        let data = sample_mlg(&[(0, 1000, 60), (100, 1100, -20), (200, 1200, 40)]);
+        let eager = Speeduino::parse_binary(&data).unwrap();
+        let lazy = Speeduino::parse_binary_lazy(data).unwrap();
+
+        let columns = lazy.lazy_data.as_ref().unwrap();
+        assert!(lazy.data.is_empty());
+        assert_eq!(columns.decoded_count(), 0);
+
+        assert_eq!(lazy.times, eager.times);
+        assert_eq!(lazy.get_channel_data(1), eager.get_channel_data(1));
+        assert_eq!(columns.decoded_count(), 1);
+        assert_eq!(lazy.value_at(0, 2), Some(1200.0));
+        assert_eq!(columns.decoded_count(), 2);
+    }
+
+    /// Parse a possibly malformed file both ways, reading every channel of
+    /// whatever loads. Must never panic.
+    fn parse_all(data: &[u8]) {
+        if let Ok(log) = Speeduino::parse_binary(data) {
+            assert!(log
+                .data
+                .iter()
+                .all(|column| column.len() == log.times.len()));
+        }
+        if let Ok(log) = Speeduino::parse_binary_lazy(data.to_vec()) {
+            for channel in 0..log.channels.len() {
+                assert_eq!(log.get_channel_data(channel).len(), log.times.len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_truncated_files() {
+        let data = sample_mlg(&[(0, 1000, 60), (100, 1100, -20), (200, 1200, 40)]);
+        for len in 0..data.len() {
+            parse_all(&data[..len]);
+        }
+
+        // A cut-off header is an error; a cut-off record just ends the log
+        let error = Speeduino::parse_binary(&data[..10]).unwrap_err();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(error.location, ErrorLocation::Offset(8));
+        let records_end = data.len() - 54;
+        let log = Speeduino::parse_binary(&data[..records_end - 1]).unwrap();
+        assert_eq!(log.record_count(), 2);
+        assert_eq!(log.skipped_records, 1);
+    }
+
+    #[test]
+    fn test_resync_after_damaged_block() {
+        let records: Vec<(u16, u16, i8)> =
+            (0..6).map(|i| (i * 10, 1000 + i * 50, i as i8)).collect();
+        let data = sample_mlg(&records);
+        let data_begin = 22 + 2 * 55;
+        let record_len = 4 + 3 + 1;
+
+        // Garbage over the header of the third record and into the fourth
+        let mut damaged = data.clone();
+        damaged[data_begin + 2 * record_len..data_begin + 3 * record_len + 2].fill(0xAA);
+        let log = Speeduino::parse_binary(&damaged).unwrap();
+        assert_eq!(log.get_channel_data(0), &[1000.0, 1050.0, 1200.0, 1250.0]);
+        assert_eq!(log.times, vec![0.0, 0.01, 0.04, 0.05]);
+        assert_eq!(log.skipped_records, 2);
+
+        // An intact file skips nothing
+        assert_eq!(Speeduino::parse_binary(&data).unwrap().skipped_records, 0);
+    }
+
+    #[test]
+    fn test_corrupt_files() {
+        // xorshift64: deterministic so failures reproduce
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        let records: Vec<(u16, u16, i8)> =
+            (0..20).map(|i| (i * 10, 1000 + i * 50, i as i8)).collect();
+        let original = sample_mlg(&records);
+        for _ in 0..5000 {
+            let mut data = original.clone();
+            for _ in 0..1 + next() % 4 {
+                let index = (next() % data.len() as u64) as usize;
+                data[index] = next() as u8;
+            }
+            let len = data.len() - (next() % 8) as usize;
+            parse_all(&data[..len]);
+        }
+
+        // Header fields pointing anywhere: version, data start and field count
+        for version in [0i16, 1, 2, -1] {
+            for data_begin in [0u32, 10, 22, 200, u32::MAX] {
+                for field_count in [0u16, 1, 2, 3, 999, u16::MAX] {
+                    let mut data = original.clone();
+                    data[6..8].copy_from_slice(&version.to_be_bytes());
+                    data[14..18].copy_from_slice(&data_begin.to_be_bytes());
+                    data[20..22].copy_from_slice(&field_count.to_be_bytes());
+                    parse_all(&data);
+                }
+            }
+        }
+    }
+}
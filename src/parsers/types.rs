@@ -1,26 +1,77 @@
 use serde::Serialize;
 use std::error::Error;
+use std::io::{self, BufRead, Write};
+use std::sync::{Arc, OnceLock};
 
-use super::ecumaster::{EcuMasterChannel, EcuMasterMeta};
-use super::haltech::{HaltechChannel, HaltechMeta};
+use crate::derived::{add_derived_channels, remove_derived_channels, DerivedChannel};
+
+use super::cobb::CobbMeta;
+use super::csv_profile::ProfileCsvMeta;
+use super::ecumaster::{EcuMaster, EcuMasterChannel, EcuMasterMeta};
+use super::generic_csv::{GenericCsv, GenericCsvChannel, GenericCsvMeta};
+use super::haltech::{Haltech, HaltechChannel, HaltechMeta};
+use super::hptuners::HpTunersMeta;
+use super::megasquirt::MegaSquirtMeta;
 use super::speeduino::{SpeeduinoChannel, SpeeduinoMeta};
 
 /// Metadata enum supporting different ECU formats
 #[derive(Clone, Debug, Serialize, Default)]
+#[serde(tag = "format", content = "meta")]
 pub enum Meta {
     Haltech(HaltechMeta),
     EcuMaster(EcuMasterMeta),
     Speeduino(SpeeduinoMeta),
+    HpTuners(HpTunersMeta),
+    Cobb(CobbMeta),
+    MegaSquirt(MegaSquirtMeta),
+    /// A logger read through a [`super::csv_profile::CsvProfile`]
+    Profile(ProfileCsvMeta),
+    Generic(GenericCsvMeta),
     #[default]
     Empty,
 }
 
+impl Meta {
+    /// Format-specific header fields worth showing to the user, as label and
+    /// value pairs. Fields the log left empty are omitted.
+    pub fn details(&self) -> Vec<(&'static str, String)> {
+        let fields: Vec<(&'static str, String)> = match self {
+            Meta::Haltech(m) => vec![
+                ("Software", m.software.clone()),
+                ("Software Version", m.software_version.clone()),
+                ("Data Log Version", m.data_log_version.clone()),
+                ("Downloaded", m.download_date_time.clone()),
+                ("Log Source", m.log_source.clone()),
+                ("Log Number", m.log_number.clone()),
+                ("Logged", m.log_date_time.clone()),
+            ],
+            Meta::Speeduino(m) => vec![
+                ("Firmware", m.version.clone()),
+                ("Capture Date", m.capture_date.clone()),
+            ],
+            Meta::HpTuners(m) => vec![("Format Version", m.version.clone())],
+            Meta::Cobb(m) => vec![("Accessport", m.ap_info.clone())],
+            Meta::MegaSquirt(m) => vec![("Firmware", m.signature.clone())],
+            Meta::Profile(m) => vec![("Logger", m.tool.clone())],
+            Meta::Generic(m) => vec![("Time Column", m.time_column.clone())],
+            Meta::EcuMaster(_) | Meta::Empty => Vec::new(),
+        };
+        fields
+            .into_iter()
+            .filter(|(_, value)| !value.trim().is_empty())
+            .collect()
+    }
+}
+
 /// Channel enum supporting different ECU formats
 #[derive(Clone, Debug)]
 pub enum Channel {
     Haltech(HaltechChannel),
     EcuMaster(EcuMasterChannel),
     Speeduino(SpeeduinoChannel),
+    Generic(GenericCsvChannel),
+    /// Computed from other channels, see [`crate::derived`]
+    Derived(DerivedChannel),
 }
 
 impl Serialize for Channel {
@@ -32,6 +83,8 @@ impl Serialize for Channel {
             Channel::Haltech(h) => h.serialize(serializer),
             Channel::EcuMaster(e) => e.serialize(serializer),
             Channel::Speeduino(s) => s.serialize(serializer),
+            Channel::Generic(g) => g.serialize(serializer),
+            Channel::Derived(d) => d.serialize(serializer),
         }
     }
 }
@@ -42,6 +95,8 @@ impl Channel {
             Channel::Haltech(h) => h.name.clone(),
             Channel::EcuMaster(e) => e.name.clone(),
             Channel::Speeduino(s) => s.name.clone(),
+            Channel::Generic(g) => g.name.clone(),
+            Channel::Derived(d) => d.name.clone(),
         }
     }
 
@@ -51,6 +106,8 @@ impl Channel {
             Channel::Haltech(h) => h.id.clone(),
             Channel::EcuMaster(e) => e.path.clone(),
             Channel::Speeduino(s) => s.name.clone(),
+            Channel::Generic(g) => g.name.clone(),
+            Channel::Derived(d) => d.name.clone(),
         }
     }
 
@@ -59,6 +116,8 @@ impl Channel {
             Channel::Haltech(h) => h.r#type.as_ref().to_string(),
             Channel::EcuMaster(e) => e.path.clone(),
             Channel::Speeduino(_) => "Speeduino/rusEFI".to_string(),
+            Channel::Generic(_) => "CSV".to_string(),
+            Channel::Derived(_) => crate::derived::DERIVED_GROUP.to_string(),
         }
     }
 
@@ -67,6 +126,8 @@ impl Channel {
             Channel::Haltech(h) => h.display_min,
             Channel::EcuMaster(_) => None,
             Channel::Speeduino(_) => None,
+            Channel::Generic(_) => None,
+            Channel::Derived(_) => None,
         }
     }
 
@@ -75,6 +136,8 @@ impl Channel {
             Channel::Haltech(h) => h.display_max,
             Channel::EcuMaster(_) => None,
             Channel::Speeduino(_) => None,
+            Channel::Generic(_) => None,
+            Channel::Derived(_) => None,
         }
     }
 
@@ -83,40 +146,131 @@ impl Channel {
             Channel::Haltech(h) => h.unit(),
             Channel::EcuMaster(e) => e.unit(),
             Channel::Speeduino(s) => s.unit(),
+            Channel::Generic(g) => g.unit(),
+            Channel::Derived(d) => d.unit(),
+        }
+    }
+
+    /// Category the ECU assigns this channel: the first segment of an
+    /// ECUMaster path or an MLG v2 category, if it has one
+    pub fn category(&self) -> Option<String> {
+        match self {
+            Channel::EcuMaster(e) => {
+                let (group, _) = e.path.split_once('/')?;
+                let mut chars = group.chars();
+                let first = chars.next()?;
+                Some(first.to_uppercase().chain(chars).collect())
+            }
+            Channel::Speeduino(s) if !s.category.is_empty() => Some(s.category.clone()),
+            Channel::Derived(_) => Some(crate::derived::DERIVED_GROUP.to_string()),
+            _ => None,
         }
     }
+
+    /// Whether this channel is computed rather than logged
+    pub fn is_derived(&self) -> bool {
+        matches!(self, Channel::Derived(_))
+    }
+}
+
+/// Source of channel values that are decoded on first access rather than at
+/// load time
+pub trait ColumnSource: Send + Sync {
+    /// Decode every value of one channel, one per entry in `Log::times`
+    fn decode_column(&self, channel_index: usize) -> Vec<f64>;
+}
+
+/// Channel columns decoded on demand, each cached after its first use
+#[derive(Clone)]
+pub struct LazyColumns {
+    source: Arc<dyn ColumnSource>,
+    cache: Arc<[OnceLock<Vec<f64>>]>,
 }
 
-/// Optimized value storage - all ECU log data is stored as f64
-/// This uses 8 bytes per value instead of 16 bytes with the previous enum
-#[derive(Clone, Copy, Debug, Default)]
-pub struct Value(f64);
+impl LazyColumns {
+    pub fn new(source: Arc<dyn ColumnSource>, channel_count: usize) -> Self {
+        Self {
+            source,
+            cache: (0..channel_count).map(|_| OnceLock::new()).collect(),
+        }
+    }
+
+    /// Get a channel column, decoding it on first access
+    pub fn column(&self, channel_index: usize) -> &[f64] {
+        self.cache.get(channel_index).map_or(&[], |cell| {
+            cell.get_or_init(|| self.source.decode_column(channel_index))
+        })
+    }
+
+    /// Number of channels decoded on demand
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
 
-impl Value {
-    /// Create a new Value from an f64
-    /// Note: Named 'Float' to maintain API compatibility with previous enum variant
-    #[inline]
-    #[allow(non_snake_case)]
-    pub fn Float(value: f64) -> Self {
-        Self(value)
+    /// Whether there are no channels to decode
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
     }
 
-    /// Convert value to f64 for charting
-    #[inline]
-    pub fn as_f64(&self) -> f64 {
-        self.0
+    /// Number of channels decoded so far
+    pub fn decoded_count(&self) -> usize {
+        self.cache
+            .iter()
+            .filter(|cell| cell.get().is_some())
+            .count()
+    }
+
+    /// Memory held by the columns decoded so far (bytes)
+    pub fn decoded_bytes(&self) -> usize {
+        self.cache
+            .iter()
+            .filter_map(|cell| cell.get())
+            .map(|column| column.len() * std::mem::size_of::<f64>())
+            .sum()
     }
 }
 
-impl Serialize for Value {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        serializer.serialize_f64(self.0)
+impl std::fmt::Debug for LazyColumns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyColumns")
+            .field("channels", &self.cache.len())
+            .field("decoded", &self.decoded_count())
+            .finish()
     }
 }
 
+/// Version of the JSON document written by [`Log::to_json`]
+pub const LOG_JSON_SCHEMA_VERSION: u32 = 1;
+
+/// JSON form of a log channel
+#[derive(Serialize)]
+struct ChannelJson<'a> {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    normalized_name: Option<&'a str>,
+    unit: &'a str,
+}
+
+/// JSON document for a log, see [`Log::to_json`]
+#[derive(Serialize)]
+struct LogJson<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    meta: &'a Meta,
+    channels: Vec<ChannelJson<'a>>,
+    times: &'a [f64],
+    data: Vec<&'a [f64]>,
+}
+
+/// A point of interest recorded in the log itself, e.g. an MLG marker block
+#[derive(Clone, Debug, PartialEq)]
+pub struct LogMarker {
+    /// Time of the marker in seconds
+    pub time: f64,
+    /// Text recorded with the marker, possibly empty
+    pub message: String,
+}
+
 /// Parsed log file structure
 #[derive(Clone, Debug, Default)]
 pub struct Log {
@@ -125,23 +279,337 @@ pub struct Log {
     pub channels: Vec<Channel>,
     /// Time values stored directly as f64 (seconds) for efficiency
     pub times: Vec<f64>,
-    pub data: Vec<Vec<Value>>,
+    /// Column-major channel data: one column per channel, each holding one
+    /// value per entry in `times`
+    pub data: Vec<Vec<f64>>,
+    /// Columns decoded on demand instead of `data` (very large binary logs).
+    /// `data` then only holds the columns of channels added after loading.
+    pub lazy_data: Option<LazyColumns>,
+    /// Damaged records the parser skipped while loading
+    pub skipped_records: usize,
+    /// Markers recorded alongside the data, in time order
+    pub markers: Vec<LogMarker>,
 }
 
 impl Log {
-    /// Get data for a specific channel by index
-    pub fn get_channel_data(&self, channel_index: usize) -> Vec<f64> {
-        self.data
+    /// Get data for a specific channel by index (empty if out of range)
+    pub fn get_channel_data(&self, channel_index: usize) -> &[f64] {
+        match &self.lazy_data {
+            Some(lazy) if channel_index < lazy.len() => lazy.column(channel_index),
+            Some(lazy) => self
+                .data
+                .get(channel_index - lazy.len())
+                .map_or(&[], Vec::as_slice),
+            None => self.data.get(channel_index).map_or(&[], Vec::as_slice),
+        }
+    }
+
+    /// Whether a channel holds no data: every value is zero or NaN, as with
+    /// unused inputs logged by many ECUs
+    pub fn is_channel_empty(&self, channel_index: usize) -> bool {
+        self.get_channel_data(channel_index)
             .iter()
-            .filter_map(|row| row.get(channel_index).map(|v| v.as_f64()))
+            .all(|v| *v == 0.0 || v.is_nan())
+    }
+
+    /// Add a channel after the existing ones, with one value per record
+    pub fn push_channel(&mut self, channel: Channel, column: Vec<f64>) {
+        self.channels.push(channel);
+        self.data.push(column);
+    }
+
+    /// Apply user unit overrides (keyed by channel path) to ECUMaster
+    /// channels, whose units are otherwise guessed from their names
+    pub fn apply_unit_overrides(&mut self, overrides: &std::collections::HashMap<String, String>) {
+        for channel in &mut self.channels {
+            if let Channel::EcuMaster(e) = channel {
+                e.apply_unit_override(overrides);
+            }
+        }
+    }
+
+    /// Number of records (samples) in the log
+    pub fn record_count(&self) -> usize {
+        self.times.len()
+    }
+
+    /// Summary of a load that had to skip damaged records, e.g. "Loaded
+    /// 182,334 of 183,001 records, 667 skipped". None if nothing was skipped.
+    pub fn skipped_summary(&self) -> Option<String> {
+        if self.skipped_records == 0 {
+            return None;
+        }
+        let loaded = self.record_count();
+        Some(format!(
+            "Loaded {} of {} records, {} skipped",
+            group_thousands(loaded),
+            group_thousands(loaded + self.skipped_records),
+            group_thousands(self.skipped_records)
+        ))
+    }
+
+    /// Estimated memory held by the times and channel data (bytes). Columns
+    /// decoded on demand only count once decoded.
+    pub fn memory_bytes(&self) -> usize {
+        let values = self.times.len() + self.data.iter().map(Vec::len).sum::<usize>();
+        values * std::mem::size_of::<f64>()
+            + self
+                .lazy_data
+                .as_ref()
+                .map_or(0, LazyColumns::decoded_bytes)
+    }
+
+    /// The records from `start` to `end` seconds as a log of their own, with
+    /// times starting at zero. Markers within the range are kept.
+    pub fn slice(&self, start: f64, end: f64) -> Log {
+        let first = self.times.partition_point(|&t| t < start);
+        let last = self.times.partition_point(|&t| t <= end).max(first);
+        let offset = self.times.get(first).copied().unwrap_or(start);
+        let mut meta = self.meta.clone();
+        if let Meta::Haltech(meta) = &mut meta {
+            meta.first_timestamp += offset;
+        }
+        Log {
+            meta,
+            channels: self.channels.clone(),
+            times: self.times[first..last].iter().map(|t| t - offset).collect(),
+            data: (0..self.channels.len())
+                .map(|channel| {
+                    let column = self.get_channel_data(channel);
+                    column[first.min(column.len())..last.min(column.len())].to_vec()
+                })
+                .collect(),
+            lazy_data: None,
+            skipped_records: 0,
+            markers: self
+                .markers
+                .iter()
+                .filter(|marker| (start..=end).contains(&marker.time))
+                .map(|marker| LogMarker {
+                    time: marker.time - offset,
+                    message: marker.message.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Split a file holding several logging sessions (Haltech multi-log
+    /// exports) into one log per session. Other logs come back whole.
+    pub fn split_runs(self) -> Vec<Log> {
+        match &self.meta {
+            Meta::Haltech(meta) if meta.runs.len() > 1 => Haltech::split_runs(self),
+            _ => vec![self],
+        }
+    }
+
+    /// Time of day of the first record (seconds since midnight), for
+    /// formats that record it: Haltech timestamps and the MLG capture date
+    pub fn wall_clock_start(&self) -> Option<f64> {
+        match &self.meta {
+            Meta::Haltech(meta) if !self.times.is_empty() => Some(meta.first_timestamp),
+            Meta::Speeduino(meta) => meta.capture_time_of_day(),
+            _ => None,
+        }
+    }
+
+    /// Time between the first and last record (seconds)
+    pub fn duration(&self) -> f64 {
+        match (self.times.first(), self.times.last()) {
+            (Some(first), Some(last)) => last - first,
+            _ => 0.0,
+        }
+    }
+
+    /// Average number of records per second, if the log spans any time
+    pub fn record_rate(&self) -> Option<f64> {
+        let duration = self.duration();
+        (duration > 0.0).then(|| (self.record_count() - 1) as f64 / duration)
+    }
+
+    /// Index of the record closest in time to `time`, if there are any
+    pub fn record_at_time(&self, time: f64) -> Option<usize> {
+        let after = self.times.partition_point(|&t| t < time);
+        match (after.checked_sub(1), self.times.get(after)) {
+            (Some(before), Some(&next)) if time - self.times[before] <= next - time => Some(before),
+            (_, Some(_)) => Some(after),
+            (before, None) => before,
+        }
+    }
+
+    /// The records either side of `time` and how far between them it lies
+    /// (0 at the first, 1 at the second), if it is within the log
+    fn bracket(&self, time: f64) -> Option<(usize, usize, f64)> {
+        let after = self.times.partition_point(|&t| t < time);
+        let next = *self.times.get(after)?;
+        if next == time {
+            return Some((after, after, 0.0));
+        }
+        let before = after.checked_sub(1)?;
+        let previous = self.times[before];
+        Some((before, after, (time - previous) / (next - previous)))
+    }
+
+    /// Value of every channel at `time`, interpolated linearly between the
+    /// records either side. Values outside the log's time range, or next to
+    /// a missing value, are NaN.
+    pub fn interpolate_at(&self, time: f64) -> Vec<f64> {
+        let bracket = self.bracket(time);
+        (0..self.channels.len())
+            .map(|channel| {
+                let column = self.get_channel_data(channel);
+                let values = bracket.and_then(|(before, after, fraction)| {
+                    Some((*column.get(before)?, *column.get(after)?, fraction))
+                });
+                match values {
+                    // On a record, its neighbour doesn't matter even if missing
+                    Some((v0, _, 0.0)) => v0,
+                    Some((v0, v1, fraction)) => v0 + (v1 - v0) * fraction,
+                    None => f64::NAN,
+                }
+            })
             .collect()
     }
 
+    /// A copy of the log at a fixed `rate_hz` records per second from its
+    /// first record, with every channel interpolated as in
+    /// [`Log::interpolate_at`]. Markers are kept.
+    pub fn resample(&self, rate_hz: f64) -> Log {
+        let start = self.times.first().copied().unwrap_or(0.0);
+        let count = if rate_hz > 0.0 && !self.times.is_empty() {
+            (self.duration() * rate_hz + 1e-9).floor() as usize + 1
+        } else {
+            0
+        };
+        let times: Vec<f64> = (0..count).map(|i| start + i as f64 / rate_hz).collect();
+
+        let mut data = vec![Vec::with_capacity(count); self.channels.len()];
+        for &time in &times {
+            for (column, value) in data.iter_mut().zip(self.interpolate_at(time)) {
+                column.push(value);
+            }
+        }
+        Log {
+            meta: self.meta.clone(),
+            channels: self.channels.clone(),
+            times,
+            data,
+            lazy_data: None,
+            skipped_records: 0,
+            markers: self.markers.clone(),
+        }
+    }
+
+    /// Value of a channel at a given record, if both exist
+    pub fn value_at(&self, channel_index: usize, record: usize) -> Option<f64> {
+        self.get_channel_data(channel_index).get(record).copied()
+    }
+
     /// Get time values as f64 slice (seconds) - no parsing needed, stored directly
     pub fn get_times_as_f64(&self) -> &[f64] {
         &self.times
     }
 
+    /// Whether records written to the file after loading can be appended
+    /// (text formats only)
+    pub fn supports_append(&self) -> bool {
+        self.lazy_data.is_none()
+            && matches!(
+                self.meta,
+                Meta::Haltech(_) | Meta::EcuMaster(_) | Meta::Generic(_)
+            )
+    }
+
+    /// Parse complete lines written to the file after loading and append
+    /// their records. Returns the number of records added.
+    pub fn append_lines(&mut self, lines: &[String]) -> usize {
+        if !self.supports_append() {
+            return 0;
+        }
+        // Parsers only know the logged channels, so derived ones are
+        // recomputed over the grown log afterwards
+        let had_derived = self.channels.iter().any(Channel::is_derived);
+        remove_derived_channels(self);
+        let added = match self.meta {
+            Meta::Haltech(_) => Haltech::append_lines(self, lines),
+            Meta::EcuMaster(_) => EcuMaster::append_lines(self, lines),
+            Meta::Generic(_) => GenericCsv::append_lines(self, lines),
+            _ => 0,
+        };
+        if had_derived {
+            add_derived_channels(self);
+        }
+        added
+    }
+
+    /// Serialize the log to a JSON string, see [`Log::to_json_writer`]
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        let mut out = Vec::new();
+        self.to_json_writer(&mut out)?;
+        Ok(String::from_utf8(out).expect("serde_json writes UTF-8"))
+    }
+
+    /// Write the log as a JSON document with a stable schema:
+    ///
+    /// ```json
+    /// {
+    ///   "schema_version": 1,
+    ///   "format": "Haltech",
+    ///   "meta": { ...format-specific metadata... },
+    ///   "channels": [{ "name": "RPM", "unit": "RPM" }],
+    ///   "times": [0.0, 0.02],
+    ///   "data": [[1000.0, 1010.0]]
+    /// }
+    /// ```
+    ///
+    /// `format` is one of `Haltech`, `EcuMaster`, `Speeduino`, `HpTuners`,
+    /// `Cobb`, `Profile` (a [`super::csv_profile::CsvProfile`] logger, named
+    /// in `meta.tool`), `MegaSquirt`, `Generic` or `Empty` (which has no
+    /// `meta`). `times` are in seconds, and `data` holds
+    /// one array per channel in the same order as `channels`, each with one
+    /// value per time. Values are in the log's source units; missing values
+    /// are `null`. `schema_version` changes if the layout changes.
+    pub fn to_json_writer<W: Write>(&self, writer: W) -> serde_json::Result<()> {
+        self.write_json(writer, None)
+    }
+
+    /// Like [`Log::to_json_writer`], adding each channel's `normalized_name`
+    pub fn to_json_writer_normalized<W: Write>(
+        &self,
+        writer: W,
+        normalized_names: &[String],
+    ) -> serde_json::Result<()> {
+        self.write_json(writer, Some(normalized_names))
+    }
+
+    fn write_json<W: Write>(
+        &self,
+        writer: W,
+        normalized_names: Option<&[String]>,
+    ) -> serde_json::Result<()> {
+        let channels = self
+            .channels
+            .iter()
+            .enumerate()
+            .map(|(idx, channel)| ChannelJson {
+                name: channel.name(),
+                normalized_name: normalized_names
+                    .and_then(|names| names.get(idx))
+                    .map(String::as_str),
+                unit: channel.unit(),
+            })
+            .collect();
+        let document = LogJson {
+            schema_version: LOG_JSON_SCHEMA_VERSION,
+            meta: &self.meta,
+            channels,
+            times: &self.times,
+            data: (0..self.channels.len())
+                .map(|idx| self.get_channel_data(idx))
+                .collect(),
+        };
+        serde_json::to_writer(writer, &document)
+    }
+
     /// Find channel index by name
     #[allow(dead_code)]
     pub fn find_channel_index(&self, name: &str) -> Option<usize> {
@@ -149,6 +617,172 @@ impl Log {
     }
 }
 
+/// Splits bytes appended to a text log into complete lines, holding back a
+/// trailing partial line until the rest of it has been written
+#[derive(Clone, Debug, Default)]
+pub struct LogTail {
+    /// Bytes of the file consumed so far
+    pub offset: u64,
+    partial: Vec<u8>,
+}
+
+impl LogTail {
+    /// Start tailing a file after its first `offset` bytes
+    pub fn new(offset: u64) -> Self {
+        Self {
+            offset,
+            partial: Vec::new(),
+        }
+    }
+
+    /// Consume newly written bytes, returning any lines they complete
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<String> {
+        self.offset += bytes.len() as u64;
+        self.partial.extend_from_slice(bytes);
+        let Some(end) = self.partial.iter().rposition(|&b| b == b'\n') else {
+            return Vec::new();
+        };
+        let complete: Vec<u8> = self.partial.drain(..=end).collect();
+        String::from_utf8_lossy(&complete)
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// Bytes consumed between progress callbacks when parsing from a reader
+const PROGRESS_INTERVAL_BYTES: u64 = 1024 * 1024;
+
+/// Line-by-line reader for text log formats.
+///
+/// Decodes each line as lossy UTF-8 (so files with stray encoding issues still
+/// parse) and reports the number of bytes consumed to a progress callback
+/// roughly every megabyte.
+pub struct LineReader<'a, R> {
+    reader: R,
+    buf: Vec<u8>,
+    line: String,
+    bytes_read: u64,
+    last_report: u64,
+    progress: &'a dyn Fn(u64),
+}
+
+impl<'a, R: BufRead> LineReader<'a, R> {
+    /// Wrap a reader, reporting bytes consumed to `progress`
+    pub fn new(reader: R, progress: &'a dyn Fn(u64)) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            line: String::new(),
+            bytes_read: 0,
+            last_report: 0,
+            progress,
+        }
+    }
+
+    /// Read the next line without its line ending, or `None` at end of input
+    pub fn next_line(&mut self) -> io::Result<Option<&str>> {
+        self.buf.clear();
+        let read = self.reader.read_until(b'\n', &mut self.buf)?;
+        if read == 0 {
+            (self.progress)(self.bytes_read);
+            return Ok(None);
+        }
+
+        self.bytes_read += read as u64;
+        if self.bytes_read - self.last_report >= PROGRESS_INTERVAL_BYTES {
+            self.last_report = self.bytes_read;
+            (self.progress)(self.bytes_read);
+        }
+
+        while matches!(self.buf.last(), Some(b'\n' | b'\r')) {
+            self.buf.pop();
+        }
+        self.line.clear();
+        self.line.push_str(&String::from_utf8_lossy(&self.buf));
+        Ok(Some(&self.line))
+    }
+
+    /// Read whole lines, line endings included, until at least `max_bytes`
+    /// have been read or the input ends. Returns `None` at end of input.
+    /// Bulk data is read this way to avoid per-line copies.
+    pub fn next_lines(&mut self, max_bytes: usize) -> io::Result<Option<&str>> {
+        // Reuse the previous block's allocation
+        self.buf = std::mem::take(&mut self.line).into_bytes();
+        self.buf.clear();
+        while self.buf.len() < max_bytes {
+            if self.reader.read_until(b'\n', &mut self.buf)? == 0 {
+                break;
+            }
+        }
+
+        self.bytes_read += self.buf.len() as u64;
+        if self.buf.is_empty() || self.bytes_read - self.last_report >= PROGRESS_INTERVAL_BYTES {
+            self.last_report = self.bytes_read;
+            (self.progress)(self.bytes_read);
+        }
+        if self.buf.is_empty() {
+            return Ok(None);
+        }
+
+        self.line = match String::from_utf8(std::mem::take(&mut self.buf)) {
+            Ok(text) => text,
+            Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+        };
+        Ok(Some(&self.line))
+    }
+}
+
+/// Where in a file a parser gave up
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ErrorLocation {
+    /// 1-based line of a text log
+    Line(usize),
+    /// Byte offset into a binary log
+    Offset(usize),
+}
+
+impl std::fmt::Display for ErrorLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorLocation::Line(line) => write!(f, "line {}", line),
+            ErrorLocation::Offset(offset) => write!(f, "byte offset {}", offset),
+        }
+    }
+}
+
+/// A parse failure that knows where in the file it happened, so the
+/// error dialog can point at it
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+    pub location: ErrorLocation,
+}
+
+impl ParseError {
+    pub fn at_line(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: ErrorLocation::Line(line),
+        }
+    }
+
+    pub fn at_offset(offset: usize, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: ErrorLocation::Offset(offset),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.message, self.location)
+    }
+}
+
+impl Error for ParseError {}
+
 /// Trait for log file parsers
 pub trait Parseable {
     fn parse(&self, data: &str) -> Result<Log, Box<dyn Error>>;
@@ -167,6 +801,14 @@ pub enum EcuType {
     MotEc,
     Link,
     Speeduino,
+    HpTuners,
+    Cobb,
+    EcuTek,
+    RomRaider,
+    Emtron,
+    Adaptronic,
+    Holley,
+    Generic,
     Unknown,
 }
 
@@ -181,7 +823,213 @@ impl EcuType {
             EcuType::MotEc => "MoTeC",
             EcuType::Link => "Link",
             EcuType::Speeduino => "Speeduino/rusEFI",
+            EcuType::HpTuners => "HP Tuners",
+            EcuType::Cobb => "Cobb Accessport",
+            EcuType::EcuTek => "EcuTek ProECU",
+            EcuType::RomRaider => "RomRaider (Subaru SSM)",
+            EcuType::Emtron => "Emtron",
+            EcuType::Adaptronic => "Adaptronic",
+            EcuType::Holley => "Holley EFI",
+            EcuType::Generic => "Generic CSV",
             EcuType::Unknown => "Unknown",
         }
     }
 }
+
+/// Format a count with comma thousands separators, e.g. "183,001"
+pub fn group_thousands(n: usize) -> String {
+    let digits = n.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::csv_profile::PROFILES;
+    use crate::parsers::{Cobb, HpTuners, ProfileCsv};
+
+    #[test]
+    fn test_record_at_time() {
+        let mut log = Log::default();
+        assert_eq!(log.record_at_time(1.0), None);
+
+        log.times = vec![0.0, 1.0, 2.0];
+        assert_eq!(log.record_at_time(-5.0), Some(0));
+        assert_eq!(log.record_at_time(0.4), Some(0));
+        assert_eq!(log.record_at_time(0.6), Some(1));
+        assert_eq!(log.record_at_time(2.0), Some(2));
+        assert_eq!(log.record_at_time(9.0), Some(2));
+    }
+
+    #[test]
+    fn test_duration_and_record_rate() {
+        let mut log = Log::default();
+        assert_eq!(log.duration(), 0.0);
+        assert_eq!(log.record_rate(), None);
+
+        log.times = vec![0.0];
+        assert_eq!(log.record_rate(), None);
+
+        log.times = (0..=100).map(|i| i as f64 * 0.02).collect();
+        assert!((log.duration() - 2.0).abs() < 1e-9);
+        assert!((log.record_rate().unwrap() - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_interpolate_at() {
+        let log = GenericCsv::default()
+            .parse("Time,RPM,AFR\n0,1000,14\n1,2000,NaN\n2,3000,12\n")
+            .unwrap();
+        assert_eq!(log.interpolate_at(0.25)[0], 1250.0);
+        assert_eq!(log.interpolate_at(2.0), vec![3000.0, 12.0]);
+        assert!(log.interpolate_at(0.5)[1].is_nan());
+        assert!(log.interpolate_at(-0.1)[0].is_nan());
+        assert!(log.interpolate_at(2.1)[0].is_nan());
+    }
+
+    #[test]
+    fn test_resample() {
+        let log = GenericCsv::default()
+            .parse("Time,RPM\n0,1000\n0.3,1600\n1,3000\n")
+            .unwrap();
+        let resampled = log.resample(2.0);
+        assert_eq!(resampled.times, vec![0.0, 0.5, 1.0]);
+        assert_eq!(resampled.get_channel_data(0), &[1000.0, 2000.0, 3000.0]);
+        assert_eq!(log.resample(0.0).record_count(), 0);
+    }
+
+    #[test]
+    fn test_slice() {
+        let mut log = GenericCsv::default()
+            .parse("Time,RPM\n10,1000\n10.5,1500\n11,2000\n11.5,2500\n")
+            .unwrap();
+        log.markers = vec![
+            LogMarker {
+                time: 10.0,
+                message: "start".to_string(),
+            },
+            LogMarker {
+                time: 11.2,
+                message: "pull".to_string(),
+            },
+        ];
+
+        let slice = log.slice(10.4, 11.2);
+        assert_eq!(slice.times, vec![0.0, 0.5]);
+        assert_eq!(slice.get_channel_data(0), &[1500.0, 2000.0]);
+        assert_eq!(slice.markers.len(), 1);
+        assert!((slice.markers[0].time - 0.7).abs() < 1e-9);
+        assert_eq!(log.slice(20.0, 30.0).record_count(), 0);
+    }
+
+    #[test]
+    fn test_meta_details_skip_empty_fields() {
+        let meta = Meta::Speeduino(SpeeduinoMeta {
+            version: "speeduino 202501".to_string(),
+            capture_date: String::new(),
+        });
+        assert_eq!(
+            meta.details(),
+            vec![("Firmware", "speeduino 202501".to_string())]
+        );
+        assert!(Meta::Empty.details().is_empty());
+    }
+
+    #[test]
+    fn test_skipped_summary() {
+        let mut log = Log {
+            times: vec![0.0; 182_334],
+            ..Default::default()
+        };
+        assert_eq!(log.skipped_summary(), None);
+        log.skipped_records = 667;
+        assert_eq!(
+            log.skipped_summary().unwrap(),
+            "Loaded 182,334 of 183,001 records, 667 skipped"
+        );
+        assert_eq!(group_thousands(0), "0");
+        assert_eq!(group_thousands(1_000), "1,000");
+        assert_eq!(group_thousands(12_345_678), "12,345,678");
+    }
+
+    #[test]
+    fn test_is_channel_empty() {
+        let log = Log {
+            channels: vec![],
+            times: vec![0.0, 0.1],
+            data: vec![vec![0.0, f64::NAN], vec![0.0, 1.0]],
+            ..Default::default()
+        };
+        assert!(log.is_channel_empty(0));
+        assert!(!log.is_channel_empty(1));
+    }
+
+    #[test]
+    fn test_log_tail_holds_partial_lines() {
+        let mut tail = LogTail::new(100);
+
+        assert!(tail.push(b"0.1;10").is_empty());
+        assert_eq!(tail.push(b"00\r\n0.2;1"), vec!["0.1;1000".to_string()]);
+        assert_eq!(tail.push(b"100\n"), vec!["0.2;1100".to_string()]);
+        assert_eq!(tail.offset, 100 + 19);
+    }
+
+    #[test]
+    fn test_to_json_schema() {
+        let log = GenericCsv::default()
+            .parse("Time,RPM (rpm),TPS\n0,1000,5\n0.5,1500,\n")
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&log.to_json().unwrap()).unwrap();
+
+        assert_eq!(value["schema_version"], LOG_JSON_SCHEMA_VERSION);
+        assert_eq!(value["format"], "Generic");
+        assert!(value["meta"].is_object());
+        assert_eq!(value["channels"][0]["name"], "RPM");
+        assert_eq!(value["channels"][0]["unit"], "rpm");
+        assert!(value["channels"][0].get("normalized_name").is_none());
+        assert_eq!(value["times"][1], 0.5);
+        assert_eq!(value["data"][0][1], 1500.0);
+
+        let mut out = Vec::new();
+        log.to_json_writer_normalized(&mut out, &["Engine RPM".into(), "Throttle".into()])
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(value["channels"][1]["normalized_name"], "Throttle");
+    }
+
+    #[test]
+    fn test_to_json_format_names() {
+        let format = |log: Log| -> serde_json::Value {
+            serde_json::from_str::<serde_json::Value>(&log.to_json().unwrap()).unwrap()["format"]
+                .clone()
+        };
+
+        let log = HpTuners
+            .parse("Offset,Engine RPM\ns,rpm\n0.0,800\n")
+            .unwrap();
+        assert_eq!(format(log), "HpTuners");
+
+        let log = Cobb
+            .parse("Time (sec),RPM (RPM),AP Info:[AP3]\n0.0,800\n")
+            .unwrap();
+        assert_eq!(format(log), "Cobb");
+
+        let log = ProfileCsv(&PROFILES[0])
+            .parse("ProECU\nTime (s),RPM\n0.0,800\n")
+            .unwrap();
+        assert_eq!(format(log), "Profile");
+
+        let log = Log {
+            meta: Meta::MegaSquirt(Default::default()),
+            ..Default::default()
+        };
+        assert_eq!(format(log), "MegaSquirt");
+    }
+}
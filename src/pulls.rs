@@ -0,0 +1,214 @@
+//! Wide-open-throttle pull detection.
+//!
+//! A pull is a stretch where the throttle stays above a threshold while RPM
+//! climbs. A WOT run through several gears is split at each shift (where RPM
+//! falls away from its peak), so every gear becomes its own pull.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// RPM drop from the running peak that ends a pull (a shift or lift)
+const SHIFT_RPM_DROP: f64 = 300.0;
+
+/// RPM bin width used when overlaying pulls against RPM
+pub const COMPARE_RPM_BIN: f64 = 100.0;
+
+/// Thresholds a stretch of the log must meet to count as a pull
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PullConfig {
+    /// Minimum throttle position (%)
+    pub min_tps: f64,
+    /// Minimum pull length (seconds)
+    pub min_duration: f64,
+    /// Minimum RPM gained over the pull
+    pub min_rpm_rise: f64,
+}
+
+impl Default for PullConfig {
+    fn default() -> Self {
+        Self {
+            min_tps: 90.0,
+            min_duration: 2.0,
+            min_rpm_rise: 1000.0,
+        }
+    }
+}
+
+/// A detected full-throttle pull
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pull {
+    /// Index of the first record of the pull
+    pub start_record: usize,
+    /// Index of the last record of the pull (its peak RPM)
+    pub end_record: usize,
+    /// Time of the first record (seconds)
+    pub start_time: f64,
+    /// Time of the last record (seconds)
+    pub end_time: f64,
+    /// RPM at the start of the pull
+    pub start_rpm: f64,
+    /// Highest RPM reached, at the end of the pull
+    pub peak_rpm: f64,
+}
+
+impl Pull {
+    /// Duration of the pull in seconds
+    pub fn duration(&self) -> f64 {
+        self.end_time - self.start_time
+    }
+
+    /// Highest finite value of a channel over the pull
+    pub fn peak(&self, data: &[f64]) -> Option<f64> {
+        data.get(self.start_record..=self.end_record)?
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .reduce(f64::max)
+    }
+
+    /// A channel's values over the pull averaged into RPM bins, as
+    /// `[bin center RPM, mean value]` points in RPM order. Binning lines up
+    /// pulls sampled at different rates and smooths sensor noise.
+    pub fn rpm_curve(&self, rpm: &[f64], values: &[f64], bin_width: f64) -> Vec<[f64; 2]> {
+        let mut bins: BTreeMap<i64, (f64, usize)> = BTreeMap::new();
+        for record in self.start_record..=self.end_record {
+            let (Some(&r), Some(&v)) = (rpm.get(record), values.get(record)) else {
+                break;
+            };
+            if r.is_finite() && v.is_finite() {
+                let bin = bins.entry((r / bin_width).floor() as i64).or_default();
+                bin.0 += v;
+                bin.1 += 1;
+            }
+        }
+        bins.into_iter()
+            .map(|(bin, (sum, count))| [(bin as f64 + 0.5) * bin_width, sum / count as f64])
+            .collect()
+    }
+}
+
+impl PullConfig {
+    /// Find every pull in a log from its throttle and RPM channels
+    pub fn detect(&self, times: &[f64], tps: &[f64], rpm: &[f64]) -> Vec<Pull> {
+        let len = times.len().min(tps.len()).min(rpm.len());
+        let mut pulls = Vec::new();
+        // Start record and index of the highest RPM so far of the open pull
+        let mut open: Option<(usize, usize)> = None;
+
+        for record in 0..=len {
+            let wot = record < len && tps[record] >= self.min_tps && rpm[record].is_finite();
+            if let Some((start, peak)) = open {
+                let shifted = wot && rpm[record] < rpm[peak] - SHIFT_RPM_DROP;
+                if !wot || shifted {
+                    pulls.extend(self.accept(times, rpm, start, peak));
+                    open = wot.then_some((record, record));
+                } else if rpm[record] >= rpm[peak] {
+                    open = Some((start, record));
+                }
+            } else if wot {
+                open = Some((record, record));
+            }
+        }
+        pulls
+    }
+
+    /// The pull from `start` to its peak RPM, if it is long enough and
+    /// gains enough RPM
+    fn accept(&self, times: &[f64], rpm: &[f64], start: usize, peak: usize) -> Option<Pull> {
+        let pull = Pull {
+            start_record: start,
+            end_record: peak,
+            start_time: times[start],
+            end_time: times[peak],
+            start_rpm: rpm[start],
+            peak_rpm: rpm[peak],
+        };
+        (pull.duration() >= self.min_duration
+            && pull.peak_rpm - pull.start_rpm >= self.min_rpm_rise)
+            .then_some(pull)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 10 Hz samples: cruise, then WOT through two gears with a shift, then lift
+    fn sample_run() -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        let mut tps = vec![20.0; 10];
+        let mut rpm = vec![2500.0; 10];
+        // First gear: 3000 -> 6500 over 3.5 s
+        for i in 0..36 {
+            tps.push(100.0);
+            rpm.push(3000.0 + i as f64 * 100.0);
+        }
+        // Shift, then second gear: 4500 -> 6500 over 4 s
+        for i in 0..41 {
+            tps.push(100.0);
+            rpm.push(4500.0 + i as f64 * 50.0);
+        }
+        tps.extend([0.0; 10]);
+        rpm.extend([3000.0; 10]);
+        let times = (0..tps.len()).map(|i| i as f64 * 0.1).collect();
+        (times, tps, rpm)
+    }
+
+    #[test]
+    fn test_detect_pulls() {
+        let (times, tps, rpm) = sample_run();
+        let pulls = PullConfig::default().detect(&times, &tps, &rpm);
+        assert_eq!(pulls.len(), 2);
+
+        assert_eq!(pulls[0].start_record, 10);
+        assert_eq!(pulls[0].end_record, 45);
+        assert_eq!(pulls[0].start_rpm, 3000.0);
+        assert_eq!(pulls[0].peak_rpm, 6500.0);
+        assert!((pulls[0].duration() - 3.5).abs() < 1e-9);
+
+        assert_eq!(pulls[1].start_record, 46);
+        assert_eq!(pulls[1].peak_rpm, 6500.0);
+        assert_eq!(pulls[1].peak(&rpm), Some(6500.0));
+    }
+
+    #[test]
+    fn test_rpm_curve() {
+        let pull = Pull {
+            start_record: 1,
+            end_record: 4,
+            start_time: 0.1,
+            end_time: 0.4,
+            start_rpm: 3000.0,
+            peak_rpm: 3250.0,
+        };
+        let rpm = [2000.0, 3000.0, 3050.0, 3150.0, 3250.0, 9000.0];
+        let boost = [0.0, 100.0, 110.0, 150.0, f64::NAN, 0.0];
+        assert_eq!(
+            pull.rpm_curve(&rpm, &boost, 100.0),
+            vec![[3050.0, 105.0], [3150.0, 150.0]]
+        );
+    }
+
+    #[test]
+    fn test_pull_thresholds() {
+        let (times, tps, rpm) = sample_run();
+        let strict = PullConfig {
+            min_duration: 3.8,
+            ..Default::default()
+        };
+        assert_eq!(strict.detect(&times, &tps, &rpm).len(), 1);
+
+        let high_rise = PullConfig {
+            min_rpm_rise: 3000.0,
+            ..Default::default()
+        };
+        assert_eq!(high_rise.detect(&times, &tps, &rpm).len(), 1);
+
+        let partial = PullConfig {
+            min_tps: 101.0,
+            ..Default::default()
+        };
+        assert!(partial.detect(&times, &tps, &rpm).is_empty());
+    }
+}
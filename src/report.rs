@@ -0,0 +1,325 @@
+//! Customer reports combining several analyses of one log.
+//!
+//! A [`Report`] is a title, a few lines of details and a list of sections,
+//! each with optional notes and a table of pre-formatted cells. The app
+//! fills it from the analysis windows and writes it as a standalone HTML
+//! page or a paginated PDF, so both formats show the same content.
+
+use std::error::Error;
+use std::fmt::Write as _;
+use std::io::Write;
+
+use printpdf::{BuiltinFont, IndirectFontRef, Mm, PdfDocument, PdfLayerReference};
+use serde::{Deserialize, Serialize};
+
+/// Analyses included in a report
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReportConfig {
+    /// WOT pulls with RPM range and peak boost and speed
+    pub pulls: bool,
+    /// Leanest AFR over each pull against the AFR target
+    pub afr_safety: bool,
+    /// Boost control over each pull
+    pub boost: bool,
+    /// Minimum, mean and maximum of the charted channels
+    pub statistics: bool,
+}
+
+impl Default for ReportConfig {
+    fn default() -> Self {
+        Self {
+            pulls: true,
+            afr_safety: true,
+            boost: true,
+            statistics: true,
+        }
+    }
+}
+
+/// A table of pre-formatted cells
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReportTable {
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// One analysis in a report
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReportSection {
+    pub title: String,
+    /// Paragraphs shown above the table, e.g. thresholds or why the
+    /// analysis couldn't run
+    pub notes: Vec<String>,
+    pub table: Option<ReportTable>,
+}
+
+/// A report ready to write as HTML or PDF
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    pub title: String,
+    /// Lines under the title, e.g. the log file and its length
+    pub details: Vec<String>,
+    pub sections: Vec<ReportSection>,
+}
+
+/// Escape text for HTML element content and attribute values
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Text the PDF's built-in fonts can show: they only cover Latin-1, so
+/// symbols used in units and labels are spelled out and anything else
+/// becomes '?'
+fn pdf_text(text: &str) -> String {
+    let mut safe = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            'λ' => safe.push_str("lambda"),
+            'Δ' => safe.push_str("delta "),
+            '→' => safe.push_str("->"),
+            '⚠' => safe.push('!'),
+            c if (c as u32) < 0x100 => safe.push(c),
+            _ => safe.push('?'),
+        }
+    }
+    safe
+}
+
+/// Page size and layout of the PDF (A4 portrait, in mm)
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+/// Height of a table row
+const ROW_HEIGHT: f32 = 5.0;
+/// Table text size (pt) and the average Helvetica character width at it
+const TABLE_FONT_SIZE: f32 = 8.0;
+const TABLE_CHAR_WIDTH: f32 = 1.5;
+
+/// Writes text top to bottom, starting a new page when one fills up
+struct PdfWriter {
+    doc: printpdf::PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    /// Baseline of the next line, from the bottom of the page
+    y: f32,
+}
+
+impl PdfWriter {
+    /// Move down by `height`, starting a new page if that would run into
+    /// the bottom margin
+    fn advance(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            let (page, layer) = self.doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Report");
+            self.layer = self.doc.get_page(page).get_layer(layer);
+            self.y = PAGE_HEIGHT - MARGIN;
+        }
+        self.y -= height;
+    }
+
+    fn text(&self, text: &str, size: f32, x: f32, bold: bool) {
+        let font = if bold { &self.bold } else { &self.regular };
+        self.layer
+            .use_text(pdf_text(text), size, Mm(x), Mm(self.y), font);
+    }
+
+    /// A paragraph wrapped to the page width
+    fn paragraph(&mut self, text: &str, size: f32) {
+        let max_chars = ((PAGE_WIDTH - 2.0 * MARGIN) / (size * 0.19)) as usize;
+        let mut line = String::new();
+        for word in text.split_whitespace() {
+            if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > max_chars {
+                self.advance(size * 0.5);
+                self.text(&line, size, MARGIN, false);
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(word);
+        }
+        if !line.is_empty() {
+            self.advance(size * 0.5);
+            self.text(&line, size, MARGIN, false);
+        }
+    }
+
+    /// One table row in equal-width columns, cutting cells that don't fit
+    fn row(&mut self, cells: &[String], bold: bool) {
+        self.advance(ROW_HEIGHT);
+        let column_width = (PAGE_WIDTH - 2.0 * MARGIN) / cells.len().max(1) as f32;
+        let max_chars = ((column_width - 1.0) / TABLE_CHAR_WIDTH).max(1.0) as usize;
+        for (i, cell) in cells.iter().enumerate() {
+            let cell: String = if cell.chars().count() > max_chars {
+                cell.chars()
+                    .take(max_chars.saturating_sub(1))
+                    .chain(['.'])
+                    .collect()
+            } else {
+                cell.clone()
+            };
+            let x = MARGIN + i as f32 * column_width;
+            self.text(&cell, TABLE_FONT_SIZE, x, bold);
+        }
+    }
+}
+
+impl Report {
+    /// The report as a standalone HTML page with inline styles
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        let _ = write!(
+            html,
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{}</title>\n<style>\n\
+             body {{ font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; \
+             margin: 2em auto; max-width: 960px; color: #222; padding: 0 1em; }}\n\
+             h1 {{ margin-bottom: 0.2em; }}\n\
+             h2 {{ border-bottom: 2px solid #71784e; padding-bottom: 0.2em; margin-top: 1.6em; }}\n\
+             .details {{ color: #666; margin: 0.1em 0; }}\n\
+             table {{ border-collapse: collapse; width: 100%; margin: 0.6em 0; }}\n\
+             th, td {{ text-align: left; padding: 0.35em 0.8em; border-bottom: 1px solid #ddd; }}\n\
+             th {{ background: #f2f3ea; }}\n\
+             tr:nth-child(even) td {{ background: #fafaf6; }}\n\
+             footer {{ color: #999; font-size: 0.85em; margin-top: 3em; }}\n\
+             </style>\n</head>\n<body>\n",
+            escape_html(&self.title)
+        );
+        let _ = writeln!(html, "<h1>{}</h1>", escape_html(&self.title));
+        for line in &self.details {
+            let _ = writeln!(html, "<p class=\"details\">{}</p>", escape_html(line));
+        }
+
+        for section in &self.sections {
+            let _ = writeln!(html, "<h2>{}</h2>", escape_html(&section.title));
+            for note in &section.notes {
+                let _ = writeln!(html, "<p>{}</p>", escape_html(note));
+            }
+            let Some(table) = &section.table else {
+                continue;
+            };
+            html.push_str("<table>\n<thead><tr>");
+            for header in &table.headers {
+                let _ = write!(html, "<th>{}</th>", escape_html(header));
+            }
+            html.push_str("</tr></thead>\n<tbody>\n");
+            for row in &table.rows {
+                html.push_str("<tr>");
+                for cell in row {
+                    let _ = write!(html, "<td>{}</td>", escape_html(cell));
+                }
+                html.push_str("</tr>\n");
+            }
+            html.push_str("</tbody>\n</table>\n");
+        }
+
+        let _ = writeln!(
+            html,
+            "<footer>Generated by UltraLog {}</footer>\n</body>\n</html>",
+            env!("CARGO_PKG_VERSION")
+        );
+        html
+    }
+
+    /// Write the report as an A4 PDF
+    pub fn write_pdf(&self, out: &mut impl Write) -> Result<(), Box<dyn Error>> {
+        let (doc, page, layer) = PdfDocument::new(
+            pdf_text(&self.title),
+            Mm(PAGE_WIDTH),
+            Mm(PAGE_HEIGHT),
+            "Report",
+        );
+        let layer = doc.get_page(page).get_layer(layer);
+        let regular = doc.add_builtin_font(BuiltinFont::Helvetica)?;
+        let bold = doc.add_builtin_font(BuiltinFont::HelveticaBold)?;
+        let mut pdf = PdfWriter {
+            doc,
+            layer,
+            regular,
+            bold,
+            y: PAGE_HEIGHT - MARGIN,
+        };
+
+        pdf.advance(8.0);
+        pdf.text(&self.title, 18.0, MARGIN, true);
+        pdf.advance(2.0);
+        for line in &self.details {
+            pdf.paragraph(line, 10.0);
+        }
+
+        for section in &self.sections {
+            pdf.advance(12.0);
+            pdf.text(&section.title, 13.0, MARGIN, true);
+            pdf.advance(2.0);
+            for note in &section.notes {
+                pdf.paragraph(note, 9.0);
+            }
+            if let Some(table) = &section.table {
+                pdf.advance(2.0);
+                pdf.row(&table.headers, true);
+                for row in &table.rows {
+                    pdf.row(row, false);
+                }
+            }
+        }
+
+        pdf.doc.save(&mut std::io::BufWriter::new(out))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        Report {
+            title: "Dyno day <Subaru>".to_string(),
+            details: vec!["Log: run1.csv".to_string()],
+            sections: vec![ReportSection {
+                title: "WOT Pulls".to_string(),
+                notes: vec!["Pulls start at 90% throttle & above 2000 RPM".to_string()],
+                table: Some(ReportTable {
+                    headers: vec!["#".to_string(), "Peak AFR".to_string()],
+                    rows: vec![vec!["1".to_string(), "0.82 λ".to_string()]],
+                }),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_to_html() {
+        let html = report().to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<h1>Dyno day &lt;Subaru&gt;</h1>"));
+        assert!(html.contains("<p>Pulls start at 90% throttle &amp; above 2000 RPM</p>"));
+        assert!(html.contains("<th>#</th><th>Peak AFR</th>"));
+        assert!(html.contains("<td>1</td><td>0.82 λ</td>"));
+        assert!(html.trim_end().ends_with("</html>"));
+    }
+
+    #[test]
+    fn test_pdf_text() {
+        assert_eq!(pdf_text("0.82 λ at 25 °C"), "0.82 lambda at 25 °C");
+        assert_eq!(pdf_text("1→2 ✓"), "1->2 ?");
+    }
+
+    #[test]
+    fn test_write_pdf() {
+        let mut pdf = Vec::new();
+        report().write_pdf(&mut pdf).unwrap();
+        assert!(pdf.starts_with(b"%PDF"));
+    }
+}
@@ -0,0 +1,134 @@
+//! Session save/load.
+//!
+//! A session records the open log files along with each tab's selected
+//! channels, annotations, time offset and logging session (for files holding
+//! several), so a working view can be restored later.
+//! Sessions are stored as JSON with a `.ulsession` extension.
+
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::state::Annotation;
+
+/// Current session file format version
+pub const SESSION_VERSION: u32 = 1;
+
+/// File extension used for session files
+pub const SESSION_EXTENSION: &str = "ulsession";
+
+/// Saved state for a single tab
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionTab {
+    /// Path to the log file shown in this tab
+    pub path: PathBuf,
+    /// Original names of the selected channels
+    pub channels: Vec<String>,
+    /// Time range annotations
+    pub annotations: Vec<Annotation>,
+    /// Time offset of the tab's log file (seconds)
+    pub time_offset: f64,
+    /// Logging session shown, for files holding several
+    pub run: usize,
+}
+
+/// A saved session
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Session {
+    /// Format version the session was written with
+    pub version: u32,
+    /// Open tabs, in display order
+    #[serde(default)]
+    pub tabs: Vec<SessionTab>,
+    /// Index of the active tab
+    #[serde(default)]
+    pub active_tab: Option<usize>,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self {
+            version: SESSION_VERSION,
+            tabs: Vec::new(),
+            active_tab: None,
+        }
+    }
+}
+
+impl Session {
+    /// Serialize the session to pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Parse a session from JSON
+    pub fn from_json(json: &str) -> Result<Self, Box<dyn Error>> {
+        let session: Session = serde_json::from_str(json)?;
+        if session.version > SESSION_VERSION {
+            return Err(format!(
+                "Session was saved by a newer version of UltraLog (format v{})",
+                session.version
+            )
+            .into());
+        }
+        Ok(session)
+    }
+
+    /// Write the session to a file
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, self.to_json()?)?;
+        Ok(())
+    }
+
+    /// Read a session from a file
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_round_trip() {
+        let session = Session {
+            version: SESSION_VERSION,
+            tabs: vec![SessionTab {
+                path: PathBuf::from("/logs/track_day.csv"),
+                channels: vec!["RPM".to_string(), "Manifold Pressure".to_string()],
+                annotations: vec![Annotation {
+                    start: 12.5,
+                    end: 18.0,
+                    label: "3rd gear pull".to_string(),
+                    color: [253, 193, 73],
+                }],
+                time_offset: -3600.0,
+                run: 2,
+            }],
+            active_tab: Some(0),
+        };
+
+        let json = session.to_json().unwrap();
+        let restored = Session::from_json(&json).unwrap();
+        assert_eq!(restored, session);
+    }
+
+    #[test]
+    fn test_session_missing_fields() {
+        let restored =
+            Session::from_json(r#"{"version": 1, "tabs": [{"path": "a.csv"}]}"#).unwrap();
+        assert_eq!(restored.tabs.len(), 1);
+        assert!(restored.tabs[0].annotations.is_empty());
+        assert_eq!(restored.tabs[0].time_offset, 0.0);
+        assert_eq!(restored.tabs[0].run, 0);
+        assert_eq!(restored.active_tab, None);
+    }
+
+    #[test]
+    fn test_session_newer_version_rejected() {
+        assert!(Session::from_json(r#"{"version": 99}"#).is_err());
+    }
+}
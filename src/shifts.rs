@@ -0,0 +1,273 @@
+//! Gear shift detection.
+//!
+//! Upshifts are found from the gear channel when the log has one: a shift is
+//! the gear number going up, ignoring neutral readings in between. Without a
+//! gear channel, a shift is RPM falling quickly from high RPM and climbing
+//! again once the next gear is in, which tells it apart from lifting off.
+//! Either way the shift runs from the RPM peak before it to the lowest RPM
+//! after it, so its duration is how long RPM was falling.
+
+use serde::{Deserialize, Serialize};
+
+/// Share of the drop RPM must climb back for an RPM-only shift to count
+const CLIMB_FRACTION: f64 = 0.1;
+
+/// Thresholds a shift must meet to be reported
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShiftConfig {
+    /// Lowest RPM at the shift; shifts below it (cruising) are ignored
+    pub min_rpm: f64,
+    /// Smallest RPM drop that counts as a shift, without a gear channel
+    pub min_rpm_drop: f64,
+    /// Longest time RPM can take to fall, without a gear channel (seconds)
+    pub max_duration: f64,
+}
+
+impl Default for ShiftConfig {
+    fn default() -> Self {
+        Self {
+            min_rpm: 2500.0,
+            min_rpm_drop: 500.0,
+            max_duration: 0.6,
+        }
+    }
+}
+
+/// An upshift
+#[derive(Clone, Debug, PartialEq)]
+pub struct Shift {
+    /// Index of the record with the RPM peak before the shift
+    pub start_record: usize,
+    /// Index of the record with the lowest RPM after the shift
+    pub end_record: usize,
+    /// Time of the RPM peak (seconds)
+    pub start_time: f64,
+    /// Time of the lowest RPM (seconds)
+    pub end_time: f64,
+    /// Gear shifted out of, if the log has a gear channel
+    pub from_gear: Option<u32>,
+    /// Gear shifted into, if the log has a gear channel
+    pub to_gear: Option<u32>,
+    /// RPM at the shift
+    pub shift_rpm: f64,
+    /// RPM the engine landed at in the next gear
+    pub landing_rpm: f64,
+}
+
+impl Shift {
+    /// RPM lost over the shift
+    pub fn rpm_drop(&self) -> f64 {
+        self.shift_rpm - self.landing_rpm
+    }
+
+    /// Time from the RPM peak to the lowest RPM (seconds)
+    pub fn duration(&self) -> f64 {
+        self.end_time - self.start_time
+    }
+
+    /// Short description, e.g. "2→3", or "Shift" without gears
+    pub fn label(&self) -> String {
+        match (self.from_gear, self.to_gear) {
+            (Some(from), Some(to)) => format!("{}→{}", from, to),
+            _ => "Shift".to_string(),
+        }
+    }
+}
+
+/// Averages of the shifts out of one gear
+#[derive(Clone, Debug, PartialEq)]
+pub struct GearSummary {
+    /// Gear shifted out of, or `None` for shifts without a gear channel
+    pub gear: Option<u32>,
+    pub count: usize,
+    pub shift_rpm: f64,
+    pub rpm_drop: f64,
+    pub duration: f64,
+}
+
+/// Averages per gear shifted out of, lowest gear first
+pub fn summarize(shifts: &[Shift]) -> Vec<GearSummary> {
+    let mut gears: Vec<Option<u32>> = shifts.iter().map(|s| s.from_gear).collect();
+    gears.sort();
+    gears.dedup();
+    gears
+        .into_iter()
+        .map(|gear| {
+            let shifts: Vec<&Shift> = shifts.iter().filter(|s| s.from_gear == gear).collect();
+            let count = shifts.len();
+            let mean = |value: fn(&Shift) -> f64| {
+                shifts.iter().map(|&s| value(s)).sum::<f64>() / count as f64
+            };
+            GearSummary {
+                gear,
+                count,
+                shift_rpm: mean(|s| s.shift_rpm),
+                rpm_drop: mean(Shift::rpm_drop),
+                duration: mean(Shift::duration),
+            }
+        })
+        .collect()
+}
+
+impl ShiftConfig {
+    /// Find upshifts in time order, from `gear` if the log has a gear
+    /// channel and from RPM alone otherwise
+    pub fn detect(&self, times: &[f64], rpm: &[f64], gear: Option<&[f64]>) -> Vec<Shift> {
+        let len = times.len().min(rpm.len());
+        match gear {
+            Some(gear) => self.detect_from_gear(times, &rpm[..len], gear),
+            None => self.detect_from_rpm(times, &rpm[..len]),
+        }
+    }
+
+    /// A shift for RPM falling from around `from` to around `to`, following
+    /// RPM back to its peak and forward to its lowest point
+    fn shift_between(times: &[f64], rpm: &[f64], from: usize, to: usize) -> (usize, usize) {
+        let mut peak = from;
+        while peak > 0 && rpm[peak - 1] > rpm[peak] {
+            peak -= 1;
+        }
+        let mut low = to.max(peak);
+        while low + 1 < rpm.len().min(times.len()) && rpm[low + 1] < rpm[low] {
+            low += 1;
+        }
+        (peak, low)
+    }
+
+    fn detect_from_gear(&self, times: &[f64], rpm: &[f64], gear: &[f64]) -> Vec<Shift> {
+        let mut shifts = Vec::new();
+        // Last gear engaged and the last record it was engaged at
+        let mut engaged: Option<(u32, usize)> = None;
+        for (i, value) in gear.iter().take(rpm.len()).enumerate() {
+            let value = value.round();
+            if !(value.is_finite() && value >= 1.0) {
+                continue;
+            }
+            let current = value as u32;
+            if let Some((previous, last)) = engaged {
+                if current > previous {
+                    let (peak, low) = Self::shift_between(times, rpm, last, i);
+                    if rpm[peak] >= self.min_rpm {
+                        shifts.push(Shift {
+                            start_record: peak,
+                            end_record: low,
+                            start_time: times[peak],
+                            end_time: times[low],
+                            from_gear: Some(previous),
+                            to_gear: Some(current),
+                            shift_rpm: rpm[peak],
+                            landing_rpm: rpm[low],
+                        });
+                    }
+                }
+            }
+            engaged = Some((current, i));
+        }
+        shifts
+    }
+
+    fn detect_from_rpm(&self, times: &[f64], rpm: &[f64]) -> Vec<Shift> {
+        let len = rpm.len();
+        let mut shifts = Vec::new();
+        let mut i = 1;
+        while i < len {
+            if !(rpm[i] < rpm[i - 1] && rpm[i - 1] >= self.min_rpm) {
+                i += 1;
+                continue;
+            }
+
+            let (peak, low) = Self::shift_between(times, rpm, i - 1, i);
+            let drop = rpm[peak] - rpm[low];
+            let climbs = (low + 1..len)
+                .take_while(|&j| times[j] - times[low] <= self.max_duration)
+                .any(|j| rpm[j] >= rpm[low] + drop * CLIMB_FRACTION);
+            if drop >= self.min_rpm_drop && times[low] - times[peak] <= self.max_duration && climbs
+            {
+                shifts.push(Shift {
+                    start_record: peak,
+                    end_record: low,
+                    start_time: times[peak],
+                    end_time: times[low],
+                    from_gear: None,
+                    to_gear: None,
+                    shift_rpm: rpm[peak],
+                    landing_rpm: rpm[low],
+                });
+            }
+            i = low + 1;
+        }
+        shifts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn times(len: usize) -> Vec<f64> {
+        (0..len).map(|i| i as f64 * 0.1).collect()
+    }
+
+    #[test]
+    fn test_detect_from_gear() {
+        let config = ShiftConfig::default();
+        // 1st to 2nd through neutral, then a cruising shift to 3rd below
+        // the minimum RPM
+        let rpm = [
+            5000.0, 6000.0, 6500.0, 5500.0, 4500.0, 4400.0, 4700.0, 3000.0, 2000.0, 2200.0, 1600.0,
+            1700.0,
+        ];
+        let gear = [1.0, 1.0, 1.0, 1.0, 0.0, 2.0, 2.0, 2.0, 2.0, 2.0, 3.0, 3.0];
+        let shifts = config.detect(&times(rpm.len()), &rpm, Some(&gear));
+
+        assert_eq!(shifts.len(), 1);
+        let shift = &shifts[0];
+        assert_eq!((shift.start_record, shift.end_record), (2, 5));
+        assert_eq!(shift.label(), "1→2");
+        assert_eq!(shift.rpm_drop(), 2100.0);
+        assert!((shift.duration() - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_detect_from_rpm() {
+        let config = ShiftConfig::default();
+        // A shift from 6500 RPM, then lifting off, which never climbs again
+        let rpm = [
+            6000.0, 6500.0, 5200.0, 4600.0, 4800.0, 5200.0, 6200.0, 5400.0, 4600.0, 3800.0, 3000.0,
+            3000.0,
+        ];
+        let shifts = config.detect(&times(rpm.len()), &rpm, None);
+
+        assert_eq!(shifts.len(), 1);
+        assert_eq!((shifts[0].start_record, shifts[0].end_record), (1, 3));
+        assert_eq!(shifts[0].from_gear, None);
+        assert_eq!(shifts[0].label(), "Shift");
+    }
+
+    #[test]
+    fn test_summarize() {
+        let shift = |from: u32, rpm: f64, landing: f64| Shift {
+            start_record: 0,
+            end_record: 1,
+            start_time: 0.0,
+            end_time: 0.2,
+            from_gear: Some(from),
+            to_gear: Some(from + 1),
+            shift_rpm: rpm,
+            landing_rpm: landing,
+        };
+        let summary = summarize(&[
+            shift(2, 6800.0, 4800.0),
+            shift(1, 6500.0, 4000.0),
+            shift(1, 6700.0, 4200.0),
+        ]);
+
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].gear, Some(1));
+        assert_eq!(summary[0].count, 2);
+        assert_eq!(summary[0].shift_rpm, 6600.0);
+        assert_eq!(summary[0].rpm_drop, 2500.0);
+        assert_eq!(summary[1].gear, Some(2));
+    }
+}
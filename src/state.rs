@@ -3,20 +3,76 @@
 //! This module contains the fundamental data structures used throughout
 //! the application, including loaded files, selected channels, and color palettes.
 
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
 
-use crate::parsers::{Channel, EcuType, Log};
+use crate::alarms::{AlarmRule, Condition, Violation};
+use crate::binning::AxisBins;
+use crate::drag::DragRun;
+use crate::dyno::DynoConfig;
+use crate::egt::EgtSpreadConfig;
+use crate::fuel_trim::{FuelTrimConfig, TrimReport};
+use crate::i18n::Language;
+use crate::idle::{IdleConfig, IdleReport};
+use crate::live::LiveConfig;
+use crate::misfire::{MisfireConfig, MisfireEvent};
+use crate::parsers::{Channel, EcuType, ErrorLocation, Log, LogTail, ParseError};
+use crate::pulls::{Pull, PullConfig};
+use crate::report::ReportConfig;
+use crate::shifts::{Shift, ShiftConfig};
+use crate::units::UnitPreferences;
+use crate::updater::UpdateChannel;
 
 // ============================================================================
 // Constants
 // ============================================================================
 
-/// Maximum number of channels that can be selected simultaneously
-pub const MAX_CHANNELS: usize = 10;
+/// Default number of channels that can be selected simultaneously. Each
+/// channel is downsampled and drawn every frame, so the limit keeps the
+/// chart responsive on large logs.
+pub const DEFAULT_MAX_CHANNELS: usize = 20;
+
+/// Highest channel limit the user can configure
+pub const MAX_CHANNELS_LIMIT: usize = 64;
+
+/// Range of the UI scale setting, applied on top of the monitor's own
+/// scale factor
+pub const MIN_UI_SCALE: f32 = 0.75;
+pub const MAX_UI_SCALE: f32 = 2.0;
+
+/// Range of the line thickness setting, which scales every chart line
+pub const MIN_LINE_WIDTH_SCALE: f32 = 0.5;
+pub const MAX_LINE_WIDTH_SCALE: f32 = 3.0;
+
+/// Selected channel count above which the chart legend uses smaller text
+pub const COMPACT_CHANNEL_COUNT: usize = 10;
+
+/// Maximum number of files loaded in parallel
+pub const MAX_PARALLEL_LOADS: usize = 4;
+
+/// Storage key for persisted user settings
+pub const SETTINGS_KEY: &str = "ultralog_settings";
 
 /// Maximum points to render in chart (for performance via LTTB downsampling)
 pub const MAX_CHART_POINTS: usize = 2000;
 
+/// Range of the chart point budget the user can configure
+pub const MIN_CHART_POINTS: usize = 500;
+pub const MAX_CHART_POINTS_LIMIT: usize = 20_000;
+
+/// MLG files larger than this many megabytes are memory-mapped and decoded
+/// lazily instead of read into memory
+pub const DEFAULT_MMAP_THRESHOLD_MB: u64 = 10;
+
+/// Memory the chart's downsample caches may use before the least recently
+/// drawn channels are dropped (MB)
+pub const DEFAULT_CACHE_BUDGET_MB: u64 = 256;
+
+/// Number of leading bytes kept from an unrecognised file for the triage preview
+pub const TRIAGE_PREVIEW_BYTES: usize = 4096;
+
 /// Color palette for chart lines (matches original theme)
 pub const CHART_COLORS: &[[u8; 3]] = &[
     [113, 120, 78],  // Olive green (primary)
@@ -61,6 +117,65 @@ pub struct LoadedFile {
     pub ecu_type: EcuType,
     /// Parsed log data
     pub log: Log,
+    /// Read position for appending new records (text logs only)
+    pub tail: Option<LogTail>,
+    /// Whether the file is watched for newly written records
+    pub following: bool,
+    /// Seconds added to the times the log was recorded with, already
+    /// applied to its records and markers
+    pub time_offset: f64,
+    /// Every logging session of a file holding several (Haltech multi-log
+    /// exports), see [`Log::split_runs`]. The slot of the session shown in
+    /// `log` holds an empty log. Empty for files of one session.
+    pub runs: Vec<Log>,
+    /// Index of the session shown in `log`
+    pub run: usize,
+}
+
+/// Dash style of a channel's chart line
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LineStyle {
+    #[default]
+    Solid,
+    Dashed,
+    Dotted,
+}
+
+impl LineStyle {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LineStyle::Solid => "Solid",
+            LineStyle::Dashed => "Dashed",
+            LineStyle::Dotted => "Dotted",
+        }
+    }
+}
+
+/// Default width of channel lines on the chart
+pub const DEFAULT_LINE_WIDTH: f32 = 1.5;
+
+/// User overrides for how a selected channel is drawn
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChannelStyle {
+    /// Line color, replacing the palette color when set
+    pub color: Option<[u8; 3]>,
+    /// Line width in points
+    pub line_width: f32,
+    pub line_style: LineStyle,
+    /// Draw a boolean/enum channel as a state band below the analog lines
+    #[serde(default)]
+    pub state_band: bool,
+}
+
+impl Default for ChannelStyle {
+    fn default() -> Self {
+        Self {
+            color: None,
+            line_width: DEFAULT_LINE_WIDTH,
+            line_style: LineStyle::Solid,
+            state_band: false,
+        }
+    }
 }
 
 /// A channel selected for visualization on the chart
@@ -74,20 +189,147 @@ pub struct SelectedChannel {
     pub channel: Channel,
     /// Index into the color palette for this channel's line
     pub color_index: usize,
+    /// Color and line style overrides
+    pub style: ChannelStyle,
 }
 
 /// Result from background file loading operation
 pub enum LoadResult {
     Success(Box<LoadedFile>),
-    Error(String),
+    Error(LoadError),
+    /// No parser recognised the file - hand it to the triage dialog
+    Unsupported(Box<UnsupportedFile>),
+}
+
+/// Why a file failed to load, with the details shown in the error dialog
+#[derive(Clone, Debug)]
+pub struct LoadError {
+    pub message: String,
+    /// Format the file was detected as, if loading got that far
+    pub format: Option<EcuType>,
+    /// Where in the file the parser gave up, if it knows
+    pub location: Option<ErrorLocation>,
+}
+
+impl LoadError {
+    /// A parser's failure for a detected format, keeping the location a
+    /// [`ParseError`] carries
+    pub fn parse(
+        format: EcuType,
+        context: &str,
+        error: &(dyn std::error::Error + 'static),
+    ) -> Self {
+        let parse_error = error.downcast_ref::<ParseError>();
+        Self {
+            message: format!(
+                "{}: {}",
+                context,
+                parse_error.map_or_else(|| error.to_string(), |e| e.message.clone())
+            ),
+            format: Some(format),
+            location: parse_error.map(|e| e.location),
+        }
+    }
+}
+
+impl From<String> for LoadError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            format: None,
+            location: None,
+        }
+    }
+}
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.location {
+            Some(location) => write!(f, "{} ({})", self.message, location),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// A file that no parser could detect, kept for the triage dialog
+pub struct UnsupportedFile {
+    /// Path to the original file
+    pub path: PathBuf,
+    /// Why detection failed
+    pub reason: String,
+    /// The first bytes of the file (up to `TRIAGE_PREVIEW_BYTES`)
+    pub preview: Vec<u8>,
+}
+
+/// State of the unsupported file triage dialog
+pub struct TriageState {
+    /// The file being triaged
+    pub file: UnsupportedFile,
+    /// Show the preview as a hex dump instead of text
+    pub show_hex: bool,
+    /// Delimiter chosen for the generic CSV parser
+    pub delimiter: char,
+    /// Zero-based line containing the column names
+    pub header_line: usize,
+    /// Zero-based index of the time column
+    pub time_column: usize,
+}
+
+/// Progress of a single file being loaded in the background
+#[derive(Clone, Debug)]
+pub struct LoadProgress {
+    /// Identifier matching the worker's messages
+    pub id: u64,
+    /// Path of the file being loaded
+    pub path: PathBuf,
+    /// File name for display
+    pub name: String,
+    /// Bytes processed so far
+    pub bytes_done: u64,
+    /// Total size of the file in bytes (0 if unknown)
+    pub bytes_total: u64,
+}
+
+impl LoadProgress {
+    /// Fraction complete (0.0 - 1.0), if the file size is known
+    pub fn fraction(&self) -> Option<f32> {
+        (self.bytes_total > 0).then(|| (self.bytes_done as f64 / self.bytes_total as f64) as f32)
+    }
+}
+
+/// Message sent from a background load worker to the UI
+pub enum LoadMessage {
+    /// Bytes processed so far for a load
+    Progress {
+        id: u64,
+        bytes_done: u64,
+        bytes_total: u64,
+    },
+    /// A load finished
+    Done { id: u64, result: LoadResult },
 }
 
 /// Current state of file loading
 pub enum LoadingState {
     /// No loading in progress
     Idle,
-    /// Loading a file (contains filename being loaded)
-    Loading(String),
+    /// Loading one or more files in parallel, with per-file progress
+    LoadingMany(Vec<LoadProgress>),
+}
+
+impl LoadingState {
+    /// Whether any file is currently loading
+    pub fn is_loading(&self) -> bool {
+        matches!(self, LoadingState::LoadingMany(loads) if !loads.is_empty())
+    }
+
+    /// Files currently loading
+    pub fn loads(&self) -> &[LoadProgress] {
+        match self {
+            LoadingState::Idle => &[],
+            LoadingState::LoadingMany(loads) => loads,
+        }
+    }
 }
 
 /// Type of toast notification (determines color)
@@ -143,6 +385,12 @@ pub enum ActiveTool {
     LogViewer,
     /// Scatter plot view for comparing two variables with color coding
     ScatterPlot,
+    /// GPS track map colored by a selected channel
+    TrackMap,
+    /// Virtual dyno power estimation from a WOT pull
+    Dyno,
+    /// Raw record values in a scrollable table
+    DataTable,
 }
 
 impl ActiveTool {
@@ -151,10 +399,63 @@ impl ActiveTool {
         match self {
             ActiveTool::LogViewer => "Log Viewer",
             ActiveTool::ScatterPlot => "Scatter Plots",
+            ActiveTool::TrackMap => "Track Map",
+            ActiveTool::Dyno => "Virtual Dyno",
+            ActiveTool::DataTable => "Data Table",
         }
     }
 }
 
+/// Color theme of the interface
+#[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+}
+
+/// Page of the preferences window
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum SettingsCategory {
+    #[default]
+    General,
+    Display,
+    Accessibility,
+    Units,
+    Performance,
+}
+
+impl SettingsCategory {
+    pub const ALL: [SettingsCategory; 5] = [
+        SettingsCategory::General,
+        SettingsCategory::Display,
+        SettingsCategory::Accessibility,
+        SettingsCategory::Units,
+        SettingsCategory::Performance,
+    ];
+
+    /// Display name for this category
+    pub fn name(&self) -> &'static str {
+        match self {
+            SettingsCategory::General => "General",
+            SettingsCategory::Display => "Display",
+            SettingsCategory::Accessibility => "Accessibility",
+            SettingsCategory::Units => "Units",
+            SettingsCategory::Performance => "Performance",
+        }
+    }
+}
+
+/// How the log viewer arranges the two panes of a split view
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplitLayout {
+    /// Panes next to each other
+    #[default]
+    SideBySide,
+    /// One pane above the other
+    Stacked,
+}
+
 /// A selected point on a heatmap
 #[derive(Clone, Default)]
 pub struct SelectedHeatmapPoint {
@@ -166,6 +467,66 @@ pub struct SelectedHeatmapPoint {
     pub hits: u32,
 }
 
+/// Rendering mode for a scatter plot panel
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScatterViewMode {
+    /// 2D hit-count heatmap
+    #[default]
+    Heatmap,
+    /// 3D surface of average Z per X/Y cell
+    Surface,
+}
+
+/// Camera orientation for the 3D surface view (radians)
+#[derive(Clone, Copy)]
+pub struct SurfaceCamera {
+    /// Rotation around the vertical axis
+    pub yaw: f32,
+    /// Tilt towards the viewer
+    pub pitch: f32,
+}
+
+impl Default for SurfaceCamera {
+    fn default() -> Self {
+        Self {
+            yaw: -0.6,
+            pitch: 0.55,
+        }
+    }
+}
+
+/// Default number of cells per axis for scatter plot binning
+pub const DEFAULT_SCATTER_BINS: usize = 16;
+
+/// Cell binning for one scatter plot axis (surface view and cell export)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScatterAxisBinning {
+    /// Number of evenly spaced bins across the data range
+    pub bin_count: usize,
+    /// Explicit bin centers, e.g. an ECU table's breakpoints (empty = automatic)
+    pub breakpoints: Vec<f64>,
+    /// Breakpoints text being edited in the UI
+    pub breakpoints_text: String,
+}
+
+impl Default for ScatterAxisBinning {
+    fn default() -> Self {
+        Self {
+            bin_count: DEFAULT_SCATTER_BINS,
+            breakpoints: Vec::new(),
+            breakpoints_text: String::new(),
+        }
+    }
+}
+
+impl ScatterAxisBinning {
+    /// Bins for data spanning `min..=max`, using breakpoints when set
+    pub fn bins(&self, min: f64, max: f64) -> AxisBins {
+        AxisBins::from_breakpoints(&self.breakpoints)
+            .unwrap_or_else(|| AxisBins::uniform(min, max, self.bin_count))
+    }
+}
+
 /// Configuration for a single scatter plot panel
 #[derive(Clone, Default)]
 pub struct ScatterPlotConfig {
@@ -179,6 +540,28 @@ pub struct ScatterPlotConfig {
     pub z_channel: Option<usize>,
     /// Currently selected point (persisted on click)
     pub selected_point: Option<SelectedHeatmapPoint>,
+    /// Heatmap or 3D surface rendering
+    pub view_mode: ScatterViewMode,
+    /// Camera orientation for the 3D surface view
+    pub surface_camera: SurfaceCamera,
+    /// X axis cell binning
+    pub x_binning: ScatterAxisBinning,
+    /// Y axis cell binning
+    pub y_binning: ScatterAxisBinning,
+    /// Sample filters - only records matching every condition are plotted
+    pub filters: Vec<Condition>,
+}
+
+/// Portion of the log used by the scatter plots
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum ScatterTimeWindow {
+    /// Use every record in the log
+    #[default]
+    FullLog,
+    /// Follow the range visible in the Log Viewer chart
+    ChartView,
+    /// A fixed time range (seconds), e.g. a single pull
+    Range { start: f64, end: f64 },
 }
 
 /// State for the scatter plot view (dual plots)
@@ -188,15 +571,179 @@ pub struct ScatterPlotState {
     pub left: ScatterPlotConfig,
     /// Configuration for the right scatter plot
     pub right: ScatterPlotConfig,
+    /// Time window applied to both plots
+    pub time_window: ScatterTimeWindow,
+}
+
+/// State for the GPS track map view
+#[derive(Clone, Default)]
+pub struct TrackMapState {
+    /// Channel index for latitude (degrees)
+    pub lat_channel: Option<usize>,
+    /// Channel index for longitude (degrees)
+    pub lon_channel: Option<usize>,
+    /// Channel index used to color the path
+    pub color_channel: Option<usize>,
+    /// Whether GPS channel auto-detection has already run for this tab
+    pub auto_detected: bool,
+}
+
+/// State for the virtual dyno view
+#[derive(Clone, Default)]
+pub struct DynoState {
+    /// Channel index for vehicle speed
+    pub speed_channel: Option<usize>,
+    /// Channel index for engine RPM
+    pub rpm_channel: Option<usize>,
+    /// Start of the selected pull (seconds)
+    pub pull_start: Option<f64>,
+    /// End of the selected pull (seconds)
+    pub pull_end: Option<f64>,
+    /// Whether speed/RPM auto-detection has already run for this tab
+    pub auto_detected: bool,
+}
+
+/// Records matching the find value condition in one file
+#[derive(Clone)]
+pub struct FindMatches {
+    /// File the condition was evaluated against
+    pub file_index: usize,
+    /// Condition the matches were found for
+    pub condition: Condition,
+    /// Unit preferences the threshold was compared in
+    pub units: UnitPreferences,
+    /// Each run of consecutive matching records, or why the search failed
+    pub matches: Result<Vec<Violation>, String>,
+}
+
+/// Fuel trim summary of one file
+#[derive(Clone)]
+pub struct FuelTrimSummary {
+    /// File the summary was built from
+    pub file_index: usize,
+    /// Settings the summary was built with
+    pub config: FuelTrimConfig,
+    /// Unit preferences the load axis is in
+    pub units: UnitPreferences,
+    /// Names of the correction channels used, e.g. "STFT + LTFT"
+    pub source: String,
+    /// Binned correction, or why it couldn't be built
+    pub report: Result<TrimReport, String>,
+}
+
+/// EGT imbalances found in one file
+#[derive(Clone)]
+pub struct EgtSpreadScan {
+    /// File that was scanned
+    pub file_index: usize,
+    /// Thresholds the scan used
+    pub config: EgtSpreadConfig,
+    /// Unit preferences the threshold was compared in
+    pub units: UnitPreferences,
+    /// Per-probe EGT channels the spread is computed from
+    pub probes: Vec<usize>,
+    /// Periods of excessive spread, or why the file couldn't be scanned
+    pub periods: Result<Vec<Violation>, String>,
+}
+
+/// Idle analysis of one file
+#[derive(Clone)]
+pub struct IdleScan {
+    /// File that was analyzed
+    pub file_index: usize,
+    /// Thresholds the analysis used
+    pub config: IdleConfig,
+    /// Idle periods and stalls, or why the file couldn't be analyzed
+    pub report: Result<IdleReport, String>,
+}
+
+/// Candidate misfires found in one file
+#[derive(Clone)]
+pub struct MisfireScan {
+    /// File that was scanned
+    pub file_index: usize,
+    /// Thresholds the scan used
+    pub config: MisfireConfig,
+    /// Candidate misfires, or why the file couldn't be scanned
+    pub events: Result<Vec<MisfireEvent>, String>,
+}
+
+/// Drag runs found in one file
+#[derive(Clone)]
+pub struct DragScan {
+    /// File that was scanned
+    pub file_index: usize,
+    /// Name of the speed channel the runs were timed from
+    pub source: String,
+    /// Runs from a standstill, or why the file couldn't be scanned
+    pub runs: Result<Vec<DragRun>, String>,
+}
+
+/// Gear shifts found in one file
+#[derive(Clone)]
+pub struct ShiftScan {
+    /// File that was scanned
+    pub file_index: usize,
+    /// Thresholds the scan used
+    pub config: ShiftConfig,
+    /// Whether shifts were found from a gear channel rather than RPM alone
+    pub from_gear_channel: bool,
+    /// Upshifts, or why the file couldn't be scanned
+    pub shifts: Result<Vec<Shift>, String>,
+}
+
+/// State for the data table view
+#[derive(Clone, Default)]
+pub struct DataTableState {
+    /// Selected records as (anchor, end), in either order; shift-click
+    /// moves the end
+    pub selection: Option<(usize, usize)>,
+    /// Cursor record the table last scrolled to, so it only follows the
+    /// cursor when the cursor moves
+    pub followed_record: Option<usize>,
+}
+
+impl DataTableState {
+    /// Selected records, first to last
+    pub fn selected_range(&self) -> Option<std::ops::RangeInclusive<usize>> {
+        self.selection
+            .map(|(anchor, end)| anchor.min(end)..=anchor.max(end))
+    }
 }
 
 // ============================================================================
 // Tab Types
 // ============================================================================
 
+/// A WOT pull picked for the RPM-aligned comparison plot
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComparedPull {
+    /// Index of the file the pull was found in
+    pub file_index: usize,
+    /// Pull number within its file (1-based, as listed)
+    pub number: usize,
+    pub pull: Pull,
+}
+
+/// A user-created highlight over a time range on the chart
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    /// Start of the highlighted range (seconds)
+    pub start: f64,
+    /// End of the highlighted range (seconds)
+    pub end: f64,
+    /// Label shown in the legend and annotations list
+    pub label: String,
+    /// Fill color (drawn translucent)
+    pub color: [u8; 3],
+}
+
 /// A tab representing a single log file's view state
 #[derive(Clone)]
 pub struct Tab {
+    /// Identifier unique among all tabs, which keys per-tab UI state such as
+    /// the chart's zoom
+    pub id: u64,
     /// Index of the file this tab displays
     pub file_index: usize,
     /// Display name for the tab (usually filename)
@@ -217,6 +764,26 @@ pub struct Tab {
     pub scatter_plot_state: ScatterPlotState,
     /// Request to jump the view to a specific time (used for min/max jump buttons)
     pub jump_to_time: Option<f64>,
+    /// Request to show exactly this time range (start, end), e.g. a WOT pull
+    pub zoom_to_range: Option<(f64, f64)>,
+    /// Time range the chart showed when this tab was last drawn
+    pub view_range: Option<(f64, f64)>,
+    /// Channel plotted on the chart's X axis instead of time
+    pub x_axis_channel: Option<usize>,
+    /// Track map state for this tab
+    pub track_map_state: TrackMapState,
+    /// Virtual dyno state for this tab
+    pub dyno_state: DynoState,
+    /// Data table state for this tab
+    pub data_table_state: DataTableState,
+    /// User-created time range annotations
+    pub annotations: Vec<Annotation>,
+    /// Unit preferences for this tab only, overriding the global defaults
+    pub unit_overrides: Option<UnitPreferences>,
+    /// Loop start (A) for playback, in seconds
+    pub loop_start: Option<f64>,
+    /// Loop end (B) for playback, in seconds
+    pub loop_end: Option<f64>,
 }
 
 impl Tab {
@@ -228,6 +795,7 @@ impl Tab {
         scatter_plot_state.right.file_index = Some(file_index);
 
         Self {
+            id: Self::next_id(),
             file_index,
             name,
             selected_channels: Vec::new(),
@@ -238,6 +806,169 @@ impl Tab {
             time_range: None,
             scatter_plot_state,
             jump_to_time: None,
+            zoom_to_range: None,
+            view_range: None,
+            x_axis_channel: None,
+            track_map_state: TrackMapState::default(),
+            dyno_state: DynoState::default(),
+            data_table_state: DataTableState::default(),
+            annotations: Vec::new(),
+            unit_overrides: None,
+            loop_start: None,
+            loop_end: None,
+        }
+    }
+
+    /// Region playback repeats within, once both loop bounds are set
+    pub fn loop_region(&self) -> Option<(f64, f64)> {
+        let (a, b) = (self.loop_start?, self.loop_end?);
+        (a != b).then_some((a.min(b), a.max(b)))
+    }
+
+    /// A copy of this tab with its own identifier, to show the same file
+    /// side by side with independent state
+    pub fn duplicate(&self) -> Self {
+        Self {
+            id: Self::next_id(),
+            ..self.clone()
+        }
+    }
+
+    fn next_id() -> u64 {
+        static NEXT_TAB_ID: AtomicU64 = AtomicU64::new(0);
+        NEXT_TAB_ID.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+/// Tabs of a file being reloaded from disk, restored once the file has
+/// loaded again
+pub struct PendingReload {
+    /// Path of the file being reloaded
+    pub path: PathBuf,
+    /// The file's tabs before reloading: position in the tab bar, the tab,
+    /// and the name of the channel on its X axis
+    pub tabs: Vec<(usize, Tab, Option<String>)>,
+    /// Id of the active tab when the reload started
+    pub active_tab_id: Option<u64>,
+    /// The file's time offset, applied again once it has loaded
+    pub time_offset: f64,
+    /// The logging session the file showed
+    pub run: usize,
+}
+
+// ============================================================================
+// Persisted Settings
+// ============================================================================
+
+/// User settings saved between sessions via eframe storage
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PersistedSettings {
+    /// Threshold alarm rules
+    pub alarm_rules: Vec<AlarmRule>,
+    /// Virtual dyno vehicle parameters
+    pub dyno_config: DynoConfig,
+    /// WOT pull detection thresholds
+    pub pull_config: PullConfig,
+    /// Fuel trim summary threshold and cell sizes
+    pub fuel_trim_config: FuelTrimConfig,
+    /// Misfire detection thresholds
+    pub misfire_config: MisfireConfig,
+    /// EGT imbalance thresholds
+    pub egt_spread_config: EgtSpreadConfig,
+    /// Idle detection thresholds
+    pub idle_config: IdleConfig,
+    /// Shift detection thresholds
+    pub shift_config: ShiftConfig,
+    /// Whether detected shifts are marked on the chart
+    pub show_shift_markers: bool,
+    /// Analyses included in generated reports
+    pub report_config: ReportConfig,
+    /// Live data connection settings
+    pub live_config: LiveConfig,
+    /// Maximum number of channels selected per tab
+    pub max_channels: usize,
+    /// Custom field normalization mappings (lowercase source name -> display name)
+    pub custom_normalizations: HashMap<String, String>,
+    /// ECUMaster unit overrides (channel path -> unit)
+    pub unit_overrides: HashMap<String, String>,
+    /// Cache parsed text logs on disk
+    pub cache_parsed_logs: bool,
+    /// UI scale relative to the monitor's scale factor
+    pub ui_scale: f32,
+    /// Check for a new version on startup
+    pub auto_check_updates: bool,
+    /// Versions the user chose to skip; startup checks don't offer them
+    pub skipped_versions: Vec<String>,
+    /// Proxy URL for update checks and downloads (empty: from the environment)
+    pub update_proxy: String,
+    /// Whether prereleases are offered as updates
+    pub update_channel: UpdateChannel,
+    /// Seconds shown when a chart is first drawn
+    pub initial_view_seconds: f64,
+    /// Use the colorblind-friendly palette
+    pub color_blind_mode: bool,
+    /// Higher-contrast text and widget outlines
+    pub high_contrast: bool,
+    /// Multiplier applied to chart line widths
+    pub line_width_scale: f32,
+    /// Language of menus and dialogs
+    pub language: Language,
+    /// Show times as the time of day logs were recorded
+    pub wall_clock_time: bool,
+    /// Global display units
+    pub unit_preferences: UnitPreferences,
+    /// Color theme
+    pub theme: Theme,
+    /// Points drawn per channel across the chart's width
+    pub max_chart_points: usize,
+    /// Size above which MLG files are memory-mapped (MB)
+    pub mmap_threshold_mb: u64,
+    /// Whether logs recorded faster than `decimate_rate_hz` are decimated
+    /// when they are opened
+    pub decimate_on_import: bool,
+    /// Rate dense logs are decimated to (Hz)
+    pub decimate_rate_hz: f64,
+    /// Memory the chart's downsample caches may use (MB)
+    pub cache_budget_mb: u64,
+}
+
+impl Default for PersistedSettings {
+    fn default() -> Self {
+        Self {
+            alarm_rules: Vec::new(),
+            dyno_config: DynoConfig::default(),
+            pull_config: PullConfig::default(),
+            fuel_trim_config: FuelTrimConfig::default(),
+            misfire_config: MisfireConfig::default(),
+            egt_spread_config: EgtSpreadConfig::default(),
+            idle_config: IdleConfig::default(),
+            shift_config: ShiftConfig::default(),
+            show_shift_markers: false,
+            report_config: ReportConfig::default(),
+            live_config: LiveConfig::default(),
+            max_channels: DEFAULT_MAX_CHANNELS,
+            custom_normalizations: HashMap::new(),
+            unit_overrides: HashMap::new(),
+            cache_parsed_logs: true,
+            ui_scale: 1.0,
+            auto_check_updates: true,
+            skipped_versions: Vec::new(),
+            update_proxy: String::new(),
+            update_channel: UpdateChannel::default(),
+            initial_view_seconds: 60.0,
+            color_blind_mode: false,
+            high_contrast: false,
+            line_width_scale: 1.0,
+            language: Language::default(),
+            wall_clock_time: false,
+            unit_preferences: UnitPreferences::default(),
+            theme: Theme::default(),
+            max_chart_points: MAX_CHART_POINTS,
+            mmap_threshold_mb: DEFAULT_MMAP_THRESHOLD_MB,
+            decimate_on_import: false,
+            decimate_rate_hz: crate::decimate::DEFAULT_RATE_HZ,
+            cache_budget_mb: DEFAULT_CACHE_BUDGET_MB,
         }
     }
 }
@@ -0,0 +1,104 @@
+//! Stepped state bands for boolean and enum channels.
+//!
+//! Channels such as "launch active", "fan on" or gear only take a handful of
+//! whole-number values. Normalized analog lines make them hard to read, so the
+//! chart can draw them instead as bands below the analog lines, one colored
+//! run per state.
+
+/// Most distinct values a channel may take to be drawn as a state band
+pub const MAX_STATES: usize = 16;
+
+/// A span of time over which a channel holds one value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StateRun {
+    pub start: f64,
+    pub end: f64,
+    pub value: f64,
+}
+
+/// Whether a channel only takes a few whole-number values (ignoring missing
+/// samples), making it suitable for a state band
+pub fn is_discrete(data: &[f64]) -> bool {
+    let mut states: Vec<f64> = Vec::new();
+    for &value in data.iter().filter(|v| v.is_finite()) {
+        if value.fract() != 0.0 {
+            return false;
+        }
+        if !states.contains(&value) {
+            if states.len() == MAX_STATES {
+                return false;
+            }
+            states.push(value);
+        }
+    }
+    !states.is_empty()
+}
+
+/// Split `[time, value]` points into runs of equal value. Each run lasts until
+/// the next change, and the last one ends at the final point.
+pub fn state_runs(points: &[[f64; 2]]) -> Vec<StateRun> {
+    let mut runs: Vec<StateRun> = Vec::new();
+    for &[time, value] in points.iter().filter(|[_, v]| v.is_finite()) {
+        match runs.last_mut() {
+            Some(run) if run.value == value => run.end = time,
+            Some(run) => {
+                run.end = time;
+                runs.push(StateRun {
+                    start: time,
+                    end: time,
+                    value,
+                });
+            }
+            None => runs.push(StateRun {
+                start: time,
+                end: time,
+                value,
+            }),
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_discrete() {
+        assert!(is_discrete(&[0.0, 1.0, 1.0, 0.0]));
+        assert!(is_discrete(&[1.0, 2.0, f64::NAN, 6.0]));
+        assert!(!is_discrete(&[0.0, 0.5]));
+        assert!(!is_discrete(&[]));
+
+        let many: Vec<f64> = (0..=MAX_STATES).map(|v| v as f64).collect();
+        assert!(!is_discrete(&many));
+        assert!(is_discrete(&many[..MAX_STATES]));
+    }
+
+    #[test]
+    fn test_state_runs() {
+        let points = [[0.0, 0.0], [1.0, 0.0], [2.0, 1.0], [3.0, 1.0], [4.0, 0.0]];
+        let runs = state_runs(&points);
+        assert_eq!(
+            runs,
+            vec![
+                StateRun {
+                    start: 0.0,
+                    end: 2.0,
+                    value: 0.0
+                },
+                StateRun {
+                    start: 2.0,
+                    end: 4.0,
+                    value: 1.0
+                },
+                StateRun {
+                    start: 4.0,
+                    end: 4.0,
+                    value: 0.0
+                },
+            ]
+        );
+        assert!(state_runs(&[]).is_empty());
+    }
+}
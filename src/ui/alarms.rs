@@ -0,0 +1,429 @@
+//! Alarm rules window and evaluation helpers.
+//!
+//! Lets users build threshold rules (e.g. "Coolant Temp > 110 while RPM > 2000"),
+//! evaluates them against the active log, and lists the resulting violations.
+
+use eframe::egui;
+
+use crate::alarms::{AlarmRule, Comparison, Condition, RuleResult};
+use crate::app::UltraLogApp;
+use crate::i18n::Language;
+use crate::normalize::normalize_channel_name_with_custom;
+use crate::state::ActiveTool;
+
+/// Deferred action from the results table
+enum ResultAction {
+    JumpTo(f64),
+}
+
+impl UltraLogApp {
+    // ========================================================================
+    // Evaluation
+    // ========================================================================
+
+    /// Get alarm results for a file, evaluating the rules if not cached
+    pub fn get_alarm_results(&mut self, file_index: usize) -> Option<&Vec<RuleResult>> {
+        if file_index >= self.files.len() {
+            return None;
+        }
+
+        // Thresholds are in display units, so a unit change invalidates everything
+        if self.alarm_results_units != *self.active_unit_preferences() {
+            self.alarm_results.clear();
+            self.alarm_results_units = self.active_unit_preferences().clone();
+        }
+
+        if !self.alarm_results.contains_key(&file_index) {
+            let results = self.evaluate_alarm_rules(file_index);
+            self.alarm_results.insert(file_index, results);
+        }
+
+        self.alarm_results.get(&file_index)
+    }
+
+    /// Evaluate all enabled rules against a file
+    fn evaluate_alarm_rules(&self, file_index: usize) -> Vec<RuleResult> {
+        let times = self.files[file_index].log.get_times_as_f64();
+        let resolve = |name: &str| self.channel_display_values(file_index, name);
+
+        self.alarm_rules
+            .iter()
+            .enumerate()
+            .filter(|(_, rule)| rule.enabled)
+            .map(|(rule_index, rule)| RuleResult {
+                rule_index,
+                violations: rule.evaluate(times, resolve),
+            })
+            .collect()
+    }
+
+    /// Values of a channel found by name, converted to the display units
+    pub(crate) fn channel_display_values(&self, file_index: usize, name: &str) -> Option<Vec<f64>> {
+        let file = &self.files[file_index];
+        let channel_index = self.find_channel_by_name(file_index, name)?;
+        let source_unit = file.log.channels[channel_index].unit();
+        Some(
+            file.log
+                .get_channel_data(channel_index)
+                .iter()
+                .map(|&v| {
+                    self.active_unit_preferences()
+                        .convert_value(v, source_unit)
+                        .0
+                })
+                .collect(),
+        )
+    }
+
+    /// Channel names of the active file for condition pickers, sorted
+    pub(crate) fn condition_channel_names(&self) -> Vec<String> {
+        let active_file = self.active_tab.map(|idx| self.tabs[idx].file_index);
+        let mut channel_names: Vec<String> = active_file
+            .and_then(|idx| self.files.get(idx))
+            .map(|file| {
+                file.log
+                    .channels
+                    .iter()
+                    .map(|c| {
+                        if self.field_normalization {
+                            normalize_channel_name_with_custom(
+                                &c.name(),
+                                Some(&self.custom_normalizations),
+                            )
+                        } else {
+                            c.name()
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        channel_names.sort_by_key(|n| n.to_lowercase());
+        channel_names.dedup();
+        channel_names
+    }
+
+    /// Find a channel by original or normalized name (case-insensitive)
+    pub(crate) fn find_channel_by_name(&self, file_index: usize, name: &str) -> Option<usize> {
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        self.files[file_index]
+            .log
+            .channels
+            .iter()
+            .position(|channel| {
+                let original = channel.name();
+                original.eq_ignore_ascii_case(name)
+                    || normalize_channel_name_with_custom(
+                        &original,
+                        Some(&self.custom_normalizations),
+                    )
+                    .eq_ignore_ascii_case(name)
+            })
+    }
+
+    // ========================================================================
+    // Alarm Rules Window
+    // ========================================================================
+
+    /// Render the alarm rules editor and results window
+    pub fn render_alarms_window(&mut self, ctx: &egui::Context) {
+        if !self.show_alarms_window {
+            return;
+        }
+
+        // Channel names from the active file for the pickers
+        let active_file = self.active_tab.map(|idx| self.tabs[idx].file_index);
+        let channel_names = self.condition_channel_names();
+
+        // Evaluate before the rules are taken out for editing
+        let results = active_file.and_then(|idx| self.get_alarm_results(idx).cloned());
+
+        let mut rules = std::mem::take(&mut self.alarm_rules);
+        let original_rules = rules.clone();
+        let mut open = true;
+        let mut action: Option<ResultAction> = None;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Alarm Rules"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(600.0)
+            .default_height(500.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.heading(lang.tr("Rules"));
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.button(lang.tr("➕ Add Rule")).clicked() {
+                            rules.push(AlarmRule {
+                                name: format!("{} {}", lang.tr("Rule"), rules.len() + 1),
+                                ..Default::default()
+                            });
+                        }
+                    });
+                });
+                ui.label(
+                    egui::RichText::new(lang.tr(
+                        "Thresholds are in your selected display units. Channel names match \
+                         either the original or normalized name.",
+                    ))
+                    .color(egui::Color32::GRAY),
+                );
+                ui.add_space(4.0);
+
+                egui::ScrollArea::vertical()
+                    .id_salt("alarm_rules_scroll")
+                    .max_height(250.0)
+                    .show(ui, |ui| {
+                        let mut remove_rule: Option<usize> = None;
+
+                        for (rule_idx, rule) in rules.iter_mut().enumerate() {
+                            egui::Frame::NONE
+                                .fill(egui::Color32::from_rgb(50, 50, 50))
+                                .corner_radius(4)
+                                .inner_margin(egui::Margin::same(6))
+                                .show(ui, |ui| {
+                                    ui.horizontal(|ui| {
+                                        ui.checkbox(&mut rule.enabled, "");
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut rule.name)
+                                                .hint_text(lang.tr("Rule name"))
+                                                .desired_width(160.0),
+                                        );
+                                        ui.with_layout(
+                                            egui::Layout::right_to_left(egui::Align::Center),
+                                            |ui| {
+                                                if ui.small_button("🗑").clicked() {
+                                                    remove_rule = Some(rule_idx);
+                                                }
+                                            },
+                                        );
+                                    });
+
+                                    ui.horizontal(|ui| {
+                                        ui.label(lang.tr("When"));
+                                        Self::render_condition_editor(
+                                            ui,
+                                            &mut rule.condition,
+                                            &channel_names,
+                                            ("alarm_cond", rule_idx, 0),
+                                            lang,
+                                        );
+                                    });
+
+                                    let mut remove_while: Option<usize> = None;
+                                    for (cond_idx, cond) in
+                                        rule.while_conditions.iter_mut().enumerate()
+                                    {
+                                        ui.horizontal(|ui| {
+                                            ui.label(if cond_idx == 0 {
+                                                lang.tr("While")
+                                            } else {
+                                                lang.tr("And")
+                                            });
+                                            Self::render_condition_editor(
+                                                ui,
+                                                cond,
+                                                &channel_names,
+                                                ("alarm_cond", rule_idx, cond_idx + 1),
+                                                lang,
+                                            );
+                                            if ui.small_button("✖").clicked() {
+                                                remove_while = Some(cond_idx);
+                                            }
+                                        });
+                                    }
+                                    if let Some(idx) = remove_while {
+                                        rule.while_conditions.remove(idx);
+                                    }
+
+                                    if ui.small_button(lang.tr("+ While condition")).clicked() {
+                                        rule.while_conditions.push(Condition::default());
+                                    }
+                                });
+                            ui.add_space(4.0);
+                        }
+
+                        if let Some(idx) = remove_rule {
+                            rules.remove(idx);
+                        }
+
+                        if rules.is_empty() {
+                            ui.label(
+                                egui::RichText::new(lang.tr("No rules defined yet."))
+                                    .color(egui::Color32::GRAY),
+                            );
+                        }
+                    });
+
+                ui.add_space(8.0);
+                ui.separator();
+                ui.heading(lang.tr("Violations"));
+                ui.add_space(4.0);
+
+                let Some(results) = results.as_ref() else {
+                    ui.label(
+                        egui::RichText::new(lang.tr("Open a log file to evaluate rules."))
+                            .color(egui::Color32::GRAY),
+                    );
+                    return;
+                };
+
+                // Results are stale while rules are being edited this frame
+                if rules != original_rules {
+                    return;
+                }
+
+                Self::render_alarm_results(ui, &rules, results, &mut action, lang);
+            });
+
+        if rules != original_rules {
+            self.alarm_results.clear();
+        }
+        self.alarm_rules = rules;
+
+        if !open {
+            self.show_alarms_window = false;
+        }
+
+        if let Some(ResultAction::JumpTo(time)) = action {
+            self.active_tool = ActiveTool::LogViewer;
+            self.set_cursor_time(Some(time));
+            let record = self.find_record_at_time(time);
+            self.set_cursor_record(record);
+            self.set_jump_to_time(Some(time));
+        }
+    }
+
+    /// Render the editor row for a single condition
+    pub(crate) fn render_condition_editor(
+        ui: &mut egui::Ui,
+        condition: &mut Condition,
+        channel_names: &[String],
+        id: (&str, usize, usize),
+        lang: Language,
+    ) {
+        ui.add(
+            egui::TextEdit::singleline(&mut condition.channel)
+                .hint_text(lang.tr("Channel"))
+                .desired_width(150.0),
+        );
+
+        egui::ComboBox::from_id_salt(("channel", id))
+            .selected_text("")
+            .width(20.0)
+            .height(300.0)
+            .show_ui(ui, |ui| {
+                for name in channel_names {
+                    if ui
+                        .selectable_label(condition.channel == *name, name)
+                        .clicked()
+                    {
+                        condition.channel = name.clone();
+                    }
+                }
+            });
+
+        egui::ComboBox::from_id_salt(("comparison", id))
+            .selected_text(condition.comparison.symbol())
+            .width(40.0)
+            .show_ui(ui, |ui| {
+                for comparison in Comparison::ALL {
+                    ui.selectable_value(&mut condition.comparison, comparison, comparison.symbol());
+                }
+            });
+
+        ui.add(egui::DragValue::new(&mut condition.threshold).speed(0.1));
+    }
+
+    /// Render the violations table for the active file
+    fn render_alarm_results(
+        ui: &mut egui::Ui,
+        rules: &[AlarmRule],
+        results: &[RuleResult],
+        action: &mut Option<ResultAction>,
+        lang: Language,
+    ) {
+        // Errors (missing channels) first
+        for result in results {
+            if let Err(e) = &result.violations {
+                let name = &rules[result.rule_index].name;
+                ui.colored_label(
+                    egui::Color32::from_rgb(253, 193, 73),
+                    format!("⚠ {}: {}", name, e),
+                );
+            }
+        }
+
+        // Flatten violations into rows
+        let rows: Vec<(usize, &crate::alarms::Violation)> = results
+            .iter()
+            .filter_map(|r| r.violations.as_ref().ok().map(|v| (r.rule_index, v)))
+            .flat_map(|(rule_index, violations)| violations.iter().map(move |v| (rule_index, v)))
+            .collect();
+
+        if rows.is_empty() {
+            ui.label(
+                egui::RichText::new(lang.tr("No violations found.")).color(egui::Color32::GRAY),
+            );
+            return;
+        }
+
+        ui.label(format!("{} {}", rows.len(), lang.tr("violation(s)")));
+        ui.add_space(4.0);
+
+        // Header
+        ui.horizontal(|ui| {
+            ui.add_sized(
+                [160.0, 18.0],
+                egui::Label::new(egui::RichText::new(lang.tr("Rule")).strong()),
+            );
+            ui.add_sized(
+                [90.0, 18.0],
+                egui::Label::new(egui::RichText::new(lang.tr("Start")).strong()),
+            );
+            ui.add_sized(
+                [80.0, 18.0],
+                egui::Label::new(egui::RichText::new(lang.tr("Duration")).strong()),
+            );
+            ui.add_sized(
+                [80.0, 18.0],
+                egui::Label::new(egui::RichText::new(lang.tr("Peak")).strong()),
+            );
+        });
+        ui.separator();
+
+        let row_height = ui.spacing().interact_size.y;
+        egui::ScrollArea::vertical()
+            .id_salt("alarm_results_scroll")
+            .auto_shrink([false, true])
+            .show_rows(ui, row_height, rows.len(), |ui, range| {
+                for (rule_index, violation) in &rows[range] {
+                    ui.horizontal(|ui| {
+                        ui.add_sized(
+                            [160.0, row_height],
+                            egui::Label::new(&rules[*rule_index].name).truncate(),
+                        );
+                        ui.add_sized(
+                            [90.0, row_height],
+                            egui::Label::new(Self::format_time(violation.start_time)),
+                        );
+                        ui.add_sized(
+                            [80.0, row_height],
+                            egui::Label::new(format!("{:.2}s", violation.duration())),
+                        );
+                        ui.add_sized(
+                            [80.0, row_height],
+                            egui::Label::new(format!("{:.2}", violation.peak_value)),
+                        );
+                        if ui.small_button(lang.tr("Jump")).clicked() {
+                            *action = Some(ResultAction::JumpTo(violation.start_time));
+                        }
+                    });
+                }
+            });
+    }
+}
@@ -0,0 +1,169 @@
+//! Annotations window.
+//!
+//! Lists the active tab's time range annotations and lets users add, edit,
+//! remove, and jump to them. Annotations are drawn as shaded spans on the chart.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::state::{ActiveTool, Annotation, CHART_COLORS};
+
+/// Default span for annotations created at the cursor (seconds)
+const DEFAULT_ANNOTATION_SECONDS: f64 = 5.0;
+
+impl UltraLogApp {
+    /// Render the annotations list window
+    pub fn render_annotations_window(&mut self, ctx: &egui::Context) {
+        if !self.show_annotations_window {
+            return;
+        }
+
+        let mut open = true;
+        let mut jump_to: Option<f64> = None;
+
+        let cursor_time = self.get_cursor_time();
+        let time_range = self.get_time_range();
+        let view_range = self.chart_view_range;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Annotations"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(480.0)
+            .default_height(320.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                let Some(tab_idx) = self.active_tab else {
+                    ui.label(
+                        egui::RichText::new(lang.tr("Open a log file to add annotations."))
+                            .color(egui::Color32::GRAY),
+                    );
+                    return;
+                };
+
+                let annotations = &mut self.tabs[tab_idx].annotations;
+                let next_color = CHART_COLORS[annotations.len() % CHART_COLORS.len()];
+
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(
+                            cursor_time.is_some(),
+                            egui::Button::new(lang.tr("➕ At Cursor")),
+                        )
+                        .on_hover_text(lang.tr("Add an annotation starting at the cursor"))
+                        .clicked()
+                    {
+                        if let Some(start) = cursor_time {
+                            let end = time_range
+                                .map(|(_, max)| (start + DEFAULT_ANNOTATION_SECONDS).min(max))
+                                .unwrap_or(start + DEFAULT_ANNOTATION_SECONDS);
+                            annotations.push(Annotation {
+                                start,
+                                end,
+                                label: format!(
+                                    "{} {}",
+                                    lang.tr("Annotation"),
+                                    annotations.len() + 1
+                                ),
+                                color: next_color,
+                            });
+                        }
+                    }
+
+                    if ui
+                        .add_enabled(
+                            view_range.is_some(),
+                            egui::Button::new(lang.tr("➕ Visible Range")),
+                        )
+                        .on_hover_text(
+                            lang.tr("Add an annotation covering the visible chart range"),
+                        )
+                        .clicked()
+                    {
+                        if let Some((start, end)) = view_range {
+                            annotations.push(Annotation {
+                                start,
+                                end,
+                                label: format!(
+                                    "{} {}",
+                                    lang.tr("Annotation"),
+                                    annotations.len() + 1
+                                ),
+                                color: next_color,
+                            });
+                        }
+                    }
+                });
+
+                ui.add_space(4.0);
+                ui.separator();
+
+                if annotations.is_empty() {
+                    ui.label(
+                        egui::RichText::new(lang.tr("No annotations on this tab."))
+                            .color(egui::Color32::GRAY),
+                    );
+                    return;
+                }
+
+                let mut remove: Option<usize> = None;
+
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    egui::Grid::new("annotations_grid")
+                        .num_columns(6)
+                        .spacing([8.0, 6.0])
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label("");
+                            ui.strong(lang.tr("Label"));
+                            ui.strong(lang.tr("Start (s)"));
+                            ui.strong(lang.tr("End (s)"));
+                            ui.label("");
+                            ui.label("");
+                            ui.end_row();
+
+                            for (i, annotation) in annotations.iter_mut().enumerate() {
+                                ui.color_edit_button_srgb(&mut annotation.color);
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut annotation.label)
+                                        .desired_width(160.0),
+                                );
+                                ui.add(
+                                    egui::DragValue::new(&mut annotation.start)
+                                        .speed(0.1)
+                                        .max_decimals(3),
+                                );
+                                ui.add(
+                                    egui::DragValue::new(&mut annotation.end)
+                                        .speed(0.1)
+                                        .max_decimals(3),
+                                );
+                                if ui.small_button(lang.tr("Jump")).clicked() {
+                                    jump_to = Some(annotation.start.min(annotation.end));
+                                }
+                                if ui.small_button("🗑").clicked() {
+                                    remove = Some(i);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+
+                if let Some(i) = remove {
+                    annotations.remove(i);
+                }
+            });
+
+        if !open {
+            self.show_annotations_window = false;
+        }
+
+        if let Some(time) = jump_to {
+            self.active_tool = ActiveTool::LogViewer;
+            self.set_cursor_time(Some(time));
+            let record = self.find_record_at_time(time);
+            self.set_cursor_record(record);
+            self.set_jump_to_time(Some(time));
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Boost control window.
+//!
+//! Lists each WOT pull in the active log with how well the boost controller
+//! tracked its target: overshoot, settling time, and the error and
+//! wastegate duty once settled. Channels are found by their normalized
+//! names (Boost Target, MAP, Wastegate Duty, Gear), so rename a channel in
+//! the normalization editor if it isn't picked up.
+
+use std::ops::RangeInclusive;
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::boost::{self, BoostReport, SETTLED_BAND};
+use crate::i18n::Language;
+use crate::pulls::Pull;
+use crate::state::ActiveTool;
+
+/// One pull in the boost control table
+pub(crate) struct BoostRow {
+    pub(crate) pull: Pull,
+    /// `None` if the pull has no usable target and pressure values
+    pub(crate) report: Option<BoostReport>,
+}
+
+/// Boost control results for the active log
+pub(crate) struct BoostAnalysis {
+    pub(crate) rows: Vec<BoostRow>,
+    /// Display unit of the pressures
+    pub(crate) unit: String,
+}
+
+impl UltraLogApp {
+    /// Analyze boost control over every pull in a file
+    pub(crate) fn analyze_boost(&mut self, file_index: usize) -> Result<BoostAnalysis, String> {
+        let target = self
+            .find_channel_by_name(file_index, "Boost Target")
+            .ok_or("No boost target channel in this log")?;
+        let actual = self
+            .find_channel_by_name(file_index, "MAP")
+            .ok_or("No manifold pressure (MAP) channel in this log")?;
+        let duty = self.find_channel_by_name(file_index, "Wastegate Duty");
+        let gear = self.find_channel_by_name(file_index, "Gear");
+        let pulls = self.get_pulls(file_index)?.clone();
+
+        let log = &self.files[file_index].log;
+        let units = self.active_unit_preferences();
+        // Only each pull's records are converted, not the whole log
+        let converted = |channel: usize, range: RangeInclusive<usize>| -> Vec<f64> {
+            let unit = log.channels[channel].unit();
+            log.get_channel_data(channel)
+                .get(range)
+                .unwrap_or_default()
+                .iter()
+                .map(|&v| units.convert_value(v, unit).0)
+                .collect()
+        };
+        let raw = |channel: usize, range: RangeInclusive<usize>| -> Vec<f64> {
+            log.get_channel_data(channel)
+                .get(range)
+                .unwrap_or_default()
+                .to_vec()
+        };
+
+        let rows = pulls
+            .into_iter()
+            .map(|pull| {
+                let range = pull.start_record..=pull.end_record;
+                let times = log
+                    .get_times_as_f64()
+                    .get(range.clone())
+                    .unwrap_or_default();
+                let duty = duty.map(|channel| raw(channel, range.clone()));
+                let gear = gear.map(|channel| raw(channel, range.clone()));
+                let report = boost::analyze(
+                    times,
+                    &converted(target, range.clone()),
+                    &converted(actual, range),
+                    duty.as_deref(),
+                    gear.as_deref(),
+                );
+                BoostRow { pull, report }
+            })
+            .collect();
+
+        let unit = units
+            .convert_value(0.0, log.channels[actual].unit())
+            .1
+            .to_string();
+        Ok(BoostAnalysis { rows, unit })
+    }
+
+    /// Render the boost control diagnostics window
+    pub fn render_boost_window(&mut self, ctx: &egui::Context) {
+        if !self.show_boost_window {
+            return;
+        }
+
+        let active_file = self
+            .active_tab
+            .map(|idx| self.tabs[idx].file_index)
+            .filter(|&idx| idx < self.files.len());
+        let analysis = active_file.map(|file_index| self.analyze_boost(file_index));
+
+        let mut open = true;
+        let mut zoom: Option<usize> = None;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Boost Control"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(720.0)
+            .default_height(360.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} {:.0}% {}",
+                        lang.tr("Boost counts as settled once manifold pressure stays within"),
+                        SETTLED_BAND * 100.0,
+                        lang.tr(
+                            "of the target for the rest of the pull. Pulls use the WOT Pulls \
+                             window's thresholds."
+                        )
+                    ))
+                    .color(egui::Color32::GRAY),
+                );
+                ui.add_space(4.0);
+                ui.separator();
+
+                match &analysis {
+                    None => {
+                        ui.label(
+                            egui::RichText::new(
+                                lang.tr("Open a log file to analyze boost control."),
+                            )
+                            .color(egui::Color32::GRAY),
+                        );
+                    }
+                    Some(Err(e)) => {
+                        ui.colored_label(egui::Color32::from_rgb(253, 193, 73), format!("⚠ {}", e));
+                    }
+                    Some(Ok(analysis)) => Self::render_boost_rows(ui, analysis, &mut zoom, lang),
+                }
+            });
+
+        if !open {
+            self.show_boost_window = false;
+        }
+
+        if let (Some(i), Some(Ok(analysis))) = (zoom, analysis) {
+            let pull = &analysis.rows[i].pull;
+            self.active_tool = ActiveTool::LogViewer;
+            self.set_zoom_to_range(Some((pull.start_time, pull.end_time)));
+            self.set_cursor_time(Some(pull.start_time));
+            self.set_cursor_record(Some(pull.start_record));
+        }
+    }
+
+    /// Render the table of per-pull boost control results
+    fn render_boost_rows(
+        ui: &mut egui::Ui,
+        analysis: &BoostAnalysis,
+        zoom: &mut Option<usize>,
+        lang: Language,
+    ) {
+        if analysis.rows.is_empty() {
+            ui.label(egui::RichText::new(lang.tr("No pulls found.")).color(egui::Color32::GRAY));
+            return;
+        }
+
+        let pressure = |value: f64| format!("{:.1} {}", value, analysis.unit);
+        let dash = || "-".to_string();
+
+        egui::ScrollArea::vertical()
+            .id_salt("boost_scroll")
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                egui::Grid::new("boost_grid")
+                    .striped(true)
+                    .spacing([16.0, 6.0])
+                    .show(ui, |ui| {
+                        for header in [
+                            "#",
+                            lang.tr("Gear"),
+                            lang.tr("Start"),
+                            lang.tr("Peak Target"),
+                            lang.tr("Overshoot"),
+                            lang.tr("Settling"),
+                            lang.tr("Steady Error"),
+                            lang.tr("Steady Duty"),
+                        ] {
+                            ui.label(egui::RichText::new(header).strong());
+                        }
+                        ui.label("");
+                        ui.end_row();
+
+                        for (i, row) in analysis.rows.iter().enumerate() {
+                            let report = row.report.as_ref();
+                            ui.label(format!("{}", i + 1));
+                            ui.label(
+                                report
+                                    .and_then(|r| r.gear)
+                                    .map_or_else(dash, |g| g.to_string()),
+                            );
+                            ui.label(Self::format_time(row.pull.start_time));
+                            ui.label(report.map_or_else(dash, |r| pressure(r.peak_target)));
+                            ui.label(report.map_or_else(dash, |r| pressure(r.overshoot)));
+                            ui.label(report.map_or_else(dash, |r| {
+                                r.settling_time
+                                    .map_or(lang.tr("Never").to_string(), |t| format!("{:.2}s", t))
+                            }));
+                            ui.label(
+                                report
+                                    .and_then(|r| r.steady_error)
+                                    .map_or_else(dash, |e| format!("{:+.1} {}", e, analysis.unit)),
+                            );
+                            ui.label(
+                                report
+                                    .and_then(|r| r.steady_duty)
+                                    .map_or_else(dash, |d| format!("{:.1}%", d)),
+                            );
+                            if ui
+                                .small_button(lang.tr("Zoom"))
+                                .on_hover_text(lang.tr("Show this pull in the Log Viewer"))
+                                .clicked()
+                            {
+                                *zoom = Some(i);
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
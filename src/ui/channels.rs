@@ -3,8 +3,19 @@
 use eframe::egui;
 
 use crate::app::UltraLogApp;
-use crate::normalize::{normalize_channel_name_with_custom, sort_channels_by_priority};
-use crate::state::MAX_CHANNELS;
+use crate::fuzzy::channel_score;
+use crate::normalize::{
+    channel_category, normalize_channel_name_with_custom, sort_categories,
+    sort_channels_by_priority, with_original_name,
+};
+use crate::parsers::Channel;
+use crate::state::{ChannelStyle, LineStyle};
+use crate::state_bands::is_discrete;
+
+/// Units offered when correcting an ECUMaster channel's inferred unit
+const COMMON_UNITS: &[&str] = &[
+    "°C", "°F", "kPa", "bar", "PSI", "V", "A", "%", "λ", "AFR", "RPM", "ms", "°", "km/h", "Hz",
+];
 
 impl UltraLogApp {
     /// Render channel selection panel - fills available space
@@ -46,34 +57,99 @@ impl UltraLogApp {
 
             ui.add_space(5.0);
 
+            ui.checkbox(&mut self.hide_empty_channels, "Hide empty channels")
+                .on_hover_text("Hide channels that are zero or missing for the whole log");
+
+            // Flag channels without data so they can be left out of the list
+            let hidden: Vec<bool> = (0..channel_count)
+                .map(|idx| self.hide_empty_channels && self.is_channel_empty(file_index, idx))
+                .collect();
+            let hidden_count = hidden.iter().filter(|h| **h).count();
+
             // Channel count
-            ui.label(format!(
-                "Selected: {} / {} | Total: {}",
-                selected_count, MAX_CHANNELS, channel_count
-            ));
+            if hidden_count > 0 {
+                ui.label(format!(
+                    "Selected: {} / {} | Total: {} ({} empty hidden)",
+                    selected_count, self.max_channels, channel_count, hidden_count
+                ));
+            } else {
+                ui.label(format!(
+                    "Selected: {} / {} | Total: {}",
+                    selected_count, self.max_channels, channel_count
+                ));
+            }
 
             ui.separator();
 
             // Channel list - use all remaining vertical space
-            let search_lower = search_text.to_lowercase();
+            let searching = !search_text.trim().is_empty();
             let mut channel_to_add: Option<(usize, usize)> = None;
             let mut channel_to_remove: Option<usize> = None;
 
             // Sort channels: normalized fields first, then alphabetically
             // Collect channel names upfront to avoid borrow issues
             let file = &self.files[file_index];
-            let sorted_channels = sort_channels_by_priority(
+            let mut sorted_channels = sort_channels_by_priority(
                 file.log.channels.len(),
                 |idx| file.log.channels[idx].name(),
                 self.field_normalization,
                 Some(&self.custom_normalizations),
             );
+            sorted_channels.retain(|(channel_index, _, _)| !hidden[*channel_index]);
+            if self.field_normalization && self.show_original_names {
+                for (channel_index, display_name, _) in &mut sorted_channels {
+                    let original = file.log.channels[*channel_index].name();
+                    *display_name = with_original_name(display_name, &original);
+                }
+            }
 
             // Get original names for all channels (needed for search)
             let channel_names: Vec<String> = (0..file.log.channels.len())
                 .map(|idx| file.log.channels[idx].name())
                 .collect();
 
+            // While searching, show one list ranked by match quality (an
+            // unnamed group); otherwise group channels by category, keeping
+            // the priority order within each group
+            let mut groups: Vec<(String, Vec<(usize, String)>)> = Vec::new();
+            if searching {
+                // Match original and normalized names fuzzily, and units exactly
+                let mut matches: Vec<(i64, usize, String)> = sorted_channels
+                    .into_iter()
+                    .filter_map(|(channel_index, display_name, _is_normalized)| {
+                        let score = channel_score(
+                            &search_text,
+                            &[&channel_names[channel_index], &display_name],
+                            file.log.channels[channel_index].unit(),
+                        )?;
+                        Some((score, channel_index, display_name))
+                    })
+                    .collect();
+                matches.sort_by_key(|(score, _, _)| std::cmp::Reverse(*score));
+                groups.push((
+                    String::new(),
+                    matches
+                        .into_iter()
+                        .map(|(_, channel_index, display_name)| (channel_index, display_name))
+                        .collect(),
+                ));
+            } else {
+                for (channel_index, display_name, _is_normalized) in sorted_channels {
+                    let category = channel_category(
+                        file.log.channels[channel_index].category(),
+                        &channel_names[channel_index],
+                        Some(&self.custom_normalizations),
+                    );
+                    match groups.iter_mut().find(|(name, _)| *name == category) {
+                        Some((_, channels)) => channels.push((channel_index, display_name)),
+                        None => groups.push((category, vec![(channel_index, display_name)])),
+                    }
+                }
+                let mut order: Vec<String> = groups.iter().map(|(name, _)| name.clone()).collect();
+                sort_categories(&mut order);
+                groups.sort_by_key(|(name, _)| order.iter().position(|o| o == name));
+            }
+
             // Get selected channels for comparison
             let selected_channels = self.get_selected_channels().to_vec();
 
@@ -82,44 +158,78 @@ impl UltraLogApp {
                 .show(ui, |ui| {
                     ui.set_width(ui.available_width());
 
-                    for (channel_index, display_name, _is_normalized) in &sorted_channels {
-                        let original_name = &channel_names[*channel_index];
+                    let mut render_channel =
+                        |ui: &mut egui::Ui, channel_index: usize, display_name: &str| {
+                            // Check if already selected and get its index in selected_channels
+                            let selected_idx = selected_channels.iter().position(|c| {
+                                c.file_index == file_index && c.channel_index == channel_index
+                            });
+                            let is_selected = selected_idx.is_some();
 
-                        // Filter by search (search both original and normalized names)
-                        if !search_lower.is_empty()
-                            && !original_name.to_lowercase().contains(&search_lower)
-                            && !display_name.to_lowercase().contains(&search_lower)
-                        {
-                            continue;
-                        }
+                            // Build the label with checkmark prefix if selected
+                            let label_text = if is_selected {
+                                format!("[*] {}", display_name)
+                            } else {
+                                format!("[ ] {}", display_name)
+                            };
+
+                            let response = ui.selectable_label(is_selected, label_text);
+                            // Screen readers get the plain name and a selected state
+                            response.widget_info(|| {
+                                egui::WidgetInfo::selected(
+                                    egui::WidgetType::SelectableLabel,
+                                    true,
+                                    is_selected,
+                                    display_name,
+                                )
+                            });
 
-                        // Check if already selected and get its index in selected_channels
-                        let selected_idx = selected_channels.iter().position(|c| {
-                            c.file_index == file_index && c.channel_index == *channel_index
-                        });
-                        let is_selected = selected_idx.is_some();
-
-                        // Build the label with checkmark prefix if selected
-                        let label_text = if is_selected {
-                            format!("[*] {}", display_name)
-                        } else {
-                            format!("[ ] {}", display_name)
-                        };
+                            // Arrow keys move focus between channels
+                            if response.has_focus() {
+                                let direction = ui.input(|i| {
+                                    if i.key_pressed(egui::Key::ArrowDown) {
+                                        Some(egui::FocusDirection::Down)
+                                    } else if i.key_pressed(egui::Key::ArrowUp) {
+                                        Some(egui::FocusDirection::Up)
+                                    } else {
+                                        None
+                                    }
+                                });
+                                if let Some(direction) = direction {
+                                    ui.memory_mut(|m| m.move_focus(direction));
+                                }
+                            }
 
-                        let response = ui.selectable_label(is_selected, label_text);
+                            if response.clicked() {
+                                if let Some(idx) = selected_idx {
+                                    // Already selected - remove it
+                                    channel_to_remove = Some(idx);
+                                } else {
+                                    // Not selected - add it
+                                    channel_to_add = Some((file_index, channel_index));
+                                }
+                            }
+                            if response.hovered() {
+                                ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                            }
+                        };
 
-                        if response.clicked() {
-                            if let Some(idx) = selected_idx {
-                                // Already selected - remove it
-                                channel_to_remove = Some(idx);
-                            } else {
-                                // Not selected - add it
-                                channel_to_add = Some((file_index, *channel_index));
+                    for (category, channels) in &groups {
+                        if category.is_empty() {
+                            for (channel_index, display_name) in channels {
+                                render_channel(ui, *channel_index, display_name);
                             }
+                            continue;
                         }
-                        if response.hovered() {
-                            ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
-                        }
+
+                        egui::CollapsingHeader::new(format!("{} ({})", category, channels.len()))
+                            .id_salt(("channel_group", category))
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                for (channel_index, display_name) in channels {
+                                    render_channel(ui, *channel_index, display_name);
+                                }
+                            });
                     }
                 });
 
@@ -149,6 +259,8 @@ impl UltraLogApp {
         ui.separator();
 
         let use_normalization = self.field_normalization;
+        let show_original = self.show_original_names;
+        let card_fill = self.theme.card_fill();
 
         // Get selected channels from the active tab
         let selected_channels = self.get_selected_channels().to_vec();
@@ -156,7 +268,11 @@ impl UltraLogApp {
         // Pre-compute all display data to avoid borrow conflicts in closure
         struct ChannelCardData {
             color: egui::Color32,
+            rgb: [u8; 3],
+            style: ChannelStyle,
             display_name: String,
+            /// ECUMaster channel path and current unit, for the unit editor
+            unit_path: Option<(String, String)>,
             min_str: Option<String>,
             max_str: Option<String>,
             min_record: Option<usize>,
@@ -168,13 +284,21 @@ impl UltraLogApp {
         let mut channel_cards: Vec<ChannelCardData> = Vec::with_capacity(selected_channels.len());
 
         for selected in &selected_channels {
-            let color = self.get_channel_color(selected.color_index);
+            let color = self.selected_channel_color(selected);
             let color32 = egui::Color32::from_rgb(color[0], color[1], color[2]);
 
             // Get display name
             let channel_name = selected.channel.name();
             let display_name = if use_normalization {
-                normalize_channel_name_with_custom(&channel_name, Some(&self.custom_normalizations))
+                let normalized = normalize_channel_name_with_custom(
+                    &channel_name,
+                    Some(&self.custom_normalizations),
+                );
+                if show_original {
+                    with_original_name(&normalized, &channel_name)
+                } else {
+                    normalized
+                }
             } else {
                 channel_name
             };
@@ -201,20 +325,30 @@ impl UltraLogApp {
                             .map(|(i, v)| (i, *v))
                             .unwrap();
 
-                        let source_unit = selected.channel.unit();
-                        let (conv_min, display_unit) =
-                            self.unit_preferences.convert_value(min_val, source_unit);
-                        let (conv_max, _) =
-                            self.unit_preferences.convert_value(max_val, source_unit);
-                        let unit_str = if display_unit.is_empty() {
-                            String::new()
-                        } else {
-                            format!(" {}", display_unit)
+                        let (conv_min, min_unit) = self.convert_channel_value(
+                            selected.file_index,
+                            selected.channel_index,
+                            min_idx,
+                            min_val,
+                        );
+                        let (conv_max, max_unit) = self.convert_channel_value(
+                            selected.file_index,
+                            selected.channel_index,
+                            max_idx,
+                            max_val,
+                        );
+                        // Boost and vacuum use different units, so each side keeps its own
+                        let unit_suffix = |unit: &str| {
+                            if unit.is_empty() {
+                                String::new()
+                            } else {
+                                format!(" {}", unit)
+                            }
                         };
 
                         (
-                            Some(format!("{:.1}{}", conv_min, unit_str)),
-                            Some(format!("{:.1}{}", conv_max, unit_str)),
+                            Some(format!("{:.1}{}", conv_min, unit_suffix(&min_unit))),
+                            Some(format!("{:.1}{}", conv_max, unit_suffix(&max_unit))),
                             Some(min_idx),
                             Some(max_idx),
                             times.get(min_idx).copied(),
@@ -229,7 +363,13 @@ impl UltraLogApp {
 
             channel_cards.push(ChannelCardData {
                 color: color32,
+                rgb: color,
+                style: selected.style,
                 display_name,
+                unit_path: match &selected.channel {
+                    Channel::EcuMaster(e) => Some((e.path.clone(), e.unit.clone())),
+                    _ => None,
+                },
                 min_str,
                 max_str,
                 min_record,
@@ -240,99 +380,191 @@ impl UltraLogApp {
         }
 
         let mut channel_to_remove: Option<usize> = None;
+        let mut channel_to_move: Option<(usize, usize)> = None; // (from, to)
+        let mut style_change: Option<(usize, ChannelStyle)> = None;
+        let mut unit_change: Option<(String, Option<String>)> = None; // (path, unit)
         let mut jump_to: Option<(usize, f64)> = None; // (record, time)
 
-        egui::ScrollArea::horizontal().show(ui, |ui| {
-            ui.horizontal(|ui| {
-                for (i, card) in channel_cards.iter().enumerate() {
-                    egui::Frame::NONE
-                        .fill(egui::Color32::from_rgb(40, 40, 40))
-                        .stroke(egui::Stroke::new(2.0, card.color))
-                        .corner_radius(5)
-                        .inner_margin(10.0)
-                        .show(ui, |ui| {
-                            ui.vertical(|ui| {
-                                ui.horizontal(|ui| {
-                                    ui.label(
-                                        egui::RichText::new(&card.display_name)
-                                            .strong()
-                                            .color(card.color),
-                                    );
-                                    let close_btn = ui.small_button("x");
-                                    if close_btn.clicked() {
-                                        channel_to_remove = Some(i);
-                                    }
-                                    if close_btn.hovered() {
-                                        ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
-                                    }
-                                });
-
-                                // Show min with jump button
-                                if let Some(min_str) = &card.min_str {
-                                    ui.horizontal(|ui| {
-                                        ui.label(
-                                            egui::RichText::new("Min:")
-                                                .color(egui::Color32::GRAY)
-                                                .small(),
-                                        );
-                                        ui.label(
-                                            egui::RichText::new(min_str)
-                                                .color(egui::Color32::LIGHT_GRAY),
-                                        );
-                                        if let (Some(record), Some(time)) =
-                                            (card.min_record, card.min_time)
-                                        {
-                                            let btn = ui
-                                                .small_button("⏵")
-                                                .on_hover_text("Jump to minimum");
-                                            if btn.clicked() {
-                                                jump_to = Some((record, time));
-                                            }
-                                            if btn.hovered() {
-                                                ui.ctx().set_cursor_icon(
-                                                    egui::CursorIcon::PointingHand,
-                                                );
+        // Cards wrap onto more rows as channels are added; past two rows they
+        // scroll so the chart keeps most of the window
+        egui::ScrollArea::vertical()
+            .max_height(190.0)
+            .show(ui, |ui| {
+                ui.horizontal_wrapped(|ui| {
+                    for (i, card) in channel_cards.iter().enumerate() {
+                        // Cards are dragged by their frame; buttons still click
+                        let card_response = ui
+                            .dnd_drag_source(egui::Id::new(("channel_card", i)), i, |ui| {
+                                egui::Frame::NONE
+                                    .fill(card_fill)
+                                    .stroke(egui::Stroke::new(2.0, card.color))
+                                    .corner_radius(5)
+                                    .inner_margin(10.0)
+                                    .show(ui, |ui| {
+                                        ui.vertical(|ui| {
+                                            ui.horizontal(|ui| {
+                                                let name_response = ui
+                                                    .add(
+                                                        egui::Label::new(
+                                                            egui::RichText::new(&card.display_name)
+                                                                .strong()
+                                                                .color(card.color),
+                                                        )
+                                                        .sense(egui::Sense::click()),
+                                                    )
+                                                    .on_hover_text(
+                                                        "Right-click for color and line style",
+                                                    );
+                                                name_response.context_menu(|ui| {
+                                                    // Only scanned while the menu is open
+                                                    let selected = &selected_channels[i];
+                                                    let discrete = self
+                                                        .files
+                                                        .get(selected.file_index)
+                                                        .is_some_and(|f| {
+                                                            is_discrete(f.log.get_channel_data(
+                                                                selected.channel_index,
+                                                            ))
+                                                        });
+                                                    if let Some(style) = Self::channel_style_menu(
+                                                        ui, card.rgb, card.style, discrete,
+                                                    ) {
+                                                        style_change = Some((i, style));
+                                                    }
+                                                    if let Some((path, unit)) = &card.unit_path {
+                                                        ui.separator();
+                                                        let overridden =
+                                                            self.unit_overrides.contains_key(path);
+                                                        if let Some(change) =
+                                                            Self::unit_override_menu(
+                                                                ui, path, unit, overridden,
+                                                            )
+                                                        {
+                                                            unit_change =
+                                                                Some((path.clone(), change));
+                                                        }
+                                                    }
+                                                });
+                                                let close_btn = ui.small_button("x");
+                                                close_btn.widget_info(|| {
+                                                    egui::WidgetInfo::labeled(
+                                                        egui::WidgetType::Button,
+                                                        true,
+                                                        format!("Remove {}", card.display_name),
+                                                    )
+                                                });
+                                                if close_btn.clicked() {
+                                                    channel_to_remove = Some(i);
+                                                }
+                                                if close_btn.hovered() {
+                                                    ui.ctx().set_cursor_icon(
+                                                        egui::CursorIcon::PointingHand,
+                                                    );
+                                                }
+                                            });
+
+                                            // Show min with jump button
+                                            if let Some(min_str) = &card.min_str {
+                                                ui.horizontal(|ui| {
+                                                    ui.label(
+                                                        egui::RichText::new("Min:")
+                                                            .color(egui::Color32::GRAY)
+                                                            .small(),
+                                                    );
+                                                    ui.label(
+                                                        egui::RichText::new(min_str)
+                                                            .color(egui::Color32::LIGHT_GRAY),
+                                                    );
+                                                    if let (Some(record), Some(time)) =
+                                                        (card.min_record, card.min_time)
+                                                    {
+                                                        let btn = ui
+                                                            .small_button("⏵")
+                                                            .on_hover_text("Jump to minimum");
+                                                        btn.widget_info(|| {
+                                                            egui::WidgetInfo::labeled(
+                                                                egui::WidgetType::Button,
+                                                                true,
+                                                                "Jump to minimum",
+                                                            )
+                                                        });
+                                                        if btn.clicked() {
+                                                            jump_to = Some((record, time));
+                                                        }
+                                                        if btn.hovered() {
+                                                            ui.ctx().set_cursor_icon(
+                                                                egui::CursorIcon::PointingHand,
+                                                            );
+                                                        }
+                                                    }
+                                                });
                                             }
-                                        }
-                                    });
-                                }
 
-                                // Show max with jump button
-                                if let Some(max_str) = &card.max_str {
-                                    ui.horizontal(|ui| {
-                                        ui.label(
-                                            egui::RichText::new("Max:")
-                                                .color(egui::Color32::GRAY)
-                                                .small(),
-                                        );
-                                        ui.label(
-                                            egui::RichText::new(max_str)
-                                                .color(egui::Color32::LIGHT_GRAY),
-                                        );
-                                        if let (Some(record), Some(time)) =
-                                            (card.max_record, card.max_time)
-                                        {
-                                            let btn = ui
-                                                .small_button("⏵")
-                                                .on_hover_text("Jump to maximum");
-                                            if btn.clicked() {
-                                                jump_to = Some((record, time));
-                                            }
-                                            if btn.hovered() {
-                                                ui.ctx().set_cursor_icon(
-                                                    egui::CursorIcon::PointingHand,
-                                                );
+                                            // Show max with jump button
+                                            if let Some(max_str) = &card.max_str {
+                                                ui.horizontal(|ui| {
+                                                    ui.label(
+                                                        egui::RichText::new("Max:")
+                                                            .color(egui::Color32::GRAY)
+                                                            .small(),
+                                                    );
+                                                    ui.label(
+                                                        egui::RichText::new(max_str)
+                                                            .color(egui::Color32::LIGHT_GRAY),
+                                                    );
+                                                    if let (Some(record), Some(time)) =
+                                                        (card.max_record, card.max_time)
+                                                    {
+                                                        let btn = ui
+                                                            .small_button("⏵")
+                                                            .on_hover_text("Jump to maximum");
+                                                        btn.widget_info(|| {
+                                                            egui::WidgetInfo::labeled(
+                                                                egui::WidgetType::Button,
+                                                                true,
+                                                                "Jump to maximum",
+                                                            )
+                                                        });
+                                                        if btn.clicked() {
+                                                            jump_to = Some((record, time));
+                                                        }
+                                                        if btn.hovered() {
+                                                            ui.ctx().set_cursor_icon(
+                                                                egui::CursorIcon::PointingHand,
+                                                            );
+                                                        }
+                                                    }
+                                                });
                                             }
-                                        }
+                                        });
                                     });
-                                }
-                            });
-                        });
+                            })
+                            .response;
+
+                        // Dropping another card here moves it to this position
+                        if let Some(dragged) = card_response.dnd_hover_payload::<usize>() {
+                            if *dragged != i {
+                                let rect = card_response.rect;
+                                let x = if *dragged < i {
+                                    rect.right()
+                                } else {
+                                    rect.left()
+                                };
+                                ui.painter().vline(
+                                    x,
+                                    rect.y_range(),
+                                    egui::Stroke::new(2.0, egui::Color32::WHITE),
+                                );
+                            }
+                        }
+                        if let Some(dragged) = card_response.dnd_release_payload::<usize>() {
+                            channel_to_move = Some((*dragged, i));
+                        }
 
-                    ui.add_space(5.0);
-                }
+                        ui.add_space(5.0);
+                    }
+                });
             });
-        });
 
         // Handle jump to min/max
         if let Some((record, time)) = jump_to {
@@ -349,6 +581,18 @@ impl UltraLogApp {
             self.remove_channel(index);
         }
 
+        if let Some((from, to)) = channel_to_move {
+            self.move_channel(from, to);
+        }
+
+        if let Some((index, style)) = style_change {
+            self.set_channel_style(index, style);
+        }
+
+        if let Some((path, unit)) = unit_change {
+            self.set_unit_override(&path, unit);
+        }
+
         if selected_channels.is_empty() {
             ui.label(
                 egui::RichText::new("Click channels to add them to the chart")
@@ -357,4 +601,98 @@ impl UltraLogApp {
             );
         }
     }
+
+    /// Color and line style editor shown when right-clicking a channel card.
+    /// Boolean/enum (`discrete`) channels can also be drawn as state bands.
+    /// Returns the new style if the user changed it.
+    fn channel_style_menu(
+        ui: &mut egui::Ui,
+        current_color: [u8; 3],
+        current: ChannelStyle,
+        discrete: bool,
+    ) -> Option<ChannelStyle> {
+        let mut style = current;
+
+        ui.horizontal(|ui| {
+            ui.label("Color:");
+            let mut color = current_color;
+            if egui::color_picker::color_edit_button_srgb(ui, &mut color).changed() {
+                style.color = Some(color);
+            }
+            if style.color.is_some() && ui.small_button("Reset").clicked() {
+                style.color = None;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Width:");
+            ui.add(egui::Slider::new(&mut style.line_width, 0.5..=5.0).step_by(0.5));
+        });
+
+        ui.horizontal(|ui| {
+            for line_style in [LineStyle::Solid, LineStyle::Dashed, LineStyle::Dotted] {
+                ui.radio_value(&mut style.line_style, line_style, line_style.name());
+            }
+        });
+
+        if discrete || style.state_band {
+            ui.checkbox(&mut style.state_band, "Show as state band")
+                .on_hover_text(
+                    "Draw on/off and gear-like channels as colored bands below the chart",
+                );
+        }
+
+        (style != current).then_some(style)
+    }
+
+    /// Unit editor for ECUMaster channels, whose units are guessed from their
+    /// names. Returns `Some(None)` to restore the guessed unit.
+    fn unit_override_menu(
+        ui: &mut egui::Ui,
+        path: &str,
+        current: &str,
+        overridden: bool,
+    ) -> Option<Option<String>> {
+        let mut change = None;
+
+        ui.menu_button(
+            format!(
+                "Unit: {}",
+                if current.is_empty() { "none" } else { current }
+            ),
+            |ui| {
+                for unit in COMMON_UNITS {
+                    if ui.selectable_label(*unit == current, *unit).clicked() {
+                        change = Some(Some(unit.to_string()));
+                    }
+                }
+
+                ui.separator();
+                // Keep the typed unit across frames while the menu is open
+                let id = egui::Id::new(("unit_override", path));
+                let mut custom: String = ui.data_mut(|d| d.get_temp(id).unwrap_or_default());
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut custom)
+                            .hint_text("Other unit")
+                            .desired_width(80.0),
+                    );
+                    if ui
+                        .add_enabled(!custom.trim().is_empty(), egui::Button::new("Set"))
+                        .clicked()
+                    {
+                        change = Some(Some(custom.trim().to_string()));
+                        custom.clear();
+                    }
+                });
+                ui.data_mut(|d| d.insert_temp(id, custom));
+
+                if overridden && ui.button("Use detected unit").clicked() {
+                    change = Some(None);
+                }
+            },
+        );
+
+        change
+    }
 }
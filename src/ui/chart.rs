@@ -1,11 +1,20 @@
 //! Chart rendering and data processing utilities.
 
 use eframe::egui;
-use egui_plot::{Line, Plot, PlotBounds, PlotPoints, VLine};
+use egui_plot::{
+    Line, Plot, PlotBounds, PlotMemory, PlotPoint, PlotPoints, Points, Polygon, Text, VLine,
+};
 
 use crate::app::UltraLogApp;
-use crate::normalize::normalize_channel_name_with_custom;
-use crate::state::{CacheKey, CHART_COLORS, COLORBLIND_COLORS, MAX_CHART_POINTS};
+use crate::downsample::{DomainDownsample, DownsamplePyramid, ViewDownsample};
+use crate::normalize::{normalize_channel_name_with_custom, with_original_name};
+use crate::state::{CacheKey, LineStyle, COMPACT_CHANNEL_COUNT};
+use crate::state_bands::state_runs;
+
+/// Height of one state band, in the chart's normalized Y units
+const BAND_HEIGHT: f64 = 0.08;
+/// Space between state bands and above the first one
+const BAND_GAP: f64 = 0.02;
 
 impl UltraLogApp {
     /// Render the main chart with cached downsampled data
@@ -24,9 +33,30 @@ impl UltraLogApp {
             return;
         }
 
-        // Pre-compute and cache downsampled + normalized data for all selected channels
+        self.render_x_axis_picker(ui);
+
+        // Channel on the X axis with its value range, or `None` for time
+        let domain: Option<(usize, usize, (f64, f64))> =
+            self.get_x_axis_channel()
+                .and_then(|(file_index, channel_index)| {
+                    let range = self.get_channel_min_max(file_index, channel_index)?;
+                    Some((file_index, channel_index, range))
+                });
+
+        let x_axis_label = domain.map_or(String::new(), |(file_index, channel_index, _)| {
+            let name = self.files[file_index].log.channels[channel_index].name();
+            if self.field_normalization {
+                normalize_channel_name_with_custom(&name, Some(&self.custom_normalizations))
+            } else {
+                name
+            }
+        });
+
+        // Pre-compute and cache the downsample pyramid and value range for all selected channels
+        let mut value_ranges: Vec<Option<(f64, f64)>> = Vec::with_capacity(selected_channels.len());
         for selected in &selected_channels {
             if selected.file_index >= self.files.len() {
+                value_ranges.push(None);
                 continue;
             }
 
@@ -35,29 +65,38 @@ impl UltraLogApp {
                 channel_index: selected.channel_index,
             };
 
+            self.touch_chart_cache(&cache_key);
             if !self.downsample_cache.contains_key(&cache_key) {
                 let file = &self.files[selected.file_index];
                 let times = file.log.get_times_as_f64();
                 let data = file.log.get_channel_data(selected.channel_index);
 
                 if times.len() == data.len() && !times.is_empty() {
-                    let downsampled = Self::downsample_lttb(&times, &data, MAX_CHART_POINTS);
-                    // Normalize Y values to 0-1 range so all channels overlay
-                    let normalized = Self::normalize_points(&downsampled);
-                    self.downsample_cache.insert(cache_key, normalized);
+                    let pyramid = DownsamplePyramid::build(times, data, self.max_chart_points);
+                    self.downsample_cache.insert(cache_key, pyramid);
                 }
             }
+
+            value_ranges
+                .push(self.get_channel_min_max(selected.file_index, selected.channel_index));
         }
 
         // Pre-compute legend names with current values at cursor position
         let use_normalization = self.field_normalization;
+        let show_original = self.show_original_names;
         let custom_mappings = &self.custom_normalizations;
         let legend_names: Vec<String> = selected_channels
             .iter()
             .map(|selected| {
                 let original_name = selected.channel.name();
                 let base_name = if use_normalization {
-                    normalize_channel_name_with_custom(&original_name, Some(custom_mappings))
+                    let normalized =
+                        normalize_channel_name_with_custom(&original_name, Some(custom_mappings));
+                    if show_original {
+                        with_original_name(&normalized, &original_name)
+                    } else {
+                        normalized
+                    }
                 } else {
                     original_name
                 };
@@ -67,9 +106,12 @@ impl UltraLogApp {
                         selected.channel_index,
                         record,
                     ) {
-                        let source_unit = selected.channel.unit();
-                        let (converted_value, display_unit) =
-                            self.unit_preferences.convert_value(value, source_unit);
+                        let (converted_value, display_unit) = self.convert_channel_value(
+                            selected.file_index,
+                            selected.channel_index,
+                            record,
+                            value,
+                        );
                         if display_unit.is_empty() {
                             format!("{}: {:.2}", base_name, converted_value)
                         } else {
@@ -84,31 +126,104 @@ impl UltraLogApp {
             })
             .collect();
 
+        // Collect alarm violation regions for the active tab's file (time spans,
+        // so not shown against another channel)
+        let (alarm_regions, annotations, shift_markers) = if domain.is_some() {
+            (Vec::new(), Vec::new(), Vec::new())
+        } else {
+            (
+                self.collect_alarm_regions(),
+                self.active_tab
+                    .map(|idx| self.tabs[idx].annotations.clone())
+                    .unwrap_or_default(),
+                self.collect_shift_markers(),
+            )
+        };
+
         // Prepare data for the plot closure (can't borrow self mutably inside)
         let cache = &self.downsample_cache;
+        let view_cache = &self.view_downsample_cache;
+        let mut new_views: Vec<(CacheKey, ViewDownsample)> = Vec::new();
+        let domain_cache = &self.domain_view_cache;
+        let mut new_domain_views: Vec<(CacheKey, DomainDownsample)> = Vec::new();
         let files = &self.files;
         // selected_channels already defined at top of function from get_selected_channels()
-        let cursor_time = self.get_cursor_time();
-        let cursor_tracking = self.cursor_tracking;
+        // Against a channel, the cursor sits at that channel's value at the cursor record
+        let cursor_time = match domain {
+            Some((file_index, channel_index, _)) => self
+                .get_cursor_record()
+                .and_then(|record| self.get_value_at_record(file_index, channel_index, record)),
+            None => self.get_cursor_time(),
+        };
+        let cursor_tracking = self.cursor_tracking && domain.is_none();
         let view_window = self.view_window_seconds;
         let time_range = self.get_time_range();
-        let color_blind_mode = self.color_blind_mode;
+        let line_colors: Vec<[u8; 3]> = selected_channels
+            .iter()
+            .map(|selected| self.selected_channel_color(selected))
+            .collect();
         let chart_interacted = self.get_chart_interacted();
         let initial_view_seconds = self.initial_view_seconds;
+        let max_chart_points = self.max_chart_points;
+        let line_width_scale = self.line_width_scale;
         let jump_to_time = self.get_jump_to_time();
+        let zoom_to_range = self.get_zoom_to_range();
 
         // Fixed Y bounds for normalized data (0-1 with small padding)
         const Y_MIN: f64 = -0.05;
         const Y_MAX: f64 = 1.05;
 
+        // State bands stack below the analog lines, extending the Y range down
+        let band_count = selected_channels
+            .iter()
+            .filter(|s| s.style.state_band && domain.is_none())
+            .count();
+        let y_min = Y_MIN - band_count as f64 * (BAND_HEIGHT + BAND_GAP);
+
+        // Shrink the legend text when many channels would crowd the chart
+        let legend_text = if selected_channels.len() > COMPACT_CHANNEL_COUNT {
+            egui::TextStyle::Small
+        } else {
+            egui::TextStyle::Body
+        };
+
+        // Each tab has its own plot, so tabs over the same file zoom
+        // independently. A tab's first frame (e.g. a duplicated tab) starts
+        // from the range it was last shown with.
+        let plot_id = egui::Id::new(("log_chart", self.active_tab.map(|idx| self.tabs[idx].id)));
+        let restore_view = self
+            .active_tab
+            .and_then(|idx| self.tabs[idx].view_range)
+            .filter(|_| domain.is_none() && PlotMemory::load(ui.ctx(), plot_id).is_none());
+
         // Build the plot - X-axis zoom only, Y fixed
         let plot = Plot::new("log_chart")
-            .legend(egui_plot::Legend::default())
+            .id(plot_id)
+            // Keep the legend in the order of the channel cards
+            .legend(
+                egui_plot::Legend::default()
+                    .text_style(legend_text)
+                    .follow_insertion_order(true),
+            )
+            .x_axis_label(x_axis_label)
             .y_axis_label("") // Hide Y axis label since values are normalized
             .show_axes([true, false]) // Show X axis (time), hide Y axis (normalized 0-1)
             .allow_zoom([true, false]) // Only allow X-axis zoom
             .allow_drag([!cursor_tracking, false]) // Only allow X-axis drag, never Y
             .allow_scroll([!cursor_tracking, false]); // Only allow X-axis scroll, never Y
+                                                      // Against time, optionally label the axis with the time of day
+        let plot = match self.wall_clock_at(0.0).filter(|_| domain.is_none()) {
+            Some(clock_at_zero) => plot.x_axis_formatter(move |mark, _| {
+                let clock = Self::format_clock_time(clock_at_zero + mark.value);
+                // Whole seconds once the grid is that coarse
+                if mark.step_size >= 1.0 {
+                    clock[..8].to_string()
+                } else {
+                    clock
+                }
+            }),
+            None => plot,
+        };
 
         let response = plot.show(ui, |plot_ui| {
             // Get current bounds
@@ -116,8 +231,29 @@ impl UltraLogApp {
             let mut x_min = current_bounds.min()[0];
             let mut x_max = current_bounds.max()[0];
 
-            // Handle jump-to-time request (from min/max jump buttons)
-            if let (Some(jump_time), Some((min_t, max_t))) = (jump_to_time, time_range) {
+            if let Some((_, _, (min_x, max_x))) = domain {
+                // Against a channel, keep the view within that channel's range
+                let current_width = x_max - x_min;
+                if current_width <= 0.0 || current_width > max_x - min_x {
+                    x_min = min_x;
+                    x_max = max_x;
+                } else if x_min < min_x {
+                    x_min = min_x;
+                    x_max = min_x + current_width;
+                } else if x_max > max_x {
+                    x_max = max_x;
+                    x_min = max_x - current_width;
+                }
+            } else if let Some((start, end)) = restore_view {
+                x_min = start;
+                x_max = end;
+            } else if let (Some((start, end)), Some((min_t, max_t))) = (zoom_to_range, time_range) {
+                // Handle zoom-to-range request (e.g. a WOT pull), with a little context each side
+                let margin = (end - start) * 0.05;
+                x_min = (start - margin).max(min_t);
+                x_max = (end + margin).min(max_t);
+            } else if let (Some(jump_time), Some((min_t, max_t))) = (jump_to_time, time_range) {
+                // Handle jump-to-time request (from min/max jump buttons)
                 // Center the view on the jump target time
                 let current_width = (x_max - x_min).max(view_window);
                 let half_width = current_width / 2.0;
@@ -169,10 +305,68 @@ impl UltraLogApp {
             }
 
             // Always enforce bounds: X clamped to data, Y fixed to normalized range
-            let new_bounds = PlotBounds::from_min_max([x_min, Y_MIN], [x_max, Y_MAX]);
+            let new_bounds = PlotBounds::from_min_max([x_min, y_min], [x_max, Y_MAX]);
             plot_ui.set_plot_bounds(new_bounds);
 
+            // Shade alarm violations that intersect the visible range
+            for (name, start, end) in &alarm_regions {
+                if *end < x_min || *start > x_max {
+                    continue;
+                }
+                let region = vec![
+                    [*start, y_min],
+                    [*end, y_min],
+                    [*end, Y_MAX],
+                    [*start, Y_MAX],
+                ];
+                plot_ui.polygon(
+                    Polygon::new(name.clone(), PlotPoints::from(region))
+                        .fill_color(egui::Color32::from_rgba_unmultiplied(191, 78, 48, 40))
+                        .stroke(egui::Stroke::NONE),
+                );
+            }
+
+            // Draw user annotations as translucent spans
+            for annotation in &annotations {
+                let (start, end) = (
+                    annotation.start.min(annotation.end),
+                    annotation.start.max(annotation.end),
+                );
+                if end < x_min || start > x_max {
+                    continue;
+                }
+                let [r, g, b] = annotation.color;
+                let span = vec![[start, y_min], [end, y_min], [end, Y_MAX], [start, Y_MAX]];
+                plot_ui.polygon(
+                    Polygon::new(annotation.label.clone(), PlotPoints::from(span))
+                        .fill_color(egui::Color32::from_rgba_unmultiplied(r, g, b, 50))
+                        .stroke(egui::Stroke::new(
+                            1.0,
+                            egui::Color32::from_rgba_unmultiplied(r, g, b, 140),
+                        )),
+                );
+            }
+
+            // Mark gear shifts, labelled with the gears at the top of the chart
+            for (label, time) in &shift_markers {
+                if *time < x_min || *time > x_max {
+                    continue;
+                }
+                let color = egui::Color32::from_rgb(71, 108, 155);
+                plot_ui.vline(
+                    VLine::new("Shifts", *time)
+                        .color(color)
+                        .style(egui_plot::LineStyle::dashed_loose()),
+                );
+                plot_ui.text(
+                    Text::new("Shifts", PlotPoint::new(*time, Y_MAX), label.as_str())
+                        .color(color)
+                        .anchor(egui::Align2::LEFT_TOP),
+                );
+            }
+
             // Draw channel data lines with values in legend
+            let mut band_index = 0;
             for (i, selected) in selected_channels.iter().enumerate() {
                 if selected.file_index >= files.len() {
                     continue;
@@ -183,22 +377,94 @@ impl UltraLogApp {
                     channel_index: selected.channel_index,
                 };
 
-                if let Some(points) = cache.get(&cache_key) {
-                    let plot_points: PlotPoints = points.iter().copied().collect();
-                    let palette = if color_blind_mode {
-                        COLORBLIND_COLORS
-                    } else {
-                        CHART_COLORS
+                // Against a channel, draw each channel of the same log as points
+                if let Some((x_file, x_channel, _)) = domain {
+                    let Some(range) = value_ranges[i] else {
+                        continue;
                     };
-                    let color = palette[selected.color_index % palette.len()];
+                    if selected.file_index != x_file {
+                        continue;
+                    }
+                    let view = match domain_cache.get(&cache_key) {
+                        Some(view) if view.covers(x_channel, x_min, x_max) => view,
+                        _ => {
+                            let log = &files[x_file].log;
+                            let view = DomainDownsample::compute(
+                                x_channel,
+                                log.get_channel_data(x_channel),
+                                log.get_channel_data(selected.channel_index),
+                                x_min,
+                                x_max,
+                                max_chart_points / 2,
+                            );
+                            new_domain_views.push((cache_key, view));
+                            &new_domain_views[new_domain_views.len() - 1].1
+                        }
+                    };
+                    let [r, g, b] = line_colors[i];
+                    plot_ui.points(
+                        Points::new(
+                            legend_names[i].clone(),
+                            Self::normalize_points(view.visible_points(x_min, x_max), range),
+                        )
+                        .color(egui::Color32::from_rgb(r, g, b))
+                        .radius(selected.style.line_width * line_width_scale),
+                    );
+                    continue;
+                }
+
+                if let (Some(pyramid), Some(range)) = (cache.get(&cache_key), value_ranges[i]) {
+                    // Re-downsample the visible range when the view moves or zooms significantly
+                    let view = match view_cache.get(&cache_key) {
+                        Some(view) if view.covers(x_min, x_max) => view,
+                        _ => {
+                            let log = &files[selected.file_index].log;
+                            let view = ViewDownsample::compute(
+                                pyramid,
+                                log.get_times_as_f64(),
+                                log.get_channel_data(selected.channel_index),
+                                x_min,
+                                x_max,
+                                max_chart_points,
+                            );
+                            new_views.push((cache_key, view));
+                            &new_views[new_views.len() - 1].1
+                        }
+                    };
+                    let points = view.visible_points(x_min, x_max);
+                    let color = line_colors[i];
 
                     // Use legend name with value if available
                     let name = &legend_names[i];
 
+                    if selected.style.state_band && domain.is_none() {
+                        let top = Y_MIN - BAND_GAP - band_index as f64 * (BAND_HEIGHT + BAND_GAP);
+                        band_index += 1;
+                        Self::draw_state_band(
+                            plot_ui,
+                            name,
+                            points,
+                            range,
+                            color,
+                            (top - BAND_HEIGHT, top),
+                            x_max - x_min,
+                        );
+                        continue;
+                    }
+
+                    // Normalize Y values to 0-1 range so all channels overlay
+                    let plot_points: PlotPoints =
+                        Self::normalize_points(points, range).into_iter().collect();
+
                     plot_ui.line(
                         Line::new(name.clone(), plot_points)
                             .color(egui::Color32::from_rgb(color[0], color[1], color[2]))
-                            .width(1.5),
+                            .width(selected.style.line_width * line_width_scale)
+                            .style(match selected.style.line_style {
+                                LineStyle::Solid => egui_plot::LineStyle::Solid,
+                                LineStyle::Dashed => egui_plot::LineStyle::dashed_loose(),
+                                LineStyle::Dotted => egui_plot::LineStyle::dotted_dense(),
+                            }),
                     );
                 }
             }
@@ -208,14 +474,48 @@ impl UltraLogApp {
                 plot_ui.vline(
                     VLine::new("Cursor", time)
                         .color(egui::Color32::from_rgb(0, 255, 255)) // Cyan cursor
-                        .width(2.0),
+                        .width(2.0 * line_width_scale),
                 );
             }
 
             // Return pointer position if hovering for click detection
-            plot_ui.pointer_coordinate()
+            (plot_ui.pointer_coordinate(), (x_min, x_max))
         });
 
+        let (pointer, view_range) = response.inner;
+        self.view_downsample_cache.extend(new_views);
+        self.domain_view_cache.extend(new_domain_views);
+
+        // Clicking against a channel moves the cursor to the nearest sample
+        if let Some((file_index, x_channel, _)) = domain {
+            if let (true, Some(pos)) = (response.response.clicked(), pointer) {
+                self.is_playing = false;
+                self.last_frame_time = None;
+                let record = self.nearest_domain_record(
+                    &selected_channels,
+                    &value_ranges,
+                    (file_index, x_channel),
+                    [pos.x, pos.y],
+                    view_range.1 - view_range.0,
+                );
+                if let Some(record) = record {
+                    let time = self.files[file_index]
+                        .log
+                        .get_times_as_f64()
+                        .get(record)
+                        .copied();
+                    self.set_cursor_record(Some(record));
+                    self.set_cursor_time(time);
+                    ui.ctx().request_repaint();
+                }
+            }
+            return;
+        }
+        self.chart_view_range = Some(view_range);
+        if let Some(tab_idx) = self.active_tab {
+            self.tabs[tab_idx].view_range = Some(view_range);
+        }
+
         // Detect user interaction with chart (drag, zoom, scroll)
         // This marks the chart as "interacted" so we stop using the initial zoomed view
         if response.response.dragged()
@@ -226,16 +526,17 @@ impl UltraLogApp {
             self.set_chart_interacted(true);
         }
 
-        // Clear jump-to-time request after it's been processed
-        if self.get_jump_to_time().is_some() {
+        // Clear jump-to-time and zoom requests after they've been processed
+        if self.get_jump_to_time().is_some() || self.get_zoom_to_range().is_some() {
             self.clear_jump_to_time();
+            self.set_zoom_to_range(None);
             // Mark chart as interacted so future jumps work correctly
             self.set_chart_interacted(true);
         }
 
         // Handle click on chart to set cursor position
         if response.response.clicked() {
-            if let Some(pos) = response.inner {
+            if let Some(pos) = pointer {
                 let clicked_time = pos.x;
                 // Clamp to time range
                 if let Some((min, max)) = self.get_time_range() {
@@ -254,6 +555,180 @@ impl UltraLogApp {
         }
     }
 
+    /// X-axis picker above the chart: time, or any channel of the active log
+    fn render_x_axis_picker(&mut self, ui: &mut egui::Ui) {
+        let Some(file) = self
+            .active_tab
+            .and_then(|idx| self.files.get(self.tabs[idx].file_index))
+        else {
+            return;
+        };
+        let display_name = |name: String| {
+            if self.field_normalization {
+                normalize_channel_name_with_custom(&name, Some(&self.custom_normalizations))
+            } else {
+                name
+            }
+        };
+        let mut channels: Vec<(usize, String)> = file
+            .log
+            .channels
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (i, display_name(c.name())))
+            .collect();
+        channels.sort_by_key(|(_, name)| name.to_lowercase());
+
+        let current = self.get_x_axis_channel().map(|(_, channel)| channel);
+        let current_name = current
+            .and_then(|c| channels.iter().find(|(i, _)| *i == c))
+            .map_or("Time".to_string(), |(_, name)| name.clone());
+        let mut selection = current;
+
+        ui.horizontal(|ui| {
+            ui.label("X axis:");
+            egui::ComboBox::from_id_salt("chart_x_axis")
+                .selected_text(current_name)
+                .height(300.0)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut selection, None, "Time");
+                    ui.separator();
+                    for (index, name) in &channels {
+                        ui.selectable_value(&mut selection, Some(*index), name);
+                    }
+                })
+                .response
+                .on_hover_text("Plot channels against time or against another channel such as RPM");
+        });
+
+        if selection != current {
+            self.set_x_axis_channel(selection);
+        }
+    }
+
+    /// Record closest to a click on a chart plotted against a channel,
+    /// measuring X as a fraction of the view width and Y in normalized units
+    fn nearest_domain_record(
+        &self,
+        selected_channels: &[crate::state::SelectedChannel],
+        value_ranges: &[Option<(f64, f64)>],
+        (file_index, x_channel): (usize, usize),
+        [click_x, click_y]: [f64; 2],
+        view_width: f64,
+    ) -> Option<usize> {
+        let log = &self.files.get(file_index)?.log;
+        let xs = log.get_channel_data(x_channel);
+        let channels: Vec<(&[f64], (f64, f64))> = selected_channels
+            .iter()
+            .zip(value_ranges)
+            .filter(|(s, _)| s.file_index == file_index)
+            .filter_map(|(s, range)| Some((log.get_channel_data(s.channel_index), (*range)?)))
+            .collect();
+        let width = if view_width > 0.0 { view_width } else { 1.0 };
+
+        let mut best: Option<(usize, f64)> = None;
+        for (record, x) in xs.iter().enumerate() {
+            let dx = (x - click_x) / width;
+            for (data, (min, max)) in &channels {
+                let Some(&value) = data.get(record) else {
+                    continue;
+                };
+                let span = if max > min { max - min } else { 1.0 };
+                let dy = (value - min) / span - click_y;
+                let distance = dx * dx + dy * dy;
+                if distance.is_finite() && best.is_none_or(|(_, d)| distance < d) {
+                    best = Some((record, distance));
+                }
+            }
+        }
+        best.map(|(record, _)| record)
+    }
+
+    /// Collect (rule name, start, end) regions for alarm violations in the active tab.
+    /// Each region extends to the next record so single-sample violations stay visible.
+    /// Draw a boolean/enum channel as a row of colored runs between `bottom`
+    /// and `top`. Higher values are more opaque; the lowest value is a faint
+    /// track. Enum values are labelled on runs wide enough to fit them.
+    fn draw_state_band(
+        plot_ui: &mut egui_plot::PlotUi,
+        name: &str,
+        points: &[[f64; 2]],
+        (min_value, max_value): (f64, f64),
+        [r, g, b]: [u8; 3],
+        (bottom, top): (f64, f64),
+        view_width: f64,
+    ) {
+        let span = max_value - min_value;
+        let labelled = span > 1.0;
+        for run in state_runs(points) {
+            if run.end <= run.start {
+                continue;
+            }
+            let level = if span > 0.0 {
+                (run.value - min_value) / span
+            } else {
+                1.0
+            };
+            let alpha = if level <= 0.0 {
+                25
+            } else {
+                (60.0 + level * 160.0) as u8
+            };
+            let rect = vec![
+                [run.start, bottom],
+                [run.end, bottom],
+                [run.end, top],
+                [run.start, top],
+            ];
+            plot_ui.polygon(
+                Polygon::new(name, PlotPoints::from(rect))
+                    .fill_color(egui::Color32::from_rgba_unmultiplied(r, g, b, alpha))
+                    .stroke(egui::Stroke::NONE),
+            );
+
+            if labelled && run.end - run.start > view_width * 0.03 {
+                plot_ui.text(
+                    Text::new(
+                        name,
+                        PlotPoint::new((run.start + run.end) / 2.0, (bottom + top) / 2.0),
+                        egui::RichText::new(format!("{}", run.value)).small(),
+                    )
+                    .color(egui::Color32::WHITE),
+                );
+            }
+        }
+    }
+
+    pub(crate) fn collect_alarm_regions(&mut self) -> Vec<(String, f64, f64)> {
+        let Some(file_index) = self.active_tab.map(|idx| self.tabs[idx].file_index) else {
+            return Vec::new();
+        };
+        if self.alarm_rules.is_empty() {
+            return Vec::new();
+        }
+
+        let Some(results) = self.get_alarm_results(file_index).cloned() else {
+            return Vec::new();
+        };
+        let times = self.files[file_index].log.get_times_as_f64();
+
+        let mut regions = Vec::new();
+        for result in &results {
+            let Ok(violations) = &result.violations else {
+                continue;
+            };
+            let name = format!("⚠ {}", self.alarm_rules[result.rule_index].name);
+            for violation in violations {
+                let end = times
+                    .get(violation.end_record + 1)
+                    .copied()
+                    .unwrap_or(violation.end_time);
+                regions.push((name.clone(), violation.start_time, end));
+            }
+        }
+        regions
+    }
+
     /// Format time in seconds to a human-readable string (h:mm:ss.xxx or m:ss.xxx or s.xxx)
     pub fn format_time(seconds: f64) -> String {
         let total_seconds = seconds.abs();
@@ -275,20 +750,17 @@ impl UltraLogApp {
         }
     }
 
-    /// Normalize values to 0-1 range for overlay display
-    pub fn normalize_points(points: &[[f64; 2]]) -> Vec<[f64; 2]> {
-        if points.is_empty() {
-            return Vec::new();
-        }
-
-        // Find min and max Y values
-        let mut min_y = f64::MAX;
-        let mut max_y = f64::MIN;
-        for point in points {
-            min_y = min_y.min(point[1]);
-            max_y = max_y.max(point[1]);
-        }
+    /// Format a time of day in seconds since midnight as hh:mm:ss.xxx
+    pub fn format_clock_time(seconds: f64) -> String {
+        let seconds = seconds.rem_euclid(86400.0);
+        let hours = (seconds / 3600.0).floor() as u32;
+        let minutes = ((seconds % 3600.0) / 60.0).floor() as u32;
+        format!("{:02}:{:02}:{:06.3}", hours, minutes, seconds % 60.0)
+    }
 
+    /// Normalize values to 0-1 range for overlay display, using the channel's
+    /// full value range so the scale doesn't change while zooming
+    pub fn normalize_points(points: &[[f64; 2]], (min_y, max_y): (f64, f64)) -> Vec<[f64; 2]> {
         // Handle case where all values are the same
         let range = max_y - min_y;
         if range.abs() < f64::EPSILON {
@@ -302,76 +774,4 @@ impl UltraLogApp {
             .map(|p| [p[0], (p[1] - min_y) / range])
             .collect()
     }
-
-    /// Downsample data using the LTTB (Largest Triangle Three Buckets) algorithm.
-    /// This preserves visual characteristics while reducing point count for performance.
-    pub fn downsample_lttb(times: &[f64], values: &[f64], target_points: usize) -> Vec<[f64; 2]> {
-        let n = times.len();
-
-        if n <= target_points || target_points < 3 {
-            // No downsampling needed
-            return times
-                .iter()
-                .zip(values.iter())
-                .map(|(t, v)| [*t, *v])
-                .collect();
-        }
-
-        let mut result = Vec::with_capacity(target_points);
-
-        // Always include first point
-        result.push([times[0], values[0]]);
-
-        // Bucket size
-        let bucket_size = (n - 2) as f64 / (target_points - 2) as f64;
-
-        let mut a_index = 0usize;
-
-        for i in 0..(target_points - 2) {
-            // Calculate bucket range
-            let bucket_start = ((i as f64 + 1.0) * bucket_size).floor() as usize + 1;
-            let bucket_end = (((i + 2) as f64) * bucket_size).floor() as usize + 1;
-            let bucket_end = bucket_end.min(n - 1);
-
-            // Calculate average point for next bucket (for triangle calculation)
-            let next_bucket_start = bucket_end;
-            let next_bucket_end = (((i + 3) as f64) * bucket_size).floor() as usize + 1;
-            let next_bucket_end = next_bucket_end.min(n);
-
-            let (avg_x, avg_y) = if next_bucket_start < next_bucket_end {
-                let count = (next_bucket_end - next_bucket_start) as f64;
-                let sum_x: f64 = times[next_bucket_start..next_bucket_end].iter().sum();
-                let sum_y: f64 = values[next_bucket_start..next_bucket_end].iter().sum();
-                (sum_x / count, sum_y / count)
-            } else {
-                (times[n - 1], values[n - 1])
-            };
-
-            // Find point in current bucket with largest triangle area
-            let mut max_area = -1.0f64;
-            let mut max_index = bucket_start;
-
-            let a_x = times[a_index];
-            let a_y = values[a_index];
-
-            for j in bucket_start..bucket_end {
-                // Calculate triangle area
-                let area =
-                    ((a_x - avg_x) * (values[j] - a_y) - (a_x - times[j]) * (avg_y - a_y)).abs();
-
-                if area > max_area {
-                    max_area = area;
-                    max_index = j;
-                }
-            }
-
-            result.push([times[max_index], values[max_index]]);
-            a_index = max_index;
-        }
-
-        // Always include last point
-        result.push([times[n - 1], values[n - 1]]);
-
-        result
-    }
 }
@@ -0,0 +1,125 @@
+//! Crash report dialog.
+//!
+//! Shown on launch when the previous run left a crash report, offering to
+//! open a GitHub issue prefilled with it.
+
+use eframe::egui;
+
+use super::triage::percent_encode;
+use crate::app::UltraLogApp;
+use crate::crash;
+
+/// GitHub page for opening a new issue
+const NEW_ISSUE_URL: &str = "https://github.com/SomethingNew71/UltraLog/issues/new";
+
+/// Longest report put in the issue URL before encoding; GitHub rejects long URLs
+const MAX_ISSUE_REPORT_BYTES: usize = 3000;
+
+impl UltraLogApp {
+    /// Render the dialog for the oldest crash report, if any
+    pub fn render_crash_dialog(&mut self, ctx: &egui::Context) {
+        let Some(path) = self.crash_reports.first().cloned() else {
+            return;
+        };
+        let report = match std::fs::read_to_string(&path) {
+            Ok(report) => report,
+            Err(e) => {
+                tracing::warn!("Failed to read crash report {}: {}", path.display(), e);
+                self.crash_reports.remove(0);
+                return;
+            }
+        };
+
+        let mut open = true;
+        let mut report_issue = false;
+        let mut copy = false;
+        let mut dismiss = false;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("UltraLog Closed Unexpectedly"))
+            .id(egui::Id::new("crash_dialog"))
+            .open(&mut open)
+            .resizable(true)
+            .collapsible(false)
+            .default_width(560.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.label(lang.tr(
+                    "UltraLog crashed the last time it ran. Reporting it on GitHub, along with \
+                     the log file that was open if you can share it, helps get it fixed.",
+                ));
+                ui.add_space(8.0);
+
+                egui::ScrollArea::vertical()
+                    .max_height(260.0)
+                    .show(ui, |ui| {
+                        ui.label(egui::RichText::new(&report).monospace().small());
+                    });
+
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .button(lang.tr("Report on GitHub"))
+                        .on_hover_text(lang.tr("Open a GitHub issue prefilled with this report"))
+                        .clicked()
+                    {
+                        report_issue = true;
+                    }
+                    if ui.button(lang.tr("Copy Report")).clicked() {
+                        copy = true;
+                    }
+                    if ui
+                        .button(lang.tr("Dismiss"))
+                        .on_hover_text(lang.tr("Delete this report"))
+                        .clicked()
+                    {
+                        dismiss = true;
+                    }
+                });
+            });
+
+        if copy {
+            ctx.copy_text(report.clone());
+            self.show_toast_success(lang.tr("Crash report copied to clipboard"));
+        }
+
+        if report_issue {
+            let title = format!("Crash: {}", crash::report_summary(&report));
+            let body = format!(
+                "**What I was doing:** \n\n**Crash report:**\n```\n{}\n```\n",
+                truncate_report(&report)
+            );
+            let url = format!(
+                "{}?title={}&body={}",
+                NEW_ISSUE_URL,
+                percent_encode(&title),
+                percent_encode(&body)
+            );
+            if open::that(&url).is_err() {
+                self.show_toast_error(lang.tr("Failed to open browser"));
+                return;
+            }
+        }
+
+        if report_issue || dismiss || !open {
+            crash::discard_report(&path);
+            self.crash_reports.remove(0);
+        }
+    }
+}
+
+/// The start of a report, cut at a line boundary to fit in an issue URL
+fn truncate_report(report: &str) -> String {
+    if report.len() <= MAX_ISSUE_REPORT_BYTES {
+        return report.to_string();
+    }
+    let mut end = 0;
+    for line in report.split_inclusive('\n') {
+        if end + line.len() > MAX_ISSUE_REPORT_BYTES {
+            break;
+        }
+        end += line.len();
+    }
+    format!("{}... (truncated)", &report[..end])
+}
@@ -0,0 +1,270 @@
+//! Data table view.
+//!
+//! Shows the active tab's log as a grid of raw records: the time column
+//! plus the channels selected for the tab, converted to the display units.
+//! Only the visible rows are laid out, so large logs scroll smoothly. The
+//! table follows the cursor, clicking a row moves the cursor there, and
+//! selected rows can be copied as tab-separated text.
+
+use eframe::egui;
+use egui_extras::{Column, TableBuilder};
+
+use crate::app::UltraLogApp;
+use crate::normalize::normalize_channel_name_with_custom;
+
+/// A channel shown as a table column
+struct TableColumn {
+    channel_index: usize,
+    /// Header text, e.g. `RPM (rpm)`
+    header: String,
+}
+
+impl UltraLogApp {
+    /// Render the data table view
+    pub fn render_data_table_view(&mut self, ui: &mut egui::Ui) {
+        let Some(tab_idx) = self.active_tab else {
+            ui.centered_and_justified(|ui| {
+                ui.label(
+                    egui::RichText::new("Load a log file to view its data")
+                        .size(20.0)
+                        .color(egui::Color32::GRAY),
+                );
+            });
+            return;
+        };
+
+        self.render_tab_bar(ui);
+        ui.add_space(10.0);
+
+        let file_index = self.tabs[tab_idx].file_index;
+        if file_index >= self.files.len() {
+            return;
+        }
+
+        let columns = self.data_table_columns(tab_idx);
+        let record_count = self.files[file_index].log.record_count();
+        let cursor_record = self
+            .get_cursor_time()
+            .and_then(|time| self.files[file_index].log.record_at_time(time));
+
+        // Scroll to the cursor only when it moves, so the table can be
+        // scrolled freely while the cursor stays put
+        let state = &mut self.tabs[tab_idx].data_table_state;
+        let scroll_to = (cursor_record != state.followed_record)
+            .then_some(cursor_record)
+            .flatten();
+        state.followed_record = cursor_record;
+        let selected = state.selected_range();
+
+        let mut copy = false;
+        let mut clear_selection = false;
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new(format!("{} records", record_count)).color(egui::Color32::GRAY),
+            );
+            if let Some(range) = &selected {
+                ui.separator();
+                ui.label(format!("{} selected", range.clone().count()));
+            }
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .add_enabled(selected.is_some(), egui::Button::new("Clear Selection"))
+                    .clicked()
+                {
+                    clear_selection = true;
+                }
+                if ui
+                    .add_enabled(selected.is_some(), egui::Button::new("📋 Copy Rows"))
+                    .on_hover_text("Copy the selected rows as tab-separated text (Ctrl+C)")
+                    .clicked()
+                {
+                    copy = true;
+                }
+            });
+        });
+        ui.add_space(4.0);
+
+        if columns.is_empty() {
+            ui.label(
+                egui::RichText::new("Select channels in the right panel to add them as columns")
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(4.0);
+        }
+
+        // Ctrl+C copies the selection unless a text field has focus
+        copy |= selected.is_some()
+            && ui.memory(|m| m.focused().is_none())
+            && ui.input(|i| {
+                // Ctrl+Shift+C copies the values at the cursor instead
+                !i.modifiers.shift && i.events.iter().any(|e| matches!(e, egui::Event::Copy))
+            });
+
+        let shift = ui.input(|i| i.modifiers.shift);
+        let highlight = egui::Color32::from_rgb(253, 193, 73);
+        let row_height = ui.text_style_height(&egui::TextStyle::Monospace) + 6.0;
+        let mut clicked_row = None;
+
+        let mut table = TableBuilder::new(ui)
+            .striped(true)
+            .resizable(true)
+            .sense(egui::Sense::click())
+            .cell_layout(egui::Layout::right_to_left(egui::Align::Center))
+            .column(Column::auto().at_least(90.0))
+            .columns(Column::auto().at_least(90.0), columns.len())
+            .min_scrolled_height(0.0);
+        if let Some(row) = scroll_to {
+            table = table.scroll_to_row(row, Some(egui::Align::Center));
+        }
+
+        let log = &self.files[file_index].log;
+        table
+            .header(24.0, |mut header| {
+                header.col(|ui| {
+                    ui.strong("Time (s)");
+                });
+                for column in &columns {
+                    header.col(|ui| {
+                        ui.strong(&column.header);
+                    });
+                }
+            })
+            .body(|body| {
+                body.rows(row_height, record_count, |mut row| {
+                    let record = row.index();
+                    row.set_selected(selected.as_ref().is_some_and(|r| r.contains(&record)));
+                    let color = (cursor_record == Some(record)).then_some(highlight);
+
+                    let cell = |text: String| {
+                        let text = egui::RichText::new(text).monospace();
+                        match color {
+                            Some(color) => text.color(color),
+                            None => text,
+                        }
+                    };
+                    row.col(|ui| {
+                        ui.label(cell(format!("{:.3}", log.times[record])));
+                    });
+                    for column in &columns {
+                        let text = log
+                            .value_at(column.channel_index, record)
+                            .map(|value| {
+                                let (converted, _) = self.convert_channel_value(
+                                    file_index,
+                                    column.channel_index,
+                                    record,
+                                    value,
+                                );
+                                format!("{:.2}", converted)
+                            })
+                            .unwrap_or_default();
+                        row.col(|ui| {
+                            ui.label(cell(text));
+                        });
+                    }
+
+                    if row.response().clicked() {
+                        clicked_row = Some(record);
+                    }
+                });
+            });
+
+        if let Some(record) = clicked_row {
+            let state = &mut self.tabs[tab_idx].data_table_state;
+            state.selection = match state.selection {
+                Some((anchor, _)) if shift => Some((anchor, record)),
+                _ => Some((record, record)),
+            };
+            // Move the cursor without scrolling the row that was clicked
+            state.followed_record = Some(record);
+            let time = self.files[file_index].log.times[record];
+            self.set_cursor_time(Some(time));
+            self.set_cursor_record(Some(record));
+        }
+
+        if copy {
+            if let Some(range) = self.tabs[tab_idx].data_table_state.selected_range() {
+                let rows = range.clone().count();
+                let text = self.data_table_text(file_index, &columns, range);
+                ui.ctx().copy_text(text);
+                self.show_toast_success(&format!(
+                    "Copied {} row{}",
+                    rows,
+                    if rows == 1 { "" } else { "s" }
+                ));
+            }
+        }
+
+        if clear_selection {
+            self.tabs[tab_idx].data_table_state.selection = None;
+        }
+    }
+
+    /// Columns for the tab's selected channels that belong to its file
+    fn data_table_columns(&self, tab_idx: usize) -> Vec<TableColumn> {
+        let tab = &self.tabs[tab_idx];
+        tab.selected_channels
+            .iter()
+            .filter(|selected| selected.file_index == tab.file_index)
+            .map(|selected| {
+                let name = if self.field_normalization {
+                    normalize_channel_name_with_custom(
+                        &selected.channel.name(),
+                        Some(&self.custom_normalizations),
+                    )
+                } else {
+                    selected.channel.name()
+                };
+                let (_, unit) =
+                    self.convert_channel_value(tab.file_index, selected.channel_index, 0, 0.0);
+                let header = if unit.is_empty() {
+                    name
+                } else {
+                    format!("{} ({})", name, unit)
+                };
+                TableColumn {
+                    channel_index: selected.channel_index,
+                    header,
+                }
+            })
+            .collect()
+    }
+
+    /// Records in `range` as tab-separated text with a header line
+    fn data_table_text(
+        &self,
+        file_index: usize,
+        columns: &[TableColumn],
+        range: std::ops::RangeInclusive<usize>,
+    ) -> String {
+        let log = &self.files[file_index].log;
+        let mut lines = Vec::with_capacity(range.clone().count() + 1);
+        let mut header = vec!["Time (s)".to_string()];
+        header.extend(columns.iter().map(|c| c.header.clone()));
+        lines.push(header.join("\t"));
+
+        for record in range {
+            let Some(time) = log.times.get(record) else {
+                break;
+            };
+            let mut fields = vec![format!("{:.3}", time)];
+            for column in columns {
+                fields.push(
+                    log.value_at(column.channel_index, record)
+                        .map(|value| {
+                            let (converted, _) = self.convert_channel_value(
+                                file_index,
+                                column.channel_index,
+                                record,
+                                value,
+                            );
+                            converted.to_string()
+                        })
+                        .unwrap_or_default(),
+                );
+            }
+            lines.push(fields.join("\t"));
+        }
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,296 @@
+//! Drag runs window.
+//!
+//! Finds every launch from a standstill in the active log and shows a results
+//! card per run: 0-60 mph and 0-100 km/h, the 60 ft, 330 ft, 1/8 and 1/4 mile
+//! times with trap speeds, and the peak speed. Runs are timed from the
+//! normalized "Vehicle Speed" channel, or a GPS speed channel if the log has
+//! no wheel speed, and can be copied or saved as CSV.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::drag::{detect_runs, runs_to_csv, DragMark, DragRun};
+use crate::dyno::speed_to_mps;
+use crate::i18n::Language;
+use crate::state::{ActiveTool, DragScan};
+
+/// What to do once the window is drawn
+enum DragAction {
+    /// Show a run in the Log Viewer
+    Zoom(usize),
+    /// Copy every run as CSV
+    Copy,
+    /// Save every run as a CSV file
+    Save,
+}
+
+impl UltraLogApp {
+    /// Vehicle speed channel of a file, falling back to GPS speed
+    fn drag_speed_channel(&self, file_index: usize) -> Option<usize> {
+        self.find_channel_by_name(file_index, "Vehicle Speed")
+            .or_else(|| {
+                self.files[file_index]
+                    .log
+                    .channels
+                    .iter()
+                    .position(|channel| {
+                        let name = channel.name().to_lowercase();
+                        name.contains("gps") && name.contains("speed")
+                    })
+            })
+    }
+
+    /// Drag runs in the active file, found again when the file has changed
+    fn current_drag_scan(&mut self) -> Option<&DragScan> {
+        let file_index = self
+            .active_tab
+            .map(|idx| self.tabs[idx].file_index)
+            .filter(|&idx| idx < self.files.len())?;
+
+        let stale = self
+            .drag_scan
+            .as_ref()
+            .is_none_or(|scan| scan.file_index != file_index);
+        if stale {
+            let speed = self.drag_speed_channel(file_index);
+            let log = &self.files[file_index].log;
+            let source = speed.map_or_else(String::new, |idx| log.channels[idx].name());
+            let runs = speed
+                .ok_or_else(|| "No vehicle speed or GPS speed channel in this log".to_string())
+                .map(|idx| {
+                    let unit = log.channels[idx].unit();
+                    let speed: Vec<f64> = log
+                        .get_channel_data(idx)
+                        .iter()
+                        .map(|&v| speed_to_mps(v, unit))
+                        .collect();
+                    detect_runs(log.get_times_as_f64(), &speed)
+                });
+            self.drag_scan = Some(DragScan {
+                file_index,
+                source,
+                runs,
+            });
+        }
+        self.drag_scan.as_ref()
+    }
+
+    /// Render the drag runs window
+    pub fn render_drag_window(&mut self, ctx: &egui::Context) {
+        if !self.show_drag_window {
+            return;
+        }
+
+        let scan = self.current_drag_scan().cloned();
+        let speed_unit = self.active_unit_preferences().speed;
+        let speed = move |mps: f64| speed_unit.convert_from_kmh(mps * 3.6);
+        let unit = speed_unit.symbol();
+
+        let mut open = true;
+        let mut action: Option<DragAction> = None;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Drag Runs"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(560.0)
+            .default_height(420.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                let runs = match &scan {
+                    None => {
+                        ui.label(
+                            egui::RichText::new(lang.tr("Open a log file to find its drag runs."))
+                                .color(egui::Color32::GRAY),
+                        );
+                        return;
+                    }
+                    Some(DragScan { runs: Err(e), .. }) => {
+                        ui.colored_label(egui::Color32::from_rgb(253, 193, 73), format!("⚠ {}", e));
+                        return;
+                    }
+                    Some(DragScan {
+                        runs: Ok(runs),
+                        source,
+                        ..
+                    }) => {
+                        ui.horizontal(|ui| {
+                            ui.label(
+                                egui::RichText::new(format!(
+                                    "{} {}",
+                                    lang.tr("Timed from"),
+                                    source
+                                ))
+                                .color(egui::Color32::GRAY),
+                            );
+                            ui.with_layout(
+                                egui::Layout::right_to_left(egui::Align::Center),
+                                |ui| {
+                                    ui.add_enabled_ui(!runs.is_empty(), |ui| {
+                                        if ui.button(lang.tr("💾 Save CSV...")).clicked() {
+                                            action = Some(DragAction::Save);
+                                        }
+                                        if ui.button(lang.tr("📋 Copy as CSV")).clicked() {
+                                            action = Some(DragAction::Copy);
+                                        }
+                                    });
+                                },
+                            );
+                        });
+                        runs
+                    }
+                };
+                ui.label(
+                    egui::RichText::new(lang.tr(
+                        "Times are estimated from vehicle speed, starting at first movement \
+                         rather than a timing beam.",
+                    ))
+                    .color(egui::Color32::GRAY),
+                );
+                ui.separator();
+
+                if runs.is_empty() {
+                    ui.label(
+                        egui::RichText::new(
+                            lang.tr("No runs from a standstill reaching 60 ft found."),
+                        )
+                        .color(egui::Color32::GRAY),
+                    );
+                    return;
+                }
+
+                egui::ScrollArea::vertical()
+                    .id_salt("drag_runs_scroll")
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        for (i, run) in runs.iter().enumerate() {
+                            Self::render_drag_card(ui, i, run, &speed, unit, &mut action, lang);
+                            ui.add_space(6.0);
+                        }
+                    });
+            });
+
+        if let Some(DragScan {
+            file_index,
+            runs: Ok(runs),
+            ..
+        }) = &scan
+        {
+            match action {
+                Some(DragAction::Zoom(i)) => {
+                    let run = &runs[i];
+                    self.active_tool = ActiveTool::LogViewer;
+                    self.set_zoom_to_range(Some((run.launch_time, run.end_time)));
+                    self.set_cursor_time(Some(run.launch_time));
+                    self.set_cursor_record(Some(run.launch_record));
+                }
+                Some(DragAction::Copy) => {
+                    ctx.copy_text(runs_to_csv(runs, speed, unit));
+                    self.show_toast_success(lang.tr("Drag runs copied as CSV"));
+                }
+                Some(DragAction::Save) => {
+                    self.save_drag_runs(*file_index, runs_to_csv(runs, speed, unit));
+                }
+                None => {}
+            }
+        }
+
+        if !open {
+            self.show_drag_window = false;
+        }
+    }
+
+    /// Render the results card of one run
+    fn render_drag_card(
+        ui: &mut egui::Ui,
+        index: usize,
+        run: &DragRun,
+        speed: &impl Fn(f64) -> f64,
+        unit: &str,
+        action: &mut Option<DragAction>,
+        lang: Language,
+    ) {
+        let seconds = |value: Option<f64>| value.map_or("-".to_string(), |v| format!("{:.2}s", v));
+
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(format!("{} {}", lang.tr("Run"), index + 1)).strong());
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} {}",
+                        lang.tr("launched at"),
+                        Self::format_time(run.launch_time)
+                    ))
+                    .color(egui::Color32::GRAY),
+                );
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui
+                        .small_button(lang.tr("Zoom"))
+                        .on_hover_text(lang.tr("Show this run in the Log Viewer"))
+                        .clicked()
+                    {
+                        *action = Some(DragAction::Zoom(index));
+                    }
+                });
+            });
+
+            egui::Grid::new(("drag_run_grid", index))
+                .striped(true)
+                .spacing([16.0, 6.0])
+                .show(ui, |ui| {
+                    for header in ["", lang.tr("Time"), lang.tr("Trap Speed")] {
+                        ui.label(egui::RichText::new(header).strong());
+                    }
+                    ui.end_row();
+
+                    ui.label("0-60 mph");
+                    ui.label(seconds(run.zero_to_60_mph));
+                    ui.label("");
+                    ui.end_row();
+
+                    ui.label("0-100 km/h");
+                    ui.label(seconds(run.zero_to_100_kmh));
+                    ui.label("");
+                    ui.end_row();
+
+                    for mark in DragMark::ALL {
+                        let result = run.mark(mark);
+                        ui.label(mark.label());
+                        ui.label(seconds(result.map(|m| m.elapsed)));
+                        ui.label(result.map_or("-".to_string(), |m| {
+                            format!("{:.1} {}", speed(m.speed_mps), unit)
+                        }));
+                        ui.end_row();
+                    }
+
+                    ui.label(lang.tr("Peak speed"));
+                    ui.label("");
+                    ui.label(format!("{:.1} {}", speed(run.peak_speed_mps), unit));
+                    ui.end_row();
+                });
+        });
+    }
+
+    /// Ask where to save the drag runs of a file and write them as CSV
+    fn save_drag_runs(&mut self, file_index: usize, csv: String) {
+        let lang = self.language;
+        let stem = self.files[file_index]
+            .path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "log".to_string());
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("CSV", &["csv"])
+            .set_file_name(format!("{}_drag_runs.csv", stem))
+            .save_file()
+        else {
+            return;
+        };
+
+        match std::fs::write(&path, csv) {
+            Ok(()) => self.show_toast_success(lang.tr("Drag runs exported")),
+            Err(e) => self.show_toast_error(&format!("{} {}", lang.tr("Export failed:"), e)),
+        }
+    }
+}
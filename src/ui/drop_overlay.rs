@@ -0,0 +1,121 @@
+//! Drag-and-drop overlay.
+//!
+//! While files are dragged over the window, covers it with a highlight
+//! naming what will be loaded and marking files whose type UltraLog can't
+//! open, before they are dropped.
+
+use std::path::Path;
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::parsers::LOG_EXTENSIONS;
+use crate::session::SESSION_EXTENSION;
+
+/// Proprietary logs UltraLog can't read but explains how to export
+const EXPLAINED_EXTENSIONS: &[&str] = &["llg", "hlgzip", "daq", "dl", "dlz"];
+
+/// Most files listed individually on the overlay
+const MAX_LISTED_FILES: usize = 8;
+
+/// Whether a dropped file is one UltraLog will try to open: a log it reads,
+/// a proprietary log it explains how to export, or a session
+pub(crate) fn accepts_drop(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| {
+        LOG_EXTENSIONS
+            .iter()
+            .chain(EXPLAINED_EXTENSIONS)
+            .chain([&SESSION_EXTENSION])
+            .any(|accepted| ext.eq_ignore_ascii_case(accepted))
+    })
+}
+
+impl UltraLogApp {
+    /// Render the overlay while files are hovering over the window
+    pub fn render_drop_overlay(&self, ctx: &egui::Context) {
+        let hovered: Vec<Option<std::path::PathBuf>> =
+            ctx.input(|i| i.raw.hovered_files.iter().map(|f| f.path.clone()).collect());
+        if hovered.is_empty() {
+            return;
+        }
+
+        let amber = egui::Color32::from_rgb(253, 193, 73);
+        let rejected_color = egui::Color32::from_rgb(191, 78, 48);
+        let accepted_color = egui::Color32::from_rgb(113, 180, 78);
+
+        // Some platforms don't say which files are being dragged until the drop
+        let known: Vec<(&Path, bool)> = hovered
+            .iter()
+            .flatten()
+            .map(|path| (path.as_path(), accepts_drop(path)))
+            .collect();
+        let accepted = known.iter().filter(|(_, ok)| *ok).count();
+        let title = match known.as_slice() {
+            [] => "Drop to load files".to_string(),
+            [(path, true)] => format!("Drop to load {}", file_name(path)),
+            [(_, false)] => "This file type can't be opened".to_string(),
+            _ if accepted == 0 => "None of these files can be opened".to_string(),
+            _ => format!("Drop to load {} of {} files", accepted, known.len()),
+        };
+
+        let screen = ctx.content_rect();
+        let painter = ctx.layer_painter(egui::LayerId::new(
+            egui::Order::Foreground,
+            egui::Id::new("drop_overlay"),
+        ));
+        painter.rect_filled(screen, 0.0, egui::Color32::from_black_alpha(170));
+        painter.rect_stroke(
+            screen.shrink(12.0),
+            12.0,
+            egui::Stroke::new(3.0, amber),
+            egui::StrokeKind::Inside,
+        );
+
+        let mut y = screen.center().y - 20.0;
+        painter.text(
+            egui::pos2(screen.center().x, y),
+            egui::Align2::CENTER_CENTER,
+            title,
+            egui::FontId::proportional(26.0),
+            amber,
+        );
+        y += 40.0;
+
+        // List each file with whether it will be loaded
+        if known.len() > 1 || known.iter().any(|(_, ok)| !ok) {
+            for (path, ok) in known.iter().take(MAX_LISTED_FILES) {
+                let (text, color) = if *ok {
+                    (format!("✔ {}", file_name(path)), accepted_color)
+                } else {
+                    (
+                        format!("✖ {} (unsupported file type)", file_name(path)),
+                        rejected_color,
+                    )
+                };
+                painter.text(
+                    egui::pos2(screen.center().x, y),
+                    egui::Align2::CENTER_CENTER,
+                    text,
+                    egui::FontId::proportional(15.0),
+                    color,
+                );
+                y += 22.0;
+            }
+            if known.len() > MAX_LISTED_FILES {
+                painter.text(
+                    egui::pos2(screen.center().x, y),
+                    egui::Align2::CENTER_CENTER,
+                    format!("and {} more", known.len() - MAX_LISTED_FILES),
+                    egui::FontId::proportional(15.0),
+                    egui::Color32::GRAY,
+                );
+            }
+        }
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
@@ -0,0 +1,467 @@
+//! Virtual dyno view.
+//!
+//! Estimates a power/torque curve from a WOT pull selected on the timeline,
+//! using the vehicle parameters from [`crate::dyno::DynoConfig`].
+
+use eframe::egui;
+use egui_plot::{Legend, Line, Plot, PlotPoints};
+
+use crate::app::UltraLogApp;
+use crate::dyno::{speed_to_mps, DynoPoint, SpeedSource, NM_PER_LB_FT, WATTS_PER_HP};
+use crate::normalize::{normalize_channel_name_with_custom, sort_channels_by_priority};
+
+/// Power curve color (rust orange)
+const POWER_COLOR: egui::Color32 = egui::Color32::from_rgb(191, 78, 48);
+
+/// Torque curve color (blue)
+const TORQUE_COLOR: egui::Color32 = egui::Color32::from_rgb(71, 108, 155);
+
+/// Width of the settings column
+const SETTINGS_WIDTH: f32 = 260.0;
+
+impl UltraLogApp {
+    /// Render the virtual dyno view
+    pub fn render_dyno_view(&mut self, ui: &mut egui::Ui) {
+        let Some(tab_idx) = self.active_tab else {
+            ui.centered_and_justified(|ui| {
+                ui.label(
+                    egui::RichText::new("Load a log file to use the virtual dyno")
+                        .size(20.0)
+                        .color(egui::Color32::GRAY),
+                );
+            });
+            return;
+        };
+
+        self.render_tab_bar(ui);
+        ui.add_space(10.0);
+
+        let file_index = self.tabs[tab_idx].file_index;
+        if file_index >= self.files.len() {
+            return;
+        }
+
+        // Auto-detect speed and RPM channels once per tab
+        if !self.tabs[tab_idx].dyno_state.auto_detected {
+            let find = |target: &str| {
+                self.files[file_index].log.channels.iter().position(|c| {
+                    normalize_channel_name_with_custom(&c.name(), Some(&self.custom_normalizations))
+                        == target
+                })
+            };
+            let speed = find("Vehicle Speed");
+            let rpm = find("RPM");
+            let state = &mut self.tabs[tab_idx].dyno_state;
+            state.speed_channel = speed;
+            state.rpm_channel = rpm;
+            state.auto_detected = true;
+        }
+
+        ui.horizontal_top(|ui| {
+            ui.allocate_ui_with_layout(
+                egui::vec2(SETTINGS_WIDTH, ui.available_height()),
+                egui::Layout::top_down(egui::Align::LEFT),
+                |ui| {
+                    egui::ScrollArea::vertical()
+                        .id_salt("dyno_settings_scroll")
+                        .show(ui, |ui| {
+                            ui.set_width(SETTINGS_WIDTH);
+                            self.render_dyno_settings(ui, tab_idx, file_index);
+                        });
+                },
+            );
+
+            ui.separator();
+
+            ui.vertical(|ui| {
+                self.render_dyno_plot(ui, tab_idx, file_index);
+            });
+        });
+    }
+
+    /// Render channel selectors, pull selection, and vehicle parameters
+    fn render_dyno_settings(&mut self, ui: &mut egui::Ui, tab_idx: usize, file_index: usize) {
+        let file = &self.files[file_index];
+        let sorted_channels = sort_channels_by_priority(
+            file.log.channels.len(),
+            |idx| file.log.channels[idx].name(),
+            self.field_normalization,
+            Some(&self.custom_normalizations),
+        );
+        let channel_names: std::collections::HashMap<usize, String> = sorted_channels
+            .iter()
+            .map(|(idx, name, _)| (*idx, name.clone()))
+            .collect();
+
+        let cursor_time = self.get_cursor_time();
+        let view_range = self.chart_view_range;
+        let time_range = self.get_time_range();
+        let annotations = self.tabs[tab_idx].annotations.clone();
+
+        let config = &mut self.dyno_config;
+        let state = &mut self.tabs[tab_idx].dyno_state;
+
+        // --- Data Source ---
+        ui.label(egui::RichText::new("Data Source").strong());
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            ui.radio_value(
+                &mut config.speed_source,
+                SpeedSource::VehicleSpeed,
+                "Vehicle Speed",
+            );
+            ui.radio_value(
+                &mut config.speed_source,
+                SpeedSource::RpmAndGear,
+                "RPM + Gear",
+            );
+        });
+
+        let channel_combo = |ui: &mut egui::Ui, label: &str, id: &str, slot: &mut Option<usize>| {
+            ui.horizontal(|ui| {
+                ui.label(label);
+                egui::ComboBox::from_id_salt(id)
+                    .selected_text(
+                        slot.and_then(|i| channel_names.get(&i).map(|n| n.as_str()))
+                            .unwrap_or("Select..."),
+                    )
+                    .width(150.0)
+                    .show_ui(ui, |ui| {
+                        for (idx, name, _) in &sorted_channels {
+                            if ui.selectable_label(*slot == Some(*idx), name).clicked() {
+                                *slot = Some(*idx);
+                            }
+                        }
+                    });
+            });
+        };
+
+        if config.speed_source == SpeedSource::VehicleSpeed {
+            channel_combo(ui, "Speed:", "dyno_speed", &mut state.speed_channel);
+        }
+        channel_combo(ui, "RPM:", "dyno_rpm", &mut state.rpm_channel);
+
+        // --- Pull Selection ---
+        ui.add_space(8.0);
+        ui.separator();
+        ui.label(egui::RichText::new("Pull Selection").strong());
+        ui.label(
+            egui::RichText::new("Select the WOT pull using the timeline cursor.")
+                .color(egui::Color32::GRAY),
+        );
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(cursor_time.is_some(), egui::Button::new("Start at Cursor"))
+                .clicked()
+            {
+                state.pull_start = cursor_time;
+            }
+            if ui
+                .add_enabled(cursor_time.is_some(), egui::Button::new("End at Cursor"))
+                .clicked()
+            {
+                state.pull_end = cursor_time;
+            }
+        });
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(view_range.is_some(), egui::Button::new("Chart View"))
+                .on_hover_text("Use the range visible in the Log Viewer chart")
+                .clicked()
+            {
+                if let Some((start, end)) = view_range {
+                    state.pull_start = Some(start);
+                    state.pull_end = Some(end);
+                }
+            }
+            if !annotations.is_empty() {
+                egui::ComboBox::from_id_salt("dyno_annotation")
+                    .selected_text("From Annotation")
+                    .width(120.0)
+                    .show_ui(ui, |ui| {
+                        for annotation in &annotations {
+                            if ui.selectable_label(false, &annotation.label).clicked() {
+                                state.pull_start = Some(annotation.start.min(annotation.end));
+                                state.pull_end = Some(annotation.start.max(annotation.end));
+                            }
+                        }
+                    });
+            }
+        });
+
+        egui::Grid::new("dyno_pull_grid")
+            .num_columns(2)
+            .spacing([8.0, 4.0])
+            .show(ui, |ui| {
+                let (min_t, max_t) = time_range.unwrap_or((0.0, 0.0));
+                for (label, value) in [
+                    ("Start (s):", &mut state.pull_start),
+                    ("End (s):", &mut state.pull_end),
+                ] {
+                    ui.label(label);
+                    let mut v = value.unwrap_or(min_t);
+                    if ui
+                        .add(
+                            egui::DragValue::new(&mut v)
+                                .speed(0.05)
+                                .range(min_t..=max_t)
+                                .max_decimals(3),
+                        )
+                        .changed()
+                    {
+                        *value = Some(v);
+                    }
+                    ui.end_row();
+                }
+            });
+
+        // --- Vehicle Parameters ---
+        ui.add_space(8.0);
+        ui.separator();
+        ui.label(egui::RichText::new("Vehicle").strong());
+        ui.add_space(4.0);
+
+        egui::Grid::new("dyno_vehicle_grid")
+            .num_columns(2)
+            .spacing([8.0, 4.0])
+            .show(ui, |ui| {
+                ui.label("Mass (kg):");
+                ui.add(egui::DragValue::new(&mut config.mass_kg).range(100.0..=10000.0));
+                ui.end_row();
+
+                ui.label("Drag coeff. (Cd):");
+                ui.add(
+                    egui::DragValue::new(&mut config.drag_coefficient)
+                        .speed(0.005)
+                        .range(0.0..=2.0),
+                );
+                ui.end_row();
+
+                ui.label("Frontal area (m²):");
+                ui.add(
+                    egui::DragValue::new(&mut config.frontal_area_m2)
+                        .speed(0.01)
+                        .range(0.0..=10.0),
+                );
+                ui.end_row();
+
+                ui.label("Rolling resistance:");
+                ui.add(
+                    egui::DragValue::new(&mut config.rolling_resistance)
+                        .speed(0.001)
+                        .range(0.0..=0.1),
+                );
+                ui.end_row();
+
+                ui.label("Tire diameter (m):");
+                ui.add(
+                    egui::DragValue::new(&mut config.tire_diameter_m)
+                        .speed(0.005)
+                        .range(0.2..=1.5),
+                );
+                ui.end_row();
+
+                if config.speed_source == SpeedSource::RpmAndGear {
+                    ui.label("Gear ratio:");
+                    ui.add(
+                        egui::DragValue::new(&mut config.gear_ratio)
+                            .speed(0.01)
+                            .range(0.1..=10.0),
+                    );
+                    ui.end_row();
+
+                    ui.label("Final drive:");
+                    ui.add(
+                        egui::DragValue::new(&mut config.final_drive)
+                            .speed(0.01)
+                            .range(0.1..=10.0),
+                    );
+                    ui.end_row();
+                }
+
+                ui.label("Smoothing (samples):");
+                ui.add(egui::DragValue::new(&mut config.smoothing_samples).range(1..=101));
+                ui.end_row();
+            });
+
+        ui.add_space(4.0);
+        ui.checkbox(&mut config.imperial_output, "Show hp / lb·ft");
+    }
+
+    /// Compute the curve for the selected pull
+    fn compute_dyno_curve(
+        &self,
+        tab_idx: usize,
+        file_index: usize,
+    ) -> Result<Vec<DynoPoint>, &'static str> {
+        let state = &self.tabs[tab_idx].dyno_state;
+        let config = &self.dyno_config;
+        let log = &self.files[file_index].log;
+
+        let (Some(start), Some(end)) = (state.pull_start, state.pull_end) else {
+            return Err("Select the start and end of a WOT pull");
+        };
+        let (start, end) = (start.min(end), start.max(end));
+
+        let first = log.times.partition_point(|&t| t < start);
+        let last = log.times.partition_point(|&t| t <= end);
+        if last.saturating_sub(first) < 3 {
+            return Err("Selected pull is too short");
+        }
+        let times = &log.times[first..last];
+        let column = |idx: usize| -> Vec<f64> {
+            log.get_channel_data(idx)
+                .get(first..last)
+                .map_or_else(|| vec![0.0; last - first], <[f64]>::to_vec)
+        };
+
+        let rpm: Option<Vec<f64>> = state.rpm_channel.map(column);
+
+        let speeds: Vec<f64> = match config.speed_source {
+            SpeedSource::VehicleSpeed => {
+                let idx = state
+                    .speed_channel
+                    .ok_or("Select a vehicle speed channel")?;
+                let unit = log.channels[idx].unit();
+                column(idx)
+                    .into_iter()
+                    .map(|v| speed_to_mps(v, unit))
+                    .collect()
+            }
+            SpeedSource::RpmAndGear => rpm
+                .as_ref()
+                .ok_or("Select an RPM channel")?
+                .iter()
+                .map(|&r| config.speed_from_rpm(r))
+                .collect(),
+        };
+
+        Ok(config.compute(times, &speeds, rpm.as_deref()))
+    }
+
+    /// Render the power/torque plot and peak summary
+    fn render_dyno_plot(&mut self, ui: &mut egui::Ui, tab_idx: usize, file_index: usize) {
+        let points = match self.compute_dyno_curve(tab_idx, file_index) {
+            Ok(points) if !points.is_empty() => points,
+            Ok(_) => {
+                Self::dyno_placeholder(ui, "Not enough data in the selected pull");
+                return;
+            }
+            Err(message) => {
+                Self::dyno_placeholder(ui, message);
+                return;
+            }
+        };
+
+        let imperial = self.dyno_config.imperial_output;
+        let (power_unit, torque_unit) = if imperial {
+            ("hp", "lb·ft")
+        } else {
+            ("kW", "Nm")
+        };
+        let power_of = |p: &DynoPoint| if imperial { p.power_hp() } else { p.power_kw() };
+        let torque_of = |p: &DynoPoint| {
+            if imperial {
+                p.torque_nm / NM_PER_LB_FT
+            } else {
+                p.torque_nm
+            }
+        };
+
+        // X axis: RPM when available, otherwise speed in display units
+        let use_rpm = points.iter().all(|p| p.rpm.is_some());
+        let speed_unit = self.active_unit_preferences().speed;
+        let x_of = |p: &DynoPoint| match p.rpm {
+            Some(rpm) if use_rpm => rpm,
+            _ => speed_unit.convert_from_kmh(p.speed_mps * 3.6),
+        };
+        let x_label = if use_rpm {
+            "RPM".to_string()
+        } else {
+            format!("Speed ({})", speed_unit.symbol())
+        };
+
+        let peak_power = points
+            .iter()
+            .copied()
+            .max_by(|a, b| a.power_w.total_cmp(&b.power_w));
+        let peak_torque = points
+            .iter()
+            .copied()
+            .max_by(|a, b| a.torque_nm.total_cmp(&b.torque_nm));
+
+        ui.horizontal(|ui| {
+            if let Some(p) = peak_power {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Peak Power: {:.1} {} @ {:.0}",
+                        power_of(&p),
+                        power_unit,
+                        x_of(&p)
+                    ))
+                    .size(16.0)
+                    .color(POWER_COLOR),
+                );
+            }
+            ui.add_space(20.0);
+            if let Some(p) = peak_torque {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Peak Torque: {:.1} {} @ {:.0}",
+                        torque_of(&p),
+                        torque_unit,
+                        x_of(&p)
+                    ))
+                    .size(16.0)
+                    .color(TORQUE_COLOR),
+                );
+            }
+        });
+        ui.label(
+            egui::RichText::new(format!(
+                "Estimated wheel power. 1 hp = {:.1} W.",
+                WATTS_PER_HP
+            ))
+            .color(egui::Color32::GRAY),
+        );
+        ui.add_space(4.0);
+
+        let power: Vec<[f64; 2]> = points.iter().map(|p| [x_of(p), power_of(p)]).collect();
+        let torque: Vec<[f64; 2]> = points.iter().map(|p| [x_of(p), torque_of(p)]).collect();
+
+        Plot::new("dyno_plot")
+            .legend(Legend::default())
+            .x_axis_label(x_label)
+            .allow_boxed_zoom(false)
+            .show(ui, |plot_ui| {
+                plot_ui.line(
+                    Line::new(format!("Power ({})", power_unit), PlotPoints::from(power))
+                        .color(POWER_COLOR)
+                        .width(2.0),
+                );
+                plot_ui.line(
+                    Line::new(
+                        format!("Torque ({})", torque_unit),
+                        PlotPoints::from(torque),
+                    )
+                    .color(TORQUE_COLOR)
+                    .width(2.0),
+                );
+            });
+    }
+
+    /// Show a centered hint in place of the dyno plot
+    fn dyno_placeholder(ui: &mut egui::Ui, message: &str) {
+        ui.centered_and_justified(|ui| {
+            ui.label(
+                egui::RichText::new(message)
+                    .size(18.0)
+                    .color(egui::Color32::GRAY),
+            );
+        });
+    }
+}
@@ -0,0 +1,288 @@
+//! EGT spread window.
+//!
+//! For logs with an EGT probe per cylinder (or bank), lists the periods where
+//! the spread between the hottest and coldest probe stays above a threshold,
+//! with the hottest and coldest probe at the worst point of each. The spread
+//! itself is the derived "EGT Spread" channel, which can be plotted from here.
+
+use eframe::egui;
+
+use crate::alarms::Violation;
+use crate::app::UltraLogApp;
+use crate::egt::{find_egt_channels, SPREAD_CHANNEL};
+use crate::state::{ActiveTool, EgtSpreadScan};
+
+/// An imbalance ready to display
+struct ImbalanceRow {
+    period: Violation,
+    /// Hottest probe and its value at the worst point, e.g. `EGT 3: 905 °C`
+    hottest: String,
+    /// Coldest probe and its value at the worst point
+    coldest: String,
+}
+
+impl UltraLogApp {
+    /// EGT imbalances in the active file, found again when the file,
+    /// thresholds or display units have changed
+    fn current_egt_spread_scan(&mut self) -> Option<&EgtSpreadScan> {
+        let file_index = self
+            .active_tab
+            .map(|idx| self.tabs[idx].file_index)
+            .filter(|&idx| idx < self.files.len())?;
+
+        let stale = self.egt_spread_scan.as_ref().is_none_or(|scan| {
+            scan.file_index != file_index
+                || scan.config != self.egt_spread_config
+                || scan.units != *self.active_unit_preferences()
+        });
+        if stale {
+            let probes = find_egt_channels(&self.files[file_index].log);
+            let periods = if probes.len() < 2 {
+                Err("This log needs at least two EGT channels in the same unit".to_string())
+            } else {
+                self.channel_display_values(file_index, SPREAD_CHANNEL)
+                    .ok_or_else(|| format!("No {} channel in this log", SPREAD_CHANNEL))
+                    .map(|spread| {
+                        let times = self.files[file_index].log.get_times_as_f64();
+                        self.egt_spread_config.imbalances(times, &spread)
+                    })
+            };
+            self.egt_spread_scan = Some(EgtSpreadScan {
+                file_index,
+                config: self.egt_spread_config.clone(),
+                units: self.active_unit_preferences().clone(),
+                probes,
+                periods,
+            });
+        }
+        self.egt_spread_scan.as_ref()
+    }
+
+    /// Hottest and coldest probe at the worst record of an imbalance
+    fn imbalance_row(&self, scan: &EgtSpreadScan, period: &Violation) -> ImbalanceRow {
+        let file_index = scan.file_index;
+        let log = &self.files[file_index].log;
+        let worst = log
+            .channels
+            .iter()
+            .position(|c| c.name() == SPREAD_CHANNEL)
+            .and_then(|spread| {
+                log.get_channel_data(spread)
+                    .get(period.start_record..=period.end_record)
+            })
+            .and_then(|values| {
+                values
+                    .iter()
+                    .enumerate()
+                    .max_by(|a, b| a.1.total_cmp(b.1))
+                    .map(|(i, _)| period.start_record + i)
+            })
+            .unwrap_or(period.start_record);
+
+        let readings: Vec<(usize, f64)> = scan
+            .probes
+            .iter()
+            .filter_map(|&probe| {
+                log.value_at(probe, worst)
+                    .filter(|v| v.is_finite())
+                    .map(|v| (probe, v))
+            })
+            .collect();
+        let describe = |reading: Option<&(usize, f64)>| {
+            reading.map_or_else(
+                || "-".to_string(),
+                |&(probe, value)| {
+                    let (converted, unit) =
+                        self.convert_channel_value(file_index, probe, worst, value);
+                    format!("{}: {:.0} {}", log.channels[probe].name(), converted, unit)
+                },
+            )
+        };
+        ImbalanceRow {
+            period: period.clone(),
+            hottest: describe(readings.iter().max_by(|a, b| a.1.total_cmp(&b.1))),
+            coldest: describe(readings.iter().min_by(|a, b| a.1.total_cmp(&b.1))),
+        }
+    }
+
+    /// Render the EGT spread window
+    pub fn render_egt_spread_window(&mut self, ctx: &egui::Context) {
+        if !self.show_egt_spread_window {
+            return;
+        }
+
+        let scan = self.current_egt_spread_scan().cloned();
+        let rows: Vec<ImbalanceRow> = match &scan {
+            Some(
+                scan @ EgtSpreadScan {
+                    periods: Ok(periods),
+                    ..
+                },
+            ) => periods
+                .iter()
+                .map(|period| self.imbalance_row(scan, period))
+                .collect(),
+            _ => Vec::new(),
+        };
+        let probe_names = scan
+            .as_ref()
+            .map(|scan| {
+                scan.probes
+                    .iter()
+                    .map(|&probe| self.files[scan.file_index].log.channels[probe].name())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+        let unit = self
+            .active_unit_preferences()
+            .temperature
+            .difference_symbol();
+
+        let mut config = self.egt_spread_config.clone();
+        let mut open = true;
+        let mut plot = false;
+        let mut zoom: Option<usize> = None;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("EGT Spread"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(640.0)
+            .default_height(360.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("Flag spread above"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.threshold)
+                            .range(1.0..=1000.0)
+                            .speed(1.0)
+                            .suffix(format!(" {}", unit)),
+                    );
+                    ui.label(lang.tr("lasting at least"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.min_duration)
+                            .range(0.0..=60.0)
+                            .speed(0.05)
+                            .suffix(" s"),
+                    );
+                });
+                ui.add_space(4.0);
+                ui.separator();
+
+                let Some(scan) = &scan else {
+                    ui.label(
+                        egui::RichText::new(lang.tr("Open a log file to check its EGT spread."))
+                            .color(egui::Color32::GRAY),
+                    );
+                    return;
+                };
+                if let Err(e) = &scan.periods {
+                    ui.colored_label(egui::Color32::from_rgb(253, 193, 73), format!("⚠ {}", e));
+                    return;
+                }
+
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} {}",
+                            lang.tr("Spread between the hottest and coldest of:"),
+                            probe_names
+                        ))
+                        .color(egui::Color32::GRAY),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui
+                            .button(lang.tr("📈 Plot Spread"))
+                            .on_hover_text(lang.tr("Add the EGT Spread channel to the chart"))
+                            .clicked()
+                        {
+                            plot = true;
+                        }
+                    });
+                });
+                ui.add_space(4.0);
+
+                if rows.is_empty() {
+                    ui.label(
+                        egui::RichText::new(lang.tr("No imbalances found."))
+                            .color(egui::Color32::GRAY),
+                    );
+                    return;
+                }
+
+                egui::ScrollArea::vertical()
+                    .id_salt("egt_spread_scroll")
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        egui::Grid::new("egt_spread_grid")
+                            .striped(true)
+                            .spacing([16.0, 6.0])
+                            .show(ui, |ui| {
+                                for header in [
+                                    "#",
+                                    lang.tr("Start"),
+                                    lang.tr("Duration"),
+                                    lang.tr("Peak Spread"),
+                                    lang.tr("Hottest"),
+                                    lang.tr("Coldest"),
+                                ] {
+                                    ui.label(egui::RichText::new(header).strong());
+                                }
+                                ui.label("");
+                                ui.end_row();
+
+                                for (i, row) in rows.iter().enumerate() {
+                                    ui.label(format!("{}", i + 1));
+                                    ui.label(Self::format_time(row.period.start_time));
+                                    ui.label(format!("{:.1}s", row.period.duration()));
+                                    ui.label(format!("{:.0} {}", row.period.peak_value, unit));
+                                    ui.label(&row.hottest);
+                                    ui.label(&row.coldest);
+                                    if ui
+                                        .small_button(lang.tr("Zoom"))
+                                        .on_hover_text(
+                                            lang.tr("Show this imbalance in the Log Viewer"),
+                                        )
+                                        .clicked()
+                                    {
+                                        zoom = Some(i);
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            });
+
+        if config != self.egt_spread_config {
+            self.egt_spread_config = config;
+        }
+
+        if plot {
+            if let Some(scan) = &scan {
+                if let Some(channel) = self.files[scan.file_index]
+                    .log
+                    .channels
+                    .iter()
+                    .position(|c| c.name() == SPREAD_CHANNEL)
+                {
+                    self.active_tool = ActiveTool::LogViewer;
+                    self.add_channel(scan.file_index, channel);
+                }
+            }
+        }
+
+        if let Some(row) = zoom.map(|i| &rows[i]) {
+            let period = &row.period;
+            self.active_tool = ActiveTool::LogViewer;
+            self.set_zoom_to_range(Some((period.start_time, period.end_time)));
+            self.set_cursor_time(Some(period.start_time));
+            self.set_cursor_record(Some(period.start_record));
+        }
+
+        if !open {
+            self.show_egt_spread_window = false;
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Load error dialog.
+//!
+//! Shows why a file failed to load: the full error, where in the file the
+//! parser gave up and the detected format, with a button that copies the
+//! details for a bug report.
+
+use std::path::Path;
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::state::LoadError;
+
+impl UltraLogApp {
+    /// Render the dialog for the oldest failed load, if any
+    pub fn render_error_dialog(&mut self, ctx: &egui::Context) {
+        let Some((path, error)) = self.load_errors.first() else {
+            return;
+        };
+
+        let lang = self.language;
+        let file_name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+        let mut rows: Vec<(&str, String)> = vec![(lang.tr("File"), file_name.clone())];
+        if let Some(format) = error.format {
+            rows.push((lang.tr("Format"), format.name().to_string()));
+        }
+        if let Some(location) = error.location {
+            rows.push((lang.tr("Location"), location.to_string()));
+        }
+        let remaining = self.load_errors.len() - 1;
+
+        let mut open = true;
+        let mut dismiss = false;
+        let mut copy = false;
+
+        egui::Window::new(lang.tr("Failed to Load File"))
+            .id(egui::Id::new("load_error_dialog"))
+            .open(&mut open)
+            .resizable(true)
+            .collapsible(false)
+            .default_width(460.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                egui::Grid::new("load_error_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        for (label, value) in &rows {
+                            ui.label(egui::RichText::new(*label).color(egui::Color32::GRAY));
+                            ui.label(value);
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(8.0);
+                egui::ScrollArea::vertical()
+                    .max_height(220.0)
+                    .show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(&error.message)
+                                .color(egui::Color32::from_rgb(191, 78, 48)),
+                        );
+                    });
+
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .button(lang.tr("Copy Diagnostics"))
+                        .on_hover_text(lang.tr("Copy the details for pasting into a GitHub issue"))
+                        .clicked()
+                    {
+                        copy = true;
+                    }
+                    let close_label = if remaining > 0 {
+                        format!("{} ({} {})", lang.tr("Next"), remaining, lang.tr("more"))
+                    } else {
+                        lang.tr("Close").to_string()
+                    };
+                    if ui.button(close_label).clicked() {
+                        dismiss = true;
+                    }
+                });
+            });
+
+        if copy {
+            ctx.copy_text(diagnostics(path, error));
+            self.show_toast_success(lang.tr("Diagnostics copied to clipboard"));
+        }
+        if dismiss || !open {
+            self.load_errors.remove(0);
+        }
+    }
+}
+
+/// Plain-text description of a failed load for a bug report
+fn diagnostics(path: &Path, error: &LoadError) -> String {
+    let mut text = format!(
+        "UltraLog {} ({} {})\n",
+        env!("CARGO_PKG_VERSION"),
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "Unknown".to_string());
+    match std::fs::metadata(path) {
+        Ok(metadata) => text.push_str(&format!("File: {} ({} bytes)\n", name, metadata.len())),
+        Err(_) => text.push_str(&format!("File: {}\n", name)),
+    }
+    text.push_str(&format!(
+        "Format: {}\n",
+        error.format.map_or("Not detected", |f| f.name())
+    ));
+    if let Some(location) = error.location {
+        text.push_str(&format!("Location: {}\n", location));
+    }
+    text.push_str(&format!("Error: {}\n", error.message));
+    text
+}
@@ -1,4 +1,5 @@
-//! Chart export functionality (PNG, PDF).
+//! Chart export functionality (PNG, PDF, interactive HTML) and log data
+//! export (CSV, Arrow).
 
 use printpdf::*;
 use std::fs::File;
@@ -8,7 +9,12 @@ use std::io::BufWriter;
 use ::image::{Rgba, RgbaImage};
 
 use crate::app::UltraLogApp;
+use crate::arrow_ipc::write_arrow;
+use crate::chart_html::{chart_html, HtmlSeries, MAX_POINTS_PER_SERIES};
+use crate::convert::write_csv;
+use crate::downsample::lttb;
 use crate::normalize::normalize_channel_name_with_custom;
+use crate::state::{ChannelStyle, LineStyle};
 
 impl UltraLogApp {
     /// Export the current chart view as PNG
@@ -46,6 +52,210 @@ impl UltraLogApp {
         }
     }
 
+    /// Export the selected channels over the visible time window as a
+    /// standalone interactive HTML page
+    pub fn export_chart_html(&mut self) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("HTML Page", &["html"])
+            .set_file_name("ultralog_chart.html")
+            .save_file()
+        else {
+            return;
+        };
+
+        match self.render_chart_to_html() {
+            Ok(html) => match std::fs::write(&path, html) {
+                Ok(()) => self.show_toast_success("Chart exported as HTML"),
+                Err(e) => self.show_toast_error(&format!("Export failed: {}", e)),
+            },
+            Err(e) => self.show_toast_error(&format!("Export failed: {}", e)),
+        }
+    }
+
+    /// Export every channel of a loaded log as an Arrow IPC (Feather) file
+    pub fn export_log_arrow(&mut self, file_index: usize) {
+        let Some(path) =
+            self.ask_log_export_path(file_index, "Arrow IPC / Feather", &["arrow", "feather"])
+        else {
+            return;
+        };
+
+        let file = &self.files[file_index];
+        let names = self.export_channel_names(file_index);
+        let result = File::create(&path)
+            .map(BufWriter::new)
+            .and_then(|mut out| write_arrow(&file.log, &names, &mut out));
+        match result {
+            Ok(()) => self.show_toast_success("Log exported as Arrow"),
+            Err(e) => self.show_toast_error(&format!("Export failed: {}", e)),
+        }
+    }
+
+    /// Export every channel of a loaded log as CSV, in the same layout as
+    /// `ultralog convert`
+    pub fn export_log_csv(&mut self, file_index: usize) {
+        let Some(path) = self.ask_log_export_path(file_index, "CSV", &["csv"]) else {
+            return;
+        };
+
+        let file = &self.files[file_index];
+        let names = self.export_channel_names(file_index);
+        let result = File::create(&path)
+            .map(BufWriter::new)
+            .and_then(|mut out| write_csv(&file.log, &names, &mut out));
+        match result {
+            Ok(()) => self.show_toast_success("Log exported as CSV"),
+            Err(e) => self.show_toast_error(&format!("Export failed: {}", e)),
+        }
+    }
+
+    /// Export the records of the active file within the trim range (the A/B
+    /// loop region, or the visible chart) as CSV, with times starting at zero
+    pub fn export_selection_csv(&mut self) {
+        let (Some(file_index), Some((start, end))) = (
+            self.active_tab.map(|idx| self.tabs[idx].file_index),
+            self.trim_range(),
+        ) else {
+            return;
+        };
+        let stem = self.files[file_index].path.file_stem().map_or_else(
+            || "ultralog_log".to_string(),
+            |s| s.to_string_lossy().to_string(),
+        );
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("CSV", &["csv"])
+            .set_file_name(format!("{}_trimmed.csv", stem))
+            .save_file()
+        else {
+            return;
+        };
+
+        let log = self.files[file_index].log.slice(start, end);
+        let names = self.export_channel_names(file_index);
+        let result = File::create(&path)
+            .map(BufWriter::new)
+            .and_then(|mut out| write_csv(&log, &names, &mut out));
+        match result {
+            Ok(()) => {
+                self.show_toast_success(&format!("Exported {} records as CSV", log.record_count()))
+            }
+            Err(e) => self.show_toast_error(&format!("Export failed: {}", e)),
+        }
+    }
+
+    /// Ask where to save an export of a loaded log, suggesting the log's
+    /// own file name with the first extension
+    fn ask_log_export_path(
+        &self,
+        file_index: usize,
+        filter: &str,
+        extensions: &[&str],
+    ) -> Option<std::path::PathBuf> {
+        let stem = self.files.get(file_index)?.path.file_stem().map_or_else(
+            || "ultralog_log".to_string(),
+            |s| s.to_string_lossy().to_string(),
+        );
+        rfd::FileDialog::new()
+            .add_filter(filter, extensions)
+            .set_file_name(format!("{}.{}", stem, extensions[0]))
+            .save_file()
+    }
+
+    /// Output column names for a log's channels, normalized when field
+    /// normalization is on
+    fn export_channel_names(&self, file_index: usize) -> Vec<String> {
+        self.files[file_index]
+            .log
+            .channels
+            .iter()
+            .map(|c| {
+                if self.field_normalization {
+                    normalize_channel_name_with_custom(&c.name(), Some(&self.custom_normalizations))
+                } else {
+                    c.name()
+                }
+            })
+            .collect()
+    }
+
+    /// Build the interactive HTML page of the selected channels over the
+    /// chart's visible time window, in display units
+    fn render_chart_to_html(&self) -> Result<String, String> {
+        let (min_time, max_time) = self
+            .chart_view_range
+            .or(self.time_range)
+            .ok_or("No time range available")?;
+
+        let series: Vec<HtmlSeries> = self
+            .get_selected_channels()
+            .iter()
+            .filter(|selected| selected.file_index < self.files.len())
+            .map(|selected| {
+                let file_index = selected.file_index;
+                let channel_index = selected.channel_index;
+                let log = &self.files[file_index].log;
+                let times = log.get_times_as_f64();
+                let first = times.partition_point(|&t| t < min_time);
+                let last = times.partition_point(|&t| t <= max_time);
+                let data = log.get_channel_data(channel_index);
+                let points: Vec<[f64; 2]> = if first < last.min(data.len()) {
+                    let last = last.min(data.len());
+                    lttb(
+                        &times[first..last],
+                        &data[first..last],
+                        MAX_POINTS_PER_SERIES,
+                    )
+                } else {
+                    Vec::new()
+                };
+
+                let mut unit = String::new();
+                let points = points
+                    .into_iter()
+                    .map(|[time, value]| {
+                        let record = times.partition_point(|&t| t < time);
+                        let (converted, display_unit) =
+                            self.convert_channel_value(file_index, channel_index, record, value);
+                        unit = display_unit;
+                        [time, converted]
+                    })
+                    .collect();
+
+                let channel_name = selected.channel.name();
+                let name = if self.field_normalization {
+                    normalize_channel_name_with_custom(
+                        &channel_name,
+                        Some(&self.custom_normalizations),
+                    )
+                } else {
+                    channel_name
+                };
+                HtmlSeries {
+                    name,
+                    unit,
+                    color: self.selected_channel_color(selected),
+                    points,
+                }
+            })
+            .collect();
+        if series.is_empty() {
+            return Err("No channels selected".to_string());
+        }
+
+        let title = self
+            .active_tab
+            .and_then(|idx| self.files.get(self.tabs[idx].file_index))
+            .map_or_else(|| "UltraLog Chart".to_string(), |file| file.name.clone());
+        let subtitle = format!(
+            "{} to {} | {} channel{}",
+            Self::format_time(min_time),
+            Self::format_time(max_time),
+            series.len(),
+            if series.len() == 1 { "" } else { "s" }
+        );
+        Ok(chart_html(&title, &subtitle, &series))
+    }
+
     /// Render chart data to PNG file
     fn render_chart_to_png(
         &self,
@@ -89,8 +299,7 @@ impl UltraLogApp {
 
         // Draw each channel
         for selected in self.get_selected_channels() {
-            let color = self.get_channel_color(selected.color_index);
-            let pixel_color = Rgba([color[0], color[1], color[2], 255]);
+            let mut pen = Pen::new(self.selected_channel_color(selected), &selected.style);
 
             // Get channel data
             if selected.file_index >= self.files.len() {
@@ -107,7 +316,7 @@ impl UltraLogApp {
             // Find min/max for normalization
             let mut data_min = f64::MAX;
             let mut data_max = f64::MIN;
-            for &val in &data {
+            for &val in data {
                 data_min = data_min.min(val);
                 data_max = data_max.max(val);
             }
@@ -136,7 +345,7 @@ impl UltraLogApp {
 
                 // Draw line from previous point
                 if let (Some(px), Some(py)) = (prev_x, prev_y) {
-                    draw_line(&mut imgbuf, px, py, x, y, pixel_color);
+                    draw_line(&mut imgbuf, px, py, x, y, &mut pen);
                 }
 
                 prev_x = Some(x);
@@ -234,7 +443,7 @@ impl UltraLogApp {
 
         // Draw each channel
         for selected in self.get_selected_channels() {
-            let color_rgb = self.get_channel_color(selected.color_index);
+            let color_rgb = self.selected_channel_color(selected);
             let line_color = Color::Rgb(Rgb::new(
                 color_rgb[0] as f32 / 255.0,
                 color_rgb[1] as f32 / 255.0,
@@ -242,8 +451,19 @@ impl UltraLogApp {
                 None,
             ));
 
+            // Chart line widths are in screen points; half that suits the page
             current_layer.set_outline_color(line_color);
-            current_layer.set_outline_thickness(0.75);
+            current_layer.set_outline_thickness(selected.style.line_width * 0.5);
+            let (dash, gap) = match selected.style.line_style {
+                LineStyle::Solid => (None, None),
+                LineStyle::Dashed => (Some(4), Some(2)),
+                LineStyle::Dotted => (Some(1), Some(2)),
+            };
+            current_layer.set_line_dash_pattern(LineDashPattern {
+                dash_1: dash,
+                gap_1: gap,
+                ..Default::default()
+            });
 
             // Get channel data
             if selected.file_index >= self.files.len() {
@@ -260,7 +480,7 @@ impl UltraLogApp {
             // Find min/max for normalization
             let mut data_min = f64::MAX;
             let mut data_max = f64::MIN;
-            for &val in &data {
+            for &val in data {
                 data_min = data_min.min(val);
                 data_max = data_max.max(val);
             }
@@ -307,7 +527,7 @@ impl UltraLogApp {
         let mut legend_x = chart_left;
 
         for selected in self.get_selected_channels() {
-            let color_rgb = self.get_channel_color(selected.color_index);
+            let color_rgb = self.selected_channel_color(selected);
             let text_color = Color::Rgb(Rgb::new(
                 color_rgb[0] as f32 / 255.0,
                 color_rgb[1] as f32 / 255.0,
@@ -323,6 +543,16 @@ impl UltraLogApp {
                 channel_name
             };
 
+            // Show the display unit so converted channels (e.g. AFR) are labelled
+            let (_, unit) = self
+                .active_unit_preferences()
+                .convert_value(0.0, selected.channel.unit());
+            let display_name = if unit.is_empty() {
+                display_name
+            } else {
+                format!("{} ({})", display_name, unit)
+            };
+
             current_layer.set_fill_color(text_color);
             current_layer.use_text(
                 &display_name,
@@ -347,8 +577,57 @@ impl UltraLogApp {
     }
 }
 
+/// Line drawing state for one channel in the PNG export: its color, width
+/// and dash pattern, which continues from one segment to the next
+struct Pen {
+    color: Rgba<u8>,
+    width: i32,
+    /// Pixels drawn and skipped in each dash cycle (None for solid lines)
+    dash: Option<(u32, u32)>,
+    /// Pixels along the line so far
+    step: u32,
+}
+
+impl Pen {
+    fn new(color: [u8; 3], style: &ChannelStyle) -> Self {
+        let width = style.line_width.round().max(1.0) as i32;
+        let dash = match style.line_style {
+            LineStyle::Solid => None,
+            LineStyle::Dashed => Some((8 * width as u32, 5 * width as u32)),
+            LineStyle::Dotted => Some((width as u32, 3 * width as u32)),
+        };
+        Self {
+            color: Rgba([color[0], color[1], color[2], 255]),
+            width,
+            dash,
+            step: 0,
+        }
+    }
+
+    /// Plot one point of the line, skipping the gaps of dashed styles
+    fn plot(&mut self, img: &mut RgbaImage, x: i32, y: i32) {
+        let step = self.step;
+        self.step += 1;
+        if let Some((on, off)) = self.dash {
+            if step % (on + off) >= on {
+                return;
+            }
+        }
+
+        let (width, height) = img.dimensions();
+        let offset = (self.width - 1) / 2;
+        for py in y - offset..y - offset + self.width {
+            for px in x - offset..x - offset + self.width {
+                if px >= 0 && px < width as i32 && py >= 0 && py < height as i32 {
+                    img.put_pixel(px as u32, py as u32, self.color);
+                }
+            }
+        }
+    }
+}
+
 /// Draw a line between two points using Bresenham's algorithm
-fn draw_line(img: &mut RgbaImage, x0: u32, y0: u32, x1: u32, y1: u32, color: Rgba<u8>) {
+fn draw_line(img: &mut RgbaImage, x0: u32, y0: u32, x1: u32, y1: u32, pen: &mut Pen) {
     let dx = (x1 as i32 - x0 as i32).abs();
     let dy = -(y1 as i32 - y0 as i32).abs();
     let sx: i32 = if x0 < x1 { 1 } else { -1 };
@@ -358,12 +637,8 @@ fn draw_line(img: &mut RgbaImage, x0: u32, y0: u32, x1: u32, y1: u32, color: Rgb
     let mut x = x0 as i32;
     let mut y = y0 as i32;
 
-    let (width, height) = img.dimensions();
-
     loop {
-        if x >= 0 && x < width as i32 && y >= 0 && y < height as i32 {
-            img.put_pixel(x as u32, y as u32, color);
-        }
+        pen.plot(img, x, y);
 
         if x == x1 as i32 && y == y1 as i32 {
             break;
@@ -0,0 +1,94 @@
+//! File info window.
+//!
+//! Shows a loaded file's detected format, header metadata and record
+//! statistics. Opened from the sidebar file entry's context menu.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+
+impl UltraLogApp {
+    /// Render the file info window for `file_info_index`
+    pub fn render_file_info_window(&mut self, ctx: &egui::Context) {
+        let Some(file) = self.file_info_index.and_then(|idx| self.files.get(idx)) else {
+            self.file_info_index = None;
+            return;
+        };
+
+        let log = &file.log;
+        let mut rows: Vec<(&str, String)> = vec![
+            ("Format", file.ecu_type.name().to_string()),
+            ("Path", file.path.display().to_string()),
+        ];
+        if let Ok(metadata) = std::fs::metadata(&file.path) {
+            rows.push((
+                "Size",
+                format!("{:.1} MB", metadata.len() as f64 / (1024.0 * 1024.0)),
+            ));
+        }
+        rows.push(("Records", log.record_count().to_string()));
+        if log.skipped_records > 0 {
+            rows.push(("Skipped (damaged)", log.skipped_records.to_string()));
+        }
+        rows.push(("Duration", Self::format_time(log.duration())));
+        if let Some(rate) = log.record_rate() {
+            rows.push(("Record Rate", format!("{:.1} Hz", rate)));
+        }
+        let derived = log.channels.iter().filter(|c| c.is_derived()).count();
+        rows.push((
+            "Channels",
+            if derived > 0 {
+                format!("{} ({} derived)", log.channels.len(), derived)
+            } else {
+                log.channels.len().to_string()
+            },
+        ));
+        let details = log.meta.details();
+
+        let mut open = true;
+        egui::Window::new(format!("File Info - {}", file.name))
+            .id(egui::Id::new("file_info_window"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(420.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                egui::Grid::new("file_info_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (label, value) in &rows {
+                            ui.label(egui::RichText::new(*label).color(egui::Color32::GRAY));
+                            ui.label(value);
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(8.0);
+                ui.label(egui::RichText::new("Header").strong());
+                if details.is_empty() {
+                    ui.label(
+                        egui::RichText::new("This format has no header metadata.")
+                            .color(egui::Color32::GRAY),
+                    );
+                    return;
+                }
+                egui::Grid::new("file_info_header_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (label, value) in &details {
+                            ui.label(egui::RichText::new(*label).color(egui::Color32::GRAY));
+                            ui.label(value);
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        if !open {
+            self.file_info_index = None;
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Find value window.
+//!
+//! Finds where a channel meets a condition such as "RPM > 7000" in the
+//! active log, and steps the cursor and chart through each stretch of
+//! matching records with Previous/Next (or Shift+F3/F3).
+
+use eframe::egui;
+
+use crate::alarms::{adjacent_violation, AlarmRule};
+use crate::app::UltraLogApp;
+use crate::state::{ActiveTool, FindMatches};
+
+impl UltraLogApp {
+    /// Matches for the find condition in the active file, searching again
+    /// when the condition, file or display units have changed
+    fn current_find_matches(&mut self) -> Option<&FindMatches> {
+        let file_index = self.active_tab.map(|idx| self.tabs[idx].file_index)?;
+        if file_index >= self.files.len() || self.find_condition.channel.trim().is_empty() {
+            return None;
+        }
+
+        let stale = self.find_matches.as_ref().is_none_or(|found| {
+            found.file_index != file_index
+                || found.condition != self.find_condition
+                || found.units != *self.active_unit_preferences()
+        });
+        if stale {
+            // A single-condition alarm rule finds each run of matching records
+            let rule = AlarmRule {
+                condition: self.find_condition.clone(),
+                ..Default::default()
+            };
+            let times = self.files[file_index].log.get_times_as_f64();
+            let matches =
+                rule.evaluate(times, |name| self.channel_display_values(file_index, name));
+            self.find_matches = Some(FindMatches {
+                file_index,
+                condition: self.find_condition.clone(),
+                units: self.active_unit_preferences().clone(),
+                matches,
+            });
+        }
+        self.find_matches.as_ref()
+    }
+
+    /// Move the cursor and chart to the next (or previous) match after the
+    /// cursor, wrapping around at the ends of the log
+    pub fn step_find_match(&mut self, forward: bool) {
+        let cursor = self.get_cursor_time();
+        let Some(Ok(matches)) = self.current_find_matches().map(|found| &found.matches) else {
+            return;
+        };
+        if let Some(time) =
+            adjacent_violation(matches, cursor, forward).map(|index| matches[index].start_time)
+        {
+            self.move_cursor_to(time);
+        }
+    }
+
+    /// Move the cursor and chart to a time, showing the Log Viewer unless
+    /// the data table is open
+    pub(crate) fn move_cursor_to(&mut self, time: f64) {
+        // The data table follows the cursor too, so it can stay open
+        if !matches!(
+            self.active_tool,
+            ActiveTool::LogViewer | ActiveTool::DataTable
+        ) {
+            self.active_tool = ActiveTool::LogViewer;
+        }
+        self.is_playing = false;
+        self.set_cursor_time(Some(time));
+        let record = self.find_record_at_time(time);
+        self.set_cursor_record(record);
+        self.set_jump_to_time(Some(time));
+    }
+
+    /// Render the find value window
+    pub fn render_find_value_window(&mut self, ctx: &egui::Context) {
+        if !self.show_find_value_window {
+            return;
+        }
+
+        let channel_names = self.condition_channel_names();
+        let has_file = self.active_tab.is_some();
+        let cursor = self.get_cursor_time();
+        // Match count and the match the cursor is in, or why the search failed
+        let status: Option<Result<(usize, Option<usize>), String>> =
+            self.current_find_matches().map(|found| {
+                found.matches.as_ref().map_err(Clone::clone).map(|matches| {
+                    let current = cursor.and_then(|time| {
+                        matches
+                            .iter()
+                            .position(|m| m.start_time <= time && time <= m.end_time)
+                    });
+                    (matches.len(), current)
+                })
+            });
+
+        let mut open = true;
+        let mut step: Option<bool> = None;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Find Value"))
+            .open(&mut open)
+            .resizable(false)
+            .collapsible(false)
+            .default_width(460.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                if !has_file {
+                    ui.label(
+                        egui::RichText::new(lang.tr("Open a log file to search it."))
+                            .color(egui::Color32::GRAY),
+                    );
+                    return;
+                }
+
+                ui.label(
+                    egui::RichText::new(lang.tr(
+                        "Find where a channel meets a condition. Values are in your selected \
+                         display units.",
+                    ))
+                    .color(egui::Color32::GRAY),
+                );
+                ui.add_space(4.0);
+
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("Find"));
+                    Self::render_condition_editor(
+                        ui,
+                        &mut self.find_condition,
+                        &channel_names,
+                        ("find_value", 0, 0),
+                        lang,
+                    );
+                });
+                ui.add_space(6.0);
+
+                let can_step = matches!(&status, Some(Ok((count, _))) if *count > 0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(can_step, egui::Button::new(lang.tr("◀ Previous")))
+                        .on_hover_text("Shift+F3")
+                        .clicked()
+                    {
+                        step = Some(false);
+                    }
+                    if ui
+                        .add_enabled(can_step, egui::Button::new(lang.tr("Next ▶")))
+                        .on_hover_text("F3")
+                        .clicked()
+                    {
+                        step = Some(true);
+                    }
+
+                    match &status {
+                        None => {}
+                        Some(Err(e)) => {
+                            ui.label(
+                                egui::RichText::new(e).color(egui::Color32::from_rgb(191, 78, 48)),
+                            );
+                        }
+                        Some(Ok((0, _))) => {
+                            ui.label(
+                                egui::RichText::new(lang.tr("No matches"))
+                                    .color(egui::Color32::GRAY),
+                            );
+                        }
+                        Some(Ok((count, Some(current)))) => {
+                            ui.label(format!(
+                                "{} {} {} {}",
+                                lang.tr("Match"),
+                                current + 1,
+                                lang.tr("of"),
+                                count
+                            ));
+                        }
+                        Some(Ok((count, None))) => {
+                            ui.label(format!(
+                                "{} {}",
+                                count,
+                                if *count == 1 {
+                                    lang.tr("match")
+                                } else {
+                                    lang.tr("matches")
+                                }
+                            ));
+                        }
+                    }
+                });
+            });
+
+        if let Some(forward) = step {
+            self.step_find_match(forward);
+        }
+
+        if !open {
+            self.show_find_value_window = false;
+        }
+    }
+}
@@ -0,0 +1,324 @@
+//! Fuel trims window.
+//!
+//! Shows the active log's closed-loop fuel correction averaged per RPM/load
+//! cell, in the same layout as a VE table, and lists the cells whose average
+//! correction is past the threshold so tuners know where to work.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::fuel_trim::{total_correction, TrimReport};
+use crate::i18n::Language;
+use crate::state::FuelTrimSummary;
+
+/// Cells where the ECU added fuel (too little VE)
+const LEAN_COLOR: egui::Color32 = egui::Color32::from_rgb(191, 78, 48);
+
+/// Cells where the ECU removed fuel (too much VE)
+const RICH_COLOR: egui::Color32 = egui::Color32::from_rgb(71, 108, 155);
+
+/// Trim channels summed into the total correction, by normalized name
+const TRIM_CHANNELS: [&str; 2] = ["Short Term Trim", "Long Term Trim"];
+
+/// Used when the log has no trim channels
+const EGO_CHANNEL: &str = "EGO Cor 1";
+
+impl UltraLogApp {
+    /// Fuel trim summary of the active file, rebuilt when the file, settings
+    /// or display units have changed
+    fn current_fuel_trim_summary(&mut self) -> Option<&FuelTrimSummary> {
+        let file_index = self
+            .active_tab
+            .map(|idx| self.tabs[idx].file_index)
+            .filter(|&idx| idx < self.files.len())?;
+
+        let stale = self.fuel_trim_summary.as_ref().is_none_or(|summary| {
+            summary.file_index != file_index
+                || summary.config != self.fuel_trim_config
+                || summary.units != *self.active_unit_preferences()
+        });
+        if stale {
+            let (source, report) = self.build_fuel_trim_report(file_index);
+            self.fuel_trim_summary = Some(FuelTrimSummary {
+                file_index,
+                config: self.fuel_trim_config.clone(),
+                units: self.active_unit_preferences().clone(),
+                source,
+                report,
+            });
+        }
+        self.fuel_trim_summary.as_ref()
+    }
+
+    /// Bin a file's fuel correction, naming the channels it came from
+    fn build_fuel_trim_report(&self, file_index: usize) -> (String, Result<TrimReport, String>) {
+        let mut names: Vec<&str> = Vec::new();
+        let mut trims: Vec<Vec<f64>> = Vec::new();
+        for name in TRIM_CHANNELS {
+            if let Some(values) = self.channel_display_values(file_index, name) {
+                names.push(name);
+                trims.push(values);
+            }
+        }
+        if trims.is_empty() {
+            if let Some(values) = self.channel_display_values(file_index, EGO_CHANNEL) {
+                names.push("EGO correction");
+                trims.push(values);
+            }
+        }
+        let source = names.join(" + ");
+
+        let report = (|| {
+            if trims.is_empty() {
+                return Err("No fuel trim or EGO correction channel in this log".to_string());
+            }
+            let rpm = self
+                .channel_display_values(file_index, "RPM")
+                .ok_or("No RPM channel in this log")?;
+            let load = self
+                .channel_display_values(file_index, "MAP")
+                .ok_or("No manifold pressure (MAP) channel in this log")?;
+            let trims: Vec<&[f64]> = trims.iter().map(Vec::as_slice).collect();
+            self.fuel_trim_config
+                .analyze(&rpm, &load, &total_correction(&trims))
+                .ok_or_else(|| "No RPM or MAP data in this log".to_string())
+        })();
+        (source, report)
+    }
+
+    /// Render the fuel trims window
+    pub fn render_fuel_trim_window(&mut self, ctx: &egui::Context) {
+        if !self.show_fuel_trim_window {
+            return;
+        }
+
+        let summary = self.current_fuel_trim_summary().cloned();
+        let load_unit = summary
+            .as_ref()
+            .and_then(|s| {
+                let channel = self.find_channel_by_name(s.file_index, "MAP")?;
+                let unit = self.files[s.file_index].log.channels[channel].unit();
+                Some(
+                    self.active_unit_preferences()
+                        .convert_value(0.0, unit)
+                        .1
+                        .to_string(),
+                )
+            })
+            .unwrap_or_default();
+
+        let mut config = self.fuel_trim_config.clone();
+        let mut open = true;
+        let mut copy_csv = false;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Fuel Trims"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(720.0)
+            .default_height(560.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("Flag cells averaging more than ±"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.threshold)
+                            .range(0.5..=50.0)
+                            .speed(0.1)
+                            .suffix(" %"),
+                    );
+                    ui.label(lang.tr("over at least"));
+                    ui.add(egui::DragValue::new(&mut config.min_hits).range(1..=10000));
+                    ui.label(lang.tr("samples"));
+                });
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("Cell size:"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.rpm_step)
+                            .range(50.0..=2000.0)
+                            .speed(10.0)
+                            .suffix(" RPM"),
+                    );
+                    ui.label("×");
+                    ui.add(
+                        egui::DragValue::new(&mut config.load_step)
+                            .range(0.5..=100.0)
+                            .speed(0.5)
+                            .suffix(format!(" {}", load_unit)),
+                    );
+                });
+                ui.add_space(4.0);
+                ui.separator();
+
+                let Some(summary) = &summary else {
+                    ui.label(
+                        egui::RichText::new(
+                            lang.tr("Open a log file to summarize its fuel trims."),
+                        )
+                        .color(egui::Color32::GRAY),
+                    );
+                    return;
+                };
+                let report = match &summary.report {
+                    Ok(report) => report,
+                    Err(e) => {
+                        ui.colored_label(egui::Color32::from_rgb(253, 193, 73), format!("⚠ {}", e));
+                        return;
+                    }
+                };
+
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} {}. {}",
+                            lang.tr("Correction from"),
+                            summary.source,
+                            lang.tr("Red cells: the ECU added fuel (VE too low). \
+                                 Blue cells: it removed fuel (VE too high).")
+                        ))
+                        .color(egui::Color32::GRAY),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui
+                            .button(lang.tr("📋 Copy as CSV"))
+                            .on_hover_text(lang.tr("Copy the average, min, max and hit tables"))
+                            .clicked()
+                        {
+                            copy_csv = true;
+                        }
+                    });
+                });
+                ui.add_space(4.0);
+
+                Self::render_trim_table(ui, report, &config, &load_unit, lang);
+
+                ui.add_space(8.0);
+                ui.separator();
+                ui.heading(lang.tr("Cells Needing Work"));
+                if report.flagged.is_empty() {
+                    ui.label(
+                        egui::RichText::new(lang.tr("No cells are past the threshold."))
+                            .color(egui::Color32::GRAY),
+                    );
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .id_salt("fuel_trim_flagged")
+                    .max_height(140.0)
+                    .show(ui, |ui| {
+                        for cell in &report.flagged {
+                            let (color, advice) = if cell.correction > 0.0 {
+                                (LEAN_COLOR, lang.tr("adding fuel, raise VE"))
+                            } else {
+                                (RICH_COLOR, lang.tr("removing fuel, lower VE"))
+                            };
+                            ui.horizontal(|ui| {
+                                ui.colored_label(color, format!("{:+.1}%", cell.correction));
+                                ui.label(format!(
+                                    "{} {:.0} RPM / {:.0} {} - {} ({} {})",
+                                    lang.tr("at"),
+                                    cell.rpm,
+                                    cell.load,
+                                    load_unit,
+                                    advice,
+                                    cell.hits,
+                                    lang.tr("samples")
+                                ));
+                            });
+                        }
+                    });
+            });
+
+        if copy_csv {
+            if let Some(Ok(report)) = summary.as_ref().map(|s| &s.report) {
+                let load_name = format!("MAP ({})", load_unit);
+                ctx.copy_text(
+                    report
+                        .grid
+                        .to_csv("RPM", &load_name, Some("Fuel Correction (%)")),
+                );
+                self.show_toast_success(lang.tr("Fuel trim tables copied to clipboard"));
+            }
+        }
+
+        if config != self.fuel_trim_config {
+            self.fuel_trim_config = config;
+        }
+
+        if !open {
+            self.show_fuel_trim_window = false;
+        }
+    }
+
+    /// Render the average correction per cell, highest load at the top
+    fn render_trim_table(
+        ui: &mut egui::Ui,
+        report: &TrimReport,
+        config: &crate::fuel_trim::FuelTrimConfig,
+        load_unit: &str,
+        lang: Language,
+    ) {
+        let grid = &report.grid;
+        egui::ScrollArea::both()
+            .id_salt("fuel_trim_table")
+            .max_height(300.0)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                egui::Grid::new("fuel_trim_grid")
+                    .spacing([2.0, 2.0])
+                    .show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(format!("{} \\ RPM", load_unit))
+                                .small()
+                                .strong(),
+                        );
+                        for rpm in grid.x.centers() {
+                            ui.label(egui::RichText::new(format!("{:.0}", rpm)).small().strong());
+                        }
+                        ui.end_row();
+
+                        for (yi, load) in grid.y.centers().iter().enumerate().rev() {
+                            ui.label(egui::RichText::new(format!("{:.0}", load)).small().strong());
+                            for xi in 0..grid.x.len() {
+                                let cell = grid.cell(xi, yi);
+                                let Some(mean) = cell.mean() else {
+                                    ui.label("");
+                                    continue;
+                                };
+                                let flagged =
+                                    cell.count >= config.min_hits && mean.abs() > config.threshold;
+                                let base = if mean > 0.0 { LEAN_COLOR } else { RICH_COLOR };
+                                // Shade by how close the cell is to the threshold
+                                let strength = if flagged {
+                                    1.0
+                                } else {
+                                    (mean.abs() / config.threshold).clamp(0.0, 1.0) as f32 * 0.4
+                                };
+                                egui::Frame::NONE
+                                    .fill(base.gamma_multiply(strength))
+                                    .inner_margin(egui::Margin::symmetric(4, 1))
+                                    .show(ui, |ui| {
+                                        let text = egui::RichText::new(format!("{:+.1}", mean))
+                                            .small()
+                                            .monospace();
+                                        let text = if cell.count < config.min_hits {
+                                            text.color(egui::Color32::GRAY)
+                                        } else {
+                                            text.color(egui::Color32::WHITE)
+                                        };
+                                        ui.label(text).on_hover_text(format!(
+                                            "{} {}, {:+.1} {} {:+.1}%",
+                                            cell.count,
+                                            lang.tr("samples"),
+                                            cell.min,
+                                            lang.tr("to"),
+                                            cell.max
+                                        ));
+                                    });
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
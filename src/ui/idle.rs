@@ -0,0 +1,370 @@
+//! Idle quality window.
+//!
+//! Summarizes how steadily the active log idles: RPM spread over each idle
+//! period, idle valve behavior, error from the idle target and any stalls.
+//! Channels are found by their normalized names (RPM, TPS, Idle Valve,
+//! Idle Target), so rename a channel in the normalization editor if it
+//! isn't picked up.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::i18n::Language;
+use crate::idle::{ChannelStats, IdleReport};
+use crate::state::{ActiveTool, IdleScan};
+
+/// RPM standard deviation at or below which idle counts as steady
+const STEADY_STD_DEV: f64 = 25.0;
+
+/// RPM standard deviation above which idle counts as hunting
+const HUNTING_STD_DEV: f64 = 50.0;
+
+/// What to move the cursor to once the window is drawn
+enum IdleAction {
+    /// Show an idle period in the Log Viewer
+    Zoom(usize),
+    /// Move the cursor to a stall
+    Jump(f64),
+}
+
+impl UltraLogApp {
+    /// Idle analysis of the active file, redone when the file or thresholds
+    /// have changed
+    fn current_idle_scan(&mut self) -> Option<&IdleScan> {
+        let file_index = self
+            .active_tab
+            .map(|idx| self.tabs[idx].file_index)
+            .filter(|&idx| idx < self.files.len())?;
+
+        let stale = self
+            .idle_scan
+            .as_ref()
+            .is_none_or(|scan| scan.file_index != file_index || scan.config != self.idle_config);
+        if stale {
+            let report = (|| {
+                let rpm = self
+                    .find_channel_by_name(file_index, "RPM")
+                    .ok_or("No RPM channel in this log")?;
+                let tps = self
+                    .find_channel_by_name(file_index, "TPS")
+                    .ok_or("No throttle position (TPS) channel in this log")?;
+                let valve = self.find_channel_by_name(file_index, "Idle Valve");
+                let target = self.find_channel_by_name(file_index, "Idle Target");
+                let log = &self.files[file_index].log;
+                Ok(self.idle_config.analyze(
+                    log.get_times_as_f64(),
+                    log.get_channel_data(rpm),
+                    log.get_channel_data(tps),
+                    valve.map(|channel| log.get_channel_data(channel)),
+                    target.map(|channel| log.get_channel_data(channel)),
+                ))
+            })();
+            self.idle_scan = Some(IdleScan {
+                file_index,
+                config: self.idle_config.clone(),
+                report,
+            });
+        }
+        self.idle_scan.as_ref()
+    }
+
+    /// Color for an RPM standard deviation: green when steady, amber when
+    /// wandering and rust when hunting
+    fn idle_stability_color(std_dev: f64) -> egui::Color32 {
+        if std_dev <= STEADY_STD_DEV {
+            egui::Color32::from_rgb(113, 180, 78)
+        } else if std_dev <= HUNTING_STD_DEV {
+            egui::Color32::from_rgb(253, 193, 73)
+        } else {
+            egui::Color32::from_rgb(191, 78, 48)
+        }
+    }
+
+    /// Render the idle quality window
+    pub fn render_idle_window(&mut self, ctx: &egui::Context) {
+        if !self.show_idle_window {
+            return;
+        }
+
+        let scan = self.current_idle_scan().cloned();
+        let valve_unit = scan
+            .as_ref()
+            .and_then(|scan| {
+                let channel = self.find_channel_by_name(scan.file_index, "Idle Valve")?;
+                Some(
+                    self.files[scan.file_index].log.channels[channel]
+                        .unit()
+                        .to_string(),
+                )
+            })
+            .unwrap_or_default();
+
+        let mut config = self.idle_config.clone();
+        let mut open = true;
+        let mut action: Option<IdleAction> = None;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Idle Quality"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(720.0)
+            .default_height(460.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("Idling is throttle below"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.max_tps)
+                            .range(0.0..=20.0)
+                            .speed(0.1)
+                            .suffix(" %"),
+                    );
+                    ui.label(lang.tr("and RPM below"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.max_rpm)
+                            .range(300.0..=4000.0)
+                            .speed(10.0),
+                    );
+                    ui.label(lang.tr("for"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.min_duration)
+                            .range(0.5..=120.0)
+                            .speed(0.1)
+                            .suffix(" s"),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("Stalled below"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.stall_rpm)
+                            .range(0.0..=1000.0)
+                            .speed(10.0)
+                            .suffix(" RPM"),
+                    );
+                });
+                ui.add_space(4.0);
+                ui.separator();
+
+                match &scan {
+                    None => {
+                        ui.label(
+                            egui::RichText::new(lang.tr("Open a log file to analyze its idle."))
+                                .color(egui::Color32::GRAY),
+                        );
+                    }
+                    Some(IdleScan { report: Err(e), .. }) => {
+                        ui.colored_label(egui::Color32::from_rgb(253, 193, 73), format!("⚠ {}", e));
+                    }
+                    Some(IdleScan {
+                        report: Ok(report), ..
+                    }) => Self::render_idle_report(ui, report, &valve_unit, &mut action, lang),
+                }
+            });
+
+        if config != self.idle_config {
+            self.idle_config = config;
+        }
+
+        match (action, &scan) {
+            (
+                Some(IdleAction::Zoom(i)),
+                Some(IdleScan {
+                    report: Ok(report), ..
+                }),
+            ) => {
+                let period = &report.periods[i];
+                self.active_tool = ActiveTool::LogViewer;
+                self.set_zoom_to_range(Some((period.start_time, period.end_time)));
+                self.set_cursor_time(Some(period.start_time));
+                self.set_cursor_record(Some(period.start_record));
+            }
+            (Some(IdleAction::Jump(time)), _) => self.move_cursor_to(time),
+            _ => {}
+        }
+
+        if !open {
+            self.show_idle_window = false;
+        }
+    }
+
+    /// Render the idle summary, the idle periods and the stalls
+    fn render_idle_report(
+        ui: &mut egui::Ui,
+        report: &IdleReport,
+        valve_unit: &str,
+        action: &mut Option<IdleAction>,
+        lang: Language,
+    ) {
+        let valve = |stats: &ChannelStats| {
+            format!(
+                "{:.1} {} ({:.1} {} {:.1})",
+                stats.mean,
+                valve_unit,
+                stats.min,
+                lang.tr("to"),
+                stats.max
+            )
+        };
+
+        egui::Grid::new("idle_summary")
+            .num_columns(2)
+            .spacing([16.0, 4.0])
+            .show(ui, |ui| {
+                ui.label(lang.tr("Time idling:"));
+                ui.label(format!(
+                    "{:.0}s {} {} {}",
+                    report.idle_time(),
+                    lang.tr("over"),
+                    report.periods.len(),
+                    if report.periods.len() == 1 {
+                        lang.tr("period")
+                    } else {
+                        lang.tr("periods")
+                    }
+                ));
+                ui.end_row();
+
+                if let Some(rpm) = &report.rpm {
+                    ui.label(lang.tr("Idle RPM:"));
+                    ui.horizontal(|ui| {
+                        ui.label(format!("{:.0} {}", rpm.mean, lang.tr("average,")));
+                        ui.colored_label(
+                            Self::idle_stability_color(rpm.std_dev),
+                            format!("±{:.0} {}", rpm.std_dev, lang.tr("standard deviation")),
+                        );
+                    });
+                    ui.end_row();
+                }
+
+                if let Some(stats) = &report.valve {
+                    ui.label(lang.tr("Idle valve:"));
+                    ui.label(valve(stats));
+                    ui.end_row();
+                }
+
+                ui.label(lang.tr("Stalls:"));
+                if report.stalls.is_empty() {
+                    ui.label(lang.tr("None"));
+                } else {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(191, 78, 48),
+                        format!("{}", report.stalls.len()),
+                    );
+                }
+                ui.end_row();
+            });
+        ui.label(
+            egui::RichText::new(format!(
+                "{} {:.0} RPM. {} {:.0} RPM. {}",
+                lang.tr("The idle is steady with a standard deviation up to"),
+                STEADY_STD_DEV,
+                lang.tr("It is hunting above"),
+                HUNTING_STD_DEV,
+                lang.tr("Stalls include switching the engine off.")
+            ))
+            .color(egui::Color32::GRAY),
+        );
+        ui.add_space(8.0);
+
+        ui.heading(lang.tr("Idle Periods"));
+        if report.periods.is_empty() {
+            ui.label(
+                egui::RichText::new(lang.tr("No idle periods found.")).color(egui::Color32::GRAY),
+            );
+        } else {
+            let has_target = report.periods.iter().any(|p| p.target_error.is_some());
+            let has_valve = report.valve.is_some();
+            egui::ScrollArea::both()
+                .id_salt("idle_periods_scroll")
+                .max_height(220.0)
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    egui::Grid::new("idle_periods_grid")
+                        .striped(true)
+                        .spacing([16.0, 6.0])
+                        .show(ui, |ui| {
+                            for header in [
+                                "#",
+                                lang.tr("Start"),
+                                lang.tr("Duration"),
+                                lang.tr("Mean RPM"),
+                                lang.tr("Std Dev"),
+                                lang.tr("Range"),
+                            ] {
+                                ui.label(egui::RichText::new(header).strong());
+                            }
+                            if has_target {
+                                ui.label(egui::RichText::new(lang.tr("Target Error")).strong());
+                            }
+                            if has_valve {
+                                ui.label(egui::RichText::new(lang.tr("Idle Valve")).strong());
+                            }
+                            ui.label("");
+                            ui.end_row();
+
+                            for (i, period) in report.periods.iter().enumerate() {
+                                ui.label(format!("{}", i + 1));
+                                ui.label(Self::format_time(period.start_time));
+                                ui.label(format!("{:.1}s", period.duration()));
+                                ui.label(format!("{:.0}", period.rpm.mean));
+                                ui.colored_label(
+                                    Self::idle_stability_color(period.rpm.std_dev),
+                                    format!("{:.0}", period.rpm.std_dev),
+                                );
+                                ui.label(format!(
+                                    "{:.0} {} {:.0}",
+                                    period.rpm.min,
+                                    lang.tr("to"),
+                                    period.rpm.max
+                                ));
+                                if has_target {
+                                    ui.label(
+                                        period
+                                            .target_error
+                                            .map_or("-".to_string(), |e| format!("{:+.0}", e)),
+                                    );
+                                }
+                                if has_valve {
+                                    ui.label(period.valve.as_ref().map_or("-".to_string(), valve));
+                                }
+                                if ui
+                                    .small_button(lang.tr("Zoom"))
+                                    .on_hover_text(lang.tr("Show this period in the Log Viewer"))
+                                    .clicked()
+                                {
+                                    *action = Some(IdleAction::Zoom(i));
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+        }
+
+        if !report.stalls.is_empty() {
+            ui.add_space(8.0);
+            ui.heading(lang.tr("Stalls"));
+            egui::ScrollArea::vertical()
+                .id_salt("idle_stalls_scroll")
+                .max_height(120.0)
+                .show(ui, |ui| {
+                    for stall in &report.stalls {
+                        ui.horizontal(|ui| {
+                            ui.label(format!(
+                                "{} {} {:.0} RPM",
+                                Self::format_time(stall.time),
+                                lang.tr("from"),
+                                stall.rpm_before
+                            ));
+                            if ui
+                                .small_button(lang.tr("Jump"))
+                                .on_hover_text(lang.tr("Move the cursor to this stall"))
+                                .clicked()
+                            {
+                                *action = Some(IdleAction::Jump(stall.time));
+                            }
+                        });
+                    }
+                });
+        }
+    }
+}
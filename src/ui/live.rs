@@ -0,0 +1,164 @@
+//! Live data connection window.
+//!
+//! Configures the serial or TCP connection to a Speeduino/rusEFI ECU and
+//! starts or stops streaming its output channels into a live tab.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::live::{LiveTransport, BAUD_RATES};
+
+/// Deferred action from the window buttons
+enum LiveAction {
+    Connect,
+    Disconnect,
+}
+
+impl UltraLogApp {
+    /// Render the live data connection window
+    pub fn render_live_window(&mut self, ctx: &egui::Context) {
+        if !self.show_live_window {
+            return;
+        }
+
+        let connected = self.live_session.is_some();
+        let record_count = self
+            .live_path
+            .as_ref()
+            .and_then(|path| self.files.iter().find(|f| &f.path == path))
+            .map(|f| f.log.record_count());
+
+        let mut config = self.live_config.clone();
+        let mut open = true;
+        let mut action: Option<LiveAction> = None;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Live Data"))
+            .open(&mut open)
+            .resizable(false)
+            .default_width(380.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.add_enabled_ui(!connected, |ui| {
+                    egui::Grid::new("live_config_grid")
+                        .num_columns(2)
+                        .spacing([12.0, 6.0])
+                        .show(ui, |ui| {
+                            ui.label(lang.tr("Connection:"));
+                            ui.horizontal(|ui| {
+                                ui.radio_value(
+                                    &mut config.transport,
+                                    LiveTransport::Serial,
+                                    lang.tr("Serial"),
+                                );
+                                ui.radio_value(&mut config.transport, LiveTransport::Tcp, "TCP");
+                            });
+                            ui.end_row();
+
+                            match config.transport {
+                                LiveTransport::Serial => {
+                                    ui.label(lang.tr("Port:"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut config.serial_port)
+                                            .hint_text(lang.tr("/dev/ttyACM0 or COM3")),
+                                    );
+                                    ui.end_row();
+
+                                    ui.label(lang.tr("Baud rate:"));
+                                    egui::ComboBox::from_id_salt("live_baud_rate")
+                                        .selected_text(config.baud_rate.to_string())
+                                        .show_ui(ui, |ui| {
+                                            for &baud in BAUD_RATES {
+                                                ui.selectable_value(
+                                                    &mut config.baud_rate,
+                                                    baud,
+                                                    baud.to_string(),
+                                                );
+                                            }
+                                        });
+                                    ui.end_row();
+                                }
+                                LiveTransport::Tcp => {
+                                    ui.label(lang.tr("Host:"));
+                                    ui.text_edit_singleline(&mut config.host);
+                                    ui.end_row();
+
+                                    ui.label(lang.tr("Port:"));
+                                    ui.add(egui::DragValue::new(&mut config.port));
+                                    ui.end_row();
+                                }
+                            }
+
+                            ui.label(lang.tr("INI file:"));
+                            ui.horizontal(|ui| {
+                                let name = config
+                                    .ini_path
+                                    .as_ref()
+                                    .and_then(|p| p.file_name())
+                                    .map(|n| n.to_string_lossy().to_string())
+                                    .unwrap_or_else(|| lang.tr("None selected").to_string());
+                                ui.label(name);
+                                if ui.button(lang.tr("Browse...")).clicked() {
+                                    if let Some(path) = rfd::FileDialog::new()
+                                        .add_filter("TunerStudio INI", &["ini"])
+                                        .pick_file()
+                                    {
+                                        config.ini_path = Some(path);
+                                    }
+                                }
+                            });
+                            ui.end_row();
+
+                            ui.label(lang.tr("Poll interval:"));
+                            ui.add(
+                                egui::DragValue::new(&mut config.poll_interval_ms)
+                                    .range(10..=1000)
+                                    .suffix(" ms"),
+                            );
+                            ui.end_row();
+                        });
+                });
+
+                ui.add_space(8.0);
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    if connected {
+                        if ui.button(lang.tr("⏹ Disconnect")).clicked() {
+                            action = Some(LiveAction::Disconnect);
+                        }
+                        let status = match record_count {
+                            Some(0) | None => lang.tr("Waiting for data...").to_string(),
+                            Some(count) => format!(
+                                "{} {} {}",
+                                lang.tr("Streaming —"),
+                                count,
+                                lang.tr("samples")
+                            ),
+                        };
+                        ui.label(
+                            egui::RichText::new(status)
+                                .color(egui::Color32::from_rgb(113, 120, 78)),
+                        );
+                    } else {
+                        if ui.button(lang.tr("▶ Connect")).clicked() {
+                            action = Some(LiveAction::Connect);
+                        }
+                        ui.label(
+                            egui::RichText::new(lang.tr("Not connected"))
+                                .color(egui::Color32::from_rgb(150, 150, 150)),
+                        );
+                    }
+                });
+            });
+
+        self.live_config = config;
+        self.show_live_window = open;
+
+        match action {
+            Some(LiveAction::Connect) => self.connect_live(),
+            Some(LiveAction::Disconnect) => self.disconnect_live(),
+            None => {}
+        }
+    }
+}
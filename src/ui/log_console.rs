@@ -0,0 +1,96 @@
+//! Log console window.
+//!
+//! Shows UltraLog's recent log output (Help → Show Logs) with a level
+//! filter, and links to the log file so it can be attached to bug reports.
+
+use eframe::egui;
+use tracing::Level;
+
+use crate::app::UltraLogApp;
+use crate::diagnostics;
+
+/// Levels offered by the filter, most severe first
+const LEVELS: [Level; 4] = [Level::ERROR, Level::WARN, Level::INFO, Level::DEBUG];
+
+/// Text color of a line at `level`
+fn level_color(level: Level) -> egui::Color32 {
+    match level {
+        Level::ERROR => egui::Color32::from_rgb(191, 78, 48),
+        Level::WARN => egui::Color32::from_rgb(253, 193, 73),
+        Level::INFO => egui::Color32::from_rgb(200, 200, 200),
+        _ => egui::Color32::GRAY,
+    }
+}
+
+impl UltraLogApp {
+    /// Render the log console window
+    pub fn render_log_console(&mut self, ctx: &egui::Context) {
+        if !self.show_log_console {
+            return;
+        }
+
+        let console = diagnostics::console();
+        let lines = console.lines(self.log_console_level);
+        let mut open = true;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Logs"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(720.0)
+            .default_height(400.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("Show:"));
+                    egui::ComboBox::from_id_salt("log_console_level")
+                        .selected_text(self.log_console_level.as_str())
+                        .show_ui(ui, |ui| {
+                            for level in LEVELS {
+                                ui.selectable_value(
+                                    &mut self.log_console_level,
+                                    level,
+                                    level.as_str(),
+                                );
+                            }
+                        });
+
+                    if ui.button(lang.tr("Copy All")).clicked() {
+                        let text: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+                        ui.ctx().copy_text(text.join("\n"));
+                    }
+                    if ui.button(lang.tr("Clear")).clicked() {
+                        console.clear();
+                    }
+                    if let Some(dir) = diagnostics::log_dir() {
+                        if ui
+                            .button(lang.tr("Open Log Folder"))
+                            .on_hover_text(dir.display().to_string())
+                            .clicked()
+                        {
+                            let _ = open::that(dir);
+                        }
+                    }
+                });
+                ui.separator();
+
+                let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+                egui::ScrollArea::both()
+                    .auto_shrink([false, false])
+                    .stick_to_bottom(true)
+                    .show_rows(ui, row_height, lines.len(), |ui, range| {
+                        for line in &lines[range] {
+                            ui.label(
+                                egui::RichText::new(line.to_string())
+                                    .monospace()
+                                    .color(level_color(line.level)),
+                            );
+                        }
+                    });
+            });
+
+        if !open {
+            self.show_log_console = false;
+        }
+    }
+}
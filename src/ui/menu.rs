@@ -3,15 +3,18 @@
 use eframe::egui;
 
 use crate::app::UltraLogApp;
-use crate::state::LoadingState;
+use crate::i18n::Language;
+use crate::session::SESSION_EXTENSION;
+use crate::state::SplitLayout;
 use crate::units::{
-    AccelerationUnit, DistanceUnit, FlowUnit, FuelEconomyUnit, PressureUnit, SpeedUnit,
-    TemperatureUnit, VolumeUnit,
+    AccelerationUnit, AfrUnit, BoostUnit, DistanceUnit, FlowUnit, FuelEconomyUnit, PressureUnit,
+    SpeedUnit, TemperatureUnit, UnitPreferences, VolumeUnit,
 };
 
 impl UltraLogApp {
     /// Render the application menu bar
     pub fn render_menu_bar(&mut self, ui: &mut egui::Ui) {
+        let lang = self.language;
         egui::MenuBar::new().ui(ui, |ui| {
             // Increase font size for menu items
             ui.style_mut()
@@ -19,7 +22,7 @@ impl UltraLogApp {
                 .insert(egui::TextStyle::Button, egui::FontId::proportional(15.0));
 
             // File menu
-            ui.menu_button("File", |ui| {
+            ui.menu_button(lang.tr("File"), |ui| {
                 ui.set_min_width(180.0);
 
                 // Increase font size for dropdown items
@@ -30,15 +33,15 @@ impl UltraLogApp {
                     .text_styles
                     .insert(egui::TextStyle::Body, egui::FontId::proportional(14.0));
 
-                let is_loading = matches!(self.loading_state, LoadingState::Loading(_));
+                let is_loading = self.loading_state.is_loading();
 
                 // Open file option
                 if ui
-                    .add_enabled(!is_loading, egui::Button::new("📂  Open Log File..."))
+                    .add_enabled(!is_loading, egui::Button::new(lang.tr("📂  Open Log File...")))
                     .clicked()
                 {
                     if let Some(path) = rfd::FileDialog::new()
-                        .add_filter("Log Files", &["csv", "log", "txt", "mlg"])
+                        .add_filter(lang.tr("Log Files"), crate::parsers::LOG_EXTENSIONS)
                         .pick_file()
                     {
                         self.start_loading_file(path);
@@ -46,31 +49,151 @@ impl UltraLogApp {
                     ui.close();
                 }
 
+                let active_file = self.active_tab.map(|idx| self.tabs[idx].file_index);
+                let can_reload =
+                    !is_loading && active_file.is_some_and(|idx| self.is_file_on_disk(idx));
+                if ui
+                    .add_enabled(can_reload, egui::Button::new(lang.tr("🔄  Reload from Disk")))
+                    .on_hover_text(lang.tr("Parse the active log again, keeping its channels and view"))
+                    .clicked()
+                {
+                    if let Some(file_index) = active_file {
+                        self.reload_file(file_index);
+                    }
+                    ui.close();
+                }
+
+                // Session options
+                if ui
+                    .add_enabled(!is_loading, egui::Button::new(lang.tr("🗂  Open Session...")))
+                    .clicked()
+                {
+                    if let Some(path) = rfd::FileDialog::new()
+                        .add_filter(lang.tr("UltraLog Session"), &[SESSION_EXTENSION])
+                        .pick_file()
+                    {
+                        self.open_session(&path);
+                    }
+                    ui.close();
+                }
+
+                if ui
+                    .add_enabled(
+                        !self.tabs.is_empty(),
+                        egui::Button::new(lang.tr("💾  Save Session...")),
+                    )
+                    .clicked()
+                {
+                    if let Some(path) = rfd::FileDialog::new()
+                        .add_filter(lang.tr("UltraLog Session"), &[SESSION_EXTENSION])
+                        .set_file_name(format!("session.{}", SESSION_EXTENSION))
+                        .save_file()
+                    {
+                        self.save_session(&path);
+                    }
+                    ui.close();
+                }
+
                 ui.separator();
 
                 // Export submenu
+                let can_trim = self.active_tab.is_some() && self.trim_range().is_some();
                 let has_chart_data =
                     !self.files.is_empty() && !self.get_selected_channels().is_empty();
-                ui.add_enabled_ui(has_chart_data, |ui| {
-                    ui.menu_button("📤  Export", |ui| {
+                ui.add_enabled_ui(self.active_tab.is_some(), |ui| {
+                    ui.menu_button(lang.tr("📤  Export"), |ui| {
                         // Increase font size for submenu items
                         ui.style_mut()
                             .text_styles
                             .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
-                        if ui.button("Export as PNG...").clicked() {
+                        if ui
+                            .add_enabled(has_chart_data, egui::Button::new(lang.tr("Export as PNG...")))
+                            .clicked()
+                        {
                             self.export_chart_png();
                             ui.close();
                         }
-                        if ui.button("Export as PDF...").clicked() {
+                        if ui
+                            .add_enabled(has_chart_data, egui::Button::new(lang.tr("Export as PDF...")))
+                            .clicked()
+                        {
                             self.export_chart_pdf();
                             ui.close();
                         }
+                        if ui
+                            .add_enabled(has_chart_data, egui::Button::new(lang.tr("Export as HTML...")))
+                            .on_hover_text(
+                                lang.tr("The visible chart as an interactive page that opens in any browser"),
+                            )
+                            .clicked()
+                        {
+                            self.export_chart_html();
+                            ui.close();
+                        }
+                        ui.separator();
+                        if ui
+                            .add_enabled(
+                                can_trim,
+                                egui::Button::new(lang.tr("Export Selection as CSV...")),
+                            )
+                            .on_hover_text(
+                                lang.tr("Records in the A/B loop region, or the visible chart, as a new CSV log"),
+                            )
+                            .clicked()
+                        {
+                            self.export_selection_csv();
+                            ui.close();
+                        }
+                        if ui
+                            .button(lang.tr("Export Log as Arrow..."))
+                            .on_hover_text(
+                                lang.tr("All channels as an Arrow IPC (Feather) file for pandas/Polars"),
+                            )
+                            .clicked()
+                        {
+                            if let Some(tab_idx) = self.active_tab {
+                                self.export_log_arrow(self.tabs[tab_idx].file_index);
+                            }
+                            ui.close();
+                        }
                     });
                 });
+
+                if ui
+                    .add_enabled(can_trim, egui::Button::new(lang.tr("✂  Trim to Selection")))
+                    .on_hover_text(
+                        lang.tr("Open the records in the A/B loop region, or the visible chart, as a new log"),
+                    )
+                    .clicked()
+                {
+                    self.trim_to_selection();
+                    ui.close();
+                }
+                if ui
+                    .add_enabled(self.files.len() >= 2, egui::Button::new(lang.tr("🔗  Merge Logs...")))
+                    .on_hover_text(lang.tr("Combine channels from two logs recorded at the same time"))
+                    .clicked()
+                {
+                    self.show_merge_window = true;
+                    ui.close();
+                }
+
+                // Report builder
+                if ui
+                    .add_enabled(
+                        self.active_tab.is_some(),
+                        egui::Button::new(lang.tr("📄  Generate Report...")),
+                    )
+                    .on_hover_text(lang.tr("Combine analyses of this log into an HTML or PDF report"))
+                    .clicked()
+                {
+                    self.show_report_window = true;
+                    ui.close();
+                }
             });
 
             // View menu
-            ui.menu_button("View", |ui| {
+            ui.menu_button(lang.tr("View"), |ui| {
                 ui.set_min_width(180.0);
 
                 // Increase font size for dropdown items
@@ -83,7 +206,57 @@ impl UltraLogApp {
 
                 // Cursor Tracking toggle
                 if ui
-                    .checkbox(&mut self.cursor_tracking, "🎯  Cursor Tracking")
+                    .checkbox(&mut self.cursor_tracking, lang.tr("🎯  Cursor Tracking"))
+                    .clicked()
+                {
+                    ui.close();
+                }
+
+                // Split view: the active tab next to another tab
+                let mut split = self.split_tab_index().is_some();
+                if ui
+                    .add_enabled(
+                        self.active_tab.is_some(),
+                        egui::Checkbox::new(&mut split, lang.tr("◫  Split View")),
+                    )
+                    .on_hover_text(lang.tr("Show two tabs at once"))
+                    .clicked()
+                {
+                    self.toggle_split_view();
+                    ui.close();
+                }
+                ui.horizontal(|ui| {
+                    ui.add_space(24.0);
+                    ui.radio_value(
+                        &mut self.split_layout,
+                        SplitLayout::SideBySide,
+                        lang.tr("Side by Side"),
+                    );
+                    ui.radio_value(&mut self.split_layout, SplitLayout::Stacked, lang.tr("Stacked"));
+                });
+                ui.horizontal(|ui| {
+                    ui.add_space(24.0);
+                    ui.checkbox(&mut self.link_split_cursors, lang.tr("Link Cursors"))
+                        .on_hover_text(lang.tr("Move both panes' cursors together"));
+                });
+
+                // Cursor link across every tab
+                if ui
+                    .checkbox(&mut self.link_all_cursors, lang.tr("🔗  Link Cursors Across Tabs"))
+                    .on_hover_text(
+                        lang.tr("Move every tab's cursor to the same time from the start of its log"),
+                    )
+                    .clicked()
+                {
+                    ui.close();
+                }
+
+                // Wall-clock time toggle
+                if ui
+                    .checkbox(&mut self.wall_clock_time, lang.tr("🕐  Wall-Clock Time"))
+                    .on_hover_text(
+                        lang.tr("Show the time of day the log was recorded instead of the time from its start (Haltech and MLG logs)"),
+                    )
                     .clicked()
                 {
                     ui.close();
@@ -91,7 +264,7 @@ impl UltraLogApp {
 
                 // Color Blind Mode toggle
                 if ui
-                    .checkbox(&mut self.color_blind_mode, "👁  Color Blind Mode")
+                    .checkbox(&mut self.color_blind_mode, lang.tr("👁  Color Blind Mode"))
                     .clicked()
                 {
                     ui.close();
@@ -101,36 +274,114 @@ impl UltraLogApp {
 
                 // Field Normalization toggle
                 if ui
-                    .checkbox(&mut self.field_normalization, "📝  Field Normalization")
-                    .on_hover_text("Standardize channel names across different ECU types")
+                    .checkbox(&mut self.field_normalization, lang.tr("📝  Field Normalization"))
+                    .on_hover_text(lang.tr("Standardize channel names across different ECU types"))
                     .clicked()
                 {
                     ui.close();
                 }
 
+                ui.horizontal(|ui| {
+                    ui.add_space(24.0);
+                    ui.add_enabled(
+                        self.field_normalization,
+                        egui::Checkbox::new(&mut self.show_original_names, lang.tr("Show Original Names")),
+                    )
+                    .on_hover_text(
+                        lang.tr("Follow normalized names with the ECU's own name, e.g. \"Engine Speed (RPM Ch1)\""),
+                    );
+                });
+
                 // Edit mappings button
-                if ui.button("      Edit Mappings...").clicked() {
+                if ui.button(lang.tr("      Edit Mappings...")).clicked() {
                     self.show_normalization_editor = true;
                     ui.close();
                 }
 
                 ui.separator();
 
-                // Auto-update preference
-                if ui
-                    .checkbox(
-                        &mut self.auto_check_updates,
-                        "🔄  Check for Updates on Startup",
-                    )
-                    .on_hover_text("Automatically check for new versions when the app starts")
-                    .clicked()
-                {
+                // Annotations window
+                if ui.button(lang.tr("🏷  Annotations...")).clicked() {
+                    self.show_annotations_window = true;
+                    ui.close();
+                }
+
+                // Alarm rules window
+                if ui.button(lang.tr("🚨  Alarm Rules...")).clicked() {
+                    self.show_alarms_window = true;
+                    ui.close();
+                }
+
+                // Find value window
+                if ui.button(lang.tr("🔍  Find Value...")).clicked() {
+                    self.show_find_value_window = true;
+                    ui.close();
+                }
+
+                // WOT pull detection window
+                if ui.button(lang.tr("🏁  WOT Pulls...")).clicked() {
+                    self.show_pulls_window = true;
+                    ui.close();
+                }
+
+                // Boost control diagnostics window
+                if ui.button(lang.tr("🌀  Boost Control...")).clicked() {
+                    self.show_boost_window = true;
+                    ui.close();
+                }
+
+                // Fuel trim summary window
+                if ui.button(lang.tr("⛽  Fuel Trims...")).clicked() {
+                    self.show_fuel_trim_window = true;
+                    ui.close();
+                }
+
+                // Misfire detector window
+                if ui.button(lang.tr("💥  Misfire Detector...")).clicked() {
+                    self.show_misfire_window = true;
+                    ui.close();
+                }
+
+                // EGT spread window
+                if ui.button(lang.tr("🔥  EGT Spread...")).clicked() {
+                    self.show_egt_spread_window = true;
+                    ui.close();
+                }
+
+                // Idle quality window
+                if ui.button(lang.tr("⏳  Idle Quality...")).clicked() {
+                    self.show_idle_window = true;
+                    ui.close();
+                }
+
+                // Drag runs window
+                if ui.button(lang.tr("🏁  Drag Runs...")).clicked() {
+                    self.show_drag_window = true;
+                    ui.close();
+                }
+
+                // Shift points window
+                if ui.button(lang.tr("🔀  Shift Points...")).clicked() {
+                    self.show_shifts_window = true;
+                    ui.close();
+                }
+
+                // Live data window
+                if ui.button(lang.tr("📡  Live Data...")).clicked() {
+                    self.show_live_window = true;
+                    ui.close();
+                }
+
+                ui.separator();
+
+                if ui.button(lang.tr("⚙  Preferences...")).clicked() {
+                    self.show_settings_window = true;
                     ui.close();
                 }
             });
 
             // Units menu
-            ui.menu_button("Units", |ui| {
+            ui.menu_button(lang.tr("Units"), |ui| {
                 ui.set_min_width(180.0);
 
                 // Increase font size for dropdown items
@@ -141,262 +392,40 @@ impl UltraLogApp {
                     .text_styles
                     .insert(egui::TextStyle::Body, egui::FontId::proportional(14.0));
 
-                // Temperature submenu
-                ui.menu_button("°C  Temperature", |ui| {
-                    // Increase font size for submenu items
-                    ui.style_mut()
-                        .text_styles
-                        .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.temperature,
-                            TemperatureUnit::Celsius,
-                            "Celsius (°C)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.temperature,
-                            TemperatureUnit::Fahrenheit,
-                            "Fahrenheit (°F)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.temperature,
-                            TemperatureUnit::Kelvin,
-                            "Kelvin (K)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                });
-
-                // Pressure submenu
-                ui.menu_button("💨  Pressure", |ui| {
-                    // Increase font size for submenu items
-                    ui.style_mut()
-                        .text_styles
-                        .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.pressure,
-                            PressureUnit::KPa,
-                            "Kilopascal (kPa)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.pressure,
-                            PressureUnit::PSI,
-                            "PSI",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.pressure,
-                            PressureUnit::Bar,
-                            "Bar",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                });
-
-                // Speed submenu
-                ui.menu_button("🚗  Speed", |ui| {
-                    // Increase font size for submenu items
-                    ui.style_mut()
-                        .text_styles
-                        .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.speed,
-                            SpeedUnit::KmH,
-                            "Kilometers/hour (km/h)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.speed,
-                            SpeedUnit::Mph,
-                            "Miles/hour (mph)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                });
-
-                // Distance submenu
-                ui.menu_button("📏  Distance", |ui| {
-                    // Increase font size for submenu items
-                    ui.style_mut()
-                        .text_styles
-                        .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.distance,
-                            DistanceUnit::Kilometers,
-                            "Kilometers (km)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.distance,
-                            DistanceUnit::Miles,
-                            "Miles (mi)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                });
-
+                // Per-tab override of the global unit preferences
+                let tab_override = self
+                    .active_tab
+                    .map(|idx| self.tabs[idx].unit_overrides.is_some());
+                let mut override_enabled = tab_override.unwrap_or(false);
+                if ui
+                    .add_enabled(
+                        tab_override.is_some(),
+                        egui::Checkbox::new(&mut override_enabled, lang.tr("📑  Override for This Tab")),
+                    )
+                    .on_hover_text(
+                        lang.tr("Use different units in this tab only; other tabs keep the global defaults"),
+                    )
+                    .changed()
+                {
+                    self.set_tab_unit_override(override_enabled);
+                }
+                ui.label(
+                    egui::RichText::new(if override_enabled {
+                        lang.tr("Editing this tab's units")
+                    } else {
+                        lang.tr("Editing global defaults")
+                    })
+                    .small()
+                    .color(egui::Color32::GRAY),
+                );
                 ui.separator();
 
-                // Fuel Economy submenu
-                ui.menu_button("⛽  Fuel Economy", |ui| {
-                    // Increase font size for submenu items
-                    ui.style_mut()
-                        .text_styles
-                        .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.fuel_economy,
-                            FuelEconomyUnit::LPer100Km,
-                            "Liters/100km (L/100km)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.fuel_economy,
-                            FuelEconomyUnit::Mpg,
-                            "Miles/gallon (mpg)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.fuel_economy,
-                            FuelEconomyUnit::KmPerL,
-                            "Kilometers/liter (km/L)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                });
-
-                // Volume submenu
-                ui.menu_button("📊  Volume", |ui| {
-                    // Increase font size for submenu items
-                    ui.style_mut()
-                        .text_styles
-                        .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.volume,
-                            VolumeUnit::Liters,
-                            "Liters (L)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.volume,
-                            VolumeUnit::Gallons,
-                            "Gallons (gal)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                });
-
-                // Flow submenu
-                ui.menu_button("💧  Flow Rate", |ui| {
-                    // Increase font size for submenu items
-                    ui.style_mut()
-                        .text_styles
-                        .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.flow,
-                            FlowUnit::CcPerMin,
-                            "cc/min",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                    if ui
-                        .radio_value(&mut self.unit_preferences.flow, FlowUnit::LbPerHr, "lb/hr")
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                });
-
-                ui.separator();
+                let units = self.active_unit_preferences_mut();
 
-                // Acceleration submenu
-                ui.menu_button("📈  Acceleration", |ui| {
-                    // Increase font size for submenu items
-                    ui.style_mut()
-                        .text_styles
-                        .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.acceleration,
-                            AccelerationUnit::MPerS2,
-                            "m/s²",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                    if ui
-                        .radio_value(
-                            &mut self.unit_preferences.acceleration,
-                            AccelerationUnit::G,
-                            "g-force (g)",
-                        )
-                        .clicked()
-                    {
-                        ui.close();
-                    }
-                });
+                Self::render_unit_submenus(ui, units, lang);
             });
 
-            ui.menu_button("Help", |ui| {
+            ui.menu_button(lang.tr("Help"), |ui| {
                 ui.set_min_width(200.0);
 
                 // Increase font size for dropdown items
@@ -407,19 +436,24 @@ impl UltraLogApp {
                     .text_styles
                     .insert(egui::TextStyle::Body, egui::FontId::proportional(14.0));
 
-                if ui.button("📖  Documentation").clicked() {
+                if ui.button(lang.tr("📖  Documentation")).clicked() {
                     let _ = open::that("https://github.com/SomethingNew71/UltraLog/wiki");
                     ui.close();
                 }
 
-                if ui.button("🐛  Report Issue").clicked() {
+                if ui.button(lang.tr("🐛  Report Issue")).clicked() {
                     let _ = open::that("https://github.com/SomethingNew71/UltraLog/issues");
                     ui.close();
                 }
 
+                if ui.button(lang.tr("📜  Show Logs")).clicked() {
+                    self.show_log_console = true;
+                    ui.close();
+                }
+
                 ui.separator();
 
-                if ui.button("💝  Support Development").clicked() {
+                if ui.button(lang.tr("💝  Support Development")).clicked() {
                     let _ = open::that("https://github.com/sponsors/SomethingNew71");
                     ui.close();
                 }
@@ -430,12 +464,12 @@ impl UltraLogApp {
                 let is_checking = matches!(
                     self.update_state,
                     crate::updater::UpdateState::Checking
-                        | crate::updater::UpdateState::Downloading
+                        | crate::updater::UpdateState::Downloading { .. }
                 );
                 let button_text = if is_checking {
-                    "🔄  Checking for Updates..."
+                    lang.tr("🔄  Checking for Updates...")
                 } else {
-                    "🔄  Check for Updates"
+                    lang.tr("🔄  Check for Updates")
                 };
 
                 if ui
@@ -445,6 +479,13 @@ impl UltraLogApp {
                     self.start_update_check();
                     ui.close();
                 }
+                if let Some(status) = &self.update_status {
+                    ui.label(
+                        egui::RichText::new(status)
+                            .small()
+                            .color(egui::Color32::GRAY),
+                    );
+                }
 
                 ui.separator();
 
@@ -457,4 +498,342 @@ impl UltraLogApp {
             });
         });
     }
+
+    /// Submenus choosing each kind of display unit, shared by the Units menu
+    /// and the preferences window
+    pub(crate) fn render_unit_submenus(
+        ui: &mut egui::Ui,
+        units: &mut UnitPreferences,
+        lang: Language,
+    ) {
+        // Temperature submenu
+        ui.menu_button(lang.tr("°C  Temperature"), |ui| {
+            // Increase font size for submenu items
+            ui.style_mut()
+                .text_styles
+                .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
+            if ui
+                .radio_value(
+                    &mut units.temperature,
+                    TemperatureUnit::Celsius,
+                    "Celsius (°C)",
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(
+                    &mut units.temperature,
+                    TemperatureUnit::Fahrenheit,
+                    "Fahrenheit (°F)",
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(
+                    &mut units.temperature,
+                    TemperatureUnit::Kelvin,
+                    "Kelvin (K)",
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+        });
+
+        // Pressure submenu
+        ui.menu_button(lang.tr("💨  Pressure"), |ui| {
+            // Increase font size for submenu items
+            ui.style_mut()
+                .text_styles
+                .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
+            if ui
+                .radio_value(&mut units.pressure, PressureUnit::KPa, "Kilopascal (kPa)")
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(&mut units.pressure, PressureUnit::PSI, "PSI")
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(&mut units.pressure, PressureUnit::Bar, "Bar")
+                .clicked()
+            {
+                ui.close();
+            }
+        });
+
+        // Speed submenu
+        ui.menu_button(lang.tr("🚗  Speed"), |ui| {
+            // Increase font size for submenu items
+            ui.style_mut()
+                .text_styles
+                .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
+            if ui
+                .radio_value(
+                    &mut units.speed,
+                    SpeedUnit::KmH,
+                    lang.tr("Kilometers/hour (km/h)"),
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(
+                    &mut units.speed,
+                    SpeedUnit::Mph,
+                    lang.tr("Miles/hour (mph)"),
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+        });
+
+        // Distance submenu
+        ui.menu_button(lang.tr("📏  Distance"), |ui| {
+            // Increase font size for submenu items
+            ui.style_mut()
+                .text_styles
+                .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
+            if ui
+                .radio_value(
+                    &mut units.distance,
+                    DistanceUnit::Kilometers,
+                    lang.tr("Kilometers (km)"),
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(
+                    &mut units.distance,
+                    DistanceUnit::Miles,
+                    lang.tr("Miles (mi)"),
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+        });
+
+        ui.separator();
+
+        // Fuel Economy submenu
+        ui.menu_button(lang.tr("⛽  Fuel Economy"), |ui| {
+            // Increase font size for submenu items
+            ui.style_mut()
+                .text_styles
+                .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
+            if ui
+                .radio_value(
+                    &mut units.fuel_economy,
+                    FuelEconomyUnit::LPer100Km,
+                    lang.tr("Liters/100km (L/100km)"),
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(
+                    &mut units.fuel_economy,
+                    FuelEconomyUnit::Mpg,
+                    lang.tr("Miles/gallon (mpg)"),
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(
+                    &mut units.fuel_economy,
+                    FuelEconomyUnit::KmPerL,
+                    lang.tr("Kilometers/liter (km/L)"),
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+        });
+
+        // Volume submenu
+        ui.menu_button(lang.tr("📊  Volume"), |ui| {
+            // Increase font size for submenu items
+            ui.style_mut()
+                .text_styles
+                .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
+            if ui
+                .radio_value(&mut units.volume, VolumeUnit::Liters, lang.tr("Liters (L)"))
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(
+                    &mut units.volume,
+                    VolumeUnit::Gallons,
+                    lang.tr("Gallons (gal)"),
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+        });
+
+        // Flow submenu
+        ui.menu_button(lang.tr("💧  Flow Rate"), |ui| {
+            // Increase font size for submenu items
+            ui.style_mut()
+                .text_styles
+                .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
+            if ui
+                .radio_value(&mut units.flow, FlowUnit::CcPerMin, "cc/min")
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(&mut units.flow, FlowUnit::LbPerHr, "lb/hr")
+                .clicked()
+            {
+                ui.close();
+            }
+        });
+
+        ui.separator();
+
+        // Acceleration submenu
+        ui.menu_button(lang.tr("📈  Acceleration"), |ui| {
+            // Increase font size for submenu items
+            ui.style_mut()
+                .text_styles
+                .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
+            if ui
+                .radio_value(&mut units.acceleration, AccelerationUnit::MPerS2, "m/s²")
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(
+                    &mut units.acceleration,
+                    AccelerationUnit::G,
+                    lang.tr("g-force (g)"),
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+        });
+
+        ui.separator();
+
+        // Air-fuel ratio submenu
+        ui.menu_button(lang.tr("λ  Air-Fuel Ratio"), |ui| {
+            // Increase font size for submenu items
+            ui.style_mut()
+                .text_styles
+                .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
+            if ui
+                .radio_value(&mut units.afr, AfrUnit::Lambda, "Lambda (λ)")
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(
+                    &mut units.afr,
+                    AfrUnit::Gasoline,
+                    lang.tr("AFR Gasoline (14.7)"),
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+            if ui
+                .radio_value(&mut units.afr, AfrUnit::E85, "AFR E85 (9.8)")
+                .clicked()
+            {
+                ui.close();
+            }
+            ui.horizontal(|ui| {
+                let mut stoich = match units.afr {
+                    AfrUnit::Custom(stoich) => stoich,
+                    _ => AfrUnit::DEFAULT_CUSTOM_STOICH,
+                };
+                let is_custom = matches!(units.afr, AfrUnit::Custom(_));
+                if ui.radio(is_custom, lang.tr("AFR Custom")).clicked() {
+                    units.afr = AfrUnit::Custom(stoich);
+                }
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut stoich)
+                            .range(1.0..=30.0)
+                            .speed(0.01)
+                            .fixed_decimals(2),
+                    )
+                    .on_hover_text(lang.tr("Stoichiometric ratio of the fuel"))
+                    .changed()
+                {
+                    units.afr = AfrUnit::Custom(stoich);
+                }
+            });
+        });
+
+        // Manifold pressure submenu
+        ui.menu_button(lang.tr("🚀  Manifold Pressure"), |ui| {
+            // Increase font size for submenu items
+            ui.style_mut()
+                .text_styles
+                .insert(egui::TextStyle::Button, egui::FontId::proportional(14.0));
+            if ui
+                .radio_value(
+                    &mut units.boost,
+                    BoostUnit::Absolute,
+                    lang.tr("Absolute (MAP)"),
+                )
+                .clicked()
+            {
+                ui.close();
+            }
+            ui.horizontal(|ui| {
+                let mut baro = match units.boost {
+                    BoostUnit::Gauge(baro) => baro,
+                    _ => BoostUnit::STANDARD_BARO_KPA,
+                };
+                let is_gauge = matches!(units.boost, BoostUnit::Gauge(_));
+                if ui
+                    .radio(is_gauge, lang.tr("Boost PSI / Vacuum inHg"))
+                    .clicked()
+                {
+                    units.boost = BoostUnit::Gauge(baro);
+                }
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut baro)
+                            .range(50.0..=110.0)
+                            .speed(0.1)
+                            .fixed_decimals(1)
+                            .suffix(" kPa"),
+                    )
+                    .on_hover_text(
+                        lang.tr("Barometric pressure used when the log has no baro channel"),
+                    )
+                    .changed()
+                {
+                    units.boost = BoostUnit::Gauge(baro);
+                }
+            });
+        });
+    }
 }
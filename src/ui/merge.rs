@@ -0,0 +1,148 @@
+//! Merge Logs window.
+//!
+//! Combines two loaded logs recorded at the same time, e.g. an ECU log and
+//! an external wideband logger's CSV, into a new log in its own tab. The
+//! second log's channels are resampled onto the first log's timestamps.
+
+use std::path::PathBuf;
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::merge::merge;
+use crate::state::{LoadResult, LoadedFile};
+
+impl UltraLogApp {
+    /// Merge the second chosen file into the first and open the result in
+    /// a new tab
+    fn merge_files(&mut self, primary: usize, secondary: usize) {
+        let (first, second) = (&self.files[primary], &self.files[secondary]);
+        let file = LoadedFile {
+            path: PathBuf::from(format!(
+                "{} + {}",
+                first.path.display(),
+                second.path.display()
+            )),
+            name: format!("{} + {}", first.name, second.name),
+            ecu_type: first.ecu_type,
+            log: merge(&first.log, &second.log),
+            tail: None,
+            following: false,
+            time_offset: 0.0,
+            runs: Vec::new(),
+            run: 0,
+        };
+        self.handle_load_result(LoadResult::Success(Box::new(file)), None);
+    }
+
+    /// Render the merge logs window
+    pub fn render_merge_window(&mut self, ctx: &egui::Context) {
+        if !self.show_merge_window {
+            return;
+        }
+
+        let file_count = self.files.len();
+        let (mut primary, mut secondary) = self.merge_selection;
+        let mut open = true;
+        let mut do_merge = false;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Merge Logs"))
+            .open(&mut open)
+            .resizable(false)
+            .default_width(420.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                if file_count < 2 {
+                    ui.label(
+                        egui::RichText::new(
+                            lang.tr("Open two logs recorded at the same time to merge them."),
+                        )
+                        .color(egui::Color32::GRAY),
+                    );
+                    return;
+                }
+                primary = primary.min(file_count - 1);
+                secondary = secondary.min(file_count - 1);
+
+                egui::Grid::new("merge_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 8.0])
+                    .show(ui, |ui| {
+                        for (label, id, choice) in [
+                            (lang.tr("Time base:"), "merge_primary", &mut primary),
+                            (
+                                lang.tr("Add channels from:"),
+                                "merge_secondary",
+                                &mut secondary,
+                            ),
+                        ] {
+                            ui.label(label);
+                            egui::ComboBox::from_id_salt(id)
+                                .width(260.0)
+                                .selected_text(self.files[*choice].name.clone())
+                                .show_ui(ui, |ui| {
+                                    for (idx, file) in self.files.iter().enumerate() {
+                                        ui.selectable_value(choice, idx, &file.name);
+                                    }
+                                });
+                            ui.end_row();
+                        }
+                    });
+                ui.add_space(4.0);
+
+                let (first, second) = (&self.files[primary].log, &self.files[secondary].log);
+                let start = first.times.first().copied().unwrap_or(0.0);
+                let end = first.times.last().copied().unwrap_or(0.0);
+                let overlap_start = start.max(second.times.first().copied().unwrap_or(0.0));
+                let overlap_end = end.min(second.times.last().copied().unwrap_or(0.0));
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} {} {}",
+                        lang.tr("The second log's channels are interpolated onto the first log's"),
+                        first.record_count(),
+                        lang.tr(
+                            "records. Times are matched as shown, so give one log a time offset \
+                             first if they started at different moments."
+                        )
+                    ))
+                    .color(egui::Color32::GRAY),
+                );
+                if primary == secondary {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(253, 193, 73),
+                        lang.tr("⚠ Choose two different logs"),
+                    );
+                } else if overlap_end <= overlap_start {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(253, 193, 73),
+                        lang.tr("⚠ The logs' times don't overlap"),
+                    );
+                } else {
+                    ui.label(format!(
+                        "{} {} {} {}",
+                        lang.tr("Overlap:"),
+                        Self::format_time(overlap_start),
+                        lang.tr("to"),
+                        Self::format_time(overlap_end)
+                    ));
+                }
+                ui.separator();
+
+                if ui
+                    .add_enabled(primary != secondary, egui::Button::new(lang.tr("🔗 Merge")))
+                    .clicked()
+                {
+                    do_merge = true;
+                }
+            });
+
+        self.merge_selection = (primary, secondary);
+        if do_merge {
+            self.merge_files(primary, secondary);
+            self.show_merge_window = false;
+        } else if !open {
+            self.show_merge_window = false;
+        }
+    }
+}
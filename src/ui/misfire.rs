@@ -0,0 +1,293 @@
+//! Misfire detector window.
+//!
+//! Lists candidate misfires in the active log (sudden RPM dips under load
+//! that recover) with the knock and lambda channels' values at each one,
+//! and jumps the cursor and chart to an event.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::i18n::Language;
+use crate::misfire::MisfireEvent;
+use crate::normalize::normalize_channel_name_with_custom;
+use crate::state::MisfireScan;
+
+/// Channels shown next to each event, matched case-insensitively
+const CONTEXT_KEYWORDS: [&str; 2] = ["knock", "lambda"];
+
+/// A channel shown as a column next to the events
+struct ContextColumn {
+    channel_index: usize,
+    /// Header text, e.g. `Knock 1 (dB)`
+    header: String,
+}
+
+impl UltraLogApp {
+    /// Candidate misfires in the active file, found again when the file or
+    /// thresholds have changed
+    fn current_misfire_scan(&mut self) -> Option<&MisfireScan> {
+        let file_index = self
+            .active_tab
+            .map(|idx| self.tabs[idx].file_index)
+            .filter(|&idx| idx < self.files.len())?;
+
+        let stale = self
+            .misfire_scan
+            .as_ref()
+            .is_none_or(|scan| scan.file_index != file_index || scan.config != self.misfire_config);
+        if stale {
+            let events = (|| {
+                let rpm = self
+                    .find_channel_by_name(file_index, "RPM")
+                    .ok_or("No RPM channel in this log")?;
+                let tps = self
+                    .find_channel_by_name(file_index, "TPS")
+                    .ok_or("No throttle position (TPS) channel in this log")?;
+                let log = &self.files[file_index].log;
+                Ok(self.misfire_config.detect(
+                    log.get_times_as_f64(),
+                    log.get_channel_data(rpm),
+                    log.get_channel_data(tps),
+                ))
+            })();
+            self.misfire_scan = Some(MisfireScan {
+                file_index,
+                config: self.misfire_config.clone(),
+                events,
+            });
+        }
+        self.misfire_scan.as_ref()
+    }
+
+    /// Knock and lambda channels of a file, in log order
+    fn misfire_context_columns(&self, file_index: usize) -> Vec<ContextColumn> {
+        self.files[file_index]
+            .log
+            .channels
+            .iter()
+            .enumerate()
+            .filter(|(_, channel)| {
+                let name = channel.name().to_lowercase();
+                CONTEXT_KEYWORDS.iter().any(|k| name.contains(k))
+            })
+            .map(|(channel_index, channel)| {
+                let name = if self.field_normalization {
+                    normalize_channel_name_with_custom(
+                        &channel.name(),
+                        Some(&self.custom_normalizations),
+                    )
+                } else {
+                    channel.name()
+                };
+                let (_, unit) = self.convert_channel_value(file_index, channel_index, 0, 0.0);
+                let header = if unit.is_empty() {
+                    name
+                } else {
+                    format!("{} ({})", name, unit)
+                };
+                ContextColumn {
+                    channel_index,
+                    header,
+                }
+            })
+            .collect()
+    }
+
+    /// Render the misfire detector window
+    pub fn render_misfire_window(&mut self, ctx: &egui::Context) {
+        if !self.show_misfire_window {
+            return;
+        }
+
+        let scan = self.current_misfire_scan().cloned();
+        let columns = scan
+            .as_ref()
+            .map(|scan| self.misfire_context_columns(scan.file_index))
+            .unwrap_or_default();
+        // Context values at each event, in display units
+        let values: Vec<Vec<String>> = match &scan {
+            Some(MisfireScan {
+                file_index,
+                events: Ok(events),
+                ..
+            }) => events
+                .iter()
+                .map(|event| {
+                    columns
+                        .iter()
+                        .map(|column| {
+                            self.get_value_at_record(
+                                *file_index,
+                                column.channel_index,
+                                event.record,
+                            )
+                            .map(|value| {
+                                let (converted, _) = self.convert_channel_value(
+                                    *file_index,
+                                    column.channel_index,
+                                    event.record,
+                                    value,
+                                );
+                                format!("{:.2}", converted)
+                            })
+                            .unwrap_or_else(|| "-".to_string())
+                        })
+                        .collect()
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let mut config = self.misfire_config.clone();
+        let mut open = true;
+        let mut jump: Option<f64> = None;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Misfire Detector"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(720.0)
+            .default_height(400.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("RPM falling faster than"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.min_drop_rate)
+                            .range(100.0..=50000.0)
+                            .speed(50.0)
+                            .suffix(" RPM/s"),
+                    );
+                    ui.label(lang.tr("above"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.min_rpm)
+                            .range(0.0..=20000.0)
+                            .speed(50.0)
+                            .suffix(" RPM"),
+                    );
+                });
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("with throttle above"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.min_tps)
+                            .range(0.0..=100.0)
+                            .speed(1.0)
+                            .suffix(" %"),
+                    );
+                    ui.label(lang.tr("and recovering within"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.recovery_time)
+                            .range(0.05..=5.0)
+                            .speed(0.01)
+                            .suffix(" s"),
+                    );
+                });
+                ui.label(
+                    egui::RichText::new(lang.tr(
+                        "Dips RPM doesn't recover from, like shifts and lifts, are left out. \
+                         Knock and lambda values are taken at the bottom of each dip.",
+                    ))
+                    .color(egui::Color32::GRAY),
+                );
+                ui.add_space(4.0);
+                ui.separator();
+
+                match &scan {
+                    None => {
+                        ui.label(
+                            egui::RichText::new(lang.tr("Open a log file to look for misfires."))
+                                .color(egui::Color32::GRAY),
+                        );
+                    }
+                    Some(MisfireScan { events: Err(e), .. }) => {
+                        ui.colored_label(egui::Color32::from_rgb(253, 193, 73), format!("⚠ {}", e));
+                    }
+                    Some(MisfireScan {
+                        events: Ok(events), ..
+                    }) => Self::render_misfire_rows(ui, events, &columns, &values, &mut jump, lang),
+                }
+            });
+
+        if config != self.misfire_config {
+            self.misfire_config = config;
+        }
+
+        if let Some(time) = jump {
+            self.move_cursor_to(time);
+        }
+
+        if !open {
+            self.show_misfire_window = false;
+        }
+    }
+
+    /// Render the table of candidate misfires
+    fn render_misfire_rows(
+        ui: &mut egui::Ui,
+        events: &[MisfireEvent],
+        columns: &[ContextColumn],
+        values: &[Vec<String>],
+        jump: &mut Option<f64>,
+        lang: Language,
+    ) {
+        if events.is_empty() {
+            ui.label(egui::RichText::new(lang.tr("No misfires found.")).color(egui::Color32::GRAY));
+            return;
+        }
+
+        ui.label(format!(
+            "{} {}",
+            events.len(),
+            if events.len() == 1 {
+                lang.tr("candidate misfire")
+            } else {
+                lang.tr("candidate misfires")
+            }
+        ));
+        ui.add_space(4.0);
+
+        egui::ScrollArea::both()
+            .id_salt("misfire_scroll")
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                egui::Grid::new("misfire_grid")
+                    .striped(true)
+                    .spacing([16.0, 6.0])
+                    .show(ui, |ui| {
+                        for header in [
+                            "#",
+                            lang.tr("Time"),
+                            "RPM",
+                            lang.tr("Drop"),
+                            lang.tr("Drop Rate"),
+                        ] {
+                            ui.label(egui::RichText::new(header).strong());
+                        }
+                        for column in columns {
+                            ui.label(egui::RichText::new(&column.header).strong());
+                        }
+                        ui.label("");
+                        ui.end_row();
+
+                        for (i, (event, row)) in events.iter().zip(values).enumerate() {
+                            ui.label(format!("{}", i + 1));
+                            ui.label(Self::format_time(event.time));
+                            ui.label(format!("{:.0} → {:.0}", event.rpm_before, event.rpm_min));
+                            ui.label(format!("{:.0} RPM", event.drop()));
+                            ui.label(format!("{:.0} RPM/s", event.drop_rate));
+                            for value in row {
+                                ui.label(value);
+                            }
+                            if ui
+                                .small_button(lang.tr("Jump"))
+                                .on_hover_text(lang.tr("Move the cursor to this event"))
+                                .clicked()
+                            {
+                                *jump = Some(event.time);
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
@@ -12,19 +12,75 @@
 //! - `normalization_editor` - Field normalization customization window
 //! - `tool_switcher` - Pill-style tab navigation between tools
 //! - `scatter_plot` - Scatter plot visualization view
+//! - `scatter_surface` - 3D surface rendering for scatter plots
+//! - `track_map` - GPS track map view
+//! - `dyno` - Virtual dyno power estimation view
+//! - `data_table` - Raw record values in a scrollable grid
 //! - `tab_bar` - Chrome-style tabs for managing multiple log files
 //! - `update_dialog` - Auto-update dialog window
+//! - `triage` - Unsupported file triage dialog
+//! - `alarms` - Threshold alarm rules window
+//! - `find_value` - Step through records where a channel meets a condition
+//! - `pulls` - WOT pull detection window
+//! - `boost` - Boost control diagnostics window
+//! - `fuel_trim` - Fuel correction by RPM/load cell window
+//! - `misfire` - Candidate misfire list window
+//! - `egt_spread` - EGT imbalance between cylinders window
+//! - `idle` - Idle quality summary window
+//! - `drag` - Drag run timing window
+//! - `shifts` - Shift points per gear window
+//! - `report` - Generate Report window combining analyses
+//! - `merge` - Merge Logs window combining two simultaneous logs
+//! - `annotations` - Chart annotations list window
+//! - `live` - Live data connection window
+//! - `file_info` - Loaded file metadata window
+//! - `split_view` - Two tabs shown side by side in the log viewer
+//! - `settings` - Preferences window
+//! - `theme` - Dark and light color themes
+//! - `error_dialog` - Failed file load details window
+//! - `log_console` - Application log window
+//! - `crash_dialog` - Offer to report a crash from the previous run
+//! - `drop_overlay` - Highlight shown while files are dragged over the window
+//! - `welcome` - Getting-started view shown while no files are open
 
+pub mod alarms;
+pub mod annotations;
+pub mod boost;
 pub mod channels;
 pub mod chart;
+pub mod crash_dialog;
+pub mod data_table;
+pub mod drag;
+pub mod drop_overlay;
+pub mod dyno;
+pub mod egt_spread;
+pub mod error_dialog;
 pub mod export;
+pub mod file_info;
+pub mod find_value;
+pub mod fuel_trim;
 pub mod icons;
+pub mod idle;
+pub mod live;
+pub mod log_console;
 pub mod menu;
+pub mod merge;
+pub mod misfire;
 pub mod normalization_editor;
+pub mod pulls;
+pub mod report;
 pub mod scatter_plot;
+pub mod scatter_surface;
+pub mod settings;
+pub mod shifts;
 pub mod sidebar;
+pub mod split_view;
 pub mod tab_bar;
+pub mod theme;
 pub mod timeline;
 pub mod toast;
 pub mod tool_switcher;
+pub mod track_map;
+pub mod triage;
 pub mod update_dialog;
+pub mod welcome;
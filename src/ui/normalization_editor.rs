@@ -5,7 +5,10 @@
 use eframe::egui;
 
 use crate::app::UltraLogApp;
-use crate::normalize::get_builtin_mappings;
+use crate::normalize::{
+    get_builtin_mappings, mapping_key, mappings_to_csv, mappings_to_json, parse_mappings,
+    validate_pattern,
+};
 
 impl UltraLogApp {
     /// Render the field normalization editor window
@@ -15,8 +18,11 @@ impl UltraLogApp {
         }
 
         let mut open = true;
+        let mut action: Option<MappingFileAction> = None;
+        let mut invalid_pattern: Option<String> = None;
+        let lang = self.language;
 
-        egui::Window::new("Field Normalization Editor")
+        egui::Window::new(lang.tr("Field Normalization Editor"))
             .open(&mut open)
             .resizable(true)
             .default_width(550.0)
@@ -25,16 +31,33 @@ impl UltraLogApp {
             .show(ctx, |ui| {
                 // Header with reset button
                 ui.horizontal(|ui| {
-                    ui.heading("Field Name Mappings");
+                    ui.heading(lang.tr("Field Name Mappings"));
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        if !self.custom_normalizations.is_empty() {
-                            if ui.button("Reset to Defaults").clicked() {
-                                self.custom_normalizations.clear();
-                                self.norm_editor_extend_source.clear();
-                                self.norm_editor_selected_target = None;
-                                self.norm_editor_custom_source.clear();
-                                self.norm_editor_custom_target.clear();
-                            }
+                        if ui
+                            .add_enabled(
+                                !self.custom_normalizations.is_empty(),
+                                egui::Button::new(lang.tr("Export...")),
+                            )
+                            .on_hover_text(lang.tr("Save your custom mappings as JSON or CSV"))
+                            .clicked()
+                        {
+                            action = Some(MappingFileAction::Export);
+                        }
+                        if ui
+                            .button(lang.tr("Import..."))
+                            .on_hover_text(lang.tr("Add mappings from a JSON or CSV file"))
+                            .clicked()
+                        {
+                            action = Some(MappingFileAction::Import);
+                        }
+                        if !self.custom_normalizations.is_empty()
+                            && ui.button(lang.tr("Reset to Defaults")).clicked()
+                        {
+                            self.custom_normalizations.clear();
+                            self.norm_editor_extend_source.clear();
+                            self.norm_editor_selected_target = None;
+                            self.norm_editor_custom_source.clear();
+                            self.norm_editor_custom_target.clear();
                         }
                     });
                 });
@@ -43,10 +66,10 @@ impl UltraLogApp {
                 // --- Extend Built-in Mappings Section ---
                 ui.separator();
                 ui.add_space(4.0);
-                ui.label(egui::RichText::new("Extend Built-in Mappings").strong());
+                ui.label(egui::RichText::new(lang.tr("Extend Built-in Mappings")).strong());
                 ui.label(
                     egui::RichText::new(
-                        "Add your own source names that map to existing normalized field names.",
+                        lang.tr("Add your own source names that map to existing normalized field names."),
                     )
                     .color(egui::Color32::GRAY),
                 );
@@ -57,20 +80,20 @@ impl UltraLogApp {
                 let builtin_names: Vec<&str> = builtin_mappings.iter().map(|(n, _)| *n).collect();
 
                 ui.horizontal(|ui| {
-                    ui.label("Source Name:");
+                    ui.label(lang.tr("Source Name:"));
                     ui.add(
                         egui::TextEdit::singleline(&mut self.norm_editor_extend_source)
-                            .hint_text("e.g., MyCustomRPM")
+                            .hint_text(lang.tr("e.g., MyCustomRPM"))
                             .desired_width(150.0),
                     );
                     ui.label("→");
-                    ui.label("Maps To:");
+                    ui.label(lang.tr("Maps To:"));
 
                     // Dropdown for selecting existing normalized name
                     let selected_text = self
                         .norm_editor_selected_target
                         .as_deref()
-                        .unwrap_or("Select...");
+                        .unwrap_or(lang.tr("Select..."));
                     egui::ComboBox::from_id_salt("extend_builtin_combo")
                         .selected_text(selected_text)
                         .width(120.0)
@@ -83,15 +106,13 @@ impl UltraLogApp {
                             }
                         });
 
-                    if ui.button("Add").clicked() {
-                        if !self.norm_editor_extend_source.is_empty() {
-                            if let Some(target) = &self.norm_editor_selected_target {
-                                self.custom_normalizations.insert(
-                                    self.norm_editor_extend_source.to_lowercase(),
-                                    target.clone(),
-                                );
-                                self.norm_editor_extend_source.clear();
-                            }
+                    if ui.button(lang.tr("Add")).clicked() && !self.norm_editor_extend_source.is_empty() {
+                        if let Some(target) = &self.norm_editor_selected_target {
+                            self.custom_normalizations.insert(
+                                mapping_key(&self.norm_editor_extend_source),
+                                target.clone(),
+                            );
+                            self.norm_editor_extend_source.clear();
                         }
                     }
                 });
@@ -101,39 +122,50 @@ impl UltraLogApp {
                 // --- Custom Mappings Section ---
                 ui.separator();
                 ui.add_space(4.0);
-                ui.label(egui::RichText::new("Create New Mapping").strong());
+                ui.label(egui::RichText::new(lang.tr("Create New Mapping")).strong());
+                ui.label(
+                    egui::RichText::new(
+                        lang.tr("Define completely new normalized names for your custom channels."),
+                    )
+                    .color(egui::Color32::GRAY),
+                );
                 ui.label(
                     egui::RichText::new(
-                        "Define completely new normalized names for your custom channels.",
+                        lang.tr("Use * to match a family (EGT Cyl * → EGT {n}) or re: for a regular expression ({1}, {2} insert its groups)."),
                     )
+                    .small()
                     .color(egui::Color32::GRAY),
                 );
                 ui.add_space(8.0);
 
                 ui.horizontal(|ui| {
-                    ui.label("Source Name:");
+                    ui.label(lang.tr("Source Name:"));
                     ui.add(
                         egui::TextEdit::singleline(&mut self.norm_editor_custom_source)
-                            .hint_text("e.g., Sensor_XYZ")
+                            .hint_text(lang.tr("e.g., Sensor_XYZ"))
                             .desired_width(150.0),
                     );
                     ui.label("→");
-                    ui.label("Display As:");
+                    ui.label(lang.tr("Display As:"));
                     ui.add(
                         egui::TextEdit::singleline(&mut self.norm_editor_custom_target)
-                            .hint_text("e.g., Custom Sensor")
+                            .hint_text(lang.tr("e.g., Custom Sensor"))
                             .desired_width(150.0),
                     );
-                    if ui.button("Add").clicked() {
-                        if !self.norm_editor_custom_source.is_empty()
-                            && !self.norm_editor_custom_target.is_empty()
-                        {
-                            self.custom_normalizations.insert(
-                                self.norm_editor_custom_source.to_lowercase(),
-                                self.norm_editor_custom_target.clone(),
-                            );
-                            self.norm_editor_custom_source.clear();
-                            self.norm_editor_custom_target.clear();
+                    if ui.button(lang.tr("Add")).clicked()
+                        && !self.norm_editor_custom_source.is_empty()
+                        && !self.norm_editor_custom_target.is_empty()
+                    {
+                        match validate_pattern(&self.norm_editor_custom_source) {
+                            Ok(()) => {
+                                self.custom_normalizations.insert(
+                                    mapping_key(&self.norm_editor_custom_source),
+                                    self.norm_editor_custom_target.clone(),
+                                );
+                                self.norm_editor_custom_source.clear();
+                                self.norm_editor_custom_target.clear();
+                            }
+                            Err(e) => invalid_pattern = Some(e),
                         }
                     }
                 });
@@ -145,7 +177,7 @@ impl UltraLogApp {
                     ui.separator();
                     ui.add_space(4.0);
                     ui.horizontal(|ui| {
-                        ui.label(egui::RichText::new("Your Custom Mappings").strong());
+                        ui.label(egui::RichText::new(lang.tr("Your Custom Mappings")).strong());
                         ui.label(
                             egui::RichText::new(format!("({})", self.custom_normalizations.len()))
                                 .color(egui::Color32::GRAY),
@@ -165,8 +197,8 @@ impl UltraLogApp {
                                 .min_col_width(100.0)
                                 .spacing([16.0, 8.0])
                                 .show(ui, |ui| {
-                                    ui.label(egui::RichText::new("Source").strong());
-                                    ui.label(egui::RichText::new("Display As").strong());
+                                    ui.label(egui::RichText::new(lang.tr("Source")).strong());
+                                    ui.label(egui::RichText::new(lang.tr("Display As")).strong());
                                     ui.label("");
                                     ui.end_row();
 
@@ -180,7 +212,7 @@ impl UltraLogApp {
                                             egui::RichText::new(target)
                                                 .color(egui::Color32::LIGHT_BLUE),
                                         );
-                                        if ui.small_button("Remove").clicked() {
+                                        if ui.small_button(lang.tr("Remove")).clicked() {
                                             to_remove = Some(source.clone());
                                         }
                                         ui.end_row();
@@ -197,12 +229,12 @@ impl UltraLogApp {
                 ui.separator();
 
                 // Built-in mappings reference (collapsible)
-                egui::CollapsingHeader::new("Built-in Mappings Reference")
+                egui::CollapsingHeader::new(lang.tr("Built-in Mappings Reference"))
                     .default_open(false)
                     .show(ui, |ui| {
                         ui.label(
                             egui::RichText::new(
-                                "These are the default mappings. You can extend them by adding new source names above.",
+                                lang.tr("These are the default mappings. You can extend them by adding new source names above."),
                             )
                             .color(egui::Color32::GRAY),
                         );
@@ -234,5 +266,75 @@ impl UltraLogApp {
         if !open {
             self.show_normalization_editor = false;
         }
+
+        if let Some(e) = invalid_pattern {
+            self.show_toast_error(&format!("{} {}", lang.tr("Invalid pattern:"), e));
+        }
+
+        match action {
+            Some(MappingFileAction::Import) => self.import_normalizations(),
+            Some(MappingFileAction::Export) => self.export_normalizations(),
+            None => {}
+        }
+    }
+
+    /// Merge custom mappings from a JSON or CSV file, replacing any existing
+    /// mappings for the same source names
+    fn import_normalizations(&mut self) {
+        let lang = self.language;
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter(lang.tr("Mappings"), &["json", "csv"])
+            .pick_file()
+        else {
+            return;
+        };
+
+        let is_json = Self::is_json_path(&path);
+        let result = std::fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| parse_mappings(&text, is_json));
+        match result {
+            Ok(mappings) => {
+                let count = mappings.len();
+                self.custom_normalizations.extend(mappings);
+                self.show_toast_success(&format!("{} {}", lang.tr("Mappings imported:"), count));
+            }
+            Err(e) => self.show_toast_error(&format!("{} {}", lang.tr("Import failed:"), e)),
+        }
+    }
+
+    /// Save the custom mappings as JSON or CSV, chosen by file extension
+    fn export_normalizations(&mut self) {
+        let lang = self.language;
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("JSON", &["json"])
+            .add_filter("CSV", &["csv"])
+            .set_file_name("ultralog_mappings.json")
+            .save_file()
+        else {
+            return;
+        };
+
+        let contents = if Self::is_json_path(&path) {
+            mappings_to_json(&self.custom_normalizations).map_err(|e| e.to_string())
+        } else {
+            Ok(mappings_to_csv(&self.custom_normalizations))
+        };
+        match contents.and_then(|text| std::fs::write(&path, text).map_err(|e| e.to_string())) {
+            Ok(()) => self.show_toast_success(lang.tr("Mappings exported")),
+            Err(e) => self.show_toast_error(&format!("{} {}", lang.tr("Export failed:"), e)),
+        }
+    }
+
+    /// Whether a mapping file is JSON (anything else is read as CSV)
+    fn is_json_path(path: &std::path::Path) -> bool {
+        path.extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
     }
 }
+
+/// Deferred import/export from the editor's header buttons
+enum MappingFileAction {
+    Import,
+    Export,
+}
@@ -0,0 +1,468 @@
+//! WOT pulls window.
+//!
+//! Lists the full-throttle pulls detected in the active log with their start,
+//! duration, RPM range and peak boost and speed, and lets users zoom the chart
+//! to a pull or export its records as CSV. Pulls from any open log can be
+//! overlaid against RPM to compare a channel run-to-run.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+use eframe::egui;
+use egui_plot::{Legend, Line, Plot, PlotPoints};
+
+use crate::app::UltraLogApp;
+use crate::convert::{channel_names, write_csv_records};
+use crate::i18n::Language;
+use crate::pulls::{Pull, COMPARE_RPM_BIN};
+use crate::state::{ActiveTool, ComparedPull};
+
+/// Channels offered in the comparison picker (normalized names)
+const COMPARE_CHANNELS: &[&str] = &["MAP", "Boost", "Ignition Adv", "AFR", "Lambda 1"];
+
+/// Deferred action from the pulls table
+enum PullAction {
+    Zoom(usize),
+    Export(usize),
+    /// Add or remove a pull of the active log from the comparison
+    Compare(usize),
+    /// Remove an entry of the comparison list
+    Uncompare(usize),
+    ClearComparison,
+}
+
+/// Display values for one row of the pulls table
+struct PullRow {
+    pull: Pull,
+    peak_boost: Option<String>,
+    peak_speed: Option<String>,
+    compared: bool,
+}
+
+/// One pull's curve in the comparison plot
+struct CompareCurve {
+    label: String,
+    points: Vec<[f64; 2]>,
+}
+
+impl UltraLogApp {
+    /// Get the pulls in a file, detecting them if not cached
+    pub fn get_pulls(&mut self, file_index: usize) -> Result<&Vec<Pull>, String> {
+        if !self.pull_results.contains_key(&file_index) {
+            let tps = self
+                .find_channel_by_name(file_index, "TPS")
+                .ok_or("No throttle position (TPS) channel in this log")?;
+            let rpm = self
+                .find_channel_by_name(file_index, "RPM")
+                .ok_or("No RPM channel in this log")?;
+            let log = &self.files[file_index].log;
+            let pulls = self.pull_config.detect(
+                log.get_times_as_f64(),
+                log.get_channel_data(tps),
+                log.get_channel_data(rpm),
+            );
+            self.pull_results.insert(file_index, pulls);
+        }
+        Ok(&self.pull_results[&file_index])
+    }
+
+    /// Peak of a channel over a pull, converted to display units
+    pub(crate) fn pull_peak(
+        &self,
+        file_index: usize,
+        channel: &str,
+        pull: &Pull,
+    ) -> Option<String> {
+        let channel_index = self.find_channel_by_name(file_index, channel)?;
+        let data = self.files[file_index].log.get_channel_data(channel_index);
+        let peak = pull.peak(data)?;
+        let record = (pull.start_record..=pull.end_record).find(|&r| data[r] == peak)?;
+        let (value, unit) = self.convert_channel_value(file_index, channel_index, record, peak);
+        Some(format!("{:.1} {}", value, unit).trim_end().to_string())
+    }
+
+    /// Curve of the comparison channel against RPM for a compared pull, in
+    /// display units, or why it can't be drawn
+    fn compare_curve(&self, compared: &ComparedPull) -> Result<(Vec<[f64; 2]>, String), String> {
+        let file = self
+            .files
+            .get(compared.file_index)
+            .ok_or("Log is no longer open")?;
+        let rpm = self
+            .find_channel_by_name(compared.file_index, "RPM")
+            .ok_or("No RPM channel")?;
+        let channel = self
+            .find_channel_by_name(compared.file_index, &self.pull_compare_channel)
+            .ok_or_else(|| format!("No '{}' channel", self.pull_compare_channel))?;
+
+        let source_unit = file.log.channels[channel].unit();
+        let units = self.active_unit_preferences();
+        let unit = units.convert_value(0.0, source_unit).1.to_string();
+        let points = compared
+            .pull
+            .rpm_curve(
+                file.log.get_channel_data(rpm),
+                file.log.get_channel_data(channel),
+                COMPARE_RPM_BIN,
+            )
+            .into_iter()
+            .map(|[rpm, value]| [rpm, units.convert_value(value, source_unit).0])
+            .collect();
+        Ok((points, unit))
+    }
+
+    /// Render the WOT pull detection window
+    pub fn render_pulls_window(&mut self, ctx: &egui::Context) {
+        if !self.show_pulls_window {
+            return;
+        }
+
+        let active_file = self
+            .active_tab
+            .map(|idx| self.tabs[idx].file_index)
+            .filter(|&idx| idx < self.files.len());
+        let rows: Option<Result<Vec<PullRow>, String>> = active_file.map(|file_index| {
+            let pulls = self.get_pulls(file_index)?.clone();
+            Ok(pulls
+                .into_iter()
+                .map(|pull| PullRow {
+                    peak_boost: self.pull_peak(file_index, "MAP", &pull),
+                    peak_speed: self.pull_peak(file_index, "Vehicle Speed", &pull),
+                    compared: self
+                        .compared_pulls
+                        .iter()
+                        .any(|c| c.file_index == file_index && c.pull == pull),
+                    pull,
+                })
+                .collect())
+        });
+
+        // Comparison curves, labelled by file and pull number
+        let mut curves: Vec<CompareCurve> = Vec::new();
+        let mut compare_errors: Vec<String> = Vec::new();
+        let mut compare_unit = String::new();
+        for compared in &self.compared_pulls {
+            let label = format!(
+                "{} #{}",
+                self.files
+                    .get(compared.file_index)
+                    .map_or("?", |f| f.name.as_str()),
+                compared.number
+            );
+            match self.compare_curve(compared) {
+                Ok((points, unit)) => {
+                    compare_unit = unit;
+                    curves.push(CompareCurve { label, points });
+                }
+                Err(e) => compare_errors.push(format!("{}: {}", label, e)),
+            }
+        }
+        let curve_colors: Vec<[u8; 3]> = (0..curves.len())
+            .map(|i| self.get_channel_color(i))
+            .collect();
+        let compared_labels: Vec<String> = self
+            .compared_pulls
+            .iter()
+            .map(|c| {
+                let name = self
+                    .files
+                    .get(c.file_index)
+                    .map_or("?", |f| f.name.as_str());
+                format!("{} #{}", name, c.number)
+            })
+            .collect();
+        let mut compare_channel = self.pull_compare_channel.clone();
+
+        let mut config = self.pull_config.clone();
+        let mut open = true;
+        let mut action: Option<PullAction> = None;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("WOT Pulls"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(640.0)
+            .default_height(400.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("Throttle ≥"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.min_tps)
+                            .range(0.0..=100.0)
+                            .suffix(" %"),
+                    );
+                    ui.label(lang.tr("for at least"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.min_duration)
+                            .range(0.1..=60.0)
+                            .speed(0.1)
+                            .suffix(" s"),
+                    );
+                    ui.label(lang.tr("gaining at least"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.min_rpm_rise)
+                            .range(0.0..=10000.0)
+                            .speed(50.0)
+                            .suffix(" RPM"),
+                    );
+                });
+                ui.label(
+                    egui::RichText::new(
+                        lang.tr("A pull ends at its peak RPM; shifting during a WOT run starts a new pull."),
+                    )
+                    .color(egui::Color32::GRAY),
+                );
+                ui.add_space(4.0);
+                ui.separator();
+
+                match &rows {
+                    None => {
+                        ui.label(
+                            egui::RichText::new(lang.tr("Open a log file to detect pulls."))
+                                .color(egui::Color32::GRAY),
+                        );
+                    }
+                    Some(Err(e)) => {
+                        ui.colored_label(egui::Color32::from_rgb(253, 193, 73), format!("⚠ {}", e));
+                    }
+                    Some(Ok(rows)) => Self::render_pull_rows(ui, rows, &mut action, lang),
+                }
+
+                ui.add_space(8.0);
+                ui.separator();
+                ui.heading(lang.tr("Compare Pulls"));
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("Channel:"));
+                    ui.add(
+                        egui::TextEdit::singleline(&mut compare_channel)
+                            .hint_text(lang.tr("Channel name"))
+                            .desired_width(140.0),
+                    );
+                    egui::ComboBox::from_id_salt("pull_compare_channel")
+                        .selected_text("")
+                        .width(20.0)
+                        .show_ui(ui, |ui| {
+                            for name in COMPARE_CHANNELS {
+                                if ui
+                                    .selectable_label(compare_channel == *name, *name)
+                                    .clicked()
+                                {
+                                    compare_channel = name.to_string();
+                                }
+                            }
+                        });
+                    if !compared_labels.is_empty() && ui.button(lang.tr("Clear")).clicked() {
+                        action = Some(PullAction::ClearComparison);
+                    }
+                });
+
+                if compared_labels.is_empty() {
+                    ui.label(
+                        egui::RichText::new(
+                            lang.tr("Tick Compare on pulls (from any open log) to overlay them against RPM."),
+                        )
+                        .color(egui::Color32::GRAY),
+                    );
+                    return;
+                }
+
+                ui.horizontal_wrapped(|ui| {
+                    for (i, label) in compared_labels.iter().enumerate() {
+                        if ui
+                            .small_button(format!("{} ✖", label))
+                            .on_hover_text(lang.tr("Remove from comparison"))
+                            .clicked()
+                        {
+                            action = Some(PullAction::Uncompare(i));
+                        }
+                    }
+                });
+                for error in &compare_errors {
+                    ui.colored_label(egui::Color32::from_rgb(253, 193, 73), format!("⚠ {}", error));
+                }
+
+                Plot::new("pull_compare_plot")
+                    .legend(Legend::default())
+                    .height(260.0)
+                    .x_axis_label("RPM")
+                    .y_axis_label(if compare_unit.is_empty() {
+                        compare_channel.clone()
+                    } else {
+                        format!("{} ({})", compare_channel, compare_unit)
+                    })
+                    .allow_boxed_zoom(false)
+                    .show(ui, |plot_ui| {
+                        for (curve, [r, g, b]) in curves.iter().zip(&curve_colors) {
+                            plot_ui.line(
+                                Line::new(curve.label.clone(), PlotPoints::from(curve.points.clone()))
+                                    .color(egui::Color32::from_rgb(*r, *g, *b))
+                                    .width(1.5),
+                            );
+                        }
+                    });
+            });
+
+        self.pull_compare_channel = compare_channel;
+
+        if config != self.pull_config {
+            self.pull_config = config;
+            self.pull_results.clear();
+        }
+
+        if !open {
+            self.show_pulls_window = false;
+        }
+
+        match action {
+            Some(PullAction::Uncompare(i)) => {
+                self.compared_pulls.remove(i);
+                return;
+            }
+            Some(PullAction::ClearComparison) => {
+                self.compared_pulls.clear();
+                return;
+            }
+            _ => {}
+        }
+
+        let (Some(file_index), Some(Ok(rows))) = (active_file, rows) else {
+            return;
+        };
+        match action {
+            Some(PullAction::Compare(i)) => {
+                let pull = &rows[i].pull;
+                if rows[i].compared {
+                    self.compared_pulls
+                        .retain(|c| !(c.file_index == file_index && c.pull == *pull));
+                } else {
+                    self.compared_pulls.push(ComparedPull {
+                        file_index,
+                        number: i + 1,
+                        pull: pull.clone(),
+                    });
+                }
+            }
+            Some(PullAction::Zoom(i)) => {
+                let pull = &rows[i].pull;
+                self.active_tool = ActiveTool::LogViewer;
+                self.set_zoom_to_range(Some((pull.start_time, pull.end_time)));
+                self.set_cursor_time(Some(pull.start_time));
+                self.set_cursor_record(Some(pull.start_record));
+            }
+            Some(PullAction::Export(i)) => self.export_pull(file_index, i + 1, &rows[i].pull),
+            _ => {}
+        }
+    }
+
+    /// Render the table of detected pulls
+    fn render_pull_rows(
+        ui: &mut egui::Ui,
+        rows: &[PullRow],
+        action: &mut Option<PullAction>,
+        lang: Language,
+    ) {
+        if rows.is_empty() {
+            ui.label(egui::RichText::new(lang.tr("No pulls found.")).color(egui::Color32::GRAY));
+            return;
+        }
+
+        ui.label(format!("{} {}", rows.len(), lang.tr("pull(s)")));
+        ui.add_space(4.0);
+
+        egui::ScrollArea::vertical()
+            .id_salt("pulls_scroll")
+            .auto_shrink([false, true])
+            .max_height(180.0)
+            .show(ui, |ui| {
+                egui::Grid::new("pulls_grid")
+                    .striped(true)
+                    .spacing([16.0, 6.0])
+                    .show(ui, |ui| {
+                        for header in [
+                            "#",
+                            lang.tr("Start"),
+                            lang.tr("Duration"),
+                            "RPM",
+                            lang.tr("Peak Boost"),
+                            lang.tr("Peak Speed"),
+                        ] {
+                            ui.label(egui::RichText::new(header).strong());
+                        }
+                        ui.label("");
+                        ui.end_row();
+
+                        for (i, row) in rows.iter().enumerate() {
+                            let pull = &row.pull;
+                            ui.label(format!("{}", i + 1));
+                            ui.label(Self::format_time(pull.start_time));
+                            ui.label(format!("{:.2}s", pull.duration()));
+                            ui.label(format!("{:.0} → {:.0}", pull.start_rpm, pull.peak_rpm));
+                            ui.label(row.peak_boost.as_deref().unwrap_or("-"));
+                            ui.label(row.peak_speed.as_deref().unwrap_or("-"));
+                            ui.horizontal(|ui| {
+                                let mut compared = row.compared;
+                                if ui
+                                    .checkbox(&mut compared, lang.tr("Compare"))
+                                    .on_hover_text(
+                                        lang.tr("Overlay this pull in the comparison plot"),
+                                    )
+                                    .changed()
+                                {
+                                    *action = Some(PullAction::Compare(i));
+                                }
+                                if ui
+                                    .small_button(lang.tr("Zoom"))
+                                    .on_hover_text(lang.tr("Show this pull in the Log Viewer"))
+                                    .clicked()
+                                {
+                                    *action = Some(PullAction::Zoom(i));
+                                }
+                                if ui
+                                    .small_button(lang.tr("Export"))
+                                    .on_hover_text(lang.tr("Save this pull's records as CSV"))
+                                    .clicked()
+                                {
+                                    *action = Some(PullAction::Export(i));
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    /// Save the records of one pull as CSV
+    fn export_pull(&mut self, file_index: usize, number: usize, pull: &Pull) {
+        let file = &self.files[file_index];
+        let stem = file
+            .path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "log".to_string());
+        let lang = self.language;
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("CSV", &["csv"])
+            .set_file_name(format!("{}_pull{}.csv", stem, number))
+            .save_file()
+        else {
+            return;
+        };
+
+        let names = channel_names(&file.log, self.field_normalization);
+        let result = File::create(&path).and_then(|out| {
+            let mut out = BufWriter::new(out);
+            write_csv_records(
+                &file.log,
+                &names,
+                pull.start_record..pull.end_record + 1,
+                &mut out,
+            )?;
+            out.flush()
+        });
+        match result {
+            Ok(()) => self.show_toast_success(&format!("{} {}", lang.tr("Pull exported:"), number)),
+            Err(e) => self.show_toast_error(&format!("{} {}", lang.tr("Export failed:"), e)),
+        }
+    }
+}
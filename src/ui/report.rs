@@ -0,0 +1,437 @@
+//! Generate Report window.
+//!
+//! Lets the user pick analyses of the active log (WOT pulls, AFR safety,
+//! boost control, channel statistics) and saves them together as a single
+//! HTML or PDF report to hand to a customer. Each section uses the same
+//! thresholds and display units as its window in the app.
+
+use std::fs::File;
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::idle::ChannelStats;
+use crate::normalize::normalize_channel_name_with_custom;
+use crate::report::{Report, ReportSection, ReportTable};
+
+/// How far above the AFR target (as a fraction of it) the leanest reading of
+/// a pull can be before the pull is flagged as lean
+const LEAN_MARGIN: f64 = 0.05;
+
+/// File format a report is saved in
+#[derive(Clone, Copy)]
+enum ReportFormat {
+    Html,
+    Pdf,
+}
+
+impl ReportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ReportFormat::Html => "html",
+            ReportFormat::Pdf => "pdf",
+        }
+    }
+
+    fn filter(self) -> &'static str {
+        match self {
+            ReportFormat::Html => "HTML Page",
+            ReportFormat::Pdf => "PDF Document",
+        }
+    }
+}
+
+/// Cells of a table, one `Vec` per row
+fn table(headers: &[&str], rows: Vec<Vec<String>>) -> ReportTable {
+    ReportTable {
+        headers: headers.iter().map(|h| h.to_string()).collect(),
+        rows,
+    }
+}
+
+/// A section that only explains why its analysis couldn't run
+fn unavailable(title: &str, reason: &str) -> ReportSection {
+    ReportSection {
+        title: title.to_string(),
+        notes: vec![reason.to_string()],
+        table: None,
+    }
+}
+
+impl UltraLogApp {
+    /// Build the report of a file from the analyses picked in the report
+    /// settings
+    fn build_report(&mut self, file_index: usize) -> Report {
+        let file = &self.files[file_index];
+        let times = file.log.get_times_as_f64();
+        let duration = times.last().copied().unwrap_or(0.0) - times.first().copied().unwrap_or(0.0);
+        let title = if self.report_title.trim().is_empty() {
+            format!("{} Report", file.name)
+        } else {
+            self.report_title.trim().to_string()
+        };
+        let mut report = Report {
+            title,
+            details: vec![
+                format!("Log: {} ({})", file.name, file.ecu_type.name()),
+                format!(
+                    "Length: {} over {} records",
+                    Self::format_time(duration),
+                    times.len()
+                ),
+            ],
+            sections: Vec::new(),
+        };
+
+        let config = self.report_config.clone();
+        if config.pulls {
+            report.sections.push(self.pulls_section(file_index));
+        }
+        if config.afr_safety {
+            report.sections.push(self.afr_safety_section(file_index));
+        }
+        if config.boost {
+            report.sections.push(self.boost_section(file_index));
+        }
+        if config.statistics {
+            report.sections.push(self.statistics_section(file_index));
+        }
+        report
+    }
+
+    /// WOT pulls with their RPM range and peak boost and speed
+    fn pulls_section(&mut self, file_index: usize) -> ReportSection {
+        const TITLE: &str = "WOT Pulls";
+        let pulls = match self.get_pulls(file_index) {
+            Ok(pulls) => pulls.clone(),
+            Err(e) => return unavailable(TITLE, &e),
+        };
+        let dash = || "-".to_string();
+        let rows = pulls
+            .iter()
+            .enumerate()
+            .map(|(i, pull)| {
+                vec![
+                    format!("{}", i + 1),
+                    Self::format_time(pull.start_time),
+                    format!("{:.1}s", pull.duration()),
+                    format!("{:.0} to {:.0}", pull.start_rpm, pull.peak_rpm),
+                    self.pull_peak(file_index, "MAP", pull).unwrap_or_else(dash),
+                    self.pull_peak(file_index, "Vehicle Speed", pull)
+                        .unwrap_or_else(dash),
+                ]
+            })
+            .collect();
+        ReportSection {
+            title: TITLE.to_string(),
+            notes: vec![format!(
+                "{} pull{} at {:.0}% throttle or more, lasting at least {:.1}s and gaining at \
+                 least {:.0} RPM.",
+                pulls.len(),
+                if pulls.len() == 1 { "" } else { "s" },
+                self.pull_config.min_tps,
+                self.pull_config.min_duration,
+                self.pull_config.min_rpm_rise
+            )],
+            table: Some(table(
+                &["#", "Start", "Duration", "RPM", "Peak Boost", "Peak Speed"],
+                rows,
+            )),
+        }
+    }
+
+    /// Leanest AFR over each pull, flagged when it is well above the target
+    fn afr_safety_section(&mut self, file_index: usize) -> ReportSection {
+        const TITLE: &str = "AFR Safety";
+        let Some(afr) = self
+            .find_channel_by_name(file_index, "AFR")
+            .or_else(|| self.find_channel_by_name(file_index, "Lambda 1"))
+        else {
+            return unavailable(TITLE, "No AFR or lambda channel in this log.");
+        };
+        let target = self.find_channel_by_name(file_index, "AFR Target");
+        let pulls = match self.get_pulls(file_index) {
+            Ok(pulls) => pulls.clone(),
+            Err(e) => return unavailable(TITLE, &e),
+        };
+
+        let log = &self.files[file_index].log;
+        let display = |channel: usize, record: usize| {
+            let value = log.get_channel_data(channel)[record];
+            self.convert_channel_value(file_index, channel, record, value)
+        };
+        let mut lean_pulls = 0;
+        let rows = pulls
+            .iter()
+            .enumerate()
+            .filter_map(|(i, pull)| {
+                // Leanest record relative to the target, or the highest
+                // reading without one
+                let leanness = |record: usize| {
+                    let actual = display(afr, record).0;
+                    match target {
+                        Some(target) => actual / display(target, record).0 - 1.0,
+                        None => actual,
+                    }
+                };
+                let leanest = (pull.start_record..=pull.end_record)
+                    .filter(|&r| leanness(r).is_finite())
+                    .max_by(|&a, &b| leanness(a).total_cmp(&leanness(b)))?;
+
+                let (actual, unit) = display(afr, leanest);
+                let mut row = vec![
+                    format!("{}", i + 1),
+                    Self::format_time(log.get_times_as_f64()[leanest]),
+                    format!("{:.2} {}", actual, unit),
+                ];
+                if let Some(target) = target {
+                    let (target_value, target_unit) = display(target, leanest);
+                    let lean = leanness(leanest);
+                    if lean > LEAN_MARGIN {
+                        lean_pulls += 1;
+                    }
+                    row.extend([
+                        format!("{:.2} {}", target_value, target_unit),
+                        format!("{:+.1}%", lean * 100.0),
+                        if lean > LEAN_MARGIN { "Lean" } else { "OK" }.to_string(),
+                    ]);
+                }
+                Some(row)
+            })
+            .collect();
+
+        let notes = if target.is_some() {
+            vec![format!(
+                "Leanest reading of each WOT pull against the AFR target. Pulls more than \
+                 {:.0}% leaner than the target are marked Lean: {} of {}.",
+                LEAN_MARGIN * 100.0,
+                lean_pulls,
+                pulls.len()
+            )]
+        } else {
+            vec![
+                "Leanest reading of each WOT pull. The log has no AFR target to compare against."
+                    .to_string(),
+            ]
+        };
+        let headers: &[&str] = if target.is_some() {
+            &["#", "Time", "Leanest", "Target", "Leaner By", "Status"]
+        } else {
+            &["#", "Time", "Leanest"]
+        };
+        ReportSection {
+            title: TITLE.to_string(),
+            notes,
+            table: Some(table(headers, rows)),
+        }
+    }
+
+    /// Boost control over each pull, as in the Boost Control window
+    fn boost_section(&mut self, file_index: usize) -> ReportSection {
+        const TITLE: &str = "Boost Control";
+        let analysis = match self.analyze_boost(file_index) {
+            Ok(analysis) => analysis,
+            Err(e) => return unavailable(TITLE, &e),
+        };
+        let dash = || "-".to_string();
+        let pressure = |value: f64| format!("{:.1} {}", value, analysis.unit);
+        let rows = analysis
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let report = row.report.as_ref();
+                vec![
+                    format!("{}", i + 1),
+                    report
+                        .and_then(|r| r.gear)
+                        .map_or_else(dash, |g| g.to_string()),
+                    report.map_or_else(dash, |r| pressure(r.peak_target)),
+                    report.map_or_else(dash, |r| pressure(r.overshoot)),
+                    report.map_or_else(dash, |r| {
+                        r.settling_time
+                            .map_or("Never".to_string(), |t| format!("{:.2}s", t))
+                    }),
+                    report
+                        .and_then(|r| r.steady_error)
+                        .map_or_else(dash, |e| format!("{:+.1} {}", e, analysis.unit)),
+                    report
+                        .and_then(|r| r.steady_duty)
+                        .map_or_else(dash, |d| format!("{:.1}%", d)),
+                ]
+            })
+            .collect();
+        ReportSection {
+            title: TITLE.to_string(),
+            notes: vec![format!(
+                "Boost counts as settled once manifold pressure stays within {:.0}% of the \
+                 target for the rest of the pull.",
+                crate::boost::SETTLED_BAND * 100.0
+            )],
+            table: Some(table(
+                &[
+                    "#",
+                    "Gear",
+                    "Peak Target",
+                    "Overshoot",
+                    "Settling",
+                    "Steady Error",
+                    "Steady Duty",
+                ],
+                rows,
+            )),
+        }
+    }
+
+    /// Minimum, mean and maximum of the channels charted in the active tab
+    fn statistics_section(&self, file_index: usize) -> ReportSection {
+        const TITLE: &str = "Channel Statistics";
+        let log = &self.files[file_index].log;
+        let units = self.active_unit_preferences();
+        let rows: Vec<Vec<String>> = self
+            .get_selected_channels()
+            .iter()
+            .filter(|selected| selected.file_index == file_index)
+            .filter_map(|selected| {
+                let channel = &log.channels[selected.channel_index];
+                let source_unit = channel.unit();
+                let stats = ChannelStats::of(
+                    log.get_channel_data(selected.channel_index)
+                        .iter()
+                        .map(|&v| units.convert_value(v, source_unit).0),
+                )?;
+                let name = if self.field_normalization {
+                    normalize_channel_name_with_custom(
+                        &channel.name(),
+                        Some(&self.custom_normalizations),
+                    )
+                } else {
+                    channel.name()
+                };
+                Some(vec![
+                    name,
+                    units.convert_value(0.0, source_unit).1.to_string(),
+                    format!("{:.2}", stats.min),
+                    format!("{:.2}", stats.mean),
+                    format!("{:.2}", stats.max),
+                ])
+            })
+            .collect();
+
+        if rows.is_empty() {
+            return unavailable(TITLE, "No channels are charted for this log.");
+        }
+        ReportSection {
+            title: TITLE.to_string(),
+            notes: vec!["Over the whole log, for the channels on the chart.".to_string()],
+            table: Some(table(&["Channel", "Unit", "Min", "Mean", "Max"], rows)),
+        }
+    }
+
+    /// Build the report of a file and ask where to save it
+    fn save_report(&mut self, file_index: usize, format: ReportFormat) {
+        let lang = self.language;
+        let stem = self.files[file_index]
+            .path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "log".to_string());
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter(lang.tr(format.filter()), &[format.extension()])
+            .set_file_name(format!("{}_report.{}", stem, format.extension()))
+            .save_file()
+        else {
+            return;
+        };
+
+        let report = self.build_report(file_index);
+        let result: Result<(), Box<dyn std::error::Error>> = match format {
+            ReportFormat::Html => std::fs::write(&path, report.to_html()).map_err(Into::into),
+            ReportFormat::Pdf => File::create(&path)
+                .map_err(Into::into)
+                .and_then(|mut out| report.write_pdf(&mut out)),
+        };
+        match result {
+            Ok(()) => self.show_toast_success(lang.tr("Report saved")),
+            Err(e) => self.show_toast_error(&format!("{} {}", lang.tr("Export failed:"), e)),
+        }
+    }
+
+    /// Render the Generate Report window
+    pub fn render_report_window(&mut self, ctx: &egui::Context) {
+        if !self.show_report_window {
+            return;
+        }
+
+        let active_file = self
+            .active_tab
+            .map(|idx| self.tabs[idx].file_index)
+            .filter(|&idx| idx < self.files.len());
+        let mut config = self.report_config.clone();
+        let mut open = true;
+        let mut save: Option<ReportFormat> = None;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Generate Report"))
+            .open(&mut open)
+            .resizable(false)
+            .default_width(380.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                let Some(file_index) = active_file else {
+                    ui.label(
+                        egui::RichText::new(lang.tr("Open a log file to report on it."))
+                            .color(egui::Color32::GRAY),
+                    );
+                    return;
+                };
+
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("Title:"));
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.report_title)
+                            .hint_text(format!("{} Report", self.files[file_index].name))
+                            .desired_width(260.0),
+                    );
+                });
+                ui.add_space(4.0);
+                ui.label(egui::RichText::new(lang.tr("Include")).strong());
+                ui.checkbox(&mut config.pulls, lang.tr("WOT pulls"));
+                ui.checkbox(&mut config.afr_safety, lang.tr("AFR safety over each pull"));
+                ui.checkbox(&mut config.boost, lang.tr("Boost control over each pull"));
+                ui.checkbox(
+                    &mut config.statistics,
+                    lang.tr("Statistics of the charted channels"),
+                );
+                ui.add_space(4.0);
+                ui.label(
+                    egui::RichText::new(lang.tr(
+                        "Sections use the thresholds from their windows and your display units.",
+                    ))
+                    .color(egui::Color32::GRAY),
+                );
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    if ui.button(lang.tr("🌐 Save as HTML...")).clicked() {
+                        save = Some(ReportFormat::Html);
+                    }
+                    if ui.button(lang.tr("📄 Save as PDF...")).clicked() {
+                        save = Some(ReportFormat::Pdf);
+                    }
+                });
+            });
+
+        if config != self.report_config {
+            self.report_config = config;
+        }
+
+        if let (Some(format), Some(file_index)) = (save, active_file) {
+            self.save_report(file_index, format);
+        }
+
+        if !open {
+            self.show_report_window = false;
+        }
+    }
+}
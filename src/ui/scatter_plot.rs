@@ -5,9 +5,13 @@
 
 use eframe::egui;
 
+use crate::alarms::{Comparison, Condition};
 use crate::app::UltraLogApp;
+use crate::binning::{parse_breakpoints, BinnedGrid};
 use crate::normalize::{normalize_channel_name_with_custom, sort_channels_by_priority};
-use crate::state::{ScatterPlotConfig, SelectedHeatmapPoint};
+use crate::state::{
+    ScatterAxisBinning, ScatterPlotConfig, ScatterTimeWindow, ScatterViewMode, SelectedHeatmapPoint,
+};
 
 /// Heat map color gradient from blue (low) to red (high)
 const HEAT_COLORS: &[[u8; 3]] = &[
@@ -56,6 +60,9 @@ impl UltraLogApp {
         self.render_tab_bar(ui);
         ui.add_space(10.0);
 
+        self.render_scatter_time_window(ui);
+        ui.add_space(8.0);
+
         // Get available size for layout
         let available_width = ui.available_width();
         let available_height = ui.available_height();
@@ -75,6 +82,135 @@ impl UltraLogApp {
         });
     }
 
+    /// Render the time window selector shared by both plots
+    fn render_scatter_time_window(&mut self, ui: &mut egui::Ui) {
+        let Some(tab_idx) = self.active_tab else {
+            return;
+        };
+
+        let view_range = self.chart_view_range;
+        let (min_t, max_t) = self.get_time_range().unwrap_or((0.0, 0.0));
+        let annotations = self.tabs[tab_idx].annotations.clone();
+        let window = &mut self.tabs[tab_idx].scatter_plot_state.time_window;
+
+        ui.horizontal(|ui| {
+            ui.label("Time Window:");
+            ui.selectable_value(window, ScatterTimeWindow::FullLog, "Full Log");
+            ui.selectable_value(window, ScatterTimeWindow::ChartView, "Chart View")
+                .on_hover_text("Use only the range visible in the Log Viewer chart");
+
+            let is_range = matches!(window, ScatterTimeWindow::Range { .. });
+            if ui.selectable_label(is_range, "Custom Range").clicked() && !is_range {
+                let (start, end) = view_range.unwrap_or((min_t, max_t));
+                *window = ScatterTimeWindow::Range { start, end };
+            }
+
+            if let ScatterTimeWindow::Range { start, end } = window {
+                ui.add_space(8.0);
+                for (label, value) in [("Start (s):", start), ("End (s):", end)] {
+                    ui.label(label);
+                    ui.add(
+                        egui::DragValue::new(value)
+                            .speed(0.05)
+                            .range(min_t..=max_t)
+                            .max_decimals(3),
+                    );
+                }
+
+                if !annotations.is_empty() {
+                    egui::ComboBox::from_id_salt("scatter_annotation")
+                        .selected_text("From Annotation")
+                        .width(120.0)
+                        .show_ui(ui, |ui| {
+                            for annotation in &annotations {
+                                if ui.selectable_label(false, &annotation.label).clicked() {
+                                    *window = ScatterTimeWindow::Range {
+                                        start: annotation.start.min(annotation.end),
+                                        end: annotation.start.max(annotation.end),
+                                    };
+                                }
+                            }
+                        });
+                }
+            }
+        });
+    }
+
+    /// Record range of a file covered by the scatter plot time window
+    fn scatter_record_range(&self, file_idx: usize) -> std::ops::Range<usize> {
+        let times = &self.files[file_idx].log.times;
+        let window = self
+            .active_tab
+            .map(|idx| self.tabs[idx].scatter_plot_state.time_window)
+            .unwrap_or_default();
+
+        let (start, end) = match window {
+            ScatterTimeWindow::FullLog => return 0..times.len(),
+            ScatterTimeWindow::ChartView => match self.chart_view_range {
+                Some(range) => range,
+                None => return 0..times.len(),
+            },
+            ScatterTimeWindow::Range { start, end } => (start.min(end), start.max(end)),
+        };
+
+        let first = times.partition_point(|&t| t < start);
+        let last = times.partition_point(|&t| t <= end).max(first);
+        first..last
+    }
+
+    /// Channel values for a scatter plot, restricted to the time window and
+    /// the plot's filter conditions. Returns one column per requested channel,
+    /// aligned by record. Filters on channels missing from the file are ignored.
+    pub(crate) fn scatter_samples(
+        &self,
+        is_left: bool,
+        file_idx: usize,
+        channels: &[usize],
+    ) -> Vec<Vec<f64>> {
+        let log = &self.files[file_idx].log;
+        let range = self.scatter_record_range(file_idx);
+
+        let filters = self
+            .active_tab
+            .map(|idx| {
+                let state = &self.tabs[idx].scatter_plot_state;
+                if is_left {
+                    state.left.filters.as_slice()
+                } else {
+                    state.right.filters.as_slice()
+                }
+            })
+            .unwrap_or_default();
+        let resolved: Vec<(&Condition, usize, &str)> = filters
+            .iter()
+            .filter_map(|cond| {
+                let idx = self.find_channel_by_name(file_idx, &cond.channel)?;
+                Some((cond, idx, log.channels[idx].unit()))
+            })
+            .collect();
+
+        let kept: Vec<usize> = range
+            .filter(|&record| {
+                resolved.iter().all(|(cond, idx, unit)| {
+                    log.value_at(*idx, record).is_some_and(|v| {
+                        let value = self.active_unit_preferences().convert_value(v, unit).0;
+                        cond.comparison.matches(value, cond.threshold)
+                    })
+                })
+            })
+            .collect();
+
+        channels
+            .iter()
+            .map(|&idx| {
+                let column = log.get_channel_data(idx);
+                kept.iter()
+                    .map(|&record| column.get(record).copied().unwrap_or(f64::NAN))
+                    .collect()
+            })
+            .collect()
+    }
+
     /// Render a single scatter plot panel with controls
     fn render_scatter_plot_panel(&mut self, ui: &mut egui::Ui, is_left: bool) {
         let Some(tab_idx) = self.active_tab else {
@@ -139,8 +275,13 @@ impl UltraLogApp {
         let x_name = get_name(config.x_channel);
         let y_name = get_name(config.y_channel);
 
-        // Always show "vs Hits" since that's the computed density
-        format!("{} vs {} vs Hits", y_name, x_name)
+        match config.view_mode {
+            // Heatmap Z is always the computed density
+            ScatterViewMode::Heatmap => format!("{} vs {} vs Hits", y_name, x_name),
+            ScatterViewMode::Surface => {
+                format!("{} vs {} vs {}", y_name, x_name, get_name(config.z_channel))
+            }
+        }
     }
 
     /// Render axis selector dropdowns
@@ -151,21 +292,18 @@ impl UltraLogApp {
 
         // Get values upfront to avoid borrow issues
         let tab_file_index = self.tabs[tab_idx].file_index;
-        let (file_idx, current_x, current_y) = if is_left {
-            let config = &self.tabs[tab_idx].scatter_plot_state.left;
-            (
-                config.file_index.unwrap_or(tab_file_index),
-                config.x_channel,
-                config.y_channel,
-            )
+        let config = if is_left {
+            &self.tabs[tab_idx].scatter_plot_state.left
         } else {
-            let config = &self.tabs[tab_idx].scatter_plot_state.right;
-            (
-                config.file_index.unwrap_or(tab_file_index),
-                config.x_channel,
-                config.y_channel,
-            )
+            &self.tabs[tab_idx].scatter_plot_state.right
         };
+        let (file_idx, current_x, current_y, current_z, view_mode) = (
+            config.file_index.unwrap_or(tab_file_index),
+            config.x_channel,
+            config.y_channel,
+            config.z_channel,
+            config.view_mode,
+        );
 
         if file_idx >= self.files.len() {
             return;
@@ -190,6 +328,19 @@ impl UltraLogApp {
         // Track which channel was selected
         let mut new_x_channel: Option<usize> = None;
         let mut new_y_channel: Option<usize> = None;
+        let mut new_z_channel: Option<usize> = None;
+        let mut new_view_mode = view_mode;
+        let mut export_clicked = false;
+        let mut binning = [config.x_binning.clone(), config.y_binning.clone()];
+        let mut filters = config.filters.clone();
+        let filter_found: Vec<bool> = filters
+            .iter()
+            .map(|f| self.find_channel_by_name(file_idx, &f.channel).is_some())
+            .collect();
+        let filter_channels: Vec<String> = sorted_channels
+            .iter()
+            .map(|(_, name, _)| name.clone())
+            .collect();
 
         ui.horizontal(|ui| {
             // X Axis selector
@@ -230,9 +381,81 @@ impl UltraLogApp {
 
             ui.add_space(16.0);
 
-            // Z Axis is always "Hits" (density)
             ui.label("Z Axis:");
-            ui.label(egui::RichText::new("Hits").color(egui::Color32::from_rgb(150, 150, 150)));
+            match view_mode {
+                // Heatmap Z is always "Hits" (density)
+                ScatterViewMode::Heatmap => {
+                    ui.label(
+                        egui::RichText::new("Hits").color(egui::Color32::from_rgb(150, 150, 150)),
+                    );
+                }
+                // Surface Z is the average of a selected channel
+                ScatterViewMode::Surface => {
+                    egui::ComboBox::from_id_salt(if is_left { "left_z" } else { "right_z" })
+                        .selected_text(
+                            current_z
+                                .and_then(|i| channel_names.get(&i).map(|n| n.as_str()))
+                                .unwrap_or("Select..."),
+                        )
+                        .width(140.0)
+                        .show_ui(ui, |ui| {
+                            for (idx, name, _is_normalized) in &sorted_channels {
+                                if ui.selectable_label(current_z == Some(*idx), name).clicked() {
+                                    new_z_channel = Some(*idx);
+                                }
+                            }
+                        });
+                }
+            }
+
+            ui.add_space(16.0);
+
+            // View mode toggle
+            ui.selectable_value(&mut new_view_mode, ScatterViewMode::Heatmap, "Heatmap");
+            ui.selectable_value(&mut new_view_mode, ScatterViewMode::Surface, "3D Surface");
+
+            ui.add_space(16.0);
+
+            ui.menu_button("Bins...", |ui| {
+                Self::render_binning_editor(ui, "X", &mut binning[0]);
+                ui.separator();
+                Self::render_binning_editor(ui, "Y", &mut binning[1]);
+            })
+            .response
+            .on_hover_text("Cell bins used by the 3D surface and CSV export");
+
+            let filter_label = if filters.is_empty() {
+                "Filters...".to_string()
+            } else {
+                format!("Filters ({})...", filters.len())
+            };
+            ui.menu_button(filter_label, |ui| {
+                Self::render_filter_editor(
+                    ui,
+                    if is_left { "left" } else { "right" },
+                    &mut filters,
+                    &filter_found,
+                    &filter_channels,
+                );
+            })
+            .response
+            .on_hover_text("Only include samples matching every condition");
+
+            let export_hint = if view_mode == ScatterViewMode::Surface {
+                "Export per-cell average, min, max and hit count as CSV"
+            } else {
+                "Export per-cell hit counts as CSV (select a Z channel in 3D Surface mode to include average, min and max)"
+            };
+            if ui
+                .add_enabled(
+                    current_x.is_some() && current_y.is_some(),
+                    egui::Button::new("Export CSV..."),
+                )
+                .on_hover_text(export_hint)
+                .clicked()
+            {
+                export_clicked = true;
+            }
         });
 
         // Apply channel updates after UI is rendered
@@ -250,6 +473,193 @@ impl UltraLogApp {
                 self.tabs[tab_idx].scatter_plot_state.right.y_channel = Some(y);
             }
         }
+        let config = if is_left {
+            &mut self.tabs[tab_idx].scatter_plot_state.left
+        } else {
+            &mut self.tabs[tab_idx].scatter_plot_state.right
+        };
+        if let Some(z) = new_z_channel {
+            config.z_channel = Some(z);
+        }
+        config.view_mode = new_view_mode;
+        let [x_binning, y_binning] = binning;
+        config.x_binning = x_binning;
+        config.y_binning = y_binning;
+        config.filters = filters;
+
+        if export_clicked {
+            self.export_scatter_cells_csv(is_left);
+        }
+    }
+
+    /// Render the bin count and breakpoint editor for one axis
+    fn render_binning_editor(ui: &mut egui::Ui, axis: &str, binning: &mut ScatterAxisBinning) {
+        ui.label(egui::RichText::new(format!("{} Axis", axis)).strong());
+        ui.horizontal(|ui| {
+            ui.label("Bins:");
+            ui.add_enabled(
+                binning.breakpoints.is_empty(),
+                egui::DragValue::new(&mut binning.bin_count).range(2..=64),
+            );
+        });
+        ui.label("Breakpoints:");
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut binning.breakpoints_text)
+                .hint_text("auto, e.g. 500, 1000, 1500")
+                .desired_width(220.0),
+        );
+        match parse_breakpoints(&binning.breakpoints_text) {
+            Ok(breakpoints) => {
+                if response.changed() {
+                    binning.breakpoints = breakpoints;
+                }
+            }
+            Err(e) => {
+                ui.colored_label(egui::Color32::from_rgb(255, 120, 120), e);
+            }
+        }
+    }
+
+    /// Render the sample filter conditions for one plot
+    fn render_filter_editor(
+        ui: &mut egui::Ui,
+        id: &str,
+        filters: &mut Vec<Condition>,
+        found: &[bool],
+        channel_names: &[String],
+    ) {
+        ui.label(egui::RichText::new("Include samples where").strong());
+        ui.label(
+            egui::RichText::new("Thresholds are in display units")
+                .small()
+                .color(egui::Color32::GRAY),
+        );
+        ui.add_space(4.0);
+
+        let mut remove = None;
+        for (i, filter) in filters.iter_mut().enumerate() {
+            ui.horizontal(|ui| {
+                egui::ComboBox::from_id_salt(format!("{}_filter_channel_{}", id, i))
+                    .selected_text(if filter.channel.is_empty() {
+                        "Select..."
+                    } else {
+                        filter.channel.as_str()
+                    })
+                    .width(140.0)
+                    .show_ui(ui, |ui| {
+                        for name in channel_names {
+                            if ui.selectable_label(filter.channel == *name, name).clicked() {
+                                filter.channel = name.clone();
+                            }
+                        }
+                    });
+                egui::ComboBox::from_id_salt(format!("{}_filter_op_{}", id, i))
+                    .selected_text(filter.comparison.symbol())
+                    .width(40.0)
+                    .show_ui(ui, |ui| {
+                        for op in Comparison::ALL {
+                            ui.selectable_value(&mut filter.comparison, op, op.symbol());
+                        }
+                    });
+                ui.add(egui::DragValue::new(&mut filter.threshold).speed(0.5));
+                if !found.get(i).copied().unwrap_or(true) && !filter.channel.is_empty() {
+                    ui.colored_label(egui::Color32::from_rgb(255, 180, 0), "⚠")
+                        .on_hover_text("Channel not in this log - filter ignored");
+                }
+                if ui.small_button("🗑").clicked() {
+                    remove = Some(i);
+                }
+            });
+        }
+        if let Some(i) = remove {
+            filters.remove(i);
+        }
+
+        ui.add_space(4.0);
+        if ui.button("➕ Add Condition").clicked() {
+            filters.push(Condition::default());
+        }
+    }
+
+    /// Export the binned X/Y grid with per-cell statistics to a CSV file
+    fn export_scatter_cells_csv(&mut self, is_left: bool) {
+        let Some(tab_idx) = self.active_tab else {
+            return;
+        };
+        let config = if is_left {
+            &self.tabs[tab_idx].scatter_plot_state.left
+        } else {
+            &self.tabs[tab_idx].scatter_plot_state.right
+        };
+        let file_idx = config.file_index.unwrap_or(self.tabs[tab_idx].file_index);
+        let (Some(x_idx), Some(y_idx)) = (config.x_channel, config.y_channel) else {
+            return;
+        };
+        // Heatmap mode has no Z channel - only hit counts are exported
+        let z_idx = match config.view_mode {
+            ScatterViewMode::Heatmap => None,
+            ScatterViewMode::Surface => config.z_channel,
+        };
+        if file_idx >= self.files.len() {
+            return;
+        }
+
+        let file = &self.files[file_idx];
+        let channel_name = |idx: usize| {
+            let name = file.log.channels[idx].name();
+            if self.field_normalization {
+                normalize_channel_name_with_custom(&name, Some(&self.custom_normalizations))
+            } else {
+                name
+            }
+        };
+        let x_name = channel_name(x_idx);
+        let y_name = channel_name(y_idx);
+        let z_name = z_idx.map(channel_name);
+
+        let channels: Vec<usize> = [x_idx, y_idx].into_iter().chain(z_idx).collect();
+        let mut columns = self
+            .scatter_samples(is_left, file_idx, &channels)
+            .into_iter();
+        let (x_data, y_data, z_data) = (
+            columns.next().unwrap_or_default(),
+            columns.next().unwrap_or_default(),
+            columns.next(),
+        );
+
+        let range = |data: &[f64]| {
+            data.iter()
+                .filter(|v| v.is_finite())
+                .fold((f64::MAX, f64::MIN), |(lo, hi), &v| (lo.min(v), hi.max(v)))
+        };
+        let (x_min, x_max) = range(&x_data);
+        let (y_min, y_max) = range(&y_data);
+        if x_min > x_max || y_min > y_max {
+            self.show_toast_error("No data to export");
+            return;
+        }
+
+        let grid = BinnedGrid::build(
+            config.x_binning.bins(x_min, x_max),
+            config.y_binning.bins(y_min, y_max),
+            &x_data,
+            &y_data,
+            z_data.as_deref(),
+        );
+        let csv = grid.to_csv(&x_name, &y_name, z_name.as_deref());
+
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("CSV", &["csv"])
+            .set_file_name("ultralog_cells.csv")
+            .save_file()
+        else {
+            return;
+        };
+
+        match std::fs::write(&path, csv) {
+            Ok(_) => self.show_toast_success("Cell statistics exported as CSV"),
+            Err(e) => self.show_toast_error(&format!("Export failed: {}", e)),
+        }
     }
 
     /// Render the actual heatmap chart
@@ -288,11 +698,30 @@ impl UltraLogApp {
             return;
         }
 
-        let file = &self.files[file_idx];
-        let x_data = file.log.get_channel_data(x_idx);
-        let y_data = file.log.get_channel_data(y_idx);
+        if config.view_mode == ScatterViewMode::Surface {
+            let z_idx = config.z_channel;
+            self.render_scatter_surface(ui, is_left, file_idx, x_idx, y_idx, z_idx);
+            return;
+        }
+
+        let mut columns = self
+            .scatter_samples(is_left, file_idx, &[x_idx, y_idx])
+            .into_iter();
+        let (x_data, y_data) = (
+            columns.next().unwrap_or_default(),
+            columns.next().unwrap_or_default(),
+        );
 
         if x_data.is_empty() || y_data.is_empty() || x_data.len() != y_data.len() {
+            let available = ui.available_size();
+            let (rect, _) = ui.allocate_exact_size(available, egui::Sense::hover());
+            ui.painter().text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                "No samples in the selected time window",
+                egui::FontId::proportional(16.0),
+                egui::Color32::GRAY,
+            );
             return;
         }
 
@@ -318,6 +747,9 @@ impl UltraLogApp {
         let mut max_hits: u32 = 0;
 
         for (&x, &y) in x_data.iter().zip(y_data.iter()) {
+            if !x.is_finite() || !y.is_finite() {
+                continue;
+            }
             let x_bin = (((x - x_min) / x_range) * (HEATMAP_BINS - 1) as f64).round() as usize;
             let y_bin = (((y - y_min) / y_range) * (HEATMAP_BINS - 1) as f64).round() as usize;
 
@@ -352,9 +784,8 @@ impl UltraLogApp {
         let cell_height = plot_rect.height() / HEATMAP_BINS as f32;
 
         // Draw heatmap cells
-        for y_bin in 0..HEATMAP_BINS {
-            for x_bin in 0..HEATMAP_BINS {
-                let hits = histogram[y_bin][x_bin];
+        for (y_bin, row) in histogram.iter().enumerate() {
+            for (x_bin, &hits) in row.iter().enumerate() {
                 if hits > 0 {
                     // Normalize hits to 0-1 using log scale for better visualization
                     let normalized = if max_hits > 1 {
@@ -433,6 +864,43 @@ impl UltraLogApp {
             );
         }
 
+        // Breakpoint grid lines, so cells can be matched to ECU table cells
+        let config = if is_left {
+            &self.tabs[tab_idx].scatter_plot_state.left
+        } else {
+            &self.tabs[tab_idx].scatter_plot_state.right
+        };
+        let breakpoint_stroke = egui::Stroke::new(
+            1.0,
+            egui::Color32::from_rgba_unmultiplied(255, 255, 255, 90),
+        );
+        for &x in &config.x_binning.breakpoints {
+            let t = ((x - x_min) / x_range) as f32;
+            if (0.0..=1.0).contains(&t) {
+                let x_pos = plot_rect.left() + t * plot_rect.width();
+                painter.line_segment(
+                    [
+                        egui::pos2(x_pos, plot_rect.top()),
+                        egui::pos2(x_pos, plot_rect.bottom()),
+                    ],
+                    breakpoint_stroke,
+                );
+            }
+        }
+        for &y in &config.y_binning.breakpoints {
+            let t = ((y - y_min) / y_range) as f32;
+            if (0.0..=1.0).contains(&t) {
+                let y_pos = plot_rect.bottom() - t * plot_rect.height();
+                painter.line_segment(
+                    [
+                        egui::pos2(plot_rect.left(), y_pos),
+                        egui::pos2(plot_rect.right(), y_pos),
+                    ],
+                    breakpoint_stroke,
+                );
+            }
+        }
+
         // Get mutable config for click handling
         let config = if is_left {
             &mut self.tabs[tab_idx].scatter_plot_state.left
@@ -445,7 +913,7 @@ impl UltraLogApp {
             let sel_rel_x = ((selected.x_value - x_min) / x_range) as f32;
             let sel_rel_y = ((selected.y_value - y_min) / y_range) as f32;
 
-            if sel_rel_x >= 0.0 && sel_rel_x <= 1.0 && sel_rel_y >= 0.0 && sel_rel_y <= 1.0 {
+            if (0.0..=1.0).contains(&sel_rel_x) && (0.0..=1.0).contains(&sel_rel_y) {
                 let sel_x = plot_rect.left() + sel_rel_x * plot_rect.width();
                 let sel_y = plot_rect.bottom() - sel_rel_y * plot_rect.height();
 
@@ -478,7 +946,7 @@ impl UltraLogApp {
                 let rel_x = (pos.x - plot_rect.left()) / plot_rect.width();
                 let rel_y = 1.0 - (pos.y - plot_rect.top()) / plot_rect.height();
 
-                if rel_x >= 0.0 && rel_x <= 1.0 && rel_y >= 0.0 && rel_y <= 1.0 {
+                if (0.0..=1.0).contains(&rel_x) && (0.0..=1.0).contains(&rel_y) {
                     let x_val = x_min + rel_x as f64 * x_range;
                     let y_val = y_min + rel_y as f64 * y_range;
 
@@ -532,7 +1000,7 @@ impl UltraLogApp {
     }
 
     /// Get a color from the heat map gradient based on normalized value (0-1)
-    fn get_heat_color(normalized: f64) -> egui::Color32 {
+    pub fn get_heat_color(normalized: f64) -> egui::Color32 {
         let t = normalized.clamp(0.0, 1.0);
         let scaled = t * (HEAT_COLORS.len() - 1) as f64;
         let idx = scaled.floor() as usize;
@@ -554,6 +1022,7 @@ impl UltraLogApp {
     }
 
     /// Render the heatmap legend with color scale and selected point info
+    #[allow(clippy::too_many_arguments)]
     fn render_heatmap_legend(
         &mut self,
         ui: &mut egui::Ui,
@@ -0,0 +1,287 @@
+//! 3D surface rendering for scatter plot data.
+//!
+//! Bins X/Y into a coarse grid, averages the Z channel per cell, fills empty
+//! cells from their neighbours, and draws the result as a shaded surface that
+//! can be rotated by dragging.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::binning::BinnedGrid;
+use crate::normalize::normalize_channel_name_with_custom;
+use crate::state::SurfaceCamera;
+
+/// Height reserved for controls and legend around the surface
+const SURFACE_CHROME_HEIGHT: f32 = 60.0;
+
+/// Radians of rotation per pixel dragged
+const ROTATE_SPEED: f32 = 0.01;
+
+/// Vertical half-height of the surface in normalized model space
+const Z_SCALE: f32 = 0.7;
+
+impl UltraLogApp {
+    /// Render the 3D surface view for a scatter plot panel
+    pub fn render_scatter_surface(
+        &mut self,
+        ui: &mut egui::Ui,
+        is_left: bool,
+        file_idx: usize,
+        x_idx: usize,
+        y_idx: usize,
+        z_idx: Option<usize>,
+    ) {
+        let Some(tab_idx) = self.active_tab else {
+            return;
+        };
+
+        let Some(z_idx) = z_idx else {
+            let available = ui.available_size();
+            let (rect, _) = ui.allocate_exact_size(available, egui::Sense::hover());
+            ui.painter().text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                "Select a Z axis channel",
+                egui::FontId::proportional(16.0),
+                egui::Color32::GRAY,
+            );
+            return;
+        };
+
+        let file = &self.files[file_idx];
+        let mut columns = self
+            .scatter_samples(is_left, file_idx, &[x_idx, y_idx, z_idx])
+            .into_iter();
+        let (x_data, y_data, z_data) = (
+            columns.next().unwrap_or_default(),
+            columns.next().unwrap_or_default(),
+            columns.next().unwrap_or_default(),
+        );
+
+        if x_data.is_empty() || y_data.is_empty() || z_data.is_empty() {
+            let available = ui.available_size();
+            let (rect, _) = ui.allocate_exact_size(available, egui::Sense::hover());
+            ui.painter().text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                "No samples in the selected time window",
+                egui::FontId::proportional(16.0),
+                egui::Color32::GRAY,
+            );
+            return;
+        }
+
+        let channel_name = |idx: usize| {
+            let name = file.log.channels[idx].name();
+            if self.field_normalization {
+                normalize_channel_name_with_custom(&name, Some(&self.custom_normalizations))
+            } else {
+                name
+            }
+        };
+        let axis_names = [
+            channel_name(x_idx),
+            channel_name(y_idx),
+            channel_name(z_idx),
+        ];
+
+        let range = |data: &[f64]| {
+            data.iter()
+                .filter(|v| v.is_finite())
+                .fold((f64::MAX, f64::MIN), |(lo, hi), &v| (lo.min(v), hi.max(v)))
+        };
+        let (x_min, x_max) = range(&x_data);
+        let (y_min, y_max) = range(&y_data);
+
+        let config = if is_left {
+            &self.tabs[tab_idx].scatter_plot_state.left
+        } else {
+            &self.tabs[tab_idx].scatter_plot_state.right
+        };
+        let grid = BinnedGrid::build(
+            config.x_binning.bins(x_min, x_max),
+            config.y_binning.bins(y_min, y_max),
+            &x_data,
+            &y_data,
+            Some(&z_data),
+        );
+        let values = grid.interpolated_means();
+        let Some((z_min, z_max)) = grid.mean_range() else {
+            return;
+        };
+        let z_span = if (z_max - z_min).abs() < f64::EPSILON {
+            1.0
+        } else {
+            z_max - z_min
+        };
+
+        let config = if is_left {
+            &mut self.tabs[tab_idx].scatter_plot_state.left
+        } else {
+            &mut self.tabs[tab_idx].scatter_plot_state.right
+        };
+
+        // Controls
+        ui.horizontal(|ui| {
+            ui.label(
+                egui::RichText::new("Drag to rotate").color(egui::Color32::from_rgb(150, 150, 150)),
+            );
+            if ui.small_button("Reset View").clicked() {
+                config.surface_camera = SurfaceCamera::default();
+            }
+        });
+
+        let available = ui.available_size();
+        let size = egui::vec2(
+            available.x,
+            (available.y - SURFACE_CHROME_HEIGHT).max(100.0),
+        );
+        let (rect, response) = ui.allocate_exact_size(size, egui::Sense::drag());
+
+        if response.dragged() {
+            let delta = response.drag_delta();
+            let camera = &mut config.surface_camera;
+            camera.yaw += delta.x * ROTATE_SPEED;
+            camera.pitch = (camera.pitch + delta.y * ROTATE_SPEED).clamp(0.05, 1.5);
+        }
+        let camera = config.surface_camera;
+
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 0.0, egui::Color32::BLACK);
+
+        let (nx, ny) = (grid.x.len(), grid.y.len());
+        if nx < 2 || ny < 2 {
+            return;
+        }
+
+        // Model space: X/Y in [-1, 1], Z in [-Z_SCALE, Z_SCALE]
+        let model = |xi: usize, yi: usize| -> [f32; 3] {
+            let x = xi as f32 / (nx - 1) as f32 * 2.0 - 1.0;
+            let y = yi as f32 / (ny - 1) as f32 * 2.0 - 1.0;
+            let z = values[yi * nx + xi]
+                .map(|v| ((v - z_min) / z_span) as f32 * 2.0 - 1.0)
+                .unwrap_or(-1.0)
+                * Z_SCALE;
+            [x, y, z]
+        };
+
+        let scale = rect.width().min(rect.height()) * 0.35;
+        let center = rect.center();
+        let (sin_yaw, cos_yaw) = camera.yaw.sin_cos();
+        let (sin_pitch, cos_pitch) = camera.pitch.sin_cos();
+
+        // Returns screen position and depth (larger = farther away)
+        let project = |p: [f32; 3]| -> (egui::Pos2, f32) {
+            let x = p[0] * cos_yaw - p[1] * sin_yaw;
+            let y = p[0] * sin_yaw + p[1] * cos_yaw;
+            let depth = y * cos_pitch - p[2] * sin_pitch;
+            let up = y * sin_pitch + p[2] * cos_pitch;
+            (
+                egui::pos2(center.x + x * scale, center.y - up * scale),
+                depth,
+            )
+        };
+
+        // Base frame and axes
+        let axis_color = egui::Color32::from_rgb(90, 90, 90);
+        let base = [
+            [-1.0, -1.0, -Z_SCALE],
+            [1.0, -1.0, -Z_SCALE],
+            [1.0, 1.0, -Z_SCALE],
+            [-1.0, 1.0, -Z_SCALE],
+        ];
+        for i in 0..4 {
+            let (a, _) = project(base[i]);
+            let (b, _) = project(base[(i + 1) % 4]);
+            painter.line_segment([a, b], egui::Stroke::new(1.0, axis_color));
+        }
+        let (z_top, _) = project([-1.0, -1.0, Z_SCALE]);
+        let (z_base, _) = project(base[0]);
+        painter.line_segment([z_base, z_top], egui::Stroke::new(1.0, axis_color));
+
+        // Collect quads and sort back-to-front
+        let mut quads: Vec<([egui::Pos2; 4], f32, f64)> = Vec::with_capacity((nx - 1) * (ny - 1));
+        for yi in 0..ny - 1 {
+            for xi in 0..nx - 1 {
+                let corners = [(xi, yi), (xi + 1, yi), (xi + 1, yi + 1), (xi, yi + 1)];
+                let mut points = [egui::Pos2::ZERO; 4];
+                let mut depth = 0.0;
+                let mut z_sum = 0.0;
+                for (k, &(cx, cy)) in corners.iter().enumerate() {
+                    let (pos, d) = project(model(cx, cy));
+                    points[k] = pos;
+                    depth += d;
+                    z_sum += values[cy * nx + cx].unwrap_or(z_min);
+                }
+                quads.push((points, depth / 4.0, (z_sum / 4.0 - z_min) / z_span));
+            }
+        }
+        quads.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        for (points, _, normalized) in quads {
+            painter.add(egui::Shape::convex_polygon(
+                points.to_vec(),
+                Self::get_heat_color(normalized),
+                egui::Stroke::new(0.5, egui::Color32::from_black_alpha(120)),
+            ));
+        }
+
+        // Axis labels show the bin center range (breakpoints when configured)
+        let (x_centers, y_centers) = (grid.x.centers(), grid.y.centers());
+        let text_color = egui::Color32::from_rgb(200, 200, 200);
+        let font = egui::FontId::proportional(11.0);
+        let label_at = |p: [f32; 3], text: String| {
+            let (pos, _) = project(p);
+            painter.text(
+                pos,
+                egui::Align2::CENTER_CENTER,
+                text,
+                font.clone(),
+                text_color,
+            );
+        };
+        label_at(
+            [0.0, -1.25, -Z_SCALE],
+            format!(
+                "{} ({:.0}–{:.0})",
+                axis_names[0],
+                x_centers[0],
+                x_centers[nx - 1]
+            ),
+        );
+        label_at(
+            [-1.25, 0.0, -Z_SCALE],
+            format!(
+                "{} ({:.0}–{:.0})",
+                axis_names[1],
+                y_centers[0],
+                y_centers[ny - 1]
+            ),
+        );
+        label_at([-1.0, -1.0, Z_SCALE + 0.15], axis_names[2].clone());
+
+        // Color legend for Z
+        ui.add_space(6.0);
+        ui.horizontal(|ui| {
+            ui.label(format!("{:.2}", z_min));
+            let (bar, _) = ui.allocate_exact_size(egui::vec2(200.0, 12.0), egui::Sense::hover());
+            let steps = 50;
+            let step_width = bar.width() / steps as f32;
+            for s in 0..steps {
+                ui.painter().rect_filled(
+                    egui::Rect::from_min_size(
+                        egui::pos2(bar.left() + s as f32 * step_width, bar.top()),
+                        egui::vec2(step_width + 0.5, bar.height()),
+                    ),
+                    0.0,
+                    Self::get_heat_color(s as f64 / (steps - 1) as f64),
+                );
+            }
+            ui.label(format!("{:.2}", z_max));
+            ui.label(
+                egui::RichText::new(format!("Average {}", axis_names[2]))
+                    .color(egui::Color32::from_rgb(150, 150, 150)),
+            );
+        });
+    }
+}
@@ -0,0 +1,347 @@
+//! Preferences window.
+//!
+//! Application preferences that are saved between sessions, grouped into
+//! categories listed down the left of the window.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::decimate::MIN_RATE_HZ;
+use crate::i18n::Language;
+use crate::state::{
+    SettingsCategory, Theme, MAX_CHANNELS_LIMIT, MAX_CHART_POINTS_LIMIT, MAX_LINE_WIDTH_SCALE,
+    MIN_CHART_POINTS, MIN_LINE_WIDTH_SCALE,
+};
+use crate::updater::UpdateChannel;
+
+/// UI scale choices, as fractions of the monitor's scale factor
+const UI_SCALE_PRESETS: [f32; 6] = [0.75, 1.0, 1.25, 1.5, 1.75, 2.0];
+
+impl UltraLogApp {
+    /// Render the preferences window
+    pub fn render_settings_window(&mut self, ctx: &egui::Context) {
+        if !self.show_settings_window {
+            return;
+        }
+
+        let lang = self.language;
+        let mut open = true;
+
+        egui::Window::new(lang.tr("Preferences"))
+            .id(egui::Id::new("preferences_window"))
+            .open(&mut open)
+            .resizable(true)
+            .collapsible(false)
+            .default_width(520.0)
+            .default_height(360.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.horizontal_top(|ui| {
+                    ui.vertical(|ui| {
+                        ui.set_width(110.0);
+                        for category in SettingsCategory::ALL {
+                            ui.selectable_value(
+                                &mut self.settings_category,
+                                category,
+                                lang.tr(category.name()),
+                            );
+                        }
+                    });
+                    ui.separator();
+                    ui.vertical(|ui| match self.settings_category {
+                        SettingsCategory::General => self.render_general_settings(ui),
+                        SettingsCategory::Display => self.render_display_settings(ui),
+                        SettingsCategory::Accessibility => self.render_accessibility_settings(ui),
+                        SettingsCategory::Units => self.render_units_settings(ui),
+                        SettingsCategory::Performance => self.render_performance_settings(ui),
+                    });
+                });
+            });
+
+        if !open {
+            self.show_settings_window = false;
+        }
+    }
+
+    fn render_general_settings(&mut self, ui: &mut egui::Ui) {
+        let lang = self.language;
+        ui.horizontal(|ui| {
+            ui.label(lang.tr("Language:"));
+            egui::ComboBox::from_id_salt("language")
+                .selected_text(self.language.name())
+                .show_ui(ui, |ui| {
+                    for language in Language::ALL {
+                        ui.selectable_value(&mut self.language, language, language.name());
+                    }
+                });
+        });
+        ui.add_space(8.0);
+        ui.checkbox(
+            &mut self.auto_check_updates,
+            lang.tr("Check for updates on startup"),
+        )
+        .on_hover_text(lang.tr("Automatically check for new versions when the app starts"));
+        ui.horizontal(|ui| {
+            ui.label(lang.tr("Update channel:"));
+            egui::ComboBox::from_id_salt("update_channel")
+                .selected_text(lang.tr(self.update_channel.name()))
+                .show_ui(ui, |ui| {
+                    for channel in [UpdateChannel::Stable, UpdateChannel::Beta] {
+                        ui.selectable_value(
+                            &mut self.update_channel,
+                            channel,
+                            lang.tr(channel.name()),
+                        );
+                    }
+                })
+                .response
+                .on_hover_text(lang.tr("Beta also offers prereleases, which may be less stable"));
+        });
+        ui.horizontal(|ui| {
+            ui.label(lang.tr("Update proxy:"));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.update_proxy)
+                    .hint_text("http://host:port")
+                    .desired_width(220.0),
+            )
+            .on_hover_text(lang.tr(
+                "Proxy for update checks and downloads. Leave blank to use the \
+                 HTTPS_PROXY environment variable, if set.",
+            ));
+        });
+        if !self.skipped_versions.is_empty() {
+            ui.horizontal(|ui| {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} {}",
+                        lang.tr("Skipped versions:"),
+                        self.skipped_versions.join(", ")
+                    ))
+                    .color(egui::Color32::GRAY),
+                );
+                if ui.small_button(lang.tr("Clear")).clicked() {
+                    self.skipped_versions.clear();
+                }
+            });
+        }
+
+        ui.add_space(8.0);
+        if let Some(dir) = crate::data_dir::data_dir() {
+            let mode = if crate::data_dir::is_portable() {
+                lang.tr("Portable mode: data is kept next to UltraLog")
+            } else {
+                lang.tr("Data folder")
+            };
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(mode).color(egui::Color32::GRAY));
+                if ui
+                    .small_button(lang.tr("Open"))
+                    .on_hover_text(dir.display().to_string())
+                    .clicked()
+                {
+                    let _ = std::fs::create_dir_all(&dir);
+                    let _ = open::that(&dir);
+                }
+            });
+        }
+
+        ui.add_space(8.0);
+        ui.checkbox(&mut self.cache_parsed_logs, lang.tr("Cache parsed logs"))
+            .on_hover_text(
+                lang.tr("Keep a copy of parsed CSV logs on disk so they reopen in moments"),
+            );
+        if ui.button(lang.tr("Clear Log Cache")).clicked() {
+            self.clear_log_cache();
+        }
+    }
+
+    fn render_display_settings(&mut self, ui: &mut egui::Ui) {
+        let lang = self.language;
+        let mut new_scale = None;
+
+        egui::Grid::new("display_settings_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                ui.label(lang.tr("Theme:"));
+                egui::ComboBox::from_id_salt("theme")
+                    .selected_text(lang.tr(self.theme.name()))
+                    .show_ui(ui, |ui| {
+                        for theme in [Theme::Dark, Theme::Light] {
+                            ui.selectable_value(&mut self.theme, theme, lang.tr(theme.name()));
+                        }
+                    });
+                ui.end_row();
+
+                ui.label(lang.tr("UI scale:"));
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_salt("ui_scale")
+                        .selected_text(format!("{:.0}%", self.ui_scale * 100.0))
+                        .show_ui(ui, |ui| {
+                            for scale in UI_SCALE_PRESETS {
+                                let selected = (self.ui_scale - scale).abs() < 0.01;
+                                if ui
+                                    .selectable_label(selected, format!("{:.0}%", scale * 100.0))
+                                    .clicked()
+                                {
+                                    new_scale = Some(scale);
+                                }
+                            }
+                        });
+                    if ui.button(lang.tr("Reset")).clicked() {
+                        new_scale = Some(1.0);
+                    }
+                });
+                ui.end_row();
+
+                ui.label(lang.tr("Initial chart window:"));
+                ui.add(
+                    egui::DragValue::new(&mut self.initial_view_seconds)
+                        .range(5.0..=3600.0)
+                        .speed(1.0)
+                        .suffix(" s"),
+                )
+                .on_hover_text(lang.tr("Seconds shown when a log's chart is first drawn"));
+                ui.end_row();
+            });
+
+        ui.label(
+            egui::RichText::new(lang.tr(
+                "UI scale is applied on top of each monitor's own scaling. Ctrl +/- also zoom.",
+            ))
+            .small()
+            .color(egui::Color32::GRAY),
+        );
+
+        if let Some(scale) = new_scale {
+            self.ui_scale = scale;
+            ui.ctx().set_zoom_factor(scale);
+        }
+    }
+
+    fn render_accessibility_settings(&mut self, ui: &mut egui::Ui) {
+        let lang = self.language;
+        ui.checkbox(
+            &mut self.color_blind_mode,
+            lang.tr("Colorblind-friendly palette"),
+        );
+        ui.checkbox(&mut self.high_contrast, lang.tr("High contrast"))
+            .on_hover_text(
+                lang.tr(
+                    "Stronger text and outlines, with a thick outline around the focused control",
+                ),
+            );
+        ui.add_space(8.0);
+        ui.horizontal(|ui| {
+            ui.label(lang.tr("Line thickness:"));
+            ui.add(
+                egui::Slider::new(
+                    &mut self.line_width_scale,
+                    MIN_LINE_WIDTH_SCALE..=MAX_LINE_WIDTH_SCALE,
+                )
+                .step_by(0.25)
+                .suffix("x"),
+            )
+            .on_hover_text(lang.tr("Scales every chart line and the cursor"));
+        });
+        ui.add_space(8.0);
+        ui.label(
+            egui::RichText::new(lang.tr(
+                "Tab and Shift+Tab move between controls, and Space or Enter activates them. \
+                 In the channel list, the arrow keys move between channels.",
+            ))
+            .small()
+            .color(egui::Color32::GRAY),
+        );
+    }
+
+    fn render_units_settings(&mut self, ui: &mut egui::Ui) {
+        let lang = self.language;
+        ui.label(
+            egui::RichText::new(
+                lang.tr("Default units, used by every tab without its own override"),
+            )
+            .color(egui::Color32::GRAY),
+        );
+        ui.add_space(4.0);
+        Self::render_unit_submenus(ui, &mut self.unit_preferences, lang);
+    }
+
+    fn render_performance_settings(&mut self, ui: &mut egui::Ui) {
+        let lang = self.language;
+        let mut chart_points = self.max_chart_points;
+
+        egui::Grid::new("performance_settings_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                ui.label(lang.tr("Chart points per channel:"));
+                ui.add(
+                    egui::DragValue::new(&mut chart_points)
+                        .range(MIN_CHART_POINTS..=MAX_CHART_POINTS_LIMIT)
+                        .speed(50),
+                )
+                .on_hover_text(lang.tr("More points show finer detail but draw slower"));
+                ui.end_row();
+
+                ui.label(lang.tr("Channel limit:"));
+                ui.add(egui::DragValue::new(&mut self.max_channels).range(1..=MAX_CHANNELS_LIMIT))
+                    .on_hover_text(lang.tr(
+                        "Maximum channels per tab; more channels make large logs slower to draw",
+                    ));
+                ui.end_row();
+
+                ui.label(lang.tr("Memory-map MLG files over:"));
+                ui.add(
+                    egui::DragValue::new(&mut self.mmap_threshold_mb)
+                        .range(1..=4096)
+                        .suffix(" MB"),
+                )
+                .on_hover_text(lang.tr(
+                    "Larger files are decoded on demand, so they open quickly and use less memory",
+                ));
+                ui.end_row();
+
+                ui.checkbox(
+                    &mut self.decimate_on_import,
+                    lang.tr("Decimate logs faster than:"),
+                )
+                .on_hover_text(lang.tr(
+                    "Keep the lowest and highest value of each channel per window, so very \
+                         dense logs use less memory while spikes stay visible",
+                ));
+                ui.add_enabled(
+                    self.decimate_on_import,
+                    egui::DragValue::new(&mut self.decimate_rate_hz)
+                        .range(MIN_RATE_HZ..=1000.0)
+                        .suffix(" Hz"),
+                )
+                .on_hover_text(lang.tr("Applies to logs opened from now on"));
+                ui.end_row();
+
+                ui.label(lang.tr("Chart cache limit:"));
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::DragValue::new(&mut self.cache_budget_mb)
+                            .range(16..=8192)
+                            .suffix(" MB"),
+                    )
+                    .on_hover_text(lang.tr(
+                        "Above this, downsampled data for the channels drawn least recently is \
+                         dropped and rebuilt when they are shown again",
+                    ));
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{:.1} MB {}",
+                            self.chart_cache_bytes(None) as f64 / 1_048_576.0,
+                            lang.tr("in use")
+                        ))
+                        .color(egui::Color32::GRAY),
+                    );
+                });
+                ui.end_row();
+            });
+
+        self.set_max_chart_points(chart_points);
+    }
+}
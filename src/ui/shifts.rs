@@ -0,0 +1,251 @@
+//! Shift points window.
+//!
+//! Lists the upshifts in the active log with the RPM at each shift, the RPM
+//! drop into the next gear and how long RPM took to fall, averaged per gear.
+//! Shifts come from the normalized "Gear" channel when the log has one and
+//! from RPM alone otherwise, and can be marked on the chart.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::i18n::Language;
+use crate::shifts::{summarize, Shift};
+use crate::state::ShiftScan;
+
+impl UltraLogApp {
+    /// Shifts in the active file, found again when the file or thresholds
+    /// have changed
+    fn current_shift_scan(&mut self) -> Option<&ShiftScan> {
+        let file_index = self
+            .active_tab
+            .map(|idx| self.tabs[idx].file_index)
+            .filter(|&idx| idx < self.files.len())?;
+
+        let stale = self
+            .shift_scan
+            .as_ref()
+            .is_none_or(|scan| scan.file_index != file_index || scan.config != self.shift_config);
+        if stale {
+            let gear = self.find_channel_by_name(file_index, "Gear");
+            let shifts = self
+                .find_channel_by_name(file_index, "RPM")
+                .ok_or_else(|| "No RPM channel in this log".to_string())
+                .map(|rpm| {
+                    let log = &self.files[file_index].log;
+                    self.shift_config.detect(
+                        log.get_times_as_f64(),
+                        log.get_channel_data(rpm),
+                        gear.map(|channel| log.get_channel_data(channel)),
+                    )
+                });
+            self.shift_scan = Some(ShiftScan {
+                file_index,
+                config: self.shift_config.clone(),
+                from_gear_channel: gear.is_some(),
+                shifts,
+            });
+        }
+        self.shift_scan.as_ref()
+    }
+
+    /// (label, time) of each shift in the active tab, for marking on the
+    /// chart. Empty unless shift markers are turned on.
+    pub(crate) fn collect_shift_markers(&mut self) -> Vec<(String, f64)> {
+        if !self.show_shift_markers {
+            return Vec::new();
+        }
+        match self.current_shift_scan() {
+            Some(ShiftScan {
+                shifts: Ok(shifts), ..
+            }) => shifts
+                .iter()
+                .map(|shift| (shift.label(), shift.start_time))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Render the shift points window
+    pub fn render_shifts_window(&mut self, ctx: &egui::Context) {
+        if !self.show_shifts_window {
+            return;
+        }
+
+        let scan = self.current_shift_scan().cloned();
+        let mut config = self.shift_config.clone();
+        let mut show_markers = self.show_shift_markers;
+        let mut open = true;
+        let mut jump: Option<f64> = None;
+        let lang = self.language;
+
+        egui::Window::new(lang.tr("Shift Points"))
+            .open(&mut open)
+            .resizable(true)
+            .default_width(600.0)
+            .default_height(440.0)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(lang.tr("Ignore shifts below"));
+                    ui.add(
+                        egui::DragValue::new(&mut config.min_rpm)
+                            .range(0.0..=12000.0)
+                            .speed(50.0)
+                            .suffix(" RPM"),
+                    );
+                    ui.checkbox(&mut show_markers, lang.tr("Show on chart"));
+                });
+                let from_gear_channel = scan.as_ref().is_some_and(|s| s.from_gear_channel);
+                ui.add_enabled_ui(!from_gear_channel, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.label(lang.tr("Without a gear channel, RPM must drop"));
+                        ui.add(
+                            egui::DragValue::new(&mut config.min_rpm_drop)
+                                .range(50.0..=5000.0)
+                                .speed(10.0)
+                                .suffix(" RPM"),
+                        );
+                        ui.label(lang.tr("within"));
+                        ui.add(
+                            egui::DragValue::new(&mut config.max_duration)
+                                .range(0.05..=3.0)
+                                .speed(0.01)
+                                .suffix(" s"),
+                        );
+                    });
+                });
+                ui.add_space(4.0);
+                ui.separator();
+
+                match &scan {
+                    None => {
+                        ui.label(
+                            egui::RichText::new(lang.tr("Open a log file to find its shifts."))
+                                .color(egui::Color32::GRAY),
+                        );
+                    }
+                    Some(ShiftScan { shifts: Err(e), .. }) => {
+                        ui.colored_label(egui::Color32::from_rgb(253, 193, 73), format!("⚠ {}", e));
+                    }
+                    Some(ShiftScan {
+                        shifts: Ok(shifts),
+                        from_gear_channel,
+                        ..
+                    }) => {
+                        ui.label(
+                            egui::RichText::new(if *from_gear_channel {
+                                lang.tr("Shifts found from the Gear channel.")
+                            } else {
+                                lang.tr(
+                                    "No Gear channel in this log, so shifts are found from RPM drops.",
+                                )
+                            })
+                            .color(egui::Color32::GRAY),
+                        );
+                        ui.add_space(4.0);
+                        Self::render_shift_report(ui, shifts, &mut jump, lang);
+                    }
+                }
+            });
+
+        if config != self.shift_config {
+            self.shift_config = config;
+        }
+        self.show_shift_markers = show_markers;
+
+        if let Some(time) = jump {
+            self.move_cursor_to(time);
+        }
+
+        if !open {
+            self.show_shifts_window = false;
+        }
+    }
+
+    /// Render the per-gear averages and the list of shifts
+    fn render_shift_report(
+        ui: &mut egui::Ui,
+        shifts: &[Shift],
+        jump: &mut Option<f64>,
+        lang: Language,
+    ) {
+        if shifts.is_empty() {
+            ui.label(egui::RichText::new(lang.tr("No shifts found.")).color(egui::Color32::GRAY));
+            return;
+        }
+
+        ui.heading(lang.tr("Per Gear"));
+        egui::Grid::new("shift_gear_grid")
+            .striped(true)
+            .spacing([16.0, 6.0])
+            .show(ui, |ui| {
+                for header in [
+                    lang.tr("Gear"),
+                    lang.tr("Shifts"),
+                    lang.tr("Shift RPM"),
+                    lang.tr("RPM Drop"),
+                    lang.tr("Duration"),
+                ] {
+                    ui.label(egui::RichText::new(header).strong());
+                }
+                ui.end_row();
+
+                for summary in summarize(shifts) {
+                    ui.label(
+                        summary
+                            .gear
+                            .map_or(lang.tr("All").to_string(), |g| g.to_string()),
+                    );
+                    ui.label(format!("{}", summary.count));
+                    ui.label(format!("{:.0}", summary.shift_rpm));
+                    ui.label(format!("{:.0}", summary.rpm_drop));
+                    ui.label(format!("{:.2}s", summary.duration));
+                    ui.end_row();
+                }
+            });
+        ui.add_space(8.0);
+
+        ui.heading(lang.tr("Shifts"));
+        egui::ScrollArea::vertical()
+            .id_salt("shift_list_scroll")
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                egui::Grid::new("shift_list_grid")
+                    .striped(true)
+                    .spacing([16.0, 6.0])
+                    .show(ui, |ui| {
+                        for header in [
+                            lang.tr("Time"),
+                            lang.tr("Shift"),
+                            lang.tr("Shift RPM"),
+                            lang.tr("Landing RPM"),
+                            lang.tr("Duration"),
+                        ] {
+                            ui.label(egui::RichText::new(header).strong());
+                        }
+                        ui.label("");
+                        ui.end_row();
+
+                        for shift in shifts {
+                            ui.label(Self::format_time(shift.start_time));
+                            ui.label(shift.label());
+                            ui.label(format!("{:.0}", shift.shift_rpm));
+                            ui.label(format!(
+                                "{:.0} (-{:.0})",
+                                shift.landing_rpm,
+                                shift.rpm_drop()
+                            ));
+                            ui.label(format!("{:.2}s", shift.duration()));
+                            if ui
+                                .small_button(lang.tr("Jump"))
+                                .on_hover_text(lang.tr("Move the cursor to this shift"))
+                                .clicked()
+                            {
+                                *jump = Some(shift.start_time);
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
@@ -3,33 +3,79 @@
 use eframe::egui;
 
 use crate::app::UltraLogApp;
-use crate::state::{ActiveTool, LoadingState};
+use crate::i18n::Language;
+use crate::state::ActiveTool;
 use crate::ui::icons::draw_upload_icon;
 
+/// Action picked for a file in the files list, applied once the list is drawn
+#[derive(Clone, Copy)]
+enum FileAction {
+    Info,
+    Reveal,
+    Reload,
+    ExportCsv,
+    ExportArrow,
+    /// Set the file's time offset (seconds)
+    SetTimeOffset(f64),
+    /// Show another logging session of the file
+    SelectRun(usize),
+    Close,
+    CloseOthers,
+}
+
 impl UltraLogApp {
     /// Render the left sidebar with file list and view options
     pub fn render_sidebar(&mut self, ui: &mut egui::Ui) {
-        ui.heading("Files");
+        let lang = self.language;
+        ui.heading(lang.tr("Files"));
         ui.separator();
 
         // Show loading indicator
-        if let LoadingState::Loading(filename) = &self.loading_state {
-            ui.horizontal(|ui| {
-                ui.spinner();
-                ui.label(format!("Loading {}...", filename));
-            });
+        // Show loading progress for each file
+        if self.loading_state.is_loading() {
+            for load in self.loading_state.loads() {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(format!("Loading {}...", load.name));
+                });
+                if let Some(fraction) = load.fraction() {
+                    ui.add(
+                        egui::ProgressBar::new(fraction)
+                            .desired_height(6.0)
+                            .text(format!(
+                                "{:.1} / {:.1} MB",
+                                load.bytes_done as f64 / 1_048_576.0,
+                                load.bytes_total as f64 / 1_048_576.0
+                            )),
+                    );
+                }
+            }
+            // Files waiting for a free load slot
+            for path in &self.load_queue {
+                let name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "Unknown".to_string());
+                ui.label(
+                    egui::RichText::new(format!("⏳ {}", name))
+                        .size(12.0)
+                        .color(egui::Color32::GRAY),
+                );
+            }
             ui.separator();
         }
 
-        let is_loading = matches!(self.loading_state, LoadingState::Loading(_));
+        let is_loading = self.loading_state.is_loading();
 
         // File list (if any files loaded)
         if !self.files.is_empty() {
-            let mut file_to_remove: Option<usize> = None;
+            let mut file_action: Option<(usize, FileAction)> = None;
             let mut file_to_switch: Option<usize> = None;
+            let mut follow_toggle: Option<(usize, bool)> = None;
+            let file_count = self.files.len();
 
             // Collect file info upfront to avoid borrow issues
-            let file_info: Vec<(String, bool, String, usize, usize)> = self
+            let file_info: Vec<(String, bool, String, usize, usize, Option<bool>)> = self
                 .files
                 .iter()
                 .enumerate()
@@ -39,46 +85,124 @@ impl UltraLogApp {
                         self.selected_file == Some(i),
                         file.ecu_type.name().to_string(),
                         file.log.channels.len(),
-                        file.log.data.len(),
+                        file.log.record_count(),
+                        file.tail.is_some().then_some(file.following),
                     )
                 })
                 .collect();
 
-            for (i, (file_name, is_selected, ecu_name, channel_count, data_count)) in
+            for (i, (file_name, is_selected, ecu_name, channel_count, data_count, following)) in
                 file_info.iter().enumerate()
             {
+                let on_disk = self.is_file_on_disk(i);
+                let time_offset = self.files[i].time_offset;
+                let recorded_start = self.files[i].log.times.first().map(|t| t - time_offset);
                 ui.horizontal(|ui| {
-                    let response = ui.selectable_label(*is_selected, file_name);
+                    let response = ui
+                        .selectable_label(*is_selected, file_name)
+                        .on_hover_text(lang.tr("Right-click for more actions"));
                     if response.clicked() {
                         file_to_switch = Some(i);
                     }
+                    response.context_menu(|ui| {
+                        if let Some(action) = Self::file_context_menu(
+                            ui,
+                            on_disk,
+                            file_count > 1,
+                            time_offset,
+                            recorded_start,
+                            lang,
+                        ) {
+                            file_action = Some((i, action));
+                        }
+                    });
 
                     // Delete button
                     if ui.small_button("x").clicked() {
-                        file_to_remove = Some(i);
+                        file_action = Some((i, FileAction::Close));
+                    }
+
+                    // Follow (watch file) toggle for text logs
+                    if let Some(following) = *following {
+                        if ui
+                            .selectable_label(following, "👁")
+                            .on_hover_text("Watch file: append records as they are written")
+                            .clicked()
+                        {
+                            follow_toggle = Some((i, !following));
+                        }
                     }
                 });
 
                 // Show ECU type and data info
+                let skipped = self.files[i].log.skipped_records;
+                let memory_mb = self.file_memory_bytes(i) as f64 / 1_048_576.0;
                 ui.indent(format!("file_indent_{}", i), |ui| {
                     ui.label(
                         egui::RichText::new(format!(
-                            "{} | {} channels | {} points",
-                            ecu_name, channel_count, data_count
+                            "{} | {} channels | {} points | ~{:.1} MB",
+                            ecu_name, channel_count, data_count, memory_mb
                         ))
                         .size(12.0)
                         .color(egui::Color32::GRAY),
-                    );
+                    )
+                    .on_hover_text("Estimated memory used by the file's data and chart caches");
+                    let runs = self.run_labels(i);
+                    if runs.len() > 1 {
+                        let shown = self.files[i].run;
+                        let mut run = shown;
+                        egui::ComboBox::from_id_salt(("file_run", i))
+                            .width(180.0)
+                            .selected_text(format!("Session {} of {}", shown + 1, runs.len()))
+                            .show_ui(ui, |ui| {
+                                for (idx, label) in runs.iter().enumerate() {
+                                    ui.selectable_value(&mut run, idx, label);
+                                }
+                            })
+                            .response
+                            .on_hover_text("The file holds several logging sessions");
+                        if run != shown {
+                            file_action = Some((i, FileAction::SelectRun(run)));
+                        }
+                    }
+                    if time_offset != 0.0 {
+                        ui.label(
+                            egui::RichText::new(format!("⏱ Time offset {:+.2} s", time_offset))
+                                .size(12.0)
+                                .color(egui::Color32::GRAY),
+                        );
+                    }
+                    if skipped > 0 {
+                        ui.label(
+                            egui::RichText::new(format!("⚠ {} damaged records skipped", skipped))
+                                .size(12.0)
+                                .color(egui::Color32::from_rgb(253, 193, 73)),
+                        );
+                    }
                 });
             }
 
+            if let Some((index, following)) = follow_toggle {
+                self.set_following(index, following);
+            }
+
             // Handle deferred file switching
             if let Some(index) = file_to_switch {
                 self.switch_to_file_tab(index);
             }
 
-            if let Some(index) = file_to_remove {
-                self.remove_file(index);
+            if let Some((index, action)) = file_action {
+                match action {
+                    FileAction::Info => self.file_info_index = Some(index),
+                    FileAction::Reveal => self.reveal_file(index),
+                    FileAction::Reload => self.reload_file(index),
+                    FileAction::ExportCsv => self.export_log_csv(index),
+                    FileAction::ExportArrow => self.export_log_arrow(index),
+                    FileAction::SetTimeOffset(offset) => self.set_time_offset(index, offset),
+                    FileAction::SelectRun(run) => self.select_run(index, run),
+                    FileAction::Close => self.remove_file(index),
+                    FileAction::CloseOthers => self.close_other_files(index),
+                }
             }
 
             ui.add_space(10.0);
@@ -96,7 +220,7 @@ impl UltraLogApp {
                         .inner_margin(egui::vec2(16.0, 8.0))
                         .show(ui, |ui| {
                             ui.label(
-                                egui::RichText::new("+ Add File")
+                                egui::RichText::new(lang.tr("+ Add File"))
                                     .color(egui::Color32::WHITE)
                                     .size(14.0),
                             );
@@ -108,7 +232,7 @@ impl UltraLogApp {
                         .clicked()
                     {
                         if let Some(path) = rfd::FileDialog::new()
-                            .add_filter("Log Files", &["csv", "log", "txt", "mlg"])
+                            .add_filter(lang.tr("Log Files"), crate::parsers::LOG_EXTENSIONS)
                             .pick_file()
                         {
                             self.start_loading_file(path);
@@ -130,9 +254,95 @@ impl UltraLogApp {
     }
 
     /// Render the drop zone for when no files are loaded
+    /// Right-click menu of a file entry. Disk actions are disabled for live
+    /// streams. `recorded_start` is the time of the first record without
+    /// the file's time offset.
+    fn file_context_menu(
+        ui: &mut egui::Ui,
+        on_disk: bool,
+        has_others: bool,
+        time_offset: f64,
+        recorded_start: Option<f64>,
+        lang: Language,
+    ) -> Option<FileAction> {
+        let mut action = None;
+        let mut dragged_offset = None;
+        let mut item = |ui: &mut egui::Ui, enabled: bool, label: &str, picked: FileAction| {
+            if ui.add_enabled(enabled, egui::Button::new(label)).clicked() {
+                action = Some(picked);
+                ui.close();
+            }
+        };
+
+        item(ui, true, lang.tr("ℹ  File Info"), FileAction::Info);
+        item(
+            ui,
+            on_disk,
+            lang.tr("📂  Reveal in File Manager"),
+            FileAction::Reveal,
+        );
+        item(
+            ui,
+            on_disk,
+            lang.tr("🔄  Reload from Disk"),
+            FileAction::Reload,
+        );
+        ui.menu_button(lang.tr("⏱  Time Offset"), |ui| {
+            ui.horizontal(|ui| {
+                ui.label(lang.tr("Shift by"));
+                let mut offset = time_offset;
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut offset)
+                            .speed(0.01)
+                            .fixed_decimals(2)
+                            .suffix(" s"),
+                    )
+                    .on_hover_text(lang.tr("Seconds added to every time in this log"))
+                    .changed()
+                {
+                    dragged_offset = Some(offset);
+                }
+            });
+            if let Some(start) = recorded_start.filter(|&start| start != 0.0) {
+                item(
+                    ui,
+                    start + time_offset != 0.0,
+                    lang.tr("Start at 0:00"),
+                    FileAction::SetTimeOffset(-start),
+                );
+            }
+            item(
+                ui,
+                time_offset != 0.0,
+                lang.tr("Reset"),
+                FileAction::SetTimeOffset(0.0),
+            );
+        });
+        ui.menu_button(lang.tr("📤  Export"), |ui| {
+            item(ui, true, lang.tr("Export as CSV..."), FileAction::ExportCsv);
+            item(
+                ui,
+                true,
+                lang.tr("Export as Arrow..."),
+                FileAction::ExportArrow,
+            );
+        });
+        ui.separator();
+        item(ui, true, lang.tr("Close"), FileAction::Close);
+        item(
+            ui,
+            has_others,
+            lang.tr("Close Others"),
+            FileAction::CloseOthers,
+        );
+        dragged_offset.map(FileAction::SetTimeOffset).or(action)
+    }
+
     fn render_drop_zone(&mut self, ui: &mut egui::Ui) {
+        let lang = self.language;
         let primary_color = egui::Color32::from_rgb(113, 120, 78); // Olive green
-        let card_bg = egui::Color32::from_rgb(45, 45, 45); // Dark card for dark theme
+        let card_bg = self.theme.panel_fill();
         let text_gray = egui::Color32::from_rgb(150, 150, 150);
 
         ui.add_space(20.0);
@@ -161,7 +371,7 @@ impl UltraLogApp {
                         .inner_margin(egui::vec2(16.0, 8.0))
                         .show(ui, |ui| {
                             ui.label(
-                                egui::RichText::new("Select a file")
+                                egui::RichText::new(lang.tr("Select a file"))
                                     .color(egui::Color32::WHITE)
                                     .size(14.0),
                             );
@@ -173,7 +383,7 @@ impl UltraLogApp {
                         .clicked()
                     {
                         if let Some(path) = rfd::FileDialog::new()
-                            .add_filter("Log Files", &["csv", "mlg"])
+                            .add_filter(lang.tr("Log Files"), crate::parsers::LOG_EXTENSIONS)
                             .pick_file()
                         {
                             self.start_loading_file(path);
@@ -186,12 +396,16 @@ impl UltraLogApp {
 
                     ui.add_space(12.0);
 
-                    ui.label(egui::RichText::new("or").color(text_gray).size(12.0));
+                    ui.label(
+                        egui::RichText::new(lang.tr("or"))
+                            .color(text_gray)
+                            .size(12.0),
+                    );
 
                     ui.add_space(8.0);
 
                     ui.label(
-                        egui::RichText::new("Drop file here")
+                        egui::RichText::new(lang.tr("Drop file here"))
                             .color(egui::Color32::LIGHT_GRAY)
                             .size(13.0),
                     );
@@ -209,6 +423,7 @@ impl UltraLogApp {
 
     /// Render view options at the bottom of the sidebar
     fn render_view_options(&mut self, ui: &mut egui::Ui) {
+        let lang = self.language;
         ui.with_layout(egui::Layout::bottom_up(egui::Align::LEFT), |ui| {
             // Reverse order since we're bottom-up
             ui.add_space(10.0);
@@ -219,21 +434,21 @@ impl UltraLogApp {
                 && self.active_tool == ActiveTool::LogViewer
             {
                 egui::Frame::NONE
-                    .fill(egui::Color32::from_rgb(35, 35, 35))
+                    .fill(self.theme.recessed_fill())
                     .corner_radius(8)
                     .inner_margin(10.0)
                     .show(ui, |ui| {
                         // Cursor tracking checkbox
-                        ui.checkbox(&mut self.cursor_tracking, "🎯  Cursor Tracking");
+                        ui.checkbox(&mut self.cursor_tracking, lang.tr("🎯  Cursor Tracking"));
                         ui.label(
-                            egui::RichText::new("Keep cursor centered while scrubbing")
+                            egui::RichText::new(lang.tr("Keep cursor centered while scrubbing"))
                                 .color(egui::Color32::GRAY),
                         );
 
                         // Window size slider (only show when cursor tracking is enabled)
                         if self.cursor_tracking {
                             ui.add_space(8.0);
-                            ui.label("View Window:");
+                            ui.label(lang.tr("View Window:"));
                             ui.add(
                                 egui::Slider::new(&mut self.view_window_seconds, 5.0..=120.0)
                                     .suffix("s")
@@ -246,9 +461,9 @@ impl UltraLogApp {
                         ui.add_space(4.0);
 
                         // Color blind mode checkbox
-                        ui.checkbox(&mut self.color_blind_mode, "👁  Color Blind Mode");
+                        ui.checkbox(&mut self.color_blind_mode, lang.tr("👁  Color Blind Mode"));
                         ui.label(
-                            egui::RichText::new("Use accessible color palette")
+                            egui::RichText::new(lang.tr("Use accessible color palette"))
                                 .color(egui::Color32::GRAY),
                         );
 
@@ -258,25 +473,38 @@ impl UltraLogApp {
 
                         // Field normalization checkbox with right-aligned Edit button
                         ui.horizontal(|ui| {
-                            ui.checkbox(&mut self.field_normalization, "📝  Field Normalization");
+                            ui.checkbox(
+                                &mut self.field_normalization,
+                                lang.tr("📝  Field Normalization"),
+                            );
                             ui.with_layout(
                                 egui::Layout::right_to_left(egui::Align::Center),
                                 |ui| {
-                                    if ui.small_button("Edit").clicked() {
+                                    if ui.small_button(lang.tr("Edit")).clicked() {
                                         self.show_normalization_editor = true;
                                     }
                                 },
                             );
                         });
                         ui.label(
-                            egui::RichText::new("Standardize channel names across ECU types")
-                                .color(egui::Color32::GRAY),
+                            egui::RichText::new(
+                                lang.tr("Standardize channel names across ECU types"),
+                            )
+                            .color(egui::Color32::GRAY),
                         );
+                        ui.add_enabled(
+                            self.field_normalization,
+                            egui::Checkbox::new(
+                                &mut self.show_original_names,
+                                lang.tr("Show original names"),
+                            ),
+                        )
+                        .on_hover_text(lang.tr("Follow normalized names with the ECU's own name"));
                     });
 
                 ui.add_space(5.0);
                 ui.separator();
-                ui.heading("View Options");
+                ui.heading(lang.tr("View Options"));
             }
         });
     }
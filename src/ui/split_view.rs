@@ -0,0 +1,96 @@
+//! Split view for the log viewer.
+//!
+//! Shows the active tab's chart next to (or above) a second tab's chart so
+//! two logs can be compared and, with linked cursors, scrubbed together.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::state::SplitLayout;
+
+/// Space between the two panes
+const PANE_GAP: f32 = 8.0;
+
+impl UltraLogApp {
+    /// Render the active tab and the split tab as two panes
+    pub fn render_split_view(&mut self, ui: &mut egui::Ui) {
+        let (Some(active), Some(other)) = (self.active_tab, self.split_tab_index()) else {
+            return;
+        };
+
+        let rect = ui.available_rect_before_wrap();
+        let (first, second) = match self.split_layout {
+            SplitLayout::SideBySide => {
+                let width = (rect.width() - PANE_GAP) / 2.0;
+                (
+                    egui::Rect::from_min_size(rect.min, egui::vec2(width, rect.height())),
+                    egui::Rect::from_min_max(egui::pos2(rect.max.x - width, rect.min.y), rect.max),
+                )
+            }
+            SplitLayout::Stacked => {
+                let height = (rect.height() - PANE_GAP) / 2.0;
+                (
+                    egui::Rect::from_min_size(rect.min, egui::vec2(rect.width(), height)),
+                    egui::Rect::from_min_max(egui::pos2(rect.min.x, rect.max.y - height), rect.max),
+                )
+            }
+        };
+
+        // The active pane is drawn last so the shared chart state (e.g. the
+        // visible range used by annotations) is the active tab's
+        self.render_split_pane(ui, second, other, false);
+        self.render_split_pane(ui, first, active, true);
+        ui.allocate_rect(rect, egui::Sense::hover());
+    }
+
+    /// Render one pane: a header naming the tab and the tab's chart
+    fn render_split_pane(
+        &mut self,
+        ui: &mut egui::Ui,
+        rect: egui::Rect,
+        tab_index: usize,
+        is_active: bool,
+    ) {
+        let builder = egui::UiBuilder::new()
+            .max_rect(rect)
+            .id_salt(("split_pane", self.tabs[tab_index].id))
+            .layout(egui::Layout::top_down(egui::Align::Min));
+        ui.scope_builder(builder, |ui| {
+            ui.horizontal(|ui| {
+                let name = egui::RichText::new(&self.tabs[tab_index].name).strong();
+                if is_active {
+                    ui.label(name.color(ui.visuals().strong_text_color()));
+                } else {
+                    if ui
+                        .add(egui::Label::new(name).sense(egui::Sense::click()))
+                        .on_hover_text("Click to make this the active tab")
+                        .clicked()
+                    {
+                        self.activate_tab(tab_index);
+                    }
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui
+                            .small_button("✕")
+                            .on_hover_text("Close split view")
+                            .clicked()
+                        {
+                            self.split_tab = None;
+                        }
+                        if ui.small_button("⇄").on_hover_text("Swap panes").clicked() {
+                            self.activate_tab(tab_index);
+                        }
+                        ui.checkbox(&mut self.link_split_cursors, "Link cursors")
+                            .on_hover_text("Move both panes' cursors together");
+                    });
+                }
+            });
+            ui.separator();
+
+            // Draw the chart as if this pane's tab were active
+            let previous_active = self.active_tab;
+            self.active_tab = Some(tab_index);
+            self.render_chart(ui);
+            self.active_tab = previous_active;
+        });
+    }
+}
@@ -13,6 +13,13 @@ impl UltraLogApp {
 
         let mut tab_to_activate: Option<usize> = None;
         let mut tab_to_close: Option<usize> = None;
+        let mut tab_to_duplicate: Option<usize> = None;
+        let mut tab_to_keep: Option<usize> = None;
+        let mut tab_to_move: Option<(usize, usize)> = None;
+        let mut tab_to_split: Option<usize> = None;
+        let mut close_split = false;
+        let split_index = self.split_tab_index();
+        let tab_count = self.tabs.len();
 
         // Collect tab info to avoid borrow issues
         let tab_info: Vec<(String, bool)> = self
@@ -24,80 +31,137 @@ impl UltraLogApp {
 
         ui.horizontal(|ui| {
             for (i, (name, is_active)) in tab_info.iter().enumerate() {
-                let tab_color = if *is_active {
-                    egui::Color32::from_rgb(60, 60, 60)
-                } else {
-                    egui::Color32::from_rgb(40, 40, 40)
-                };
-
-                let text_color = if *is_active {
-                    egui::Color32::WHITE
-                } else {
-                    egui::Color32::from_rgb(180, 180, 180)
-                };
+                let tab_color = self.theme.tab_fill(*is_active);
+                let text_color = self.theme.tab_text(*is_active);
 
                 let border_color = if *is_active {
                     egui::Color32::from_rgb(113, 120, 78) // Primary olive green
+                } else if split_index == Some(i) {
+                    egui::Color32::from_rgb(82, 87, 58) // Dimmed olive for the split pane
                 } else {
                     egui::Color32::from_rgb(60, 60, 60)
                 };
 
-                egui::Frame::NONE
-                    .fill(tab_color)
-                    .corner_radius(egui::CornerRadius {
-                        nw: 6,
-                        ne: 6,
-                        sw: 0,
-                        se: 0,
+                // Tabs are dragged by their frame; the name and close button still click
+                let tab_response = ui
+                    .dnd_drag_source(egui::Id::new(("tab", i)), i, |ui| {
+                        egui::Frame::NONE
+                            .fill(tab_color)
+                            .corner_radius(egui::CornerRadius {
+                                nw: 6,
+                                ne: 6,
+                                sw: 0,
+                                se: 0,
+                            })
+                            .stroke(egui::Stroke::new(
+                                if *is_active { 2.0 } else { 1.0 },
+                                border_color,
+                            ))
+                            .inner_margin(egui::Margin {
+                                left: 12,
+                                right: 8,
+                                top: 6,
+                                bottom: 6,
+                            })
+                            .show(ui, |ui| {
+                                ui.horizontal(|ui| {
+                                    // Tab name (clickable)
+                                    let label_response = ui.add(
+                                        egui::Label::new(
+                                            egui::RichText::new(name).color(text_color).size(13.0),
+                                        )
+                                        .sense(egui::Sense::click()),
+                                    );
+
+                                    if label_response.clicked() {
+                                        tab_to_activate = Some(i);
+                                    }
+                                    label_response.context_menu(|ui| {
+                                        if ui.button("Close").clicked() {
+                                            tab_to_close = Some(i);
+                                            ui.close();
+                                        }
+                                        if ui
+                                            .add_enabled(
+                                                tab_count > 1,
+                                                egui::Button::new("Close Others"),
+                                            )
+                                            .clicked()
+                                        {
+                                            tab_to_keep = Some(i);
+                                            ui.close();
+                                        }
+                                        ui.separator();
+                                        if ui.button("Duplicate Tab").clicked() {
+                                            tab_to_duplicate = Some(i);
+                                            ui.close();
+                                        }
+                                        if split_index == Some(i) {
+                                            if ui.button("Close Split View").clicked() {
+                                                close_split = true;
+                                                ui.close();
+                                            }
+                                        } else if !*is_active
+                                            && ui.button("Open in Split View").clicked()
+                                        {
+                                            tab_to_split = Some(i);
+                                            ui.close();
+                                        }
+                                    });
+                                    if label_response.hovered() {
+                                        ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                                    }
+
+                                    ui.add_space(4.0);
+
+                                    // Close button
+                                    let close_btn = ui.add(
+                                        egui::Label::new(
+                                            egui::RichText::new("×")
+                                                .color(egui::Color32::from_rgb(150, 150, 150))
+                                                .size(14.0),
+                                        )
+                                        .sense(egui::Sense::click()),
+                                    );
+
+                                    if close_btn.clicked() {
+                                        tab_to_close = Some(i);
+                                    }
+
+                                    if close_btn.hovered() {
+                                        ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+                                    }
+                                });
+                            });
                     })
-                    .stroke(egui::Stroke::new(
-                        if *is_active { 2.0 } else { 1.0 },
-                        border_color,
-                    ))
-                    .inner_margin(egui::Margin {
-                        left: 12,
-                        right: 8,
-                        top: 6,
-                        bottom: 6,
-                    })
-                    .show(ui, |ui| {
-                        ui.horizontal(|ui| {
-                            // Tab name (clickable)
-                            let label_response = ui.add(
-                                egui::Label::new(
-                                    egui::RichText::new(name).color(text_color).size(13.0),
-                                )
-                                .sense(egui::Sense::click()),
-                            );
-
-                            if label_response.clicked() {
-                                tab_to_activate = Some(i);
-                            }
-                            if label_response.hovered() {
-                                ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
-                            }
-
-                            ui.add_space(4.0);
-
-                            // Close button
-                            let close_btn = ui.add(
-                                egui::Label::new(
-                                    egui::RichText::new("×")
-                                        .color(egui::Color32::from_rgb(150, 150, 150))
-                                        .size(14.0),
-                                )
-                                .sense(egui::Sense::click()),
-                            );
-
-                            if close_btn.clicked() {
-                                tab_to_close = Some(i);
-                            }
-
-                            if close_btn.hovered() {
-                                ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
-                            }
-                        });
-                    });
+                    .response;
+
+                // Middle-click anywhere on the tab closes it
+                if ui.rect_contains_pointer(tab_response.rect)
+                    && ui.input(|i| i.pointer.button_clicked(egui::PointerButton::Middle))
+                {
+                    tab_to_close = Some(i);
+                }
+
+                // Dropping another tab here moves it to this position
+                if let Some(dragged) = tab_response.dnd_hover_payload::<usize>() {
+                    if *dragged != i {
+                        let rect = tab_response.rect;
+                        let x = if *dragged < i {
+                            rect.right()
+                        } else {
+                            rect.left()
+                        };
+                        ui.painter().vline(
+                            x,
+                            rect.y_range(),
+                            egui::Stroke::new(2.0, egui::Color32::WHITE),
+                        );
+                    }
+                }
+                if let Some(dragged) = tab_response.dnd_release_payload::<usize>() {
+                    tab_to_move = Some((*dragged, i));
+                }
 
                 ui.add_space(2.0);
             }
@@ -105,8 +169,26 @@ impl UltraLogApp {
 
         // Handle deferred tab activation
         if let Some(index) = tab_to_activate {
-            self.active_tab = Some(index);
-            self.selected_file = Some(self.tabs[index].file_index);
+            self.activate_tab(index);
+        }
+
+        if let Some(index) = tab_to_split {
+            self.open_split_view(index);
+        }
+        if close_split {
+            self.split_tab = None;
+        }
+
+        if let Some((from, to)) = tab_to_move {
+            self.move_tab(from, to);
+        }
+
+        if let Some(index) = tab_to_duplicate {
+            self.duplicate_tab(index);
+        }
+
+        if let Some(index) = tab_to_keep {
+            self.close_other_tabs(index);
         }
 
         // Handle deferred tab close
@@ -0,0 +1,95 @@
+//! Color themes.
+//!
+//! egui visuals for each theme plus the fills of UltraLog's own panels,
+//! cards and tabs, which are drawn with explicit colors.
+
+use eframe::egui::{self, Color32};
+
+use crate::state::Theme;
+
+/// Outline of the focused widget in high-contrast mode (amber)
+const ACCENT: Color32 = Color32::from_rgb(253, 193, 73);
+
+impl Theme {
+    /// Display name for this theme
+    pub fn name(&self) -> &'static str {
+        match self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+        }
+    }
+
+    /// egui visuals for this theme, optionally with high-contrast text and
+    /// outlines. Keyboard focus uses the active widget style, so a thick
+    /// outline there makes the focused widget easy to find.
+    pub fn visuals(&self, high_contrast: bool) -> egui::Visuals {
+        let mut visuals = match self {
+            Theme::Dark => egui::Visuals::dark(),
+            Theme::Light => egui::Visuals::light(),
+        };
+        if high_contrast {
+            let (text, outline) = match self {
+                Theme::Dark => (Color32::WHITE, Color32::from_gray(200)),
+                Theme::Light => (Color32::BLACK, Color32::from_gray(40)),
+            };
+            visuals.override_text_color = Some(text);
+            visuals.widgets.noninteractive.bg_stroke.color = outline;
+            for widget in [
+                &mut visuals.widgets.inactive,
+                &mut visuals.widgets.hovered,
+                &mut visuals.widgets.open,
+            ] {
+                widget.bg_stroke = egui::Stroke::new(1.5, outline);
+                widget.fg_stroke.color = text;
+            }
+            visuals.widgets.active.bg_stroke = egui::Stroke::new(3.0, ACCENT);
+            visuals.widgets.active.fg_stroke.color = text;
+            visuals.selection.stroke = egui::Stroke::new(2.0, text);
+        }
+        visuals
+    }
+
+    /// Fill of the side panels
+    pub fn panel_fill(&self) -> Color32 {
+        match self {
+            Theme::Dark => Color32::from_rgb(45, 45, 45),
+            Theme::Light => Color32::from_rgb(240, 240, 240),
+        }
+    }
+
+    /// Fill of recessed strips such as the tool switcher
+    pub fn recessed_fill(&self) -> Color32 {
+        match self {
+            Theme::Dark => Color32::from_rgb(35, 35, 35),
+            Theme::Light => Color32::from_rgb(226, 226, 226),
+        }
+    }
+
+    /// Fill of cards inside a panel, e.g. channel cards
+    pub fn card_fill(&self) -> Color32 {
+        match self {
+            Theme::Dark => Color32::from_rgb(40, 40, 40),
+            Theme::Light => Color32::from_rgb(250, 250, 250),
+        }
+    }
+
+    /// Fill of a tab in the tab bar
+    pub fn tab_fill(&self, active: bool) -> Color32 {
+        match (self, active) {
+            (Theme::Dark, true) => Color32::from_rgb(60, 60, 60),
+            (Theme::Dark, false) => Color32::from_rgb(40, 40, 40),
+            (Theme::Light, true) => Color32::from_rgb(252, 252, 252),
+            (Theme::Light, false) => Color32::from_rgb(222, 222, 222),
+        }
+    }
+
+    /// Color of a tab's name in the tab bar
+    pub fn tab_text(&self, active: bool) -> Color32 {
+        match (self, active) {
+            (Theme::Dark, true) => Color32::WHITE,
+            (Theme::Dark, false) => Color32::from_rgb(180, 180, 180),
+            (Theme::Light, true) => Color32::from_rgb(20, 20, 20),
+            (Theme::Light, false) => Color32::from_rgb(90, 90, 90),
+        }
+    }
+}
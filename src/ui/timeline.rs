@@ -3,6 +3,14 @@
 use eframe::egui;
 
 use crate::app::UltraLogApp;
+use crate::normalize::normalize_channel_name_with_custom;
+
+/// An event shown as a tick under the timeline scrubber
+struct TimelineMarker {
+    time: f64,
+    label: String,
+    color: egui::Color32,
+}
 
 impl UltraLogApp {
     /// Render the timeline scrubber bar
@@ -19,11 +27,11 @@ impl UltraLogApp {
         // Time labels row
         ui.horizontal(|ui| {
             ui.label(
-                egui::RichText::new(Self::format_time(min_time)).color(egui::Color32::LIGHT_GRAY),
+                egui::RichText::new(self.display_time(min_time)).color(egui::Color32::LIGHT_GRAY),
             );
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                 ui.label(
-                    egui::RichText::new(Self::format_time(max_time))
+                    egui::RichText::new(self.display_time(max_time))
                         .color(egui::Color32::LIGHT_GRAY),
                 );
             });
@@ -43,10 +51,35 @@ impl UltraLogApp {
             .clamping(egui::SliderClamping::Always);
 
         let slider_response = ui.add(slider);
+        // Arrow keys move the focused slider; name it for screen readers
+        slider_response
+            .widget_info(|| egui::WidgetInfo::slider(true, slider_value, "Timeline position"));
 
         // Restore original slider width
         ui.spacing_mut().slider_width = old_slider_width;
 
+        // The slider's rail is inset by its handle radius
+        let rect = slider_response.rect;
+        let rail = rect.x_range().shrink(rect.height() / 2.5);
+        let x_at = |time: f64| rail.min + ((time - min_time) / total_duration) as f32 * rail.span();
+
+        // Shade the loop region and mark its bounds on the rail
+        if let Some(tab_idx) = self.active_tab {
+            let tab = &self.tabs[tab_idx];
+            let color = egui::Color32::from_rgb(253, 193, 73);
+            let painter = ui.painter();
+            if let Some((start, end)) = tab.loop_region() {
+                painter.rect_filled(
+                    egui::Rect::from_x_y_ranges(x_at(start)..=x_at(end), rect.y_range()),
+                    2.0,
+                    color.gamma_multiply(0.25),
+                );
+            }
+            for bound in [tab.loop_start, tab.loop_end].into_iter().flatten() {
+                painter.vline(x_at(bound), rect.y_range(), egui::Stroke::new(2.0, color));
+            }
+        }
+
         if slider_response.changed() {
             // Stop playback when user manually scrubs
             self.is_playing = false;
@@ -58,6 +91,103 @@ impl UltraLogApp {
             // Force repaint to update legend values
             ui.ctx().request_repaint();
         }
+
+        // Event markers as ticks in a strip under the rail
+        let markers = self.timeline_markers();
+        if markers.is_empty() {
+            return;
+        }
+        let (strip, _) =
+            ui.allocate_exact_size(egui::vec2(rect.width(), 10.0), egui::Sense::hover());
+        let mut jump_to = None;
+        let mut last_x = f32::NEG_INFINITY;
+        for (i, marker) in markers.iter().enumerate() {
+            let x = x_at(marker.time);
+            // One tick per couple of pixels; the first of a cluster wins
+            if x - last_x < 2.0 || !rail.contains(x) {
+                continue;
+            }
+            last_x = x;
+            ui.painter()
+                .vline(x, strip.y_range(), egui::Stroke::new(2.0, marker.color));
+            let hit = egui::Rect::from_x_y_ranges(x - 3.0..=x + 3.0, strip.y_range());
+            let response = ui
+                .interact(
+                    hit,
+                    ui.id().with(("timeline_marker", i)),
+                    egui::Sense::click(),
+                )
+                .on_hover_text(format!(
+                    "{}\n{}",
+                    marker.label,
+                    self.display_time(marker.time)
+                ));
+            response.widget_info(|| {
+                egui::WidgetInfo::labeled(
+                    egui::WidgetType::Button,
+                    true,
+                    format!("{} {}", marker.label, self.display_time(marker.time)),
+                )
+            });
+            if response.hovered() {
+                ui.ctx().set_cursor_icon(egui::CursorIcon::PointingHand);
+            }
+            if response.clicked() {
+                jump_to = Some(marker.time);
+            }
+        }
+
+        if let Some(time) = jump_to {
+            self.is_playing = false;
+            self.last_frame_time = None;
+            self.set_cursor_time(Some(time));
+            let record = self.find_record_at_time(time);
+            self.set_cursor_record(record);
+            self.set_jump_to_time(Some(time));
+        }
+    }
+
+    /// Markers shown under the timeline for the active tab, in time order:
+    /// markers recorded in the log, annotations and alarm violations
+    fn timeline_markers(&mut self) -> Vec<TimelineMarker> {
+        let Some(tab_idx) = self.active_tab else {
+            return Vec::new();
+        };
+        let tab = &self.tabs[tab_idx];
+
+        let mut markers: Vec<TimelineMarker> = self.files[tab.file_index]
+            .log
+            .markers
+            .iter()
+            .map(|marker| TimelineMarker {
+                time: marker.time,
+                label: if marker.message.is_empty() {
+                    "Marker".to_string()
+                } else {
+                    format!("Marker: {}", marker.message)
+                },
+                color: egui::Color32::from_rgb(100, 180, 255),
+            })
+            .collect();
+        markers.extend(tab.annotations.iter().map(|annotation| {
+            let [r, g, b] = annotation.color;
+            TimelineMarker {
+                time: annotation.start.min(annotation.end),
+                label: annotation.label.clone(),
+                color: egui::Color32::from_rgb(r, g, b),
+            }
+        }));
+        markers.extend(
+            self.collect_alarm_regions()
+                .into_iter()
+                .map(|(name, start, _)| TimelineMarker {
+                    time: start,
+                    label: name,
+                    color: egui::Color32::from_rgb(191, 78, 48),
+                }),
+        );
+        markers.sort_by(|a, b| a.time.total_cmp(&b.time));
+        markers
     }
 
     /// Render the record/time indicator bar with playback controls
@@ -77,7 +207,15 @@ impl UltraLogApp {
             ))
             .min_size(button_size);
 
-            if ui.add(play_button).clicked() {
+            let play_response = ui.add(play_button);
+            play_response.widget_info(|| {
+                egui::WidgetInfo::labeled(
+                    egui::WidgetType::Button,
+                    true,
+                    if self.is_playing { "Pause" } else { "Play" },
+                )
+            });
+            if play_response.clicked() {
                 self.is_playing = !self.is_playing;
                 if self.is_playing {
                     // Reset frame time when starting playback
@@ -101,7 +239,10 @@ impl UltraLogApp {
             )
             .min_size(button_size);
 
-            if ui.add(stop_button).clicked() {
+            let stop_response = ui.add(stop_button);
+            stop_response
+                .widget_info(|| egui::WidgetInfo::labeled(egui::WidgetType::Button, true, "Stop"));
+            if stop_response.clicked() {
                 self.is_playing = false;
                 self.last_frame_time = None;
                 // Reset cursor to beginning
@@ -112,12 +253,32 @@ impl UltraLogApp {
                 }
             }
 
+            // Step buttons move exactly one record
+            let step_back =
+                egui::Button::new(egui::RichText::new("⏮").size(16.0)).min_size(button_size);
+            let step_back = ui.add(step_back).on_hover_text("Previous record");
+            step_back.widget_info(|| {
+                egui::WidgetInfo::labeled(egui::WidgetType::Button, true, "Previous record")
+            });
+            if step_back.clicked() {
+                self.step_record(false);
+            }
+            let step_forward =
+                egui::Button::new(egui::RichText::new("⏭").size(16.0)).min_size(button_size);
+            let step_forward = ui.add(step_forward).on_hover_text("Next record");
+            step_forward.widget_info(|| {
+                egui::WidgetInfo::labeled(egui::WidgetType::Button, true, "Next record")
+            });
+            if step_forward.clicked() {
+                self.step_record(true);
+            }
+
             ui.separator();
 
             // Playback speed selector
-            ui.label(egui::RichText::new("Speed:").color(egui::Color32::GRAY));
+            let speed_label = ui.label(egui::RichText::new("Speed:").color(egui::Color32::GRAY));
 
-            let speed_options = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+            let speed_options = [0.05, 0.1, 0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
             egui::ComboBox::from_id_salt("playback_speed")
                 .selected_text(format!("{}x", self.playback_speed))
                 .width(60.0)
@@ -125,17 +286,73 @@ impl UltraLogApp {
                     for speed in speed_options {
                         ui.selectable_value(&mut self.playback_speed, speed, format!("{}x", speed));
                     }
+                })
+                .response
+                .labelled_by(speed_label.id);
+
+            ui.separator();
+
+            // Loop region: A and B set its bounds at the cursor
+            let loop_color = egui::Color32::from_rgb(253, 193, 73);
+            if ui
+                .button("A")
+                .on_hover_text("Set the loop start at the cursor (A)")
+                .clicked()
+            {
+                self.set_loop_bound_at_cursor(true);
+            }
+            if ui
+                .button("B")
+                .on_hover_text("Set the loop end at the cursor (B)")
+                .clicked()
+            {
+                self.set_loop_bound_at_cursor(false);
+            }
+            if let Some((start, end)) = self.get_loop_region() {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "🔁 {} - {}",
+                        self.display_time(start),
+                        self.display_time(end)
+                    ))
+                    .color(loop_color),
+                );
+            }
+            let has_bounds = self.active_tab.is_some_and(|idx| {
+                self.tabs[idx].loop_start.is_some() || self.tabs[idx].loop_end.is_some()
+            });
+            if has_bounds {
+                let clear = ui.small_button("✕").on_hover_text("Clear the loop region");
+                clear.widget_info(|| {
+                    egui::WidgetInfo::labeled(egui::WidgetType::Button, true, "Clear loop region")
                 });
+                if clear.clicked() {
+                    self.clear_loop_region();
+                }
+            }
 
             ui.separator();
 
             // Current time display
             if let Some(time) = self.get_cursor_time() {
                 ui.label(
-                    egui::RichText::new(format!("Time: {}", Self::format_time(time)))
+                    egui::RichText::new(format!("Time: {}", self.display_time(time)))
                         .strong()
                         .color(egui::Color32::from_rgb(0, 255, 255)), // Cyan to match cursor
                 );
+                let copy = ui
+                    .small_button("📋")
+                    .on_hover_text("Copy values at cursor (Ctrl/Cmd+Shift+C)");
+                copy.widget_info(|| {
+                    egui::WidgetInfo::labeled(
+                        egui::WidgetType::Button,
+                        true,
+                        "Copy values at cursor",
+                    )
+                });
+                if copy.clicked() {
+                    self.copy_cursor_values(ui.ctx());
+                }
             }
 
             ui.separator();
@@ -145,7 +362,7 @@ impl UltraLogApp {
                 if let Some(tab_idx) = self.active_tab {
                     let file_index = self.tabs[tab_idx].file_index;
                     if file_index < self.files.len() {
-                        let total_records = self.files[file_index].log.data.len();
+                        let total_records = self.files[file_index].log.record_count();
                         ui.label(
                             egui::RichText::new(format!(
                                 "Record {} of {}",
@@ -160,6 +377,76 @@ impl UltraLogApp {
         });
     }
 
+    /// Move the cursor to the next or previous record of the active tab's
+    /// log, pausing playback
+    pub fn step_record(&mut self, forward: bool) {
+        let Some(tab_idx) = self.active_tab else {
+            return;
+        };
+        let tab = &self.tabs[tab_idx];
+        let times = self.files[tab.file_index].log.get_times_as_f64();
+        if times.is_empty() {
+            return;
+        }
+        let record = match (tab.cursor_record, forward) {
+            (Some(record), true) => (record + 1).min(times.len() - 1),
+            (Some(record), false) => record.saturating_sub(1),
+            (None, _) => 0,
+        };
+        let time = times[record];
+
+        self.is_playing = false;
+        self.last_frame_time = None;
+        self.set_cursor_record(Some(record));
+        self.set_cursor_time(Some(time));
+    }
+
+    /// Tab-separated values of the selected channels at the cursor: the
+    /// time in seconds, then the name, value and unit of each channel. `None`
+    /// without a cursor.
+    fn cursor_values_text(&self) -> Option<String> {
+        let time = self.get_cursor_time()?;
+        let record = self.get_cursor_record()?;
+        let time_text = self
+            .wall_clock_at(time)
+            .map_or_else(|| format!("{:.3}", time), Self::format_clock_time);
+        let mut fields = vec![time_text];
+        for selected in self.get_selected_channels() {
+            let name = selected.channel.name();
+            fields.push(if self.field_normalization {
+                normalize_channel_name_with_custom(&name, Some(&self.custom_normalizations))
+            } else {
+                name
+            });
+            match self.get_value_at_record(selected.file_index, selected.channel_index, record) {
+                Some(value) => {
+                    let (converted, unit) = self.convert_channel_value(
+                        selected.file_index,
+                        selected.channel_index,
+                        record,
+                        value,
+                    );
+                    fields.push(format!("{:.2}", converted));
+                    fields.push(unit);
+                }
+                None => fields.extend([String::new(), String::new()]),
+            }
+        }
+        Some(fields.join("\t"))
+    }
+
+    /// Copy the selected channels' values at the cursor to the clipboard as
+    /// one tab-separated line
+    pub fn copy_cursor_values(&mut self, ctx: &egui::Context) {
+        match self.cursor_values_text() {
+            Some(text) => {
+                ctx.copy_text(text);
+                self.show_toast_success("Copied values at cursor");
+            }
+            None => self.show_toast_error("Place the cursor on the chart first"),
+        }
+    }
+
     /// Update playback state - advances cursor based on elapsed time
     pub fn update_playback(&mut self, ctx: &egui::Context) {
         if !self.is_playing {
@@ -183,7 +470,17 @@ impl UltraLogApp {
         if let Some(current_time) = self.get_cursor_time() {
             let new_time = current_time + (delta * self.playback_speed);
 
-            if new_time >= max_time {
+            if let Some((loop_start, loop_end)) = self.get_loop_region() {
+                // Repeat within the loop region, starting there if outside it
+                let new_time = if new_time < loop_start || new_time >= loop_end {
+                    loop_start
+                } else {
+                    new_time
+                };
+                self.set_cursor_time(Some(new_time));
+                let record = self.find_record_at_time(new_time);
+                self.set_cursor_record(record);
+            } else if new_time >= max_time {
                 // Reached end - stop playback
                 self.set_cursor_time(Some(max_time));
                 let record = self.find_record_at_time(max_time);
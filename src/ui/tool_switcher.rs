@@ -1,7 +1,7 @@
 //! Tool switcher component for switching between different views.
 //!
 //! Renders a pill-style tab bar at the top of the main content area
-//! allowing users to switch between Log Viewer and Scatter Plots views.
+//! allowing users to switch between the Log Viewer and analysis tools.
 
 use eframe::egui;
 
@@ -15,7 +15,13 @@ impl UltraLogApp {
             ui.add_space(10.0);
 
             // Define available tools
-            let tools = [ActiveTool::LogViewer, ActiveTool::ScatterPlot];
+            let tools = [
+                ActiveTool::LogViewer,
+                ActiveTool::ScatterPlot,
+                ActiveTool::TrackMap,
+                ActiveTool::Dyno,
+                ActiveTool::DataTable,
+            ];
 
             for tool in tools {
                 let is_selected = self.active_tool == tool;
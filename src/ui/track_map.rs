@@ -0,0 +1,358 @@
+//! GPS track map view.
+//!
+//! Draws the driven path for logs containing latitude/longitude channels,
+//! colored by a user-selected channel, with a marker synced to the timeline cursor.
+
+use eframe::egui;
+use egui_plot::{Line, MarkerShape, Plot, PlotPoints, Points};
+
+use crate::app::UltraLogApp;
+use crate::normalize::sort_channels_by_priority;
+
+/// Maximum number of path points drawn (path is decimated above this)
+const MAX_TRACK_POINTS: usize = 5000;
+
+/// Number of color buckets used when coloring the path
+const TRACK_COLOR_BUCKETS: usize = 32;
+
+/// Channel name fragments recognised as latitude
+const LATITUDE_NAMES: &[&str] = &["latitude", "gps lat", "gps_lat", "gpslat", "lat"];
+
+/// Channel name fragments recognised as longitude
+const LONGITUDE_NAMES: &[&str] = &[
+    "longitude",
+    "gps lon",
+    "gps_lon",
+    "gpslon",
+    "gps long",
+    "lon",
+    "long",
+    "lng",
+];
+
+/// Marker color for the cursor position
+const MARKER_COLOR: egui::Color32 = egui::Color32::from_rgb(0, 255, 255); // Cyan, matches chart cursor
+
+impl UltraLogApp {
+    /// Render the track map view
+    pub fn render_track_map_view(&mut self, ui: &mut egui::Ui) {
+        let Some(tab_idx) = self.active_tab else {
+            ui.centered_and_justified(|ui| {
+                ui.label(
+                    egui::RichText::new("Load a log file with GPS data to view the track map")
+                        .size(20.0)
+                        .color(egui::Color32::GRAY),
+                );
+            });
+            return;
+        };
+
+        self.render_tab_bar(ui);
+        ui.add_space(10.0);
+
+        let file_index = self.tabs[tab_idx].file_index;
+        if file_index >= self.files.len() {
+            return;
+        }
+
+        // Auto-detect GPS channels once per tab
+        if !self.tabs[tab_idx].track_map_state.auto_detected {
+            let channel_names: Vec<String> = self.files[file_index]
+                .log
+                .channels
+                .iter()
+                .map(|c| c.name())
+                .collect();
+            let state = &mut self.tabs[tab_idx].track_map_state;
+            state.lat_channel = Self::find_gps_channel(&channel_names, LATITUDE_NAMES);
+            state.lon_channel = Self::find_gps_channel(&channel_names, LONGITUDE_NAMES);
+            state.auto_detected = true;
+        }
+
+        self.render_track_map_selectors(ui, tab_idx, file_index);
+        ui.add_space(8.0);
+
+        let state = self.tabs[tab_idx].track_map_state.clone();
+        let (Some(lat_idx), Some(lon_idx)) = (state.lat_channel, state.lon_channel) else {
+            ui.centered_and_justified(|ui| {
+                ui.label(
+                    egui::RichText::new(
+                        "No GPS channels detected - select latitude and longitude above",
+                    )
+                    .size(18.0)
+                    .color(egui::Color32::GRAY),
+                );
+            });
+            return;
+        };
+
+        self.render_track_map_plot(ui, file_index, lat_idx, lon_idx, state.color_channel);
+    }
+
+    /// Find a channel whose name matches one of the given GPS name fragments.
+    /// Exact matches win over substring matches.
+    fn find_gps_channel(channel_names: &[String], candidates: &[&str]) -> Option<usize> {
+        let lower: Vec<String> = channel_names
+            .iter()
+            .map(|n| n.to_lowercase().replace('/', " "))
+            .collect();
+
+        for candidate in candidates {
+            if let Some(idx) = lower
+                .iter()
+                .position(|n| n == candidate || n.ends_with(&format!(" {}", candidate)))
+            {
+                return Some(idx);
+            }
+        }
+
+        // Longer fragments are unambiguous enough for substring matching
+        candidates
+            .iter()
+            .filter(|c| c.len() > 4)
+            .find_map(|candidate| lower.iter().position(|n| n.contains(candidate)))
+    }
+
+    /// Render the latitude/longitude/color channel selectors
+    fn render_track_map_selectors(&mut self, ui: &mut egui::Ui, tab_idx: usize, file_index: usize) {
+        let file = &self.files[file_index];
+        let sorted_channels = sort_channels_by_priority(
+            file.log.channels.len(),
+            |idx| file.log.channels[idx].name(),
+            self.field_normalization,
+            Some(&self.custom_normalizations),
+        );
+        let channel_names: std::collections::HashMap<usize, String> = sorted_channels
+            .iter()
+            .map(|(idx, name, _)| (*idx, name.clone()))
+            .collect();
+
+        let state = &self.tabs[tab_idx].track_map_state;
+        let current = [state.lat_channel, state.lon_channel, state.color_channel];
+        let mut updated = current;
+
+        ui.horizontal(|ui| {
+            for (slot, (label, id)) in [
+                ("Latitude:", "track_lat"),
+                ("Longitude:", "track_lon"),
+                ("Color By:", "track_color"),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                ui.label(label);
+                egui::ComboBox::from_id_salt(id)
+                    .selected_text(
+                        current[slot]
+                            .and_then(|i| channel_names.get(&i).map(|n| n.as_str()))
+                            .unwrap_or(if slot == 2 { "None" } else { "Select..." }),
+                    )
+                    .width(140.0)
+                    .show_ui(ui, |ui| {
+                        if slot == 2 && ui.selectable_label(current[2].is_none(), "None").clicked()
+                        {
+                            updated[2] = None;
+                        }
+                        for (idx, name, _) in &sorted_channels {
+                            if ui
+                                .selectable_label(current[slot] == Some(*idx), name)
+                                .clicked()
+                            {
+                                updated[slot] = Some(*idx);
+                            }
+                        }
+                    });
+                ui.add_space(16.0);
+            }
+        });
+
+        let state = &mut self.tabs[tab_idx].track_map_state;
+        state.lat_channel = updated[0];
+        state.lon_channel = updated[1];
+        state.color_channel = updated[2];
+    }
+
+    /// Render the track path, color legend, and cursor marker
+    fn render_track_map_plot(
+        &mut self,
+        ui: &mut egui::Ui,
+        file_index: usize,
+        lat_idx: usize,
+        lon_idx: usize,
+        color_idx: Option<usize>,
+    ) {
+        let file = &self.files[file_index];
+        let lats = file.log.get_channel_data(lat_idx);
+        let lons = file.log.get_channel_data(lon_idx);
+        let color_values: Option<Vec<f64>> = color_idx.map(|idx| {
+            let unit = file.log.channels[idx].unit();
+            file.log
+                .get_channel_data(idx)
+                .iter()
+                .map(|&v| self.active_unit_preferences().convert_value(v, unit).0)
+                .collect()
+        });
+
+        // Records with a valid fix (0,0 means no fix on most loggers)
+        let valid: Vec<usize> = (0..lats.len().min(lons.len()))
+            .filter(|&i| {
+                let (lat, lon) = (lats[i], lons[i]);
+                lat.is_finite()
+                    && lon.is_finite()
+                    && (-90.0..=90.0).contains(&lat)
+                    && (-180.0..=180.0).contains(&lon)
+                    && !(lat == 0.0 && lon == 0.0)
+            })
+            .collect();
+
+        if valid.len() < 2 {
+            ui.centered_and_justified(|ui| {
+                ui.label(
+                    egui::RichText::new("No valid GPS fixes in this log")
+                        .size(18.0)
+                        .color(egui::Color32::GRAY),
+                );
+            });
+            return;
+        }
+
+        // Equirectangular projection scaled around the mean latitude
+        let mean_lat = valid.iter().map(|&i| lats[i]).sum::<f64>() / valid.len() as f64;
+        let lon_scale = mean_lat.to_radians().cos();
+        let project = |i: usize| [lons[i] * lon_scale, lats[i]];
+
+        // Decimate long tracks
+        let stride = valid.len().div_ceil(MAX_TRACK_POINTS).max(1);
+        let path: Vec<usize> = valid.iter().copied().step_by(stride).collect();
+
+        // Color range
+        let color_range = color_values.as_ref().map(|values| {
+            let (min, max) = path.iter().fold((f64::MAX, f64::MIN), |(lo, hi), &i| {
+                (lo.min(values[i]), hi.max(values[i]))
+            });
+            (min, max)
+        });
+
+        // Split the path into runs that share a color bucket
+        let bucket_of = |i: usize| -> usize {
+            match (&color_values, color_range) {
+                (Some(values), Some((min, max))) if max > min => {
+                    let t = (values[i] - min) / (max - min);
+                    ((t * (TRACK_COLOR_BUCKETS - 1) as f64).round() as usize)
+                        .min(TRACK_COLOR_BUCKETS - 1)
+                }
+                _ => 0,
+            }
+        };
+
+        let mut runs: Vec<(usize, Vec<[f64; 2]>)> = Vec::new();
+        for &i in &path {
+            let bucket = bucket_of(i);
+            let point = project(i);
+            match runs.last_mut() {
+                Some((b, points)) if *b == bucket => points.push(point),
+                Some((_, points)) => {
+                    // Start a new run that connects to the previous point
+                    let last = *points.last().unwrap_or(&point);
+                    runs.push((bucket, vec![last, point]));
+                }
+                None => runs.push((bucket, vec![point])),
+            }
+        }
+
+        let cursor_point = self
+            .get_cursor_record()
+            .filter(|&r| valid.binary_search(&r).is_ok())
+            .or_else(|| {
+                // Fall back to the nearest valid record before the cursor
+                let record = self.get_cursor_record()?;
+                let pos = valid.partition_point(|&i| i <= record);
+                valid.get(pos.saturating_sub(1)).copied()
+            })
+            .map(project);
+
+        let has_color = color_values.is_some();
+
+        // Legend for the color channel
+        if let (Some((min, max)), Some(idx)) = (color_range, color_idx) {
+            let unit = self
+                .active_unit_preferences()
+                .convert_value(0.0, file.log.channels[idx].unit())
+                .1
+                .to_string();
+            ui.horizontal(|ui| {
+                ui.label(format!("{:.1} {}", min, unit));
+                let (rect, _) =
+                    ui.allocate_exact_size(egui::vec2(200.0, 12.0), egui::Sense::hover());
+                let steps = 50;
+                let step_width = rect.width() / steps as f32;
+                for s in 0..steps {
+                    let x = rect.left() + s as f32 * step_width;
+                    ui.painter().rect_filled(
+                        egui::Rect::from_min_size(
+                            egui::pos2(x, rect.top()),
+                            egui::vec2(step_width + 0.5, rect.height()),
+                        ),
+                        0.0,
+                        Self::get_heat_color(s as f64 / (steps - 1) as f64),
+                    );
+                }
+                ui.label(format!("{:.1} {}", max, unit));
+            });
+            ui.add_space(4.0);
+        }
+
+        let response = Plot::new("track_map")
+            .data_aspect(1.0)
+            .show_axes([false, false])
+            .show_grid(false)
+            .allow_boxed_zoom(false)
+            .show(ui, |plot_ui| {
+                for (bucket, points) in runs {
+                    let color = if has_color {
+                        Self::get_heat_color(bucket as f64 / (TRACK_COLOR_BUCKETS - 1) as f64)
+                    } else {
+                        egui::Color32::from_rgb(191, 78, 48)
+                    };
+                    plot_ui.line(
+                        Line::new("", PlotPoints::from(points))
+                            .color(color)
+                            .width(2.5),
+                    );
+                }
+
+                if let Some(point) = cursor_point {
+                    plot_ui.points(
+                        Points::new("Cursor", vec![point])
+                            .shape(MarkerShape::Circle)
+                            .radius(6.0)
+                            .filled(true)
+                            .color(MARKER_COLOR),
+                    );
+                }
+
+                plot_ui.pointer_coordinate()
+            });
+
+        // Click on the map moves the cursor to the nearest point on the track
+        if response.response.clicked() {
+            if let Some(pos) = response.inner {
+                let nearest = valid.iter().copied().min_by(|&a, &b| {
+                    let pa = project(a);
+                    let pb = project(b);
+                    let da = (pa[0] - pos.x).powi(2) + (pa[1] - pos.y).powi(2);
+                    let db = (pb[0] - pos.x).powi(2) + (pb[1] - pos.y).powi(2);
+                    da.total_cmp(&db)
+                });
+                if let Some(record) = nearest {
+                    let time = self.files[file_index].log.times[record];
+                    self.is_playing = false;
+                    self.last_frame_time = None;
+                    self.set_cursor_time(Some(time));
+                    self.set_cursor_record(Some(record));
+                    ui.ctx().request_repaint();
+                }
+            }
+        }
+    }
+}
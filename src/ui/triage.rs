@@ -0,0 +1,310 @@
+//! Unsupported file triage dialog.
+//!
+//! Shown when no parser recognises a file. Displays a text or hex preview of
+//! the first few KB and offers two ways forward: load the file with the
+//! generic CSV parser using a user-chosen delimiter and time column, or open
+//! a pre-filled format request on GitHub with the header snippet.
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+use crate::parsers::generic_csv::DELIMITERS;
+use crate::parsers::GenericCsv;
+use crate::state::{TriageState, UnsupportedFile};
+
+/// GitHub new-issue URL used for format requests
+const FORMAT_REQUEST_URL: &str = "https://github.com/SomethingNew71/UltraLog/issues/new";
+
+/// Maximum number of header lines included in a format request
+const SNIPPET_LINES: usize = 15;
+
+/// Maximum characters per line included in a format request
+const SNIPPET_LINE_CHARS: usize = 300;
+
+impl UltraLogApp {
+    /// Open the triage dialog for a file no parser recognised
+    pub fn open_triage(&mut self, file: UnsupportedFile) {
+        let text = String::from_utf8_lossy(&file.preview);
+        let delimiter = GenericCsv::guess_delimiter(&text);
+
+        self.triage = Some(TriageState {
+            file,
+            show_hex: false,
+            delimiter,
+            header_line: 0,
+            time_column: 0,
+        });
+    }
+
+    /// Render the unsupported file triage dialog
+    pub fn render_triage_dialog(&mut self, ctx: &egui::Context) {
+        let lang = self.language;
+        let Some(triage) = &mut self.triage else {
+            return;
+        };
+
+        let mut open = true;
+        let mut load_as_csv = false;
+        let mut request_format = false;
+        let mut copy_snippet = false;
+
+        let file_name = triage
+            .file
+            .path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| "Unknown".to_string());
+
+        let preview_text = String::from_utf8_lossy(&triage.file.preview).to_string();
+        let preview_lines: Vec<&str> = preview_text.lines().collect();
+
+        egui::Window::new(lang.tr("Unsupported File"))
+            .open(&mut open)
+            .resizable(true)
+            .collapsible(false)
+            .default_width(640.0)
+            .default_height(520.0)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} \"{}\"",
+                        lang.tr("UltraLog couldn't recognise"),
+                        file_name
+                    ))
+                        .size(16.0)
+                        .strong(),
+                );
+                ui.label(egui::RichText::new(&triage.file.reason).color(egui::Color32::GRAY));
+
+                ui.add_space(8.0);
+                ui.separator();
+
+                // --- Preview ---
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(lang.tr("Preview")).strong());
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "({} {} {})",
+                            lang.tr("first"),
+                            triage.file.preview.len(),
+                            lang.tr("bytes")
+                        ))
+                            .color(egui::Color32::GRAY),
+                    );
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        ui.selectable_value(&mut triage.show_hex, true, "Hex");
+                        ui.selectable_value(&mut triage.show_hex, false, lang.tr("Text"));
+                    });
+                });
+
+                egui::ScrollArea::both()
+                    .id_salt("triage_preview_scroll")
+                    .max_height(200.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        let preview = if triage.show_hex {
+                            hex_dump(&triage.file.preview)
+                        } else {
+                            preview_lines
+                                .iter()
+                                .enumerate()
+                                .map(|(i, line)| format!("{:>4}  {}", i + 1, line))
+                                .collect::<Vec<_>>()
+                                .join("\n")
+                        };
+                        ui.label(egui::RichText::new(preview).monospace().size(11.0));
+                    });
+
+                ui.add_space(8.0);
+                ui.separator();
+
+                // --- Generic CSV options ---
+                ui.label(egui::RichText::new(lang.tr("Load as delimited text")).strong());
+                ui.label(
+                    egui::RichText::new(lang.tr(
+                        "Pick the delimiter, header line and time column. Every other column becomes a channel.",
+                    ))
+                    .color(egui::Color32::GRAY),
+                );
+                ui.add_space(4.0);
+
+                let max_header_line = preview_lines.len().saturating_sub(1);
+                triage.header_line = triage.header_line.min(max_header_line);
+                let columns = preview_lines
+                    .get(triage.header_line)
+                    .map(|line| GenericCsv::split_line(line, triage.delimiter))
+                    .unwrap_or_default();
+                if triage.time_column >= columns.len() {
+                    triage.time_column = 0;
+                }
+
+                egui::Grid::new("triage_options_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.label(lang.tr("Delimiter:"));
+                        let delimiter_name = DELIMITERS
+                            .iter()
+                            .find(|(d, _)| *d == triage.delimiter)
+                            .map(|(_, name)| lang.tr(name))
+                            .unwrap_or(lang.tr("Comma"));
+                        egui::ComboBox::from_id_salt("triage_delimiter")
+                            .selected_text(delimiter_name)
+                            .width(140.0)
+                            .show_ui(ui, |ui| {
+                                for (delimiter, name) in DELIMITERS {
+                                    ui.selectable_value(&mut triage.delimiter, *delimiter, lang.tr(name));
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label(lang.tr("Header line:"));
+                        let mut header_display = triage.header_line + 1;
+                        if ui
+                            .add(
+                                egui::DragValue::new(&mut header_display)
+                                    .range(1..=max_header_line + 1),
+                            )
+                            .changed()
+                        {
+                            triage.header_line = header_display - 1;
+                        }
+                        ui.end_row();
+
+                        ui.label(lang.tr("Time column:"));
+                        egui::ComboBox::from_id_salt("triage_time_column")
+                            .selected_text(
+                                columns
+                                    .get(triage.time_column)
+                                    .map(|c| c.as_str())
+                                    .unwrap_or("---"),
+                            )
+                            .width(200.0)
+                            .show_ui(ui, |ui| {
+                                for (i, name) in columns.iter().enumerate() {
+                                    ui.selectable_value(&mut triage.time_column, i, name);
+                                }
+                            });
+                        ui.end_row();
+                    });
+
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} {}",
+                        columns.len(),
+                        lang.tr("columns detected")
+                    ))
+                        .color(egui::Color32::GRAY),
+                );
+
+                ui.add_space(8.0);
+                ui.separator();
+
+                // --- Actions ---
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(columns.len() > 1, egui::Button::new(lang.tr("Load as CSV")))
+                        .clicked()
+                    {
+                        load_as_csv = true;
+                    }
+
+                    if ui
+                        .button(lang.tr("Request Format Support"))
+                        .on_hover_text(lang.tr("Open a GitHub issue pre-filled with the file's header"))
+                        .clicked()
+                    {
+                        request_format = true;
+                    }
+
+                    if ui.button(lang.tr("Copy Header")).clicked() {
+                        copy_snippet = true;
+                    }
+                });
+            });
+
+        let snippet = header_snippet(&preview_lines);
+
+        if copy_snippet {
+            ctx.copy_text(snippet.clone());
+            self.show_toast(lang.tr("Header copied to clipboard"));
+        }
+
+        if request_format {
+            let title = format!("Format request: {}", file_name);
+            let body = format!(
+                "**File:** {}\n**Detection:** {}\n\n**Header snippet:**\n```\n{}\n```\n\n**ECU / logging software:** \n",
+                file_name, self.triage.as_ref().map(|t| t.file.reason.as_str()).unwrap_or(""), snippet
+            );
+            let url = format!(
+                "{}?title={}&body={}",
+                FORMAT_REQUEST_URL,
+                percent_encode(&title),
+                percent_encode(&body)
+            );
+            if open::that(&url).is_err() {
+                self.show_toast_error(lang.tr("Failed to open browser"));
+            }
+        }
+
+        if load_as_csv {
+            if let Some(triage) = self.triage.take() {
+                let parser = GenericCsv {
+                    delimiter: triage.delimiter,
+                    header_line: triage.header_line,
+                    time_column: triage.time_column,
+                };
+                self.start_loading_generic_csv(triage.file.path, parser);
+            }
+        } else if !open {
+            self.triage = None;
+        }
+    }
+}
+
+/// Build a header snippet suitable for a format request
+fn header_snippet(lines: &[&str]) -> String {
+    lines
+        .iter()
+        .take(SNIPPET_LINES)
+        .map(|line| line.chars().take(SNIPPET_LINE_CHARS).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Format bytes as a classic 16-bytes-per-row hex dump
+fn hex_dump(bytes: &[u8]) -> String {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|b| format!("{:02X}", b)).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| {
+                    if b.is_ascii_graphic() || b == b' ' {
+                        b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            format!("{:08X}  {:<47}  {}", row * 16, hex.join(" "), ascii)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Percent-encode a string for use in a URL query parameter
+pub(crate) fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
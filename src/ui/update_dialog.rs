@@ -17,7 +17,7 @@ impl UltraLogApp {
         let mut open = true;
         let mut should_close = false;
 
-        egui::Window::new("Update Available")
+        egui::Window::new(self.language.tr("Update Available"))
             .open(&mut open)
             .resizable(false)
             .collapsible(false)
@@ -29,12 +29,13 @@ impl UltraLogApp {
                     let info_clone = info.clone();
                     self.render_update_available(ui, info_clone, &mut should_close);
                 }
-                UpdateState::Downloading => {
-                    self.render_downloading(ui);
+                &UpdateState::Downloading { downloaded, total } => {
+                    self.render_downloading(ui, downloaded, total);
                 }
-                UpdateState::ReadyToInstall(path) => {
+                UpdateState::ReadyToInstall { path, verified } => {
                     let path_clone = path.clone();
-                    self.render_ready_to_install(ui, &path_clone, &mut should_close);
+                    let verified = *verified;
+                    self.render_ready_to_install(ui, &path_clone, verified, &mut should_close);
                 }
                 UpdateState::Error(e) => {
                     let error = e.clone();
@@ -46,8 +47,10 @@ impl UltraLogApp {
         if !open || should_close {
             self.show_update_dialog = false;
             // Reset state if user dismissed without updating
-            if matches!(self.update_state, UpdateState::UpdateAvailable(_)) {
-                self.update_state = UpdateState::Idle;
+            match self.update_state {
+                UpdateState::UpdateAvailable(_) => self.update_state = UpdateState::Idle,
+                UpdateState::Downloading { .. } => self.cancel_update_download(),
+                _ => {}
             }
         }
     }
@@ -58,11 +61,12 @@ impl UltraLogApp {
         info: UpdateInfo,
         should_close: &mut bool,
     ) {
+        let lang = self.language;
         ui.vertical_centered(|ui| {
             ui.add_space(10.0);
 
             ui.label(
-                egui::RichText::new("A new version is available!")
+                egui::RichText::new(lang.tr("A new version is available!"))
                     .size(18.0)
                     .strong(),
             );
@@ -71,12 +75,12 @@ impl UltraLogApp {
 
             // Version comparison
             ui.horizontal(|ui| {
-                ui.label("Current version:");
+                ui.label(lang.tr("Current version:"));
                 ui.label(egui::RichText::new(&info.current_version).color(egui::Color32::GRAY));
             });
 
             ui.horizontal(|ui| {
-                ui.label("New version:");
+                ui.label(lang.tr("New version:"));
                 ui.label(
                     egui::RichText::new(&info.new_version)
                         .color(egui::Color32::LIGHT_GREEN)
@@ -89,7 +93,7 @@ impl UltraLogApp {
             // Download size
             let size_mb = info.download_size as f64 / (1024.0 * 1024.0);
             ui.label(
-                egui::RichText::new(format!("Download size: {:.1} MB", size_mb))
+                egui::RichText::new(format!("{} {:.1} MB", lang.tr("Download size:"), size_mb))
                     .color(egui::Color32::GRAY),
             );
 
@@ -97,7 +101,7 @@ impl UltraLogApp {
 
             // Release notes (scrollable, collapsible)
             if let Some(notes) = &info.release_notes {
-                egui::CollapsingHeader::new("Release Notes")
+                egui::CollapsingHeader::new(lang.tr("Release Notes"))
                     .default_open(true)
                     .show(ui, |ui| {
                         egui::ScrollArea::vertical()
@@ -108,19 +112,55 @@ impl UltraLogApp {
                     });
             }
 
+            // Without a checksum the download can't be verified, so it
+            // needs the user's explicit agreement
+            if info.sha256.is_none() {
+                ui.add_space(10.0);
+                ui.label(
+                    egui::RichText::new(lang.tr("⚠ No checksum was published for this release"))
+                        .color(egui::Color32::from_rgb(253, 193, 73)),
+                );
+                ui.checkbox(
+                    &mut self.allow_unverified_update,
+                    lang.tr("Download without checksum verification"),
+                );
+            }
+
             ui.add_space(15.0);
 
             // Action buttons
             ui.horizontal(|ui| {
-                if ui.button("Download & Install").clicked() {
-                    self.start_update_download(info.download_url.clone());
+                let can_download = info.sha256.is_some() || self.allow_unverified_update;
+                if ui
+                    .add_enabled(
+                        can_download,
+                        egui::Button::new(lang.tr("Download & Install")),
+                    )
+                    .clicked()
+                {
+                    self.start_update_download(&info);
                 }
 
-                if ui.button("View on GitHub").clicked() {
+                if ui.button(lang.tr("View on GitHub")).clicked() {
                     let _ = open::that(&info.release_page_url);
                 }
 
-                if ui.button("Later").clicked() {
+                if ui
+                    .button(lang.tr("Remind Me Later"))
+                    .on_hover_text(lang.tr("Ask again the next time UltraLog starts"))
+                    .clicked()
+                {
+                    *should_close = true;
+                }
+
+                if ui
+                    .button(lang.tr("Skip This Version"))
+                    .on_hover_text(lang.tr("Don't offer this version again on startup"))
+                    .clicked()
+                {
+                    if !self.skipped_versions.contains(&info.new_version) {
+                        self.skipped_versions.push(info.new_version.clone());
+                    }
                     *should_close = true;
                 }
             });
@@ -129,19 +169,51 @@ impl UltraLogApp {
         });
     }
 
-    fn render_downloading(&self, ui: &mut egui::Ui) {
+    fn render_downloading(&mut self, ui: &mut egui::Ui, downloaded: u64, total: Option<u64>) {
+        let lang = self.language;
         ui.vertical_centered(|ui| {
             ui.add_space(20.0);
 
-            ui.label(egui::RichText::new("Downloading update...").size(16.0));
+            ui.label(egui::RichText::new(lang.tr("Downloading update...")).size(16.0));
 
             ui.add_space(15.0);
 
-            ui.spinner();
+            let downloaded_mb = downloaded as f64 / (1024.0 * 1024.0);
+            match total.filter(|&total| total > 0) {
+                Some(total) => {
+                    let fraction = (downloaded as f32 / total as f32).min(1.0);
+                    ui.add(
+                        egui::ProgressBar::new(fraction)
+                            .show_percentage()
+                            .desired_width(300.0),
+                    );
+                    ui.add_space(5.0);
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{:.1} {} {:.1} MB",
+                            downloaded_mb,
+                            lang.tr("of"),
+                            total as f64 / (1024.0 * 1024.0)
+                        ))
+                        .color(egui::Color32::GRAY),
+                    );
+                }
+                None => {
+                    // Without a Content-Length the size is unknown
+                    ui.spinner();
+                    ui.add_space(5.0);
+                    ui.label(
+                        egui::RichText::new(format!("{:.1} MB", downloaded_mb))
+                            .color(egui::Color32::GRAY),
+                    );
+                }
+            }
 
             ui.add_space(15.0);
 
-            ui.label(egui::RichText::new("Please wait...").color(egui::Color32::GRAY));
+            if ui.button(lang.tr("Cancel")).clicked() {
+                self.cancel_update_download();
+            }
 
             ui.add_space(20.0);
         });
@@ -151,56 +223,100 @@ impl UltraLogApp {
         &mut self,
         ui: &mut egui::Ui,
         path: &std::path::Path,
+        verified: bool,
         should_close: &mut bool,
     ) {
+        let lang = self.language;
         ui.vertical_centered(|ui| {
             ui.add_space(20.0);
 
             ui.label(
-                egui::RichText::new("Download complete!")
+                egui::RichText::new(lang.tr("Download complete!"))
                     .size(16.0)
                     .color(egui::Color32::LIGHT_GREEN),
             );
 
-            ui.add_space(15.0);
+            ui.add_space(5.0);
 
-            ui.label("Click Install to open the update file.");
+            if verified {
+                ui.label(
+                    egui::RichText::new(lang.tr("✔ SHA-256 checksum verified"))
+                        .color(egui::Color32::GRAY),
+                );
+            } else {
+                ui.label(
+                    egui::RichText::new(lang.tr("⚠ No checksum was published for this release"))
+                        .color(egui::Color32::from_rgb(253, 193, 73)),
+                );
+            }
 
-            #[cfg(target_os = "windows")]
-            ui.label(
-                egui::RichText::new("Extract the ZIP and replace the application.")
-                    .color(egui::Color32::GRAY),
-            );
+            ui.add_space(15.0);
+
+            if !verified {
+                // Unverified downloads are only installed by hand
+                ui.label(lang.tr("Open the download folder to install the update by hand."));
+            } else if crate::updater::can_self_update() {
+                ui.label(
+                    lang.tr(
+                        "Click Install to replace UltraLog with the new version and restart it.",
+                    ),
+                );
+            } else {
+                ui.label(lang.tr("Click Install to open the update file."));
+            }
 
             #[cfg(target_os = "macos")]
             ui.label(
-                egui::RichText::new("Open the DMG and drag the app to Applications.")
+                egui::RichText::new(lang.tr("Open the DMG and drag the app to Applications."))
                     .color(egui::Color32::GRAY),
             );
 
             #[cfg(target_os = "linux")]
             ui.label(
-                egui::RichText::new("Extract the archive and replace the binary.")
+                egui::RichText::new(lang.tr("Extract the archive and replace the binary."))
                     .color(egui::Color32::GRAY),
             );
 
             ui.add_space(15.0);
 
             ui.horizontal(|ui| {
-                if ui.button("Install Now").clicked() {
+                if !verified {
+                    if ui.button(lang.tr("Open Folder")).clicked() {
+                        if let Err(e) = crate::updater::open_download_folder(path) {
+                            self.show_toast_error(&e);
+                        }
+                    }
+                } else if crate::updater::can_self_update() {
+                    if ui.button(lang.tr("Install and Restart")).clicked() {
+                        match crate::updater::install_and_relaunch(path, verified) {
+                            // Closing normally saves settings before the swap
+                            Ok(()) => ui.ctx().send_viewport_cmd(egui::ViewportCommand::Close),
+                            Err(e) => self.show_toast_error(&e),
+                        }
+                    }
+                    if ui
+                        .button(lang.tr("Open ZIP"))
+                        .on_hover_text(lang.tr("Install the update by hand instead"))
+                        .clicked()
+                    {
+                        if let Err(e) = crate::updater::install_update(path) {
+                            self.show_toast_error(&e);
+                        }
+                    }
+                } else if ui.button(lang.tr("Install Now")).clicked() {
                     if let Err(e) = crate::updater::install_update(path) {
                         self.show_toast_error(&e);
                     } else {
                         self.show_toast_success(
-                            "Update file opened. Follow the installer instructions.",
+                            lang.tr("Update file opened. Follow the installer instructions."),
                         );
                         *should_close = true;
                         self.update_state = UpdateState::Idle;
                     }
                 }
 
-                if ui.button("Install Later").clicked() {
-                    self.show_toast("Update saved to your temp folder.");
+                if ui.button(lang.tr("Install Later")).clicked() {
+                    self.show_toast(lang.tr("Update saved to your temp folder."));
                     *should_close = true;
                     self.update_state = UpdateState::Idle;
                 }
@@ -211,11 +327,12 @@ impl UltraLogApp {
     }
 
     fn render_update_error(&mut self, ui: &mut egui::Ui, error: &str, should_close: &mut bool) {
+        let lang = self.language;
         ui.vertical_centered(|ui| {
             ui.add_space(20.0);
 
             ui.label(
-                egui::RichText::new("Update Error")
+                egui::RichText::new(lang.tr("Update Error"))
                     .size(16.0)
                     .color(egui::Color32::from_rgb(191, 78, 48)),
             );
@@ -226,7 +343,7 @@ impl UltraLogApp {
 
             ui.add_space(15.0);
 
-            if ui.button("Close").clicked() {
+            if ui.button(lang.tr("Close")).clicked() {
                 *should_close = true;
                 self.update_state = UpdateState::Idle;
             }
@@ -0,0 +1,139 @@
+//! Welcome view.
+//!
+//! Fills the log viewer while no files are open, with quick ways to get
+//! started: open a log, try one of the sample logs bundled with the app, or
+//! read the documentation.
+
+use std::path::PathBuf;
+
+use eframe::egui;
+
+use crate::app::UltraLogApp;
+
+/// Project wiki with the user guide
+const DOCS_URL: &str = "https://github.com/SomethingNew71/UltraLog/wiki";
+
+/// A log bundled with the app for trying it out
+struct SampleLog {
+    /// Button label
+    label: &'static str,
+    /// File name the sample is written to, which the tab shows
+    file_name: &'static str,
+    bytes: &'static [u8],
+}
+
+const SAMPLE_LOGS: &[SampleLog] = &[
+    SampleLog {
+        label: "Haltech sample",
+        file_name: "haltech-sample.csv",
+        bytes: include_bytes!("../../assets/samples/haltech-sample.csv"),
+    },
+    SampleLog {
+        label: "Speeduino sample",
+        file_name: "speeduino-sample.mlg",
+        bytes: include_bytes!("../../assets/samples/speeduino-sample.mlg"),
+    },
+];
+
+impl UltraLogApp {
+    /// Render the welcome view in the central panel
+    pub fn render_welcome(&mut self, ui: &mut egui::Ui) {
+        let primary_color = egui::Color32::from_rgb(113, 120, 78); // Olive green
+        let amber = egui::Color32::from_rgb(253, 193, 73);
+        let is_loading = self.loading_state.is_loading();
+        let lang = self.language;
+
+        let mut open_file = false;
+        let mut sample: Option<&SampleLog> = None;
+
+        ui.vertical_centered(|ui| {
+            ui.add_space((ui.available_height() * 0.2).max(20.0));
+
+            ui.label(
+                egui::RichText::new(lang.tr("Welcome to UltraLog"))
+                    .size(28.0)
+                    .strong()
+                    .color(amber),
+            );
+            ui.add_space(6.0);
+            ui.label(
+                egui::RichText::new(lang.tr("Open an ECU log to chart and analyze it"))
+                    .size(15.0)
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(24.0);
+
+            ui.add_enabled_ui(!is_loading, |ui| {
+                let open_button = egui::Button::new(
+                    egui::RichText::new(lang.tr("📂  Open Log File..."))
+                        .size(16.0)
+                        .color(egui::Color32::WHITE),
+                )
+                .fill(primary_color)
+                .corner_radius(6)
+                .min_size(egui::vec2(220.0, 36.0));
+                if ui.add(open_button).clicked() {
+                    open_file = true;
+                }
+
+                ui.add_space(8.0);
+                ui.label(
+                    egui::RichText::new(lang.tr("or drop files anywhere on the window"))
+                        .size(12.0)
+                        .color(egui::Color32::GRAY),
+                );
+
+                ui.add_space(24.0);
+                ui.label(egui::RichText::new(lang.tr("No log handy? Try a sample:")).size(14.0));
+                ui.add_space(6.0);
+                for log in SAMPLE_LOGS {
+                    if ui
+                        .add(
+                            egui::Button::new(lang.tr(log.label)).min_size(egui::vec2(220.0, 28.0)),
+                        )
+                        .on_hover_text(log.file_name)
+                        .clicked()
+                    {
+                        sample = Some(log);
+                    }
+                }
+            });
+
+            ui.add_space(24.0);
+            if ui.link(lang.tr("📖  Read the user guide")).clicked() {
+                let _ = open::that(DOCS_URL);
+            }
+        });
+
+        if open_file {
+            if let Some(path) = rfd::FileDialog::new()
+                .add_filter(lang.tr("Log Files"), crate::parsers::LOG_EXTENSIONS)
+                .pick_file()
+            {
+                self.start_loading_file(path);
+            }
+        }
+
+        if let Some(log) = sample {
+            match write_sample(log) {
+                Ok(path) => self.start_loading_file(path),
+                Err(e) => self.show_toast_error(&format!(
+                    "{} {}",
+                    lang.tr("Failed to open sample log:"),
+                    e
+                )),
+            }
+        }
+    }
+}
+
+/// Write a bundled sample to disk so it loads like any other file
+fn write_sample(log: &SampleLog) -> std::io::Result<PathBuf> {
+    let dir = crate::data_dir::data_dir()
+        .unwrap_or_else(std::env::temp_dir)
+        .join("samples");
+    std::fs::create_dir_all(&dir)?;
+    let path = dir.join(log.file_name);
+    std::fs::write(&path, log.bytes)?;
+    Ok(path)
+}
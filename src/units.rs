@@ -3,8 +3,10 @@
 //! This module provides user-configurable unit preferences for displaying
 //! ECU log data in various measurement systems (metric, imperial, etc.).
 
+use serde::{Deserialize, Serialize};
+
 /// Temperature unit preference
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub enum TemperatureUnit {
     Kelvin,
     #[default]
@@ -21,6 +23,34 @@ impl TemperatureUnit {
         }
     }
 
+    /// Symbol for a temperature difference in this unit, e.g. "Δ°C"
+    pub fn difference_symbol(&self) -> &'static str {
+        match self {
+            TemperatureUnit::Kelvin => "ΔK",
+            TemperatureUnit::Celsius => "Δ°C",
+            TemperatureUnit::Fahrenheit => "Δ°F",
+        }
+    }
+
+    /// Recognise a temperature unit as written in a log ("K", "°C", "C",
+    /// "degF", ...)
+    pub fn from_source_unit(unit: &str) -> Option<Self> {
+        let unit = unit.trim();
+        match unit {
+            "K" => Some(TemperatureUnit::Kelvin),
+            "°C" | "C" | "℃" => Some(TemperatureUnit::Celsius),
+            "°F" | "F" | "℉" => Some(TemperatureUnit::Fahrenheit),
+            _ => {
+                let lower = unit.to_ascii_lowercase().replace(' ', "");
+                match lower.as_str() {
+                    "degc" | "deg.c" => Some(TemperatureUnit::Celsius),
+                    "degf" | "deg.f" => Some(TemperatureUnit::Fahrenheit),
+                    _ => None,
+                }
+            }
+        }
+    }
+
     /// Convert from Kelvin to the selected unit
     pub fn convert_from_kelvin(&self, kelvin: f64) -> f64 {
         match self {
@@ -29,10 +59,38 @@ impl TemperatureUnit {
             TemperatureUnit::Fahrenheit => (kelvin - 273.15) * 9.0 / 5.0 + 32.0,
         }
     }
+
+    /// Convert a value in this unit to Kelvin
+    pub fn to_kelvin(&self, value: f64) -> f64 {
+        match self {
+            TemperatureUnit::Kelvin => value,
+            TemperatureUnit::Celsius => value + 273.15,
+            TemperatureUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0 + 273.15,
+        }
+    }
+
+    /// Convert a value from `source` to the selected unit
+    pub fn convert_from(&self, value: f64, source: TemperatureUnit) -> f64 {
+        if source == *self {
+            value
+        } else {
+            self.convert_from_kelvin(source.to_kelvin(value))
+        }
+    }
+
+    /// Convert a temperature difference from `source` to the selected unit.
+    /// Unlike [`Self::convert_from`] there is no offset, only the scale.
+    pub fn convert_difference_from(&self, value: f64, source: TemperatureUnit) -> f64 {
+        let scale = |unit: &TemperatureUnit| match unit {
+            TemperatureUnit::Kelvin | TemperatureUnit::Celsius => 1.0,
+            TemperatureUnit::Fahrenheit => 9.0 / 5.0,
+        };
+        value * scale(self) / scale(&source)
+    }
 }
 
 /// Pressure unit preference
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub enum PressureUnit {
     #[default]
     KPa,
@@ -60,7 +118,7 @@ impl PressureUnit {
 }
 
 /// Speed unit preference
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub enum SpeedUnit {
     #[default]
     KmH,
@@ -85,7 +143,7 @@ impl SpeedUnit {
 }
 
 /// Distance unit preference
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub enum DistanceUnit {
     #[default]
     Kilometers,
@@ -110,7 +168,7 @@ impl DistanceUnit {
 }
 
 /// Fuel economy unit preference
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub enum FuelEconomyUnit {
     #[default]
     LPer100Km,
@@ -150,7 +208,7 @@ impl FuelEconomyUnit {
 }
 
 /// Volume unit preference
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub enum VolumeUnit {
     #[default]
     Liters,
@@ -175,7 +233,7 @@ impl VolumeUnit {
 }
 
 /// Flow rate unit preference
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub enum FlowUnit {
     #[default]
     CcPerMin,
@@ -201,7 +259,7 @@ impl FlowUnit {
 }
 
 /// Acceleration unit preference
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub enum AccelerationUnit {
     #[default]
     MPerS2,
@@ -225,8 +283,83 @@ impl AccelerationUnit {
     }
 }
 
+/// Air-fuel ratio display preference for lambda channels
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum AfrUnit {
+    #[default]
+    Lambda,
+    /// AFR for gasoline (stoich 14.7)
+    Gasoline,
+    /// AFR for E85 (stoich 9.8)
+    E85,
+    /// AFR with a user-defined stoichiometric ratio
+    Custom(f64),
+}
+
+impl AfrUnit {
+    /// Default stoichiometric ratio offered for a new custom fuel
+    pub const DEFAULT_CUSTOM_STOICH: f64 = 14.7;
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            AfrUnit::Lambda => "λ",
+            _ => "AFR",
+        }
+    }
+
+    /// Stoichiometric ratio used to convert lambda, if displaying AFR
+    pub fn stoich_ratio(&self) -> Option<f64> {
+        match self {
+            AfrUnit::Lambda => None,
+            AfrUnit::Gasoline => Some(14.7),
+            AfrUnit::E85 => Some(9.8),
+            AfrUnit::Custom(stoich) => Some(*stoich),
+        }
+    }
+
+    /// Convert from lambda to the selected unit
+    pub fn convert_from_lambda(&self, lambda: f64) -> f64 {
+        self.stoich_ratio().map_or(lambda, |stoich| lambda * stoich)
+    }
+}
+
+/// Manifold pressure display preference
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum BoostUnit {
+    /// Show MAP as absolute pressure using the pressure unit
+    #[default]
+    Absolute,
+    /// Show boost in PSI above, and vacuum in inHg below, this baro (kPa)
+    Gauge(f64),
+}
+
+impl BoostUnit {
+    /// Standard sea-level barometric pressure in kPa
+    pub const STANDARD_BARO_KPA: f64 = 101.325;
+
+    /// Convert absolute MAP in kPa to boost (PSI) or vacuum (inHg).
+    /// A measured `baro_kpa` takes precedence over the configured constant.
+    /// Returns None when displaying absolute pressure.
+    pub fn convert_from_map(
+        &self,
+        map_kpa: f64,
+        baro_kpa: Option<f64>,
+    ) -> Option<(f64, &'static str)> {
+        let BoostUnit::Gauge(default_baro) = self else {
+            return None;
+        };
+        let gauge_kpa = map_kpa - baro_kpa.unwrap_or(*default_baro);
+        if gauge_kpa >= 0.0 {
+            Some((gauge_kpa * 0.145038, "PSI"))
+        } else {
+            Some((-gauge_kpa * 0.2953, "inHg"))
+        }
+    }
+}
+
 /// User preferences for display units
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct UnitPreferences {
     pub temperature: TemperatureUnit,
     pub pressure: PressureUnit,
@@ -236,18 +369,33 @@ pub struct UnitPreferences {
     pub volume: VolumeUnit,
     pub flow: FlowUnit,
     pub acceleration: AccelerationUnit,
+    pub afr: AfrUnit,
+    pub boost: BoostUnit,
 }
 
 impl UnitPreferences {
     /// Convert a value and get the display unit based on the source unit string
     /// Returns (converted_value, display_unit)
     pub fn convert_value<'a>(&self, value: f64, source_unit: &'a str) -> (f64, &'a str) {
-        match source_unit {
-            // Temperature (source is Kelvin)
-            "K" => (
-                self.temperature.convert_from_kelvin(value),
+        // Temperature (source may be Kelvin, Celsius or Fahrenheit)
+        if let Some(source) = TemperatureUnit::from_source_unit(source_unit) {
+            return (
+                self.temperature.convert_from(value, source),
                 self.temperature.symbol(),
-            ),
+            );
+        }
+        // Temperature difference, e.g. "Δ°C"
+        if let Some(source) = source_unit
+            .strip_prefix('Δ')
+            .and_then(TemperatureUnit::from_source_unit)
+        {
+            return (
+                self.temperature.convert_difference_from(value, source),
+                self.temperature.difference_symbol(),
+            );
+        }
+
+        match source_unit {
             // Pressure (source is kPa)
             "kPa" => (
                 self.pressure.convert_from_kpa(value),
@@ -271,8 +419,125 @@ impl UnitPreferences {
                 self.acceleration.convert_from_m_per_s2(value),
                 self.acceleration.symbol(),
             ),
+            // Air-fuel ratio (source is lambda)
+            "λ" | "lambda" | "Lambda" => (self.afr.convert_from_lambda(value), self.afr.symbol()),
             // No conversion needed for other units
             _ => (value, source_unit),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_preferences_round_trip() {
+        let units = UnitPreferences {
+            pressure: PressureUnit::PSI,
+            afr: AfrUnit::Custom(12.5),
+            boost: BoostUnit::Gauge(98.0),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&units).unwrap();
+        assert_eq!(
+            serde_json::from_str::<UnitPreferences>(&json).unwrap(),
+            units
+        );
+        // Kinds missing from older saved settings keep their defaults
+        let partial: UnitPreferences = serde_json::from_str(r#"{"pressure":"PSI"}"#).unwrap();
+        assert_eq!(partial.pressure, PressureUnit::PSI);
+        assert_eq!(partial.afr, AfrUnit::Lambda);
+    }
+
+    #[test]
+    fn test_temperature_source_units() {
+        assert_eq!(
+            TemperatureUnit::from_source_unit("°C"),
+            Some(TemperatureUnit::Celsius)
+        );
+        assert_eq!(
+            TemperatureUnit::from_source_unit("deg F"),
+            Some(TemperatureUnit::Fahrenheit)
+        );
+        assert_eq!(TemperatureUnit::from_source_unit("kPa"), None);
+
+        let fahrenheit = UnitPreferences {
+            temperature: TemperatureUnit::Fahrenheit,
+            ..Default::default()
+        };
+        let (value, unit) = fahrenheit.convert_value(100.0, "°C");
+        assert!(approx(value, 212.0));
+        assert_eq!(unit, "°F");
+        assert!(approx(fahrenheit.convert_value(373.15, "K").0, 212.0));
+        assert!(approx(fahrenheit.convert_value(212.0, "F").0, 212.0));
+
+        let celsius = UnitPreferences::default();
+        assert!(approx(celsius.convert_value(32.0, "°F").0, 0.0));
+        assert_eq!(celsius.convert_value(90.0, "C"), (90.0, "°C"));
+    }
+
+    #[test]
+    fn test_temperature_difference() {
+        let fahrenheit = UnitPreferences {
+            temperature: TemperatureUnit::Fahrenheit,
+            ..Default::default()
+        };
+        let (value, unit) = fahrenheit.convert_value(100.0, "Δ°C");
+        assert!(approx(value, 180.0));
+        assert_eq!(unit, "Δ°F");
+
+        let kelvin = UnitPreferences {
+            temperature: TemperatureUnit::Kelvin,
+            ..Default::default()
+        };
+        assert_eq!(kelvin.convert_value(50.0, "ΔC"), (50.0, "ΔK"));
+        assert!(approx(
+            UnitPreferences::default().convert_value(90.0, "ΔF").0,
+            50.0
+        ));
+    }
+
+    #[test]
+    fn test_afr_from_lambda() {
+        let lambda = UnitPreferences::default();
+        assert_eq!(lambda.convert_value(0.85, "λ"), (0.85, "λ"));
+
+        let e85 = UnitPreferences {
+            afr: AfrUnit::E85,
+            ..Default::default()
+        };
+        let (value, unit) = e85.convert_value(1.0, "λ");
+        assert!(approx(value, 9.8));
+        assert_eq!(unit, "AFR");
+
+        let custom = UnitPreferences {
+            afr: AfrUnit::Custom(14.64),
+            ..Default::default()
+        };
+        assert!(approx(custom.convert_value(0.5, "lambda").0, 7.32));
+    }
+
+    #[test]
+    fn test_boost_from_map() {
+        assert_eq!(BoostUnit::Absolute.convert_from_map(200.0, None), None);
+
+        let gauge = BoostUnit::Gauge(BoostUnit::STANDARD_BARO_KPA);
+        let (value, unit) = gauge.convert_from_map(200.0, None).unwrap();
+        assert!((value - 14.31).abs() < 0.01);
+        assert_eq!(unit, "PSI");
+
+        // Vacuum reads as positive inHg below baro
+        let (value, unit) = gauge.convert_from_map(40.0, None).unwrap();
+        assert!((value - 18.11).abs() < 0.01);
+        assert_eq!(unit, "inHg");
+
+        // A measured baro overrides the configured constant
+        let (value, _) = gauge.convert_from_map(200.0, Some(90.0)).unwrap();
+        assert!((value - 15.95).abs() < 0.01);
+    }
+}
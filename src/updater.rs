@@ -2,14 +2,35 @@
 //!
 //! Checks GitHub releases for new versions, downloads updates,
 //! and provides installation assistance.
+//!
+//! Downloads are checked against the SHA-256 published for the release
+//! asset, either the `digest` GitHub reports for it or a `<asset>.sha256`
+//! file uploaded alongside, and refused if they don't match. A release
+//! without a published checksum is only downloaded when the user explicitly
+//! agrees to an unverified download, and such a download is never installed
+//! in place.
+//!
+//! Requests go through an optional HTTP(S) proxy (falling back to the
+//! standard proxy environment variables) and time out, so a check on an
+//! offline or firewalled network fails quickly with a plain explanation.
 
-use serde::Deserialize;
-use std::io::Write;
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
-const GITHUB_API_URL: &str = "https://api.github.com/repos/SomethingNew71/UltraLog/releases/latest";
+/// Recent releases, newest first (including prereleases)
+const GITHUB_API_URL: &str =
+    "https://api.github.com/repos/SomethingNew71/UltraLog/releases?per_page=30";
 const USER_AGENT: &str = concat!("UltraLog/", env!("CARGO_PKG_VERSION"));
 
+/// Longest an update check may take in total before it is abandoned
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Longest any request may wait to connect, or for the server to respond
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(20);
+
 // ============================================================================
 // Data Structures
 // ============================================================================
@@ -33,6 +54,9 @@ pub struct ReleaseAsset {
     pub name: String,
     pub browser_download_url: String,
     pub size: u64,
+    /// Checksum computed by GitHub, e.g. `sha256:<hex>`
+    #[serde(default)]
+    pub digest: Option<String>,
 }
 
 /// Information about an available update
@@ -43,9 +67,30 @@ pub struct UpdateInfo {
     pub release_notes: Option<String>,
     pub download_url: String,
     pub download_size: u64,
+    /// Expected SHA-256 of the download (lowercase hex), if published
+    pub sha256: Option<String>,
     pub release_page_url: String,
 }
 
+/// Which releases the user wants to be offered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UpdateChannel {
+    /// Full releases only
+    #[default]
+    Stable,
+    /// Prereleases as well, for testers
+    Beta,
+}
+
+impl UpdateChannel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            UpdateChannel::Stable => "Stable",
+            UpdateChannel::Beta => "Beta",
+        }
+    }
+}
+
 /// Result from update check operation
 #[derive(Debug, Clone)]
 pub enum UpdateCheckResult {
@@ -60,12 +105,23 @@ pub enum UpdateCheckResult {
 /// Result from download operation
 #[derive(Debug, Clone)]
 pub enum DownloadResult {
-    /// Download completed successfully
-    Success(PathBuf),
+    /// Download completed successfully; `verified` if its checksum matched
+    Success { path: PathBuf, verified: bool },
+    /// Download was cancelled by the user
+    Cancelled,
     /// Download failed
     Error(String),
 }
 
+/// Message from the background download thread
+#[derive(Debug, Clone)]
+pub enum DownloadMessage {
+    /// Bytes downloaded so far, and the total if the server reported it
+    Progress { downloaded: u64, total: Option<u64> },
+    /// The download finished, failed or was cancelled
+    Done(DownloadResult),
+}
+
 /// Current state of the update process
 #[derive(Debug, Clone, Default)]
 pub enum UpdateState {
@@ -73,8 +129,14 @@ pub enum UpdateState {
     Idle,
     Checking,
     UpdateAvailable(UpdateInfo),
-    Downloading,
-    ReadyToInstall(PathBuf),
+    Downloading {
+        downloaded: u64,
+        total: Option<u64>,
+    },
+    ReadyToInstall {
+        path: PathBuf,
+        verified: bool,
+    },
     Error(String),
 }
 
@@ -90,34 +152,16 @@ pub enum Platform {
 impl Platform {
     /// Detect current platform at compile time
     pub fn current() -> Option<Self> {
-        #[cfg(all(target_os = "windows", target_arch = "x86_64"))]
-        {
-            return Some(Platform::WindowsX64);
-        }
-
-        #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
-        {
-            return Some(Platform::MacOSIntel);
-        }
-
-        #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
-        {
-            return Some(Platform::MacOSArm);
-        }
-
-        #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
-        {
-            return Some(Platform::LinuxX64);
-        }
-
-        #[cfg(not(any(
-            all(target_os = "windows", target_arch = "x86_64"),
-            all(target_os = "macos", target_arch = "x86_64"),
-            all(target_os = "macos", target_arch = "aarch64"),
-            all(target_os = "linux", target_arch = "x86_64")
-        )))]
-        {
-            return None;
+        if cfg!(all(target_os = "windows", target_arch = "x86_64")) {
+            Some(Platform::WindowsX64)
+        } else if cfg!(all(target_os = "macos", target_arch = "x86_64")) {
+            Some(Platform::MacOSIntel)
+        } else if cfg!(all(target_os = "macos", target_arch = "aarch64")) {
+            Some(Platform::MacOSArm)
+        } else if cfg!(all(target_os = "linux", target_arch = "x86_64")) {
+            Some(Platform::LinuxX64)
+        } else {
+            None
         }
     }
 
@@ -145,42 +189,89 @@ impl Platform {
 // Core Functions
 // ============================================================================
 
-/// Check for updates by querying GitHub releases API.
+/// HTTP client for update requests. `proxy` is a proxy URL such as
+/// `http://proxy.example.com:8080`; when empty, the `HTTPS_PROXY` and
+/// `ALL_PROXY` environment variables are used if set. `timeout` limits the
+/// whole request, for checks that shouldn't hang around.
+fn agent(proxy: &str, timeout: Option<Duration>) -> Result<ureq::Agent, String> {
+    let proxy = match proxy.trim() {
+        "" => ureq::Proxy::try_from_env(),
+        url => {
+            Some(ureq::Proxy::new(url).map_err(|e| format!("Invalid proxy \"{}\": {}", url, e))?)
+        }
+    };
+    Ok(ureq::Agent::config_builder()
+        .proxy(proxy)
+        .timeout_global(timeout)
+        .timeout_connect(Some(RESPONSE_TIMEOUT))
+        .timeout_recv_response(Some(RESPONSE_TIMEOUT))
+        .build()
+        .new_agent())
+}
+
+/// Explain a failed request in terms of what the user can do about it
+fn describe_error(error: &ureq::Error) -> String {
+    match error {
+        ureq::Error::StatusCode(status) => format!("GitHub returned status {}", status),
+        ureq::Error::Timeout(_) => {
+            "GitHub didn't respond in time. Check your connection or proxy settings.".to_string()
+        }
+        ureq::Error::HostNotFound | ureq::Error::ConnectionFailed | ureq::Error::Io(_) => {
+            "Couldn't reach GitHub. You may be offline or need a proxy (Preferences → General)."
+                .to_string()
+        }
+        e => format!("Network error: {}", e),
+    }
+}
+
+/// The newest release on `channel` and its version. Drafts and tags that
+/// aren't semantic versions are ignored; the stable channel also ignores
+/// releases marked as prereleases or with a prerelease version (`-beta.1`).
+fn newest_release(
+    releases: &[GitHubRelease],
+    channel: UpdateChannel,
+) -> Option<(&GitHubRelease, semver::Version)> {
+    releases
+        .iter()
+        .filter(|release| !release.draft)
+        .filter_map(|release| {
+            let version = semver::Version::parse(release.tag_name.trim_start_matches('v')).ok()?;
+            let prerelease = release.prerelease || !version.pre.is_empty();
+            (channel == UpdateChannel::Beta || !prerelease).then_some((release, version))
+        })
+        .max_by(|(_, a), (_, b)| a.cmp(b))
+}
+
+/// Check for updates on `channel` by querying GitHub releases API, through
+/// `proxy` if one is given (see [`agent`]).
 /// This is a blocking operation - run in a background thread.
-pub fn check_for_updates() -> UpdateCheckResult {
+pub fn check_for_updates(proxy: &str, channel: UpdateChannel) -> UpdateCheckResult {
     let current_version = env!("CARGO_PKG_VERSION");
 
+    let agent = match agent(proxy, Some(CHECK_TIMEOUT)) {
+        Ok(agent) => agent,
+        Err(e) => return UpdateCheckResult::Error(e),
+    };
+
     // Make HTTP request to GitHub API
-    let mut response = match ureq::get(GITHUB_API_URL)
+    let mut response = match agent
+        .get(GITHUB_API_URL)
         .header("User-Agent", USER_AGENT)
         .header("Accept", "application/vnd.github.v3+json")
         .call()
     {
         Ok(resp) => resp,
-        Err(ureq::Error::StatusCode(status)) => {
-            return UpdateCheckResult::Error(format!("GitHub API returned status {}", status));
-        }
-        Err(e) => {
-            return UpdateCheckResult::Error(format!("Network error: {}", e));
-        }
+        Err(e) => return UpdateCheckResult::Error(describe_error(&e)),
     };
 
     // Parse JSON response
-    let release: GitHubRelease = match response.body_mut().read_json() {
+    let releases: Vec<GitHubRelease> = match response.body_mut().read_json() {
         Ok(r) => r,
         Err(e) => {
             return UpdateCheckResult::Error(format!("Failed to parse response: {}", e));
         }
     };
 
-    // Skip drafts and prereleases
-    if release.draft || release.prerelease {
-        return UpdateCheckResult::UpToDate;
-    }
-
-    // Parse versions for comparison
-    let remote_version_str = release.tag_name.trim_start_matches('v');
-
     let current = match semver::Version::parse(current_version) {
         Ok(v) => v,
         Err(_) => {
@@ -188,15 +279,10 @@ pub fn check_for_updates() -> UpdateCheckResult {
         }
     };
 
-    let remote = match semver::Version::parse(remote_version_str) {
-        Ok(v) => v,
-        Err(_) => {
-            return UpdateCheckResult::Error(format!(
-                "Invalid remote version format: {}",
-                remote_version_str
-            ));
-        }
+    let Some((release, remote)) = newest_release(&releases, channel) else {
+        return UpdateCheckResult::UpToDate;
     };
+    let remote_version_str = remote.to_string();
 
     // Compare versions
     if remote <= current {
@@ -222,33 +308,105 @@ pub fn check_for_updates() -> UpdateCheckResult {
         }
     };
 
+    let sha256 = expected_sha256(&agent, release, asset);
+    if sha256.is_none() {
+        tracing::warn!("No SHA-256 published for {}", asset.name);
+    }
+
     UpdateCheckResult::UpdateAvailable(UpdateInfo {
         current_version: current_version.to_string(),
         new_version: remote_version_str.to_string(),
-        release_notes: release.body,
+        release_notes: release.body.clone(),
         download_url: asset.browser_download_url.clone(),
         download_size: asset.size,
-        release_page_url: release.html_url,
+        sha256,
+        release_page_url: release.html_url.clone(),
     })
 }
 
-/// Download update file to temp directory.
+/// The SHA-256 published for a release asset: GitHub's own digest if it
+/// reports one, otherwise the contents of a `<asset>.sha256` file
+fn expected_sha256(
+    agent: &ureq::Agent,
+    release: &GitHubRelease,
+    asset: &ReleaseAsset,
+) -> Option<String> {
+    if let Some(hash) = asset
+        .digest
+        .as_deref()
+        .and_then(|digest| digest.strip_prefix("sha256:"))
+        .and_then(parse_sha256)
+    {
+        return Some(hash);
+    }
+
+    let checksum_name = format!("{}.sha256", asset.name);
+    let checksum_asset = release.assets.iter().find(|a| a.name == checksum_name)?;
+    let mut response = agent
+        .get(&checksum_asset.browser_download_url)
+        .header("User-Agent", USER_AGENT)
+        .call()
+        .inspect_err(|e| tracing::warn!("Failed to fetch {}: {}", checksum_name, e))
+        .ok()?;
+    let text = response.body_mut().read_to_string().ok()?;
+    parse_sha256(&text)
+}
+
+/// Read a SHA-256 from a checksum file or digest: the first word, if it is
+/// 64 hex digits (`sha256sum` output has the file name after it)
+fn parse_sha256(text: &str) -> Option<String> {
+    let hash = text.split_whitespace().next()?;
+    (hash.len() == 64 && hash.bytes().all(|b| b.is_ascii_hexdigit()))
+        .then(|| hash.to_ascii_lowercase())
+}
+
+/// Lowercase hex of a digest
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Download update file to temp directory, calling `progress` with the bytes
+/// downloaded and the total size (from the Content-Length header, if sent)
+/// after each chunk. Setting `cancel` stops the download and removes the
+/// partial file. A download whose checksum doesn't match `sha256` is removed
+/// and reported as an error; without `sha256`, nothing is downloaded unless
+/// `allow_unverified` says the user agreed to that. `proxy` is used as in
+/// [`check_for_updates`].
 /// This is a blocking operation - run in a background thread.
-pub fn download_update(url: &str) -> DownloadResult {
+pub fn download_update(
+    url: &str,
+    sha256: Option<&str>,
+    allow_unverified: bool,
+    proxy: &str,
+    progress: &dyn Fn(u64, Option<u64>),
+    cancel: &AtomicBool,
+) -> DownloadResult {
     let platform = match Platform::current() {
         Some(p) => p,
         None => return DownloadResult::Error("Unsupported platform".to_string()),
     };
+    if sha256.is_none() && !allow_unverified {
+        return DownloadResult::Error(
+            "No SHA-256 checksum was published for this release, so the download \
+             can't be verified. Download it from the release page instead."
+                .to_string(),
+        );
+    }
 
     // Create temp file path
     let temp_dir = std::env::temp_dir();
     let filename = format!("ultralog-update.{}", platform.extension());
     let download_path = temp_dir.join(&filename);
 
-    // Download file
-    let response = match ureq::get(url).header("User-Agent", USER_AGENT).call() {
+    // Download file; large downloads may take a while, so only connecting
+    // and the first response are time-limited
+    let agent = match agent(proxy, None) {
+        Ok(agent) => agent,
+        Err(e) => return DownloadResult::Error(e),
+    };
+    let response = match agent.get(url).header("User-Agent", USER_AGENT).call() {
         Ok(resp) => resp,
-        Err(e) => return DownloadResult::Error(format!("Download failed: {}", e)),
+        Err(e) => return DownloadResult::Error(describe_error(&e)),
     };
 
     // Create output file
@@ -258,16 +416,29 @@ pub fn download_update(url: &str) -> DownloadResult {
     };
 
     // Read response body into file
-    let mut reader = response.into_body().into_reader();
-    let mut buffer = [0u8; 8192];
+    let body = response.into_body();
+    let total = body.content_length();
+    let mut reader = body.into_reader();
+    let mut buffer = vec![0u8; 64 * 1024];
+    let mut downloaded = 0u64;
+    let mut hasher = ring::digest::Context::new(&ring::digest::SHA256);
+    progress(downloaded, total);
 
     loop {
-        match std::io::Read::read(&mut reader, &mut buffer) {
+        if cancel.load(Ordering::Relaxed) {
+            drop(file);
+            let _ = std::fs::remove_file(&download_path);
+            return DownloadResult::Cancelled;
+        }
+        match reader.read(&mut buffer) {
             Ok(0) => break, // EOF
             Ok(n) => {
                 if let Err(e) = file.write_all(&buffer[..n]) {
                     return DownloadResult::Error(format!("Write error: {}", e));
                 }
+                hasher.update(&buffer[..n]);
+                downloaded += n as u64;
+                progress(downloaded, total);
             }
             Err(e) => return DownloadResult::Error(format!("Read error: {}", e)),
         }
@@ -277,8 +448,30 @@ pub fn download_update(url: &str) -> DownloadResult {
     if let Err(e) = file.flush() {
         return DownloadResult::Error(format!("Failed to flush file: {}", e));
     }
+    drop(file);
+
+    // Refuse corrupt or tampered downloads
+    let actual = to_hex(hasher.finish().as_ref());
+    if let Some(expected) = sha256 {
+        if actual != expected {
+            let _ = std::fs::remove_file(&download_path);
+            tracing::error!(
+                "Update checksum mismatch: expected {}, got {}",
+                expected,
+                actual
+            );
+            return DownloadResult::Error(
+                "The downloaded update is corrupt or has been modified (SHA-256 mismatch) \
+                 and was deleted. Try again, or download it from the release page."
+                    .to_string(),
+            );
+        }
+    }
 
-    DownloadResult::Success(download_path)
+    DownloadResult::Success {
+        path: download_path,
+        verified: sha256.is_some(),
+    }
 }
 
 /// Open the downloaded update file using system default handler.
@@ -286,6 +479,251 @@ pub fn install_update(path: &std::path::Path) -> Result<(), String> {
     open::that(path).map_err(|e| format!("Failed to open update file: {}", e))
 }
 
+/// Open the folder holding the downloaded update, for installing by hand
+pub fn open_download_folder(path: &std::path::Path) -> Result<(), String> {
+    let folder = path.parent().ok_or("The update has no download folder")?;
+    open::that(folder).map_err(|e| format!("Failed to open download folder: {}", e))
+}
+
+// ============================================================================
+// Windows Self-Update
+// ============================================================================
+
+/// PowerShell run by the helper process: wait for UltraLog to exit, move the
+/// new executable over the old one (retrying while Windows still holds the
+/// file), then start UltraLog again. If the move keeps failing the old
+/// version is started instead, so the user is never left without the app.
+/// The script deletes itself when it is done.
+#[cfg(target_os = "windows")]
+const SWAP_SCRIPT: &str = r#"param([int]$ProcessId, [string]$Source, [string]$Target)
+Wait-Process -Id $ProcessId -ErrorAction SilentlyContinue
+for ($i = 0; $i -lt 40; $i++) {
+    try {
+        Move-Item -LiteralPath $Source -Destination $Target -Force -ErrorAction Stop
+        break
+    } catch {
+        Start-Sleep -Milliseconds 250
+    }
+}
+Start-Process -FilePath $Target
+Remove-Item -LiteralPath $PSCommandPath -Force -ErrorAction SilentlyContinue
+"#;
+
+/// Write [`SWAP_SCRIPT`] to the temp folder under a new random name. The
+/// file must not exist yet, so a script planted there by something else is
+/// never run in its place.
+#[cfg(target_os = "windows")]
+fn write_swap_script() -> Result<PathBuf, String> {
+    use ring::rand::SecureRandom;
+
+    let random = ring::rand::SystemRandom::new();
+    for _ in 0..8 {
+        let mut suffix = [0u8; 8];
+        random
+            .fill(&mut suffix)
+            .map_err(|_| "Failed to name update helper".to_string())?;
+        let path = std::env::temp_dir().join(format!("ultralog-update-{}.ps1", to_hex(&suffix)));
+        let mut file = match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(format!("Failed to write update helper: {}", e)),
+        };
+        if let Err(e) = file.write_all(SWAP_SCRIPT.as_bytes()) {
+            drop(file);
+            let _ = std::fs::remove_file(&path);
+            return Err(format!("Failed to write update helper: {}", e));
+        }
+        return Ok(path);
+    }
+    Err("Failed to write update helper: no free file name in the temp folder".to_string())
+}
+
+/// Why an update without a matching checksum isn't installed in place
+const UNVERIFIED_INSTALL: &str =
+    "This update couldn't be verified, so it must be installed by hand from its download folder.";
+
+/// Whether updates can be installed in place on this platform, replacing
+/// the running executable and relaunching
+pub fn can_self_update() -> bool {
+    cfg!(target_os = "windows")
+}
+
+/// Install a downloaded Windows update in place: unpack the executable
+/// next to the running one, then start a helper process that swaps it in
+/// once UltraLog exits and relaunches it. Settings live in the app's data
+/// folder and are saved as the app closes, so they carry over.
+///
+/// Only a `verified` download (its checksum matched) is installed; others
+/// must be installed by hand. On success the caller must close the app for
+/// the swap to happen.
+#[cfg(target_os = "windows")]
+pub fn install_and_relaunch(zip_path: &std::path::Path, verified: bool) -> Result<(), String> {
+    use std::os::windows::process::CommandExt;
+
+    /// Start the helper without a console window
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    if !verified {
+        return Err(UNVERIFIED_INSTALL.to_string());
+    }
+
+    let current_exe = std::env::current_exe()
+        .map_err(|e| format!("Failed to find the running executable: {}", e))?;
+    let archive =
+        std::fs::read(zip_path).map_err(|e| format!("Failed to read update file: {}", e))?;
+    let (_, executable) = extract_executable(&archive)?;
+
+    let mut staged = current_exe.clone().into_os_string();
+    staged.push(".new");
+    let staged = PathBuf::from(staged);
+    std::fs::write(&staged, executable).map_err(|e| {
+        format!(
+            "Failed to write the new version next to {} ({}). \
+             Install it manually from the downloaded ZIP.",
+            current_exe.display(),
+            e
+        )
+    })?;
+
+    let script = write_swap_script()?;
+
+    // The script removes itself once it has run
+    std::process::Command::new("powershell")
+        .args(["-NoProfile", "-ExecutionPolicy", "Bypass", "-File"])
+        .arg(&script)
+        .arg("-ProcessId")
+        .arg(std::process::id().to_string())
+        .arg("-Source")
+        .arg(&staged)
+        .arg("-Target")
+        .arg(&current_exe)
+        .creation_flags(CREATE_NO_WINDOW)
+        .spawn()
+        .map_err(|e| {
+            let _ = std::fs::remove_file(&script);
+            format!("Failed to start update helper: {}", e)
+        })?;
+
+    tracing::info!("Update staged at {}, restarting", staged.display());
+    Ok(())
+}
+
+/// Install in place is only implemented for Windows
+#[cfg(not(target_os = "windows"))]
+pub fn install_and_relaunch(_zip_path: &std::path::Path, verified: bool) -> Result<(), String> {
+    if !verified {
+        return Err(UNVERIFIED_INSTALL.to_string());
+    }
+    Err("Installing in place is only supported on Windows".to_string())
+}
+
+/// Find the `.exe` in a ZIP archive and unpack it, returning its name and
+/// contents. Handles the stored and deflated entries `Compress-Archive`
+/// writes; the entry's CRC-32 and size are checked. ZIP64 archives and
+/// encrypted entries are rejected rather than half-understood.
+pub fn extract_executable(archive: &[u8]) -> Result<(String, Vec<u8>), String> {
+    const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+    const ZIP64_END_LOCATOR: u32 = 0x0706_4b50;
+    const CENTRAL_DIRECTORY_ENTRY: u32 = 0x0201_4b50;
+    const LOCAL_HEADER: u32 = 0x0403_4b50;
+    /// General purpose flags marking an encrypted entry (traditional or strong)
+    const ENCRYPTED_FLAGS: u16 = 0x0001 | 0x0040;
+    /// A size or offset that is really stored in a ZIP64 extra field
+    const ZIP64_MARKER: u32 = u32::MAX;
+    const UNSUPPORTED_ZIP64: &str = "ZIP64 update archives are not supported";
+
+    let u16_at = |offset: usize| -> Result<u16, String> {
+        archive
+            .get(offset..offset + 2)
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .ok_or_else(|| "Update archive is truncated".to_string())
+    };
+    let u32_at = |offset: usize| -> Result<u32, String> {
+        archive
+            .get(offset..offset + 4)
+            .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .ok_or_else(|| "Update archive is truncated".to_string())
+    };
+
+    // The end record is in the last 22 bytes, plus up to 64 KB of comment
+    let search_start = archive.len().saturating_sub(22 + u16::MAX as usize);
+    let end = (search_start..archive.len().saturating_sub(21))
+        .rev()
+        .find(|&offset| u32_at(offset) == Ok(END_OF_CENTRAL_DIRECTORY))
+        .ok_or("Update archive is not a ZIP file")?;
+    if end >= 20 && u32_at(end - 20)? == ZIP64_END_LOCATOR {
+        return Err(UNSUPPORTED_ZIP64.to_string());
+    }
+    let entry_count = u16_at(end + 10)? as usize;
+    let mut offset = u32_at(end + 16)? as usize;
+    if entry_count == u16::MAX as usize || offset == ZIP64_MARKER as usize {
+        return Err(UNSUPPORTED_ZIP64.to_string());
+    }
+
+    for _ in 0..entry_count {
+        if u32_at(offset)? != CENTRAL_DIRECTORY_ENTRY {
+            return Err("Update archive is damaged".to_string());
+        }
+        let flags = u16_at(offset + 8)?;
+        let method = u16_at(offset + 10)?;
+        let crc = u32_at(offset + 16)?;
+        let compressed_size = u32_at(offset + 20)? as usize;
+        let size = u32_at(offset + 24)? as usize;
+        let name_len = u16_at(offset + 28)? as usize;
+        let extra_len = u16_at(offset + 30)? as usize;
+        let comment_len = u16_at(offset + 32)? as usize;
+        let local_offset = u32_at(offset + 42)? as usize;
+        let name = archive
+            .get(offset + 46..offset + 46 + name_len)
+            .map(|n| String::from_utf8_lossy(n).replace('\\', "/"))
+            .ok_or("Update archive is truncated")?;
+        offset += 46 + name_len + extra_len + comment_len;
+
+        if !name.to_ascii_lowercase().ends_with(".exe") {
+            continue;
+        }
+
+        if [compressed_size, size, local_offset].contains(&(ZIP64_MARKER as usize)) {
+            return Err(UNSUPPORTED_ZIP64.to_string());
+        }
+        if u32_at(local_offset)? != LOCAL_HEADER {
+            return Err("Update archive is damaged".to_string());
+        }
+        if (flags | u16_at(local_offset + 6)?) & ENCRYPTED_FLAGS != 0 {
+            return Err(format!("{} in the update archive is encrypted", name));
+        }
+        let data_start = local_offset
+            + 30
+            + u16_at(local_offset + 26)? as usize
+            + u16_at(local_offset + 28)? as usize;
+        let data = archive
+            .get(data_start..data_start + compressed_size)
+            .ok_or("Update archive is truncated")?;
+        let contents = match method {
+            0 => data.to_vec(),
+            8 => {
+                let mut contents = Vec::with_capacity(size);
+                flate2::read::DeflateDecoder::new(data)
+                    .read_to_end(&mut contents)
+                    .map_err(|e| format!("Failed to unpack {}: {}", name, e))?;
+                contents
+            }
+            other => return Err(format!("Unsupported ZIP compression method {}", other)),
+        };
+        if contents.len() != size || crc32fast::hash(&contents) != crc {
+            return Err(format!("{} in the update archive is corrupt", name));
+        }
+        let file_name = name.rsplit('/').next().unwrap_or(&name).to_string();
+        return Ok((file_name, contents));
+    }
+
+    Err("The update archive doesn't contain an executable".to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,6 +747,186 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_sha256() {
+        let hash = "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855";
+        assert_eq!(
+            parse_sha256(&format!("{}  ultralog-windows.zip\n", hash)),
+            Some(hash.to_ascii_lowercase())
+        );
+        assert_eq!(parse_sha256("abc123  ultralog-windows.zip"), None);
+        assert_eq!(parse_sha256(""), None);
+    }
+
+    #[test]
+    fn test_sha256_hex() {
+        let digest = ring::digest::digest(&ring::digest::SHA256, b"");
+        assert_eq!(
+            to_hex(digest.as_ref()),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    /// A ZIP with one deflated entry, laid out as `Compress-Archive` writes it
+    fn sample_zip(name: &str, contents: &[u8]) -> Vec<u8> {
+        let mut encoder =
+            flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(contents).unwrap();
+        let compressed = encoder.finish().unwrap();
+        let crc = crc32fast::hash(contents);
+
+        let mut zip = Vec::new();
+        zip.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        zip.extend_from_slice(&[20, 0, 0, 0, 8, 0, 0, 0, 0, 0]);
+        zip.extend_from_slice(&crc.to_le_bytes());
+        zip.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        zip.extend_from_slice(&(contents.len() as u32).to_le_bytes());
+        zip.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        zip.extend_from_slice(&0u16.to_le_bytes());
+        zip.extend_from_slice(name.as_bytes());
+        zip.extend_from_slice(&compressed);
+
+        let central_start = zip.len();
+        zip.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        zip.extend_from_slice(&[20, 0, 20, 0, 0, 0, 8, 0, 0, 0, 0, 0]);
+        zip.extend_from_slice(&crc.to_le_bytes());
+        zip.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        zip.extend_from_slice(&(contents.len() as u32).to_le_bytes());
+        zip.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        zip.extend_from_slice(&[0; 12]);
+        zip.extend_from_slice(&0u32.to_le_bytes());
+        zip.extend_from_slice(name.as_bytes());
+        let central_len = zip.len() - central_start;
+
+        zip.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+        zip.extend_from_slice(&[0, 0, 0, 0, 1, 0, 1, 0]);
+        zip.extend_from_slice(&(central_len as u32).to_le_bytes());
+        zip.extend_from_slice(&(central_start as u32).to_le_bytes());
+        zip.extend_from_slice(&0u16.to_le_bytes());
+        zip
+    }
+
+    #[test]
+    fn test_extract_executable() {
+        let exe = b"MZ fake executable ".repeat(100);
+        let zip = sample_zip("output/ultralog-windows.exe", &exe);
+        assert_eq!(
+            extract_executable(&zip),
+            Ok(("ultralog-windows.exe".to_string(), exe.clone()))
+        );
+
+        // A flipped byte in the compressed data fails the CRC or inflate
+        let mut damaged = zip.clone();
+        damaged[60] ^= 0xFF;
+        assert!(extract_executable(&damaged).is_err());
+
+        assert!(extract_executable(&sample_zip("readme.txt", b"hi")).is_err());
+        assert!(extract_executable(b"not a zip").is_err());
+    }
+
+    /// Offset of the central directory in a [`sample_zip`] archive
+    fn central_start(zip: &[u8]) -> usize {
+        let end = zip.len() - 22;
+        u32::from_le_bytes(zip[end + 16..end + 20].try_into().unwrap()) as usize
+    }
+
+    #[test]
+    fn test_extract_executable_checks_crc() {
+        let mut zip = sample_zip("ultralog.exe", b"MZ new version");
+        let central = central_start(&zip);
+        // Consistent headers, but neither matches the contents
+        zip[14] ^= 1;
+        zip[central + 16] ^= 1;
+        assert_eq!(
+            extract_executable(&zip),
+            Err("ultralog.exe in the update archive is corrupt".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_executable_rejects_encrypted() {
+        let mut zip = sample_zip("ultralog.exe", b"MZ new version");
+        let central = central_start(&zip);
+        zip[6] |= 1;
+        zip[central + 8] |= 1;
+        assert_eq!(
+            extract_executable(&zip),
+            Err("ultralog.exe in the update archive is encrypted".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_executable_rejects_zip64() {
+        let zip64 = Err("ZIP64 update archives are not supported".to_string());
+
+        // Sizes moved to a ZIP64 extra field
+        let mut zip = sample_zip("ultralog.exe", b"MZ new version");
+        let central = central_start(&zip);
+        zip[central + 20..central + 28].fill(0xFF);
+        assert_eq!(extract_executable(&zip), zip64);
+
+        // A ZIP64 end of central directory locator before the end record
+        let zip = sample_zip("ultralog.exe", b"MZ new version");
+        let (body, end) = zip.split_at(zip.len() - 22);
+        let mut with_locator = body.to_vec();
+        with_locator.extend_from_slice(&0x0706_4b50u32.to_le_bytes());
+        with_locator.extend_from_slice(&[0; 16]);
+        with_locator.extend_from_slice(end);
+        assert_eq!(extract_executable(&with_locator), zip64);
+    }
+
+    #[test]
+    fn test_unverified_download_needs_opt_in() {
+        let never_called = |_, _| panic!("nothing should be downloaded");
+        let result = download_update(
+            "http://127.0.0.1:9/update",
+            None,
+            false,
+            "",
+            &never_called,
+            &AtomicBool::new(false),
+        );
+        assert!(matches!(result, DownloadResult::Error(_)));
+    }
+
+    fn release(tag: &str, prerelease: bool) -> GitHubRelease {
+        GitHubRelease {
+            tag_name: tag.to_string(),
+            name: tag.to_string(),
+            html_url: String::new(),
+            body: None,
+            assets: Vec::new(),
+            prerelease,
+            draft: false,
+        }
+    }
+
+    #[test]
+    fn test_newest_release() {
+        let mut draft = release("v2.0.0", false);
+        draft.draft = true;
+        let releases = vec![
+            draft,
+            release("v1.4.0-beta.2", true),
+            release("v1.3.1", false),
+            release("not-a-version", false),
+            release("v1.3.0", false),
+            release("v1.4.0-rc.1", false), // prerelease version not flagged as one
+        ];
+
+        let tag = |channel| newest_release(&releases, channel).map(|(r, _)| r.tag_name.as_str());
+        assert_eq!(tag(UpdateChannel::Stable), Some("v1.3.1"));
+        assert_eq!(tag(UpdateChannel::Beta), Some("v1.4.0-rc.1"));
+        assert!(newest_release(&[], UpdateChannel::Beta).is_none());
+    }
+
+    #[test]
+    fn test_proxy_config() {
+        assert!(agent("", Some(CHECK_TIMEOUT)).is_ok());
+        assert!(agent("http://proxy.example.com:8080", None).is_ok());
+        assert!(agent("ftp://proxy.example.com", None).is_err());
+    }
+
     #[test]
     fn test_asset_names() {
         assert_eq!(Platform::WindowsX64.asset_name(), "ultralog-windows.zip");